@@ -0,0 +1,269 @@
+//! Benchmarks der heißen Pfade: Sample lesen, Datensätze serialisieren, Bericht rendern
+//!
+//! Läuft ohne Gerät: sysfs kommt aus einem erzeugten Baum (`--sysroot`),
+//! Property-Abfragen beantwortet das Replay-Backend. Gemessen wird mit
+//! criterion:
+//!
+//! ```sh
+//! cargo bench --bench sampling -- --save-baseline main
+//! BENCH_THRESHOLD=10 cargo bench --bench sampling -- --baseline main
+//! ```
+//!
+//! criterion meldet Änderungen nur. Mit `--baseline` vergleicht der Lauf
+//! danach den Median jedes Benchmarks mit der Baseline und endet mit
+//! Exit-Code 1, wenn einer um mehr als `BENCH_THRESHOLD` Prozent (Standard
+//! 10) langsamer ist. `cargo test --benches` führt jeden Benchmark nur einmal
+//! aus. In beiden Modi endet der Lauf mit Exit-Code 1, wenn der
+//! [`IoctlCollector`] mehr als [`IOCTL_SAMPLE_BUDGET`] Syscalls je Sample braucht.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use criterion::Criterion;
+
+use adreno_ioctl::backend::{self, Capture, CaptureEntry, Replay};
+use adreno_ioctl::collector::{Collector, IoctlCollector, SysfsCollector, IOCTL_SAMPLE_BUDGET};
+use adreno_ioctl::json::Json;
+use adreno_ioctl::kgsl::{KgslDeviceInfo, KgslVersionInfo, IOCTL_KGSL_DEVICE_GETPROPERTY, KGSL_PROP_DEVICE_INFO};
+use adreno_ioctl::monitor::Monitor;
+use adreno_ioctl::report::{gpu_info_json, render_gpu_info, to_ascii, InfoExtras};
+use adreno_ioctl::sink::prometheus_text;
+use adreno_ioctl::sys::IntoBytes;
+use adreno_ioctl::sysroot;
+
+/// Gerät im erzeugten Baum
+const DEVICE: &str = "/dev/kgsl-3d0";
+
+/// Umgebungsvariable für die Regressionsschwelle in Prozent
+const THRESHOLD_ENV: &str = "BENCH_THRESHOLD";
+
+/// Standard für [`THRESHOLD_ENV`]
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Samples für die Prüfung des Syscall-Budgets
+const BUDGET_SAMPLES: u64 = 100;
+
+/// Alle Benchmarks, in der Reihenfolge der Ausführung
+const BENCHES: [&str; 9] = [
+    "monitor_sample",
+    "collect_sysfs",
+    "collect_ioctl",
+    "sample_to_record",
+    "record_json",
+    "record_prometheus",
+    "render_gpu_info",
+    "render_plain",
+    "gpu_info_json",
+];
+
+// ============================================================================
+// Regressionsschwelle
+// ============================================================================
+
+/// Wo criterion seine Ergebnisse ablegt, in derselben Reihenfolge wie criterion selbst
+fn criterion_dir() -> PathBuf {
+    if let Some(home) = std::env::var_os("CRITERION_HOME") {
+        return PathBuf::from(home);
+    }
+    match std::env::var_os("CARGO_TARGET_DIR") {
+        Some(target) => PathBuf::from(target).join("criterion"),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target/criterion"),
+    }
+}
+
+/// Name aus `--baseline NAME` bzw. `--baseline-lenient NAME`
+fn compared_baseline() -> Option<String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.split_once('=') {
+            Some(("--baseline" | "--baseline-lenient", name)) => return Some(name.to_string()),
+            _ if arg == "--baseline" || arg == "--baseline-lenient" => return args.next(),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn threshold() -> Result<f64, String> {
+    match std::env::var(THRESHOLD_ENV) {
+        Ok(text) => text.parse().map_err(|_| format!("Invalid {} '{}'", THRESHOLD_ENV, text)),
+        Err(_) => Ok(DEFAULT_THRESHOLD),
+    }
+}
+
+/// Median in ns aus `estimates.json` eines Laufs
+fn median_ns(bench: &str, run: &str) -> Option<f64> {
+    let path = criterion_dir().join(bench).join(run).join("estimates.json");
+    let json = Json::parse(&fs::read_to_string(path).ok()?).ok()?;
+    json.get("median")?.get("point_estimate")?.as_f64()
+}
+
+fn format_ns(ns: f64) -> String {
+    match ns {
+        n if n >= 1e6 => format!("{:.2} ms", n / 1e6),
+        n if n >= 1e3 => format!("{:.2} µs", n / 1e3),
+        n => format!("{:.0} ns", n),
+    }
+}
+
+/// Anzahl der Benchmarks über der Schwelle
+fn compare_baseline(name: &str, threshold: f64) -> usize {
+    println!("\n📋 Compared with baseline '{}' (threshold {} %)", name, threshold);
+    let mut regressions = 0;
+    for bench in BENCHES {
+        let (Some(old), Some(new)) = (median_ns(bench, name), median_ns(bench, "new")) else {
+            println!("   ➖ {:<24} not measured in both runs", bench);
+            continue;
+        };
+        let change = (new / old - 1.0) * 100.0;
+        let icon = if change > threshold {
+            regressions += 1;
+            "❌"
+        } else if change < -threshold {
+            "🚀"
+        } else {
+            "✅"
+        };
+        println!("   {} {:<24} {:>10} → {:>10}  {:+.1} %", icon, bench, format_ns(old), format_ns(new), change);
+    }
+    regressions
+}
+
+// ============================================================================
+// Testumgebung
+// ============================================================================
+
+fn write(root: &Path, path: &str, content: &str) {
+    let path = root.join(path.trim_start_matches('/'));
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+/// sysfs eines A610 mit sechs Power Levels, `/proc/interrupts` mit KGSL-Zeile
+fn fake_sysroot() -> PathBuf {
+    let root = std::env::temp_dir().join(format!("adreno_ioctl-bench-{}", std::process::id()));
+    let dir = "/sys/class/kgsl/kgsl-3d0";
+    write(&root, DEVICE, "");
+    write(&root, &format!("{}/gpuclk", dir), "600000000\n");
+    write(&root, &format!("{}/gpu_busy_percentage", dir), "37 %\n");
+    write(&root, &format!("{}/gpu_available_frequencies", dir), "950000000 845000000 745000000 600000000 465000000 320000000\n");
+    write(&root, &format!("{}/gpu_clock_stats", dir), "1200 3400 5600 7800 9000 12000\n");
+    write(&root, &format!("{}/min_pwrlevel", dir), "5\n");
+    write(&root, &format!("{}/max_pwrlevel", dir), "0\n");
+    write(&root, &format!("{}/thermal_pwrlevel", dir), "0\n");
+    write(&root, &format!("{}/preempt_count", dir), "42\n");
+    write(&root, &format!("{}/ifpc_count", dir), "1234\n");
+    write(&root, &format!("{}/temp", dir), "48500\n");
+    write(
+        &root,
+        "/proc/interrupts",
+        "           CPU0       CPU1\n 301:     123456      654321     GICv3 332 Level     kgsl-3d0\n 302:         12          0     GICv3 336 Level     kgsl_hfi_irq\n",
+    );
+    root
+}
+
+fn install_backend() {
+    let info = KgslDeviceInfo { device_id: 1, chip_id: 0x0601_0000, mmu_enabled: 1, gmem_gpubaseaddr: 0x0010_0000 };
+    let mut capture = Capture::new();
+    capture.push(CaptureEntry {
+        request: IOCTL_KGSL_DEVICE_GETPROPERTY,
+        prop: KGSL_PROP_DEVICE_INFO,
+        size: size_of::<KgslDeviceInfo>() as u32,
+        result: Ok(info.as_bytes().to_vec()),
+    });
+    backend::install(Box::new(Replay::new(capture)));
+}
+
+// ============================================================================
+// Benchmarks
+// ============================================================================
+
+/// Syscalls je Sample eines frischen Collectors; `Err` über [`IOCTL_SAMPLE_BUDGET`]
+fn check_syscall_budget() -> Result<f64, String> {
+    let mut collector = IoctlCollector::new(DEVICE);
+    for _ in 0..BUDGET_SAMPLES {
+        collector.collect().map_err(|e| format!("Collector failed: {}", e))?;
+    }
+    let stats = collector.stats();
+    if stats.samples != BUDGET_SAMPLES {
+        return Err(format!("Collector counted {} of {} samples", stats.samples, BUDGET_SAMPLES));
+    }
+    match stats.per_sample() {
+        per_sample if per_sample > IOCTL_SAMPLE_BUDGET as f64 => Err(format!(
+            "Collector needs {:.2} syscalls per sample, budget is {}",
+            per_sample, IOCTL_SAMPLE_BUDGET
+        )),
+        per_sample => Ok(per_sample),
+    }
+}
+
+fn benches(c: &mut Criterion) {
+    // Sample lesen: ein Monitor-Takt bzw. ein Collector-Durchlauf
+    let monitor = Monitor::new(DEVICE).with_preemption().with_ifpc();
+    c.bench_function("monitor_sample", |b| b.iter(|| monitor.sample()));
+    let mut sysfs = SysfsCollector::new(DEVICE);
+    c.bench_function("collect_sysfs", |b| b.iter(|| sysfs.collect()));
+    let mut ioctl = IoctlCollector::new(DEVICE);
+    c.bench_function("collect_ioctl", |b| b.iter(|| ioctl.collect()));
+
+    // Serialisieren wie die Sinks
+    let prev = monitor.sample();
+    let sample = monitor.sample();
+    c.bench_function("sample_to_record", |b| b.iter(|| sample.to_record(DEVICE, &prev, Duration::from_secs(1))));
+    let record = sample.to_record(DEVICE, &prev, Duration::from_secs(1));
+    c.bench_function("record_json", |b| b.iter(|| record.to_json().to_compact()));
+    c.bench_function("record_prometheus", |b| b.iter(|| prometheus_text(&record, &mut BTreeSet::new())));
+
+    // Bericht von `info`
+    let info = KgslDeviceInfo { device_id: 1, chip_id: 0x0601_0000, mmu_enabled: 1, gmem_gpubaseaddr: 0x0010_0000 };
+    let extras = InfoExtras {
+        version: Some(KgslVersionInfo { driver_version: 0x0003_000f, device_version: 0x0003_0001 }),
+        freq_hz: Some(845_000_000),
+        model: Some("Adreno610v1".to_string()),
+        gmem_bytes: Some(512 * 1024),
+        ..Default::default()
+    };
+    c.bench_function("render_gpu_info", |b| b.iter(|| render_gpu_info(&info, &extras)));
+    let rendered = render_gpu_info(&info, &extras);
+    c.bench_function("render_plain", |b| b.iter(|| to_ascii(&rendered)));
+    c.bench_function("gpu_info_json", |b| b.iter(|| gpu_info_json(&info, &extras).to_pretty()));
+}
+
+fn main() {
+    let threshold = match threshold() {
+        Ok(threshold) => threshold,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(2);
+        }
+    };
+    let root = fake_sysroot();
+    sysroot::set(Some(root.clone()));
+    install_backend();
+
+    let mut criterion = Criterion::default()
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(3))
+        .configure_from_args();
+    benches(&mut criterion);
+    criterion.final_summary();
+
+    let budget = check_syscall_budget();
+    let _ = fs::remove_dir_all(&root);
+    match budget {
+        Ok(per_sample) => println!("✅ ioctl collector: {:.2} syscalls per sample (budget {})", per_sample, IOCTL_SAMPLE_BUDGET),
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    }
+
+    let Some(baseline) = compared_baseline() else { return };
+    let regressions = compare_baseline(&baseline, threshold);
+    if regressions > 0 {
+        eprintln!("❌ {} benchmark(s) slower than the baseline by more than {} %", regressions, threshold);
+        std::process::exit(1);
+    }
+}
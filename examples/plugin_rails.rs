@@ -0,0 +1,75 @@
+//! Beispiel-Plugin: Energiezähler der Power Rails (ODPM) aus IIO
+//!
+//! Pixel-Kernel legen je PMIC ein `iio:deviceN` mit `energy_value` an:
+//!
+//! ```text
+//! t=123456789
+//! CH0(T=123456789)[S2M_VDD_CPUCL2], 87654321
+//! CH3(T=123456789)[S3M_VDD_GPU], 1234567
+//! ```
+//!
+//! Bauen und laden:
+//!
+//! ```sh
+//! cargo build --release --example plugin_rails
+//! adreno_ioctl collect --plugin target/release/examples/libplugin_rails.so
+//! ```
+
+use std::ffi::{CString, c_int};
+use std::fs;
+use std::sync::Mutex;
+
+use adreno_ioctl::plugin::{PluginDescriptor, PluginMetric};
+
+const IIO_DIR: &str = "/sys/bus/iio/devices";
+
+/// Namen der letzten Messung; müssen bis zum nächsten `collect` leben
+static NAMES: Mutex<Vec<CString>> = Mutex::new(Vec::new());
+
+// SAFETY: `collect` schreibt höchstens `max` Einträge, die Namen leben in NAMES
+static DESCRIPTOR: PluginDescriptor = unsafe { PluginDescriptor::new(c"rails", Some(supported), collect) };
+
+/// Alle `energy_value`-Dateien
+fn energy_files() -> Vec<String> {
+    let Ok(entries) = fs::read_dir(IIO_DIR) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path().join("energy_value"))
+        .filter(|path| path.exists())
+        .filter_map(|path| path.to_str().map(str::to_string))
+        .collect()
+}
+
+/// `CH3(T=...)[S3M_VDD_GPU], 1234567` → ("s3m_vdd_gpu_uws", 1234567)
+fn parse_line(line: &str) -> Option<(String, f64)> {
+    let (head, value) = line.split_once(',')?;
+    let rail = head.split_once('[')?.1.strip_suffix(']')?;
+    Some((format!("{}_uws", rail.to_ascii_lowercase()), value.trim().parse().ok()?))
+}
+
+unsafe extern "C" fn supported() -> c_int {
+    c_int::from(!energy_files().is_empty())
+}
+
+unsafe extern "C" fn collect(out: *mut PluginMetric, max: usize) -> c_int {
+    let rails: Vec<(String, f64)> = energy_files()
+        .iter()
+        .filter_map(|file| fs::read_to_string(file).ok())
+        .flat_map(|text| text.lines().filter_map(parse_line).collect::<Vec<_>>())
+        .take(max)
+        .collect();
+    let mut names = NAMES.lock().unwrap();
+    *names = rails.iter().filter_map(|(name, _)| CString::new(name.as_str()).ok()).collect();
+    let out = unsafe { std::slice::from_raw_parts_mut(out, max) };
+    for ((slot, name), (_, value)) in out.iter_mut().zip(names.iter()).zip(&rails) {
+        *slot = PluginMetric::new(name, *value);
+    }
+    names.len() as c_int
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn adreno_plugin_init() -> *const PluginDescriptor {
+    &DESCRIPTOR
+}
@@ -0,0 +1,10 @@
+//! Alarmregeln von `monitor --alert`
+#![no_main]
+
+use adreno_ioctl::alert::AlertRule;
+use adreno_ioctl::units::{parse_duration, parse_size};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let _ = AlertRule::parse(text, parse_duration, parse_size);
+});
@@ -0,0 +1,16 @@
+//! Aufzeichnungen für `analyze`, CSV und JSON Lines
+#![no_main]
+
+use std::time::Duration;
+
+use adreno_ioctl::analyze::{aggregate, parse_csv, read_samples};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let _ = parse_csv(text);
+    if let Ok(rows) = read_samples(text) {
+        for window in aggregate(&rows, Duration::from_secs(1)) {
+            let _ = window.to_json();
+        }
+    }
+});
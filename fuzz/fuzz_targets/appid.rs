@@ -0,0 +1,10 @@
+//! `packages.list` und `/proc/<pid>/status`
+#![no_main]
+
+use adreno_ioctl::appid::{parse_status_uid, PackageDb};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let _ = PackageDb::parse(text);
+    let _ = parse_status_uid(text);
+});
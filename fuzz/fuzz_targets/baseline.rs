@@ -0,0 +1,13 @@
+//! Gespeicherte Scans für `scan --baseline`
+#![no_main]
+
+use adreno_ioctl::json::Json;
+use adreno_ioctl::scan::{diff, Scan};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    if let Ok(scan) = Json::parse(text).and_then(|json| Scan::from_json(&json)) {
+        let _ = diff(&scan, &scan);
+        let _ = scan.to_json();
+    }
+});
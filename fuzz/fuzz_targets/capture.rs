@@ -0,0 +1,11 @@
+//! Capture-Dateien von `--capture`, auch von Nutzern eingeschickte
+#![no_main]
+
+use adreno_ioctl::backend::Capture;
+use adreno_ioctl::json::Json;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let _ = Json::parse(text);
+    let _ = Capture::parse(text);
+});
@@ -0,0 +1,13 @@
+//! Chip-ID aus der Property und Modellname aus `KGSL_PROP_GPU_MODEL`
+#![no_main]
+
+use adreno_ioctl::chip::{decode_chip_id, lookup_model};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(id) = data.get(..4) {
+        let chip = decode_chip_id(u32::from_le_bytes(id.try_into().unwrap()));
+        let _ = chip.spec();
+    }
+    let _ = lookup_model(&String::from_utf8_lossy(data));
+});
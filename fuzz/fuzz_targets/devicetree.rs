@@ -0,0 +1,39 @@
+//! Device-Tree Knoten, auch aus Dumps unter `--sysroot`
+#![no_main]
+
+use std::fs;
+use std::iter;
+
+use adreno_ioctl::devicetree::{parse_cells, parse_gpu_node, parse_strings};
+use libfuzzer_sys::fuzz_target;
+
+/// Properties des Testbaums; die Eingabe wird an 0xff-Bytes auf sie verteilt
+const PROPERTIES: [&str; 10] = [
+    "compatible",
+    "qcom,gpu-speed-bin",
+    "qcom,gpu-pwrlevels/qcom,speed-bin",
+    "qcom,gpu-pwrlevels/qcom,gpu-pwrlevel@0/reg",
+    "qcom,gpu-pwrlevels/qcom,gpu-pwrlevel@0/qcom,gpu-freq",
+    "qcom,gpu-pwrlevels/qcom,gpu-pwrlevel@0/qcom,level",
+    "opp-table/opp-1/opp-hz",
+    "opp-table/opp-1/opp-microvolt",
+    "opp-table/opp-1/opp-level",
+    "zap-shader/firmware-name",
+];
+
+fuzz_target!(|data: &[u8]| {
+    let _ = parse_cells(data);
+    let _ = parse_strings(data);
+
+    // Jede Property wird geschrieben, damit nichts vom vorigen Lauf übrig bleibt
+    let node = std::env::temp_dir().join(format!("adreno_ioctl-fuzz-dt-{}", std::process::id()));
+    let values = data.split(|&b| b == 0xff).chain(iter::repeat(&[][..]));
+    for (property, value) in PROPERTIES.iter().zip(values) {
+        let path = node.join(property);
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let _ = fs::write(&path, value);
+    }
+    let _ = parse_gpu_node(&node);
+});
@@ -0,0 +1,15 @@
+//! Kernel-Log und `trace_pipe`-Zeilen
+#![no_main]
+
+use adreno_ioctl::dmesg::{grep, GPU_FAULT_PATTERNS};
+use adreno_ioctl::events::parse_trace_line;
+use adreno_ioctl::triage::parse_faults;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|log: &str| {
+    let _ = parse_faults(log);
+    let _ = grep(log, &GPU_FAULT_PATTERNS);
+    for line in log.lines() {
+        let _ = parse_trace_line(line, "kgsl-3d0");
+    }
+});
@@ -0,0 +1,12 @@
+//! Zustandsdatei der laufenden Instanz (`restore`)
+#![no_main]
+
+use adreno_ioctl::instance::InstanceState;
+use adreno_ioctl::json::Json;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    if let Ok(json) = Json::parse(text) {
+        let _ = InstanceState::from_json(&json);
+    }
+});
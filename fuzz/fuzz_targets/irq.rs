@@ -0,0 +1,11 @@
+//! `/proc/interrupts`
+#![no_main]
+
+use adreno_ioctl::irq::parse_interrupts;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    for line in parse_interrupts(text) {
+        let _ = (line.total(), line.short_name(), line.is_gpu());
+    }
+});
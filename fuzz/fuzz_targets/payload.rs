@@ -0,0 +1,12 @@
+//! Property-Antworten aus Scans und Captures
+#![no_main]
+
+use adreno_ioctl::kgsl::{KgslDeviceInfo, KgslDevinfo, KgslVersionInfo};
+use adreno_ioctl::payload::Decode;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = KgslDeviceInfo::decode_bytes(data);
+    let _ = KgslDevinfo::decode_bytes(data);
+    let _ = KgslVersionInfo::decode_bytes(data);
+});
@@ -0,0 +1,18 @@
+//! PM4 Command Streams, z.B. IBs aus Dumps
+#![no_main]
+
+use adreno_ioctl::pm4::{decode_bytes, opcode_name, CommandStream};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    for generation in [4, 6] {
+        let Ok(packets) = decode_bytes(data, generation) else { continue };
+        // Neu kodiert muss dieselbe Paketfolge herauskommen
+        let mut stream = CommandStream::new(generation);
+        for packet in &packets {
+            let _ = opcode_name(packet.opcode);
+            stream.packet(packet.opcode, &packet.payload);
+        }
+        assert_eq!(decode_bytes(&stream.as_bytes(), generation), Ok(packets));
+    }
+});
@@ -0,0 +1,9 @@
+//! GPU-Snapshot-Dumps (`snapshot/dump`, `triage --snapshot`)
+#![no_main]
+
+use adreno_ioctl::triage::parse_snapshot;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = parse_snapshot(data);
+});
@@ -0,0 +1,11 @@
+//! Inhalt von sysfs-Knoten, die Vendor-Kernel beliebig formatieren
+#![no_main]
+
+use adreno_ioctl::sysfs::{parse_gpubusy, parse_u64, parse_u64_list};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let _ = parse_u64(text);
+    let _ = parse_u64_list(text);
+    let _ = parse_gpubusy(text);
+});
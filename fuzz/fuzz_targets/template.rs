@@ -0,0 +1,9 @@
+//! Vorlagen von `get --format`
+#![no_main]
+
+use adreno_ioctl::template;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let _ = template::parse(text, &["chip_id", "model"]);
+});
@@ -0,0 +1,10 @@
+//! Dauern und Größen der Kommandozeile
+#![no_main]
+
+use adreno_ioctl::units::{parse_duration, parse_size};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let _ = parse_duration(text);
+    let _ = parse_size(text);
+});
@@ -0,0 +1,44 @@
+/*
+ * adreno_ioctl collector plugin ABI, version 1
+ *
+ * A plugin is a shared library exporting adreno_plugin_init(). The tool
+ * loads it with dlopen(RTLD_NOW | RTLD_LOCAL), checks abi_version and then
+ * treats it like a built-in collector:
+ *
+ *   adreno_ioctl collect --plugin ./librails.so
+ *   ADRENO_IOCTL_PLUGIN_DIR=/vendor/lib64/adreno_ioctl adreno_ioctl collect
+ *
+ * Rules:
+ * - The descriptor and the name it points to must stay valid until the
+ *   library is unloaded.
+ * - collect() writes at most max entries to out and returns their number,
+ *   or a negative errno. Metric names must stay valid until the next call.
+ * - supported() may be NULL (always supported).
+ * - Calls never overlap, but may come from different threads.
+ *
+ * Fields are only ever appended; any other change bumps
+ * ADRENO_PLUGIN_ABI_VERSION. Static builds of the tool cannot load plugins.
+ */
+#ifndef ADRENO_PLUGIN_H
+#define ADRENO_PLUGIN_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define ADRENO_PLUGIN_ABI_VERSION 1
+
+struct adreno_metric {
+	const char *name;
+	double value;
+};
+
+struct adreno_plugin {
+	uint32_t abi_version;
+	const char *name;
+	int (*supported)(void);
+	int (*collect)(struct adreno_metric *out, size_t max);
+};
+
+const struct adreno_plugin *adreno_plugin_init(void);
+
+#endif
@@ -0,0 +1,68 @@
+#!/bin/sh
+# Idle-Verbrauch mit und ohne Back-off messen (siehe README, "Idle back-off")
+#
+# Läuft auf dem Host und steuert das Telefon über adb, am besten per WLAN:
+# über USB lädt das Telefon, und der Coulomb-Zähler misst nichts Sinnvolles.
+# Je Durchlauf wird `charge_counter` nur am Anfang und am Ende gelesen, damit
+# die Messung selbst das SoC nicht weckt.
+#
+#   scripts/idle_power.sh [BINARY] [SEKUNDEN]
+#
+# BINARY ist der Pfad auf dem Telefon (Standard /data/local/tmp/adreno_ioctl),
+# SEKUNDEN die Dauer je Durchlauf (Standard 1800). Vor jeder Messung wartet
+# das Skript SETTLE Sekunden (Standard 60), bis das Telefon zur Ruhe kommt.
+
+set -eu
+
+BIN=${1:-/data/local/tmp/adreno_ioctl}
+SECS=${2:-1800}
+SETTLE=${SETTLE:-60}
+SUPPLY=/sys/class/power_supply/battery
+
+dev() {
+    adb shell "$@" | tr -d '\r'
+}
+
+status=$(dev cat "$SUPPLY/status")
+if [ "$status" != "Discharging" ]; then
+    echo "❌ Battery is '$status', not 'Discharging': disconnect USB power and use adb over Wi-Fi" >&2
+    exit 1
+fi
+if [ -z "$(dev cat "$SUPPLY/charge_counter" 2>/dev/null)" ]; then
+    echo "❌ $SUPPLY/charge_counter is not readable on this device" >&2
+    exit 1
+fi
+
+# Ein Durchlauf: LABEL und die Argumente für `daemon`, leer = ohne Daemon
+run() {
+    label=$1
+    shift
+    pid=
+    if [ $# -gt 0 ]; then
+        pid=$(dev "nohup $BIN daemon $* </dev/null >/dev/null 2>&1 & echo \$!")
+    fi
+    # Erst einschwingen lassen, dann mit ausgeschaltetem Bildschirm messen
+    dev input keyevent KEYCODE_SLEEP
+    sleep "$SETTLE"
+    start_uah=$(dev cat "$SUPPLY/charge_counter")
+    start_uv=$(dev cat "$SUPPLY/voltage_now")
+    sleep "$SECS"
+    end_uah=$(dev cat "$SUPPLY/charge_counter")
+    end_uv=$(dev cat "$SUPPLY/voltage_now")
+    if [ -n "$pid" ]; then
+        dev kill "$pid" || true
+    fi
+    # µAh über SECS Sekunden -> mA; mit der mittleren Spannung -> mW
+    awk -v label="$label" -v a="$start_uah" -v b="$end_uah" -v u1="$start_uv" -v u2="$end_uv" -v s="$SECS" 'BEGIN {
+        ma = (a - b) / 1000 * 3600 / s
+        printf "| %-28s | %8.1f | %8.1f |\n", label, ma, ma * (u1 + u2) / 2 / 1e6
+    }'
+}
+
+echo "📱 $(dev getprop ro.product.model), $(dev getprop ro.build.version.release), $SECS s per run"
+echo
+echo "| Run                          |       mA |       mW |"
+echo "|------------------------------|---------:|---------:|"
+run "no daemon"
+run "daemon --max-interval 1s" --max-interval 1s
+run "daemon (default back-off)"  --max-interval 10s
@@ -0,0 +1,112 @@
+//! Host-Seite des Farm-Modus: Geräte über `adb` ansteuern
+//!
+//! Das Binary wird nach `/data/local/tmp` geschoben (dort ist es auch ohne
+//! Root ausführbar) und per `adb shell` gestartet. `adb` ab Platform-Tools
+//! 24 reicht den Exit-Code des entfernten Befehls durch.
+
+use std::path::Path;
+use std::process::Command;
+
+/// Ziel des Binarys auf dem Gerät
+pub const REMOTE_BINARY: &str = "/data/local/tmp/adreno_ioctl";
+
+/// Umgebungsvariable für ein anderes `adb`
+pub const ADB_ENV: &str = "ADB";
+
+fn adb_program() -> String {
+    std::env::var(ADB_ENV).ok().filter(|v| !v.is_empty()).unwrap_or_else(|| "adb".to_string())
+}
+
+/// Seriennummern betriebsbereiter Geräte aus `adb devices`
+/// (`unauthorized` und `offline` fallen heraus)
+pub fn parse_devices(text: &str) -> Vec<String> {
+    text.lines()
+        .skip_while(|line| !line.starts_with("List of devices"))
+        .skip(1)
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let serial = parts.next()?;
+            (parts.next() == Some("device")).then(|| serial.to_string())
+        })
+        .collect()
+}
+
+pub fn list_devices() -> Result<Vec<String>, String> {
+    let output = Command::new(adb_program())
+        .arg("devices")
+        .output()
+        .map_err(|e| format!("Cannot run adb: {}", e))?;
+    Ok(parse_devices(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Quoting für die entfernte `sh`
+pub fn shell_quote(arg: &str) -> String {
+    let safe = !arg.is_empty() && arg.bytes().all(|b| b.is_ascii_alphanumeric() || b"-_./=:,@%+".contains(&b));
+    if safe {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// Ergebnis eines entfernten Befehls
+#[derive(Debug, Clone)]
+pub struct RemoteOutput {
+    /// `None`, wenn `adb` selbst abbrach
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Ein Gerät per Seriennummer
+#[derive(Debug, Clone)]
+pub struct Adb {
+    pub serial: String,
+}
+
+impl Adb {
+    pub fn new(serial: &str) -> Self {
+        Adb { serial: serial.to_string() }
+    }
+
+    fn run(&self, args: &[&str]) -> Result<RemoteOutput, String> {
+        let output = Command::new(adb_program())
+            .arg("-s")
+            .arg(&self.serial)
+            .args(args)
+            .output()
+            .map_err(|e| format!("Cannot run adb: {}", e))?;
+        Ok(RemoteOutput {
+            exit_code: output.status.code(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        })
+    }
+
+    /// Schiebt `local` nach `remote` und macht es ausführbar
+    pub fn push_executable(&self, local: &Path, remote: &str) -> Result<(), String> {
+        let local = local.to_string_lossy();
+        let pushed = self.run(&["push", &local, remote])?;
+        if pushed.exit_code != Some(0) {
+            return Err(format!("adb push failed: {}", pushed.stderr.trim()));
+        }
+        let chmod = self.shell(&["chmod".to_string(), "755".to_string(), remote.to_string()])?;
+        match chmod.exit_code {
+            Some(0) => Ok(()),
+            _ => Err(format!("chmod failed: {}", chmod.stderr.trim())),
+        }
+    }
+
+    /// Startet `argv` in der entfernten Shell
+    pub fn shell(&self, argv: &[String]) -> Result<RemoteOutput, String> {
+        let command: Vec<String> = argv.iter().map(|a| shell_quote(a)).collect();
+        self.run(&["shell", &command.join(" ")])
+    }
+
+    /// System-Property des Geräts, leer als `None`
+    pub fn getprop(&self, key: &str) -> Option<String> {
+        let output = self.shell(&["getprop".to_string(), key.to_string()]).ok()?;
+        let value = output.stdout.trim();
+        (!value.is_empty()).then(|| value.to_string())
+    }
+}
@@ -0,0 +1,158 @@
+//! CPU-Affinität und Nice-Level des eigenen Prozesses
+//!
+//! Wer die GPU-Last eines Spiels misst, will die großen Kerne nicht mit dem
+//! Messwerkzeug teilen. [`Placement::apply`] pinnt den Prozess auf ausgewählte Kerne
+//! (z.B. die kleinen) und senkt seine Priorität; Threads, die danach starten,
+//! erben beides. Deshalb vor dem ersten `thread::spawn` aufrufen.
+//!
+//! Die CPU-Topologie für `little` kommt wie jeder sysfs-Pfad über
+//! [`crate::sysroot`], mit `--sysroot` also aus dem fremden Baum. Gepinnt
+//! wird trotzdem der laufende Prozess.
+
+use std::fmt;
+use std::fs;
+use std::io;
+
+use crate::sys;
+use crate::sysroot;
+
+/// CPU-Verzeichnisse, über [`sysroot::resolve`]
+const CPU_DIR: &str = "/sys/devices/system/cpu";
+
+/// Standard für `--cpus`
+pub const CPUS_ENV: &str = "ADRENO_IOCTL_CPUS";
+
+/// Standard für `--nice`
+pub const NICE_ENV: &str = "ADRENO_IOCTL_NICE";
+
+/// Liste wie in `/sys/devices/system/cpu/online`: `0-3,6`
+pub fn parse_cpu_list(text: &str) -> Result<Vec<usize>, String> {
+    let mut cpus = Vec::new();
+    for part in text.trim().split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let parse = |s: &str| s.trim().parse::<usize>().map_err(|_| format!("Invalid CPU list '{}'", text));
+        match part.split_once('-') {
+            Some((first, last)) => cpus.extend(parse(first)?..=parse(last)?),
+            None => cpus.push(parse(part)?),
+        }
+    }
+    cpus.sort_unstable();
+    cpus.dedup();
+    if cpus.is_empty() {
+        return Err(format!("Invalid CPU list '{}'", text));
+    }
+    Ok(cpus)
+}
+
+/// Kompakte Schreibweise, Umkehrung von [`parse_cpu_list`]
+pub fn format_cpu_list(cpus: &[usize]) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < cpus.len() {
+        let start = cpus[i];
+        while i + 1 < cpus.len() && cpus[i + 1] == cpus[i] + 1 {
+            i += 1;
+        }
+        parts.push(if cpus[i] == start { start.to_string() } else { format!("{}-{}", start, cpus[i]) });
+        i += 1;
+    }
+    parts.join(",")
+}
+
+/// Leistungsmaß je CPU: `cpu_capacity` (arm64), sonst `cpuinfo_max_freq`
+fn cpu_weights() -> Vec<(usize, u64)> {
+    let cpu_dir = sysroot::resolve(CPU_DIR);
+    let online = fs::read_to_string(cpu_dir.join("online")).ok();
+    let Some(cpus) = online.and_then(|text| parse_cpu_list(&text).ok()) else {
+        return Vec::new();
+    };
+    cpus.into_iter()
+        .filter_map(|cpu| {
+            let dir = cpu_dir.join(format!("cpu{}", cpu));
+            let read = |file: &str| fs::read_to_string(dir.join(file)).ok()?.trim().parse::<u64>().ok();
+            read("cpu_capacity").or_else(|| read("cpufreq/cpuinfo_max_freq")).map(|w| (cpu, w))
+        })
+        .collect()
+}
+
+/// Die schwächsten Kerne (kleinster Wert); leer, wenn alle gleich sind
+pub fn little_cores() -> Vec<usize> {
+    let weights = cpu_weights();
+    let (Some(min), Some(max)) = (weights.iter().map(|w| w.1).min(), weights.iter().map(|w| w.1).max()) else {
+        return Vec::new();
+    };
+    if min == max {
+        return Vec::new();
+    }
+    weights.into_iter().filter(|w| w.1 == min).map(|w| w.0).collect()
+}
+
+/// `little` oder eine CPU-Liste
+pub fn resolve_cpus(spec: &str) -> Result<Vec<usize>, String> {
+    if spec == "little" {
+        let little = little_cores();
+        if little.is_empty() {
+            return Err("No little cores: all CPUs report the same capacity (use an explicit list like 0-3)".to_string());
+        }
+        return Ok(little);
+    }
+    parse_cpu_list(spec)
+}
+
+/// Pinnt den aufrufenden Thread und alle späteren
+pub fn pin(cpus: &[usize]) -> io::Result<()> {
+    sys::set_affinity(cpus)
+}
+
+/// Nice-Level des aufrufenden Threads; negative Werte brauchen CAP_SYS_NICE
+pub fn set_nice(nice: i32) -> io::Result<()> {
+    if !(-20..=19).contains(&nice) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "nice must be between -20 and 19"));
+    }
+    sys::set_priority(nice)
+}
+
+/// Gewünschte Platzierung aus `--cpus`/`--nice` bzw. den Umgebungsvariablen
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Placement {
+    pub cpus: Option<Vec<usize>>,
+    pub nice: Option<i32>,
+}
+
+impl Placement {
+    /// Optionen vor Umgebung; `None`/leer heißt unverändert
+    pub fn parse(cpus: Option<&str>, nice: Option<&str>) -> Result<Self, String> {
+        let env = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        let cpus = cpus.map(str::to_string).or_else(|| env(CPUS_ENV));
+        let nice = nice.map(str::to_string).or_else(|| env(NICE_ENV));
+        Ok(Placement {
+            cpus: cpus.as_deref().map(resolve_cpus).transpose()?,
+            nice: nice
+                .map(|n| n.trim().parse().ok().filter(|v| (-20..=19).contains(v)).ok_or(format!("Invalid nice level '{}' (-20 to 19)", n)))
+                .transpose()?,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cpus.is_none() && self.nice.is_none()
+    }
+
+    pub fn apply(&self) -> Result<(), String> {
+        if let Some(cpus) = &self.cpus {
+            pin(cpus).map_err(|e| format!("Cannot pin to CPUs {}: {}", format_cpu_list(cpus), e))?;
+        }
+        if let Some(nice) = self.nice {
+            set_nice(nice).map_err(|e| format!("Cannot set nice level {}: {}", nice, e))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Placement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cpus = self.cpus.as_deref().map_or("all".to_string(), format_cpu_list);
+        match self.nice {
+            Some(nice) => write!(f, "CPUs {}, nice {}", cpus, nice),
+            None => write!(f, "CPUs {}", cpus),
+        }
+    }
+}
@@ -0,0 +1,308 @@
+//! Schwellwert-Regeln für den Monitor-Modus
+//!
+//! Eine Regel pro Zeile bzw. `--alert`, z.B.
+//! `temp > 95`, `busy > 98 for 30s`, `mem > 1.5G => exec:/data/hook.sh`,
+//! `resets > 0 => exit`. Ohne Aktion wird nur geloggt.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::monitor::Sample;
+
+/// Überwachte Größe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// GPU-Temperatur in °C
+    Temp,
+    /// Auslastung in Prozent
+    Busy,
+    /// Frequenz in MHz
+    Freq,
+    /// GPU-Speicher aller Prozesse in Bytes
+    Mem,
+    /// GPU-Fehler seit Start des Monitors
+    Resets,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 5] = [Metric::Temp, Metric::Busy, Metric::Freq, Metric::Mem, Metric::Resets];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Metric::Temp => "temp",
+            Metric::Busy => "busy",
+            Metric::Freq => "freq",
+            Metric::Mem => "mem",
+            Metric::Resets => "resets",
+        }
+    }
+
+    fn unit(self) -> &'static str {
+        match self {
+            Metric::Temp => "°C",
+            Metric::Busy => "%",
+            Metric::Freq => " MHz",
+            Metric::Mem => " B",
+            Metric::Resets => "",
+        }
+    }
+}
+
+/// Vergleich einer Regel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Above,
+    Below,
+}
+
+/// Was beim Auslösen passiert
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlertAction {
+    Log,
+    /// Monitor beenden, Exit-Code ungleich 0
+    Exit,
+    /// Kommando über `sh -c` starten
+    Exec(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertRule {
+    pub metric: Metric,
+    pub comparison: Comparison,
+    pub threshold: f64,
+    /// Wie lange die Bedingung ununterbrochen gelten muss
+    pub hold: Duration,
+    pub action: AlertAction,
+    /// Originaltext für Meldungen
+    pub text: String,
+}
+
+impl fmt::Display for AlertRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl AlertRule {
+    /// Parst `METRIK >|< WERT [for DAUER] [=> log|exit|exec:CMD]`
+    ///
+    /// `parse_duration` und `parse_size` kommen vom Aufrufer, damit Einheiten
+    /// wie beim Rest der Kommandozeile gelten.
+    pub fn parse(
+        text: &str,
+        parse_duration: impl Fn(&str) -> Result<Duration, String>,
+        parse_size: impl Fn(&str) -> Result<u64, String>,
+    ) -> Result<Self, String> {
+        let (condition, action) = match text.split_once("=>") {
+            Some((condition, action)) => (condition.trim(), parse_action(action.trim())?),
+            None => (text.trim(), AlertAction::Log),
+        };
+        let (condition, hold) = match condition.split_once(" for ") {
+            Some((condition, hold)) => (condition.trim(), parse_duration(hold.trim())?),
+            None => (condition, Duration::ZERO),
+        };
+        let (name, comparison, value) = match (condition.split_once('>'), condition.split_once('<')) {
+            (Some((name, value)), None) => (name, Comparison::Above, value),
+            (None, Some((name, value))) => (name, Comparison::Below, value),
+            _ => return Err(format!("Alert rule needs '>' or '<': {}", text)),
+        };
+        let metric = Metric::ALL.into_iter().find(|m| m.name() == name.trim()).ok_or_else(|| {
+            let names: Vec<&str> = Metric::ALL.iter().map(|m| m.name()).collect();
+            format!("Unknown alert metric '{}' (one of: {})", name.trim(), names.join(", "))
+        })?;
+        let value = value.trim().trim_end_matches("°C").trim_end_matches('%');
+        let threshold = match metric {
+            Metric::Mem => parse_size(value)? as f64,
+            _ => value.parse().map_err(|_| format!("Invalid alert threshold: {}", value))?,
+        };
+        Ok(AlertRule { metric, comparison, threshold, hold, action, text: text.trim().to_string() })
+    }
+
+    fn matches(&self, value: f64) -> bool {
+        match self.comparison {
+            Comparison::Above => value > self.threshold,
+            Comparison::Below => value < self.threshold,
+        }
+    }
+}
+
+fn parse_action(text: &str) -> Result<AlertAction, String> {
+    match text {
+        "log" => Ok(AlertAction::Log),
+        "exit" => Ok(AlertAction::Exit),
+        _ => match text.strip_prefix("exec:") {
+            Some(command) if !command.trim().is_empty() => Ok(AlertAction::Exec(command.trim().to_string())),
+            _ => Err(format!("Unknown alert action '{}' (log, exit or exec:COMMAND)", text)),
+        },
+    }
+}
+
+/// Zustandswechsel einer Regel
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertEvent {
+    /// Index in [`AlertEngine::rules`]
+    pub rule: usize,
+    pub value: f64,
+    /// `true` beim Auslösen, `false` wenn die Bedingung wieder wegfällt
+    pub firing: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+struct RuleState {
+    since: Option<Instant>,
+    firing: bool,
+}
+
+/// Wertet Regeln gegen Monitor-Samples aus
+#[derive(Debug, Clone)]
+pub struct AlertEngine {
+    rules: Vec<AlertRule>,
+    states: Vec<RuleState>,
+    baseline_faults: Option<u64>,
+}
+
+impl AlertEngine {
+    pub fn new(rules: Vec<AlertRule>) -> Self {
+        let states = vec![RuleState::default(); rules.len()];
+        AlertEngine { rules, states, baseline_faults: None }
+    }
+
+    pub fn rules(&self) -> &[AlertRule] {
+        &self.rules
+    }
+
+    /// Ob eine Regel die Metrik braucht (teure Quellen nur bei Bedarf)
+    pub fn uses(&self, metric: Metric) -> bool {
+        self.rules.iter().any(|r| r.metric == metric)
+    }
+
+    /// Wert einer Metrik im Sample
+    pub fn value(&mut self, metric: Metric, sample: &Sample) -> Option<f64> {
+        match metric {
+            Metric::Temp => sample.temp_c,
+            Metric::Busy => sample.busy_percent,
+            Metric::Freq => sample.freq_hz.map(|hz| hz as f64 / 1e6),
+            Metric::Mem => sample.gpu_mem_bytes.map(|b| b as f64),
+            Metric::Resets => {
+                let faults = sample.gpu_faults?;
+                let baseline = *self.baseline_faults.get_or_insert(faults);
+                Some(faults.saturating_sub(baseline) as f64)
+            }
+        }
+    }
+
+    /// Prüft alle Regeln; fehlende Werte lassen den Zustand unverändert
+    pub fn evaluate(&mut self, sample: &Sample) -> Vec<AlertEvent> {
+        let mut events = Vec::new();
+        for i in 0..self.rules.len() {
+            let Some(value) = self.value(self.rules[i].metric, sample) else {
+                continue;
+            };
+            let (rule, state) = (&self.rules[i], &mut self.states[i]);
+            if !rule.matches(value) {
+                state.since = None;
+                if state.firing {
+                    state.firing = false;
+                    events.push(AlertEvent { rule: i, value, firing: false });
+                }
+                continue;
+            }
+            let since = *state.since.get_or_insert(sample.time);
+            if !state.firing && sample.time.duration_since(since) >= rule.hold {
+                state.firing = true;
+                events.push(AlertEvent { rule: i, value, firing: true });
+            }
+        }
+        events
+    }
+
+    /// Lesbarer Wert mit Einheit
+    pub fn format_value(&self, event: &AlertEvent) -> String {
+        let metric = self.rules[event.rule].metric;
+        match metric {
+            Metric::Mem => format!("{:.0}{}", event.value, metric.unit()),
+            _ => format!("{:.1}{}", event.value, metric.unit()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use super::*;
+    use crate::units::{parse_duration, parse_size};
+
+    fn parse(text: &str) -> Result<AlertRule, String> {
+        AlertRule::parse(text, parse_duration, parse_size)
+    }
+
+    fn sample(time: Instant, temp_c: Option<f64>, gpu_faults: Option<u64>) -> Sample {
+        Sample {
+            time,
+            boottime: Duration::ZERO,
+            wall: SystemTime::UNIX_EPOCH,
+            freq_hz: None,
+            busy_percent: None,
+            temp_c,
+            thermal_level: None,
+            irqs: Vec::new(),
+            clock_stats: Vec::new(),
+            battery: None,
+            queues: None,
+            preempt_count: None,
+            ifpc_count: None,
+            gpu_mem_bytes: None,
+            gpu_faults,
+            retired: None,
+            counters: None,
+        }
+    }
+
+    #[test]
+    fn rules_with_hold_units_and_actions() {
+        let rule = parse(" busy > 98% for 30s ").unwrap();
+        assert_eq!((rule.metric, rule.comparison, rule.threshold), (Metric::Busy, Comparison::Above, 98.0));
+        assert_eq!((rule.hold, &rule.action), (Duration::from_secs(30), &AlertAction::Log));
+        assert_eq!(rule.text, "busy > 98% for 30s");
+        let rule = parse("mem > 1.5G => exec: /data/hook.sh").unwrap();
+        assert_eq!((rule.threshold, rule.action), ((3u64 << 29) as f64, AlertAction::Exec("/data/hook.sh".into())));
+        let rule = parse("temp<20°C=>exit").unwrap();
+        assert_eq!((rule.metric, rule.comparison, rule.threshold), (Metric::Temp, Comparison::Below, 20.0));
+        assert_eq!(rule.action, AlertAction::Exit);
+    }
+
+    #[test]
+    fn broken_rules() {
+        let broken = [
+            "temp 95",
+            "temp > 1 < 2",
+            "volt > 1",
+            "busy > lots",
+            "busy > 1 for ever",
+            "busy > 1 => shout",
+            "busy > 1 => exec:",
+        ];
+        for bad in broken {
+            assert!(parse(bad).is_err(), "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn fires_after_hold_and_clears() {
+        let mut engine = AlertEngine::new(vec![parse("temp > 90 for 10s").unwrap(), parse("resets > 0").unwrap()]);
+        let start = Instant::now();
+        let at = |secs: u64, temp: f64, faults: u64| sample(start + Duration::from_secs(secs), Some(temp), Some(faults));
+        // Erster Fehlerzähler ist nur die Basis
+        assert!(engine.evaluate(&at(0, 95.0, 7)).is_empty());
+        assert!(engine.evaluate(&at(5, 95.0, 7)).is_empty());
+        assert_eq!(engine.evaluate(&at(10, 96.0, 7)), [AlertEvent { rule: 0, value: 96.0, firing: true }]);
+        // Feuert nur einmal, fehlende Werte ändern nichts
+        assert!(engine.evaluate(&at(11, 97.0, 7)).is_empty());
+        assert!(engine.evaluate(&sample(start + Duration::from_secs(12), None, None)).is_empty());
+        let events = engine.evaluate(&at(13, 80.0, 8));
+        let expected = [AlertEvent { rule: 0, value: 80.0, firing: false }, AlertEvent { rule: 1, value: 1.0, firing: true }];
+        assert_eq!(events, expected);
+        assert_eq!(engine.format_value(&events[0]), "80.0°C");
+    }
+}
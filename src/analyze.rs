@@ -0,0 +1,280 @@
+//! Auswertung aufgezeichneter Samples in Zeitfenstern
+//!
+//! Liest, was `monitor --sink csv:FILE` oder `--sink json:FILE` schreibt,
+//! und fasst die `sample`-Datensätze je Gerät in Fenster fester Länge
+//! zusammen. Fenster laufen auf `elapsed_s`, also wacher Zeit; Suspend-
+//! Phasen stehen getrennt in [`Window::suspended`].
+//!
+//! Jedes Sample steht für das Intervall seit dem vorigen Sample desselben
+//! Geräts, wie in [`crate::summary`]: Residency und Drosselzeit sind damit
+//! zeitgewichtet, Mittelwerte dagegen je Sample.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::json::Json;
+use crate::summary::Stat;
+
+/// Die für die Auswertung nötigen Spalten eines `sample`-Datensatzes
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub device: String,
+    pub elapsed: Duration,
+    pub freq_hz: Option<u64>,
+    pub busy_percent: Option<f64>,
+    pub temp_c: Option<f64>,
+    pub thermal_level: Option<u32>,
+    pub suspended: Duration,
+}
+
+impl Row {
+    /// `None` ohne `elapsed_s`; fehlende Spalten sind "nicht verfügbar"
+    fn from_fields(get: impl Fn(&str) -> Option<f64>, device: Option<String>) -> Option<Self> {
+        let secs = |name: &str| get(name).and_then(|s| Duration::try_from_secs_f64(s).ok());
+        Some(Row {
+            device: device.unwrap_or_default(),
+            elapsed: secs("elapsed_s")?,
+            freq_hz: get("freq_hz").map(|hz| hz as u64),
+            busy_percent: get("busy_percent"),
+            temp_c: get("temp_c"),
+            thermal_level: get("thermal_level").map(|l| l as u32),
+            suspended: secs("suspended_s").unwrap_or_default(),
+        })
+    }
+}
+
+// ============================================================================
+// Einlesen
+// ============================================================================
+
+/// Zeilen und Zellen nach RFC 4180, auch mit Zeilenumbrüchen in Anführungszeichen
+pub fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                cell.push('"');
+            }
+            ('"', true) => quoted = false,
+            ('"', false) if cell.is_empty() => quoted = true,
+            (',', false) => row.push(std::mem::take(&mut cell)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut cell));
+                rows.push(std::mem::take(&mut row));
+            }
+            (c, _) => cell.push(c),
+        }
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+    rows
+}
+
+/// `sample`-Datensätze aus CSV; Kopfzeilen (`record,...`) gelten bis zur nächsten
+fn read_csv(text: &str) -> Vec<Row> {
+    let mut columns: Vec<String> = Vec::new();
+    let mut rows = Vec::new();
+    for cells in parse_csv(text) {
+        match cells.first().map(String::as_str) {
+            Some("record") => columns = cells,
+            Some("sample") => {
+                let cell = |name: &str| columns.iter().position(|c| c == name).and_then(|i| cells.get(i));
+                let get = |name: &str| cell(name).and_then(|v| v.parse::<f64>().ok());
+                rows.extend(Row::from_fields(get, cell("device").cloned()));
+            }
+            _ => {}
+        }
+    }
+    rows
+}
+
+/// `sample`-Datensätze aus JSON Lines
+fn read_json_lines(text: &str) -> Result<Vec<Row>, String> {
+    let mut rows = Vec::new();
+    for (number, line) in text.lines().enumerate().filter(|(_, l)| !l.trim().is_empty()) {
+        let json = Json::parse(line).map_err(|e| format!("line {}: {}", number + 1, e))?;
+        if json.get("record").and_then(Json::as_str) != Some("sample") {
+            continue;
+        }
+        let get = |name: &str| json.get(name).and_then(Json::as_f64);
+        rows.extend(Row::from_fields(get, json.get("device").and_then(Json::as_str).map(str::to_string)));
+    }
+    Ok(rows)
+}
+
+/// Erkennt das Format am ersten Zeichen: `{` JSON Lines, sonst CSV
+pub fn read_samples(text: &str) -> Result<Vec<Row>, String> {
+    match text.trim_start().starts_with('{') {
+        true => read_json_lines(text),
+        false => Ok(read_csv(text)),
+    }
+}
+
+// ============================================================================
+// Fenster
+// ============================================================================
+
+/// Aggregate eines Zeitfensters für ein Gerät
+#[derive(Debug, Clone, Default)]
+pub struct Window {
+    pub device: String,
+    /// Beginn relativ zu `elapsed_s` = 0
+    pub start: Duration,
+    pub length: Duration,
+    pub samples: u64,
+    pub busy_percent: Stat,
+    pub freq_mhz: Stat,
+    pub temp_c: Stat,
+    /// Zeit mit `thermal_level` > 0, `None` ohne die Spalte
+    pub throttled: Option<Duration>,
+    /// Zeit je Frequenz in Hz
+    pub residency: BTreeMap<u64, Duration>,
+    pub suspended: Duration,
+}
+
+impl Window {
+    fn add(&mut self, row: &Row, interval: Option<Duration>) {
+        self.samples += 1;
+        if let Some(busy) = row.busy_percent {
+            self.busy_percent.add(busy);
+        }
+        if let Some(hz) = row.freq_hz {
+            self.freq_mhz.add(hz as f64 / 1e6);
+        }
+        if let Some(temp) = row.temp_c {
+            self.temp_c.add(temp);
+        }
+        // Saturierend: Eingabedateien können beliebige Werte enthalten
+        self.suspended = self.suspended.saturating_add(row.suspended);
+        if let Some(level) = row.thermal_level {
+            let throttled = self.throttled.get_or_insert(Duration::ZERO);
+            if level > 0 {
+                *throttled = throttled.saturating_add(interval.unwrap_or_default());
+            }
+        }
+        if let (Some(hz), Some(interval)) = (row.freq_hz, interval) {
+            let residency = self.residency.entry(hz).or_default();
+            *residency = residency.saturating_add(interval);
+        }
+    }
+
+    /// Anteil je Frequenz in Prozent, höchste Frequenz zuerst
+    pub fn residency_percent(&self) -> Vec<(u64, f64)> {
+        let total: f64 = self.residency.values().map(Duration::as_secs_f64).sum();
+        if total <= 0.0 {
+            return Vec::new();
+        }
+        self.residency.iter().rev().map(|(&hz, time)| (hz, time.as_secs_f64() * 100.0 / total)).collect()
+    }
+
+    pub fn to_json(&self) -> Json {
+        let stat = |stat: &Stat| {
+            let seen = stat.count > 0;
+            Json::object().field("avg", stat.avg()).field("min", seen.then_some(stat.min)).field("max", seen.then_some(stat.max))
+        };
+        let residency: Vec<Json> = self
+            .residency_percent()
+            .into_iter()
+            .map(|(hz, percent)| Json::object().field("freq_hz", hz).field("percent", percent))
+            .collect();
+        Json::object()
+            .field("device", self.device.as_str())
+            .field("start_s", self.start.as_secs_f64())
+            .field("length_s", self.length.as_secs_f64())
+            .field("samples", self.samples)
+            .field("busy_percent", stat(&self.busy_percent))
+            .field("freq_mhz", stat(&self.freq_mhz))
+            .field("temp_c", stat(&self.temp_c))
+            .field("throttled_s", self.throttled.map(|t| t.as_secs_f64()))
+            .field("suspended_s", self.suspended.as_secs_f64())
+            .field("residency", residency)
+    }
+}
+
+/// Fenster der Länge `length` je Gerät, nach Gerät und Beginn sortiert;
+/// leere Fenster fehlen
+pub fn aggregate(rows: &[Row], length: Duration) -> Vec<Window> {
+    if length.is_zero() {
+        return Vec::new();
+    }
+    let mut windows: BTreeMap<(String, u64), Window> = BTreeMap::new();
+    let mut last: BTreeMap<&str, Duration> = BTreeMap::new();
+    for row in rows {
+        let index = (row.elapsed.as_secs_f64() / length.as_secs_f64()) as u64;
+        // Rücksprung heißt neue Aufzeichnung in derselben Datei: kein Intervall
+        let interval = last.insert(&row.device, row.elapsed).and_then(|prev| row.elapsed.checked_sub(prev));
+        windows
+            .entry((row.device.clone(), index))
+            .or_insert_with(|| Window {
+                device: row.device.clone(),
+                start: Duration::try_from_secs_f64(length.as_secs_f64() * index as f64).unwrap_or(Duration::MAX),
+                length,
+                ..Default::default()
+            })
+            .add(row, interval);
+    }
+    windows.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_quotes_and_newlines() {
+        let rows = parse_csv("a,\"b,c\",\"say \"\"hi\"\"\"\r\n\"multi\nline\",\n");
+        assert_eq!(rows, [vec!["a", "b,c", "say \"hi\""], vec!["multi\nline", ""]]);
+        assert_eq!(parse_csv("last,row"), [vec!["last", "row"]]);
+        assert!(parse_csv("").is_empty());
+    }
+
+    #[test]
+    fn samples_from_csv_and_json_lines() {
+        let csv = "record,device,elapsed_s,freq_hz\nsample,kgsl-3d0,0.5,600000000\nevent,x\nsample,kgsl-3d0,,1\n\
+                   record,elapsed_s,device\nsample,1.5,kgsl-3d1\n";
+        let rows = read_samples(csv).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].device, "kgsl-3d0");
+        assert_eq!((rows[0].elapsed, rows[0].freq_hz), (Duration::from_millis(500), Some(600_000_000)));
+        assert_eq!((rows[1].device.as_str(), rows[1].freq_hz), ("kgsl-3d1", None));
+
+        let json = "{\"record\":\"sample\",\"device\":\"kgsl-3d0\",\"elapsed_s\":2,\"temp_c\":41.5}\n\n{\"record\":\"event\"}\n";
+        let rows = read_samples(json).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!((rows[0].elapsed, rows[0].temp_c), (Duration::from_secs(2), Some(41.5)));
+        assert!(read_samples("{\"record\":\"sample\"}\n{broken").unwrap_err().starts_with("line 2:"));
+    }
+
+    #[test]
+    fn windows_weight_residency_by_interval() {
+        let row = |secs: u64, hz: u64, level: u32| Row {
+            device: "kgsl-3d0".into(),
+            elapsed: Duration::from_secs(secs),
+            freq_hz: Some(hz),
+            busy_percent: Some(secs as f64),
+            temp_c: None,
+            thermal_level: Some(level),
+            suspended: Duration::ZERO,
+        };
+        // Der Rücksprung auf 1 s beginnt eine neue Aufzeichnung ohne Intervall
+        let rows = [row(0, 300, 0), row(1, 300, 0), row(4, 600, 1), row(12, 600, 0), row(1, 300, 0)];
+        let windows = aggregate(&rows, Duration::from_secs(10));
+        assert_eq!(windows.len(), 2);
+        let first = &windows[0];
+        assert_eq!((first.start, first.samples), (Duration::ZERO, 4));
+        assert_eq!(first.residency.get(&300), Some(&Duration::from_secs(1)));
+        assert_eq!(first.residency.get(&600), Some(&Duration::from_secs(3)));
+        assert_eq!(first.throttled, Some(Duration::from_secs(3)));
+        assert_eq!(first.residency_percent(), [(600, 75.0), (300, 25.0)]);
+        assert_eq!((windows[1].start, windows[1].samples), (Duration::from_secs(10), 1));
+        assert!(aggregate(&rows, Duration::ZERO).is_empty());
+    }
+}
@@ -0,0 +1,265 @@
+//! Prozessnamen auf Android: Benutzerprofile, isolierte Prozesse, Pakete
+//!
+//! `comm` ist auf 15 Zeichen gekürzt ("com.google.andr") und sagt nichts
+//! über das Profil. Android kodiert beides in der UID: `user_id * 100000 +
+//! app_id`. Apps liegen bei app_id 10000-19999, SDK-Sandboxen 10000 darüber,
+//! isolierte Prozesse (Browser-Renderer, `isolatedProcess`) bei 90000-99999
+//! ohne eigenes Paket. Das Paket zur UID steht in `packages.list` (nur für
+//! root lesbar); ohne sie bleibt die Kommandozeile, die bei App-Prozessen
+//! der Paketname ist.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use crate::sysroot;
+
+/// Paket-UID-Zuordnung des Package Managers
+pub const PACKAGES_LIST: &str = "/data/system/packages.list";
+
+/// UIDs je Benutzerprofil
+pub const PER_USER_RANGE: u32 = 100_000;
+
+const AID_APP_START: u32 = 10_000;
+const AID_APP_END: u32 = 19_999;
+const AID_SDK_SANDBOX_START: u32 = 20_000;
+const AID_SDK_SANDBOX_END: u32 = 29_999;
+const AID_ISOLATED_START: u32 = 90_000;
+const AID_ISOLATED_END: u32 = 99_999;
+
+/// Art einer UID innerhalb eines Profils
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UidKind {
+    /// root, system, Dienste (app_id < 10000)
+    System,
+    App,
+    /// SDK-Runtime einer App (app_id der App + 10000)
+    SdkSandbox,
+    /// Isolierter Prozess ohne eigene Rechte und ohne Paket
+    Isolated,
+    Other,
+}
+
+/// Zerlegte Android-UID
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AndroidUid {
+    pub uid: u32,
+    /// 0 = Hauptbenutzer, 10+ = weitere Benutzer und Arbeitsprofile
+    pub user_id: u32,
+    pub app_id: u32,
+}
+
+impl AndroidUid {
+    pub fn new(uid: u32) -> Self {
+        AndroidUid { uid, user_id: uid / PER_USER_RANGE, app_id: uid % PER_USER_RANGE }
+    }
+
+    pub fn kind(self) -> UidKind {
+        match self.app_id {
+            0..AID_APP_START => UidKind::System,
+            AID_APP_START..=AID_APP_END => UidKind::App,
+            AID_SDK_SANDBOX_START..=AID_SDK_SANDBOX_END => UidKind::SdkSandbox,
+            AID_ISOLATED_START..=AID_ISOLATED_END => UidKind::Isolated,
+            _ => UidKind::Other,
+        }
+    }
+
+    /// app_id, unter der das Paket in `packages.list` steht
+    pub fn package_app_id(self) -> Option<u32> {
+        match self.kind() {
+            UidKind::App => Some(self.app_id),
+            UidKind::SdkSandbox => Some(self.app_id - (AID_SDK_SANDBOX_START - AID_APP_START)),
+            _ => None,
+        }
+    }
+}
+
+/// Reale UID aus `/proc/<pid>/status` (`Uid: real effective saved fs`)
+pub fn parse_status_uid(status: &str) -> Option<u32> {
+    status.lines().find_map(|line| line.strip_prefix("Uid:")?.split_whitespace().next()?.parse().ok())
+}
+
+// ============================================================================
+// packages.list
+// ============================================================================
+
+/// Pakete je app_id; mehrere bei `sharedUserId`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackageDb {
+    by_app_id: BTreeMap<u32, Vec<String>>,
+}
+
+impl PackageDb {
+    /// `name uid debuggable datadir seinfo gids ...`, eine Zeile pro Paket
+    pub fn parse(text: &str) -> Self {
+        let mut by_app_id: BTreeMap<u32, Vec<String>> = BTreeMap::new();
+        for line in text.lines() {
+            let mut fields = line.split_whitespace();
+            let (Some(name), Some(Ok(uid))) = (fields.next(), fields.next().map(str::parse::<u32>)) else {
+                continue;
+            };
+            by_app_id.entry(uid % PER_USER_RANGE).or_default().push(name.to_string());
+        }
+        PackageDb { by_app_id }
+    }
+
+    pub fn packages(&self, app_id: u32) -> &[String] {
+        self.by_app_id.get(&app_id).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_app_id.is_empty()
+    }
+}
+
+/// Zuletzt gelesene Liste mit ihrer Änderungszeit
+static PACKAGES: Mutex<Option<(Option<SystemTime>, PackageDb)>> = Mutex::new(None);
+
+/// `packages.list`, neu gelesen nur nach einer Änderung; leer ohne Zugriff
+pub fn packages() -> PackageDb {
+    let path = sysroot::resolve(PACKAGES_LIST);
+    let mtime = fs::metadata(&path).and_then(|m| m.modified()).ok();
+    let mut cache = PACKAGES.lock().unwrap();
+    match cache.as_ref() {
+        Some((cached, db)) if *cached == mtime => db.clone(),
+        _ => {
+            let db = fs::read_to_string(&path).map(|text| PackageDb::parse(&text)).unwrap_or_default();
+            *cache = Some((mtime, db.clone()));
+            db
+        }
+    }
+}
+
+// ============================================================================
+// Prozesse
+// ============================================================================
+
+/// Wer hinter einer PID steckt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessIdentity {
+    pub pid: u32,
+    pub uid: Option<AndroidUid>,
+    /// Paket laut `packages.list` bzw. Kommandozeile
+    pub package: Option<String>,
+    /// Bester Name ohne Profil-Zusatz
+    pub name: String,
+}
+
+impl ProcessIdentity {
+    /// Aus den Rohdaten von `/proc/<pid>`
+    pub fn resolve(pid: u32, comm: &str, cmdline: &str, status: &str, packages: &PackageDb) -> Self {
+        let uid = parse_status_uid(status).map(AndroidUid::new);
+        // argv[0]; App-Prozesse setzen ihn auf "paket" oder "paket:prozess"
+        let argv0 = cmdline.split('\0').next().unwrap_or("").trim();
+        let known = uid.and_then(AndroidUid::package_app_id).map(|app_id| packages.packages(app_id)).unwrap_or(&[]);
+        let from_cmdline = argv0.split(':').next().filter(|p| p.contains('.') && !p.contains('/'));
+
+        let is_app = uid.is_some_and(|u| matches!(u.kind(), UidKind::App | UidKind::SdkSandbox | UidKind::Isolated));
+        let package = match (known, from_cmdline) {
+            (_, Some(p)) if known.iter().any(|k| k == p) => Some(p.to_string()),
+            ([first, ..], _) => Some(first.clone()),
+            ([], Some(p)) if is_app => Some(p.to_string()),
+            _ => None,
+        };
+        let name = match &package {
+            // "paket:prozess", bei isolierten Prozessen ohne Dienstklasse
+            Some(p) if argv0.starts_with(p.as_str()) => argv0.splitn(3, ':').take(2).collect::<Vec<_>>().join(":"),
+            Some(p) => p.clone(),
+            None if comm.trim().is_empty() => "?".to_string(),
+            None => comm.trim().to_string(),
+        };
+        ProcessIdentity { pid, uid, package, name }
+    }
+
+    /// Liest `/proc/<pid>`; `None`, wenn der Prozess schon weg ist
+    pub fn read(pid: u32, packages: &PackageDb) -> Option<Self> {
+        let dir = sysroot::resolve(format!("/proc/{}", pid));
+        let comm = fs::read_to_string(dir.join("comm")).ok()?;
+        let cmdline = fs::read(dir.join("cmdline")).map(|b| String::from_utf8_lossy(&b).into_owned()).unwrap_or_default();
+        let status = fs::read_to_string(dir.join("status")).unwrap_or_default();
+        Some(Self::resolve(pid, &comm, &cmdline, &status, packages))
+    }
+}
+
+impl fmt::Display for ProcessIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)?;
+        let Some(uid) = self.uid else {
+            return Ok(());
+        };
+        match uid.kind() {
+            UidKind::Isolated => f.write_str(" [isolated]")?,
+            UidKind::SdkSandbox => f.write_str(" [sdk]")?,
+            _ => {}
+        }
+        if uid.user_id > 0 && uid.kind() != UidKind::System {
+            write!(f, " [user {}]", uid.user_id)?;
+        }
+        Ok(())
+    }
+}
+
+/// Anzeigename einer PID, "?" wenn sie nicht mehr existiert
+pub fn process_label(pid: u32) -> String {
+    ProcessIdentity::read(pid, &packages()).map_or_else(|| "?".to_string(), |p| p.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACKAGES: &str = "com.example.game 10123 0 /data/user/0/com.example.game default:targetSdkVersion=34 3003\n\
+                            com.example.shared 10200 0 /data/user/0/com.example.shared platform none\n\
+                            com.example.plugin 10200 0 /data/user/0/com.example.plugin platform none\n\
+                            broken-line\n\
+                            com.example.bad notanumber 0 /data\n";
+
+    fn status(uid: u32) -> String {
+        format!("Name:\tgame\nUmask:\t0077\nUid:\t{}\t{}\t{}\t{}\nGid:\t{}\n", uid, uid, uid, uid, uid)
+    }
+
+    #[test]
+    fn uid_kinds() {
+        let uid = AndroidUid::new(1_010_123);
+        assert_eq!((uid.user_id, uid.app_id, uid.kind()), (10, 10123, UidKind::App));
+        assert_eq!(AndroidUid::new(20_123).package_app_id(), Some(10123));
+        assert_eq!(AndroidUid::new(99_001).kind(), UidKind::Isolated);
+        assert_eq!(AndroidUid::new(1000).package_app_id(), None);
+        assert_eq!(parse_status_uid(&status(10123)), Some(10123));
+        assert_eq!(parse_status_uid("Name:\tx\nUid:\tnope\n"), None);
+    }
+
+    #[test]
+    fn packages_list_skips_broken_lines() {
+        let db = PackageDb::parse(PACKAGES);
+        assert_eq!(db.packages(10123), ["com.example.game"]);
+        assert_eq!(db.packages(10200), ["com.example.shared", "com.example.plugin"]);
+        assert!(db.packages(10999).is_empty());
+        assert!(PackageDb::parse("").is_empty());
+    }
+
+    #[test]
+    fn names_with_profile_and_process() {
+        let db = PackageDb::parse(PACKAGES);
+        let cmdline = "com.example.game:render\0--flag";
+        let work = ProcessIdentity::resolve(1, "com.example.gam", cmdline, &status(1_010_123), &db);
+        assert_eq!(work.package.as_deref(), Some("com.example.game"));
+        assert_eq!(work.to_string(), "com.example.game:render [user 10]");
+
+        // sharedUserId: die Kommandozeile wählt das Paket
+        let shared = ProcessIdentity::resolve(2, "plugin", "com.example.plugin\0", &status(10200), &db);
+        assert_eq!(shared.name, "com.example.plugin");
+
+        // Isolierter Prozess ohne packages.list-Eintrag, Dienstklasse fällt weg
+        let cmdline = "com.example.game:sandboxed:Service0\0";
+        let isolated = ProcessIdentity::resolve(3, "sandboxed", cmdline, &status(99_001), &db);
+        assert_eq!(isolated.to_string(), "com.example.game:sandboxed [isolated]");
+
+        let daemon = ProcessIdentity::resolve(4, "surfaceflinger\n", "/system/bin/surfaceflinger\0", &status(1000), &db);
+        assert_eq!(daemon.package, None);
+        assert_eq!(daemon.to_string(), "surfaceflinger");
+        assert_eq!(ProcessIdentity::resolve(5, "", "", "", &PackageDb::default()).name, "?");
+    }
+}
@@ -0,0 +1,281 @@
+//! Aufzeichnen und Abspielen von GETPROPERTY-Verkehr (Mock-Backend)
+//!
+//! Alle Property-Abfragen in [`crate::kgsl`] laufen über
+//! [`getproperty_ioctl`]. Ist ein Backend installiert, wird es statt des
+//! echten ioctls gefragt; ist eine Aufnahme aktiv, wird jede Antwort des
+//! Kernels mitgeschrieben. So lassen sich Captures von Nutzern auf jedem
+//! Rechner nachstellen.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::payload::NATIVE_WORD;
+use crate::sys;
+
+/// Kopfzeile einer Capture-Datei
+const CAPTURE_HEADER: &str = "# adreno_ioctl property capture v2";
+
+/// Ältere Kopfzeile ohne `word`-Zeile
+const CAPTURE_HEADER_V1: &str = "# adreno_ioctl property capture v1";
+
+/// Breite von `long` in v1-Captures: die kamen von arm64-Geräten
+const V1_WORD: usize = 8;
+
+/// Eine beobachtete Property-Abfrage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptureEntry {
+    /// Verwendete IOCTL-Nummer (ältere Pfade probieren mehrere Größen)
+    pub request: u32,
+    pub prop: u32,
+    pub size: u32,
+    /// Antwort-Bytes oder errno
+    pub result: Result<Vec<u8>, i32>,
+}
+
+/// Aufzeichnung als portable Textdatei
+///
+/// `word N` nennt die Breite von `long` auf dem aufnehmenden Gerät, danach
+/// eine Zeile pro Abfrage: `request prop size ok HEX` oder
+/// `request prop size err ERRNO`, Kommentare mit `#`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capture {
+    pub comments: Vec<String>,
+    /// Breite von `long`/`size_t` in Bytes, bestimmt die Offsets beim Dekodieren
+    pub word: usize,
+    pub entries: Vec<CaptureEntry>,
+}
+
+impl Default for Capture {
+    fn default() -> Self {
+        Capture { comments: Vec::new(), word: NATIVE_WORD, entries: Vec::new() }
+    }
+}
+
+impl Capture {
+    /// Leere Aufnahme mit der Wortbreite dieses Hosts
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn comment(mut self, text: impl Into<String>) -> Self {
+        self.comments.push(text.into());
+        self
+    }
+
+    pub fn push(&mut self, entry: CaptureEntry) {
+        self.entries.push(entry);
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut out = format!("{}\n", CAPTURE_HEADER);
+        for comment in &self.comments {
+            let _ = writeln!(out, "# {}", comment);
+        }
+        let _ = writeln!(out, "word {}", self.word);
+        for e in &self.entries {
+            let _ = match &e.result {
+                Ok(bytes) => writeln!(out, "{:08x} {:08x} {} ok {}", e.request, e.prop, e.size, to_hex(bytes)),
+                Err(errno) => writeln!(out, "{:08x} {:08x} {} err {}", e.request, e.prop, e.size, errno),
+            };
+        }
+        out
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut capture = Capture::new();
+        let mut lines = text.lines().enumerate();
+        match lines.next() {
+            Some((_, line)) if line.trim() == CAPTURE_HEADER => {}
+            Some((_, line)) if line.trim() == CAPTURE_HEADER_V1 => capture.word = V1_WORD,
+            _ => return Err("Not a property capture (missing header)".to_string()),
+        }
+        for (i, line) in lines {
+            let line = line.trim();
+            if let Some(comment) = line.strip_prefix('#') {
+                capture.comments.push(comment.trim().to_string());
+                continue;
+            }
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(word) = line.strip_prefix("word ") {
+                capture.word = match word.trim().parse() {
+                    Ok(word @ (4 | 8)) => word,
+                    _ => return Err(format!("Invalid capture line {}: {}", i + 1, line)),
+                };
+                continue;
+            }
+            let entry = parse_entry(line).ok_or_else(|| format!("Invalid capture line {}: {}", i + 1, line))?;
+            capture.entries.push(entry);
+        }
+        Ok(capture)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+        Self::parse(&text)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+}
+
+fn parse_entry(line: &str) -> Option<CaptureEntry> {
+    let mut fields = line.split_whitespace();
+    let request = u32::from_str_radix(fields.next()?, 16).ok()?;
+    let prop = u32::from_str_radix(fields.next()?, 16).ok()?;
+    let size = fields.next()?.parse().ok()?;
+    let result = match (fields.next()?, fields.next()) {
+        ("ok", hex) => Ok(from_hex(hex.unwrap_or(""))?),
+        ("err", Some(errno)) => Err(errno.parse().ok()?),
+        _ => return None,
+    };
+    Some(CaptureEntry { request, prop, size, result })
+}
+
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub(crate) fn from_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..text.len()).step_by(2).map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok()).collect()
+}
+
+// ============================================================================
+// Backends
+// ============================================================================
+
+/// Quelle für Property-Antworten anstelle des Kernels
+pub trait PropertySource: Send {
+    /// Füllt `value` für `prop`, Fehler als errno
+    fn get_property(&mut self, request: u32, prop: u32, value: &mut [u8]) -> Result<(), i32>;
+
+    /// Breite von `long`/`size_t` des Geräts, dessen Antworten die Quelle liefert
+    fn word_size(&self) -> usize {
+        NATIVE_WORD
+    }
+}
+
+/// Spielt eine [`Capture`] ab
+pub struct Replay {
+    capture: Capture,
+}
+
+impl Replay {
+    pub fn new(capture: Capture) -> Self {
+        Replay { capture }
+    }
+}
+
+impl PropertySource for Replay {
+    fn get_property(&mut self, request: u32, prop: u32, value: &mut [u8]) -> Result<(), i32> {
+        let size = value.len() as u32;
+        // Exakte Übereinstimmung bevorzugt, sonst gleiche Property und Größe (z.B. aus dem Tracer)
+        let entry = self
+            .capture
+            .entries
+            .iter()
+            .find(|e| e.request == request && e.prop == prop && e.size == size)
+            .or_else(|| self.capture.entries.iter().find(|e| e.prop == prop && e.size == size))
+            .ok_or(libc::EINVAL)?;
+        let bytes = entry.result.as_ref().map_err(|&errno| errno)?;
+        let n = bytes.len().min(value.len());
+        value[..n].copy_from_slice(&bytes[..n]);
+        Ok(())
+    }
+
+    fn word_size(&self) -> usize {
+        self.capture.word
+    }
+}
+
+static BACKEND: Mutex<Option<Box<dyn PropertySource>>> = Mutex::new(None);
+static RECORDING: Mutex<Option<Capture>> = Mutex::new(None);
+
+/// Breite von `long`/`size_t` der aktiven Quelle: laut Capture oder dieses Hosts
+pub fn word_size() -> usize {
+    BACKEND.lock().unwrap().as_ref().map_or(NATIVE_WORD, |source| source.word_size())
+}
+
+/// Ersetzt den Kernel für alle folgenden Property-Abfragen
+pub fn install(source: Box<dyn PropertySource>) {
+    *BACKEND.lock().unwrap() = Some(source);
+}
+
+/// Schreibt ab jetzt alle Property-Abfragen mit
+pub fn start_recording(capture: Capture) {
+    *RECORDING.lock().unwrap() = Some(capture);
+}
+
+/// Beendet die Aufnahme und liefert sie zurück
+pub fn stop_recording() -> Option<Capture> {
+    RECORDING.lock().unwrap().take()
+}
+
+/// GETPROPERTY-Aufruf über das aktive Backend, sonst als ioctl
+///
+/// `value` ist der Antwortpuffer, seine Länge geht als `sizebytes` mit.
+pub(crate) fn getproperty_ioctl(fd: i32, request: u32, type_: u32, value: &mut [u8]) -> io::Result<()> {
+    if let Some(source) = BACKEND.lock().unwrap().as_mut() {
+        // Steht für einen ioctl: zählt für Syscall-Budgets wie ein echter
+        sys::count_device_call();
+        return source.get_property(request, type_, value).map_err(io::Error::from_raw_os_error);
+    }
+
+    let result = sys::property_ioctl(fd, request, type_, value);
+    if let Some(capture) = RECORDING.lock().unwrap().as_mut() {
+        capture.push(CaptureEntry {
+            request,
+            prop: type_,
+            size: value.len() as u32,
+            result: match &result {
+                Ok(()) => Ok(value.to_vec()),
+                Err(e) => Err(e.raw_os_error().unwrap_or(0)),
+            },
+        });
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_survives_round_trip() {
+        let mut capture = Capture::new();
+        capture.word = 4;
+        capture.push(CaptureEntry { request: 0xc0140902, prop: 0x1, size: 4, result: Ok(vec![1, 0, 0, 0]) });
+        let parsed = Capture::parse(&capture.to_text()).unwrap();
+        assert_eq!(parsed, capture);
+    }
+
+    #[test]
+    fn entries_comments_and_broken_lines() {
+        let text = format!("{}\n# Pixel 7\n\nc0140902 00000001 4 ok 01000000\nc0140902 00000020 8 err 22\n", CAPTURE_HEADER);
+        let capture = Capture::parse(&text).unwrap();
+        assert_eq!(capture.comments, ["Pixel 7"]);
+        assert_eq!(capture.entries.len(), 2);
+        assert_eq!(capture.entries[0].result, Ok(vec![1, 0, 0, 0]));
+        assert_eq!((capture.entries[1].prop, &capture.entries[1].result), (0x20, &Err(22)));
+        for line in ["c0140902 1 4 ok 0", "c0140902 1 4 maybe", "c0140902 1 4 err", "zz 1 4 ok", "c0140902 1"] {
+            let error = Capture::parse(&format!("{}\n{}\n", CAPTURE_HEADER, line)).unwrap_err();
+            assert!(error.starts_with("Invalid capture line 2"), "{:?}: {}", line, error);
+        }
+        assert!(Capture::parse("").is_err());
+    }
+
+    #[test]
+    fn v1_capture_is_arm64() {
+        let parsed = Capture::parse(&format!("{}\n", CAPTURE_HEADER_V1)).unwrap();
+        assert_eq!(parsed.word, 8);
+        assert!(Capture::parse(&format!("{}\nword 2\n", CAPTURE_HEADER)).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+//! Erfahrungswerte je Chip: was ein gesundes Gerät typischerweise misst
+//!
+//! Rohzahlen sagen Laien wenig. [`compare`] setzt einen Messwert gegen den
+//! typischen Bereich des Chips und liefert ein [`Verdict`] wie "35% below
+//! typical". Die Bereiche stammen aus Messungen an Seriengeräten verschiedener
+//! Hersteller; Abweichungen innerhalb von [`SIGNIFICANT`] gelten als normal.
+
+use std::fmt;
+
+use crate::chip::ChipSpec;
+
+/// Relative Abweichung vom Bereich, ab der gewarnt wird
+pub const SIGNIFICANT: f64 = 0.10;
+
+/// Typische Werte eines Chips
+#[derive(Debug, Clone, Copy)]
+pub struct Baseline {
+    /// Wie `ChipSpec::name`
+    pub name: &'static str,
+    /// Höchste Frequenz in MHz über die ausgelieferten SoC-Varianten
+    pub max_freq_mhz: (u32, u32),
+    /// Median von `bench compute` (NOP-Submit bis Retire) in µs
+    pub submit_median_us: (u32, u32),
+}
+
+const fn baseline(name: &'static str, max_freq_mhz: (u32, u32), submit_median_us: (u32, u32)) -> Baseline {
+    Baseline { name, max_freq_mhz, submit_median_us }
+}
+
+pub const BASELINES: &[Baseline] = &[
+    baseline("Adreno 530", (510, 653), (60, 180)),
+    baseline("Adreno 540", (670, 710), (50, 160)),
+    baseline("Adreno 610", (600, 1115), (45, 150)),
+    baseline("Adreno 612", (745, 845), (45, 140)),
+    baseline("Adreno 615", (700, 780), (40, 130)),
+    baseline("Adreno 618", (750, 825), (40, 130)),
+    baseline("Adreno 619", (800, 950), (40, 120)),
+    baseline("Adreno 620", (625, 750), (35, 120)),
+    baseline("Adreno 630", (700, 710), (35, 110)),
+    baseline("Adreno 640", (585, 675), (30, 100)),
+    baseline("Adreno 650", (587, 670), (30, 100)),
+    baseline("Adreno 660", (818, 900), (25, 90)),
+    baseline("Adreno 680", (585, 600), (30, 100)),
+    baseline("Adreno 690", (660, 690), (30, 100)),
+    baseline("Adreno 730", (818, 900), (20, 80)),
+    baseline("Adreno 740", (680, 719), (20, 70)),
+    baseline("Adreno 750", (903, 1100), (15, 60)),
+];
+
+/// Erfahrungswerte zu einem Datenbank-Eintrag
+pub fn lookup(spec: &ChipSpec) -> Option<&'static Baseline> {
+    BASELINES.iter().find(|b| b.name == spec.name)
+}
+
+/// Welche Richtung besser ist
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Better {
+    Higher,
+    Lower,
+    /// Fester Wert, jede Abweichung ist auffällig (z.B. GMEM)
+    Exact,
+}
+
+/// Einordnung eines Messwerts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Verdict {
+    /// Im Bereich oder knapp daneben
+    Typical,
+    /// Relativ zur nächsten Bereichsgrenze, z.B. 0.35 = 35%
+    Below(f64),
+    Above(f64),
+}
+
+impl Verdict {
+    pub fn is_typical(self) -> bool {
+        self == Verdict::Typical
+    }
+}
+
+/// Vergleich eines Messwerts mit dem typischen Bereich
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub metric: &'static str,
+    pub unit: &'static str,
+    pub measured: f64,
+    pub range: (f64, f64),
+    pub better: Better,
+    pub verdict: Verdict,
+}
+
+impl Comparison {
+    /// Abweichung in die schlechtere Richtung
+    pub fn is_worse(&self) -> bool {
+        !matches!(
+            (self.better, self.verdict),
+            (_, Verdict::Typical) | (Better::Higher, Verdict::Above(_)) | (Better::Lower, Verdict::Below(_))
+        )
+    }
+}
+
+fn range_text(range: (f64, f64)) -> String {
+    if range.0 == range.1 { format!("{}", range.0) } else { format!("{}-{}", range.0, range.1) }
+}
+
+impl fmt::Display for Comparison {
+    /// `max clock 600 MHz - 35% below typical (950-1115 MHz)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.metric, self.measured.round(), self.unit)?;
+        match self.verdict {
+            Verdict::Typical => write!(f, " - typical ({} {})", range_text(self.range), self.unit),
+            Verdict::Below(d) => {
+                write!(f, " - {:.0}% below typical ({} {})", d * 100.0, range_text(self.range), self.unit)
+            }
+            Verdict::Above(d) => {
+                write!(f, " - {:.0}% above typical ({} {})", d * 100.0, range_text(self.range), self.unit)
+            }
+        }
+    }
+}
+
+/// Ordnet `measured` gegen `range` ein; bei [`Better::Exact`] zählt jede
+/// Abweichung, sonst erst ab [`SIGNIFICANT`]
+pub fn compare(metric: &'static str, unit: &'static str, measured: f64, range: (f64, f64), better: Better) -> Comparison {
+    let tolerance = if better == Better::Exact { 0.0 } else { SIGNIFICANT };
+    let (low, high) = range;
+    let verdict = if low > 0.0 && measured < low * (1.0 - tolerance) {
+        Verdict::Below((low - measured) / low)
+    } else if high > 0.0 && measured > high * (1.0 + tolerance) {
+        Verdict::Above((measured - high) / high)
+    } else {
+        Verdict::Typical
+    };
+    Comparison { metric, unit, measured, range, better, verdict }
+}
+
+fn as_range((low, high): (u32, u32)) -> (f64, f64) {
+    (low as f64, high as f64)
+}
+
+impl Baseline {
+    /// Höchster Takt laut `gpu_available_frequencies`
+    pub fn max_freq(&self, hz: u64) -> Comparison {
+        compare("max clock", "MHz", (hz / 1_000_000) as f64, as_range(self.max_freq_mhz), Better::Higher)
+    }
+
+    pub fn submit_median(&self, us: f64) -> Comparison {
+        compare("submit round-trip", "µs", us, as_range(self.submit_median_us), Better::Lower)
+    }
+}
+
+/// GMEM laut Treiber gegen die Chip-Datenbank
+pub fn gmem(spec: &ChipSpec, bytes: u64) -> Comparison {
+    let kb = (spec.gmem_bytes / 1024) as f64;
+    compare("GMEM", "KB", (bytes / 1024) as f64, (kb, kb), Better::Exact)
+}
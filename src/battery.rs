@@ -0,0 +1,52 @@
+//! Batterie-Strom und -Spannung (`/sys/class/power_supply`)
+//!
+//! Pfade und Vorzeichen unterscheiden sich je Hersteller: manche melden den
+//! Entladestrom positiv, andere negativ. Gemeldet wird deshalb der Betrag.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::sysfs;
+use crate::sysroot;
+
+pub const POWER_SUPPLY_DIR: &str = "/sys/class/power_supply";
+
+/// Ein Messpunkt der Batterie
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BatterySample {
+    /// Mikroampere (Vorzeichen herstellerabhängig)
+    pub current_ua: Option<i64>,
+    /// Mikrovolt
+    pub voltage_uv: Option<i64>,
+}
+
+impl BatterySample {
+    /// Systemleistung in mW (Betrag)
+    pub fn power_mw(&self) -> Option<f64> {
+        Some((self.current_ua? as f64 * self.voltage_uv? as f64).abs() / 1e9)
+    }
+}
+
+/// Findet die Batterie: `battery`, sonst das erste Supply mit `type` = Battery
+pub fn find_battery() -> Option<PathBuf> {
+    let root = sysroot::resolve(POWER_SUPPLY_DIR);
+    let preferred = root.join("battery");
+    if preferred.is_dir() {
+        return Some(preferred);
+    }
+    let mut supplies: Vec<PathBuf> = fs::read_dir(root).ok()?.flatten().map(|e| e.path()).collect();
+    supplies.sort();
+    supplies.into_iter().find(|p| sysfs::read_string(p.join("type")).is_ok_and(|t| t == "Battery"))
+}
+
+fn read_i64(path: PathBuf) -> Option<i64> {
+    sysfs::read_string(path).ok()?.parse().ok()
+}
+
+/// Liest Strom und Spannung aus einem power_supply Verzeichnis
+pub fn read_battery(dir: &Path) -> BatterySample {
+    BatterySample {
+        current_ua: read_i64(dir.join("current_now")),
+        voltage_uv: read_i64(dir.join("voltage_now")),
+    }
+}
@@ -0,0 +1,40 @@
+//! Mikrobenchmarks über den Submission-Pfad
+
+use std::io;
+use std::time::{Duration, Instant};
+
+use crate::submit::Submitter;
+
+/// Verteilung gemessener Dauern
+#[derive(Debug, Clone, Copy)]
+pub struct LatencyStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl LatencyStats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+        let at = |q: f64| samples[((samples.len() - 1) as f64 * q).round() as usize];
+        Some(LatencyStats { min: samples[0], median: at(0.5), p99: at(0.99), max: samples[samples.len() - 1] })
+    }
+}
+
+/// Submit eines NOP-Streams bis zum Retire, `iterations` mal
+///
+/// Misst den Overhead von Kernel, CP und Interrupt-Pfad ohne Shader-Arbeit.
+pub fn submit_roundtrip(submitter: &Submitter, iterations: u32, timeout: Duration) -> io::Result<LatencyStats> {
+    let mut samples = Vec::with_capacity(iterations as usize);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let ts = submitter.submit_nop()?;
+        submitter.wait(ts, timeout)?;
+        samples.push(start.elapsed());
+    }
+    LatencyStats::from_samples(samples).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no iterations"))
+}
@@ -0,0 +1,140 @@
+//! Version der Adreno-Userspace-Treiber (Vendor-Blobs)
+//!
+//! Die Blobs tragen einen Build-String wie
+//! `OpenGL ES 3.2 V@0615.65 (GIT@..., ...) (Date:...)`. Die Zahl nach `V@`
+//! ist die Treiber-Release, die Vulkan als `512.615.65` meldet. Updatable
+//! Drivers (Android 10+) kommen als APK, deren Paket `ro.gfx.driver.0` nennt;
+//! die Bibliotheken liegen dort entpackt oder unkomprimiert in der APK.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::driver::read_build_prop;
+use crate::sysroot;
+
+/// Bekannte Blob-Pfade, 64 Bit zuerst
+pub const BLOB_PATHS: [&str; 8] = [
+    "/vendor/lib64/hw/vulkan.adreno.so",
+    "/vendor/lib64/egl/libGLESv2_adreno.so",
+    "/vendor/lib64/egl/libEGL_adreno.so",
+    "/vendor/lib64/libOpenCL_adreno.so",
+    "/vendor/lib/hw/vulkan.adreno.so",
+    "/vendor/lib/egl/libGLESv2_adreno.so",
+    "/vendor/lib/egl/libEGL_adreno.so",
+    "/vendor/lib/libOpenCL_adreno.so",
+];
+
+/// Installierte Apps (Updatable Drivers)
+const APP_DIR: &str = "/data/app";
+
+/// Vulkan-Major der Adreno-Treiber
+const VULKAN_MAJOR: u32 = 512;
+
+/// Längster Build-String, der übernommen wird
+const MAX_STRING: usize = 200;
+
+/// Eine gefundene Versionsangabe
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlobVersion {
+    /// Zahl nach `V@`, z.B. "0615.65"
+    pub version: String,
+    /// Umgebender Build-String
+    pub text: String,
+}
+
+impl BlobVersion {
+    /// Release wie Vulkans `driverVersion`, z.B. "512.615.65"
+    pub fn release(&self) -> Option<String> {
+        let (minor, patch) = self.version.split_once('.')?;
+        Some(format!("{}.{}.{}", VULKAN_MAJOR, minor.parse::<u32>().ok()?, patch.parse::<u32>().ok()?))
+    }
+}
+
+fn is_text(b: u8) -> bool {
+    b.is_ascii_graphic() || b == b' '
+}
+
+/// Alle `V@NNNN.NN`-Angaben in einer Binärdatei, ohne Duplikate
+pub fn parse_versions(data: &[u8]) -> Vec<BlobVersion> {
+    let mut found: Vec<BlobVersion> = Vec::new();
+    let mut i = 0;
+    while let Some(pos) = data[i..].windows(2).position(|w| w == b"V@") {
+        let at = i + pos;
+        i = at + 2;
+        let digits = data[i..].iter().take_while(|b| b.is_ascii_digit() || **b == b'.').count();
+        let version = String::from_utf8_lossy(&data[i..i + digits]).trim_end_matches('.').to_string();
+        if !version.contains('.') || !version.starts_with(|c: char| c.is_ascii_digit()) {
+            continue;
+        }
+        // Build-String bis zum nächsten Nicht-Text-Byte in beide Richtungen
+        let start = data[..at].iter().rposition(|&b| !is_text(b)).map_or(0, |p| p + 1).max(at.saturating_sub(MAX_STRING));
+        let end = data[at..].iter().position(|&b| !is_text(b)).map_or(data.len(), |p| at + p).min(at + MAX_STRING);
+        let text = String::from_utf8_lossy(&data[start..end]).trim().to_string();
+        if !found.iter().any(|f| f.version == version) {
+            found.push(BlobVersion { version, text });
+        }
+    }
+    found
+}
+
+/// Ein untersuchter Blob
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlobInfo {
+    pub path: PathBuf,
+    pub versions: Vec<BlobVersion>,
+}
+
+pub fn read_blob(path: &Path) -> io::Result<BlobInfo> {
+    Ok(BlobInfo { path: path.to_path_buf(), versions: parse_versions(&fs::read(path)?) })
+}
+
+/// Alle vorhandenen Vendor-Blobs
+pub fn read_vendor_blobs() -> Vec<BlobInfo> {
+    BLOB_PATHS.iter().map(sysroot::resolve).filter(|p| p.exists()).filter_map(|p| read_blob(&p).ok()).collect()
+}
+
+/// Updatable Driver laut `ro.gfx.driver.0`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdatableDriver {
+    pub package: String,
+    /// Installierte Bibliotheken oder die APK selbst (braucht Root)
+    pub files: Vec<BlobInfo>,
+}
+
+/// Verzeichnisse eines Pakets unter `/data/app` (ab Android 11 eine Ebene tiefer)
+fn package_dirs(root: &Path, package: &str) -> Vec<PathBuf> {
+    let prefix = format!("{}-", package);
+    let mut dirs = Vec::new();
+    let Ok(entries) = fs::read_dir(root) else {
+        return dirs;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name.starts_with(&prefix) {
+            dirs.push(entry.path());
+        } else if name.starts_with("~~") {
+            dirs.extend(package_dirs(&entry.path(), package));
+        }
+    }
+    dirs.sort();
+    dirs
+}
+
+/// Liest Paketname und, wenn erreichbar, die installierten Treiber-Dateien
+pub fn read_updatable_driver() -> Option<UpdatableDriver> {
+    let package = read_build_prop("ro.gfx.driver.0").filter(|p| !p.is_empty())?;
+    let mut files = Vec::new();
+    for dir in package_dirs(&sysroot::resolve(APP_DIR), &package) {
+        let libs: Vec<PathBuf> = ["lib/arm64", "lib/arm"]
+            .iter()
+            .filter_map(|sub| fs::read_dir(dir.join(sub)).ok())
+            .flat_map(|entries| entries.flatten().map(|e| e.path()))
+            .filter(|p| p.extension().is_some_and(|e| e == "so"))
+            .collect();
+        // Nicht entpackt: Bibliotheken liegen unkomprimiert in der APK
+        let candidates = if libs.is_empty() { vec![dir.join("base.apk")] } else { libs };
+        files.extend(candidates.iter().filter_map(|p| read_blob(p).ok()).filter(|b| !b.versions.is_empty()));
+    }
+    Some(UpdatableDriver { package, files })
+}
@@ -0,0 +1,199 @@
+//! Bausteine für `bugreport`: Tar-Archiv, SHA-256 und Sammelfunktionen
+//!
+//! Ohne externe Crates: das Archiv ist ein unkomprimiertes POSIX-ustar,
+//! das jedes `tar` und jeder Issue-Tracker annimmt.
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::sysroot;
+use crate::zap::FIRMWARE_DIRS;
+
+// ============================================================================
+// Tar
+// ============================================================================
+
+const BLOCK: usize = 512;
+
+/// Schreibt ein ustar-Archiv
+pub struct TarWriter<W: Write> {
+    out: W,
+    mtime: u64,
+}
+
+/// Oktalfeld mit abschließendem NUL
+fn octal(field: &mut [u8], value: u64) {
+    let text = format!("{:0width$o}", value, width = field.len() - 1);
+    field[..text.len()].copy_from_slice(text.as_bytes());
+}
+
+impl<W: Write> TarWriter<W> {
+    /// `mtime` gilt für alle Einträge (Unix-Sekunden)
+    pub fn new(out: W, mtime: u64) -> Self {
+        TarWriter { out, mtime }
+    }
+
+    /// Hängt eine Datei an; `name` höchstens 100 Bytes
+    pub fn append(&mut self, name: &str, data: &[u8]) -> io::Result<()> {
+        if name.len() > 100 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("tar name too long: {}", name)));
+        }
+        let mut header = [0u8; BLOCK];
+        header[..name.len()].copy_from_slice(name.as_bytes());
+        octal(&mut header[100..108], 0o644);
+        octal(&mut header[108..116], 0);
+        octal(&mut header[116..124], 0);
+        octal(&mut header[124..136], data.len() as u64);
+        octal(&mut header[136..148], self.mtime);
+        header[156] = b'0';
+        header[257..263].copy_from_slice(b"ustar\0");
+        header[263..265].copy_from_slice(b"00");
+        // Prüfsumme wird mit Leerzeichen im Prüfsummenfeld berechnet
+        header[148..156].fill(b' ');
+        let sum: u32 = header.iter().map(|&b| b as u32).sum();
+        let text = format!("{:06o}\0 ", sum);
+        header[148..156].copy_from_slice(text.as_bytes());
+
+        self.out.write_all(&header)?;
+        self.out.write_all(data)?;
+        let padding = (BLOCK - data.len() % BLOCK) % BLOCK;
+        self.out.write_all(&[0u8; BLOCK][..padding])
+    }
+
+    /// Zwei leere Blöcke markieren das Ende
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.write_all(&[0u8; BLOCK * 2])?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+// ============================================================================
+// SHA-256
+// ============================================================================
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+fn compress(state: &mut [u32; 8], block: &[u8]) {
+    let mut w = [0u32; 64];
+    for (i, word) in block.chunks_exact(4).enumerate() {
+        w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+    }
+    for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+    }
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for i in 0..64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(K[i]).wrapping_add(w[i]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = s0.wrapping_add(maj);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+    }
+    for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *s = s.wrapping_add(v);
+    }
+}
+
+/// SHA-256 als Hex-String
+pub fn sha256_hex(data: &[u8]) -> String {
+    let mut state: [u32; 8] =
+        [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+    for block in message.chunks_exact(64) {
+        compress(&mut state, block);
+    }
+    state.iter().map(|word| format!("{:08x}", word)).collect()
+}
+
+// ============================================================================
+// Sammeln
+// ============================================================================
+
+/// Größte Datei, die aus sysfs übernommen wird
+const MAX_SYSFS_FILE: u64 = 16 * 1024;
+
+/// Größte Firmware-Datei, die gehasht wird
+const MAX_FIRMWARE_FILE: u64 = 32 * 1024 * 1024;
+
+/// Alle lesbaren Dateien eines sysfs-Verzeichnisses als `name: wert`-Zeilen
+pub fn dump_sysfs_dir(dir: &Path) -> String {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return String::new();
+    };
+    let mut files: Vec<PathBuf> = entries.flatten().map(|e| e.path()).filter(|p| p.is_file()).collect();
+    files.sort();
+    let mut out = String::new();
+    for file in files {
+        let mut text = String::new();
+        let readable = fs::File::open(&file).and_then(|f| f.take(MAX_SYSFS_FILE).read_to_string(&mut text));
+        if readable.is_ok() {
+            let name = file.file_name().unwrap_or_default().to_string_lossy();
+            out.push_str(&format!("{}: {}\n", name, text.trim_end().replace('\n', "\n    ")));
+        }
+    }
+    out
+}
+
+/// GPU-Firmware: `aNNN_*` (SQE, GMU, Zap) und `adreno*` in den üblichen Verzeichnissen
+pub fn find_gpu_firmware() -> Vec<PathBuf> {
+    let is_gpu = |name: &str| {
+        let bytes = name.as_bytes();
+        name.starts_with("adreno")
+            || (bytes.len() > 4 && bytes[0] == b'a' && bytes[1..4].iter().all(u8::is_ascii_digit))
+    };
+    let mut found: Vec<PathBuf> = FIRMWARE_DIRS
+        .iter()
+        .filter_map(|dir| fs::read_dir(sysroot::resolve(dir)).ok())
+        .flat_map(|entries| entries.flatten())
+        .filter(|e| e.file_name().to_str().is_some_and(is_gpu))
+        .map(|e| e.path())
+        .filter(|p| p.is_file())
+        .collect();
+    found.sort();
+    found.dedup();
+    found
+}
+
+/// `sha256  größe  pfad` je Datei, wie `sha256sum`
+pub fn hash_files(files: &[PathBuf]) -> String {
+    let mut out = String::new();
+    for file in files {
+        let size = fs::metadata(file).map(|m| m.len()).unwrap_or(0);
+        let line = match size {
+            s if s > MAX_FIRMWARE_FILE => format!("{:<64}  {:>9}  {} (too large, not hashed)\n", "-", s, file.display()),
+            _ => match fs::read(file) {
+                Ok(data) => format!("{}  {:>9}  {}\n", sha256_hex(&data), data.len(), file.display()),
+                Err(e) => format!("{:<64}  {:>9}  {} ({})\n", "-", size, file.display(), e),
+            },
+        };
+        out.push_str(&line);
+    }
+    out
+}
@@ -0,0 +1,99 @@
+//! Anonymisiertes Ergebnis-Paket für eine Geräte-Datenbank der Community
+//!
+//! Enthält nur, was Chips vergleichbar macht: Chip, Treiber- und
+//! Kernel-Version, Fähigkeiten, Messwerte. Keine Seriennummern, Gerätepfade,
+//! Host- oder Build-Namen; das Kernel-Release wird auf `X.Y.Z` gekürzt, weil
+//! der Rest Build-Hashes und Hersteller-Tags trägt. Das Paket wird nur
+//! geschrieben, nie verschickt.
+
+use crate::baseline::Comparison;
+use crate::bench::LatencyStats;
+use crate::caps::{Capability, Support};
+use crate::chip::ChipInfo;
+use crate::json::Json;
+use crate::schema::versioned;
+
+/// Was in das Paket eingeht
+#[derive(Debug, Clone, Default)]
+pub struct Submission {
+    pub chip: Option<ChipInfo>,
+    /// `KGSL_PROP_GPU_MODEL`, z.B. "Adreno610v1"
+    pub gpu_model: Option<String>,
+    pub gmem_bytes: Option<u64>,
+    pub frequencies_hz: Vec<u64>,
+    pub driver_version: Option<u32>,
+    pub device_version: Option<u32>,
+    /// Volles `uname -r`; erst [`to_json`](Submission::to_json) kürzt
+    pub kernel_release: String,
+    pub android_release: Option<String>,
+    pub android_sdk: Option<u32>,
+    /// `ro.soc.model`, z.B. "SM6225"
+    pub soc_model: Option<String>,
+    pub capabilities: Vec<Capability>,
+    pub submit_roundtrip: Option<LatencyStats>,
+    pub comparisons: Vec<Comparison>,
+}
+
+/// `5.15.104-android13-8-00001-gdeadbeef-ab123` -> `5.15.104`
+pub fn kernel_series(release: &str) -> String {
+    let end = release.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(release.len());
+    release[..end].trim_end_matches('.').to_string()
+}
+
+fn support_json(support: Support) -> Json {
+    match support {
+        Support::Yes => Json::Bool(true),
+        Support::No => Json::Bool(false),
+        Support::Unknown => Json::Null,
+        Support::Version(v) => Json::from(v as u32),
+    }
+}
+
+impl Submission {
+    pub fn to_json(&self) -> Json {
+        let chip = self.chip.as_ref();
+        let capabilities = self.capabilities.iter().fold(Json::object(), |caps, cap| {
+            caps.field(
+                cap.name,
+                Json::object()
+                    .field("hardware", support_json(cap.hardware))
+                    .field("driver", support_json(cap.driver))
+                    .field("enabled", support_json(cap.enabled)),
+            )
+        });
+        let us = |d: std::time::Duration| (d.as_secs_f64() * 1e7).round() / 10.0;
+        let scores = Json::object()
+            .field("submit_median_us", self.submit_roundtrip.map(|s| us(s.median)))
+            .field("submit_p99_us", self.submit_roundtrip.map(|s| us(s.p99)));
+        let comparisons: Vec<Json> = self
+            .comparisons
+            .iter()
+            .map(|c| {
+                Json::object()
+                    .field("metric", c.metric)
+                    .field("measured", c.measured)
+                    .field("typical", vec![c.range.0, c.range.1])
+                    .field("typical_match", c.verdict.is_typical())
+            })
+            .collect();
+        versioned(
+            Json::object()
+                .field("chip_id", chip.map(|c| format!("0x{:08x}", c.raw_id)))
+                .field("model", chip.map(|c| c.model_name.as_str()))
+                .field("gpu_model", self.gpu_model.as_deref())
+                .field("generation", chip.map(|c| c.adreno_generation.as_str()))
+                .field("soc_model", self.soc_model.as_deref())
+                .field("gmem_bytes", self.gmem_bytes)
+                .field("max_freq_hz", self.frequencies_hz.iter().max().copied())
+                .field("frequencies_hz", self.frequencies_hz.clone())
+                .field("driver_version", self.driver_version)
+                .field("device_version", self.device_version)
+                .field("kernel", kernel_series(&self.kernel_release))
+                .field("android_release", self.android_release.as_deref())
+                .field("android_sdk", self.android_sdk)
+                .field("capabilities", capabilities)
+                .field("scores", scores)
+                .field("baseline", comparisons),
+        )
+    }
+}
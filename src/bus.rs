@@ -0,0 +1,166 @@
+//! Bus-Votes (DDR/ICB) der GPU
+//!
+//! Drei Quellen, je nach Kernel:
+//! - devfreq `gpubw` (ältere Kernel): aktueller Bus-Vote als Frequenz/Index
+//! - Interconnect-debugfs (`interconnect_summary`): avg/peak-Bandbreite je
+//!   Consumer in kBps, für die GPU typischerweise auf dem Pfad zu `ebi`
+//! - Device Tree: `qcom,bus-table-ddr` mit den DDR-Frequenzen je Bus-Level;
+//!   `qcom,bus-min/max` eines Power Levels indizieren in diese Tabelle
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::devicetree::{read_cells, DtPwrLevel};
+use crate::sysfs::{read_string, read_u64};
+use crate::sysroot;
+
+pub const DEVFREQ_DIR: &str = "/sys/class/devfreq";
+pub const ICC_SUMMARY: &str = "/sys/kernel/debug/interconnect/interconnect_summary";
+
+/// Consumer-Namen der GPU im Interconnect-Baum
+const GPU_CONSUMER_HINTS: [&str; 3] = ["gpu", "kgsl", "adreno"];
+
+/// devfreq-Knoten des GPU-Bus-Votes (`soc:qcom,gpubw`, `...gpu-bw...`)
+pub fn find_gpubw_devfreq(root: &Path) -> Option<PathBuf> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir(root)
+        .ok()?
+        .flatten()
+        .filter(|e| {
+            let name = e.file_name().to_string_lossy().to_lowercase();
+            name.contains("gpubw") || name.contains("gpu-bw") || name.contains("gpu_bw")
+        })
+        .map(|e| e.path())
+        .collect();
+    dirs.sort();
+    dirs.into_iter().next()
+}
+
+/// Zustand eines devfreq-Bus-Knotens
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DevfreqBus {
+    pub dir: PathBuf,
+    pub cur: Option<u64>,
+    pub available: Vec<u64>,
+    pub governor: Option<String>,
+}
+
+impl DevfreqBus {
+    pub fn read(dir: &Path) -> Self {
+        let mut available: Vec<u64> = read_string(dir.join("available_frequencies"))
+            .map(|text| text.split_whitespace().filter_map(|v| v.parse().ok()).collect())
+            .unwrap_or_default();
+        available.sort_unstable();
+        DevfreqBus {
+            dir: dir.to_path_buf(),
+            cur: read_u64(dir.join("cur_freq")).ok(),
+            available,
+            governor: read_string(dir.join("governor")).ok(),
+        }
+    }
+
+    /// Stufe des aktuellen Votes (0 = niedrigste) und Anzahl Stufen
+    pub fn tier(&self) -> Option<(usize, usize)> {
+        let cur = self.cur?;
+        let index = self.available.iter().position(|&f| f == cur)?;
+        Some((index, self.available.len()))
+    }
+}
+
+/// Bandbreiten-Vote eines GPU-Consumers auf einem Interconnect-Knoten
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IccVote {
+    /// Knoten, z.B. `ebi` oder `qns_llcc`
+    pub node: String,
+    pub consumer: String,
+    pub avg_kbps: u64,
+    pub peak_kbps: u64,
+}
+
+/// Parst `interconnect_summary`: Knotenzeilen beginnen am Zeilenanfang,
+/// Consumer-Zeilen sind eingerückt
+pub fn parse_icc_summary(text: &str) -> Vec<IccVote> {
+    let mut votes = Vec::new();
+    let mut node = String::new();
+    for line in text.lines() {
+        if line.trim().is_empty() || line.starts_with('-') || line.trim_start().starts_with("node") {
+            continue;
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if !line.starts_with(' ') {
+            node = fields[0].to_string();
+            continue;
+        }
+        let consumer = fields[0];
+        if !GPU_CONSUMER_HINTS.iter().any(|hint| consumer.contains(hint)) {
+            continue;
+        }
+        // "<consumer> [tag] <avg> <peak>" - die letzten beiden Spalten zählen
+        let numbers: Vec<u64> = fields[1..].iter().filter_map(|f| f.parse().ok()).collect();
+        if let [.., avg, peak] = numbers.as_slice() {
+            votes.push(IccVote { node: node.clone(), consumer: consumer.to_string(), avg_kbps: *avg, peak_kbps: *peak });
+        }
+    }
+    votes
+}
+
+pub fn read_icc_votes(path: &Path) -> io::Result<Vec<IccVote>> {
+    Ok(parse_icc_summary(&fs::read_to_string(path)?))
+}
+
+/// DDR-Frequenzen je Bus-Level aus `qcom,bus-table-ddr` (kHz)
+pub fn read_ddr_table(gpu_node: &Path) -> Vec<u32> {
+    read_cells(&gpu_node.join("qcom,bus-table-ddr")).unwrap_or_default()
+}
+
+/// DDR-Bereich in kHz, den ein Power Level erlaubt
+pub fn ddr_range_khz(level: &DtPwrLevel, ddr_table: &[u32]) -> Option<(u32, u32)> {
+    let at = |index: Option<u32>| ddr_table.get(index? as usize).copied();
+    match (at(level.bus_min), at(level.bus_max)) {
+        (Some(min), Some(max)) => Some((min, max)),
+        _ => at(level.bus_freq).map(|f| (f, f)),
+    }
+}
+
+/// DDR-Typ, vom Bootloader in `/memory` des Device Tree eingetragen
+/// (KGSL liest denselben Wert für UBWC und Highest Bank Bit)
+pub const DDR_TYPE_PATH: &str = "/proc/device-tree/memory/ddr_device_type";
+
+/// Speichertyp (Kodierung wie `of_fdt_get_ddrtype`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DdrType {
+    Lpddr3,
+    Lpddr4,
+    Lpddr4x,
+    Lpddr5,
+    Lpddr5x,
+    Unknown(u32),
+}
+
+impl DdrType {
+    pub fn from_raw(raw: u32) -> Self {
+        match raw {
+            5 => DdrType::Lpddr3,
+            6 => DdrType::Lpddr4,
+            7 => DdrType::Lpddr4x,
+            8 => DdrType::Lpddr5,
+            9 => DdrType::Lpddr5x,
+            other => DdrType::Unknown(other),
+        }
+    }
+
+    pub fn label(self) -> String {
+        match self {
+            DdrType::Lpddr3 => "LPDDR3".to_string(),
+            DdrType::Lpddr4 => "LPDDR4".to_string(),
+            DdrType::Lpddr4x => "LPDDR4X".to_string(),
+            DdrType::Lpddr5 => "LPDDR5".to_string(),
+            DdrType::Lpddr5x => "LPDDR5X".to_string(),
+            DdrType::Unknown(raw) => format!("unknown ({})", raw),
+        }
+    }
+}
+
+pub fn read_ddr_type() -> Option<DdrType> {
+    read_cells(&sysroot::resolve(DDR_TYPE_PATH)).ok()?.first().map(|&raw| DdrType::from_raw(raw))
+}
@@ -0,0 +1,162 @@
+//! Cache für unveränderliche Geräte-Fakten (Chip ID, GMEM, Modell, ...)
+//!
+//! Widgets rufen `get` oft im Sekundentakt auf. Was sich ohne neuen Kernel
+//! oder neue Firmware nicht ändern kann, wird in einer JSON-Datei gehalten.
+//! Der Fingerabdruck aus Kernel-Build sowie Größe und mtime von Firmware
+//! und `build.prop` macht den Cache automatisch ungültig.
+
+use std::fs;
+use std::io;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+
+use crate::driver::{uname, BUILD_PROPS};
+use crate::json::Json;
+use crate::zap::FIRMWARE_DIRS;
+
+/// Umgebungsvariable für einen anderen Cache-Pfad
+pub const CACHE_ENV: &str = "ADRENO_IOCTL_CACHE";
+
+/// Werte, die gecacht werden dürfen (Platzhalter-Namen von `get`)
+pub const IMMUTABLE_KEYS: &[&str] =
+    &["model", "chip_id", "device_id", "driver_version", "gmem_size", "gmem_kb", "max_freq_mhz"];
+
+/// Standardpfad: `/data/local/tmp` auf Android, sonst `~/.cache`
+pub fn cache_path() -> PathBuf {
+    if let Some(path) = std::env::var_os(CACHE_ENV) {
+        return PathBuf::from(path);
+    }
+    if cfg!(target_os = "android") {
+        return PathBuf::from("/data/local/tmp/adreno_ioctl.cache.json");
+    }
+    let dir = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .unwrap_or_else(std::env::temp_dir);
+    dir.join("adreno_ioctl.cache.json")
+}
+
+/// Kernel-Build und Metadaten von GPU-Firmware und `build.prop`
+pub fn fingerprint() -> String {
+    let (release, version, _) = uname();
+    format!("{} {} fw:{:016x}", release, version, metadata_hash())
+}
+
+/// Ob eine Datei GPU-Microcode ist (a660_sqe.fw, a640_gmu.bin, a630_zap.mdt, ...)
+fn is_gpu_firmware(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next() == Some('a')
+        && chars.next().is_some_and(|c| c.is_ascii_digit())
+        && ["sqe", "gmu", "pm4", "pfp", "zap"].iter().any(|part| name.contains(part))
+}
+
+/// FNV-1a über Pfad, Größe und mtime der GPU-Firmware und der `build.prop`-Dateien
+///
+/// Nur `stat`, kein Inhalt: ein OTA tauscht `build.prop` mit aus, ein
+/// nachgeschobener Blob ändert Größe oder mtime.
+fn metadata_hash() -> u64 {
+    let mut files: Vec<PathBuf> = FIRMWARE_DIRS
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.file_name().and_then(|n| n.to_str()).is_some_and(is_gpu_firmware))
+        .collect();
+    files.sort();
+    files.extend(BUILD_PROPS.iter().map(PathBuf::from));
+
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut feed = |bytes: &[u8]| {
+        for &b in bytes {
+            hash ^= b as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    };
+    for path in &files {
+        let Ok(meta) = fs::metadata(path) else { continue };
+        feed(path.to_string_lossy().as_bytes());
+        feed(&meta.len().to_le_bytes());
+        feed(&meta.mtime().to_le_bytes());
+        feed(&meta.mtime_nsec().to_le_bytes());
+    }
+    hash
+}
+
+/// Gecachte Werte pro Gerät
+#[derive(Debug, Clone, Default)]
+pub struct FactCache {
+    path: PathBuf,
+    fingerprint: String,
+    devices: Vec<(String, Vec<(String, String)>)>,
+    dirty: bool,
+}
+
+impl FactCache {
+    /// Lädt den Cache; fehlt er oder passt der Fingerabdruck nicht, ist er leer
+    pub fn load(path: &Path) -> Self {
+        let fingerprint = fingerprint();
+        let mut cache = FactCache { path: path.to_path_buf(), fingerprint, ..Default::default() };
+        let Some(json) = fs::read_to_string(path).ok().and_then(|text| Json::parse(&text).ok()) else {
+            return cache;
+        };
+        if json.get("fingerprint").and_then(Json::as_str) != Some(cache.fingerprint.as_str()) {
+            return cache;
+        }
+        for (device, values) in json.get("devices").and_then(Json::as_object).unwrap_or_default() {
+            let values = values
+                .as_object()
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+                .collect();
+            cache.devices.push((device.clone(), values));
+        }
+        cache
+    }
+
+    pub fn get(&self, device: &str, key: &str) -> Option<&str> {
+        let (_, values) = self.devices.iter().find(|(d, _)| d == device)?;
+        values.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    /// Merkt einen Wert, nur für [`IMMUTABLE_KEYS`]
+    pub fn insert(&mut self, device: &str, key: &str, value: &str) {
+        if !IMMUTABLE_KEYS.contains(&key) || self.get(device, key) == Some(value) {
+            return;
+        }
+        let index = match self.devices.iter().position(|(d, _)| d == device) {
+            Some(index) => index,
+            None => {
+                self.devices.push((device.to_string(), Vec::new()));
+                self.devices.len() - 1
+            }
+        };
+        let values = &mut self.devices[index].1;
+        values.retain(|(k, _)| k != key);
+        values.push((key.to_string(), value.to_string()));
+        self.dirty = true;
+    }
+
+    /// Schreibt den Cache, falls sich etwas geändert hat
+    pub fn save(&mut self) -> io::Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        let mut devices = Json::object();
+        for (device, values) in &self.devices {
+            let mut object = Json::object();
+            for (key, value) in values {
+                object = object.field(key, value.as_str());
+            }
+            devices = devices.field(device, object);
+        }
+        let json = Json::object().field("fingerprint", self.fingerprint.as_str()).field("devices", devices);
+        // Erst schreiben, dann umbenennen - parallele Widgets sehen nie eine halbe Datei
+        let tmp = self.path.with_extension(format!("tmp.{}", std::process::id()));
+        fs::write(&tmp, json.to_pretty() + "\n")?;
+        fs::rename(&tmp, &self.path)?;
+        self.dirty = false;
+        Ok(())
+    }
+}
@@ -0,0 +1,235 @@
+//! Capability-Matrix: Hardware vs. Treiber vs. aktiv
+//!
+//! Kennt der Kernel `KGSL_PROP_QUERY_CAPABILITIES`, liefert er die Liste
+//! aller unterstützten Properties; die Treiber-Spalte kommt dann von dort
+//! statt aus Probe-Aufrufen.
+
+use std::io;
+use std::mem::size_of;
+
+use zerocopy::{FromBytes, Immutable, IntoBytes};
+
+use crate::chip::ChipInfo;
+use crate::devicetree;
+use crate::features::read_bool_property;
+use crate::kgsl::{self, AccessMode};
+use crate::propmap::{property_id, Prop};
+use crate::sparse::sparse_supported;
+use crate::sysfs;
+use crate::sysroot;
+
+pub const KGSL_PROP_UBWC_MODE: u32 = 0x0000001B;
+
+/// GMU/RGMU Knoten im Device Tree
+const GMU_COMPATIBLE: [&str; 3] = ["qcom,gpu-gmu", "qcom,gpu-rgmu", "qcom,adreno-gmu"];
+
+// ============================================================================
+// QUERY_CAPABILITIES
+// ============================================================================
+
+/// `KGSL_QUERY_CAPS_PROPERTIES`: Liste der unterstützten Property-IDs
+const KGSL_QUERY_CAPS_PROPERTIES: u32 = 1;
+
+/// `struct kgsl_capabilities`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub(crate) struct KgslCapabilities {
+    pub(crate) data: u64,
+    pub(crate) size: u64,
+    pub(crate) querytype: u32,
+    pub(crate) _pad: u32,
+}
+
+/// `struct kgsl_capabilities_properties`
+#[repr(C)]
+#[derive(Debug, Default, FromBytes, IntoBytes, Immutable)]
+struct KgslCapabilitiesProperties {
+    list: u64,
+    count: u32,
+    _pad: u32,
+}
+
+/// Ein Aufruf mit `list`-Puffer der Größe `count` (0 fragt nur die Anzahl ab)
+fn query_properties_into(fd: i32, list: &mut [u32]) -> io::Result<u32> {
+    let mut props = KgslCapabilitiesProperties {
+        list: if list.is_empty() { 0 } else { list.as_mut_ptr() as u64 },
+        count: list.len() as u32,
+        _pad: 0,
+    };
+    let mut caps = KgslCapabilities {
+        data: &mut props as *mut KgslCapabilitiesProperties as u64,
+        size: size_of::<KgslCapabilitiesProperties>() as u64,
+        querytype: KGSL_QUERY_CAPS_PROPERTIES,
+        _pad: 0,
+    };
+    kgsl::query_property(fd, Prop::QueryCapabilities, &mut caps)?;
+    Ok(props.count)
+}
+
+/// Anzahl-Abfrage von QUERY_CAPABILITIES ohne Fehler-Umdeutung, für die Baum-Erkennung
+pub(crate) fn probe_query_capabilities(fd: i32) -> io::Result<()> {
+    let mut props = KgslCapabilitiesProperties { list: 0, count: 0, _pad: 0 };
+    let mut caps = KgslCapabilities {
+        data: &mut props as *mut KgslCapabilitiesProperties as u64,
+        size: size_of::<KgslCapabilitiesProperties>() as u64,
+        querytype: KGSL_QUERY_CAPS_PROPERTIES,
+        _pad: 0,
+    };
+    kgsl::query_property_id(fd, property_id(Prop::QueryCapabilities), &mut caps)
+}
+
+/// Vom Treiber gemeldete Property-IDs, sortiert
+///
+/// Zwei Aufrufe: der erste liefert die Anzahl, der zweite die Liste.
+pub fn query_properties(fd: i32) -> io::Result<Vec<u32>> {
+    let count = query_properties_into(fd, &mut [])?;
+    let mut list = vec![0u32; count as usize];
+    if count > 0 {
+        let filled = query_properties_into(fd, &mut list)?;
+        list.truncate(filled.min(count) as usize);
+    }
+    list.sort_unstable();
+    list.dedup();
+    Ok(list)
+}
+
+// ============================================================================
+// Matrix
+// ============================================================================
+
+/// Ein Feld der Matrix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Support {
+    Yes,
+    No,
+    Unknown,
+    /// Versionsnummer, z.B. UBWC 3
+    Version(u8),
+}
+
+impl Support {
+    fn from_bool(value: bool) -> Self {
+        if value { Support::Yes } else { Support::No }
+    }
+
+    fn from_option(value: Option<bool>) -> Self {
+        value.map_or(Support::Unknown, Support::from_bool)
+    }
+}
+
+/// Eine Zeile der Matrix
+#[derive(Debug, Clone)]
+pub struct Capability {
+    pub name: &'static str,
+    /// Laut Chip-Datenbank
+    pub hardware: Support,
+    /// Property/sysfs/IOCTL vorhanden
+    pub driver: Support,
+    /// Aktueller Zustand
+    pub enabled: Support,
+}
+
+/// Sammelt die Matrix aus Properties, sysfs und Chip-Datenbank
+pub fn capability_matrix(fd: i32, device_path: &str, chip: &ChipInfo) -> Vec<Capability> {
+    let spec = chip.spec();
+    let hw = |f: fn(&crate::chip::ChipSpec) -> bool| spec.map_or(Support::Unknown, |s| Support::from_bool(f(s)));
+    let dir = sysfs::device_dir(device_path);
+    let sysfs_flag = |name: &str| sysfs::read_u64(dir.join(name)).ok().map(|v| v != 0);
+
+    // Treiber-Spalte: gemeldete Liste, sonst hat der Probe-Aufruf geklappt
+    let advertised = query_properties(fd).ok();
+    let driver = |prop: Prop, probed: bool| match &advertised {
+        Some(list) => Support::from_bool(list.contains(&property_id(prop))),
+        None => Support::from_bool(probed),
+    };
+    let queried = |prop: Prop| {
+        let value = read_bool_property(fd, prop);
+        (driver(prop, value.is_some()), Support::from_option(value))
+    };
+
+    let ubwc_mode = kgsl::read_property::<u32>(fd, Prop::UbwcMode).ok();
+    let ubwc_driver = ubwc_mode.is_some();
+
+    let (secure_driver, secure) = queried(Prop::SecureCtxtSupport);
+    let (lpac_driver, lpac) = queried(Prop::IsLpacEnabled);
+    let (raytracing_driver, raytracing) = queried(Prop::IsRaytracingEnabled);
+    let (aqe_driver, aqe) = queried(Prop::IsAqeEnabled);
+    let preemption = sysfs_flag("preemption");
+    let ifpc = sysfs_flag("ifpc");
+    let gmu = devicetree::find_compatible(&sysroot::resolve(devicetree::DT_ROOT), &GMU_COMPATIBLE).is_some();
+    let sparse = sparse_supported(fd);
+    let writable = kgsl::probe_access(&sysroot::resolve(device_path)).map(AccessMode::allows_write);
+    let opened_rw = AccessMode::of(fd).ok().map(AccessMode::allows_write);
+
+    vec![
+        Capability {
+            name: "UBWC",
+            hardware: spec.map_or(Support::Unknown, |s| match s.ubwc_version {
+                0 => Support::No,
+                v => Support::Version(v),
+            }),
+            driver: driver(Prop::UbwcMode, ubwc_driver),
+            enabled: match ubwc_mode {
+                None => Support::Unknown,
+                Some(0) => Support::No,
+                Some(v) => Support::Version(v as u8),
+            },
+        },
+        Capability {
+            name: "Preemption",
+            hardware: hw(|s| s.preemption),
+            driver: Support::from_bool(preemption.is_some()),
+            enabled: Support::from_option(preemption),
+        },
+        Capability {
+            name: "Secure contexts",
+            hardware: hw(|s| s.secure_contexts),
+            driver: secure_driver,
+            enabled: secure,
+        },
+        Capability {
+            name: "LPAC",
+            hardware: hw(|s| s.lpac),
+            driver: lpac_driver,
+            enabled: lpac,
+        },
+        // Nicht in der Chip-Datenbank, nur der Treiber weiß es
+        Capability {
+            name: "Ray tracing",
+            hardware: Support::Unknown,
+            driver: raytracing_driver,
+            enabled: raytracing,
+        },
+        Capability {
+            name: "AQE",
+            hardware: Support::Unknown,
+            driver: aqe_driver,
+            enabled: aqe,
+        },
+        Capability {
+            name: "IFPC",
+            hardware: hw(|s| s.ifpc),
+            driver: Support::from_bool(ifpc.is_some()),
+            enabled: Support::from_option(ifpc),
+        },
+        Capability {
+            name: "GMU",
+            hardware: hw(|s| s.gmu),
+            driver: Support::from_bool(gmu),
+            enabled: Support::from_bool(gmu),
+        },
+        Capability {
+            name: "Sparse memory",
+            hardware: hw(|s| s.sparse_memory),
+            driver: Support::from_bool(sparse),
+            enabled: Support::from_bool(sparse),
+        },
+        // Treiber: Dateirechte/SELinux erlauben O_RDWR; aktiv: dieser Deskriptor
+        Capability {
+            name: "Write access",
+            hardware: Support::Unknown,
+            driver: Support::from_option(writable),
+            enabled: Support::from_option(opened_rw),
+        },
+    ]
+}
@@ -0,0 +1,359 @@
+//! Chip ID Dekodierung und Chip-Datenbank
+
+// ============================================================================
+// Chip ID Decoding
+// ============================================================================
+
+#[derive(Debug, Clone)]
+pub struct ChipInfo {
+    pub raw_id: u32,
+    pub major: u8,
+    pub minor: u8,
+    pub patch: u8,
+    pub revision: u8,
+    pub model_name: String,
+    pub adreno_generation: String,
+    pub snapdragon_model: Option<String>,
+}
+
+pub fn decode_chip_id(chip_id: u32) -> ChipInfo {
+    let major = ((chip_id >> 24) & 0xFF) as u8;
+    let minor = ((chip_id >> 16) & 0xFF) as u8;
+    let patch = ((chip_id >> 8) & 0xFF) as u8;
+    let revision = (chip_id & 0xFF) as u8;
+
+    // Bestimme Adreno Generation
+    let adreno_gen = match major {
+        1 => "100",
+        2 => "200",
+        3 => "300",
+        4 => "400",
+        5 => "500",
+        6 => "600",
+        7 => "700",
+        8 => "800",
+        9 => "900",
+        _ => "Unknown",
+    };
+
+    // Spezifisches Modell
+    let model_name = match (major, minor) {
+        (6, 0) => "Adreno 600",
+        (6, 1) => "Adreno 610",
+        (6, 2) => "Adreno 620",
+        (6, 3) => "Adreno 630",
+        (6, 4) => "Adreno 640",
+        (6, 5) => "Adreno 650",
+        (6, 6) => "Adreno 660",
+        (6, 8) => "Adreno 680",
+        (6, 9) => "Adreno 690",
+        (7, 0) => "Adreno 700",
+        (7, 1) => "Adreno 710",
+        (7, 2) => "Adreno 720",
+        (7, 3) => "Adreno 730",
+        (7, 4) => "Adreno 740",
+        (7, 5) => "Adreno 750",
+        _ => "Adreno GPU",
+    };
+
+    // Typische Snapdragon Zuordnung
+    let snapdragon_model = match (major, minor) {
+        (6, 1) => Some("Snapdragon 665/680/685/690/6 Gen 1"),
+        (6, 2) => Some("Snapdragon 730/732G"),
+        (6, 3) => Some("Snapdragon 835/845"),
+        (6, 4) => Some("Snapdragon 855"),
+        (6, 5) => Some("Snapdragon 865/870"),
+        (6, 6) => Some("Snapdragon 888"),
+        (6, 8) => Some("Snapdragon 8 Gen 1"),
+        (6, 9) => Some("Snapdragon 7+ Gen 2"),
+        (7, 2) => Some("Snapdragon 7 Gen 1"),
+        (7, 3) => Some("Snapdragon 8+ Gen 1"),
+        (7, 5) => Some("Snapdragon 8 Gen 2"),
+        _ => None,
+    };
+
+    ChipInfo {
+        raw_id: chip_id,
+        major,
+        minor,
+        patch,
+        revision,
+        model_name: model_name.to_string(),
+        adreno_generation: adreno_gen.to_string(),
+        snapdragon_model: snapdragon_model.map(|s| s.to_string()),
+    }
+}
+
+// ============================================================================
+// Chip-Datenbank
+// ============================================================================
+
+const KB: u32 = 1024;
+
+/// Bekannte Hardware-Eigenschaften eines Chips (Werte aus adreno-gpulist.h,
+/// soweit bekannt - "typisch", nicht garantiert)
+#[derive(Debug, Clone, Copy)]
+pub struct ChipSpec {
+    pub major: u8,
+    pub minor: u8,
+    /// None = gilt für alle Patch-Stände
+    pub patch: Option<u8>,
+    pub name: &'static str,
+    pub gmem_bytes: u32,
+    /// Shader Processor Kerne (je zwei uSPTP ab A6xx)
+    pub num_sp: u8,
+    /// FP32 ALU-Lanes pro SP
+    pub alus_per_sp: u16,
+    /// Geschätzte Leckleistung in mW (GPU aktiv, aber ohne Last)
+    pub static_mw: u16,
+    /// Geschätzte dynamische Leistung in mW bei Maximaltakt und 100% Last
+    pub dynamic_mw: u16,
+    /// Anzahl CCUs (Color Cache Units), auf die GMEM aufgeteilt ist
+    pub num_ccu: u8,
+    /// UBWC Version, 0 = kein UBWC
+    pub ubwc_version: u8,
+    pub preemption: bool,
+    pub secure_contexts: bool,
+    pub lpac: bool,
+    pub ifpc: bool,
+    pub gmu: bool,
+    pub sparse_memory: bool,
+    /// UCHE (GPU-L2) in KB, Schätzwert
+    pub uche_kb: u16,
+    /// Breite des DDR-Busses der SoC in Bit
+    pub bus_bits: u16,
+}
+
+impl ChipSpec {
+    /// Standardwerte je Generation
+    const fn new(major: u8, minor: u8, name: &'static str, gmem_bytes: u32) -> Self {
+        ChipSpec {
+            major,
+            minor,
+            patch: None,
+            name,
+            gmem_bytes,
+            num_ccu: 1,
+            static_mw: match major {
+                0..=4 => 30,
+                5 => 50,
+                6 => 70,
+                _ => 150,
+            },
+            dynamic_mw: match major {
+                0..=4 => 800,
+                5 => 1800,
+                6 => 2500,
+                _ => 5500,
+            },
+            num_sp: 1,
+            alus_per_sp: match major {
+                0..=4 => 64,
+                5 => 128,
+                _ => 256,
+            },
+            ubwc_version: 0,
+            preemption: major >= 5,
+            secure_contexts: major >= 5,
+            lpac: false,
+            ifpc: false,
+            gmu: false,
+            sparse_memory: major >= 6,
+            uche_kb: match major {
+                0..=4 => 64,
+                5 => 128,
+                6 => 256,
+                _ => 512,
+            },
+            bus_bits: 64,
+        }
+    }
+
+    const fn patch(mut self, patch: u8) -> Self {
+        self.patch = Some(patch);
+        self
+    }
+
+    const fn sp(mut self, count: u8, alus_per_sp: u16) -> Self {
+        self.num_sp = count;
+        self.alus_per_sp = alus_per_sp;
+        self
+    }
+
+    const fn power(mut self, static_mw: u16, dynamic_mw: u16) -> Self {
+        self.static_mw = static_mw;
+        self.dynamic_mw = dynamic_mw;
+        self
+    }
+
+    const fn ccu(mut self, count: u8) -> Self {
+        self.num_ccu = count;
+        self
+    }
+
+    const fn ubwc(mut self, version: u8) -> Self {
+        self.ubwc_version = version;
+        self
+    }
+
+    /// GMU vorhanden, damit auch IFPC
+    const fn gmu(mut self) -> Self {
+        self.gmu = true;
+        self.ifpc = true;
+        self
+    }
+
+    const fn lpac(mut self) -> Self {
+        self.lpac = true;
+        self
+    }
+
+    const fn uche(mut self, kb: u16) -> Self {
+        self.uche_kb = kb;
+        self
+    }
+
+    /// Compute-Plattformen (8cx) mit 128-Bit-Bus
+    const fn wide_bus(mut self) -> Self {
+        self.bus_bits = 128;
+        self
+    }
+}
+
+pub const CHIP_DB: &[ChipSpec] = &[
+    ChipSpec::new(3, 0, "Adreno 305", 256 * KB),
+    ChipSpec::new(3, 2, "Adreno 320", 512 * KB),
+    ChipSpec::new(3, 3, "Adreno 330", 1024 * KB),
+    ChipSpec::new(4, 0, "Adreno 405", 256 * KB),
+    ChipSpec::new(4, 2, "Adreno 420", 1536 * KB),
+    ChipSpec::new(4, 3, "Adreno 430", 1536 * KB),
+    ChipSpec::new(5, 0, "Adreno 50x", 136 * KB),
+    ChipSpec::new(5, 1, "Adreno 51x", 272 * KB),
+    ChipSpec::new(5, 3, "Adreno 530", 1024 * KB).sp(2, 128).ubwc(1),
+    ChipSpec::new(5, 4, "Adreno 540", 1024 * KB).sp(3, 128).ubwc(1),
+    ChipSpec::new(6, 1, "Adreno 610", 132 * KB).sp(1, 128).patch(0).ubwc(1).uche(128).power(40, 900),
+    ChipSpec::new(6, 1, "Adreno 612", 272 * KB).sp(1, 128).patch(2).ubwc(2).uche(128).gmu().power(45, 1100),
+    ChipSpec::new(6, 1, "Adreno 615", 512 * KB).patch(5).ubwc(2).gmu().power(55, 1400),
+    ChipSpec::new(6, 1, "Adreno 618", 512 * KB).patch(8).ubwc(2).gmu().power(60, 1500),
+    ChipSpec::new(6, 1, "Adreno 619", 512 * KB).patch(9).ubwc(2).gmu().power(60, 1500),
+    ChipSpec::new(6, 2, "Adreno 620", 512 * KB).sp(1, 384).ubwc(3).gmu().power(65, 1900),
+    ChipSpec::new(6, 3, "Adreno 630", 1024 * KB).sp(2, 256).ccu(2).ubwc(2).gmu().power(80, 2500),
+    ChipSpec::new(6, 4, "Adreno 640", 1024 * KB).sp(2, 384).ccu(2).ubwc(3).gmu().power(90, 3000),
+    ChipSpec::new(6, 5, "Adreno 650", 1152 * KB).sp(3, 384).ccu(3).ubwc(3).gmu().power(120, 4000),
+    ChipSpec::new(6, 6, "Adreno 660", 1536 * KB).sp(3, 384).ccu(3).ubwc(4).gmu().power(150, 5000),
+    ChipSpec::new(6, 8, "Adreno 680", 2048 * KB).sp(4, 384).ccu(4).ubwc(3).gmu().uche(512).wide_bus().power(150, 5000),
+    ChipSpec::new(6, 9, "Adreno 690", 2048 * KB).sp(8, 192).ccu(8).ubwc(4).gmu().uche(512).wide_bus().power(150, 5000),
+    ChipSpec::new(7, 3, "Adreno 730", 2048 * KB).sp(4, 384).ccu(4).ubwc(4).gmu().lpac().power(150, 5500),
+    ChipSpec::new(7, 4, "Adreno 740", 3072 * KB).sp(6, 384).ccu(6).ubwc(4).gmu().lpac().power(180, 6500),
+    ChipSpec::new(7, 5, "Adreno 750", 3072 * KB).sp(6, 512).ccu(6).ubwc(4).gmu().lpac().uche(1024).power(200, 7000),
+];
+
+/// Speicherseite eines Chips, für Zugriffsmuster in Compute-Shadern
+#[derive(Debug, Clone, Copy)]
+pub struct MemoryLayout {
+    pub bus_bits: u32,
+    pub uche_bytes: u32,
+    /// Cache-Zeile von UCHE
+    pub cache_line_bytes: u32,
+    /// Kleinster sinnvoller Zugriff (`KGSL_PROP_MIN_ACCESS_LENGTH` auf A6xx+)
+    pub min_access_bytes: u32,
+}
+
+impl ChipSpec {
+    /// Speicherseite laut Datenbank und Generation
+    pub fn memory_layout(&self) -> MemoryLayout {
+        MemoryLayout {
+            bus_bits: self.bus_bits as u32,
+            uche_bytes: self.uche_kb as u32 * KB,
+            cache_line_bytes: if self.major >= 7 { 128 } else { 64 },
+            min_access_bytes: if self.major >= 6 { 32 } else { 64 },
+        }
+    }
+}
+
+/// Shader-Kern Aufbau eines Chips
+#[derive(Debug, Clone, Copy)]
+pub struct ShaderCores {
+    pub sp: u32,
+    /// Micro-SP/TP Einheiten (zwei pro SP ab A6xx)
+    pub micro_tp: u32,
+    pub alus: u32,
+    pub wave_size: u32,
+    /// Gleichzeitig residente Waves pro SP
+    pub max_waves_per_sp: u32,
+}
+
+impl ShaderCores {
+    pub fn max_waves(&self) -> u32 {
+        self.sp * self.max_waves_per_sp
+    }
+
+    /// FMA zählt als zwei Operationen
+    pub fn theoretical_gflops(&self, freq_hz: u64) -> f64 {
+        self.alus as f64 * 2.0 * freq_hz as f64 / 1e9
+    }
+}
+
+impl ChipSpec {
+    /// Shader-Kerne laut Datenbank und Generation
+    pub fn shader_cores(&self) -> ShaderCores {
+        let (micro_per_sp, wave_size, max_waves_per_sp) = match self.major {
+            0..=4 => (1, 32, 8),
+            5 => (1, 64, 16),
+            6 => (2, 64, 32),
+            _ => (2, 64, 48),
+        };
+        ShaderCores {
+            sp: self.num_sp as u32,
+            micro_tp: self.num_sp as u32 * micro_per_sp,
+            alus: self.num_sp as u32 * self.alus_per_sp as u32,
+            wave_size,
+            max_waves_per_sp,
+        }
+    }
+}
+
+/// Tile-Grenzen für Binning (wie `fd_dev_info` in freedreno)
+#[derive(Debug, Clone, Copy)]
+pub struct TileLimits {
+    pub align_w: u32,
+    pub align_h: u32,
+    pub max_w: u32,
+    pub max_h: u32,
+    /// Anzahl VSC Pipes (maximale Bin-Gruppen)
+    pub vsc_pipes: u32,
+}
+
+/// Tile-Grenzen je Generation
+pub const fn tile_limits(generation: u8) -> TileLimits {
+    match generation {
+        0..=4 => TileLimits { align_w: 32, align_h: 32, max_w: 1024, max_h: 1024, vsc_pipes: 8 },
+        5 => TileLimits { align_w: 64, align_h: 32, max_w: 1024, max_h: 1024, vsc_pipes: 16 },
+        _ => TileLimits { align_w: 32, align_h: 16, max_w: 1024, max_h: 1008, vsc_pipes: 32 },
+    }
+}
+
+/// Sucht den passendsten Eintrag (exakter Patch vor generischem)
+pub fn lookup(major: u8, minor: u8, patch: u8) -> Option<&'static ChipSpec> {
+    let candidates = CHIP_DB.iter().filter(|s| s.major == major && s.minor == minor);
+    candidates.clone().find(|s| s.patch == Some(patch))
+        .or_else(|| candidates.clone().find(|s| s.patch.is_none()))
+        .or_else(|| candidates.clone().next())
+}
+
+/// Sucht über die Modellnummer, z.B. 740 aus "Adreno740v2"
+pub fn lookup_model(model: &str) -> Option<&'static ChipSpec> {
+    let digits: String = model.chars().skip_while(|c| !c.is_ascii_digit()).take_while(char::is_ascii_digit).collect();
+    if digits.is_empty() {
+        return None;
+    }
+    let name = format!("Adreno {}", digits);
+    CHIP_DB.iter().find(|s| s.name == name)
+}
+
+impl ChipInfo {
+    /// Datenbank-Eintrag zum Chip, falls bekannt
+    pub fn spec(&self) -> Option<&'static ChipSpec> {
+        lookup(self.major, self.minor, self.patch)
+    }
+}
@@ -0,0 +1,120 @@
+//! `allocflags` - Allokations-Matrix über alle relevanten Flag-Kombinationen
+
+use adreno_ioctl::memory::{
+    describe_flags, GpuBuffer, KGSL_CACHEMODE_SHIFT, KGSL_CACHEMODE_WRITEBACK, KGSL_MEMFLAGS_FORCE_32BIT,
+    KGSL_MEMFLAGS_GPUREADONLY, KGSL_MEMFLAGS_SECURE,
+};
+use adreno_ioctl::messages::Msg;
+
+use super::{open_device_rw, parse_size, Args};
+
+/// Unabhängig kombinierte Flags (Cache-Modus WC ist 0)
+const AXES: [(&str, u64); 4] = [
+    ("cached", KGSL_CACHEMODE_WRITEBACK << KGSL_CACHEMODE_SHIFT),
+    ("gpu-ro", KGSL_MEMFLAGS_GPUREADONLY),
+    ("secure", KGSL_MEMFLAGS_SECURE),
+    ("32bit", KGSL_MEMFLAGS_FORCE_32BIT),
+];
+
+/// Ergebnis einer Kombination
+struct AllocResult {
+    flags: u64,
+    outcome: Result<Allocated, String>,
+}
+
+struct Allocated {
+    gpuaddr: u64,
+    /// Vom Treiber zurückgegebene Flags (können abweichen)
+    flags: u64,
+    mmap: Result<&'static str, String>,
+}
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let size = match args.value("--size")? {
+        Some(s) => parse_size(&s)?,
+        None => 64 * 1024,
+    };
+    let (path, file) = open_device_rw(&mut args)?;
+    args.finish()?;
+
+    println!("🧪 {}\n", Msg::AllocMatrix { path: &path, kb: &(size / 1024) });
+    let results: Vec<AllocResult> = (0..1u32 << AXES.len())
+        .map(|mask| {
+            let flags = AXES
+                .iter()
+                .enumerate()
+                .filter(|(bit, _)| mask & (1 << bit) != 0)
+                .fold(0, |acc, (_, (_, flag))| acc | flag);
+            AllocResult { flags, outcome: try_alloc(&file, size, flags) }
+        })
+        .collect();
+
+    println!("   {:<26} {:<6} {:<16} {:>6}  mmap", "Requested", "Alloc", "GPU VA", "Align");
+    for result in &results {
+        let requested = label(result.flags);
+        match &result.outcome {
+            Ok(a) => {
+                let mmap = match &a.mmap {
+                    Ok(s) => format!("✅ {}", s),
+                    Err(e) => format!("❌ {}", e),
+                };
+                println!(
+                    "   {:<26} ✅    0x{:<14x} {:>6}  {}",
+                    requested,
+                    a.gpuaddr,
+                    format_alignment(a.gpuaddr),
+                    mmap
+                );
+                if a.flags != result.flags {
+                    println!("   {:<26} ↳ {}", "", Msg::AllocDriverReturned { flags: &describe_flags(a.flags) });
+                }
+            }
+            Err(e) => println!("   {:<26} ❌    {}", requested, e),
+        }
+    }
+
+    let ok = results.iter().filter(|r| r.outcome.is_ok()).count();
+    println!("\n   {}", Msg::AllocSummary { ok: &ok, total: &results.len() });
+    println!("   ℹ️  {}", Msg::AllocUbwcNote);
+    Ok(())
+}
+
+fn try_alloc(file: &std::fs::File, size: u64, flags: u64) -> Result<Allocated, String> {
+    let buffer = GpuBuffer::alloc(file, size, flags).map_err(|e| e.to_string())?;
+    let mmap = buffer.map().map_err(|e| e.to_string()).and_then(|mapping| {
+        // Schreiben und zurücklesen, um kaputte Mappings zu erkennen
+        let last = mapping.len() - 1;
+        mapping.write::<u8>(0, 0xA5);
+        mapping.write::<u8>(last, 0x5A);
+        if mapping.read::<u8>(0) == Some(0xA5) && mapping.read::<u8>(last) == Some(0x5A) {
+            Ok("read/write")
+        } else {
+            Err(Msg::AllocReadbackMismatch.to_string())
+        }
+    });
+    Ok(Allocated { gpuaddr: buffer.gpuaddr, flags: buffer.flags, mmap })
+}
+
+fn label(flags: u64) -> String {
+    let names: Vec<&str> = AXES.iter().filter(|(_, f)| flags & f != 0).map(|(n, _)| *n).collect();
+    if flags & AXES[0].1 == 0 {
+        std::iter::once("wc").chain(names).collect::<Vec<_>>().join(" ")
+    } else {
+        names.join(" ")
+    }
+}
+
+/// Größte Zweierpotenz, die die Adresse teilt
+fn format_alignment(gpuaddr: u64) -> String {
+    match gpuaddr {
+        0 => "-".to_string(),
+        addr => {
+            let align = 1u64 << addr.trailing_zeros();
+            match align {
+                a if a >= 1 << 20 => format!("{}M", a >> 20),
+                a if a >= 1 << 10 => format!("{}K", a >> 10),
+                a => a.to_string(),
+            }
+        }
+    }
+}
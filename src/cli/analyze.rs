@@ -0,0 +1,101 @@
+//! `analyze` - Aufzeichnungen von `monitor --sink` in Zeitfenstern auswerten
+
+use std::fs;
+use std::time::Duration;
+
+use adreno_ioctl::analyze::{aggregate, read_samples, Window};
+use adreno_ioctl::json::Json;
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::schema::versioned;
+
+use super::{parse_duration, Args};
+
+/// So viele Frequenzen zeigt die Residency-Spalte
+const SHOWN_LEVELS: usize = 3;
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let window = match args.value("--window")? {
+        Some(w) => parse_duration(&w)?,
+        None => Duration::from_secs(60),
+    };
+    let json = args.flag("--json");
+    let file = args.positional().ok_or("analyze needs a recording: analyze FILE [--window 1m]")?;
+    args.finish()?;
+    if window < Duration::from_secs(1) {
+        return Err(Msg::AnalyzeWindowTooShort.to_string());
+    }
+
+    let text = fs::read_to_string(&file).map_err(|e| Msg::CannotRead { path: &file, error: &e }.to_string())?;
+    let rows = read_samples(&text).map_err(|e| format!("{}: {}", file, e))?;
+    if rows.is_empty() {
+        return Err(Msg::AnalyzeNoSamples { file: &file }.to_string());
+    }
+    let windows = aggregate(&rows, window);
+
+    if json {
+        let report = Json::object()
+            .field("file", file.as_str())
+            .field("window_s", window.as_secs_f64())
+            .field("windows", windows.iter().map(Window::to_json).collect::<Vec<_>>());
+        println!("{}", versioned(report).to_pretty());
+        return Ok(());
+    }
+
+    println!(
+        "📊 {}",
+        Msg::AnalyzeSummary { file: &file, samples: &rows.len(), windows: &windows.len(), secs: &window.as_secs_f64() }
+    );
+    // Gerätespalte nur bei Aufzeichnungen mehrerer GPUs
+    let devices = windows.iter().any(|w| w.device != windows[0].device);
+    let width = windows.iter().map(|w| w.device.len()).max().unwrap_or(0).max("device".len());
+    let tag = |device: &str| if devices { format!("{:<width$}  ", device, width = width) } else { String::new() };
+    println!(
+        "   {}{:<15} {:>8} {:>8} {:>9} {:>9}  residency (MHz)",
+        tag("device"),
+        "window",
+        "busy avg",
+        "busy max",
+        "freq avg",
+        "throttled"
+    );
+    for w in &windows {
+        let span = format!("{}-{}", format_clock(w.start), format_clock(w.start + w.length));
+        let busy_avg = w.busy_percent.avg().map_or("-".to_string(), |b| format!("{:.1}%", b));
+        let busy_max = w.busy_percent.avg().map_or("-".to_string(), |_| format!("{:.1}%", w.busy_percent.max));
+        let freq = w.freq_mhz.avg().map_or("-".to_string(), |f| format!("{:.0} MHz", f));
+        let throttled = w.throttled.map_or("-".to_string(), |t| format!("{:.1}s", t.as_secs_f64()));
+        let residency: Vec<String> = w
+            .residency_percent()
+            .into_iter()
+            .filter(|(_, percent)| *percent >= 0.5)
+            .take(SHOWN_LEVELS)
+            .map(|(hz, percent)| format!("{} {:.0}%", hz / 1_000_000, percent))
+            .collect();
+        let suspended = match w.suspended.is_zero() {
+            true => String::new(),
+            false => format!("  💤 {:.0}s", w.suspended.as_secs_f64()),
+        };
+        let line = format!(
+            "   {}{:<15} {:>8} {:>8} {:>9} {:>9}  {}{}",
+            tag(&w.device),
+            span,
+            busy_avg,
+            busy_max,
+            freq,
+            throttled,
+            residency.join(" · "),
+            suspended
+        );
+        println!("{}", line.trim_end());
+    }
+    Ok(())
+}
+
+/// `m:ss` bzw. `h:mm:ss`
+fn format_clock(time: Duration) -> String {
+    let secs = time.as_secs();
+    match secs {
+        s if s >= 3600 => format!("{}:{:02}:{:02}", s / 3600, s / 60 % 60, s % 60),
+        s => format!("{}:{:02}", s / 60, s % 60),
+    }
+}
@@ -0,0 +1,130 @@
+//! `bench` - ioctl- und sysfs-Zugriffe im Vergleich
+
+use std::os::fd::AsRawFd;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use adreno_ioctl::collector::{Collector, IoctlCollector, IOCTL_SAMPLE_BUDGET};
+use adreno_ioctl::kgsl::{
+    read_gpu_info, read_interrupt_waits, read_timestamp, KGSL_MEMSTORE_GLOBAL, KGSL_TIMESTAMP_QUEUED,
+    KGSL_TIMESTAMP_RETIRED,
+};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::sysfs;
+
+use super::{device_path, open_device, parse_duration, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    match args.positional().as_deref() {
+        Some("ioctl") => run_ioctl(args),
+        Some("sysfs") => run_sysfs(args),
+        Some(other) => Err(Msg::BenchUnknown { name: &other }.to_string()),
+        None => Err(Msg::BenchUsage.to_string()),
+    }
+}
+
+/// Dateien eines typischen Exporter-Scrapes
+const SCRAPE_FILES: [&str; 8] = [
+    "gpuclk",
+    "gpu_busy_percentage",
+    "gpubusy",
+    "gpu_clock_stats",
+    "gpu_available_frequencies",
+    "num_pwrlevels",
+    "min_pwrlevel",
+    "max_pwrlevel",
+];
+
+/// Vergleicht direkte sysfs-Lesezugriffe mit [`sysfs::CachedSysfs`]
+fn run_sysfs(mut args: Args) -> Result<(), String> {
+    let iterations: u32 = args.parsed("--iterations")?.unwrap_or(1000);
+    let ttl = match args.value("--ttl")? {
+        Some(t) => parse_duration(&t)?,
+        None => Duration::from_millis(100),
+    };
+    let path = device_path(&mut args)?;
+    args.finish()?;
+
+    let dir = sysfs::device_dir(&path);
+    let files: Vec<_> = SCRAPE_FILES.iter().map(|name| dir.join(name)).filter(|p| p.exists()).collect();
+    if files.is_empty() {
+        return Err(Msg::BenchNoSysfsFiles { dir: &dir.display() }.to_string());
+    }
+    let paths: Vec<&Path> = files.iter().map(|p| p.as_path()).collect();
+    println!(
+        "🗂️  {}",
+        Msg::BenchSysfsHeader { files: &paths.len(), scrapes: &iterations, dir: &dir.display() }
+    );
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        for path in &paths {
+            let _ = sysfs::read_string(path);
+        }
+    }
+    let direct = start.elapsed();
+    let direct_calls = iterations as u64 * paths.len() as u64;
+
+    let mut rows = vec![("direct (open/read/close)", direct, direct_calls, direct_calls)];
+    for (label, ttl) in [("cached, TTL 0 (fd reuse)", Duration::ZERO), ("cached, TTL --ttl", ttl)] {
+        let mut cache = sysfs::CachedSysfs::new(ttl);
+        let start = Instant::now();
+        for _ in 0..iterations {
+            cache.read_many(&paths);
+        }
+        let stats = cache.stats();
+        rows.push((label, start.elapsed(), stats.opens, stats.reads));
+    }
+
+    println!("\n   {:<26} {:>12} {:>10} {:>10}", "Mode", "per scrape", "opens", "reads");
+    println!("   {}", "─".repeat(61));
+    for (label, elapsed, opens, reads) in &rows {
+        let per_scrape = elapsed.as_secs_f64() * 1e6 / iterations as f64;
+        println!("   {:<26} {:>9.1} µs {:>10} {:>10}", label, per_scrape, opens, reads);
+    }
+    let best = rows.iter().map(|r| r.1).min().unwrap_or(direct);
+    let factor = direct.as_secs_f64() / best.as_secs_f64().max(1e-9);
+    println!("\n   {}", Msg::BenchSysfsSpeedup { factor: &factor, ttl: &ttl.as_millis() });
+    Ok(())
+}
+
+/// Vergleicht Einzelabfragen je Property mit [`IoctlCollector`]
+fn run_ioctl(mut args: Args) -> Result<(), String> {
+    let iterations: u32 = args.parsed("--iterations")?.unwrap_or(1000);
+    let (path, file) = open_device(&mut args)?;
+    args.finish()?;
+    let fd = file.as_raw_fd();
+    read_gpu_info(fd)?;
+    println!("🔁 {}", Msg::BenchIoctlHeader { samples: &iterations, path: &path });
+
+    // Wie früher: jede Property bei jedem Sample neu
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let _ = read_gpu_info(fd);
+        let _ = read_interrupt_waits(fd);
+        let _ = read_timestamp(fd, KGSL_MEMSTORE_GLOBAL, KGSL_TIMESTAMP_QUEUED);
+        let _ = read_timestamp(fd, KGSL_MEMSTORE_GLOBAL, KGSL_TIMESTAMP_RETIRED);
+    }
+    let naive = start.elapsed();
+
+    let mut collector = IoctlCollector::new(&path);
+    let start = Instant::now();
+    for _ in 0..iterations {
+        collector.collect().map_err(|e| Msg::BenchCollectorFailed { error: &e }.to_string())?;
+    }
+    let batched = start.elapsed();
+    let stats = collector.stats();
+
+    let us = |d: Duration| d.as_secs_f64() * 1e6 / iterations as f64;
+    println!("\n   {:<26} {:>12} {:>14}", "Mode", "per sample", "syscalls/sample");
+    println!("   {}", "─".repeat(55));
+    println!("   {:<26} {:>9.1} µs {:>14}", "per-property calls", us(naive), 4);
+    println!("   {:<26} {:>9.1} µs {:>14.1}", "collector", us(batched), stats.per_sample());
+    println!("   {}", Msg::BenchSetupSyscalls { count: &stats.setup_syscalls });
+
+    if stats.per_sample() > IOCTL_SAMPLE_BUDGET as f64 {
+        return Err(Msg::BenchOverBudget { per_sample: &stats.per_sample(), budget: &IOCTL_SAMPLE_BUDGET }.to_string());
+    }
+    println!("\n   ✅ {}", Msg::BenchWithinBudget { budget: &IOCTL_SAMPLE_BUDGET });
+    Ok(())
+}
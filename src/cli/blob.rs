@@ -0,0 +1,59 @@
+//! `blob` - Release der Adreno-Userspace-Treiber aus den Vendor-Bibliotheken
+
+use std::path::Path;
+
+use adreno_ioctl::blob::{read_blob, read_updatable_driver, read_vendor_blobs, BlobInfo, BLOB_PATHS};
+use adreno_ioctl::messages::Msg;
+
+use super::Args;
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let files = args.values("--file")?;
+    args.finish()?;
+
+    // Gezogene Bibliotheken eines anderen Geräts
+    if !files.is_empty() {
+        for file in &files {
+            let blob = read_blob(Path::new(file)).map_err(|e| Msg::CannotRead { path: &file, error: &e }.to_string())?;
+            print_blob(&blob);
+        }
+        return Ok(());
+    }
+
+    println!("📚 {}", Msg::BlobHeader);
+    let blobs = read_vendor_blobs();
+    if blobs.is_empty() {
+        println!("   • {}", Msg::BlobNoneFound { path: &BLOB_PATHS[0] });
+    }
+    for blob in &blobs {
+        print_blob(blob);
+    }
+
+    match read_updatable_driver() {
+        Some(driver) => {
+            println!("\n🔄 {}", Msg::BlobUpdatable { package: &driver.package });
+            if driver.files.is_empty() {
+                println!("   • {}", Msg::BlobNotInstalled);
+            }
+            for blob in &driver.files {
+                print_blob(blob);
+            }
+            println!("   💡 {}", Msg::BlobOptInHint);
+        }
+        None => println!("\n   • {}", Msg::BlobNoUpdatable),
+    }
+    Ok(())
+}
+
+fn print_blob(blob: &BlobInfo) {
+    match blob.versions.first() {
+        Some(v) => {
+            println!("   • {}: {}", blob.path.display(), v.release().unwrap_or_else(|| format!("V@{}", v.version)));
+            println!("     \"{}\"", v.text);
+            for other in &blob.versions[1..] {
+                println!("     {}", Msg::BlobAlsoContains { version: &other.version });
+            }
+        }
+        None => println!("   • {}", Msg::BlobNoVersion { path: &blob.path.display() }),
+    }
+}
@@ -0,0 +1,118 @@
+//! `boost` - Hebt das minimale Power Level temporär an
+//!
+//! Primär über `min_pwrlevel` in sysfs (Root), sonst über einen
+//! Power-Constraint-Vote auf einem eigenen Context, den NOP-Submissions
+//! beschäftigt halten: der Treiber wendet den Vote nur bei Arbeit an. Der alte Zustand wird immer wiederhergestellt:
+//! per [`SettingsGuard`], auch bei Ctrl+C, SIGTERM oder Panic, nach einem
+//! Absturz über `adreno_ioctl restore`.
+
+use std::io;
+use std::os::fd::AsRawFd;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use adreno_ioctl::features::generation;
+use adreno_ioctl::kgsl::{read_gpu_info, ConstraintLevel, ConstraintTarget, PowerVote, KGSL_CONTEXT_PWR_CONSTRAINT};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::settings::{Setting, SettingsGuard};
+use adreno_ioctl::submit::Submitter;
+use adreno_ioctl::sysfs;
+
+use super::{install_interrupt_handler, open_device_rw, parse_duration, sleep_interruptible, Args};
+
+/// Abstand der NOP-Submissions, solange der Vote gehalten wird
+const BUSY_INTERVAL: Duration = Duration::from_millis(50);
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let duration = match args.value("--duration")? {
+        Some(d) => parse_duration(&d)?,
+        None => Duration::from_secs(5),
+    };
+    let level = args.parsed::<u32>("--level")?;
+    // Der Fallback legt einen Context an und reicht ein
+    let (path, file) = open_device_rw(&mut args)?;
+    args.finish()?;
+
+    let dir = sysfs::device_dir(&path);
+    let mut settings = SettingsGuard::new("boost")?;
+    install_interrupt_handler();
+
+    // Ziel: angegebenes Level oder das höchste erlaubte (kleinster Index)
+    let target = level
+        .or_else(|| sysfs::max_pwrlevel(&dir).ok())
+        .unwrap_or(0);
+    if let Ok(count) = sysfs::num_pwrlevels(&dir)
+        && target >= count
+    {
+        return Err(Msg::BoostLevelOutOfRange { level: &target, count: &count }.to_string());
+    }
+    if let Some(freq) = sysfs::available_frequencies(&dir).ok().and_then(|f| f.get(target as usize).copied()) {
+        println!("🎯 {}", Msg::BoostTarget { level: &target, mhz: &(freq / 1_000_000) });
+    }
+
+    match settings.set_setting(&dir, Setting::MinPwrlevel, target) {
+        Ok(()) => {
+            let original = settings.original(&Setting::MinPwrlevel.path(&dir)).unwrap_or("?").to_string();
+            println!("⚡ {}", Msg::BoostSysfs { from: &original, to: &target, secs: &duration.as_secs_f64() });
+            hold(duration, &dir, Duration::from_secs(1), || Ok(()))?;
+            for (change, e) in settings.restore() {
+                eprintln!("⚠️  {}", Msg::CannotRestore { path: &change.path.display(), error: &e });
+            }
+
+            match sysfs::min_pwrlevel(&dir) {
+                Ok(now) if now.to_string() == original => println!("✅ {}", Msg::BoostRestored { level: &now }),
+                Ok(now) => eprintln!("⚠️  {}", Msg::BoostRestoreMismatch { now: &now, expected: &original }),
+                Err(e) => eprintln!("⚠️  {}", Msg::BoostCannotVerify { error: &e }),
+            }
+        }
+        Err(e) => {
+            println!("⚠️  {}", Msg::BoostFallback { error: &e });
+            let info = read_gpu_info(file.as_raw_fd())?;
+            // Reihenfolge: der Vote wird vor dem Context freigegeben
+            let submitter = Submitter::with_context_flags(&file, generation(info.chip_id), KGSL_CONTEXT_PWR_CONSTRAINT)
+                .map_err(|e| Msg::CannotCreateContext { error: &e }.to_string())?;
+            let vote = PowerVote::on_context(&file, submitter.context_id(), ConstraintTarget::GpuPwrLevel, ConstraintLevel::Max)
+                .map_err(|e| Msg::BoostConstraintFailed { error: &e }.to_string())?;
+            // Ohne Arbeit auf dem Context hat der Vote keine Wirkung
+            keep_busy(&submitter).map_err(|e| Msg::BoostSubmitFailed { error: &e }.to_string())?;
+            let interval = BUSY_INTERVAL.as_millis();
+            println!(
+                "⚡ {}",
+                Msg::BoostVote { context: &vote.context_id(), secs: &duration.as_secs_f64(), interval: &interval }
+            );
+            hold(duration, &dir, BUSY_INTERVAL, || keep_busy(&submitter))?;
+            vote.release().map_err(|e| Msg::BoostReleaseFailed { error: &e }.to_string())?;
+            println!("✅ {}", Msg::BoostReleased);
+        }
+    }
+
+    Ok(())
+}
+
+/// Reicht einen NOP ein und wartet, bis er retired ist
+fn keep_busy(submitter: &Submitter) -> io::Result<()> {
+    let timestamp = submitter.submit_nop()?;
+    submitter.wait(timestamp, Duration::from_secs(1))
+}
+
+/// Wartet die Boost-Dauer ab, ruft alle `interval` `tick` auf und zeigt
+/// jede Sekunde die aktuelle Frequenz
+fn hold(duration: Duration, dir: &Path, interval: Duration, mut tick: impl FnMut() -> io::Result<()>) -> Result<(), String> {
+    let start = Instant::now();
+    let mut next_report = Duration::from_secs(1);
+    while start.elapsed() < duration {
+        let step = duration.saturating_sub(start.elapsed()).min(interval);
+        if !sleep_interruptible(step) {
+            println!("\n🛑 {}", Msg::InterruptedRestoring);
+            return Ok(());
+        }
+        tick().map_err(|e| Msg::BoostBusyFailed { error: &e }.to_string())?;
+        if start.elapsed() >= next_report {
+            next_report += Duration::from_secs(1);
+            if let Ok(clk) = sysfs::gpuclk(dir) {
+                println!("   {:>5.1}s  {} MHz", start.elapsed().as_secs_f64(), clk / 1_000_000);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,129 @@
+//! `bugreport` - Alles für einen Bug-Report in einem Tar-Archiv
+//!
+//! Inhalt: JSON-Gerätebericht, GPU-Zeilen aus dem Kernel-Log, ein
+//! vorhandener GPU-Snapshot, sysfs-Dump, Firmware-Hashes und Versionen.
+//! KGSL erzeugt Snapshots nur bei Faults; ein ausstehender wird übernommen
+//! (und dabei vom Kernel freigegeben).
+
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::os::unix::io::AsRawFd;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use adreno_ioctl::bugreport::{dump_sysfs_dir, find_gpu_firmware, hash_files, TarWriter};
+use adreno_ioctl::dmesg::{grep, read_kernel_log};
+use adreno_ioctl::driver::read_driver_info;
+use adreno_ioctl::json::Json;
+use adreno_ioctl::kgsl::read_gpu_info;
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::report::gpu_info_json;
+use adreno_ioctl::schema::versioned;
+use adreno_ioctl::sysfs;
+use adreno_ioctl::zap;
+
+use super::{format_size, open_device, Args};
+
+/// Muster für GPU-relevante Kernel-Log-Zeilen
+const LOG_PATTERNS: [&str; 5] = ["kgsl", "adreno", "gpu", "zap", "gmu"];
+
+/// So viele Log-Zeilen werden höchstens übernommen (die neuesten)
+const MAX_LOG_LINES: usize = 2000;
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let output = args.value("--output")?.map(PathBuf::from);
+    let (path, file) = open_device(&mut args)?;
+    args.finish()?;
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let output = output.unwrap_or_else(|| PathBuf::from(format!("adreno_bugreport-{}.tar", now)));
+    let fd = file.as_raw_fd();
+    let dir = sysfs::device_dir(&path);
+    let driver = read_driver_info();
+
+    let mut entries: Vec<(&str, Vec<u8>)> = Vec::new();
+    let mut notes: Vec<String> = Vec::new();
+
+    // Gerätebericht wie `info --json`, plus Treiber und Zap
+    let gpu = match read_gpu_info(fd) {
+        Ok(info) => gpu_info_json(&info, &crate::read_extras(fd, Some(&path))),
+        Err(e) => {
+            notes.push(format!("device info: {}", e));
+            Json::Null
+        }
+    };
+    let zap_status = zap::detect(fd);
+    let report = Json::object()
+        .field("tool", concat!("adreno_ioctl ", env!("CARGO_PKG_VERSION")))
+        .field("created", now)
+        .field("device", path.as_str())
+        .field("gpu", gpu)
+        .field("kernel_release", driver.kernel_release.as_str())
+        .field("kgsl_module_version", driver.module_version.clone())
+        .field("zap_shader", zap_status.configured.clone())
+        .field("zap_diagnosis", zap_status.diagnosis());
+    entries.push(("device.json", (versioned(report).to_pretty() + "\n").into_bytes()));
+
+    match read_kernel_log() {
+        Ok(log) => {
+            let lines = grep(&log, &LOG_PATTERNS);
+            let start = lines.len().saturating_sub(MAX_LOG_LINES);
+            entries.push(("dmesg.txt", (lines[start..].join("\n") + "\n").into_bytes()));
+        }
+        Err(e) => notes.push(format!("kernel log: {} (root or dmesg_restrict=0 needed)", e)),
+    }
+
+    match fs::read(dir.join("snapshot/dump")) {
+        Ok(data) if !data.is_empty() => entries.push(("snapshot.bin", data)),
+        Ok(_) => notes.push("snapshot: none pending (KGSL only captures one on a GPU fault)".to_string()),
+        Err(e) => notes.push(format!("snapshot: {}", e)),
+    }
+
+    let mut sysfs_text = format!("# {}\n{}", dir.display(), dump_sysfs_dir(&dir));
+    for sub in ["devfreq", "snapshot"] {
+        let text = dump_sysfs_dir(&dir.join(sub));
+        if !text.is_empty() {
+            sysfs_text.push_str(&format!("\n# {}\n{}", dir.join(sub).display(), text));
+        }
+    }
+    entries.push(("sysfs.txt", sysfs_text.into_bytes()));
+
+    let firmware = find_gpu_firmware();
+    if firmware.is_empty() {
+        notes.push("firmware: no GPU firmware files found".to_string());
+    }
+    entries.push(("firmware.txt", hash_files(&firmware).into_bytes()));
+
+    let mut version = format!(
+        "adreno_ioctl {}\nkernel {} {} ({})\nkgsl module {} srcversion {}{}\n",
+        env!("CARGO_PKG_VERSION"),
+        driver.kernel_release,
+        driver.kernel_version,
+        driver.machine,
+        driver.module_version.as_deref().unwrap_or("-"),
+        driver.srcversion.as_deref().unwrap_or("-"),
+        if driver.builtin { " (built-in)" } else { "" }
+    );
+    for note in &notes {
+        version.push_str(&format!("note: {}\n", note));
+    }
+    entries.push(("version.txt", version.into_bytes()));
+
+    let out = File::create(&output).map_err(|e| Msg::CannotCreate { path: &output.display(), error: &e }.to_string())?;
+    let mut tar = TarWriter::new(BufWriter::new(out), now);
+    let prefix = format!("adreno_bugreport-{}", now);
+    for (name, data) in &entries {
+        tar.append(&format!("{}/{}", prefix, name), data)
+            .map_err(|e| Msg::WritingFailed { path: &output.display(), error: &e }.to_string())?;
+    }
+    tar.finish().map_err(|e| Msg::WritingFailed { path: &output.display(), error: &e }.to_string())?;
+
+    println!("📦 {}", Msg::BugreportWritten { path: &output.display() });
+    for (name, data) in &entries {
+        println!("   • {:<14} {:>10}", name, format_size(data.len() as u64));
+    }
+    for note in &notes {
+        println!("   ℹ️  {}", note);
+    }
+    Ok(())
+}
@@ -0,0 +1,110 @@
+//! `bus` - DDR/ICB-Votes der GPU neben der aktuellen GPU-Frequenz
+
+use std::time::Duration;
+
+use adreno_ioctl::bus::{
+    ddr_range_khz, find_gpubw_devfreq, read_ddr_table, read_ddr_type, read_icc_votes, DevfreqBus, IccVote, DEVFREQ_DIR, ICC_SUMMARY,
+};
+use adreno_ioctl::devicetree::{read_gpu_node, DtPwrLevel};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::sysfs;
+use adreno_ioctl::sysroot;
+
+use super::{device_path, install_interrupt_handler, parse_duration, sleep_interruptible, Args};
+
+/// Ab dieser Auslastung auf dem höchsten Level lohnt der Blick auf den Bus
+const STARVED_BUSY_PERCENT: f64 = 90.0;
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let interval = match args.value("--interval")? {
+        Some(i) => parse_duration(&i)?,
+        None => Duration::from_secs(1),
+    };
+    let count = args.parsed::<u64>("--count")?;
+    let device = device_path(&mut args)?;
+    args.finish()?;
+
+    let dir = sysfs::device_dir(&device);
+    let frequencies = sysfs::available_frequencies(&dir).unwrap_or_default();
+    let node = read_gpu_node();
+    let ddr_table = node.as_ref().map(|n| read_ddr_table(&n.path)).unwrap_or_default();
+    let gpubw = find_gpubw_devfreq(&sysroot::resolve(DEVFREQ_DIR));
+
+    println!("🚌 {}", Msg::BusHeader { device: &device });
+    match &gpubw {
+        Some(path) => println!("   • devfreq: {}", path.display()),
+        None => println!("   • {}", Msg::BusNoGpubw { dir: &DEVFREQ_DIR }),
+    }
+    if ddr_table.is_empty() {
+        println!("   • {}", Msg::BusNoDtTable);
+    } else {
+        let mhz: Vec<String> = ddr_table.iter().map(|k| (k / 1000).to_string()).collect();
+        println!("   • {}", Msg::BusDtTable { mhz: &mhz.join(", ") });
+    }
+    if let Some(ddr) = read_ddr_type() {
+        println!("   • {}", Msg::BusMemory { label: &ddr.label() });
+    }
+
+    install_interrupt_handler();
+    let mut n = 0;
+    loop {
+        let freq = sysfs::gpuclk(&dir).ok();
+        let level = freq.and_then(|f| frequencies.iter().position(|&x| x == f));
+        let busy = sysfs::busy_percent(&dir).ok();
+        let bus = gpubw.as_deref().map(DevfreqBus::read);
+        let votes = read_icc_votes(&sysroot::resolve(ICC_SUMMARY)).unwrap_or_default();
+        let dt_level = level.and_then(|l| {
+            node.as_ref()?.tables.iter().flat_map(|t| &t.levels).find(|d| d.index == l as u32 && Some(d.freq_hz) == freq)
+        });
+
+        let freq_text = freq.map_or("-".to_string(), |f| format!("{} MHz", f / 1_000_000));
+        let level_text = level.map_or(Msg::BusLevelUnknown.to_string(), |l| Msg::BusLevel { level: &l }.to_string());
+        let busy_text = busy.map_or("-".to_string(), |b| format!("{:.0}%", b));
+        println!("\n   {}", Msg::BusGpuLine { freq: &freq_text, level: &level_text, busy: &busy_text });
+        if let Some(range) = dt_level.and_then(|d| ddr_range(d, &ddr_table)) {
+            println!("   {}", Msg::BusDtAllows { range: &range });
+        }
+        if let Some(bus) = &bus {
+            match (bus.cur, bus.tier()) {
+                (Some(cur), Some((tier, tiers))) => {
+                    let governor = bus.governor.as_deref().unwrap_or("?");
+                    println!("   {}", Msg::BusVoteTier { vote: &cur, tier: &(tier + 1), tiers: &tiers, governor: &governor });
+                }
+                (Some(cur), None) => println!("   {}", Msg::BusVote { vote: &cur }),
+                _ => println!("   {}", Msg::BusNotReadable),
+            }
+        }
+        print_icc(&votes);
+
+        // "GPU am Anschlag, Bus nicht": höchstes Level, hohe Last, Bus-Vote unter Maximum
+        if level == Some(0)
+            && busy.is_some_and(|b| b >= STARVED_BUSY_PERCENT)
+            && let Some((tier, tiers)) = bus.as_ref().and_then(DevfreqBus::tier)
+            && tier + 1 < tiers
+        {
+            let busy = busy.unwrap_or(0.0);
+            println!("   ⚠️  {}", Msg::BusStarved { busy: &busy, tier: &(tier + 1), tiers: &tiers });
+        }
+
+        n += 1;
+        if count.is_none_or(|c| n >= c) || !sleep_interruptible(interval) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn ddr_range(level: &DtPwrLevel, ddr_table: &[u32]) -> Option<String> {
+    let (min, max) = ddr_range_khz(level, ddr_table)?;
+    Some(match min == max {
+        true => format!("{} MHz", min / 1000),
+        false => format!("{}-{} MHz", min / 1000, max / 1000),
+    })
+}
+
+fn print_icc(votes: &[IccVote]) {
+    let gbps = |kbps: u64| kbps as f64 / 1_000_000.0;
+    for vote in votes.iter().filter(|v| v.avg_kbps > 0 || v.peak_kbps > 0) {
+        println!("   ICC:  {} -> {}: avg {:.2} GB/s, peak {:.2} GB/s", vote.consumer, vote.node, gbps(vote.avg_kbps), gbps(vote.peak_kbps));
+    }
+}
@@ -0,0 +1,57 @@
+//! `caps` - Capability-Matrix Hardware / Treiber / aktiv
+
+use std::os::unix::io::AsRawFd;
+
+use adreno_ioctl::caps::{capability_matrix, query_properties, Support};
+use adreno_ioctl::chip::decode_chip_id;
+use adreno_ioctl::ioctls::property_name;
+use adreno_ioctl::kgsl::read_gpu_info;
+use adreno_ioctl::messages::Msg;
+
+use super::{open_device, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let (path, file) = open_device(&mut args)?;
+    args.finish()?;
+    let fd = file.as_raw_fd();
+
+    let info = read_gpu_info(fd)?;
+    let chip = decode_chip_id(info.chip_id);
+    let matrix = capability_matrix(fd, &path, &chip);
+
+    println!("🧮 {}", Msg::CapsHeader { model: &chip.model_name, chip_id: chip.raw_id });
+    if chip.spec().is_none() {
+        println!("   {}", Msg::CapsUnknownChip);
+    }
+    println!();
+    println!("   {:<16} {:<10} {:<10} {:<10}", "Feature", "Hardware", "Driver", "Enabled");
+    println!("   {}", "─".repeat(46));
+    for cap in &matrix {
+        println!("   {:<16} {:<10} {:<10} {:<10}",
+            cap.name, cell(cap.hardware), cell(cap.driver), cell(cap.enabled));
+    }
+
+    match query_properties(fd) {
+        Ok(ids) => {
+            println!("\n📜 {}", Msg::CapsAdvertised { count: &ids.len() });
+            let names: Vec<String> = ids
+                .iter()
+                .map(|&id| property_name(id).map_or(format!("0x{:02x}", id), str::to_string))
+                .collect();
+            for line in names.chunks(4) {
+                println!("   {}", line.join(", "));
+            }
+        }
+        Err(_) => println!("\n   ℹ️  {}", Msg::CapsNoQuery),
+    }
+    Ok(())
+}
+
+fn cell(support: Support) -> String {
+    match support {
+        Support::Yes => "✅ yes".to_string(),
+        Support::No => "❌ no".to_string(),
+        Support::Unknown => "❔ ?".to_string(),
+        Support::Version(v) => format!("✅ v{}", v),
+    }
+}
@@ -0,0 +1,89 @@
+//! `collect` - ausgewählte Sammler abfragen und in Sinks schreiben
+
+use std::path::Path;
+use std::time::Duration;
+
+use adreno_ioctl::collector::{self, Collector, COLLECTORS_ENV};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::plugin::{self, PLUGIN_DIR_ENV};
+use adreno_ioctl::sink::{self, Sink, TerminalSink};
+
+use super::{device_path, install_interrupt_handler, parse_duration, sleep_interruptible, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let list = args.flag("--list");
+    let specs = args.values("--collectors")?;
+    let interval = match args.value("--interval")? {
+        Some(i) => parse_duration(&i)?,
+        None => Duration::from_secs(1),
+    };
+    let count = args.parsed::<u64>("--count")?.unwrap_or(1);
+    let sink_specs = args.values("--sink")?;
+    let plugins = args.values("--plugin")?;
+    let path = device_path(&mut args)?;
+    args.finish()?;
+
+    let env = std::env::var(COLLECTORS_ENV).ok();
+    let selection: Vec<&str> = env.iter().chain(&specs).map(String::as_str).collect();
+    let mut available = collector::builtin(&path);
+    let mut loaded = Vec::new();
+    if let Some(dir) = std::env::var_os(PLUGIN_DIR_ENV) {
+        let dir = Path::new(&dir);
+        // SAFETY: wer das Verzeichnis setzt, vertraut den Bibliotheken darin (siehe README)
+        #[allow(unsafe_code)]
+        let results = unsafe { plugin::load_dir(dir) };
+        for result in results.map_err(|e| Msg::CannotRead { path: &dir.display(), error: &e }.to_string())? {
+            match result {
+                Ok(p) => loaded.push(p),
+                Err(e) => eprintln!("⚠️  {}", e),
+            }
+        }
+    }
+    for file in &plugins {
+        // SAFETY: wie oben, die Bibliothek wurde ausdrücklich mit --plugin genannt
+        #[allow(unsafe_code)]
+        loaded.push(unsafe { plugin::load(Path::new(file)) }?);
+    }
+    for p in loaded {
+        if available.iter().any(|c| c.name() == p.name()) {
+            eprintln!("⚠️  {}", Msg::CollectDuplicate { path: &p.path().display(), name: &p.name() });
+            continue;
+        }
+        available.push(Box::new(p));
+    }
+    let mut collectors = collector::select(available, &selection)?;
+
+    if list {
+        println!("🧩 {}", Msg::CollectList { device: &path });
+        for c in &collectors {
+            let state = match c.supported() {
+                true => format!("✅ {}", Msg::CollectSupported),
+                false => format!("❌ {}", Msg::CollectNotAvailable),
+            };
+            println!("   • {:<8} {}", c.name(), state);
+        }
+        return Ok(());
+    }
+    collectors.retain(|c| c.supported());
+    if collectors.is_empty() {
+        return Err(Msg::CollectNoneAvailable.to_string());
+    }
+
+    let mut sinks: Vec<Box<dyn Sink>> = match sink_specs.is_empty() {
+        true => vec![Box::new(TerminalSink::new(Box::new(std::io::stdout())))],
+        false => sink_specs.iter().map(|spec| sink::open(spec)).collect::<Result<_, _>>()?,
+    };
+    install_interrupt_handler();
+    for n in 0..count {
+        if n > 0 && !sleep_interruptible(interval) {
+            break;
+        }
+        for c in collectors.iter_mut() {
+            match c.collect() {
+                Ok(record) => sinks.write(&record).map_err(|e| Msg::CannotWriteRecord { error: &e }.to_string())?,
+                Err(e) => eprintln!("⚠️  {}: {}", c.name(), e),
+            }
+        }
+    }
+    sinks.finish().map_err(|e| Msg::CannotWriteRecord { error: &e }.to_string())
+}
@@ -0,0 +1,206 @@
+//! `completions` und `--man` - Shell-Vervollständigung und Man-Page aus der Kommandotabelle
+
+use adreno_ioctl::messages::Msg;
+
+use super::{get, Args, CommandSpec, COMMANDS, EXIT_CODES, GLOBAL_OPTIONS};
+
+/// Optionen, deren Wert ein Pfad ist
+const PATH_OPTIONS: [&str; 6] = ["--device", "--output", "--path", "--record", "--replay", "--socket"];
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let shell = args.positional().ok_or("completions needs a shell: bash, zsh or fish")?;
+    args.finish()?;
+
+    match shell.as_str() {
+        "bash" => print!("{}", bash()),
+        "zsh" => print!("{}", zsh()),
+        "fish" => print!("{}", fish()),
+        other => return Err(Msg::UnknownShell { shell: &other }.to_string()),
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Auswertung der Usage-Strings
+// ============================================================================
+
+/// Eine Option aus dem Usage-String
+struct OptionSpec {
+    name: String,
+    takes_value: bool,
+}
+
+/// Optionen eines Kommandos: `[--flag]` ist ein Schalter, `--flag WERT` nimmt einen Wert
+fn options(cmd: &CommandSpec) -> Vec<OptionSpec> {
+    let tokens: Vec<&str> = cmd.usage.split_whitespace().collect();
+    let mut options: Vec<OptionSpec> = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        let trimmed = token.trim_start_matches('[');
+        if !trimmed.starts_with("--") {
+            continue;
+        }
+        let name = trimmed.trim_end_matches(']').to_string();
+        let takes_value = !trimmed.ends_with(']') && tokens.get(i + 1).is_some_and(|next| !next.starts_with(['|', '[']));
+        if !options.iter().any(|o| o.name == name) {
+            options.push(OptionSpec { name, takes_value });
+        }
+    }
+    options
+}
+
+/// Feste Werte für das erste Argument, z.B. `ioctl|sysfs`
+fn choices(cmd: &CommandSpec) -> Vec<String> {
+    if cmd.name == "get" {
+        return get::FIELDS.iter().map(|(name, _)| name.to_string()).collect();
+    }
+    match cmd.usage.split_whitespace().nth(1) {
+        Some(token) if token.chars().all(|c| c.is_ascii_lowercase() || c == '|' || c == '-') => {
+            token.split('|').map(str::to_string).collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn command_names() -> Vec<&'static str> {
+    COMMANDS.iter().map(|c| c.name).collect()
+}
+
+// ============================================================================
+// Shells
+// ============================================================================
+
+fn bash() -> String {
+    let mut cases = String::new();
+    for cmd in COMMANDS {
+        let mut words: Vec<String> = choices(cmd);
+        words.extend(options(cmd).into_iter().map(|o| o.name));
+        cases.push_str(&format!("        {}) words=\"{}\" ;;\n", cmd.name, words.join(" ")));
+    }
+    format!(
+        r#"# bash completion for adreno_ioctl
+_adreno_ioctl() {{
+    local cur prev words
+    cur="${{COMP_WORDS[COMP_CWORD]}}"
+    prev="${{COMP_WORDS[COMP_CWORD-1]}}"
+    case "$prev" in
+        {paths}) COMPREPLY=($(compgen -f -- "$cur")); return ;;
+    esac
+    if [ "$COMP_CWORD" -eq 1 ]; then
+        COMPREPLY=($(compgen -W "{commands} --quiet --man" -- "$cur"))
+        return
+    fi
+    case "${{COMP_WORDS[1]}}" in
+{cases}        *) words="" ;;
+    esac
+    COMPREPLY=($(compgen -W "$words --quiet" -- "$cur"))
+}}
+complete -F _adreno_ioctl adreno_ioctl
+"#,
+        paths = PATH_OPTIONS.join("|"),
+        commands = command_names().join(" "),
+        cases = cases
+    )
+}
+
+fn zsh() -> String {
+    let commands: Vec<String> = COMMANDS
+        .iter()
+        .map(|c| format!("        '{}:{}'", c.name, c.about.replace('\'', "")))
+        .collect();
+    let mut cases = String::new();
+    for cmd in COMMANDS {
+        let mut words: Vec<String> = choices(cmd);
+        words.extend(options(cmd).into_iter().map(|o| o.name));
+        cases.push_str(&format!("        {}) compadd -- {} --quiet ;;\n", cmd.name, words.join(" ")));
+    }
+    format!(
+        r#"#compdef adreno_ioctl
+_adreno_ioctl() {{
+    local -a commands
+    commands=(
+{commands}
+    )
+    case "$words[CURRENT-1]" in
+        {paths}) _files; return ;;
+    esac
+    if (( CURRENT == 2 )); then
+        _describe 'command' commands
+        return
+    fi
+    case "$words[2]" in
+{cases}    esac
+}}
+_adreno_ioctl "$@"
+"#,
+        commands = commands.join("\n"),
+        paths = PATH_OPTIONS.join("|"),
+        cases = cases
+    )
+}
+
+fn fish() -> String {
+    let mut out = String::from("# fish completion for adreno_ioctl\ncomplete -c adreno_ioctl -f\n");
+    out.push_str("complete -c adreno_ioctl -s q -l quiet -d 'Print only the requested value'\n");
+    for cmd in COMMANDS {
+        out.push_str(&format!(
+            "complete -c adreno_ioctl -n __fish_use_subcommand -a {} -d '{}'\n",
+            cmd.name,
+            cmd.about.replace('\'', "\\'")
+        ));
+        let condition = format!("'__fish_seen_subcommand_from {}'", cmd.name);
+        let choices = choices(cmd);
+        if !choices.is_empty() {
+            out.push_str(&format!("complete -c adreno_ioctl -n {} -a '{}'\n", condition, choices.join(" ")));
+        }
+        for option in options(cmd) {
+            let long = option.name.trim_start_matches("--");
+            let kind = match (option.takes_value, PATH_OPTIONS.contains(&option.name.as_str())) {
+                (true, true) => " -r -F",
+                (true, false) => " -x",
+                (false, _) => "",
+            };
+            out.push_str(&format!("complete -c adreno_ioctl -n {} -l {}{}\n", condition, long, kind));
+        }
+    }
+    out
+}
+
+// ============================================================================
+// Man-Page
+// ============================================================================
+
+/// Escaping für troff (Backslash und führende Punkte)
+fn roff(text: &str) -> String {
+    let escaped = text.replace('\\', "\\e").replace('-', "\\-");
+    match escaped.starts_with(['.', '\'']) {
+        true => format!("\\&{}", escaped),
+        false => escaped,
+    }
+}
+
+pub fn print_man() {
+    println!(".TH ADRENO_IOCTL 1 \"\" \"adreno_ioctl {}\" \"User Commands\"", env!("CARGO_PKG_VERSION"));
+    println!(".SH NAME");
+    println!("adreno_ioctl \\- inspect and exercise Qualcomm Adreno GPUs through the KGSL driver");
+    println!(".SH SYNOPSIS");
+    println!(".B adreno_ioctl");
+    println!("[\\fICOMMAND\\fR] [\\fIOPTIONS\\fR]");
+    println!(".SH COMMANDS");
+    for cmd in COMMANDS {
+        println!(".TP");
+        println!(".B adreno_ioctl {}", roff(cmd.usage));
+        println!("{}", roff(cmd.about));
+    }
+    println!(".SH GLOBAL OPTIONS");
+    for (flag, about) in GLOBAL_OPTIONS {
+        println!(".TP");
+        println!(".B {}", roff(flag));
+        println!("{}", roff(about));
+    }
+    println!(".SH EXIT STATUS");
+    for (code, about) in EXIT_CODES {
+        println!(".TP");
+        println!(".B {}", code);
+        println!("{}", roff(about));
+    }
+}
@@ -0,0 +1,76 @@
+//! `contexts` - Alle GPU-Contexts des Systems aus debugfs, mit Leak-Hinweisen
+
+use std::collections::BTreeMap;
+
+use adreno_ioctl::debugfs::{collect, ContextInfo};
+use adreno_ioctl::json::Json;
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::schema::versioned;
+use adreno_ioctl::sysroot;
+
+use super::{device_path, Args};
+
+/// Ab so vielen Contexts eines Prozesses liegt ein Leak nahe
+const LEAK_THRESHOLD: usize = 32;
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let pid = args.parsed::<u32>("--pid")?;
+    let json = args.flag("--json");
+    let device = device_path(&mut args)?;
+    args.finish()?;
+
+    let snapshot = match collect(&device) {
+        Ok(snapshot) => snapshot,
+        Err(reason) => {
+            println!("ℹ️  {}", Msg::ContextsUnavailable { reason: &reason });
+            println!("   {}", Msg::ContextsDebugfsOnly);
+            return Ok(());
+        }
+    };
+    let contexts: Vec<&ContextInfo> = snapshot.contexts.iter().filter(|c| pid.is_none() || c.pid == pid).collect();
+
+    if json {
+        let list: Vec<Json> = contexts.iter().map(|c| c.to_json()).collect();
+        println!("{}", versioned(Json::object().field("device", device.as_str()).field("contexts", list)).to_pretty());
+        return Ok(());
+    }
+
+    println!("🧩 {}", Msg::ContextsHeader { device: &device, root: &snapshot.root.display() });
+    if contexts.is_empty() {
+        println!("   {}", Msg::None);
+        return Ok(());
+    }
+    let ts = |t: Option<u32>| t.map_or("?".to_string(), |t| t.to_string());
+    println!("\n   {:>5} {:>7} {:<16} {:<5} {:>4} {:>10} {:>10} {:>6}  Flags", "ID", "PID", "Process", "Type", "Prio", "Queued", "Retired", "Busy");
+    for c in &contexts {
+        println!(
+            "   {:>5} {:>7} {:<16} {:<5} {:>4} {:>10} {:>10} {:>6}  {}",
+            c.id,
+            c.pid.map_or("?".to_string(), |p| p.to_string()),
+            c.process.as_deref().unwrap_or("?"),
+            c.kind.as_deref().unwrap_or("?"),
+            c.priority.map_or("?".to_string(), |p| p.to_string()),
+            ts(c.queued),
+            ts(c.retired),
+            ts(c.inflight()),
+            c.flags.join("|")
+        );
+    }
+
+    // Je Prozess zählen: viele Contexts oder tote Besitzer deuten auf Leaks
+    let mut per_process: BTreeMap<Option<u32>, (usize, &str)> = BTreeMap::new();
+    for c in &contexts {
+        let entry = per_process.entry(c.pid).or_insert((0, c.process.as_deref().unwrap_or("?")));
+        entry.0 += 1;
+    }
+    println!("\n   📊 {}", Msg::ContextsSummary { contexts: &contexts.len(), processes: &per_process.len() });
+    for (pid, (count, name)) in &per_process {
+        let Some(pid) = pid else { continue };
+        if !sysroot::resolve(format!("/proc/{}", pid)).exists() {
+            println!("   ⚠️  {}", Msg::ContextsExitedOwner { name, pid, count });
+        } else if *count >= LEAK_THRESHOLD {
+            println!("   ⚠️  {}", Msg::ContextsLeak { name, pid, count });
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! `cores` - Shader Processor, Micro-TP, Wave-Kapazität und Speicherseite
+
+use std::os::fd::AsRawFd;
+
+use adreno_ioctl::chip::{decode_chip_id, lookup_model};
+use adreno_ioctl::kgsl::{read_gpu_info, read_gpu_model, read_min_access_length};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::sysfs;
+
+use super::{fail, format_size, open_device, Args, EXIT_UNSUPPORTED};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    // ALU-Gegenprobe bräuchte einen Compute-Dispatch mit fertigem Shader
+    if args.flag("--measure") {
+        return Err(fail(EXIT_UNSUPPORTED, Msg::CoresMeasureUnsupported.to_string()));
+    }
+    let (path, file) = open_device(&mut args)?;
+    args.finish()?;
+
+    let info = read_gpu_info(file.as_raw_fd())?;
+    let chip = decode_chip_id(info.chip_id);
+    let model = read_gpu_model(file.as_raw_fd());
+    // Neue A7xx Chip IDs sind nicht in der Datenbank - dann über den Modellnamen
+    let spec = chip
+        .spec()
+        .or_else(|| model.as_deref().and_then(lookup_model))
+        .ok_or_else(|| Msg::NotInChipDatabase { model: &model.as_deref().unwrap_or(&chip.model_name) }.to_string())?;
+    let cores = spec.shader_cores();
+
+    println!("🧮 {}", Msg::CoresHeader { path: &path, name: &spec.name });
+    if let Some(model) = &model {
+        println!("   • {}", Msg::CoresDriverModel { model: &model });
+    }
+    println!("   • {}", Msg::CoresSp { count: &cores.sp });
+    println!("   • {}", Msg::CoresMicroTp { count: &cores.micro_tp });
+    println!("   • {}", Msg::CoresAlus { count: &cores.alus });
+    println!("   • {}", Msg::CoresWaveSize { fibers: &cores.wave_size });
+    println!("   • {}", Msg::CoresMaxWaves { total: &cores.max_waves(), per_sp: &cores.max_waves_per_sp });
+    println!("   ℹ️  {}", Msg::CoresFromDatabase);
+
+    let memory = spec.memory_layout();
+    let min_access = read_min_access_length(file.as_raw_fd());
+    println!("\n   {}", Msg::CoresMemory);
+    println!("   • {}", Msg::CoresBusWidth { bits: &memory.bus_bits });
+    println!("   • {}", Msg::CoresUche { size: &format_size(memory.uche_bytes as u64) });
+    println!("   • {}", Msg::CoresCacheLine { bytes: &memory.cache_line_bytes });
+    match min_access {
+        Some(bytes) => println!("   • {}", Msg::CoresMinAccessDriver { bytes: &bytes }),
+        None => println!("   • {}", Msg::CoresMinAccessTypical { bytes: &memory.min_access_bytes }),
+    }
+    println!("   💡 {}", Msg::CoresCoalesce { bytes: &min_access.unwrap_or(memory.min_access_bytes) });
+
+    let dir = sysfs::device_dir(&path);
+    let max_hz = sysfs::available_frequencies(&dir).ok().and_then(|f| f.into_iter().max());
+    let cur_hz = sysfs::gpuclk(&dir).ok();
+    if let Some(hz) = max_hz {
+        println!("\n   {}", Msg::CoresGflopsMax { gflops: &cores.theoretical_gflops(hz), mhz: &(hz / 1_000_000) });
+    }
+    if let Some(hz) = cur_hz {
+        println!("   {}", Msg::CoresGflopsCurrent { gflops: &cores.theoretical_gflops(hz), mhz: &(hz / 1_000_000) });
+    }
+    Ok(())
+}
@@ -0,0 +1,236 @@
+//! `daemon` - Sampelt im Hintergrund und beantwortet Anfragen über einen Unix-Socket
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::os::fd::{AsFd, AsRawFd};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use adreno_ioctl::daemon::{default_socket, query, DaemonState, MAX_CLIENTS, MAX_REQUEST_BYTES};
+use adreno_ioctl::history::HistoryPoint;
+use adreno_ioctl::instance;
+use adreno_ioctl::json::Json;
+use adreno_ioctl::kgsl::{read_gpu_info, read_gpu_model};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::monitor::{AdaptiveInterval, Monitor};
+use adreno_ioctl::schema::versioned;
+use adreno_ioctl::sys;
+use adreno_ioctl::sysfs;
+use adreno_ioctl::sysroot;
+
+#[cfg(not(feature = "http"))]
+use super::{fail, EXIT_UNSUPPORTED};
+use super::{
+    device_path, install_interrupt_handler, parse_duration, sleep_interruptible, switch_user,
+    wait_readable_interruptible, Args, PrivilegeDrop,
+};
+
+/// Obergrenze des Intervalls bei untätiger GPU
+const DEFAULT_MAX_INTERVAL: Duration = Duration::from_secs(10);
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let socket = args.value("--socket")?.map(PathBuf::from);
+    let privileges = PrivilegeDrop::from_args(&mut args)?;
+    // Ohne `--socket` liegt der Socket im privaten Verzeichnis des Benutzers,
+    // unter dem der Daemon läuft; `--query` mit denselben Optionen findet ihn
+    let target = privileges.target()?;
+    let (uid, gid) = target.as_ref().map_or_else(|| (sys::geteuid(), sys::getegid()), |user| (user.uid, user.gid));
+    if let Some(request) = args.value("--query")? {
+        args.finish()?;
+        let socket = socket.unwrap_or_else(|| default_socket(uid));
+        let reply = query(&socket, &request).map_err(|e| Msg::CannotQuery { path: &socket.display(), error: &e }.to_string())?;
+        println!("{}", reply);
+        return Ok(());
+    }
+    let interval = match args.value("--interval")? {
+        Some(i) => parse_duration(&i)?,
+        None => Duration::from_secs(1),
+    };
+    // Im Leerlauf seltener sampeln, damit der Daemon das SoC nicht wach hält
+    let max_interval = match args.value("--max-interval")? {
+        Some(i) => parse_duration(&i)?,
+        None => DEFAULT_MAX_INTERVAL.max(interval),
+    };
+    let retention = match args.value("--history")? {
+        Some(h) => parse_duration(&h)?,
+        None => Duration::from_secs(600),
+    };
+    let http = args.value("--http")?;
+    let device = device_path(&mut args)?;
+    args.finish()?;
+
+    let mut initial = DaemonState::new(&device, interval, retention);
+    initial.gpu = gpu_facts(&device);
+    let state = Arc::new(Mutex::new(initial));
+    let http = http.map(|addr| bind_http(&addr)).transpose()?;
+
+    let socket = match socket {
+        Some(socket) => socket,
+        None => {
+            instance::secure_user_dir(uid, gid)
+                .map_err(|e| Msg::CannotCreate { path: &instance::user_dir(uid).display(), error: &e }.to_string())?;
+            default_socket(uid)
+        }
+    };
+    // Verwaister Socket eines abgestürzten Daemons blockiert sonst bind()
+    if socket.exists() && UnixStream::connect(&socket).is_err() {
+        let _ = std::fs::remove_file(&socket);
+    }
+    let listener = UnixListener::bind(&socket).map_err(|e| Msg::CannotBind { path: &socket.display(), error: &e }.to_string())?;
+    listener.set_nonblocking(true).map_err(|e| e.to_string())?;
+
+    // Alles Privilegierte ist offen; Anfragen verarbeitet erst der neue Benutzer
+    let monitor = Monitor::new(&device).with_memory();
+    if let Some(user) = target {
+        // Damit der Daemon seinen Socket beim Beenden selbst entfernen kann
+        let _ = std::os::unix::fs::chown(&socket, Some(user.uid), Some(user.gid));
+        switch_user(&user, &[&device])?;
+        println!("🔒 {}", Msg::DroppedRoot { user: &user });
+    }
+    if let Some(listener) = http {
+        serve_http(listener, Arc::clone(&state));
+    }
+
+    install_interrupt_handler();
+    let sampler = {
+        let state = Arc::clone(&state);
+        let mut pacing = AdaptiveInterval::new(interval, max_interval);
+        thread::spawn(move || loop {
+            let sample = monitor.sample();
+            let next = pacing.update(&sample, monitor.frequencies());
+            {
+                let mut state = state.lock().unwrap();
+                state.history.push(HistoryPoint::from(&sample));
+                state.current_interval = next;
+            }
+            if !sleep_interruptible(next) {
+                break;
+            }
+        })
+    };
+
+    if let Some(placement) = super::placement() {
+        println!("📌 {}", Msg::SamplingOn { placement: &placement });
+    }
+    println!(
+        "🛰️  {}",
+        Msg::DaemonListening {
+            device: &device,
+            socket: &socket.display(),
+            interval: &interval.as_millis(),
+            max_interval: &max_interval.as_millis(),
+            keep: &retention.as_secs(),
+        }
+    );
+    // Kein Polling: der Thread schläft bis zur Verbindung oder zum Signal
+    let clients = Arc::new(AtomicUsize::new(0));
+    while wait_readable_interruptible(listener.as_fd()) {
+        match listener.accept() {
+            Ok((mut stream, _)) => {
+                if clients.fetch_add(1, Ordering::SeqCst) >= MAX_CLIENTS {
+                    clients.fetch_sub(1, Ordering::SeqCst);
+                    let _ = writeln!(stream, "{}", versioned(Json::object().field("error", "Too many clients")).to_compact());
+                    continue;
+                }
+                let state = Arc::clone(&state);
+                let clients = Arc::clone(&clients);
+                thread::spawn(move || {
+                    serve_client(stream, &state);
+                    clients.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+            Err(e) if e.kind() == ErrorKind::WouldBlock => {}
+            Err(e) => eprintln!("⚠️  {}", Msg::AcceptFailed { error: &e }),
+        }
+    }
+    let _ = sampler.join();
+    let _ = std::fs::remove_file(&socket);
+    println!("👋 {}", Msg::DaemonStopped);
+    Ok(())
+}
+
+/// Chip und Modell für `info`; ohne Zugriff auf das Gerät `Null`
+fn gpu_facts(device: &str) -> Json {
+    let Ok(file) = File::open(sysroot::resolve(device)) else {
+        return Json::Null;
+    };
+    let Ok(info) = read_gpu_info(file.as_raw_fd()) else {
+        return Json::Null;
+    };
+    let frequencies = sysfs::available_frequencies(&sysfs::device_dir(device)).unwrap_or_default();
+    Json::object()
+        .field("chip_id", format!("0x{:08x}", info.chip_id))
+        .field("model", read_gpu_model(file.as_raw_fd()))
+        .field("max_freq_hz", frequencies.iter().max().copied())
+}
+
+/// Bindet vor dem Rechteabbau, damit auch Ports unter 1024 gehen
+#[cfg(feature = "http")]
+fn bind_http(addr: &str) -> Result<std::net::TcpListener, String> {
+    let listener = std::net::TcpListener::bind(addr).map_err(|e| Msg::CannotListen { addr: &addr, error: &e }.to_string())?;
+    println!("🌐 {}", Msg::DashboardOn { addr: &addr });
+    Ok(listener)
+}
+
+#[cfg(not(feature = "http"))]
+fn bind_http(_addr: &str) -> Result<std::convert::Infallible, String> {
+    Err(fail(EXIT_UNSUPPORTED, Msg::HttpFeatureMissing.to_string()))
+}
+
+#[cfg(feature = "http")]
+fn serve_http(listener: std::net::TcpListener, state: Arc<Mutex<DaemonState>>) {
+    // Läuft bis Prozessende; ein Thread pro Verbindung, höchstens `MAX_CLIENTS`
+    thread::spawn(move || {
+        let clients = Arc::new(AtomicUsize::new(0));
+        for stream in listener.incoming().flatten() {
+            if clients.fetch_add(1, Ordering::SeqCst) >= MAX_CLIENTS {
+                clients.fetch_sub(1, Ordering::SeqCst);
+                continue;
+            }
+            let state = Arc::clone(&state);
+            let clients = Arc::clone(&clients);
+            thread::spawn(move || {
+                let _ = adreno_ioctl::http::serve(stream, &state, parse_duration);
+                clients.fetch_sub(1, Ordering::SeqCst);
+            });
+        }
+    });
+}
+
+#[cfg(not(feature = "http"))]
+fn serve_http(listener: std::convert::Infallible, _state: Arc<Mutex<DaemonState>>) {
+    match listener {}
+}
+
+/// Eine Antwortzeile pro Anfragezeile, bis der Client schließt
+fn serve_client(stream: UnixStream, state: &Mutex<DaemonState>) {
+    if stream.set_nonblocking(false).is_err() {
+        return;
+    }
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(60)));
+    let Ok(mut writer) = stream.try_clone() else {
+        return;
+    };
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    loop {
+        line.clear();
+        // `take` begrenzt den Puffer, auch wenn nie ein Zeilenende kommt
+        match (&mut reader).take(MAX_REQUEST_BYTES).read_line(&mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+        let complete = line.ends_with('\n');
+        let reply = match complete || (line.len() as u64) < MAX_REQUEST_BYTES {
+            true => state.lock().unwrap().handle(&line, Instant::now(), parse_duration),
+            false => Json::object().field("error", format!("Request longer than {} bytes", MAX_REQUEST_BYTES)),
+        };
+        if writeln!(writer, "{}", versioned(reply).to_compact()).is_err() || !complete {
+            break;
+        }
+    }
+}
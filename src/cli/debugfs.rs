@@ -0,0 +1,58 @@
+//! `debugfs` - Speicherlisten, Contexts und Dispatcher aus KGSL-debugfs
+
+use adreno_ioctl::appid::process_label;
+use adreno_ioctl::debugfs::collect;
+use adreno_ioctl::messages::Msg;
+
+use super::{device_path, format_size, Args};
+
+/// Größte Prozesse in der Übersicht
+const TOP_PROCESSES: usize = 10;
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let device = device_path(&mut args)?;
+    args.finish()?;
+
+    let snapshot = match collect(&device) {
+        Ok(snapshot) => snapshot,
+        Err(reason) => {
+            println!("ℹ️  {}", Msg::DebugfsUnavailable { reason: &reason });
+            println!("   {}", Msg::DebugfsOnlyThere);
+            return Ok(());
+        }
+    };
+
+    println!("🔬 {}", Msg::DebugfsHeader { root: &snapshot.root.display() });
+
+    let mut processes: Vec<_> = snapshot.processes.iter().collect();
+    processes.sort_by_key(|p| std::cmp::Reverse(p.total_bytes()));
+    println!("\n   📦 {}", Msg::DebugfsMemoryLists { count: &processes.len() });
+    for p in processes.iter().take(TOP_PROCESSES) {
+        let name = process_label(p.pid);
+        let size = format_size(p.total_bytes());
+        println!("   • {}", Msg::DebugfsProcess { pid: &p.pid, name: &name, count: &p.entries.len(), size: &size });
+    }
+    if !snapshot.globals.is_empty() {
+        let total: u64 = snapshot.globals.iter().map(|e| e.size).sum();
+        println!("   • {}", Msg::DebugfsGlobal { count: &snapshot.globals.len(), size: &format_size(total) });
+    }
+
+    println!("\n   🧩 {}", Msg::DebugfsContexts { count: &snapshot.contexts.len() });
+    for ctx in &snapshot.contexts {
+        let ts = |t: Option<u32>| t.map_or("?".to_string(), |t| t.to_string());
+        let process = ctx.process.as_deref().unwrap_or("?");
+        let (queued, retired, inflight) = (ts(ctx.queued), ts(ctx.retired), ts(ctx.inflight()));
+        println!(
+            "   • {}",
+            Msg::DebugfsContext { id: &ctx.id, process: &process, queued: &queued, retired: &retired, inflight: &inflight }
+        );
+    }
+
+    if !snapshot.dispatcher.is_empty() {
+        println!("\n   📬 Dispatcher:");
+        for (key, value) in &snapshot.dispatcher {
+            println!("   • {:<28} {}", key, value);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! `driver` - Kernel- und KGSL-Modul Build-Informationen
+
+use std::os::fd::AsRawFd;
+
+use adreno_ioctl::blob::read_vendor_blobs;
+use adreno_ioctl::driver::{read_driver_info, DriverInfo};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::propmap::{self, KgslTree};
+
+use super::{default_device, open_path, Args};
+
+pub fn run(args: Args) -> Result<(), String> {
+    args.finish()?;
+    // Ohne Gerät bleibt nur die Schätzung aus `uname -r`
+    let device = default_device().ok().and_then(|path| open_path(&path).ok());
+    print_driver_info(&read_driver_info(), device.as_ref().map(|file| propmap::tree(file.as_raw_fd())), true);
+
+    // Userspace-Seite zum Vergleich (Details: `blob`)
+    if let Some((blob, version)) = read_vendor_blobs().iter().find_map(|b| Some((b, b.versions.first()?.release()?))) {
+        println!("   • {}", Msg::DriverUserspaceBlob { version: &version, path: &blob.path.display() });
+    }
+    Ok(())
+}
+
+/// Gibt den Treiber-Kontext aus, `tree` vom Gerät (sonst aus dem Kernel-Release),
+/// `all_params` listet alle Modulparameter
+pub fn print_driver_info(info: &DriverInfo, tree: Option<KgslTree>, all_params: bool) {
+    println!("🐧 {}", Msg::DriverHeader);
+    println!("   • Kernel: {} ({})", info.kernel_release, info.machine);
+    println!("   • Build: {}", info.kernel_version);
+
+    match &info.module_name {
+        Some(name) => {
+            let kind = if info.builtin { Msg::DriverBuiltin } else { Msg::DriverModule };
+            println!("   • KGSL: {} ({}){}{}",
+                name,
+                kind,
+                info.module_version.as_ref().map(|v| format!(" version {}", v)).unwrap_or_default(),
+                info.srcversion.as_ref().map(|v| format!(" srcversion {}", v)).unwrap_or_default(),
+            );
+        }
+        None => println!("   • {}", Msg::DriverNoModule),
+    }
+    match tree {
+        Some(tree) => println!("   • {}", Msg::DriverTreeFromDriver { tree: &tree }),
+        None => {
+            if let Some(tree) = KgslTree::from_kernel_release(&info.kernel_release) {
+                println!("   • {}", Msg::DriverTreeFromRelease { tree: &tree });
+            }
+        }
+    }
+
+    for (name, value, desc) in info.notable_parameters() {
+        println!("   • {} = {}  ({})", name, value, desc);
+    }
+
+    if all_params && !info.parameters.is_empty() {
+        println!("   {}", Msg::DriverParameters);
+        for (name, value) in &info.parameters {
+            println!("      {} = {}", name, value);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! `dt` - Board-Konfiguration aus dem Device Tree neben der Laufzeit-Tabelle,
+//! mit Spannungen aus DT und debugfs (nur lesend)
+
+use adreno_ioctl::devicetree::{read_gpu_node, DtPwrLevelTable};
+use adreno_ioctl::kgsl::find_kgsl_devices;
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::opp::{read_gpu_opp_tables, OPP_DEBUGFS_DIR};
+use adreno_ioctl::sysfs;
+use adreno_ioctl::sysroot;
+
+use super::Args;
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let device = args.value("--device")?;
+    args.finish()?;
+
+    let node = read_gpu_node().ok_or_else(|| Msg::DtNoGpuNode.to_string())?;
+
+    // Laufzeit-Tabelle aus sysfs (Index = Power Level)
+    let device = device.or_else(|| find_kgsl_devices().into_iter().next());
+    let runtime = device
+        .map(|d| sysfs::available_frequencies(&sysfs::device_dir(&d)).unwrap_or_default())
+        .unwrap_or_default();
+
+    println!("🌳 {}", Msg::DtHeader { path: &node.path.display() });
+    println!("   • compatible: {}", node.compatible.join(", "));
+    match (&node.zap_shader, node.has_zap_node) {
+        (Some(name), _) => println!("   • {}", Msg::DtZapShader { name }),
+        (None, true) => println!("   • {}", Msg::DtZapNoName),
+        (None, false) => println!("   • {}", Msg::DtZapNone),
+    }
+    if let Some(fuse) = &node.speed_bin_fuse {
+        match fuse.as_slice() {
+            [offset, mask, shift] => println!(
+                "   • Speed-bin fuse: offset 0x{:x}, mask 0x{:x}, shift {}", offset, mask, shift),
+            other => println!("   • Speed-bin fuse: {:x?}", other),
+        }
+    }
+
+    if node.tables.is_empty() {
+        println!("\n   {}", Msg::DtNoTables);
+    }
+
+    for table in &node.tables {
+        let active = !runtime.is_empty() && matches_runtime(table, &runtime);
+        let bin = table.speed_bin.map_or(Msg::DtDefault.to_string(), |b| Msg::DtSpeedBin { bin: &b }.to_string());
+        let marker = if active { format!("  ← {}", Msg::DtActive) } else { String::new() };
+        println!("\n   📋 {}{}", Msg::DtPowerLevels { bin: &bin }, marker);
+        println!("   {:>5}  {:>9}  {:>12}  {:<16}  {:>9}", "Level", "DT MHz", "Bus min/max", "Voltage", "Runtime");
+        for level in &table.levels {
+            let bus = match (level.bus_min, level.bus_max) {
+                (Some(min), Some(max)) => format!("{}/{}", min, max),
+                _ => level.bus_freq.map(|b| b.to_string()).unwrap_or_else(|| "-".to_string()),
+            };
+            let rt = runtime.get(level.index as usize).map(|f| f / 1_000_000);
+            let marker = match rt {
+                Some(f) if f == level.freq_hz / 1_000_000 => "✅",
+                Some(_) => "⚠️",
+                None => "",
+            };
+            println!("   {:>5}  {:>9}  {:>12}  {:<16}  {:>9} {}",
+                level.index,
+                level.freq_hz / 1_000_000,
+                bus,
+                level.voltage.map(|v| v.to_string()).unwrap_or_else(|| "-".to_string()),
+                rt.map(|f| f.to_string()).unwrap_or_else(|| "-".to_string()),
+                marker,
+            );
+        }
+    }
+
+    if !runtime.is_empty() && !node.tables.iter().any(|t| matches_runtime(t, &runtime)) {
+        let mhz = runtime.iter().map(|f| (f / 1_000_000).to_string()).collect::<Vec<_>>().join(", ");
+        println!("\n⚠️  {}", Msg::DtRuntimeMismatch { mhz: &mhz });
+    }
+
+    print_debugfs_opps();
+    Ok(())
+}
+
+/// Vom Kernel geladene OPP-Tabelle, falls debugfs sie zeigt
+fn print_debugfs_opps() {
+    let tables = match read_gpu_opp_tables(&sysroot::resolve(OPP_DEBUGFS_DIR)) {
+        Ok(tables) => tables,
+        Err(e) => {
+            println!("\n   ℹ️  {}", Msg::DtOppUnreadable { dir: &OPP_DEBUGFS_DIR, error: &e });
+            return;
+        }
+    };
+    for table in tables.iter().filter(|t| !t.entries.is_empty()) {
+        println!("\n   ⚡ {}", Msg::DtRuntimeOpp { dir: &OPP_DEBUGFS_DIR, device: &table.device });
+        println!("   {:>9}  {:>10}  {:>21}  {:>6}", "MHz", "Target mV", "Min/max mV", "Level");
+        let mv = |uv: Option<u64>| uv.map(|v| format!("{:.1}", v as f64 / 1000.0)).unwrap_or_else(|| "-".to_string());
+        for entry in &table.entries {
+            println!("   {:>9}  {:>10}  {:>21}  {:>6}",
+                entry.rate_hz / 1_000_000,
+                mv(entry.microvolt_target),
+                format!("{}/{}", mv(entry.microvolt_min), mv(entry.microvolt_max)),
+                entry.level.map(|l| l.to_string()).unwrap_or_else(|| "-".to_string()),
+            );
+        }
+    }
+}
+
+/// Ob eine DT-Tabelle der Laufzeit-Tabelle entspricht (auf MHz gerundet)
+fn matches_runtime(table: &DtPwrLevelTable, runtime: &[u64]) -> bool {
+    let mut dt: Vec<u64> = table.levels.iter().map(|l| l.freq_hz / 1_000_000).collect();
+    let mut rt: Vec<u64> = runtime.iter().map(|f| f / 1_000_000).collect();
+    dt.sort_unstable();
+    rt.sort_unstable();
+    dt == rt
+}
@@ -0,0 +1,73 @@
+//! `events` - Takt-, Power-Level-, Drossel- und Reset-Ereignisse als Strom
+
+use std::time::{Duration, Instant};
+
+use adreno_ioctl::events::{EventWatcher, EVENT_KINDS};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::sink::{self, Sink};
+
+use super::{device_path, install_interrupt_handler, interrupted, parse_duration, sleep_interruptible, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let interval = match args.value("--interval")? {
+        Some(i) => parse_duration(&i)?,
+        None => Duration::from_millis(50),
+    };
+    let count = args.parsed::<u64>("--count")?;
+    let ftrace = args.flag("--ftrace");
+    let kinds = match args.value("--events")? {
+        Some(list) => list.split(',').map(str::trim).map(str::to_string).collect(),
+        None => EVENT_KINDS.iter().map(|k| k.to_string()).collect::<Vec<_>>(),
+    };
+    if let Some(unknown) = kinds.iter().find(|k| !EVENT_KINDS.contains(&k.as_str())) {
+        return Err(Msg::EventsUnknown { event: &unknown, expected: &EVENT_KINDS.join(", ") }.to_string());
+    }
+    let mut sinks = args.values("--sink")?.iter().map(|spec| sink::open(spec)).collect::<Result<Vec<_>, _>>()?;
+    let path = device_path(&mut args)?;
+    args.finish()?;
+
+    let mut watcher = EventWatcher::new(&path);
+    if ftrace {
+        watcher = watcher.with_ftrace().map_err(|e| Msg::EventsNoFtrace { error: &e }.to_string())?;
+    }
+    install_interrupt_handler();
+    let use_sinks = !sinks.is_empty();
+    if !use_sinks {
+        let mode = if watcher.uses_ftrace() { "ftrace + sysfs".to_string() } else { Msg::EventsPolling.to_string() };
+        println!("📡 {}", Msg::EventsHeader { path: &path, kinds: &kinds.join(", "), mode: &mode });
+    }
+
+    let start = Instant::now();
+    let device = path.clone();
+    let wanted = kinds.clone();
+    watcher.subscribe(move |event| {
+        if !wanted.iter().any(|k| k == event.name()) {
+            return;
+        }
+        if use_sinks {
+            if let Err(e) = sinks.write(&event.to_record(&device)) {
+                eprintln!("⚠️  {}", Msg::CannotWriteRecord { error: &e });
+            }
+        } else {
+            let at = event.time.duration_since(start).as_secs_f64();
+            println!("   {:>9.3}s  {}  ({})", at, event, event.source.label());
+        }
+    });
+
+    let mut seen = 0;
+    while count.is_none_or(|c| seen < c) {
+        let result = if watcher.uses_ftrace() {
+            watcher.wait(interval)
+        } else if sleep_interruptible(interval) {
+            watcher.poll()
+        } else {
+            break;
+        };
+        if interrupted() {
+            break;
+        }
+        let events = result.map_err(|e| Msg::EventsCannotRead { error: &e }.to_string())?;
+        seen += events.iter().filter(|e| kinds.iter().any(|k| k == e.name())).count() as u64;
+    }
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! `explain` - Zerlegt eine IOCTL-Nummer in Richtung, Typ, Kommando und Größe
+
+use adreno_ioctl::ioctls::{IoctlRequest, OTHER_IOCTLS};
+use adreno_ioctl::messages::Msg;
+
+use super::Args;
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let arg = args.positional().ok_or("explain needs an ioctl number, e.g. 0xc0140902")?;
+    args.finish()?;
+
+    let raw = parse_request(&arg)?;
+    print_explanation(raw);
+    Ok(())
+}
+
+/// Hex (`0x...`) oder dezimal
+fn parse_request(arg: &str) -> Result<u32, String> {
+    let parsed = match arg.strip_prefix("0x").or_else(|| arg.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => arg.parse(),
+    };
+    parsed.map_err(|_| Msg::ExplainInvalid { arg: &arg }.to_string())
+}
+
+pub fn print_explanation(raw: u32) {
+    let req = IoctlRequest::decode(raw);
+
+    println!("💡 IOCTL 0x{:08x}:", raw);
+    println!("   • {}", Msg::ExplainDirection { dir: &req.direction_name() });
+    match req.type_name() {
+        Some(name) => println!("   • Type: 0x{:02x} ({})", req.type_, name),
+        None => println!("   • Type: 0x{:02x} ('{}')", req.type_, (req.type_ as char).escape_default()),
+    }
+    println!("   • {}", Msg::ExplainCommand { nr: req.nr as u32 });
+    println!("   • {}", Msg::ExplainSize { bytes: &req.size });
+
+    if let Some(cmd) = req.kgsl_command() {
+        println!("   • Name: {}", cmd.name);
+        println!("   • Struct: struct {}", cmd.struct_name);
+        match cmd.request {
+            Some(expected) if expected == raw => println!("   • ✅ {}", Msg::ExplainMatches),
+            Some(expected) => {
+                let exp = IoctlRequest::decode(expected);
+                println!("   • ⚠️  {}", Msg::ExplainToolUses { request: expected, dir: &exp.direction_name(), bytes: &exp.size });
+            }
+            None => {}
+        }
+    } else if let Some((_, name, struct_name)) = OTHER_IOCTLS.iter().find(|(r, _, _)| *r == raw) {
+        println!("   • Name: {}", name);
+        println!("   • Struct: struct {}", struct_name);
+    } else if req.type_name() == Some("KGSL_IOC_TYPE") {
+        println!("   • ❓ {}", Msg::ExplainUnknownKgsl);
+    }
+}
@@ -0,0 +1,48 @@
+//! `export` - Geräte-Fakten in Formaten anderer Werkzeuge
+
+use std::os::unix::io::AsRawFd;
+use std::path::PathBuf;
+
+use adreno_ioctl::chip::decode_chip_id;
+use adreno_ioctl::driver::uname;
+use adreno_ioctl::gmem::read_gmem;
+use adreno_ioctl::kgsl::{read_gpu_info, read_gpu_model, read_vk_device_id};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::sysfs;
+use adreno_ioctl::vkjson::{to_vkjson, DeviceFacts};
+
+use super::{open_device, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let vkjson = args.flag("--vkjson");
+    let output = args.value("--output")?.map(PathBuf::from);
+    let (path, file) = open_device(&mut args)?;
+    args.finish()?;
+    if !vkjson {
+        return Err(Msg::ExportNeedsFormat.to_string());
+    }
+    let fd = file.as_raw_fd();
+
+    let info = read_gpu_info(fd)?;
+    let chip = decode_chip_id(info.chip_id);
+    let mut frequencies_hz = sysfs::available_frequencies(&sysfs::device_dir(&path)).unwrap_or_default();
+    frequencies_hz.sort_unstable();
+    let facts = DeviceFacts {
+        model: read_gpu_model(fd),
+        vk_device_id: read_vk_device_id(fd),
+        gmem: read_gmem(fd, &chip),
+        frequencies_hz,
+        kernel_release: uname().0,
+        chip,
+    };
+    let text = to_vkjson(&facts).to_pretty();
+
+    match output {
+        Some(out) => {
+            std::fs::write(&out, text + "\n").map_err(|e| Msg::CannotWrite { path: &out.display(), error: &e }.to_string())?;
+            println!("💾 {}", Msg::ExportWritten { path: &out.display() });
+        }
+        None => println!("{}", text),
+    }
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! Farm-Modus (`--adb SERIAL`): Subcommand auf einem oder vielen Geräten
+//! ausführen und die Ergebnisse in einem JSON-Bericht sammeln
+
+use std::path::PathBuf;
+use std::thread;
+
+use adreno_ioctl::adb::{list_devices, Adb, REMOTE_BINARY};
+use adreno_ioctl::json::Json;
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::schema::versioned;
+
+use super::{set_exit_code, EXIT_FAILURE, EXIT_NO_DEVICE, EXIT_PARTIAL};
+
+/// Steht für alle angeschlossenen Geräte
+const ALL_DEVICES: &str = "all";
+
+/// `serials` aus `--adb`, `binary` aus `--adb-binary` (sonst dieses Programm)
+pub fn run(serials: Vec<String>, binary: Option<String>, command: String, args: Vec<String>) -> Result<(), String> {
+    let serials = if serials.iter().any(|s| s == ALL_DEVICES) { list_devices()? } else { serials };
+    if serials.is_empty() {
+        return Err(super::fail(EXIT_NO_DEVICE, Msg::FarmNoDevices.to_string()));
+    }
+    let binary = match binary {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_exe().map_err(|e| Msg::FarmNoBinary { error: &e }.to_string())?,
+    };
+    if !binary.is_file() {
+        return Err(Msg::NotAFile { path: &binary.display() }.to_string());
+    }
+
+    // Ohne Subcommand die Inventur: `info --json`
+    let mut remote = vec![REMOTE_BINARY.to_string(), "--plain".to_string(), command.clone()];
+    remote.extend(args);
+    if super::read_only() {
+        remote.push("--read-only".to_string());
+    }
+    if command == "info" && !remote.iter().any(|a| a == "--json") {
+        remote.push("--json".to_string());
+    }
+
+    eprintln!("📱 {}", Msg::FarmRunning { command: &remote[2..].join(" "), count: &serials.len() });
+    let workers: Vec<_> = serials
+        .into_iter()
+        .map(|serial| {
+            let (binary, remote) = (binary.clone(), remote.clone());
+            thread::spawn(move || run_device(&Adb::new(&serial), &binary, &remote))
+        })
+        .collect();
+    let results: Vec<(bool, Json)> = workers.into_iter().map(|w| w.join().expect("adb worker panicked")).collect();
+
+    let failed = results.iter().filter(|(ok, _)| !ok).count();
+    if failed == results.len() {
+        set_exit_code(EXIT_FAILURE);
+    } else if failed > 0 {
+        set_exit_code(EXIT_PARTIAL);
+    }
+    eprintln!("   {}", Msg::FarmSummary { ok: &(results.len() - failed), failed: &failed });
+
+    let devices: Vec<Json> = results.into_iter().map(|(_, json)| json).collect();
+    let report = Json::object()
+        .field("command", remote[2..].join(" "))
+        .field("devices", devices);
+    println!("{}", versioned(report).to_pretty());
+    Ok(())
+}
+
+/// Ein Gerät: schieben, ausführen, Ausgabe einsammeln
+fn run_device(adb: &Adb, binary: &std::path::Path, remote: &[String]) -> (bool, Json) {
+    let mut entry = Json::object()
+        .field("serial", adb.serial.as_str())
+        .field("model", adb.getprop("ro.product.model"))
+        .field("android", adb.getprop("ro.build.version.release"));
+    if let Err(e) = adb.push_executable(binary, REMOTE_BINARY) {
+        eprintln!("   ❌ {}: {}", adb.serial, e);
+        return (false, entry.field("error", e));
+    }
+    let output = match adb.shell(remote) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("   ❌ {}: {}", adb.serial, e);
+            return (false, entry.field("error", e));
+        }
+    };
+    let ok = output.exit_code == Some(0);
+    eprintln!("   {} {}: exit {}", if ok { "✅" } else { "⚠️ " }, adb.serial, output.exit_code.map_or("?".to_string(), |c| c.to_string()));
+
+    entry = entry.field("exit_code", output.exit_code.map(i64::from));
+    // JSON-Ausgabe direkt übernehmen, alles andere als Text
+    entry = match Json::parse(output.stdout.trim()) {
+        Ok(json) => entry.field("result", json),
+        Err(_) => entry.field("output", output.stdout.trim_end()),
+    };
+    if !output.stderr.trim().is_empty() {
+        entry = entry.field("stderr", output.stderr.trim_end());
+    }
+    (ok, entry)
+}
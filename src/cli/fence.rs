@@ -0,0 +1,98 @@
+//! `fence` - sync_file Fences untersuchen (eigene, geerbte oder eines Prozesses)
+
+use std::fs::File;
+use std::os::fd::AsRawFd;
+use std::time::Duration;
+
+use adreno_ioctl::features::generation;
+use adreno_ioctl::fence::{create_fence, find_sync_files, monotonic_now, sync_file_info, FenceStatus, SyncFileInfo};
+use adreno_ioctl::kgsl::read_gpu_info;
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::submit::Submitter;
+
+use super::{open_device_rw, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let fd = args.value("--fd")?;
+    let path = args.value("--path")?;
+    let pid = args.value("--pid")?;
+
+    if let Some(fd) = fd {
+        args.finish()?;
+        let fd: i32 = fd.parse().map_err(|_| Msg::InvalidValue { option: "--fd", value: &fd }.to_string())?;
+        return inspect(&format!("fd {}", fd), fd, None);
+    }
+    if let Some(path) = path {
+        args.finish()?;
+        let file = File::open(&path).map_err(|e| Msg::CannotOpen { path: &path, error: &e }.to_string())?;
+        return inspect(&path, file.as_raw_fd(), None);
+    }
+    if let Some(pid) = pid {
+        args.finish()?;
+        let pid: u32 = pid.parse().map_err(|_| Msg::InvalidValue { option: "--pid", value: &pid }.to_string())?;
+        return inspect_process(pid);
+    }
+    run_demo(args)
+}
+
+/// Alle sync_files eines Prozesses, z.B. bei hängenden Buffer Queues
+fn inspect_process(pid: u32) -> Result<(), String> {
+    let files = find_sync_files(pid).map_err(|e| Msg::CannotRead { path: &format!("/proc/{}/fd", pid), error: &e }.to_string())?;
+    println!("🔎 {}", Msg::FenceSyncFiles { count: &files.len(), pid: &pid });
+    for (fd, path) in files {
+        match File::open(&path) {
+            Ok(file) => inspect(&format!("fd {}", fd), file.as_raw_fd(), None)?,
+            Err(e) => println!("\n   {}", Msg::FenceCannotOpen { fd: &fd, error: &e }),
+        }
+    }
+    Ok(())
+}
+
+/// Eigene Fence auf einen NOP-Submit, vor und nach dem Signalisieren
+fn run_demo(mut args: Args) -> Result<(), String> {
+    let (path, file) = open_device_rw(&mut args)?;
+    args.finish()?;
+
+    let info = read_gpu_info(file.as_raw_fd())?;
+    let submitter = Submitter::new(&file, generation(info.chip_id))
+        .map_err(|e| Msg::CannotSetUpSubmission { error: &e }.to_string())?;
+    let ts = submitter.submit_nop().map_err(|e| Msg::SubmitFailed { error: &e }.to_string())?;
+    let fence = create_fence(file.as_raw_fd(), submitter.context_id(), ts)
+        .map_err(|e| Msg::FenceEventFailed { error: &e }.to_string())?;
+
+    println!("🧷 {}", Msg::FenceCreated { context: &submitter.context_id(), timestamp: &ts, path: &path });
+    inspect("right after submit", fence.as_raw_fd(), Some((submitter.context_id(), ts)))?;
+    let _ = submitter.wait(ts, Duration::from_secs(1));
+    inspect("after wait", fence.as_raw_fd(), Some((submitter.context_id(), ts)))
+}
+
+fn inspect(label: &str, fd: i32, owner: Option<(u32, u32)>) -> Result<(), String> {
+    let info = sync_file_info(fd).map_err(|e| Msg::FenceNotSyncFile { label: &label, error: &e }.to_string())?;
+    print_sync_file(label, &info, owner);
+    Ok(())
+}
+
+fn print_sync_file(label: &str, info: &SyncFileInfo, owner: Option<(u32, u32)>) {
+    println!("\n   {} - \"{}\" {}", label, info.name, status_text(info.status));
+    let now = monotonic_now();
+    for fence in &info.fences {
+        println!("   • {} [{}]", fence.obj_name, fence.driver_name);
+        println!("     {}", Msg::FenceStatus { status: &status_text(fence.status) });
+        if let Some(t) = fence.signal_time {
+            println!("     {}", Msg::FenceSignaled { ms: &(now.saturating_sub(t).as_secs_f64() * 1000.0) });
+        }
+        match (owner, fence.kgsl_context_id()) {
+            (Some((ctx, ts)), _) => println!("     {}", Msg::FenceOwner { context: &ctx, timestamp: &ts }),
+            (None, Some(ctx)) => println!("     {}", Msg::FenceOwnerKgsl { context: &ctx }),
+            (None, None) => {}
+        }
+    }
+}
+
+fn status_text(status: FenceStatus) -> String {
+    match status {
+        FenceStatus::Active => format!("⏳ {}", Msg::FenceActive),
+        FenceStatus::Signaled => format!("✅ {}", Msg::FenceSignaledState),
+        FenceStatus::Error(e) => format!("❌ {}", Msg::FenceError { code: &e, error: &std::io::Error::from_raw_os_error(-e) }),
+    }
+}
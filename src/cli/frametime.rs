@@ -0,0 +1,62 @@
+//! `frametime` - GPU-Frame-Zeiten einer App aus Retire-Timestamps
+
+use std::time::{Duration, Instant};
+
+use adreno_ioctl::frametime::{FrameTimer, JANK_FACTOR};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::queue::{ctx_dir, read_context_queues};
+
+use super::{device_path, install_interrupt_handler, interrupted, parse_duration, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let pid: u32 = args.parsed("--pid")?.ok_or("frametime needs --pid PID")?;
+    let duration = match args.value("--duration")? {
+        Some(d) => parse_duration(&d)?,
+        None => Duration::from_secs(10),
+    };
+    let poll = match args.value("--poll")? {
+        Some(p) => parse_duration(&p)?,
+        None => Duration::from_millis(1),
+    };
+    let merge = match args.value("--merge")? {
+        Some(m) => parse_duration(&m)?,
+        None => Duration::from_millis(2),
+    };
+    let path = device_path(&mut args)?;
+    args.finish()?;
+
+    if !read_context_queues(&path).iter().any(|q| q.pid == Some(pid)) {
+        return Err(Msg::FrametimeNoContexts { pid: &pid, dir: &ctx_dir(&path).display() }.to_string());
+    }
+
+    install_interrupt_handler();
+    println!("🎞️  {}", Msg::FrametimeMeasuring {
+        pid: &pid,
+        secs: &duration.as_secs_f64(),
+        poll: &poll.as_micros(),
+        merge: &merge.as_millis(),
+    });
+
+    let mut timer = FrameTimer::new(pid, merge);
+    let start = Instant::now();
+    while start.elapsed() < duration && !interrupted() {
+        timer.observe(Instant::now(), &read_context_queues(&path));
+        std::thread::sleep(poll);
+    }
+    // Letzten offenen Frame abschließen
+    timer.observe(Instant::now() + merge * 2, &[]);
+
+    let stats = timer.stats().ok_or_else(|| Msg::FrametimeNoFrames.to_string())?;
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+    println!("\n   • {}", Msg::FrametimeContexts { count: &timer.contexts() });
+    println!("   • {}", Msg::FrametimeFrames { frames: &stats.frames, fps: &stats.fps() });
+    println!("   • {}", Msg::FrametimeLatency {
+        avg: &ms(stats.avg),
+        median: &ms(stats.latency.median),
+        p99: &ms(stats.latency.p99),
+        max: &ms(stats.latency.max),
+    });
+    println!("   • {}", Msg::FrametimeJanks { janks: &stats.janks, factor: &JANK_FACTOR });
+    println!("   ℹ️  {}", Msg::FrametimeBasis);
+    Ok(())
+}
@@ -0,0 +1,342 @@
+//! `get` - Einzelne Werte ohne Dekoration ausgeben (für Skripte und Widgets)
+//!
+//! Entweder ein Feld (`get freq`) oder eine Vorlage
+//! (`get --format "{model} {freq_mhz}MHz {busy}%"`). Unveränderliche Werte
+//! kommen aus dem Cache, solange `--no-cache` fehlt.
+
+use std::fs::File;
+use std::os::fd::AsRawFd;
+
+use adreno_ioctl::cache::{cache_path, FactCache};
+use adreno_ioctl::chip::decode_chip_id;
+use adreno_ioctl::gmem::read_gmem;
+use adreno_ioctl::kgsl::{read_gpu_info, read_gpu_model, read_gpu_version, KgslDeviceInfo};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::sysfs;
+use adreno_ioctl::sysroot;
+use adreno_ioctl::template;
+
+use super::{default_device, fail, io_exit_code, open_path, print_value, Args, EXIT_FAILURE, EXIT_UNSUPPORTED};
+
+/// Platzhalter für `--format` mit Beschreibung
+pub const PLACEHOLDERS: &[(&str, &str)] = &[
+    ("model", "GPU model name, e.g. Adreno 740"),
+    ("chip_id", "raw chip id in hex"),
+    ("device_id", "KGSL device id"),
+    ("driver_version", "KGSL driver version in hex"),
+    ("gmem_size", "GMEM size in bytes"),
+    ("gmem_kb", "GMEM size in KB"),
+    ("busy", "current GPU busy percentage"),
+    ("freq_mhz", "current GPU clock in MHz"),
+    ("freq_hz", "current GPU clock in Hz"),
+    ("max_freq_mhz", "highest available GPU clock in MHz"),
+    ("device", "device node path"),
+];
+
+/// Felder für `get FIELD` und der zugehörige Platzhalter
+pub const FIELDS: &[(&str, &str)] = &[
+    ("model", "model"),
+    ("chip-id", "chip_id"),
+    ("device-id", "device_id"),
+    ("driver-version", "driver_version"),
+    ("gmem-size", "gmem_size"),
+    ("busy", "busy"),
+    ("freq", "freq_mhz"),
+    ("max-freq", "max_freq_mhz"),
+];
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let format = args.value("--format")?;
+    let no_cache = args.flag("--no-cache");
+    let field = args.positional();
+
+    let template = match (format, field) {
+        (Some(format), None) => format,
+        (None, Some(field)) => match FIELDS.iter().find(|(name, _)| *name == field) {
+            Some((_, key)) => format!("{{{}}}", key),
+            None => return Err(Msg::GetUnknownField { field: &field, names: &field_names() }.to_string()),
+        },
+        (Some(_), Some(_)) => return Err(Msg::GetFieldAndFormat.to_string()),
+        (None, None) => {
+            print_placeholders();
+            return Err(Msg::GetNeedsField.to_string());
+        }
+    };
+
+    parse_template(&template)?;
+    let device = args.value("--device")?;
+    args.finish()?;
+
+    // Einzelwert: ein open, ein ioctl bzw. sysfs-Read, keine Allokation
+    if let Some(key) = template.strip_prefix('{').and_then(|t| t.strip_suffix('}'))
+        && !sysroot::active()
+        && fast::print(key, device.as_deref())
+    {
+        return Ok(());
+    }
+    let path = match device {
+        Some(path) => path,
+        None => default_device()?,
+    };
+
+    let mut values = Values::new(path);
+    // Der Cache-Schlüssel kennt nur Gerätepfad und Host-Kernel, nicht den fremden Baum
+    let mut cache = (!no_cache && !sysroot::active()).then(|| FactCache::load(&cache_path()));
+    let text = render(&template, |key| {
+        if let Some(value) = cache.as_ref().and_then(|c| c.get(&values.path, key)) {
+            return Ok(value.to_string());
+        }
+        let value = values.get(key)?;
+        if let Some(cache) = cache.as_mut() {
+            cache.insert(&values.path, key, &value);
+        }
+        Ok(value)
+    })?;
+    print_value(&text);
+    // Ein nicht beschreibbarer Cache ist kein Fehler, nur langsamer
+    if let Some(cache) = cache.as_mut() {
+        let _ = cache.save();
+    }
+    Ok(())
+}
+
+fn field_names() -> String {
+    FIELDS.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(", ")
+}
+
+fn print_placeholders() {
+    eprintln!("{}", Msg::GetFields { names: &field_names() });
+    eprintln!("\n{}", Msg::GetPlaceholders);
+    for (name, about) in PLACEHOLDERS {
+        eprintln!("   {{{:<14} {}", format!("{}}}", name), about);
+    }
+}
+
+// ============================================================================
+// Vorlagen
+// ============================================================================
+
+/// Zerlegt eine Vorlage; nur die Platzhalter aus `PLACEHOLDERS` sind erlaubt
+fn parse_template(template: &str) -> Result<Vec<(bool, String)>, String> {
+    let names: Vec<&str> = PLACEHOLDERS.iter().map(|(name, _)| *name).collect();
+    template::parse(template, &names)
+}
+
+/// Setzt die Werte ein; alle Platzhalter werden vorher geprüft
+fn render(template: &str, mut lookup: impl FnMut(&str) -> Result<String, String>) -> Result<String, String> {
+    let mut out = String::new();
+    for (is_key, part) in parse_template(template)? {
+        match is_key {
+            true => out.push_str(&lookup(&part)?),
+            false => out.push_str(&part),
+        }
+    }
+    Ok(out)
+}
+
+// ============================================================================
+// Werte
+// ============================================================================
+
+/// Liest Werte bei Bedarf; das Gerät wird nur für Properties geöffnet
+struct Values {
+    path: String,
+    device: Option<(File, KgslDeviceInfo)>,
+}
+
+impl Values {
+    fn new(path: String) -> Self {
+        Values { path, device: None }
+    }
+
+    fn device(&mut self) -> Result<&(File, KgslDeviceInfo), String> {
+        if self.device.is_none() {
+            let file = open_path(&self.path)?;
+            let info = read_gpu_info(file.as_raw_fd()).map_err(|e| fail(EXIT_UNSUPPORTED, e))?;
+            self.device = Some((file, info));
+        }
+        Ok(self.device.as_ref().unwrap())
+    }
+
+    fn get(&mut self, key: &str) -> Result<String, String> {
+        let dir = sysfs::device_dir(&self.path);
+        let read_error = |e: std::io::Error| fail(io_exit_code(&e), Msg::CannotReadFrom { key: &key, path: &dir.display(), error: &e }.to_string());
+
+        Ok(match key {
+            "device" => self.path.clone(),
+            "busy" => format!("{:.0}", sysfs::busy_percent(&dir).map_err(read_error)?),
+            "freq_mhz" => (sysfs::gpuclk(&dir).map_err(read_error)? / 1_000_000).to_string(),
+            "freq_hz" => sysfs::gpuclk(&dir).map_err(read_error)?.to_string(),
+            "max_freq_mhz" => {
+                let max = sysfs::available_frequencies(&dir).map_err(read_error)?.into_iter().max();
+                (max.ok_or_else(|| fail(EXIT_FAILURE, Msg::NoFrequencies.to_string()))? / 1_000_000).to_string()
+            }
+            _ => {
+                let (file, info) = self.device()?;
+                let fd = file.as_raw_fd();
+                let chip = decode_chip_id(info.chip_id);
+                match key {
+                    "model" => read_gpu_model(fd).unwrap_or(chip.model_name),
+                    "chip_id" => format!("0x{:08x}", info.chip_id),
+                    "device_id" => info.device_id.to_string(),
+                    "driver_version" => {
+                        let version = read_gpu_version(fd).map_err(|e| fail(EXIT_UNSUPPORTED, e))?;
+                        format!("0x{:08x}", version.driver_version)
+                    }
+                    _ => {
+                        let gmem = read_gmem(fd, &chip)
+                            .ok_or_else(|| fail(EXIT_UNSUPPORTED, Msg::GmemUnknown { model: &chip.model_name }.to_string()))?;
+                        match key {
+                            "gmem_kb" => (gmem.size_bytes / 1024).to_string(),
+                            _ => gmem.size_bytes.to_string(),
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+
+// ============================================================================
+// Schnellpfad
+// ============================================================================
+
+/// Einzelwerte für Widgets ohne Geräte-Suche, Cache und Allokationen
+///
+/// Jeder Fehler fällt auf den normalen Pfad zurück, der dann die passende
+/// Meldung und den Exit-Code liefert.
+mod fast {
+    use std::fs::File;
+    use std::io::{Read, Write};
+    use std::os::fd::AsRawFd;
+
+    use adreno_ioctl::kgsl::{read_property, KgslDeviceInfo};
+    use adreno_ioctl::propmap::Prop;
+    use adreno_ioctl::sysfs::KGSL_CLASS_DIR;
+
+    use super::print_value;
+
+    /// Gleiche Reihenfolge wie [`adreno_ioctl::kgsl::find_kgsl_devices`]
+    const DEFAULT_DEVICES: [&str; 2] = ["/dev/kgsl-3d0", "/dev/kgsl/kgsl-3d0"];
+
+    /// Gibt `key` aus, `false` wenn der normale Pfad übernehmen muss
+    pub fn print(key: &str, device: Option<&str>) -> bool {
+        let mut buf = [0u8; 64];
+        let len = match key {
+            "freq_mhz" => sysfs_u64(device, "gpuclk").and_then(|hz| format(&mut buf, format_args!("{}", hz / 1_000_000))),
+            "freq_hz" => sysfs_u64(device, "gpuclk").and_then(|hz| format(&mut buf, format_args!("{}", hz))),
+            "busy" => sysfs_u64(device, "gpu_busy_percentage").and_then(|p| format(&mut buf, format_args!("{}", p))),
+            "max_freq_mhz" => max_frequency(device).and_then(|hz| format(&mut buf, format_args!("{}", hz / 1_000_000))),
+            "chip_id" => device_info(device).and_then(|i| format(&mut buf, format_args!("0x{:08x}", i.chip_id))),
+            "device_id" => device_info(device).and_then(|i| format(&mut buf, format_args!("{}", i.device_id))),
+            "model" => model(device, &mut buf),
+            _ => None,
+        };
+        match len.and_then(|len| std::str::from_utf8(&buf[..len]).ok()) {
+            Some(text) => {
+                print_value(text);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn format(buf: &mut [u8], args: std::fmt::Arguments) -> Option<usize> {
+        let total = buf.len();
+        let mut cursor = &mut buf[..];
+        cursor.write_fmt(args).ok()?;
+        Some(total - cursor.len())
+    }
+
+    fn open_device(device: Option<&str>) -> Option<File> {
+        match device {
+            Some(path) => File::open(path).ok(),
+            None => DEFAULT_DEVICES.iter().find_map(|path| File::open(path).ok()),
+        }
+    }
+
+    fn device_info(device: Option<&str>) -> Option<KgslDeviceInfo> {
+        let file = open_device(device)?;
+        let info: KgslDeviceInfo = read_property(file.as_raw_fd(), Prop::DeviceInfo).ok()?;
+        (info.chip_id != 0 || info.device_id != 0).then_some(info)
+    }
+
+    /// Nur `KGSL_PROP_GPU_MODEL`; ältere Kernel gehen über die Chip-Datenbank
+    fn model(device: Option<&str>, buf: &mut [u8; 64]) -> Option<usize> {
+        let file = open_device(device)?;
+        let name: [u8; 32] = read_property(file.as_raw_fd(), Prop::GpuModel).ok()?;
+        let end = name.iter().position(|&b| b == 0).unwrap_or(name.len());
+        let text = std::str::from_utf8(&name[..end]).ok()?.trim();
+        if text.is_empty() {
+            return None;
+        }
+        buf[..text.len()].copy_from_slice(text.as_bytes());
+        Some(text.len())
+    }
+
+    /// Liest `/sys/class/kgsl/<gerät>/<file>` in einen Stack-Puffer
+    fn read_sysfs<'a>(device: Option<&str>, file: &str, out: &'a mut [u8]) -> Option<&'a str> {
+        let name = device.map_or("kgsl-3d0", |d| d.rsplit('/').next().unwrap_or(d));
+        let mut path = [0u8; 128];
+        let len = format(&mut path, format_args!("{}/{}/{}", KGSL_CLASS_DIR, name, file))?;
+        let mut f = File::open(std::str::from_utf8(&path[..len]).ok()?).ok()?;
+        let mut n = 0;
+        loop {
+            // Voller Puffer: Inhalt evtl. abgeschnitten, normaler Pfad übernimmt
+            if n == out.len() {
+                return None;
+            }
+            match f.read(&mut out[n..]).ok()? {
+                0 => break,
+                read => n += read,
+            }
+        }
+        std::str::from_utf8(&out[..n]).ok().map(str::trim)
+    }
+
+    fn sysfs_u64(device: Option<&str>, file: &str) -> Option<u64> {
+        let mut buf = [0u8; 64];
+        read_sysfs(device, file, &mut buf)?.split_whitespace().next()?.parse().ok()
+    }
+
+    fn max_frequency(device: Option<&str>) -> Option<u64> {
+        let mut buf = [0u8; 512];
+        read_sysfs(device, "gpu_available_frequencies", &mut buf)?
+            .split_whitespace()
+            .filter_map(|s| s.parse().ok())
+            .max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn template_parts_and_escapes() {
+        let parts = parse_template("{model} ({{chip}}: {chip_id})").unwrap();
+        let expected = [(false, ""), (true, "model"), (false, " ({chip}: "), (true, "chip_id"), (false, ")")];
+        assert_eq!(parts, expected.map(|(key, text)| (key, text.to_string())));
+        assert_eq!(parse_template("").unwrap(), [(false, String::new())]);
+    }
+
+    #[test]
+    fn template_errors() {
+        assert!(parse_template("{model").unwrap_err().starts_with("Unclosed placeholder {model"));
+        assert!(parse_template("{nope}").unwrap_err().starts_with("Unknown placeholder {nope}"));
+        assert!(parse_template("model}").unwrap_err().starts_with("Unmatched '}'"));
+    }
+
+    #[test]
+    fn render_checks_before_lookup() {
+        let mut asked = Vec::new();
+        let out = render("{model}/{gmem_kb} KB", |key| {
+            asked.push(key.to_string());
+            Ok(key.len().to_string())
+        });
+        assert_eq!(out.unwrap(), "5/7 KB");
+        assert_eq!(asked, ["model", "gmem_kb"]);
+        // Unbekannter Platzhalter am Ende: kein Wert wird gelesen
+        assert!(render("{model} {nope}", |_| panic!("lookup before validation")).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+//! `gmem` - GMEM-Konfiguration und daraus folgende Bin-Größen
+
+use std::os::fd::AsRawFd;
+
+use adreno_ioctl::chip::decode_chip_id;
+use adreno_ioctl::gmem::{bin_layout, read_gmem, GmemSource};
+use adreno_ioctl::kgsl::read_gpu_info;
+use adreno_ioctl::messages::Msg;
+
+use super::{open_device, Args};
+
+/// Typische Render-Ziel Kombinationen: Name, Bytes pro Pixel (inkl. MSAA)
+const TARGETS: [(&str, u32); 5] = [
+    ("RGBA8", 4),
+    ("RGBA8 + D24S8", 8),
+    ("RGBA16F + D32F", 12),
+    ("RGBA8 + D24S8 4x MSAA", 32),
+    ("2x RGBA8 + D24S8 (G-buffer)", 12),
+];
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let width: u32 = args.parsed("--width")?.unwrap_or(1920);
+    let height: u32 = args.parsed("--height")?.unwrap_or(1080);
+    let (path, file) = open_device(&mut args)?;
+    args.finish()?;
+
+    let info = read_gpu_info(file.as_raw_fd())?;
+    let chip = decode_chip_id(info.chip_id);
+    let gmem = read_gmem(file.as_raw_fd(), &chip)
+        .ok_or_else(|| Msg::GmemNoSource { model: &chip.model_name }.to_string())?;
+
+    println!("💾 {}", Msg::GmemHeader { path: &path, model: &chip.model_name });
+    let source = match gmem.source {
+        GmemSource::Driver => Msg::GmemFromDriver,
+        GmemSource::ChipDb => Msg::GmemFromDatabase,
+    };
+    println!("   • {}", Msg::GmemSize { kb: &(gmem.size_bytes / 1024), source: &source });
+    match gmem.base_addr {
+        Some(base) => println!("   • {}", Msg::GmemBase { base }),
+        None => println!("   • {}", Msg::GmemBaseShort { base: info.gmem_gpubaseaddr as u64 }),
+    }
+    if let (Some(ccus), Some(per_ccu)) = (gmem.num_ccu, gmem.bytes_per_ccu()) {
+        println!("   • {}", Msg::GmemSlices { ccus: &ccus, kb: &(per_ccu / 1024) });
+    }
+    let l = &gmem.limits;
+    println!("   • {}", Msg::GmemTileAlign {
+        w: &l.align_w,
+        h: &l.align_h,
+        max_w: &l.max_w,
+        max_h: &l.max_h,
+        pipes: &l.vsc_pipes,
+    });
+
+    println!("\n   {}", Msg::GmemBins { w: &width, h: &height });
+    println!("   {:<28} {:>5}  {:>11}  {:>6}", "Attachments", "B/px", "Tile", "Bins");
+    for (name, bpp) in TARGETS {
+        match bin_layout(gmem.size_bytes, bpp, l, width, height) {
+            Some(b) => println!(
+                "   {:<28} {:>5}  {:>11}  {:>6}",
+                name,
+                bpp,
+                format!("{}x{}", b.tile_w, b.tile_h),
+                format!("{}x{}", b.bins_x, b.bins_y)
+            ),
+            None => println!("   {:<28} {:>5}  {}", name, bpp, Msg::GmemNoFit),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,86 @@
+//! `health` - Heartbeat über Retire-Timestamps, erkennt eine hängende GPU
+
+use std::os::fd::AsRawFd;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use adreno_ioctl::features::generation;
+use adreno_ioctl::kgsl::read_gpu_info;
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::submit::{retired_since, Submitter};
+
+use super::{install_interrupt_handler, open_device_rw, parse_duration, sleep_interruptible, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let interval = match args.value("--interval")? {
+        Some(i) => parse_duration(&i)?,
+        None => Duration::from_secs(5),
+    };
+    let timeout = match args.value("--timeout")? {
+        Some(t) => parse_duration(&t)?,
+        None => Duration::from_secs(2),
+    };
+    let count = args.parsed::<u64>("--count")?;
+    let keep_going = args.flag("--keep-going");
+    let (path, file) = open_device_rw(&mut args)?;
+    args.finish()?;
+
+    let info = read_gpu_info(file.as_raw_fd())?;
+    let submitter = Submitter::new(&file, generation(info.chip_id))
+        .map_err(|e| Msg::CannotSetUpSubmission { error: &e }.to_string())?;
+
+    install_interrupt_handler();
+    println!(
+        "💓 {}",
+        Msg::HealthHeader {
+            path: &path,
+            interval: &interval.as_secs_f64(),
+            timeout: &timeout.as_secs_f64(),
+            context: &submitter.context_id(),
+        }
+    );
+
+    let mut alarms = 0u64;
+    let mut n = 0;
+    while count.is_none_or(|c| n < c) {
+        if n > 0 && !sleep_interruptible(interval) {
+            break;
+        }
+        n += 1;
+
+        let start = Instant::now();
+        let result = submitter.submit_nop().and_then(|ts| {
+            // Ein Timeout beim Warten ist kein Fehler - der Vergleich unten entscheidet
+            let _ = submitter.wait(ts, timeout);
+            Ok((ts, submitter.retired()?))
+        });
+        let latency = start.elapsed();
+
+        match result {
+            Ok((submitted, retired)) if retired_since(retired, submitted) => {
+                println!("   ✅ {}", Msg::HealthRetired { ts: &submitted, ms: &(latency.as_secs_f64() * 1000.0) });
+            }
+            Ok((submitted, retired)) => {
+                alarms += 1;
+                log_alarm(&Msg::HealthStuck { retired: &retired, submitted: &submitted, secs: &latency.as_secs_f64() });
+            }
+            Err(e) => {
+                alarms += 1;
+                log_alarm(&Msg::SubmissionFailed { error: &e });
+            }
+        }
+        if alarms > 0 && !keep_going {
+            break;
+        }
+    }
+
+    if alarms > 0 {
+        return Err(Msg::HealthFailed { alarms: &alarms }.to_string());
+    }
+    Ok(())
+}
+
+/// Alarm-Zeile auf stderr, mit Unix-Zeit für Logsammler
+fn log_alarm(message: &Msg) {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    eprintln!("   🚨 {}", Msg::HealthAlarm { time: &now, message });
+}
@@ -0,0 +1,53 @@
+//! `llc` - Nutzung des System-Cache (LLCC) durch die GPU
+
+use adreno_ioctl::devicetree::{find_gpu_node, DT_ROOT};
+use adreno_ioctl::llc::{read_llc_info, read_perfmon_dump};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::sysfs;
+use adreno_ioctl::sysroot;
+
+use super::{device_path, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let device = device_path(&mut args)?;
+    args.finish()?;
+
+    let node = find_gpu_node(&sysroot::resolve(DT_ROOT));
+    let info = read_llc_info(&sysfs::device_dir(&device), node.as_deref());
+
+    println!("🗄️  {}", Msg::LlcHeader { device: &device });
+    println!("   • {}", Msg::LlcDriver { state: if info.driver { &Msg::LlcBound } else { &Msg::LlcNotFound } });
+    if info.slices.is_empty() {
+        println!("   • {}", Msg::LlcNoSlices);
+    }
+    for slice in &info.slices {
+        let id = match (slice.usecase_id, slice.usecase_name()) {
+            (Some(id), Some(name)) => format!("LLCC_{} ({})", name, id),
+            (Some(id), None) => Msg::LlcUsecase { id: &id }.to_string(),
+            (None, _) => Msg::LlcIdUnknown.to_string(),
+        };
+        let state = match slice.enabled {
+            Some(true) => format!("✅ {}", Msg::Enabled),
+            Some(false) => format!("❌ {}", Msg::Disabled),
+            None => Msg::LlcStateUnknown.to_string(),
+        };
+        println!("   • {}", Msg::LlcSlice { name: &slice.name, id: &id, state: &state });
+    }
+    if !info.slices.is_empty() {
+        println!("   ➜ {}", if info.in_use() { Msg::LlcUsed } else { Msg::LlcUnused });
+    }
+
+    match &info.perfmon {
+        Some(perfmon) => match read_perfmon_dump(perfmon) {
+            Some(dump) => {
+                println!("\n   📊 {}", Msg::LlcCounters);
+                for line in dump.lines() {
+                    println!("   {}", line);
+                }
+            }
+            None => println!("\n   ℹ️  {}", Msg::LlcNoCounters { path: &perfmon.display() }),
+        },
+        None => println!("\n   ℹ️  {}", Msg::LlcNoPerfmon),
+    }
+    Ok(())
+}
@@ -0,0 +1,58 @@
+//! `lpac` - Low Priority Async Compute: Knoten, Property und Test-Kontext
+
+use std::os::unix::io::AsRawFd;
+
+use adreno_ioctl::json::Json;
+use adreno_ioctl::kgsl::DeviceKind;
+use adreno_ioctl::lpac::{self, LpacInfo};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::schema::versioned;
+
+use super::{open_device_rw, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let json = args.flag("--json");
+    let (path, file) = open_device_rw(&mut args)?;
+    args.finish()?;
+
+    let info = lpac::probe(file.as_raw_fd());
+    if !info.usable() {
+        super::set_exit_code(super::EXIT_UNSUPPORTED);
+    }
+    if json {
+        println!("{}", versioned(to_json(&path, &info)).to_pretty());
+        return Ok(());
+    }
+    if super::quiet() {
+        super::print_value(if info.usable() { "yes" } else { "no" });
+    }
+
+    println!("🧵 {}", Msg::LpacHeader { path: &path, kind: &DeviceKind::from_path(&path).label() });
+    if info.nodes.is_empty() {
+        println!("   • {}", Msg::LpacNoNode);
+    }
+    for node in &info.nodes {
+        println!("   • {}", Msg::LpacNode { node });
+    }
+    match info.enabled {
+        Some(true) => println!("   • {}", Msg::LpacEnabled),
+        Some(false) => println!("   • {}", Msg::LpacDisabled),
+        None => println!("   • {}", Msg::LpacNotReported),
+    }
+    match &info.context {
+        Ok(()) => println!("   • {}", Msg::LpacContext),
+        Err(e) => println!("   • {}", Msg::LpacContextFailed { error: e }),
+    }
+    Ok(())
+}
+
+fn to_json(path: &str, info: &LpacInfo) -> Json {
+    Json::object()
+        .field("device", path)
+        .field("kind", DeviceKind::from_path(path).label())
+        .field("nodes", info.nodes.clone())
+        .field("enabled", info.enabled)
+        .field("context", info.context.is_ok())
+        .field("context_error", info.context.as_ref().err().map(|e| e.to_string()))
+        .field("usable", info.usable())
+}
@@ -0,0 +1,752 @@
+//! Kommandozeile - einfache Subcommand/Flag Auswertung ohne externe Crates
+
+pub mod bench;
+pub mod blob;
+pub mod boost;
+pub mod bugreport;
+pub mod bus;
+pub mod allocflags;
+pub mod analyze;
+pub mod caps;
+pub mod collect;
+pub mod completions;
+pub mod contexts;
+pub mod cores;
+pub mod daemon;
+pub mod debugfs;
+pub mod driver;
+pub mod dt;
+pub mod events;
+pub mod explain;
+pub mod export;
+pub mod farm;
+pub mod fence;
+pub mod frametime;
+pub mod get;
+pub mod gmem;
+pub mod health;
+pub mod llc;
+pub mod lpac;
+pub mod monitor;
+pub mod overlay;
+pub mod plain;
+pub mod procmem;
+pub mod profile;
+pub mod reset_stat;
+pub mod restore;
+pub mod scan;
+pub mod sched;
+pub mod selftest;
+pub mod slumber;
+pub mod sparse;
+pub mod stress;
+pub mod submit;
+pub mod timesync;
+pub mod trace;
+pub mod triage;
+pub mod turnip;
+pub mod usermem;
+pub mod vamap;
+pub mod wait_idle;
+
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::os::fd::{AsFd, BorrowedFd, IntoRawFd, OwnedFd};
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use adreno_ioctl::affinity::Placement;
+use adreno_ioctl::baseline::Comparison;
+use adreno_ioctl::kgsl::{find_kgsl_devices, open_with_mode, AccessMode};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::platform;
+use adreno_ioctl::privilege::{self, Identity};
+use adreno_ioctl::sys;
+use adreno_ioctl::sysfs;
+use adreno_ioctl::sysroot;
+pub use adreno_ioctl::units::{parse_duration, parse_size};
+
+/// Beschreibung eines Subcommands für Hilfe-Ausgabe
+pub struct CommandSpec {
+    pub name: &'static str,
+    pub usage: &'static str,
+    pub about: &'static str,
+}
+
+/// Alle Subcommands
+pub const COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "info",
+        usage: "info [--json] [--record FILE | --replay FILE] [--device PATH]",
+        about: "Show GPU information (default)",
+    },
+    CommandSpec {
+        name: "allocflags",
+        usage: "allocflags [--size 64K] [--device PATH]",
+        about: "Try allocations with every flag combination, report alignment and mmap",
+    },
+    CommandSpec {
+        name: "analyze",
+        usage: "analyze FILE [--window 1m] [--json]",
+        about: "Per-window busy, frequency residency and throttle time of a monitor --sink csv/json recording",
+    },
+    CommandSpec {
+        name: "bench",
+        usage: "bench ioctl|sysfs [--iterations 200] [--ttl 100ms] [--device PATH]",
+        about: "Compare cached vs direct sysfs and ioctl reads",
+    },
+    CommandSpec {
+        name: "blob",
+        usage: "blob [--file PATH]",
+        about: "Adreno userspace driver release from the vendor libraries and updatable driver APK",
+    },
+    CommandSpec {
+        name: "boost",
+        usage: "boost [--duration 5s] [--level N] [--device PATH]",
+        about: "Temporarily raise the minimum GPU power level, then restore it",
+    },
+    CommandSpec {
+        name: "bugreport",
+        usage: "bugreport [--output FILE] [--device PATH]",
+        about: "Collect device report, kernel log, snapshot, sysfs and firmware hashes into one tarball",
+    },
+    CommandSpec {
+        name: "bus",
+        usage: "bus [--interval 1s] [--count N] [--device PATH]",
+        about: "Show DDR/interconnect bus votes next to the current GPU clock",
+    },
+    CommandSpec {
+        name: "caps",
+        usage: "caps [--device PATH]",
+        about: "Capability matrix: hardware supports / driver exposes / enabled",
+    },
+    CommandSpec {
+        name: "collect",
+        usage: "collect [--collectors LIST] [--plugin FILE.so] [--list] [--interval 1s] [--count 1] [--sink FORMAT[:TARGET]] [--device PATH]",
+        about: "Run the ioctl/sysfs/devfreq/thermal/debugfs/ftrace collectors (LIST: sysfs,thermal or -debugfs)",
+    },
+    CommandSpec {
+        name: "completions",
+        usage: "completions bash|zsh|fish",
+        about: "Print a shell completion script built from the command table",
+    },
+    CommandSpec {
+        name: "contexts",
+        usage: "contexts [--pid PID] [--json] [--device PATH]",
+        about: "List all GPU contexts system-wide with owner, priority, flags and timestamps",
+    },
+    CommandSpec {
+        name: "cores",
+        usage: "cores [--device PATH]",
+        about: "Shader processor, micro-TP and wave counts, theoretical GFLOPS, bus width and caches",
+    },
+    CommandSpec {
+        name: "daemon",
+        usage: "daemon [--interval 1s] [--max-interval 10s] [--history 10m] [--socket PATH] [--query REQUEST] [--http ADDR] [--user NAME | --keep-root] [--device PATH]",
+        about: "Sample in the background and answer history queries on a Unix socket",
+    },
+    CommandSpec {
+        name: "debugfs",
+        usage: "debugfs [--device PATH]",
+        about: "Memory lists, contexts and dispatcher state from KGSL debugfs (userdebug builds)",
+    },
+    CommandSpec {
+        name: "driver",
+        usage: "driver",
+        about: "Show kernel release, KGSL module version and parameters",
+    },
+    CommandSpec {
+        name: "dt",
+        usage: "dt [--device PATH]",
+        about: "Show the device tree power level and voltage table next to the runtime table",
+    },
+    CommandSpec {
+        name: "events",
+        usage: "events [--events freq,pwrlevel,throttle,reset] [--interval 50ms] [--count N] [--ftrace] [--sink FORMAT[:TARGET]]... [--device PATH]",
+        about: "Stream clock, power level, throttling and reset events instead of samples",
+    },
+    CommandSpec {
+        name: "explain",
+        usage: "explain 0xc0140902",
+        about: "Decode an ioctl number: direction, type, command, size and KGSL name",
+    },
+    CommandSpec {
+        name: "export",
+        usage: "export --vkjson [--output FILE] [--device PATH]",
+        about: "Export hardware facts as vulkaninfo/devsim-style JSON for capability databases",
+    },
+    CommandSpec {
+        name: "fence",
+        usage: "fence [--fd N | --path /proc/PID/fd/N | --pid PID] [--device PATH]",
+        about: "Inspect sync_file fences: status, signal time, owning context",
+    },
+    CommandSpec {
+        name: "frametime",
+        usage: "frametime --pid PID [--duration 10s] [--poll 1ms] [--merge 2ms] [--device PATH]",
+        about: "Estimate GPU frame times (avg/p99/janks) of an app from its retire timestamps",
+    },
+    CommandSpec {
+        name: "get",
+        usage: "get FIELD | --format \"{model} {freq_mhz}MHz {busy}%\" [--no-cache] [--device PATH]",
+        about: "Print a single value without decoration, for scripts and widgets",
+    },
+    CommandSpec {
+        name: "gmem",
+        usage: "gmem [--width 1920] [--height 1080] [--device PATH]",
+        about: "GMEM size, slices and base address, with implied bin sizes",
+    },
+    CommandSpec {
+        name: "health",
+        usage: "health [--interval 5s] [--timeout 2s] [--count N] [--keep-going] [--device PATH]",
+        about: "Submit a no-op every interval and alarm if the GPU stops retiring work",
+    },
+    CommandSpec {
+        name: "llc",
+        usage: "llc [--device PATH]",
+        about: "Show whether the GPU uses the system cache (LLCC) slices",
+    },
+    CommandSpec {
+        name: "lpac",
+        usage: "lpac [--json] [--device PATH]",
+        about: "Detect the low priority async compute queue or node and test a context",
+    },
+    CommandSpec {
+        name: "monitor",
+        usage: "monitor [--interval 1s] [--max-interval DUR] [--count N] [--power] [--with-battery] [--queue] [--preempt] [--ifpc] [--waits] [--alert RULE] [--alerts FILE] [--sink FORMAT[:TARGET]] [--user NAME | --keep-root] [--all-devices | --device PATH]",
+        about: "Sample frequency, busy %, GPU interrupt rates and estimated power",
+    },
+    CommandSpec {
+        name: "overlay",
+        usage: "overlay [--interval 50ms] [--path FILE] [--read] [--device PATH]",
+        about: "Publish freq/busy/temp as a 64-byte shared-memory record for FPS overlays",
+    },
+    CommandSpec {
+        name: "procmem",
+        usage: "procmem [--watch] [--interval 1s] [--min-change 1M] [--count N] [--json | --sink FORMAT[:TARGET]]",
+        about: "GPU memory per process; --watch emits start/grow/shrink/exit events",
+    },
+    CommandSpec {
+        name: "profile",
+        usage: "profile apply|show|reset [NAME] [--device PATH]",
+        about: "Apply, inspect or undo presets: powersave, balanced, performance, benchmark",
+    },
+    CommandSpec {
+        name: "reset-stat",
+        usage: "reset-stat [--device PATH]",
+        about: "Check GL_EXT_robustness style reset status on a context of this process",
+    },
+    CommandSpec {
+        name: "restore",
+        usage: "restore",
+        about: "Roll back settings left modified by a crashed instance",
+    },
+    CommandSpec {
+        name: "scan",
+        usage: "scan [--baseline FILE] [--output FILE] [--ioctls] [--json] [--device PATH]",
+        about: "Query every property id with its size and bytes, diff against a stored scan, or map implemented ioctls",
+    },
+    CommandSpec {
+        name: "sched",
+        usage: "sched [--interval 1s] [--count N] [--device PATH]",
+        about: "CPU scheduling stats of the kgsl worker threads and dispatcher",
+    },
+    CommandSpec {
+        name: "selftest",
+        usage: "selftest [--device PATH]",
+        about: "Quick functional test: contexts, memory, submission, fences",
+    },
+    CommandSpec {
+        name: "slumber",
+        usage: "slumber [--cycles 20] [--idle 20ms] [--timeout 2s] [--device PATH]",
+        about: "Force repeated GPU slumber/resume and check properties and timestamps stay consistent",
+    },
+    CommandSpec {
+        name: "sparse",
+        usage: "sparse [--size 1M] [--pagesize 64K] [--device PATH]",
+        about: "Probe and exercise the sparse memory bind/unbind ioctls",
+    },
+    CommandSpec {
+        name: "stress",
+        usage: "stress mem [--size 1GB] [--iterations 100] [--device PATH]",
+        about: "Allocate, fill, sync, verify and free GPU memory in a loop",
+    },
+    CommandSpec {
+        name: "submit",
+        usage: "submit [--out result.json] [--no-bench] [--device PATH]",
+        about: "Write an anonymized result bundle for the community device database (offline)",
+    },
+    CommandSpec {
+        name: "timesync",
+        usage: "timesync [--samples 32] [--interval 5ms] [--device PATH]",
+        about: "Calibrate GPU ticks against CLOCK_MONOTONIC via the QTimer and show the conversion",
+    },
+    CommandSpec {
+        name: "trace",
+        usage: "trace --pid PID [--count N] [--record FILE]",
+        about: "Attach to a process (root) and decode its KGSL ioctls as they happen",
+    },
+    CommandSpec {
+        name: "triage",
+        usage: "triage [--log FILE] [--snapshot FILE] [--device PATH]",
+        about: "After a GPU reset, correlate kernel log, snapshot and contexts to name the guilty process",
+    },
+    CommandSpec {
+        name: "turnip",
+        usage: "turnip [--device PATH]",
+        about: "Check whether the open-source Turnip Vulkan driver supports this GPU and what it needs",
+    },
+    CommandSpec {
+        name: "usermem",
+        usage: "usermem [--size 64K] [--device PATH]",
+        about: "Import a CPU buffer into the GPU address space (zero-copy)",
+    },
+    CommandSpec {
+        name: "vamap",
+        usage: "vamap [--max-id 1024] [--demo N] [--json] [--device PATH]",
+        about: "Map this process's GPU address space: objects, sizes, flags, gaps",
+    },
+    CommandSpec {
+        name: "wait-idle",
+        usage: "wait-idle [--timeout 10s] [--device PATH]",
+        about: "Wait until all submitted GPU work has retired (exit 1 on timeout)",
+    },
+    CommandSpec {
+        name: "help",
+        usage: "help",
+        about: "Show this help",
+    },
+];
+
+pub fn print_help() {
+    println!("{}\n", Msg::HelpUsage);
+    println!("{}", Msg::HelpCommands);
+    for cmd in COMMANDS {
+        println!("   {:<10} {}", cmd.name, cmd.about);
+        println!("   {:<10} adreno_ioctl {}", "", cmd.usage);
+    }
+    println!("\n{}", Msg::HelpGlobalOptions);
+    for (flag, about) in GLOBAL_OPTIONS {
+        println!("   {:<17} {}", flag, about);
+    }
+    println!("\n{}", Msg::HelpExitCodes);
+    for (code, about) in EXIT_CODES {
+        println!("   {}  {}", code, about);
+    }
+}
+
+/// Optionen, die vor oder nach jedem Subcommand stehen dürfen
+pub const GLOBAL_OPTIONS: &[(&str, &str)] = &[
+    ("--quiet, -q", "Print only the requested value, report status via exit code"),
+    ("--lang de|en", "Language of messages (default English, also ADRENO_IOCTL_LANG)"),
+    ("--plain", "Pure ASCII output (also with NO_COLOR or when stdout is not a terminal)"),
+    ("--adb SERIAL", "Run the command on adb devices instead, one JSON report (repeatable, 'all' for every device)"),
+    ("--adb-binary FILE", "Binary to push with --adb (default this program; needs an Android build)"),
+    ("--sysroot DIR", "Read /dev, /sys, /proc, /vendor, /system and /data below DIR, e.g. a tree captured on another device (also ADRENO_IOCTL_SYSROOT)"),
+    ("--read-only", "Never open the device for writing; commands that allocate or submit fail with exit code 3"),
+    ("--cpus LIST|little", "Pin this process and its sampling threads to CPUs, e.g. 0-3 (also ADRENO_IOCTL_CPUS)"),
+    ("--nice N", "Run at nice level N, e.g. 10 to yield to the measured workload (also ADRENO_IOCTL_NICE)"),
+    ("--man", "Print a man page (troff) built from the command table"),
+    ("--schema", "Print the JSON Schema of all JSON outputs (schema_version and compatibility rules)"),
+];
+
+// ============================================================================
+// Exit-Codes und Ausgabe
+// ============================================================================
+
+pub const EXIT_OK: i32 = 0;
+pub const EXIT_FAILURE: i32 = 1;
+pub const EXIT_NO_DEVICE: i32 = 2;
+pub const EXIT_PERMISSION: i32 = 3;
+pub const EXIT_UNSUPPORTED: i32 = 4;
+pub const EXIT_PARTIAL: i32 = 5;
+pub const EXIT_ALERT: i32 = 6;
+
+/// Exit-Codes mit Beschreibung für Hilfe und Man-Page
+pub const EXIT_CODES: &[(i32, &str)] = &[
+    (EXIT_OK, "success"),
+    (EXIT_FAILURE, "error"),
+    (EXIT_NO_DEVICE, "no KGSL device found"),
+    (EXIT_PERMISSION, "permission denied"),
+    (EXIT_UNSUPPORTED, "kernel does not support the request"),
+    (EXIT_PARTIAL, "partial data (some values could not be read)"),
+    (EXIT_ALERT, "an alert rule with the exit action fired"),
+];
+
+static EXIT_CODE: AtomicI32 = AtomicI32::new(EXIT_OK);
+
+/// Merkt einen Exit-Code vor; ein echter Fehler überschreibt "partial"
+pub fn set_exit_code(code: i32) {
+    let current = EXIT_CODE.load(Ordering::SeqCst);
+    if current == EXIT_OK || current == EXIT_PARTIAL {
+        EXIT_CODE.store(code, Ordering::SeqCst);
+    }
+}
+
+/// Vorgemerkter Exit-Code
+pub fn exit_code() -> i32 {
+    EXIT_CODE.load(Ordering::SeqCst)
+}
+
+/// Fehlermeldung mit vorgemerktem Exit-Code
+pub fn fail(code: i32, message: impl Into<String>) -> String {
+    set_exit_code(code);
+    message.into()
+}
+
+/// Exit-Code passend zu einem Fehler des Kernels
+pub fn io_exit_code(e: &io::Error) -> i32 {
+    match e.raw_os_error() {
+        Some(libc::EACCES | libc::EPERM) => EXIT_PERMISSION,
+        Some(libc::ENOENT | libc::ENODEV | libc::ENXIO) => EXIT_NO_DEVICE,
+        Some(libc::ENOTTY | libc::EINVAL | libc::EOPNOTSUPP) => EXIT_UNSUPPORTED,
+        _ => EXIT_FAILURE,
+    }
+}
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+static VALUE_FD: AtomicI32 = AtomicI32::new(libc::STDOUT_FILENO);
+
+/// Leitet die normale Ausgabe nach /dev/null um, nur [`print_value`] bleibt sichtbar
+pub fn enable_quiet() {
+    let (Ok(saved), Ok(null)) = (sys::dup(libc::STDOUT_FILENO), OpenOptions::new().write(true).open("/dev/null")) else {
+        return;
+    };
+    if sys::dup2(null.as_fd(), libc::STDOUT_FILENO).is_err() {
+        return;
+    }
+    VALUE_FD.store(saved.into_raw_fd(), Ordering::SeqCst);
+    QUIET.store(true, Ordering::SeqCst);
+}
+
+/// Ob `--quiet` aktiv ist
+pub fn quiet() -> bool {
+    QUIET.load(Ordering::SeqCst)
+}
+
+/// Einordnung gegen die Erfahrungswerte, Warnung nur in die schlechte Richtung
+pub fn print_comparison(comparison: &Comparison) {
+    let icon = match (comparison.verdict.is_typical(), comparison.is_worse()) {
+        (true, _) => "✅",
+        (false, true) => "⚠️ ",
+        (false, false) => "ℹ️ ",
+    };
+    println!("   {} {}", icon, comparison);
+}
+
+/// Gibt einen angefragten Wert aus, auch im Quiet-Modus
+pub fn print_value(text: &str) {
+    let fd = VALUE_FD.load(Ordering::SeqCst);
+    if fd == libc::STDOUT_FILENO {
+        println!("{}", text);
+        return;
+    }
+    // Ohne Zwischenpuffer, `get` soll nichts allozieren
+    let _ = sys::writev(fd, &[text.as_bytes(), b"\n"]);
+}
+
+// ============================================================================
+// Argumente
+// ============================================================================
+
+/// Restliche Argumente nach dem Subcommand
+pub struct Args {
+    rest: Vec<String>,
+}
+
+impl Args {
+    pub fn new(rest: Vec<String>) -> Self {
+        Args { rest }
+    }
+
+    /// Entfernt eine Option mit Wert (`--name value` oder `--name=value`)
+    pub fn value(&mut self, name: &str) -> Result<Option<String>, String> {
+        let prefix = format!("{}=", name);
+        for i in 0..self.rest.len() {
+            if self.rest[i] == name {
+                if i + 1 >= self.rest.len() {
+                    return Err(Msg::RequiresValue { option: name }.to_string());
+                }
+                let value = self.rest.remove(i + 1);
+                self.rest.remove(i);
+                return Ok(Some(value));
+            }
+            if let Some(v) = self.rest[i].strip_prefix(&prefix) {
+                let value = v.to_string();
+                self.rest.remove(i);
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Alle Werte einer wiederholbaren Option
+    pub fn values(&mut self, name: &str) -> Result<Vec<String>, String> {
+        let mut values = Vec::new();
+        while let Some(value) = self.value(name)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    /// Wie [`Args::value`], aber mit `FromStr` geparst
+    pub fn parsed<T: std::str::FromStr>(&mut self, name: &str) -> Result<Option<T>, String> {
+        match self.value(name)? {
+            Some(v) => v.parse().map(Some).map_err(|_| Msg::InvalidValue { option: name, value: &v }.to_string()),
+            None => Ok(None),
+        }
+    }
+
+    /// Entfernt das erste Argument, falls es keine Option ist
+    pub fn positional(&mut self) -> Option<String> {
+        match self.rest.first() {
+            Some(arg) if !arg.starts_with("--") => Some(self.rest.remove(0)),
+            _ => None,
+        }
+    }
+
+    /// Entfernt einen Schalter ohne Wert, `true` wenn vorhanden
+    pub fn flag(&mut self, name: &str) -> bool {
+        match self.rest.iter().position(|a| a == name) {
+            Some(i) => {
+                self.rest.remove(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Fehler, falls unbekannte Argumente übrig sind
+    pub fn finish(self) -> Result<(), String> {
+        match self.rest.first() {
+            Some(arg) => Err(Msg::UnknownArgument { arg }.to_string()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Lesbare Größe, z.B. "64 KB" oder "1.5 MB"
+pub fn format_size(bytes: u64) -> String {
+    match bytes {
+        b if b >= 1 << 30 => format!("{:.1} GB", b as f64 / (1u64 << 30) as f64),
+        b if b >= 1 << 20 => format!("{:.1} MB", b as f64 / (1u64 << 20) as f64),
+        b if b >= 1 << 10 => format!("{} KB", b >> 10),
+        b => format!("{} B", b),
+    }
+}
+
+// ============================================================================
+// Gerät
+// ============================================================================
+
+/// `--device PATH` oder das erste gefundene KGSL-Gerät
+pub fn device_path(args: &mut Args) -> Result<String, String> {
+    match args.value("--device")? {
+        Some(p) => Ok(p),
+        None => default_device(),
+    }
+}
+
+/// "Keine Geräte" mit Erklärung für dieses System (nicht beim Lesen eines fremden Baums)
+pub fn no_devices_message() -> String {
+    if sysroot::active() {
+        return Msg::NoDevices.to_string();
+    }
+    format!("{}\n   💡 {}", Msg::NoDevices, platform::no_device_hint())
+}
+
+/// Das erste gefundene KGSL-Gerät
+pub fn default_device() -> Result<String, String> {
+    find_kgsl_devices()
+        .into_iter()
+        .next()
+        .ok_or_else(|| fail(EXIT_NO_DEVICE, no_devices_message()))
+}
+
+/// Öffnet `--device PATH` oder das erste gefundene KGSL-Gerät
+pub fn open_device(args: &mut Args) -> Result<(String, File), String> {
+    let path = device_path(args)?;
+    let file = open_path(&path)?;
+    Ok((path, file))
+}
+
+/// Öffnet ein Gerät lesend, Fehler mit passendem Exit-Code
+pub fn open_path(path: &str) -> Result<File, String> {
+    open_with_mode(&sysroot::resolve(path), AccessMode::ReadOnly)
+        .map_err(|e| fail(io_exit_code(&e), Msg::CannotOpen { path, error: &e }.to_string()))
+}
+
+/// Wie [`open_device`], aber lesend und schreibend: für Kommandos, die
+/// Speicher anlegen, Contexts erzeugen oder einreichen
+pub fn open_device_rw(args: &mut Args) -> Result<(String, File), String> {
+    let path = device_path(args)?;
+    if read_only() {
+        return Err(fail(EXIT_PERMISSION, Msg::ReadOnly.to_string()));
+    }
+    let file = open_with_mode(&sysroot::resolve(&path), AccessMode::ReadWrite)
+        .map_err(|e| fail(io_exit_code(&e), Msg::CannotOpen { path: &path, error: &e }.to_string()))?;
+    Ok((path, file))
+}
+
+static PLACEMENT: Mutex<Option<Placement>> = Mutex::new(None);
+
+/// `--cpus`/`--nice` anwenden, bevor ein Kommando Threads startet
+pub fn place_process(placement: Placement) -> Result<(), String> {
+    if placement.is_empty() {
+        return Ok(());
+    }
+    placement.apply().map_err(|e| fail(EXIT_PERMISSION, e))?;
+    *PLACEMENT.lock().unwrap() = Some(placement);
+    Ok(())
+}
+
+/// Angewendete Platzierung für Statuszeilen
+pub fn placement() -> Option<Placement> {
+    PLACEMENT.lock().unwrap().clone()
+}
+
+static READ_ONLY: AtomicBool = AtomicBool::new(false);
+
+/// `--read-only`: kein Kommando darf das Gerät schreibend öffnen
+pub fn enable_read_only() {
+    READ_ONLY.store(true, Ordering::Relaxed);
+}
+
+pub fn read_only() -> bool {
+    READ_ONLY.load(Ordering::Relaxed)
+}
+
+// ============================================================================
+// Rechte
+// ============================================================================
+
+/// `--user NAME` und `--keep-root` der lang laufenden Modi
+pub struct PrivilegeDrop {
+    user: Option<String>,
+    keep_root: bool,
+}
+
+impl PrivilegeDrop {
+    pub fn from_args(args: &mut Args) -> Result<Self, String> {
+        let user = args.value("--user")?;
+        let keep_root = args.flag("--keep-root");
+        if user.is_some() && keep_root {
+            return Err(Msg::UserAndKeepRoot.to_string());
+        }
+        Ok(PrivilegeDrop { user, keep_root })
+    }
+
+    /// Zielbenutzer, wenn ein Wechsel ansteht (nur als root)
+    pub fn target(&self) -> Result<Option<Identity>, String> {
+        if self.keep_root {
+            return Ok(None);
+        }
+        if !privilege::is_root() {
+            return match &self.user {
+                Some(_) => Err(Msg::UserNeedsRoot.to_string()),
+                None => Ok(None),
+            };
+        }
+        privilege::lookup(self.user.as_deref().unwrap_or(privilege::DEFAULT_USER)).map(Some)
+    }
+
+    /// Hält die sysfs-Knoten der Geräte offen und wechselt auf [`Self::target`];
+    /// `None`, wenn nichts zu tun war
+    pub fn apply(&self, devices: &[&str]) -> Result<Option<Identity>, String> {
+        let Some(identity) = self.target()? else {
+            return Ok(None);
+        };
+        switch_user(&identity, devices)?;
+        Ok(Some(identity))
+    }
+}
+
+/// Wie [`PrivilegeDrop::apply`] mit bekanntem Ziel
+pub fn switch_user(identity: &Identity, devices: &[&str]) -> Result<(), String> {
+    for device in devices {
+        let dir = sysfs::device_dir(device);
+        sysfs::hold_dir(&dir);
+        sysfs::hold_dir(&dir.join("devfreq"));
+        for file in sysfs::temperature_files(&dir) {
+            let _ = sysfs::hold(file);
+        }
+    }
+    privilege::drop_to(identity).map_err(|e| Msg::CannotSwitchUser { user: identity, error: &e }.to_string())
+}
+
+// ============================================================================
+// Signale
+// ============================================================================
+
+/// Lese- und Schreibende der Weck-Pipe; der Handler kennt nur den Schreib-fd
+static WAKE_PIPE: OnceLock<Option<(OwnedFd, OwnedFd)>> = OnceLock::new();
+
+/// Fängt SIGINT/SIGTERM/SIGHUP ab, damit Aufräumcode laufen kann
+///
+/// Das Signal weckt über eine Pipe auch schlafende Threads, damit Warten
+/// ohne Polling auskommt (siehe [`sleep_interruptible`]).
+pub fn install_interrupt_handler() {
+    let wake = WAKE_PIPE.get_or_init(|| sys::pipe_nonblocking().ok()).as_ref().map(|(_, write)| write.as_fd());
+    sys::catch_signals(&[libc::SIGINT, libc::SIGTERM, libc::SIGHUP], wake);
+}
+
+/// Lässt blockierende Syscalls (z.B. `waitpid`) bei Abbruch-Signal mit
+/// `EINTR` zurückkehren statt sie neu zu starten
+pub fn interrupt_blocking_calls() {
+    for sig in [libc::SIGINT, libc::SIGTERM, libc::SIGHUP] {
+        sys::interrupt_syscalls_on(sig);
+    }
+}
+
+/// Ob ein Abbruch-Signal empfangen wurde
+pub fn interrupted() -> bool {
+    sys::signal_caught()
+}
+
+/// Schläft und bricht bei Signal ab
+///
+/// Gibt `false` zurück, wenn unterbrochen wurde. Nach
+/// [`install_interrupt_handler`] wartet der Thread auf der Weck-Pipe und
+/// wacht nur zum Ende oder beim Signal auf, sonst in Schritten von 100 ms.
+pub fn sleep_interruptible(duration: Duration) -> bool {
+    let step = Duration::from_millis(100);
+    let deadline = std::time::Instant::now() + duration;
+    loop {
+        if interrupted() {
+            return false;
+        }
+        let now = std::time::Instant::now();
+        if now >= deadline {
+            return true;
+        }
+        match wake_fd() {
+            Some(fd) => {
+                let _ = sys::poll_readable(fd, deadline - now);
+            }
+            None => std::thread::sleep(step.min(deadline - now)),
+        }
+    }
+}
+
+/// Wartet bis `fd` lesbar ist; `false` bei Abbruch-Signal
+pub fn wait_readable_interruptible(fd: BorrowedFd<'_>) -> bool {
+    let step = Duration::from_millis(100);
+    while !interrupted() {
+        let ready = match wake_fd() {
+            Some(wake) => sys::poll_readable_any(&[fd, wake], Duration::MAX).map(|i| i == Some(0)),
+            None => sys::poll_readable(fd, step),
+        };
+        match ready {
+            Ok(true) => return !interrupted(),
+            Ok(false) => {}
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(_) => std::thread::sleep(step),
+        }
+    }
+    false
+}
+
+/// Lesende der Weck-Pipe, falls eingerichtet
+fn wake_fd() -> Option<BorrowedFd<'static>> {
+    WAKE_PIPE.get().and_then(Option::as_ref).map(|(read, _)| read.as_fd())
+}
@@ -0,0 +1,523 @@
+//! `monitor` - Laufende Anzeige von Frequenz, Auslastung und GPU-Interrupts
+
+use std::fs::File;
+use std::os::fd::AsRawFd;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use adreno_ioctl::alert::{AlertAction, AlertEngine, AlertEvent, AlertRule, Metric};
+use adreno_ioctl::chip::{decode_chip_id, lookup_model};
+use adreno_ioctl::dmesg;
+use adreno_ioctl::kgsl::{find_kgsl_devices, read_gpu_info, read_gpu_model, read_interrupt_waits};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::monitor::{AdaptiveInterval, Monitor, Sample};
+use adreno_ioctl::power::{EnergyMeter, EstimateSource, PowerEstimate, PowerModel};
+use adreno_ioctl::sink::{self, Sink};
+use adreno_ioctl::summary::SessionSummary;
+use adreno_ioctl::sysfs;
+use adreno_ioctl::sysroot;
+
+use super::{
+    device_path, install_interrupt_handler, parse_duration, parse_size, sleep_interruptible, Args, PrivilegeDrop,
+};
+
+/// Inflight-Kommandos eines Contexts, ab denen eine App die GPU überfüttert
+/// (Standard-Limit des KGSL Dispatchers pro Context)
+const OVERFEED_INFLIGHT: u32 = 15;
+
+/// Ab dieser Rate gilt eine Interrupt-Leitung als "stürmend"
+const IRQ_STORM_PER_SEC: f64 = 20_000.0;
+
+/// Mit `--sink` gehört stdout den Datensätzen, Statuszeilen gehen nach stderr
+static STATUS_TO_STDERR: AtomicBool = AtomicBool::new(false);
+
+macro_rules! status {
+    ($($arg:tt)*) => {
+        if STATUS_TO_STDERR.load(Ordering::Relaxed) {
+            eprintln!($($arg)*)
+        } else {
+            println!($($arg)*)
+        }
+    };
+}
+
+/// Obergrenze des Intervalls im Leerlauf, wenn Sinks aktiv sind
+const DEFAULT_MAX_INTERVAL: Duration = Duration::from_secs(10);
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let interval = match args.value("--interval")? {
+        Some(i) => parse_duration(&i)?,
+        None => Duration::from_secs(1),
+    };
+    let max_interval = args.value("--max-interval")?.map(|i| parse_duration(&i)).transpose()?;
+    let count = args.parsed::<u64>("--count")?;
+    let power = args.flag("--power");
+    let with_battery = args.flag("--with-battery");
+    let queues = args.flag("--queue");
+    let preempt = args.flag("--preempt");
+    let ifpc = args.flag("--ifpc");
+    let waits = args.flag("--waits");
+    let all = args.flag("--all-devices");
+    let privileges = PrivilegeDrop::from_args(&mut args)?;
+    let mut sinks = args.values("--sink")?.iter().map(|spec| sink::open(spec)).collect::<Result<Vec<_>, _>>()?;
+    STATUS_TO_STDERR.store(!sinks.is_empty(), Ordering::Relaxed);
+    // Als Exporter (mit Sinks) läuft der Monitor oft stundenlang im Leerlauf
+    let mut pacing = match max_interval {
+        Some(max) => AdaptiveInterval::new(interval, max),
+        None if !sinks.is_empty() => AdaptiveInterval::new(interval, DEFAULT_MAX_INTERVAL),
+        None => AdaptiveInterval::fixed(interval),
+    };
+    let mut rules = args.values("--alert")?;
+    if let Some(file) = args.value("--alerts")? {
+        let text = std::fs::read_to_string(&file).map_err(|e| Msg::CannotRead { path: &file, error: &e }.to_string())?;
+        rules.extend(text.lines().map(str::trim).filter(|l| !l.is_empty() && !l.starts_with('#')).map(str::to_string));
+    }
+    let rules = rules
+        .iter()
+        .map(|rule| AlertRule::parse(rule, parse_duration, parse_size))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut alerts = AlertEngine::new(rules);
+    if all {
+        args.finish()?;
+        if !alerts.rules().is_empty() {
+            return Err(Msg::MonitorAlertSingle.to_string());
+        }
+        if power || with_battery || waits {
+            return Err(Msg::MonitorOptionsSingle.to_string());
+        }
+        return run_all_devices(pacing, count, queues, preempt, ifpc, &privileges, &mut sinks);
+    }
+    let path = device_path(&mut args)?;
+    args.finish()?;
+
+    install_interrupt_handler();
+    let mut monitor = Monitor::new(&path);
+    if with_battery {
+        monitor = monitor.with_battery();
+        match monitor.battery_dir() {
+            Some(dir) => status!("🔌 {}", Msg::MonitorBattery { path: &dir.display() }),
+            None => status!("⚠️  {}", Msg::MonitorNoBattery),
+        }
+    }
+    if queues {
+        monitor = monitor.with_queues(&path);
+        if adreno_ioctl::queue::read_context_queues(&path).is_empty() {
+            status!("⚠️  {}", Msg::MonitorNoQueues { dir: &adreno_ioctl::queue::ctx_dir(&path).display() });
+        }
+    }
+    if preempt {
+        monitor = monitor.with_preemption();
+        print_preemption_header(&monitor);
+    }
+    if waits {
+        // Retired-Zähler aus dem Memstore, sonst aus den Context-Queues
+        monitor = monitor.with_memstore(&path);
+        if !monitor.has_memstore() {
+            monitor = monitor.with_queues(&path);
+        }
+        print_waits_header(&path);
+    }
+    // Auslastung aus Always-On- und Busy-Zähler statt aus dem DCVS-Fenster
+    monitor = monitor.with_counters(&path);
+    if monitor.has_counters() {
+        status!("📊 {}", Msg::MonitorCounterBusy);
+    }
+    if ifpc {
+        monitor = monitor.with_ifpc();
+        print_ifpc_header(&monitor);
+    }
+    if alerts.uses(Metric::Mem) {
+        monitor = monitor.with_memory();
+    }
+    if alerts.uses(Metric::Resets) {
+        monitor = monitor.with_faults();
+    }
+    for rule in alerts.rules() {
+        status!("🚨 {}", Msg::MonitorAlertRule { rule });
+    }
+    // Gerät nur öffnen, wenn die Chip ID gebraucht wird - sysfs reicht sonst
+    let model = if power { Some(power_model(&path, &monitor)?) } else { None };
+    let mut meter = EnergyMeter::default();
+    if let Some(identity) = privileges.apply(&[&path])? {
+        status!("🔒 {}", Msg::DroppedRoot { user: &identity });
+    }
+
+    if let Some(placement) = super::placement() {
+        status!("📌 {}", Msg::SamplingOn { placement: &placement });
+    }
+    status!("📈 {}", Msg::MonitorStart { path: &path, secs: &interval.as_secs_f64() });
+    let mut prev = monitor.sample();
+    if prev.irqs.is_empty() {
+        status!("   {}", Msg::MonitorNoIrqs);
+    }
+
+    let start = prev.time;
+    let mut summary = SessionSummary::new();
+    summary.faults_start = dmesg::count_gpu_faults().ok();
+    summary.add(&prev);
+    let mut n = 0;
+    while count.is_none_or(|c| n < c) {
+        if !sleep_interruptible(pacing.current()) {
+            break;
+        }
+        let mut sample = monitor.sample();
+        if let Some(busy) = sample.counter_busy_percent(&prev) {
+            sample.busy_percent = Some(busy);
+        }
+        let backed_off = pacing.is_backed_off();
+        let next = pacing.update(&sample, monitor.frequencies());
+        if pacing.is_backed_off() != backed_off {
+            match backed_off {
+                false => status!("💤 {}", Msg::MonitorBackoff { secs: &pacing.max().as_secs_f64() }),
+                true => status!("⚡ {}", Msg::MonitorActive { secs: &next.as_secs_f64() }),
+            }
+        }
+        let estimate = model.and_then(|m| m.estimate(&prev, &sample, monitor.frequencies()));
+        if let Some(e) = &estimate {
+            meter.add(e, sample.time.duration_since(prev.time));
+        }
+        let elapsed = sample.time.duration_since(start);
+        if sinks.is_empty() {
+            print_suspend(None, &sample, &prev);
+            print_row(None, &sample, &prev, elapsed, estimate.as_ref());
+        } else {
+            let record = sample.to_record(&path, &prev, elapsed).field("power_mw", estimate.as_ref().map(|e| e.milliwatts));
+            sinks.write(&record).map_err(|e| Msg::CannotWriteRecord { error: &e }.to_string())?;
+        }
+        summary.add(&sample);
+        let stop = alerts.evaluate(&sample).iter().any(|event| handle_alert(&alerts, event, &path));
+        prev = sample;
+        n += 1;
+        if stop {
+            break;
+        }
+    }
+
+    sinks.finish().map_err(|e| Msg::CannotWriteRecord { error: &e }.to_string())?;
+    summary.faults_end = dmesg::count_gpu_faults().ok();
+    print_summary(None, &summary, monitor.frequencies());
+    if model.is_some() {
+        status!(
+            "\n🔋 {}",
+            Msg::MonitorEnergy {
+                joules: &meter.joules,
+                secs: &meter.elapsed.as_secs_f64(),
+                avg: &meter.average_mw(),
+                peak: &meter.peak_mw,
+            }
+        );
+    }
+    Ok(())
+}
+
+/// Führt die Aktion einer Regel aus, `true` wenn der Monitor enden soll
+fn handle_alert(alerts: &AlertEngine, event: &AlertEvent, device: &str) -> bool {
+    let rule = &alerts.rules()[event.rule];
+    let value = alerts.format_value(event);
+    if !event.firing {
+        eprintln!("   ✅ {}", Msg::MonitorAlertCleared { rule, value: &value });
+        return false;
+    }
+    eprintln!("   🚨 {}", Msg::MonitorAlert { rule, value: &value });
+    match &rule.action {
+        AlertAction::Log => false,
+        AlertAction::Exit => {
+            super::set_exit_code(super::EXIT_ALERT);
+            true
+        }
+        AlertAction::Exec(command) => {
+            let spawned = std::process::Command::new("sh")
+                .arg("-c")
+                .arg(command)
+                .env("ADRENO_ALERT_RULE", &rule.text)
+                .env("ADRENO_ALERT_METRIC", rule.metric.name())
+                .env("ADRENO_ALERT_VALUE", format!("{}", event.value))
+                .env("ADRENO_ALERT_DEVICE", device)
+                .spawn();
+            match spawned {
+                // Hook läuft weiter, ein Thread sammelt den Exit-Status ein
+                Ok(mut child) => drop(thread::spawn(move || child.wait())),
+                Err(e) => eprintln!("   ⚠️  {}", Msg::MonitorHookFailed { error: &e }),
+            }
+            false
+        }
+    }
+}
+
+/// Ein Thread pro Gerät, damit langsame sysfs-Knoten die anderen nicht bremsen
+fn run_all_devices(
+    pacing: AdaptiveInterval,
+    count: Option<u64>,
+    queues: bool,
+    preempt: bool,
+    ifpc: bool,
+    privileges: &PrivilegeDrop,
+    sinks: &mut Vec<Box<dyn Sink>>,
+) -> Result<(), String> {
+    let devices = find_kgsl_devices();
+    if devices.is_empty() {
+        return Err(super::fail(super::EXIT_NO_DEVICE, super::no_devices_message()));
+    }
+    let paths: Vec<&str> = devices.iter().map(String::as_str).collect();
+    if let Some(identity) = privileges.apply(&paths)? {
+        status!("🔒 {}", Msg::DroppedRoot { user: &identity });
+    }
+    install_interrupt_handler();
+    if let Some(placement) = super::placement() {
+        status!("📌 {}", Msg::SamplingOn { placement: &placement });
+    }
+    status!("📈 {}", Msg::MonitorStartAll { count: &devices.len(), secs: &pacing.current().as_secs_f64() });
+
+    let (tx, rx) = mpsc::channel();
+    let workers: Vec<_> = devices
+        .iter()
+        .enumerate()
+        .map(|(index, path)| {
+            let (tx, path, mut pacing) = (tx.clone(), path.clone(), pacing.clone());
+            thread::spawn(move || {
+                let mut monitor = Monitor::new(&path);
+                if queues {
+                    monitor = monitor.with_queues(&path);
+                }
+                if preempt {
+                    monitor = monitor.with_preemption();
+                }
+                if ifpc {
+                    monitor = monitor.with_ifpc();
+                }
+                let mut n = 0;
+                // Erstes Sample nur als Bezugspunkt
+                if tx.send((index, monitor.sample())).is_err() {
+                    return;
+                }
+                while count.is_none_or(|c| n < c) && sleep_interruptible(pacing.current()) {
+                    let sample = monitor.sample();
+                    pacing.update(&sample, monitor.frequencies());
+                    if tx.send((index, sample)).is_err() {
+                        return;
+                    }
+                    n += 1;
+                }
+            })
+        })
+        .collect();
+    drop(tx);
+
+    let tags: Vec<String> = devices
+        .iter()
+        .map(|path| Path::new(path).file_name().map_or(path.clone(), |n| n.to_string_lossy().into_owned()))
+        .collect();
+    let width = tags.iter().map(String::len).max().unwrap_or(0);
+    let mut prev: Vec<Option<Sample>> = vec![None; devices.len()];
+    let mut summaries = vec![SessionSummary::new(); devices.len()];
+    let mut start = None;
+    for (index, sample) in rx {
+        let start = *start.get_or_insert(sample.time);
+        if let Some(prev) = &prev[index] {
+            let elapsed = sample.time.duration_since(start);
+            if sinks.is_empty() {
+                let tag = format!("{:<width$}", tags[index], width = width);
+                print_suspend(Some(&tag), &sample, prev);
+                print_row(Some(&tag), &sample, prev, elapsed, None);
+            } else {
+                let record = sample.to_record(&devices[index], prev, elapsed);
+                sinks.write(&record).map_err(|e| Msg::CannotWriteRecord { error: &e }.to_string())?;
+            }
+        }
+        summaries[index].add(&sample);
+        prev[index] = Some(sample);
+    }
+    for worker in workers {
+        let _ = worker.join();
+    }
+    sinks.finish().map_err(|e| Msg::CannotWriteRecord { error: &e }.to_string())?;
+    for (path, (tag, summary)) in devices.iter().zip(tags.iter().zip(&summaries)) {
+        let frequencies = sysfs::available_frequencies(&sysfs::device_dir(path)).unwrap_or_default();
+        print_summary(Some(tag), summary, &frequencies);
+    }
+    Ok(())
+}
+
+/// Markiert eine Suspend-Phase vor dem ersten Sample danach
+fn print_suspend(tag: Option<&str>, sample: &Sample, prev: &Sample) {
+    let gap = sample.suspended_since(prev);
+    if !gap.is_zero() {
+        let tag = tag.map_or(String::new(), |t| format!("{}  ", t));
+        status!("{}💤 {}", tag, Msg::MonitorSuspended { secs: &gap.as_secs_f64() });
+    }
+}
+
+fn print_summary(tag: Option<&str>, summary: &SessionSummary, frequencies: &[u64]) {
+    if summary.samples < 2 {
+        return;
+    }
+    let title = tag.map_or(String::new(), |t| format!(" {}", t));
+    status!("\n📋 {}", Msg::MonitorSummary { title: &title, secs: &summary.elapsed().as_secs_f64(), samples: &summary.samples });
+    let stat = |name: &str, stat: &adreno_ioctl::summary::Stat, unit: &str| {
+        if let Some(avg) = stat.avg() {
+            status!("   • {:<12} min {:>7.1}{u}  avg {:>7.1}{u}  max {:>7.1}{u}", name, stat.min, avg, stat.max, u = unit);
+        }
+    };
+    stat(&Msg::MonitorFrequency.to_string(), &summary.freq_mhz, " MHz");
+    stat(&Msg::MonitorBusy.to_string(), &summary.busy_percent, "%");
+    stat(&Msg::MonitorTemperature.to_string(), &summary.temp_c, "°C");
+    if let Some(throttled) = summary.throttled {
+        let elapsed = summary.elapsed().as_secs_f64().max(1e-9);
+        status!(
+            "   • {}",
+            Msg::MonitorThrottled { secs: &throttled.as_secs_f64(), percent: &(throttled.as_secs_f64() * 100.0 / elapsed) }
+        );
+    }
+    if let Some(entries) = summary.ifpc_entries() {
+        let elapsed = summary.elapsed().as_secs_f64().max(1e-9);
+        status!("   • {}", Msg::MonitorIfpc { entries: &entries, rate: &(entries as f64 / elapsed) });
+        if let Some(idle) = summary.idle_time() {
+            status!(
+                "   • {}",
+                Msg::MonitorIdle { secs: &idle.as_secs_f64(), percent: &(idle.as_secs_f64() * 100.0 / elapsed) }
+            );
+        }
+    }
+    if summary.suspends > 0 {
+        status!(
+            "   • {}",
+            Msg::MonitorSuspends {
+                secs: &summary.suspended.as_secs_f64(),
+                count: &summary.suspends,
+                total: &summary.boot_elapsed().as_secs_f64(),
+            }
+        );
+    }
+    if let Some(delta) = summary.fault_delta() {
+        status!("   • {}", Msg::MonitorFaults { count: &delta });
+    }
+    let residency = summary.residency(frequencies);
+    if !residency.is_empty() {
+        status!("   • {}", Msg::MonitorResidency);
+        for r in residency.iter().filter(|r| r.percent > 0.0) {
+            let freq = r.freq_hz.map_or("?".to_string(), |hz| (hz / 1_000_000).to_string());
+            status!("       level {:>2} {:>5} MHz  {:>5.1}%  ({:.1}s)", r.level, freq, r.percent, r.busy.as_secs_f64());
+        }
+    }
+}
+
+fn print_preemption_header(monitor: &Monitor) {
+    let dir = monitor.dir();
+    let enabled = match sysfs::preemption_enabled(dir) {
+        Ok(true) => Msg::Enabled,
+        Ok(false) => Msg::Disabled,
+        Err(_) => Msg::Unknown,
+    };
+    let level = match sysfs::preempt_level(dir) {
+        Ok(0) => "ringbuffer".to_string(),
+        Ok(1) => "chunk".to_string(),
+        Ok(2) => "draw".to_string(),
+        Ok(l) => l.to_string(),
+        Err(_) => "?".to_string(),
+    };
+    status!("🔀 {}", Msg::MonitorPreemption { state: &enabled, level: &level });
+    if sysfs::preempt_count(dir).is_err() {
+        status!("⚠️  {}", Msg::MonitorNoPreemptCount);
+    }
+}
+
+fn print_waits_header(path: &str) {
+    let reported = File::open(sysroot::resolve(path)).ok().and_then(|f| read_interrupt_waits(f.as_raw_fd()));
+    match reported {
+        Some(true) => status!("⏳ {}", Msg::MonitorWaitsIrq),
+        Some(false) => status!("⏳ {}", Msg::MonitorWaitsPolled),
+        None => status!("⏳ {}", Msg::MonitorWaitsEstimated),
+    }
+}
+
+fn print_ifpc_header(monitor: &Monitor) {
+    let dir = monitor.dir();
+    match (sysfs::ifpc_enabled(dir), sysfs::ifpc_count(dir)) {
+        (Ok(enabled), Ok(count)) => {
+            status!("💤 {}", Msg::MonitorIfpcCount { state: if enabled { &Msg::Enabled } else { &Msg::Disabled }, count: &count })
+        }
+        (Ok(enabled), Err(_)) => {
+            status!("💤 {}", Msg::MonitorIfpcNoCount { state: if enabled { &Msg::Enabled } else { &Msg::Disabled } })
+        }
+        (Err(_), _) => status!("⚠️  {}", Msg::MonitorNoIfpc),
+    }
+}
+
+/// Leistungsmodell aus Chip-Datenbank und höchster verfügbarer Frequenz
+fn power_model(path: &str, monitor: &Monitor) -> Result<PowerModel, String> {
+    let file = File::open(sysroot::resolve(path)).map_err(|e| Msg::CannotOpen { path, error: &e }.to_string())?;
+    let fd = file.as_raw_fd();
+    let info = read_gpu_info(fd)?;
+    let chip = decode_chip_id(info.chip_id);
+    let spec = chip
+        .spec()
+        .or_else(|| read_gpu_model(fd).as_deref().and_then(lookup_model))
+        .ok_or_else(|| Msg::MonitorNoCoefficients { model: &chip.model_name }.to_string())?;
+    let max_freq = monitor.frequencies().iter().copied().max().ok_or_else(|| Msg::MonitorNoFrequencies.to_string())?;
+    status!("🔋 {}", Msg::MonitorPowerModel { name: &spec.name, static_mw: &spec.static_mw, dynamic_mw: &spec.dynamic_mw });
+    Ok(PowerModel::new(spec, max_freq))
+}
+
+/// Eine Zeile; `tag` ist der Gerätename bei `--all-devices`
+fn print_row(tag: Option<&str>, sample: &Sample, prev: &Sample, elapsed: Duration, power: Option<&PowerEstimate>) {
+    let freq = sample.freq_hz.map_or("   -".to_string(), |f| format!("{:4}", f / 1_000_000));
+    let busy = sample.busy_percent.map_or("  -".to_string(), |b| format!("{:3.0}", b));
+    let tag = tag.map_or(String::new(), |t| format!("{}  ", t));
+    let mut line = format!("   {}{:>6.1}s  ⚡ {} MHz  📊 {}%", tag, elapsed.as_secs_f64(), freq, busy);
+    if let Some(temp) = sample.temp_c {
+        line.push_str(&format!("  🌡️ {:.0}°C", temp));
+    }
+    if let Some(p) = power {
+        // ~ markiert die gröbere Schätzung ohne Residenz-Daten
+        let marker = if p.source == EstimateSource::Residency { "" } else { "~" };
+        line.push_str(&format!("  🔋 {}{:5.0} mW", marker, p.milliwatts));
+    }
+    if let Some(rate) = sample.preemptions_per_second(prev) {
+        line.push_str(&format!("  🔀 {:.0} preempt/s", rate));
+    }
+    if let Some(rate) = sample.ifpc_per_second(prev) {
+        line.push_str(&format!("  💤 {:.1} ifpc/s", rate));
+    }
+    if let Some(rate) = sample.retired_per_second(prev) {
+        line.push_str(&format!("  🏁 {:.0} retired/s", rate));
+    }
+    if let Some(ratio) = sample.interrupt_wait_ratio(prev) {
+        line.push_str(&format!("  ⏳ {:.0}% irq-waits", ratio * 100.0));
+    }
+    if let Some(battery) = &sample.battery {
+        let ma = battery.current_ua.map_or("    -".to_string(), |ua| format!("{:5}", ua.abs() / 1000));
+        let v = battery.voltage_uv.map_or("   -".to_string(), |uv| format!("{:.2}", uv as f64 / 1e6));
+        let mw = battery.power_mw().map_or("    -".to_string(), |mw| format!("{:5.0}", mw));
+        line.push_str(&format!("  🔌 {} mA {} V {} mW", ma, v, mw));
+    }
+
+    let mut warnings = Vec::new();
+    if let Some(queues) = &sample.queues {
+        let total: u32 = queues.iter().map(|q| q.inflight()).sum();
+        line.push_str(&format!("  📥 {} inflight", total));
+        for q in queues.iter().filter(|q| q.inflight() > 0) {
+            let owner = q.process.as_deref().unwrap_or("?");
+            line.push_str(&format!(" [{}:{} {}]", q.id, owner, q.inflight()));
+            if q.inflight() >= OVERFEED_INFLIGHT {
+                warnings.push(Msg::MonitorOverfeed { context: &q.id, owner: &owner, count: &q.inflight() }.to_string());
+            }
+        }
+    }
+    for rate in sample.irq_rates(prev) {
+        line.push_str(&format!("  🔔 {} {} (+{:.0}/s)", rate.name, rate.total, rate.per_second));
+        if rate.per_second > IRQ_STORM_PER_SEC {
+            warnings.push(Msg::MonitorIrqStorm { name: &rate.name }.to_string());
+        }
+        if rate.per_second == 0.0 && sample.busy_percent.is_some_and(|b| b > 0.0) {
+            warnings.push(Msg::MonitorNoIrqsBusy { name: &rate.name }.to_string());
+        }
+    }
+
+    status!("{}", line);
+    for w in warnings {
+        status!("   ⚠️  {}", w);
+    }
+}
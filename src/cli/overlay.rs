@@ -0,0 +1,82 @@
+//! `overlay` - Binärer Shared-Memory-Feed für FPS-Overlays
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::overlay::{
+    default_path, monotonic_ns, OverlayReader, OverlayRecord, OverlayWriter, FLAG_BUSY, FLAG_FREQ, FLAG_TEMP,
+    FLAG_THERMAL,
+};
+use adreno_ioctl::sysfs;
+
+use super::{device_path, install_interrupt_handler, parse_duration, sleep_interruptible, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let path = args.value("--path")?.map_or_else(default_path, PathBuf::from);
+    let read = args.flag("--read");
+    let interval = match args.value("--interval")? {
+        Some(i) => parse_duration(&i)?,
+        None => Duration::from_millis(50),
+    };
+    if read {
+        args.finish()?;
+        return print_record(&path);
+    }
+    let device = device_path(&mut args)?;
+    args.finish()?;
+
+    let dir = sysfs::device_dir(&device);
+    let temp_files = sysfs::temperature_files(&dir);
+    let max_freq_hz = sysfs::available_frequencies(&dir).ok().and_then(|f| f.into_iter().max()).unwrap_or(0);
+    let writer = OverlayWriter::create(&path).map_err(|e| Msg::CannotCreate { path: &path.display(), error: &e }.to_string())?;
+
+    install_interrupt_handler();
+    println!("🎮 {}", Msg::OverlayPublishing { device: &device, path: &writer.path().display(), ms: &interval.as_millis() });
+    loop {
+        let mut record = OverlayRecord {
+            timestamp_ns: monotonic_ns(),
+            max_freq_hz,
+            interval_us: interval.as_micros() as u32,
+            ..Default::default()
+        };
+        if let Ok(hz) = sysfs::gpuclk(&dir) {
+            record.freq_hz = hz;
+            record.flags |= FLAG_FREQ;
+        }
+        if let Ok(busy) = sysfs::busy_percent(&dir) {
+            record.busy_permille = (busy * 10.0).round().clamp(0.0, 1000.0) as u32;
+            record.flags |= FLAG_BUSY;
+        }
+        if let Ok(temp) = sysfs::read_temperature(&temp_files) {
+            record.temp_millicelsius = (temp * 1000.0) as i32;
+            record.flags |= FLAG_TEMP;
+        }
+        if let Ok(level) = sysfs::thermal_pwrlevel(&dir) {
+            record.thermal_level = level;
+            record.flags |= FLAG_THERMAL;
+        }
+        writer.publish(&record);
+        if !sleep_interruptible(interval) {
+            break;
+        }
+    }
+    // Veralteter Zeitstempel zeigt Lesern, dass kein Writer mehr läuft
+    Ok(())
+}
+
+fn print_record(path: &std::path::Path) -> Result<(), String> {
+    let reader = OverlayReader::open(path).map_err(|e| Msg::CannotOpen { path: &path.display().to_string(), error: &e }.to_string())?;
+    let record = reader.read().ok_or_else(|| Msg::OverlayNoRecord.to_string())?;
+    let age_ms = monotonic_ns().saturating_sub(record.timestamp_ns) as f64 / 1e6;
+    let field = |flag: u32, text: String| if record.has(flag) { text } else { "-".to_string() };
+    println!("🎮 {}", Msg::OverlayRecord { path: &path.display(), seq: &record.seq, age: &age_ms });
+    println!("   • {}", Msg::OverlayFrequency {
+        freq: &field(FLAG_FREQ, format!("{} MHz", record.freq_hz / 1_000_000)),
+        max: &(record.max_freq_hz / 1_000_000),
+    });
+    println!("   • {}", Msg::OverlayBusy { busy: &field(FLAG_BUSY, format!("{:.1}%", record.busy_permille as f64 / 10.0)) });
+    println!("   • {}", Msg::OverlayTemp { temp: &field(FLAG_TEMP, format!("{:.1}°C", record.temp_millicelsius as f64 / 1000.0)) });
+    println!("   • {}", Msg::OverlayThermal { level: &field(FLAG_THERMAL, Msg::Level { level: &record.thermal_level }.to_string()) });
+    Ok(())
+}
@@ -0,0 +1,79 @@
+//! Reine ASCII-Ausgabe (`--plain`, `NO_COLOR`, keine Konsole)
+//!
+//! stdout und stderr werden auf Pipes umgelenkt; je ein Thread übersetzt
+//! Emoji und Rahmenzeichen in ASCII mit gleicher Anzeigebreite, damit
+//! ausgerichtete Spalten ausgerichtet bleiben.
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::os::fd::AsFd;
+use std::sync::Mutex;
+use std::thread::JoinHandle;
+
+use adreno_ioctl::report::to_ascii;
+use adreno_ioctl::sys;
+
+static FILTERS: Mutex<Vec<JoinHandle<()>>> = Mutex::new(Vec::new());
+
+/// Ob ASCII-Ausgabe gewünscht ist: `--plain`, `NO_COLOR` oder stdout ist keine Konsole
+pub fn wanted(flag: bool) -> bool {
+    flag || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) || !sys::isatty(libc::STDOUT_FILENO)
+}
+
+/// Startet die Filter für stdout und stderr
+pub fn enable() {
+    let mut filters = FILTERS.lock().unwrap();
+    for target in [libc::STDOUT_FILENO, libc::STDERR_FILENO] {
+        let Ok(original) = sys::dup(target) else { return };
+        let Ok((reader, pipe_writer)) = sys::pipe() else { return };
+        if sys::dup2(pipe_writer.as_fd(), target).is_err() {
+            return;
+        }
+        drop(pipe_writer);
+        let (reader, writer) = (File::from(reader), File::from(original));
+        filters.push(std::thread::spawn(move || filter(reader, writer)));
+    }
+}
+
+/// Schließt die Pipes und wartet, bis alles ausgegeben ist
+///
+/// stdout und stderr zeigen danach auf `/dev/null`, so bleiben die fds belegt
+/// und die Filter sehen trotzdem EOF.
+pub fn finish() {
+    let _ = io::stdout().flush();
+    let _ = io::stderr().flush();
+    let handles = std::mem::take(&mut *FILTERS.lock().unwrap());
+    if handles.is_empty() {
+        return;
+    }
+    let Ok(null) = OpenOptions::new().write(true).open("/dev/null") else { return };
+    for target in [libc::STDOUT_FILENO, libc::STDERR_FILENO] {
+        let _ = sys::dup2(null.as_fd(), target);
+    }
+    for handle in handles {
+        let _ = handle.join();
+    }
+}
+
+fn filter(mut reader: File, mut writer: File) {
+    let mut buf = [0u8; 4096];
+    let mut pending: Vec<u8> = Vec::new();
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => n,
+        };
+        pending.extend_from_slice(&buf[..n]);
+        // Unvollständige UTF-8 Sequenz am Ende für den nächsten Block aufheben
+        let valid = match std::str::from_utf8(&pending) {
+            Ok(text) => text.len(),
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => pending.len(),
+        };
+        let text = String::from_utf8_lossy(&pending[..valid]).into_owned();
+        if writer.write_all(to_ascii(&text).as_bytes()).is_err() {
+            break;
+        }
+        pending.drain(..valid);
+    }
+}
@@ -0,0 +1,98 @@
+//! `procmem` - GPU-Speicher je Prozess, mit `--watch` als Event-Strom
+
+use std::time::Duration;
+
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::procmem::{read_processes, MemoryEvent, MemoryWatcher, KGSL_PROC_DIR};
+use adreno_ioctl::schema::versioned;
+use adreno_ioctl::sink::{self, Sink};
+
+use super::{
+    format_size, install_interrupt_handler, interrupted, parse_duration, parse_size, sleep_interruptible, Args,
+};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let watch = args.flag("--watch");
+    let interval = match args.value("--interval")? {
+        Some(i) => parse_duration(&i)?,
+        None => Duration::from_secs(1),
+    };
+    let min_change = match args.value("--min-change")? {
+        Some(s) => parse_size(&s)?,
+        None => 1 << 20,
+    };
+    let count = args.parsed::<u64>("--count")?;
+    let json = args.flag("--json");
+    let mut sinks = args.values("--sink")?.iter().map(|spec| sink::open(spec)).collect::<Result<Vec<_>, _>>()?;
+    args.finish()?;
+    if !sinks.is_empty() && (!watch || json) {
+        return Err(Msg::ProcmemSinkNeedsWatch.to_string());
+    }
+
+    if !watch {
+        let processes = read_processes().map_err(|e| Msg::CannotRead { path: &KGSL_PROC_DIR, error: &e }.to_string())?;
+        println!("🧠 {}", Msg::ProcmemHeader { dir: &KGSL_PROC_DIR });
+        for p in &processes {
+            let details: Vec<String> =
+                p.memtypes().filter(|(_, v)| *v > 0).map(|(name, v)| format!("{} {}", name, format_size(*v))).collect();
+            println!("   • {:>7} {:<20} {:>10}   {}", p.pid, p.name, format_size(p.total_bytes), details.join(", "));
+        }
+        if processes.is_empty() {
+            println!("   {}", Msg::ProcmemNone);
+        }
+        return Ok(());
+    }
+
+    let mut watcher = MemoryWatcher::new(min_change).map_err(|e| Msg::CannotRead { path: &KGSL_PROC_DIR, error: &e }.to_string())?;
+    install_interrupt_handler();
+    if !json && sinks.is_empty() {
+        let mode = if watcher.uses_inotify() { Msg::ProcmemInotify } else { Msg::ProcmemPolling };
+        println!("👀 {}", Msg::ProcmemWatching { count: &watcher.processes().count(), mode: &mode, min: &format_size(min_change) });
+    }
+    let use_sinks = !sinks.is_empty();
+    watcher.subscribe(move |event| {
+        if use_sinks {
+            if let Err(e) = sinks.write(&event.to_record()) {
+                eprintln!("⚠️  {}", Msg::CannotWriteRecord { error: &e });
+            }
+        } else if json {
+            println!("{}", versioned(event.to_json()).to_compact());
+        } else {
+            print_event(event);
+        }
+    });
+
+    let mut n = 0;
+    while count.is_none_or(|c| n < c) {
+        let result = if watcher.uses_inotify() {
+            watcher.wait(interval)
+        } else if sleep_interruptible(interval) {
+            watcher.poll()
+        } else {
+            break;
+        };
+        if interrupted() {
+            break;
+        }
+        result.map_err(|e| Msg::CannotRead { path: &KGSL_PROC_DIR, error: &e }.to_string())?;
+        n += 1;
+    }
+    Ok(())
+}
+
+fn print_event(event: &MemoryEvent) {
+    match event {
+        MemoryEvent::Started { pid, name, bytes } => {
+            println!("   🟢 {:>7} {:<20} {}", pid, name, Msg::ProcmemStarted { size: &format_size(*bytes) })
+        }
+        MemoryEvent::Grew { pid, name, from, to } => {
+            println!("   📈 {:>7} {:<20} {}", pid, name, Msg::ProcmemGrew { delta: &format_size(to - from), total: &format_size(*to) })
+        }
+        MemoryEvent::Shrank { pid, name, from, to } => {
+            println!("   📉 {:>7} {:<20} {}", pid, name, Msg::ProcmemShrank { delta: &format_size(from - to), total: &format_size(*to) })
+        }
+        MemoryEvent::Exited { pid, name, last_bytes } => {
+            println!("   ⚪ {:>7} {:<20} {}", pid, name, Msg::ProcmemExited { size: &format_size(*last_bytes) })
+        }
+    }
+}
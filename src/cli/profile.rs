@@ -0,0 +1,139 @@
+//! `profile` - Vordefinierte Einstellungs-Bündel anwenden, anzeigen, zurücksetzen
+
+use adreno_ioctl::instance::{self, state_path, InstanceState};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::profile::Profile;
+use adreno_ioctl::settings::{Setting, SettingsGuard};
+use adreno_ioctl::sysfs;
+
+use super::{device_path, set_exit_code, Args, EXIT_PARTIAL};
+
+/// Präfix im Journal, an dem ein angewendetes Profil erkannt wird
+const COMMAND_PREFIX: &str = "profile apply ";
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    match args.positional().as_deref() {
+        Some("apply") => run_apply(args),
+        Some("show") => run_show(args),
+        Some("reset") => run_reset(args),
+        Some(other) => Err(Msg::ProfileUnknownAction { action: &other }.to_string()),
+        None => Err(Msg::ProfileUsage.to_string()),
+    }
+}
+
+fn profile_arg(args: &mut Args) -> Result<Option<Profile>, String> {
+    args.positional()
+        .map(|name| {
+            Profile::from_name(&name).ok_or_else(|| {
+                let names: Vec<&str> = Profile::ALL.iter().map(|p| p.name()).collect();
+                Msg::ProfileUnknown { name: &name, names: &names.join(", ") }.to_string()
+            })
+        })
+        .transpose()
+}
+
+/// Angewendetes Profil laut Journal
+fn active_profile() -> Option<String> {
+    let state = InstanceState::load(&state_path()).ok()??;
+    let name = state.command.strip_prefix(COMMAND_PREFIX)?;
+    (state.persistent && !state.changes.is_empty()).then(|| name.to_string())
+}
+
+fn run_apply(mut args: Args) -> Result<(), String> {
+    let profile = profile_arg(&mut args)?.ok_or_else(|| Msg::ProfileNeedsName.to_string())?;
+    let device = device_path(&mut args)?;
+    args.finish()?;
+    let dir = sysfs::device_dir(&device);
+    let plan = profile.plan(&dir)?;
+    if plan.is_empty() {
+        return Err(Msg::ProfileNoSettings { dir: &dir.display() }.to_string());
+    }
+
+    // Profilwechsel: erst das alte Profil zurücknehmen
+    if let Some(previous) = active_profile() {
+        println!("↩️  {}", Msg::ProfileResettingFirst { name: &previous });
+        reset()?;
+    }
+
+    let mut settings = SettingsGuard::new(&format!("{}{}", COMMAND_PREFIX, profile.name()))?;
+    println!("🎛️  {}", Msg::ProfileApplying { name: &profile.name(), device: &device });
+    for (setting, value) in &plan {
+        let path = setting.path(&dir);
+        let before = sysfs::read_string(&path).unwrap_or_default();
+        // Bei einem Fehler nimmt der Guard alles bereits Geschriebene zurück
+        settings
+            .set(&path, value)
+            .map_err(|e| Msg::ProfileCannotSet { setting, value, error: &e }.to_string())?;
+        println!("   • {:<24} {} -> {}", setting.file(), before, value);
+    }
+    settings.persist().map_err(|e| Msg::CannotWrite { path: &state_path().display(), error: &e }.to_string())?;
+    println!("✅ {}", Msg::ProfileActive { name: &profile.name() });
+    Ok(())
+}
+
+fn run_show(mut args: Args) -> Result<(), String> {
+    let profile = profile_arg(&mut args)?;
+    let device = device_path(&mut args)?;
+    args.finish()?;
+    let dir = sysfs::device_dir(&device);
+
+    let model = sysfs::read_string(dir.join("gpu_model")).unwrap_or_else(|_| Msg::UnknownGpu.to_string());
+    let levels = sysfs::num_pwrlevels(&dir).map_or("?".to_string(), |n| n.to_string());
+    println!("🎛️  {}", Msg::ProfileShowHeader { model: &model, device: &device, levels: &levels });
+    match active_profile() {
+        Some(name) => println!("   {}", Msg::ProfileActiveName { name: &name }),
+        None => println!("   {}", Msg::ProfileNoneActive),
+    }
+
+    println!("\n   {}", Msg::ProfileCurrent);
+    for setting in Setting::ALL {
+        if let Ok(value) = sysfs::read_string(setting.path(&dir)) {
+            println!("   • {:<24} {}", setting.file(), value);
+        }
+    }
+
+    let profiles = match profile {
+        Some(p) => vec![p],
+        None => Profile::ALL.to_vec(),
+    };
+    for p in profiles {
+        println!("\n   {} - {}", p.name(), p.about());
+        match p.plan(&dir) {
+            Ok(plan) => {
+                for (setting, value) in plan {
+                    println!("   • {:<24} {}", setting.file(), value);
+                }
+            }
+            Err(e) => println!("   ⚠️  {}", e),
+        }
+    }
+    Ok(())
+}
+
+fn run_reset(args: Args) -> Result<(), String> {
+    args.finish()?;
+    match active_profile() {
+        Some(name) => println!("↩️  {}", Msg::ProfileResetting { name: &name }),
+        None => {
+            println!("✅ {}", Msg::ProfileNoneApplied);
+            return Ok(());
+        }
+    }
+    reset()?;
+    println!("✅ {}", Msg::ProfileStockRestored);
+    Ok(())
+}
+
+fn reset() -> Result<(), String> {
+    let Some((state, results)) = instance::restore()? else {
+        return Ok(());
+    };
+    for r in results.iter().filter(|r| r.result.is_err()) {
+        eprintln!("⚠️  {}", Msg::CouldNotRestore { path: &r.change.path.display(), value: &r.change.original });
+    }
+    if !state.changes.is_empty() {
+        set_exit_code(EXIT_PARTIAL);
+        return Err(Msg::NotRestored { count: &state.changes.len(), path: &state_path().display() }.to_string());
+    }
+    Ok(())
+}
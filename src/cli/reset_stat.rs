@@ -0,0 +1,33 @@
+//! `reset-stat` - Reset-Status auf einem eigenen Context
+//!
+//! KGSL-Contexts gehören dem File Descriptor, der sie angelegt hat; fremde
+//! Contexts beantwortet der Kernel nicht. Das Kommando prüft daher nur, ob
+//! der Treiber `KGSL_PROP_GPU_RESET_STAT` für einen eigenen Context liefert.
+
+use std::os::unix::io::AsRawFd;
+
+use adreno_ioctl::kgsl::{
+    create_context, destroy_context, read_reset_status, KGSL_CONTEXT_NO_GMEM_ALLOC,
+    KGSL_CONTEXT_PREAMBLE,
+};
+use adreno_ioctl::messages::Msg;
+
+use super::{open_device_rw, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let (path, file) = open_device_rw(&mut args)?;
+    args.finish()?;
+    let fd = file.as_raw_fd();
+
+    let context = create_context(fd, KGSL_CONTEXT_NO_GMEM_ALLOC | KGSL_CONTEXT_PREAMBLE)
+        .map_err(|e| Msg::CannotCreateContext { error: &e }.to_string())?;
+    let status = read_reset_status(fd, context);
+    let _ = destroy_context(fd, context);
+    let status = status.map_err(|e| Msg::ResetStatFailed { context: &context, error: &e }.to_string())?;
+
+    println!("🔄 {}", Msg::ResetStatHeader { path: &path });
+    println!("   {:>8}  {:<10} GL_EXT_robustness", "Context", "Status");
+    println!("   {:>8}  {:<10} {}", context, format!("{:?}", status), status.gl_name());
+    println!("\n💡 {}", Msg::ResetStatOwnFd);
+    Ok(())
+}
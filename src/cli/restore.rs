@@ -0,0 +1,29 @@
+//! `restore` - Nimmt Änderungen einer abgestürzten Instanz zurück
+
+use adreno_ioctl::instance::{self, state_path};
+use adreno_ioctl::messages::Msg;
+
+use super::{set_exit_code, Args, EXIT_PARTIAL};
+
+pub fn run(args: Args) -> Result<(), String> {
+    args.finish()?;
+    let Some((state, results)) = instance::restore()? else {
+        println!("✅ {}", Msg::RestoreNothing { path: &state_path().display() });
+        return Ok(());
+    };
+    println!("♻️  {}", Msg::RestoreHeader { pid: &state.pid, command: &state.command });
+    for r in &results {
+        match &r.result {
+            Ok(()) => println!("   ✅ {} = {}", r.change.path.display(), r.change.original),
+            Err(e) => println!("   ❌ {} = {}: {}", r.change.path.display(), r.change.original, e),
+        }
+    }
+    if results.is_empty() {
+        println!("   {}", Msg::RestoreNoChanges);
+    }
+    if !state.changes.is_empty() {
+        set_exit_code(EXIT_PARTIAL);
+        println!("⚠️  {}", Msg::RestoreRemaining { count: &state.changes.len(), path: &state_path().display() });
+    }
+    Ok(())
+}
@@ -0,0 +1,149 @@
+//! `scan` - alle Properties abfragen, optional gegen eine Baseline vergleichen;
+//! `--ioctls` sucht stattdessen die implementierten Kommandonummern
+
+use std::io;
+use std::os::unix::io::AsRawFd;
+use std::path::PathBuf;
+
+use adreno_ioctl::driver::uname;
+use adreno_ioctl::ioctls::KGSL_IOCTLS;
+use adreno_ioctl::kgsl::{KgslDeviceInfo, KgslDevinfo, KgslVersionInfo, KGSL_PROP_DEVICE_INFO, KGSL_PROP_VERSION};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::scan::{
+    diff, ioctls_to_json, probe_request, scan_ioctls, scan_properties, PropertyChange, Scan, ScannedProperty,
+    MAX_IOCTL_NR,
+};
+use adreno_ioctl::schema::versioned;
+
+use super::{open_device, Args};
+
+/// Angezeigte Bytes je Property in der Tabelle
+const PREVIEW_BYTES: usize = 16;
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let json = args.flag("--json");
+    let ioctls = args.flag("--ioctls");
+    let output = args.value("--output")?.map(PathBuf::from);
+    let baseline = args.value("--baseline")?.map(PathBuf::from);
+    let (path, file) = open_device(&mut args)?;
+    args.finish()?;
+    if ioctls {
+        if baseline.is_some() || output.is_some() {
+            return Err(Msg::ScanBaselineWithIoctls.to_string());
+        }
+        return run_ioctls(&path, file.as_raw_fd(), json);
+    }
+    // Vor dem Scan laden, damit ein Tippfehler nicht erst nach tausenden ioctls auffällt
+    let baseline = baseline.map(|p| Scan::load(&p).map(|scan| (p, scan))).transpose()?;
+
+    let scan = Scan { kernel_release: uname().0, properties: scan_properties(file.as_raw_fd()) };
+    if let Some(out) = &output {
+        scan.save(out).map_err(|e| Msg::CannotWrite { path: &out.display(), error: &e }.to_string())?;
+    }
+    if json {
+        println!("{}", versioned(scan.to_json()).to_pretty());
+        return Ok(());
+    }
+
+    let present: Vec<&ScannedProperty> = scan.properties.iter().filter(|p| p.is_present()).collect();
+    println!("🔎 {}", Msg::ScanHeader { path: &path, kernel: &scan.kernel_release });
+    for p in &present {
+        let bytes = p.result.as_ref().map(Vec::as_slice).unwrap_or_default();
+        let preview: String = bytes.iter().take(PREVIEW_BYTES).map(|b| format!("{:02x}", b)).collect();
+        let more = if bytes.len() > PREVIEW_BYTES { "…" } else { "" };
+        println!("   0x{:02x} {:<26} {:>4} bytes  {}{}", p.id, p.name(), bytes.len(), preview, more);
+        if let Some(text) = decoded(p) {
+            println!("        ↳ {}", text);
+        }
+    }
+    println!("   {}", Msg::ScanAnswered { present: &present.len(), total: &scan.properties.len() });
+    if let Some(out) = &output {
+        println!("💾 {}", Msg::SavedTo { path: &out.display() });
+    }
+
+    if let Some((file, old)) = baseline {
+        println!("\n📋 {}", Msg::ScanCompared { path: &file.display(), kernel: &old.kernel_release });
+        let changes = diff(&old, &scan);
+        if changes.is_empty() {
+            println!("   ✅ {}", Msg::ScanNoChanges);
+        }
+        for (id, change) in &changes {
+            let name = scan.property(*id).or_else(|| old.property(*id)).map(ScannedProperty::name).unwrap_or_default();
+            println!("   {}", describe_change(*id, &name, change));
+        }
+    }
+    Ok(())
+}
+
+/// Bekannte Strukturen zusätzlich lesbar
+fn decoded(p: &ScannedProperty) -> Option<String> {
+    match p.id {
+        KGSL_PROP_DEVICE_INFO => p
+            .decode::<KgslDevinfo>()
+            .map(|d| format!("chip_id 0x{:08x}, gpu_id {}, gmem {} KiB", d.chip_id, d.gpu_id, d.gmem_sizebytes / 1024))
+            .or_else(|| p.decode::<KgslDeviceInfo>().map(|d| format!("chip_id 0x{:08x}, device_id {}", d.chip_id, d.device_id))),
+        KGSL_PROP_VERSION => p
+            .decode::<KgslVersionInfo>()
+            .map(|v| format!("driver {}, device {}", v.driver_version, v.device_version)),
+        _ => None,
+    }
+}
+
+fn run_ioctls(path: &str, fd: i32, json: bool) -> Result<(), String> {
+    let results = scan_ioctls(fd);
+    if json {
+        println!("{}", versioned(ioctls_to_json(&results)).to_pretty());
+        return Ok(());
+    }
+    println!("🔎 {}", Msg::ScanIoctlHeader { path: &path, max: MAX_IOCTL_NR as u32 });
+    let mut unknown = 0;
+    for &(nr, response) in results.iter().filter(|(_, r)| r.is_implemented()) {
+        let name = KGSL_IOCTLS.iter().find(|c| c.nr == nr).map(|c| c.name);
+        if name.is_none() {
+            unknown += 1;
+        }
+        let name = name.map_or_else(|| Msg::ScanUnknownIoctl.to_string(), str::to_string);
+        println!("   0x{:02x}  {:08x}  {:<8} {}", nr, probe_request(nr), response.label(), name);
+    }
+    let implemented = results.iter().filter(|(_, r)| r.is_implemented()).count();
+    println!("   {}", Msg::ScanIoctlSummary { implemented: &implemented, total: &results.len(), unknown: &unknown });
+    let missing: Vec<&str> = KGSL_IOCTLS
+        .iter()
+        .filter(|c| results.iter().any(|(nr, r)| *nr == c.nr && !r.is_implemented()))
+        .map(|c| c.name)
+        .collect();
+    if !missing.is_empty() {
+        println!("   {}", Msg::ScanAbsent { names: &missing.join(", ") });
+    }
+    println!("\n💡 {}", Msg::ScanErrnoLegend);
+    Ok(())
+}
+
+fn describe_change(id: u32, name: &str, change: &PropertyChange) -> String {
+    match change {
+        PropertyChange::Appeared { size } => format!("➕ {}", Msg::ScanAppeared { id, name: &name, size }),
+        PropertyChange::Disappeared { size, errno } => {
+            format!("➖ {}", Msg::ScanDisappeared { id, name: &name, size, error: &io::Error::from_raw_os_error(*errno) })
+        }
+        PropertyChange::Resized { old, new } => format!("📏 {}", Msg::ScanResized { id, name: &name, old, new }),
+        PropertyChange::Changed { offsets } => {
+            format!("✏️  {}", Msg::ScanChanged { id, name: &name, ranges: &format_ranges(offsets) })
+        }
+    }
+}
+
+/// `[0,1,2,3,8]` → "0-3, 8"
+fn format_ranges(offsets: &[usize]) -> String {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for &o in offsets {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == o => *end = o,
+            _ => ranges.push((o, o)),
+        }
+    }
+    ranges
+        .iter()
+        .map(|&(start, end)| if start == end { start.to_string() } else { format!("{}-{}", start, end) })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,70 @@
+//! `sched` - CPU-Scheduling der KGSL Worker: GPU- oder Dispatcher-gebunden?
+
+use std::time::Duration;
+
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::sched::{find_kgsl_threads, read_dispatcher_counters, read_thread, ThreadDelta};
+
+use super::{device_path, install_interrupt_handler, parse_duration, sleep_interruptible, Args};
+
+/// Ab dieser mittleren Runqueue-Wartezeit pro Zeitscheibe gilt ein Thread als CPU-gebremst
+const SLOW_WAIT_PER_SLICE: Duration = Duration::from_millis(1);
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let interval = match args.value("--interval")? {
+        Some(i) => parse_duration(&i)?,
+        None => Duration::from_secs(1),
+    };
+    let count = args.parsed::<u64>("--count")?;
+    let path = device_path(&mut args)?;
+    args.finish()?;
+
+    let mut threads = find_kgsl_threads();
+    if threads.is_empty() {
+        return Err(Msg::SchedNoThreads.to_string());
+    }
+    println!("🧵 {}", Msg::SchedThreads);
+    for t in &threads {
+        println!("   • {:>6} {}", t.pid, t.comm);
+    }
+
+    let counters = read_dispatcher_counters(&path);
+    if !counters.is_empty() {
+        println!("\n📬 {}", Msg::SchedDispatcher);
+        for (key, value) in &counters {
+            println!("   • {:<28} {}", key, value);
+        }
+    }
+
+    install_interrupt_handler();
+    println!("\n   {:<18} {:>8} {:>9} {:>11} {:>9}", "Thread", "CPU %", "Wait %", "Wait/slice", "Preempt");
+    let mut n = 0;
+    while count.is_none_or(|c| n < c) {
+        if !sleep_interruptible(interval) {
+            break;
+        }
+        let deltas: Vec<ThreadDelta> = threads
+            .iter()
+            .filter_map(|prev| Some(read_thread(prev.pid)?.delta(prev)))
+            .collect();
+        for d in deltas.iter().filter(|d| d.timeslices > 0) {
+            let pct = |x: Duration| x.as_secs_f64() * 100.0 / interval.as_secs_f64();
+            let slow = if d.wait_per_slice() > SLOW_WAIT_PER_SLICE { " ⚠️" } else { "" };
+            println!(
+                "   {:<18} {:>7.1}% {:>8.1}% {:>8.2} ms {:>9}{}",
+                d.comm,
+                pct(d.run),
+                pct(d.wait),
+                d.wait_per_slice().as_secs_f64() * 1000.0,
+                d.nonvoluntary_switches,
+                slow
+            );
+        }
+        if deltas.iter().any(|d| d.wait_per_slice() > SLOW_WAIT_PER_SLICE) {
+            println!("   ⚠️  {}", Msg::SchedCpuBound);
+        }
+        threads = threads.iter().filter_map(|t| read_thread(t.pid)).collect();
+        n += 1;
+    }
+    Ok(())
+}
@@ -0,0 +1,209 @@
+//! `selftest` - Kurzer Funktionstest der wichtigsten KGSL-Pfade
+
+use std::fs::File;
+use std::os::fd::AsRawFd;
+use std::time::Duration;
+
+use adreno_ioctl::baseline::{self, Comparison};
+use adreno_ioctl::chip::{decode_chip_id, lookup_model};
+use adreno_ioctl::features::generation;
+use adreno_ioctl::fence::{create_fence, sync_file_info, FenceStatus};
+use adreno_ioctl::kgsl::{
+    create_context, destroy_context, read_devinfo, read_gpu_info, read_gpu_model, read_gpu_version, KGSL_CONTEXT_PREAMBLE,
+};
+use adreno_ioctl::memory::GpuBuffer;
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::platform::{self, own_interpreter};
+use adreno_ioctl::submit::Submitter;
+use adreno_ioctl::sysfs;
+use adreno_ioctl::timeline::{detect_hw_fences, HwFenceSupport, Timeline};
+
+use super::{open_device_rw, print_comparison, Args};
+
+/// Wartezeit für Submits und Fences
+const WAIT: Duration = Duration::from_secs(1);
+
+/// Ergebnis eines Testschritts
+enum Outcome {
+    Pass(String),
+    Fail(String),
+    Skip(String),
+}
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let (path, file) = open_device_rw(&mut args)?;
+    args.finish()?;
+    let fd = file.as_raw_fd();
+
+    println!("🩺 {}\n", Msg::SelftestHeader { path: &path });
+    let info = read_gpu_info(fd);
+    let chip_gen = info.as_ref().map(|i| generation(i.chip_id)).unwrap_or(0);
+    let hw_fences = detect_hw_fences(fd, chip_gen);
+
+    let checks: Vec<(Msg, Outcome)> = vec![
+        (
+            Msg::SelftestDeviceInfo,
+            match &info {
+                Ok(i) => Outcome::Pass(Msg::SelftestChip { chip_id: i.chip_id }.to_string()),
+                Err(e) => Outcome::Fail(e.clone()),
+            },
+        ),
+        (
+            Msg::SelftestDriverVersion,
+            match read_gpu_version(fd) {
+                Ok(v) => Outcome::Pass(Msg::SelftestVersion { driver: &v.driver_version, device: &v.device_version }.to_string()),
+                Err(e) => Outcome::Fail(e),
+            },
+        ),
+        (Msg::SelftestBinary, check_binary()),
+        (Msg::SelftestContext, check_context(fd)),
+        (Msg::SelftestAlloc, check_alloc(&file)),
+        (Msg::SelftestSubmit, check_submit(&file, chip_gen)),
+        (Msg::SelftestTimestampFence, check_timestamp_fence(&file, chip_gen)),
+        (Msg::SelftestHardwareFence, check_hw_fence(&file, &hw_fences)),
+    ];
+
+    let mut failed = 0;
+    for (name, outcome) in &checks {
+        // Breite gilt nur für Strings, nicht für Display-Impls ohne `pad`
+        let name = name.to_string();
+        match outcome {
+            Outcome::Pass(detail) => println!("   ✅ {:<24} {}", name, detail),
+            Outcome::Fail(detail) => {
+                failed += 1;
+                println!("   ❌ {:<24} {}", name, detail)
+            }
+            Outcome::Skip(detail) => println!("   ⏭️  {:<24} {}", name, detail),
+        }
+    }
+    print_hw_fence_detection(&hw_fences);
+    if let Ok(info) = &info {
+        print_baseline(fd, &path, info.chip_id);
+    }
+
+    if failed > 0 {
+        return Err(Msg::SelftestFailed { failed: &failed, total: &checks.len() }.to_string());
+    }
+    println!("\n   ✅ {}", Msg::SelftestPassed);
+    Ok(())
+}
+
+/// Dynamisch gelinkt ist kein Fehler, scheitert aber in Recovery und Minimal-Shells
+fn check_binary() -> Outcome {
+    match own_interpreter() {
+        Ok(None) => Outcome::Pass(Msg::SelftestStatic { platform: &platform::detect().label() }.to_string()),
+        Ok(Some(loader)) => Outcome::Skip(Msg::SelftestDynamic { loader: &loader }.to_string()),
+        Err(e) => Outcome::Skip(Msg::SelftestCannotInspect { error: &e }.to_string()),
+    }
+}
+
+fn check_context(fd: i32) -> Outcome {
+    match create_context(fd, KGSL_CONTEXT_PREAMBLE) {
+        Ok(id) => match destroy_context(fd, id) {
+            Ok(()) => Outcome::Pass(Msg::SelftestContextId { id: &id }.to_string()),
+            Err(e) => Outcome::Fail(Msg::SelftestDestroyFailed { error: &e }.to_string()),
+        },
+        Err(e) => Outcome::Fail(Msg::SelftestCreateFailed { error: &e }.to_string()),
+    }
+}
+
+fn check_alloc(file: &File) -> Outcome {
+    let buffer = match GpuBuffer::alloc(file, 4096, 0) {
+        Ok(b) => b,
+        Err(e) => return Outcome::Fail(Msg::GpuobjAllocFailed { error: &e }.to_string()),
+    };
+    match buffer.map() {
+        Ok(mapping) => {
+            mapping.write::<u8>(0, 0x42);
+            Outcome::Pass(Msg::SelftestMapped { id: &buffer.id, gpuaddr: buffer.gpuaddr }.to_string())
+        }
+        Err(e) => Outcome::Fail(Msg::SelftestMmapFailed { error: &e }.to_string()),
+    }
+}
+
+fn check_submit(file: &File, chip_gen: u8) -> Outcome {
+    let result = Submitter::new(file, chip_gen).and_then(|s| {
+        let ts = s.submit_nop()?;
+        s.wait(ts, WAIT)?;
+        Ok(ts)
+    });
+    match result {
+        Ok(ts) => Outcome::Pass(Msg::SelftestRetired { ts: &ts }.to_string()),
+        Err(e) => Outcome::Fail(e.to_string()),
+    }
+}
+
+fn check_timestamp_fence(file: &File, chip_gen: u8) -> Outcome {
+    let result = Submitter::new(file, chip_gen).and_then(|s| {
+        let ts = s.submit_nop()?;
+        let fence = create_fence(file.as_raw_fd(), s.context_id(), ts)?;
+        s.wait(ts, WAIT)?;
+        sync_file_info(fence.as_raw_fd())
+    });
+    match result {
+        Ok(info) if info.status == FenceStatus::Signaled => Outcome::Pass(Msg::SelftestSignaled.to_string()),
+        Ok(info) => Outcome::Fail(Msg::SelftestStatusAfterRetire { status: &format!("{:?}", info.status) }.to_string()),
+        Err(e) => Outcome::Fail(e.to_string()),
+    }
+}
+
+/// Timeline anlegen, Fence holen, von der CPU signalisieren und warten
+fn check_hw_fence(file: &File, support: &HwFenceSupport) -> Outcome {
+    if !support.timeline_ioctls {
+        return Outcome::Skip(Msg::SelftestNoTimeline.to_string());
+    }
+    let result = Timeline::create(file, 0).and_then(|timeline| {
+        let fence = timeline.fence(1)?;
+        let before = sync_file_info(fence.as_raw_fd())?.status;
+        timeline.signal(1)?;
+        timeline.wait(1, WAIT)?;
+        let after = sync_file_info(fence.as_raw_fd())?.status;
+        Ok((before, after, timeline.query()?))
+    });
+    let backing = if support.likely_enabled() { Msg::SelftestHardwareBacked } else { Msg::SelftestSoftwareBacked };
+    match result {
+        Ok((FenceStatus::Active, FenceStatus::Signaled, 1)) => {
+            Outcome::Pass(Msg::SelftestTimelineOk { backing: &backing }.to_string())
+        }
+        Ok((before, after, value)) => {
+            let (before, after) = (format!("{:?}", before), format!("{:?}", after));
+            Outcome::Fail(Msg::SelftestUnexpected { before: &before, after: &after, value: &value }.to_string())
+        }
+        Err(e) => Outcome::Fail(e.to_string()),
+    }
+}
+
+fn print_hw_fence_detection(support: &HwFenceSupport) {
+    println!("\n   {}", Msg::SelftestHwFenceDetection);
+    println!("   • {}", Msg::SelftestA7xx { answer: if support.hardware { &Msg::Yes } else { &Msg::No } });
+    println!("   • {}", Msg::SelftestTimelineIoctls { answer: if support.timeline_ioctls { &Msg::Yes } else { &Msg::No } });
+    let module = support.kernel_module.clone().unwrap_or_else(|| Msg::None.to_string());
+    println!("   • {}", Msg::SelftestKernelModule { module: &module });
+    for line in support.log_lines.iter().take(3) {
+        println!("   • dmesg: {}", line.trim());
+    }
+}
+
+/// Takt und GMEM gegen die Erfahrungswerte des Chips; Abweichungen sind
+/// Hinweise, keine Fehlschläge
+fn print_baseline(fd: i32, path: &str, chip_id: u32) {
+    let chip = decode_chip_id(chip_id);
+    let Some(spec) = chip.spec().or_else(|| read_gpu_model(fd).as_deref().and_then(lookup_model)) else {
+        return;
+    };
+    let mut comparisons: Vec<Comparison> = Vec::new();
+    let max_hz = sysfs::available_frequencies(&sysfs::device_dir(path)).ok().and_then(|f| f.into_iter().max());
+    if let (Some(known), Some(hz)) = (baseline::lookup(spec), max_hz) {
+        comparisons.push(known.max_freq(hz));
+    }
+    if let Some(bytes) = read_devinfo(fd).ok().map(|d| d.gmem_sizebytes as u64).filter(|&b| b > 0) {
+        comparisons.push(baseline::gmem(spec, bytes));
+    }
+    if comparisons.is_empty() {
+        return;
+    }
+    println!("\n   {}", Msg::SelftestComparedTo { name: &spec.name });
+    for comparison in &comparisons {
+        print_comparison(comparison);
+    }
+}
@@ -0,0 +1,187 @@
+//! `slumber` - Zyklen aus Power Collapse und Resume, prüft den Zustand danach
+//!
+//! Ein kurzer `idle_timer` schickt die GPU nach jeder Submission schnell in
+//! Slumber; die nächste Submission weckt sie wieder auf. Nach jedem Zyklus
+//! müssen Properties und Timestamps zum Stand davor passen - manche Kernel
+//! verlieren beim Power Collapse den Memstore oder liefern andere Werte.
+
+use std::os::fd::AsRawFd;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use adreno_ioctl::features::generation;
+use adreno_ioctl::kgsl::{read_gpu_info, read_gpu_model, read_timestamp, KGSL_MEMSTORE_GLOBAL, KGSL_TIMESTAMP_RETIRED};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::settings::{Setting, SettingsGuard};
+use adreno_ioctl::submit::{retired_since, Submitter};
+use adreno_ioctl::sysfs;
+
+use super::{install_interrupt_handler, open_device_rw, parse_duration, sleep_interruptible, Args};
+
+/// Aufschlag auf den Idle-Timer, bis der Collapse sicher durch ist
+const SLUMBER_MARGIN: Duration = Duration::from_millis(50);
+
+/// Idle-Timer des Kernels, falls er nicht lesbar ist
+const DEFAULT_IDLE_TIMER: Duration = Duration::from_millis(80);
+
+/// Was über einen Power Collapse hinweg gleich bleiben muss
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Snapshot {
+    chip_id: u32,
+    device_id: u32,
+    mmu_enabled: u32,
+    gmem_base: u32,
+    model: Option<String>,
+    frequencies: Vec<u64>,
+}
+
+impl Snapshot {
+    fn read(fd: i32, dir: &Path) -> Result<Self, String> {
+        let info = read_gpu_info(fd)?;
+        Ok(Snapshot {
+            chip_id: info.chip_id,
+            device_id: info.device_id,
+            mmu_enabled: info.mmu_enabled,
+            gmem_base: info.gmem_gpubaseaddr,
+            model: read_gpu_model(fd),
+            frequencies: sysfs::available_frequencies(dir).unwrap_or_default(),
+        })
+    }
+
+    /// Abweichende Felder als lesbare Zeilen
+    fn diff(&self, other: &Snapshot) -> Vec<String> {
+        let mut out = Vec::new();
+        let mut check = |name: &str, a: String, b: String| {
+            if a != b {
+                out.push(Msg::SlumberChanged { name: &name, old: &a, new: &b }.to_string());
+            }
+        };
+        check("chip_id", format!("0x{:08x}", self.chip_id), format!("0x{:08x}", other.chip_id));
+        check("device_id", self.device_id.to_string(), other.device_id.to_string());
+        check("mmu_enabled", self.mmu_enabled.to_string(), other.mmu_enabled.to_string());
+        check("gmem base", format!("0x{:x}", self.gmem_base), format!("0x{:x}", other.gmem_base));
+        check("model", format!("{:?}", self.model), format!("{:?}", other.model));
+        check("frequencies", format!("{:?}", self.frequencies), format!("{:?}", other.frequencies));
+        out
+    }
+}
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let cycles = args.parsed::<u64>("--cycles")?.unwrap_or(20);
+    let idle = match args.value("--idle")? {
+        Some(i) => parse_duration(&i)?,
+        None => Duration::from_millis(20),
+    };
+    let timeout = match args.value("--timeout")? {
+        Some(t) => parse_duration(&t)?,
+        None => Duration::from_secs(2),
+    };
+    let (path, file) = open_device_rw(&mut args)?;
+    args.finish()?;
+
+    let fd = file.as_raw_fd();
+    let dir = sysfs::device_dir(&path);
+    let baseline = Snapshot::read(fd, &dir)?;
+    let submitter = Submitter::new(&file, generation(baseline.chip_id))
+        .map_err(|e| Msg::CannotSetUpSubmission { error: &e }.to_string())?;
+
+    // Kurzer Idle-Timer beschleunigt den Collapse; ohne Root bleibt der des Kernels
+    let mut settings = SettingsGuard::new("slumber")?;
+    let idle_timer = match settings.set_setting(&dir, Setting::IdleTimer, idle.as_millis()) {
+        Ok(()) => idle,
+        Err(e) => {
+            let current = sysfs::read_u64(Setting::IdleTimer.path(&dir)).ok().map(Duration::from_millis);
+            println!("⚠️  {}", Msg::SlumberIdleTimer { error: &e });
+            current.unwrap_or(DEFAULT_IDLE_TIMER)
+        }
+    };
+    let settle = idle_timer + SLUMBER_MARGIN;
+
+    install_interrupt_handler();
+    println!(
+        "💤 {}",
+        Msg::SlumberHeader {
+            path: &path,
+            cycles: &cycles,
+            idle: &idle_timer.as_millis(),
+            settle: &settle.as_millis(),
+            context: &submitter.context_id(),
+        }
+    );
+
+    let mut failures = 0u64;
+    let mut last = submitter.submit_nop().and_then(|ts| submitter.wait(ts, timeout).map(|_| ts))
+        .map_err(|e| Msg::SlumberInitialFailed { error: &e }.to_string())?;
+    let mut last_global = read_timestamp(fd, KGSL_MEMSTORE_GLOBAL, KGSL_TIMESTAMP_RETIRED).unwrap_or(0);
+    let ifpc_before = sysfs::ifpc_count(&dir).ok();
+    let mut done = 0;
+
+    for cycle in 1..=cycles {
+        if !sleep_interruptible(settle) {
+            println!("\n🛑 {}", Msg::Interrupted);
+            break;
+        }
+        let mut problems = Vec::new();
+
+        // Nach dem Collapse, vor dem Aufwecken: Memstore muss den alten Stand halten
+        match submitter.retired() {
+            Ok(retired) if retired == last => {}
+            Ok(retired) => problems.push(Msg::SlumberRetiredMismatch { retired: &retired, expected: &last }.to_string()),
+            Err(e) => problems.push(Msg::SlumberReadRetiredFailed { error: &e }.to_string()),
+        }
+
+        let start = Instant::now();
+        match submitter.submit_nop() {
+            Ok(ts) => {
+                let _ = submitter.wait(ts, timeout);
+                let wake = start.elapsed();
+                match submitter.retired() {
+                    Ok(retired) if retired_since(retired, ts) && !retired_since(last, ts) => {
+                        last = ts;
+                        if cycle == 1 || cycle == cycles {
+                            println!("   {}", Msg::SlumberCycle { cycle: &cycle, ts: &ts, ms: &(wake.as_secs_f64() * 1000.0) });
+                        }
+                    }
+                    Ok(retired) => {
+                        problems.push(Msg::SlumberSubmitted { ts: &ts, previous: &last, retired: &retired }.to_string());
+                        last = retired;
+                    }
+                    Err(e) => problems.push(Msg::SlumberReadRetiredFailed { error: &e }.to_string()),
+                }
+            }
+            Err(e) => problems.push(Msg::SubmissionFailed { error: &e }.to_string()),
+        }
+
+        match read_timestamp(fd, KGSL_MEMSTORE_GLOBAL, KGSL_TIMESTAMP_RETIRED) {
+            Ok(global) if retired_since(global, last_global) => last_global = global,
+            Ok(global) => problems.push(Msg::SlumberGlobalBackwards { old: &last_global, new: &global }.to_string()),
+            Err(e) => problems.push(Msg::SlumberReadGlobalFailed { error: &e }.to_string()),
+        }
+
+        match Snapshot::read(fd, &dir) {
+            Ok(now) => problems.extend(baseline.diff(&now)),
+            Err(e) => problems.push(Msg::SlumberReadPropertiesFailed { error: &e }.to_string()),
+        }
+
+        done = cycle;
+        if !problems.is_empty() {
+            failures += 1;
+            for problem in &problems {
+                println!("   ❌ {}", Msg::SlumberCycleProblem { cycle: &cycle, problem });
+            }
+        }
+    }
+
+    for (change, e) in settings.restore() {
+        eprintln!("⚠️  {}", Msg::SlumberCouldNotRestore { path: &change.path.display(), error: &e });
+    }
+
+    if let (Some(before), Ok(after)) = (ifpc_before, sysfs::ifpc_count(&dir)) {
+        println!("   • {}", Msg::SlumberIfpc { count: &after.saturating_sub(before) });
+    }
+    if failures > 0 {
+        return Err(Msg::SlumberFailed { failures: &failures, cycles: &done }.to_string());
+    }
+    println!("✅ {}", Msg::SlumberPassed { cycles: &done });
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//! `sparse` - Probe und Kurztest der Sparse-Speicher IOCTLs
+
+use std::os::unix::io::AsRawFd;
+
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::sparse::{sparse_supported, SparsePhys, SparseVirt, SPARSE_PAGE_SIZE};
+
+use super::{open_device_rw, parse_size, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let size = match args.value("--size")? {
+        Some(s) => parse_size(&s)?,
+        None => 1024 * 1024,
+    };
+    let pagesize = match args.value("--pagesize")? {
+        Some(s) => parse_size(&s)?,
+        None => SPARSE_PAGE_SIZE,
+    };
+    let (path, file) = open_device_rw(&mut args)?;
+    args.finish()?;
+
+    if size == 0 || size % pagesize != 0 {
+        return Err(Msg::SparseBadSize { size: &size, pagesize: &pagesize }.to_string());
+    }
+
+    println!("🧩 {}", Msg::SparseHeader { path: &path });
+    if !sparse_supported(file.as_raw_fd()) {
+        println!("   ❌ {}", Msg::SparseUnsupported);
+        return Ok(());
+    }
+    println!("   ✅ {}", Msg::SparseSupported);
+
+    let virt = SparseVirt::alloc(&file, size, pagesize)
+        .map_err(|e| Msg::SparseVirtFailed { error: &e }.to_string())?;
+    println!("   ✅ {}", Msg::SparseVirt { id: &virt.id, gpuaddr: virt.gpuaddr, kb: &(size / 1024) });
+
+    let phys = SparsePhys::alloc(&file, size, pagesize)
+        .map_err(|e| Msg::SparsePhysFailed { error: &e }.to_string())?;
+    println!("   ✅ {}", Msg::SparsePhys { id: &phys.id, kb: &(size / 1024) });
+
+    // Erste Seite binden, dann den Rest, dann alles wieder lösen
+    step(Msg::SparseBindFirst, virt.bind(0, &phys, 0, pagesize))?;
+    if size > pagesize {
+        step(Msg::SparseBindRest, virt.bind(pagesize, &phys, pagesize, size - pagesize))?;
+    }
+    step(Msg::SparseUnbind, virt.unbind(0, size))?;
+
+    drop(phys);
+    drop(virt);
+    println!("   ✅ {}", Msg::SparseFreed);
+    Ok(())
+}
+
+fn step(name: Msg, result: std::io::Result<()>) -> Result<(), String> {
+    match result {
+        Ok(()) => {
+            println!("   ✅ {}", name);
+            Ok(())
+        }
+        Err(e) => Err(Msg::StepFailed { step: &name, error: &e }.to_string()),
+    }
+}
@@ -0,0 +1,162 @@
+//! `stress mem` - GPU-Speicher Dauertest mit Integritätsprüfung
+
+use std::fs::File;
+use std::time::Instant;
+
+use adreno_ioctl::memory::{
+    GpuBuffer, KGSL_CACHEMODE_SHIFT, KGSL_CACHEMODE_WRITEBACK, KGSL_GPUMEM_CACHE_CLEAN, KGSL_GPUMEM_CACHE_INV,
+};
+use adreno_ioctl::messages::Msg;
+
+use super::{install_interrupt_handler, interrupted, open_device_rw, parse_size, Args};
+
+/// Größte Einzelallokation; größere Tests werden aufgeteilt
+const CHUNK_SIZE: u64 = 64 << 20;
+
+/// Testmuster, pro Iteration rotierend
+const PATTERNS: [&str; 5] = ["zeros", "ones", "checkerboard", "walking-ones", "address"];
+
+/// Fehlerzähler über alle Iterationen
+#[derive(Default)]
+struct Stats {
+    alloc_failures: u64,
+    map_failures: u64,
+    sync_failures: u64,
+    corrupted_words: u64,
+    bytes_verified: u64,
+}
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    match args.positional().as_deref() {
+        Some("mem") => run_mem(args),
+        Some(other) => Err(Msg::StressUnknownTarget { target: &other }.to_string()),
+        None => Err(Msg::StressUsage.to_string()),
+    }
+}
+
+fn run_mem(mut args: Args) -> Result<(), String> {
+    let size = match args.value("--size")? {
+        Some(s) => parse_size(&s)?,
+        None => 1 << 30,
+    };
+    let iterations: u32 = args.parsed("--iterations")?.unwrap_or(100);
+    let (path, file) = open_device_rw(&mut args)?;
+    args.finish()?;
+
+    if size == 0 {
+        return Err(Msg::StressZeroSize.to_string());
+    }
+
+    install_interrupt_handler();
+    println!("🔥 {}\n", Msg::StressHeader { path: &path, mb: &(size >> 20), iterations: &iterations });
+
+    let mut stats = Stats::default();
+    let start = Instant::now();
+    let mut completed = 0;
+    for iteration in 0..iterations {
+        if interrupted() {
+            println!("   ⏹️  {}", Msg::Interrupted);
+            break;
+        }
+        let before = stats.corrupted_words + stats.alloc_failures + stats.map_failures + stats.sync_failures;
+        let verified_before = stats.bytes_verified;
+        let t = Instant::now();
+        run_iteration(&file, size, iteration, &mut stats);
+        let errors = stats.corrupted_words + stats.alloc_failures + stats.map_failures + stats.sync_failures - before;
+        println!(
+            "   [{:>4}/{}] {:<13} {:>6.0} MB/s  {}",
+            iteration + 1,
+            iterations,
+            PATTERNS[iteration as usize % PATTERNS.len()],
+            // Jedes Byte wird einmal geschrieben und einmal gelesen
+            ((stats.bytes_verified - verified_before) * 2) as f64 / (1 << 20) as f64 / t.elapsed().as_secs_f64(),
+            if errors == 0 { "✅".to_string() } else { format!("❌ {}", Msg::StressErrors { count: &errors }) }
+        );
+        completed += 1;
+    }
+
+    println!("\n📊 {}", Msg::StressSummary { count: &completed, secs: &start.elapsed().as_secs_f64() });
+    println!("   • {}", Msg::StressVerified { mb: &(stats.bytes_verified >> 20) });
+    println!("   • {}", Msg::StressAllocFailures { count: &stats.alloc_failures });
+    println!("   • {}", Msg::StressMapFailures { count: &stats.map_failures });
+    println!("   • {}", Msg::StressSyncFailures { count: &stats.sync_failures });
+    println!("   • {}", Msg::StressCorrupted { count: &stats.corrupted_words });
+
+    let failures = stats.alloc_failures + stats.map_failures + stats.sync_failures + stats.corrupted_words;
+    if failures > 0 {
+        return Err(Msg::StressFailures { count: &failures }.to_string());
+    }
+    println!("   ✅ {}", Msg::StressClean);
+    Ok(())
+}
+
+/// Eine Runde: alle Chunks allokieren, füllen, syncen, prüfen, freigeben
+fn run_iteration(file: &File, size: u64, iteration: u32, stats: &mut Stats) {
+    let flags = KGSL_CACHEMODE_WRITEBACK << KGSL_CACHEMODE_SHIFT;
+    let mut buffers = Vec::new();
+    let mut remaining = size;
+    while remaining > 0 {
+        let chunk = remaining.min(CHUNK_SIZE);
+        match GpuBuffer::alloc(file, chunk, flags) {
+            Ok(buffer) => buffers.push(buffer),
+            Err(e) => {
+                eprintln!("   ⚠️  {}", Msg::StressAllocFailed { kb: &(chunk >> 10), error: &e });
+                stats.alloc_failures += 1;
+            }
+        }
+        remaining -= chunk;
+    }
+
+    for (index, buffer) in buffers.iter().enumerate() {
+        let mapping = match buffer.map() {
+            Ok(m) => m,
+            Err(e) => {
+                eprintln!("   ⚠️  {}", Msg::StressMmapFailed { id: &buffer.id, error: &e });
+                stats.map_failures += 1;
+                continue;
+            }
+        };
+        let seed = ((iteration as u64) << 32) | index as u64;
+        // Mappings sind seitenausgerichtet, jedes Wort ist ausgerichtet
+        let words = (buffer.size as usize).min(mapping.len()) / 8;
+        for i in 0..words {
+            mapping.write::<u64>(i * 8, pattern(iteration, seed, i));
+        }
+
+        // Aus dem CPU-Cache schreiben und verwerfen, damit aus dem DRAM gelesen wird
+        for op in [KGSL_GPUMEM_CACHE_CLEAN, KGSL_GPUMEM_CACHE_INV] {
+            if let Err(e) = buffer.sync(op) {
+                eprintln!("   ⚠️  {}", Msg::StressSyncFailed { id: &buffer.id, error: &e });
+                stats.sync_failures += 1;
+            }
+        }
+
+        let mut reported = 0;
+        for i in 0..words {
+            let expected = pattern(iteration, seed, i);
+            let word = mapping.read::<u64>(i * 8).unwrap_or(!expected);
+            if word != expected {
+                stats.corrupted_words += 1;
+                if reported < 4 {
+                    eprintln!(
+                        "   ❌ {}",
+                        Msg::StressMismatch { id: &buffer.id, gpuaddr: buffer.gpuaddr + (i * 8) as u64, expected, read: word }
+                    );
+                    reported += 1;
+                }
+            }
+        }
+        stats.bytes_verified += buffer.size;
+    }
+}
+
+fn pattern(iteration: u32, seed: u64, index: usize) -> u64 {
+    match iteration as usize % PATTERNS.len() {
+        0 => 0,
+        1 => u64::MAX,
+        2 if index.is_multiple_of(2) => 0xAAAA_AAAA_AAAA_AAAA,
+        2 => 0x5555_5555_5555_5555,
+        3 => 1u64 << (index % 64),
+        _ => (index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ seed,
+    }
+}
@@ -0,0 +1,85 @@
+//! `submit` - Anonymisiertes Ergebnis-Paket für die Geräte-Datenbank
+
+use std::os::fd::AsRawFd;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use adreno_ioctl::baseline;
+use adreno_ioctl::bench::submit_roundtrip;
+use adreno_ioctl::bundle::Submission;
+use adreno_ioctl::caps::capability_matrix;
+use adreno_ioctl::chip::{decode_chip_id, lookup_model};
+use adreno_ioctl::driver::{read_android_version, read_build_prop, uname};
+use adreno_ioctl::features::generation;
+use adreno_ioctl::kgsl::{read_devinfo, read_gpu_info, read_gpu_model, read_gpu_version};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::submit::Submitter;
+use adreno_ioctl::sysfs;
+
+use super::{open_device, open_device_rw, Args};
+
+/// NOP-Submits für die Latenz, wie `bench compute`
+const BENCH_ITERATIONS: u32 = 200;
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let output = args.value("--out")?.map(PathBuf::from);
+    let no_bench = args.flag("--no-bench");
+    let (path, file) = if no_bench { open_device(&mut args)? } else { open_device_rw(&mut args)? };
+    args.finish()?;
+    let fd = file.as_raw_fd();
+
+    let info = read_gpu_info(fd)?;
+    let chip = decode_chip_id(info.chip_id);
+    let gpu_model = read_gpu_model(fd);
+    let spec = chip.spec().or_else(|| gpu_model.as_deref().and_then(lookup_model));
+    let version = read_gpu_version(fd).ok();
+    let android = read_android_version();
+    let mut frequencies_hz = sysfs::available_frequencies(&sysfs::device_dir(&path)).unwrap_or_default();
+    frequencies_hz.sort_unstable();
+
+    let submit_roundtrip = if no_bench {
+        None
+    } else {
+        eprintln!("⏱️  {}", Msg::SubmitMeasuring { count: &BENCH_ITERATIONS });
+        let submitter = Submitter::new(&file, generation(info.chip_id))
+            .map_err(|e| Msg::SubmitUseNoBench { error: &Msg::CannotSetUpSubmission { error: &e } }.to_string())?;
+        Some(submit_roundtrip(&submitter, BENCH_ITERATIONS, Duration::from_secs(1)).map_err(|e| e.to_string())?)
+    };
+
+    let gmem_bytes = read_devinfo(fd).ok().map(|d| d.gmem_sizebytes as u64).filter(|&b| b > 0);
+    let mut comparisons = Vec::new();
+    if let Some(spec) = spec {
+        if let Some(known) = baseline::lookup(spec) {
+            comparisons.extend(frequencies_hz.last().map(|&hz| known.max_freq(hz)));
+            comparisons.extend(submit_roundtrip.map(|s| known.submit_median(s.median.as_secs_f64() * 1e6)));
+        }
+        comparisons.extend(gmem_bytes.map(|bytes| baseline::gmem(spec, bytes)));
+    }
+
+    let submission = Submission {
+        capabilities: capability_matrix(fd, &path, &chip),
+        chip: Some(chip),
+        gpu_model,
+        gmem_bytes,
+        frequencies_hz,
+        driver_version: version.as_ref().map(|v| v.driver_version),
+        device_version: version.as_ref().map(|v| v.device_version),
+        kernel_release: uname().0,
+        android_release: android.as_ref().map(|a| a.release.clone()),
+        android_sdk: android.and_then(|a| a.sdk),
+        soc_model: read_build_prop("ro.soc.model"),
+        submit_roundtrip,
+        comparisons,
+    };
+    let text = submission.to_json().to_pretty();
+
+    match output {
+        Some(out) => {
+            std::fs::write(&out, text + "\n").map_err(|e| Msg::CannotWrite { path: &out.display(), error: &e }.to_string())?;
+            println!("📦 {}", Msg::SubmitWritten { path: &out.display() });
+            println!("{}", Msg::SubmitPrivacy);
+        }
+        None => println!("{}", text),
+    }
+    Ok(())
+}
@@ -0,0 +1,65 @@
+//! `timesync` - GPU-Ticks gegen CLOCK_MONOTONIC kalibrieren
+
+use std::os::unix::io::AsRawFd;
+use std::time::Duration;
+
+use adreno_ioctl::fence::monotonic_now;
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::perfcounter::AlwaysOnCounter;
+use adreno_ioctl::timesync::{read_cpu_qtimer, read_qtimer, TimestampConverter, QTIMER_HZ};
+
+use super::{fail, open_device, parse_duration, Args, EXIT_UNSUPPORTED};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let samples = args.parsed::<usize>("--samples")?.unwrap_or(32).max(2);
+    let interval = match args.value("--interval")? {
+        Some(t) => parse_duration(&t)?,
+        None => Duration::from_millis(5),
+    };
+    let (path, file) = open_device(&mut args)?;
+    args.finish()?;
+
+    println!("⏱️  {}", Msg::TimesyncHeader { path: &path });
+    match read_qtimer(file.as_raw_fd()) {
+        Ok(qtimer) => println!("   {}", Msg::TimesyncQtimer { gpuaddr: qtimer.gpuaddr, size: &qtimer.size }),
+        Err(e) => println!("   {}", Msg::TimesyncNoQtimer { error: &e }),
+    }
+
+    let mut calibrated = false;
+    match TimestampConverter::from_qtimer(samples, interval) {
+        Ok(converter) => {
+            print_converter(Msg::TimesyncCpuSource, &converter, samples, interval);
+            if let Some(ticks) = read_cpu_qtimer() {
+                let now = monotonic_now();
+                println!("   {}", Msg::TimesyncNow { ticks: &ticks, secs: &(converter.to_monotonic_ns(ticks) as f64 / 1e9) });
+                println!("   {}", Msg::TimesyncClockGettime { secs: &now.as_secs_f64() });
+            }
+            calibrated = true;
+        }
+        Err(e) => println!("\n   {}", Msg::TimesyncCpuQtimer { error: &e }),
+    }
+
+    // GPU-seitig über den Always-On-Zähler
+    match AlwaysOnCounter::open(file) {
+        Ok(counter) => {
+            let converter = TimestampConverter::calibrate(|| counter.read(), QTIMER_HZ, samples, interval)
+                .map_err(|e| Msg::TimesyncAlwaysOnFailed { error: &e }.to_string())?;
+            print_converter(Msg::TimesyncGpuSource, &converter, samples, interval);
+            calibrated = true;
+        }
+        Err(e) => println!("\n   {}", Msg::TimesyncNoAlwaysOn { error: &e }),
+    }
+
+    if !calibrated {
+        return Err(fail(EXIT_UNSUPPORTED, Msg::TimesyncNoSource.to_string()));
+    }
+    Ok(())
+}
+
+fn print_converter(source: Msg, converter: &TimestampConverter, samples: usize, interval: Duration) {
+    println!("\n   {}", source);
+    println!("   {}", Msg::TimesyncNominal { mhz: &(converter.nominal_hz() as f64 / 1e6) });
+    println!("   {}", Msg::TimesyncMeasured { mhz: &(converter.frequency_hz() / 1e6), ppm: &converter.drift_ppm() });
+    let span = format!("{:?}", interval * (samples as u32 - 1));
+    println!("   {}", Msg::TimesyncUncertainty { ns: &converter.uncertainty_ns(), samples: &samples, span: &span });
+}
@@ -0,0 +1,164 @@
+//! `trace` - KGSL-ioctls eines anderen Prozesses live dekodieren (wie strace)
+
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use adreno_ioctl::backend::{Capture, CaptureEntry};
+use adreno_ioctl::ioctls::{name_of, property_name};
+use adreno_ioctl::memory::describe_flags;
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::trace::{KgslCall, TraceEvent, Tracer};
+
+use super::{format_size, install_interrupt_handler, interrupt_blocking_calls, interrupted, Args};
+
+/// Zeitraum der Zusammenfassung
+const SUMMARY_PERIOD: Duration = Duration::from_secs(1);
+
+/// Zähler für die periodische Zusammenfassung
+#[derive(Default)]
+struct Window {
+    calls: u64,
+    submits: u64,
+    allocs: u64,
+    alloc_bytes: u64,
+    frees: u64,
+    errors: u64,
+}
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let pid: i32 = args.parsed("--pid")?.ok_or_else(|| Msg::TraceNeedsPid.to_string())?;
+    let count: Option<u64> = args.parsed("--count")?;
+    let record = args.value("--record")?.map(PathBuf::from);
+    args.finish()?;
+
+    install_interrupt_handler();
+    interrupt_blocking_calls();
+
+    let mut tracer = Tracer::attach(pid).map_err(|e| match e.raw_os_error() {
+        Some(libc::EPERM) => Msg::TraceAttachDenied { pid: &pid, error: &e }.to_string(),
+        _ => Msg::TraceAttachFailed { pid: &pid, error: &e }.to_string(),
+    })?;
+    println!("🔍 {}\n", Msg::TraceHeader { pid: &pid, threads: &tracer.thread_count() });
+
+    let start = Instant::now();
+    let mut window = Window::default();
+    let mut window_start = start;
+    let mut total = 0u64;
+    let mut capture = Capture::new().comment(format!("traced from pid {}", pid));
+
+    while !interrupted() && count.is_none_or(|c| total < c) {
+        let event = match tracer.next_event() {
+            Ok(Some(event)) => event,
+            Ok(None) if tracer.is_alive() => continue,
+            Ok(None) => {
+                println!("\n🏁 {}", Msg::TraceExited { pid: &pid });
+                break;
+            }
+            Err(e) => return Err(Msg::TracePtraceFailed { error: &e }.to_string()),
+        };
+        total += 1;
+        if let KgslCall::GetProperty { prop, size, value } = &event.call {
+            capture.push(CaptureEntry {
+                request: event.request,
+                prop: *prop,
+                size: *size,
+                result: if event.succeeded() { Ok(value.clone()) } else { Err(-event.result as i32) },
+            });
+        }
+        print_event(&event, start);
+        window.add(&event);
+
+        let elapsed = event.time.duration_since(window_start);
+        if elapsed >= SUMMARY_PERIOD {
+            window.print(elapsed);
+            window = Window::default();
+            window_start = event.time;
+        }
+    }
+
+    println!("\n📊 {}", Msg::TraceTotal { count: &total, secs: &start.elapsed().as_secs_f64() });
+    if let Some(path) = record {
+        capture.save(&path).map_err(|e| Msg::CannotWrite { path: &path.display(), error: &e }.to_string())?;
+        println!("💾 {}", Msg::TraceSaved { count: &capture.entries.len(), path: &path.display() });
+    }
+    Ok(())
+}
+
+fn print_event(event: &TraceEvent, start: Instant) {
+    let name = name_of(event.request).map_or_else(
+        || format!("0x{:08x}", event.request),
+        |n| n.trim_start_matches("IOCTL_KGSL_").to_string(),
+    );
+    let detail = match &event.call {
+        KgslCall::GetProperty { prop, size, .. } | KgslCall::SetProperty { prop, size } => {
+            format!("{} ({} bytes)", property_label(*prop), size)
+        }
+        KgslCall::GpuobjAlloc { size, flags, id, mmapsize } if event.succeeded() => {
+            format!("{} [{}] → id {} (mmap {})", format_size(*size), describe_flags(*flags), id, format_size(*mmapsize))
+        }
+        KgslCall::GpuobjAlloc { size, flags, .. } => format!("{} [{}]", format_size(*size), describe_flags(*flags)),
+        KgslCall::GpuobjFree { id } => format!("id {}", id),
+        KgslCall::GpuCommand { context_id, numcmds, numobjs, timestamp } => {
+            format!("ctx {} cmds {} objs {} → ts {}", context_id, numcmds, numobjs, timestamp)
+        }
+        KgslCall::DrawctxtCreate { flags, context_id } => format!("flags 0x{:08x} → ctx {}", flags, context_id),
+        KgslCall::DrawctxtDestroy { context_id } => format!("ctx {}", context_id),
+        KgslCall::Other => String::new(),
+    };
+    let result = if event.succeeded() {
+        event.result.to_string()
+    } else {
+        format!("{} ({})", event.result, io::Error::from_raw_os_error(-event.result as i32))
+    };
+    println!(
+        "{:>9.3} [{}] fd {} {} {} = {}",
+        event.time.duration_since(start).as_secs_f64(),
+        event.tid,
+        event.fd,
+        name,
+        detail,
+        result
+    );
+}
+
+fn property_label(prop: u32) -> String {
+    match property_name(prop) {
+        Some(name) => name.to_string(),
+        None => format!("0x{:02x}", prop),
+    }
+}
+
+impl Window {
+    fn add(&mut self, event: &TraceEvent) {
+        self.calls += 1;
+        if !event.succeeded() {
+            self.errors += 1;
+            return;
+        }
+        match event.call {
+            KgslCall::GpuCommand { .. } => self.submits += 1,
+            KgslCall::GpuobjAlloc { size, .. } => {
+                self.allocs += 1;
+                self.alloc_bytes += size;
+            }
+            KgslCall::GpuobjFree { .. } => self.frees += 1,
+            _ => {}
+        }
+    }
+
+    fn print(&self, elapsed: Duration) {
+        let secs = elapsed.as_secs_f64();
+        println!(
+            "   📊 {}",
+            Msg::TraceWindow {
+                calls: &(self.calls as f64 / secs),
+                submits: &(self.submits as f64 / secs),
+                allocs: &self.allocs,
+                bytes: &format_size(self.alloc_bytes),
+                frees: &self.frees,
+                errors: &self.errors,
+            }
+        );
+    }
+}
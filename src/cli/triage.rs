@@ -0,0 +1,123 @@
+//! `triage` - Nach einem GPU-Reset den wahrscheinlichen Verursacher benennen
+
+use std::fs;
+
+use adreno_ioctl::debugfs;
+use adreno_ioctl::dmesg::read_kernel_log;
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::procmem::read_processes;
+use adreno_ioctl::sysfs;
+use adreno_ioctl::sysroot;
+use adreno_ioctl::triage::{identify, parse_faults, read_snapshot, snapshot_fault_count};
+
+use super::{device_path, format_size, set_exit_code, Args, EXIT_PARTIAL};
+
+/// So viele Fault-Zeilen werden gezeigt
+const SHOWN_FAULTS: usize = 5;
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let log_file = args.value("--log")?;
+    let snapshot_file = args.value("--snapshot")?;
+    let device = device_path(&mut args)?;
+    args.finish()?;
+
+    let dir = sysfs::device_dir(&device);
+    println!("🩺 {}", Msg::TriageHeader { device: &device });
+
+    // Kernel-Log: gespeicherte Datei oder Ringpuffer
+    let log = match &log_file {
+        Some(path) => fs::read_to_string(path).map_err(|e| Msg::CannotRead { path: &path, error: &e }.to_string())?,
+        None => read_kernel_log().unwrap_or_else(|e| {
+            println!("   ⚠️  {}", Msg::TriageNoLog { error: &e });
+            set_exit_code(EXIT_PARTIAL);
+            String::new()
+        }),
+    };
+    let faults = parse_faults(&log);
+    println!("   • {}", Msg::TriageFaultLines { count: &faults.len() });
+    for fault in faults.iter().rev().take(SHOWN_FAULTS).rev() {
+        println!("     {}", fault.line);
+    }
+
+    let fault_count = snapshot_fault_count(&dir).ok();
+    let snapshot_path = snapshot_file.map_or_else(|| dir.join("snapshot/dump"), Into::into);
+    let snapshot = read_snapshot(&snapshot_path).ok().flatten();
+    match (&snapshot, fault_count) {
+        (Some(s), _) => println!(
+            "   • {}",
+            Msg::TriageSnapshot {
+                chip_id: s.chip_id,
+                owner: &s.process.as_deref().unwrap_or("?"),
+                pid: &s.pid.map_or("?".to_string(), |p| p.to_string()),
+                context: &s.context.map_or("?".to_string(), |c| c.to_string()),
+            }
+        ),
+        (None, Some(count)) => println!("   • {}", Msg::TriageSnapshotNoDump { count: &count }),
+        (None, None) => println!("   • {}", Msg::TriageNoSnapshot),
+    }
+
+    let contexts = match debugfs::collect(&device) {
+        Ok(snapshot) => snapshot.contexts,
+        Err(reason) => {
+            println!("   • {}", Msg::TriageContextList { reason: &reason });
+            Vec::new()
+        }
+    };
+
+    if faults.is_empty() && snapshot.is_none() && fault_count.is_none_or(|c| c == 0) {
+        println!("\n✅ {}", Msg::TriageNoReset);
+        return Ok(());
+    }
+
+    let Some(suspect) = identify(&faults, snapshot.as_ref(), &contexts) else {
+        println!("\n❓ {}", Msg::TriageNoSuspect);
+        return Ok(());
+    };
+
+    println!(
+        "\n🎯 {}",
+        Msg::TriageSuspect {
+            process: &suspect.process.as_deref().unwrap_or("?"),
+            pid: &suspect.pid.map_or("?".to_string(), |p| p.to_string()),
+            context: &suspect.context.map_or("?".to_string(), |c| c.to_string()),
+        }
+    );
+    println!("   {}", Msg::TriageEvidence { evidence: &suspect.evidence.join(", ") });
+    for conflict in &suspect.conflicts {
+        println!("   ⚠️  {}", Msg::TriageConflict { conflict });
+    }
+
+    // Letzter Submission-Stand des Verdächtigen
+    let owned: Vec<_> = contexts
+        .iter()
+        .filter(|c| Some(c.id) == suspect.context || (suspect.pid.is_some() && c.pid == suspect.pid))
+        .collect();
+    if !owned.is_empty() {
+        println!("\n   📊 {}", Msg::TriageSubmissions);
+    }
+    for c in owned {
+        let ts = |t: Option<u32>| t.map_or("?".to_string(), |t| t.to_string());
+        println!(
+            "   • {}",
+            Msg::TriageContext {
+                id: &c.id,
+                kind: &c.kind.as_deref().unwrap_or("?"),
+                priority: &c.priority.map_or("?".to_string(), |p| p.to_string()),
+                queued: &ts(c.queued),
+                consumed: &ts(c.consumed),
+                retired: &ts(c.retired),
+                inflight: &ts(c.inflight()),
+            }
+        );
+    }
+    if let Some(pid) = suspect.pid {
+        if let Some(mem) = read_processes().ok().and_then(|p| p.into_iter().find(|m| m.pid == pid)) {
+            println!("   • {}", Msg::TriageMemory { size: &format_size(mem.total_bytes) });
+        } else if !sysroot::resolve(format!("/proc/{}", pid)).exists() {
+            println!("   • {}", Msg::TriageExited);
+        }
+        let faults_by_pid = faults.iter().filter(|f| f.pid == Some(pid)).count();
+        println!("   • {}", Msg::TriagePidFaults { count: &faults_by_pid });
+    }
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! `turnip` - Unterstützt Mesa/Turnip diese GPU, und was wird dafür gebraucht?
+
+use std::fs;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+
+use adreno_ioctl::chip::{decode_chip_id, ChipInfo};
+use adreno_ioctl::devicetree::read_strings;
+use adreno_ioctl::driver::read_android_version;
+use adreno_ioctl::kgsl::{find_kgsl_devices, read_gpu_info};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::sysroot;
+use adreno_ioctl::turnip::{advise, chip_from_compatible, detect_kernel_driver, KernelDriver, Support};
+
+use super::{open_path, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let device = args.value("--device")?;
+    args.finish()?;
+
+    let kgsl = device.or_else(|| find_kgsl_devices().into_iter().next());
+    let driver = detect_kernel_driver(kgsl.is_some());
+    let chip = match (&kgsl, &driver) {
+        (Some(path), _) => {
+            let file = open_path(path)?;
+            decode_chip_id(read_gpu_info(file.as_raw_fd())?.chip_id)
+        }
+        (None, KernelDriver::Msm(node)) => msm_chip(node).ok_or_else(|| Msg::TurnipNoCompatible.to_string())?,
+        (None, _) => return Err(Msg::TurnipNoDevice.to_string()),
+    };
+    let android = read_android_version();
+    let advice = advise(&chip, &driver, android.as_ref());
+
+    println!("🐧 {}", Msg::TurnipHeader);
+    println!("   • {}", Msg::TurnipGpu { model: &chip.model_name, chip_id: chip.raw_id });
+    println!("   • {}", Msg::TurnipDriver { driver: &driver.label() });
+    match &android {
+        Some(a) => println!("   • {}", Msg::TurnipAndroid { release: &a.release, sdk: &a.sdk.map_or("?".to_string(), |s| s.to_string()) }),
+        None => println!("   • {}", Msg::TurnipNoAndroid),
+    }
+
+    match advice.support {
+        Support::Supported(mesa) => println!("\n✅ {}", Msg::TurnipSupported { mesa: &mesa }),
+        Support::Experimental(mesa) => println!("\n🧪 {}", Msg::TurnipExperimental { mesa: &mesa }),
+        Support::TooOld => println!("\n❌ {}", Msg::TurnipTooOld),
+        Support::Unknown => println!("\n❓ {}", Msg::TurnipUnknown),
+    }
+    if !advice.requirements.is_empty() {
+        println!("   {}", Msg::TurnipRequirements);
+        for requirement in &advice.requirements {
+            println!("   • {}", requirement);
+        }
+    }
+    for note in &advice.notes {
+        println!("   💡 {}", note);
+    }
+    Ok(())
+}
+
+/// Chip aus dem `compatible` des DRM-Geräts (`/sys/class/drm/renderD*/device/of_node`)
+fn msm_chip(node: &Path) -> Option<ChipInfo> {
+    let name = node.file_name()?.to_str()?;
+    let of_node = fs::canonicalize(sysroot::resolve(format!("/sys/class/drm/{}/device/of_node", name))).ok()?;
+    let (major, minor, patch) = read_strings(&of_node.join("compatible")).iter().find_map(|c| chip_from_compatible(c))?;
+    Some(decode_chip_id(u32::from_be_bytes([major, minor, patch, 0])))
+}
@@ -0,0 +1,42 @@
+//! `usermem` - CPU-Puffer zero-copy in den GPU-Adressraum importieren
+
+use adreno_ioctl::memory::{import_user_memory, ImportPath, PageBuffer};
+use adreno_ioctl::messages::Msg;
+
+use super::{open_device_rw, parse_size, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let size = match args.value("--size")? {
+        Some(s) => parse_size(&s)?,
+        None => 64 * 1024,
+    };
+    let (path, file) = open_device_rw(&mut args)?;
+    args.finish()?;
+
+    let mut buffer = PageBuffer::new(size as usize).map_err(|e| Msg::MmapFailed { error: &e }.to_string())?;
+    let slice = buffer.as_mut_slice();
+    for (i, byte) in slice.iter_mut().enumerate() {
+        *byte = (i % 251) as u8;
+    }
+    let host = slice.as_ptr() as usize;
+    let len = slice.len();
+
+    println!("📥 {}", Msg::UsermemImporting { kb: &(len / 1024), host: host as u64, path: &path });
+    let imported = import_user_memory(&file, slice, 0).map_err(|e| Msg::UsermemImportFailed { error: &e }.to_string())?;
+
+    let method = match imported.path {
+        ImportPath::GpuobjImport => "GPUOBJ_IMPORT (KGSL_USER_MEM_TYPE_ADDR)",
+        ImportPath::MapUserMem => "MAP_USER_MEM (legacy)",
+    };
+    println!("   ✅ {}", Msg::UsermemMethod { method: &method });
+    if let Some(id) = imported.id {
+        println!("   • ID:      {}", id);
+    }
+    println!("   • GPU VA:  0x{:x}", imported.gpuaddr);
+    println!("   • {}", Msg::UsermemSize { size: &imported.size });
+    println!("   • Flags:   0x{:x}", imported.flags);
+
+    drop(imported);
+    println!("   ✅ {}", Msg::UsermemUnmapped);
+    Ok(())
+}
@@ -0,0 +1,110 @@
+//! `vamap` - GPU-Adressraum des Prozesses als Text-Diagramm oder JSON
+
+use std::os::fd::AsRawFd;
+
+use adreno_ioctl::json::Json;
+use adreno_ioctl::memory::{describe_flags, GpuBuffer, KGSL_MEMTYPE_SHIFT};
+use adreno_ioctl::messages::Msg;
+use adreno_ioctl::schema::versioned;
+use adreno_ioctl::vamap::{VaMap, VaRegion};
+
+use super::{format_size, open_device, open_device_rw, Args};
+
+/// Breite des Balkendiagramms in Zeichen
+const BAR_WIDTH: usize = 64;
+
+/// Größen der Demo-Allokationen, zyklisch
+const DEMO_SIZES: [u64; 4] = [4096, 64 * 1024, 256 * 1024, 1024 * 1024];
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let max_id: u32 = args.parsed("--max-id")?.unwrap_or(1024);
+    let demo: usize = args.parsed("--demo")?.unwrap_or(0);
+    let json = args.flag("--json");
+    // Schreibzugriff nur für die Demo-Allokationen
+    let (path, file) = if demo > 0 { open_device_rw(&mut args)? } else { open_device(&mut args)? };
+    args.finish()?;
+
+    // Ein frischer Prozess hat noch keine Objekte - optional welche anlegen
+    let mut buffers = Vec::new();
+    for i in 0..demo {
+        let memtype = (i as u64 % 7) << KGSL_MEMTYPE_SHIFT;
+        let buffer = GpuBuffer::alloc(&file, DEMO_SIZES[i % DEMO_SIZES.len()], memtype)
+            .map_err(|e| Msg::GpuobjAllocFailed { error: &e }.to_string())?;
+        buffers.push(buffer);
+    }
+    // Jede zweite wieder freigeben, damit Lücken sichtbar werden
+    let mut index = 0;
+    buffers.retain(|_| {
+        index += 1;
+        index % 2 == 1
+    });
+
+    let map = VaMap::read(file.as_raw_fd(), max_id);
+    if json {
+        println!("{}", versioned(to_json(&path, &map)).to_pretty());
+    } else {
+        print_map(&path, &map);
+    }
+    Ok(())
+}
+
+fn print_map(path: &str, map: &VaMap) {
+    println!("🗺️  {}", Msg::VamapHeader { path: &path });
+    let Some((start, end)) = map.span() else {
+        println!("   {}", Msg::VamapEmpty);
+        return;
+    };
+
+    println!("\n   {:<31} {:>10}  {:>5}  Flags", "Range", "Size", "ID");
+    for region in &map.regions {
+        let range = format!("0x{:012x}-0x{:012x}", region.start(), region.end());
+        match region {
+            VaRegion::Object(o) => {
+                println!("   {} {:>10}  {:>5}  {}", range, format_size(o.size), o.id, describe_flags(o.flags));
+            }
+            VaRegion::Gap { len, .. } => println!("   {} {:>10}  {:>5}  {}", range, format_size(*len), "-", Msg::VamapGap),
+        }
+    }
+
+    println!("\n   0x{:x} {} 0x{:x}", start, bar(map, start, end), end);
+    println!(
+        "   {}",
+        Msg::VamapSummary { count: &map.objects().count(), used: &format_size(map.used_bytes()), span: &format_size(end - start) }
+    );
+}
+
+/// Balken über den gesamten Span: `#` belegt, `.` Lücke
+fn bar(map: &VaMap, start: u64, end: u64) -> String {
+    let span = (end - start).max(1) as f64;
+    let mut cells = vec!['.'; BAR_WIDTH];
+    for obj in map.objects() {
+        let from = ((obj.gpuaddr - start) as f64 / span * BAR_WIDTH as f64) as usize;
+        let to = (((obj.gpuaddr + obj.size.max(1) - start) as f64 / span * BAR_WIDTH as f64).ceil() as usize).min(BAR_WIDTH);
+        for cell in &mut cells[from.min(BAR_WIDTH - 1)..to.max(from + 1).min(BAR_WIDTH)] {
+            *cell = '#';
+        }
+    }
+    format!("[{}]", cells.into_iter().collect::<String>())
+}
+
+fn to_json(path: &str, map: &VaMap) -> Json {
+    let regions = map
+        .regions
+        .iter()
+        .map(|region| match region {
+            VaRegion::Object(o) => Json::object()
+                .field("kind", "object")
+                .field("id", o.id)
+                .field("gpuaddr", o.gpuaddr)
+                .field("size", o.size)
+                .field("va_len", o.va_len)
+                .field("flags", o.flags)
+                .field("flags_decoded", describe_flags(o.flags)),
+            VaRegion::Gap { start, len } => Json::object().field("kind", "gap").field("gpuaddr", *start).field("size", *len),
+        })
+        .collect::<Vec<_>>();
+    Json::object()
+        .field("device", path)
+        .field("used_bytes", map.used_bytes())
+        .field("regions", regions)
+}
@@ -0,0 +1,31 @@
+//! `wait-idle` - Wartet, bis die GPU alle eingereichte Arbeit abgeschlossen hat
+
+use std::time::Duration;
+
+use adreno_ioctl::kgsl::wait_idle;
+use adreno_ioctl::messages::Msg;
+
+use super::{open_device, parse_duration, Args};
+
+pub fn run(mut args: Args) -> Result<(), String> {
+    let timeout = match args.value("--timeout")? {
+        Some(t) => parse_duration(&t)?,
+        None => Duration::from_secs(10),
+    };
+    let (path, file) = open_device(&mut args)?;
+    args.finish()?;
+
+    match wait_idle(&file, timeout) {
+        Ok(state) => {
+            println!(
+                "✅ {}",
+                Msg::WaitIdleDone { path: &path, ms: &(state.waited.as_secs_f64() * 1000.0), retired: &state.retired }
+            );
+            Ok(())
+        }
+        Err(e) if e.raw_os_error() == Some(libc::ETIMEDOUT) => {
+            Err(Msg::WaitIdleBusy { path: &path, secs: &timeout.as_secs_f64() }.to_string())
+        }
+        Err(e) => Err(Msg::WaitIdleCannotRead { error: &e }.to_string()),
+    }
+}
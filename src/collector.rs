@@ -0,0 +1,418 @@
+//! Datenquellen als austauschbare Sammler
+//!
+//! Jede Quelle (ioctl, sysfs, devfreq, thermal, debugfs, ftrace) ist ein
+//! [`Collector`] und liefert pro Aufruf einen [`Record`] mit ihrem Namen als
+//! Art. Welche laufen, entscheidet [`select`] zur Laufzeit aus
+//! `--collectors` bzw. `ADRENO_IOCTL_COLLECTORS`; eine neue Quelle ist eine
+//! neue Implementierung plus ein Eintrag in [`builtin`] - oder ein Plugin
+//! aus [`crate::plugin`].
+
+use std::fs::{self, File};
+use std::io;
+use std::os::fd::AsRawFd;
+use std::path::{Path, PathBuf};
+
+use crate::caps::query_properties;
+use crate::debugfs;
+use crate::kgsl::{
+    read_gpu_info, read_interrupt_waits, read_timestamp, KgslDeviceInfo, KGSL_MEMSTORE_GLOBAL, KGSL_TIMESTAMP_QUEUED,
+    KGSL_TIMESTAMP_RETIRED,
+};
+use crate::memstore::Memstore;
+use crate::propmap::{property_id, Prop};
+use crate::sink::Record;
+use crate::sys;
+use crate::sysfs;
+use crate::sysroot;
+use crate::warnings::WarningCause;
+
+/// Umgebungsvariable mit der Standard-Auswahl, Syntax wie `--collectors`
+pub const COLLECTORS_ENV: &str = "ADRENO_IOCTL_COLLECTORS";
+
+/// Eine Datenquelle
+pub trait Collector: Send {
+    /// Kurzname für Auswahl und Datensatz-Art
+    fn name(&self) -> &str;
+
+    /// Ob die Quelle auf diesem System lesbar ist
+    fn supported(&self) -> bool;
+
+    fn collect(&mut self) -> io::Result<Record>;
+}
+
+fn unavailable(what: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, format!("{} not available", what))
+}
+
+// ============================================================================
+// ioctl
+// ============================================================================
+
+/// Obergrenze an ioctl-Syscalls je Sample, geprüft von `bench ioctl` und `benches/sampling.rs`
+pub const IOCTL_SAMPLE_BUDGET: u64 = 2;
+
+/// Syscall-Zähler des [`IoctlCollector`], gemessen an [`sys::device_calls`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IoctlStats {
+    /// Einmalig beim Öffnen (Geräteinfo, Property-Liste, Memstore)
+    pub setup_syscalls: u64,
+    pub samples: u64,
+    /// Syscalls aller Samples zusammen
+    pub sample_syscalls: u64,
+}
+
+impl IoctlStats {
+    pub fn per_sample(&self) -> f64 {
+        self.sample_syscalls as f64 / self.samples.max(1) as f64
+    }
+}
+
+/// Gerätedaten über GETPROPERTY und die globalen Timestamps
+///
+/// Geräteinfo und `interrupt_waits` ändern sich zur Laufzeit nicht und
+/// werden beim Öffnen einmal gelesen; was der Treiber nicht meldet oder
+/// einmal mit ENOTTY/EINVAL ablehnt, wird nicht mehr gefragt. Retired kommt
+/// aus dem gemappten Memstore, so bleibt pro Sample nur der
+/// Queued-Timestamp als ioctl.
+pub struct IoctlCollector {
+    file: Option<File>,
+    info: Option<KgslDeviceInfo>,
+    interrupt_waits: Option<bool>,
+    memstore: Option<Memstore>,
+    read_queued: bool,
+    read_retired: bool,
+    stats: IoctlStats,
+}
+
+impl IoctlCollector {
+    pub fn new(device_path: &str) -> Self {
+        let mut collector = IoctlCollector {
+            file: File::open(sysroot::resolve(device_path)).ok(),
+            info: None,
+            interrupt_waits: None,
+            memstore: None,
+            read_queued: true,
+            read_retired: true,
+            stats: IoctlStats::default(),
+        };
+        collector.probe();
+        collector
+    }
+
+    /// Einmalige Abfragen beim Öffnen
+    fn probe(&mut self) {
+        let Some(file) = &self.file else {
+            return;
+        };
+        let fd = file.as_raw_fd();
+        let before = sys::device_calls();
+        self.info = read_gpu_info(fd).ok();
+        if self.info.is_some() {
+            // Leere Liste: Treiber ohne Property-Liste, dann einfach fragen
+            let advertised = query_properties(fd).unwrap_or_default();
+            if advertised.is_empty() || advertised.contains(&property_id(Prop::InterruptWaits)) {
+                self.interrupt_waits = read_interrupt_waits(fd);
+            }
+            self.memstore = Memstore::map(file).ok();
+            self.read_retired = self.memstore.is_none();
+        }
+        self.stats.setup_syscalls += sys::device_calls() - before;
+    }
+
+    pub fn stats(&self) -> IoctlStats {
+        self.stats
+    }
+
+    /// Timestamp per ioctl; abgelehnte Typen werden danach übersprungen
+    fn timestamp(&mut self, fd: i32, type_: u32) -> Option<u32> {
+        match read_timestamp(fd, KGSL_MEMSTORE_GLOBAL, type_) {
+            Ok(ts) => Some(ts),
+            Err(e) => {
+                if WarningCause::from_io(&e) == WarningCause::Kernel {
+                    match type_ {
+                        KGSL_TIMESTAMP_QUEUED => self.read_queued = false,
+                        _ => self.read_retired = false,
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+impl Collector for IoctlCollector {
+    fn name(&self) -> &str {
+        "ioctl"
+    }
+
+    fn supported(&self) -> bool {
+        self.info.is_some()
+    }
+
+    fn collect(&mut self) -> io::Result<Record> {
+        let fd = self.file.as_ref().ok_or_else(|| unavailable("device"))?.as_raw_fd();
+        let info = self.info.ok_or_else(|| unavailable("GPU info"))?;
+        let before = sys::device_calls();
+        let queued = if self.read_queued { self.timestamp(fd, KGSL_TIMESTAMP_QUEUED) } else { None };
+        let retired = match &self.memstore {
+            Some(memstore) => memstore.timestamps(KGSL_MEMSTORE_GLOBAL).map(|ts| ts.retired),
+            None if self.read_retired => self.timestamp(fd, KGSL_TIMESTAMP_RETIRED),
+            None => None,
+        };
+        self.stats.samples += 1;
+        self.stats.sample_syscalls += sys::device_calls() - before;
+        Ok(Record::new(self.name())
+            .field("device_id", info.device_id)
+            .field("chip_id", info.chip_id)
+            .field("mmu_enabled", info.mmu_enabled != 0)
+            .field("interrupt_waits", self.interrupt_waits)
+            .field("queued", queued)
+            .field("retired", retired))
+    }
+}
+
+// ============================================================================
+// sysfs
+// ============================================================================
+
+/// Frequenz, Auslastung und Power Levels aus `/sys/class/kgsl/<dev>`
+pub struct SysfsCollector {
+    dir: PathBuf,
+}
+
+impl SysfsCollector {
+    pub fn new(device_path: &str) -> Self {
+        SysfsCollector { dir: sysfs::device_dir(device_path) }
+    }
+}
+
+impl Collector for SysfsCollector {
+    fn name(&self) -> &str {
+        "sysfs"
+    }
+
+    fn supported(&self) -> bool {
+        sysfs::gpuclk(&self.dir).is_ok()
+    }
+
+    fn collect(&mut self) -> io::Result<Record> {
+        let dir = &self.dir;
+        Ok(Record::new(self.name())
+            .field("freq_hz", sysfs::gpuclk(dir)?)
+            .field("busy_percent", sysfs::busy_percent(dir).ok())
+            .field("min_pwrlevel", sysfs::min_pwrlevel(dir).ok())
+            .field("max_pwrlevel", sysfs::max_pwrlevel(dir).ok())
+            .field("thermal_pwrlevel", sysfs::thermal_pwrlevel(dir).ok())
+            .field("preempt_count", sysfs::preempt_count(dir).ok())
+            .field("ifpc_count", sysfs::ifpc_count(dir).ok()))
+    }
+}
+
+// ============================================================================
+// devfreq
+// ============================================================================
+
+/// Governor und Frequenzgrenzen aus `<dev>/devfreq`
+pub struct DevfreqCollector {
+    dir: PathBuf,
+}
+
+impl DevfreqCollector {
+    pub fn new(device_path: &str) -> Self {
+        DevfreqCollector { dir: sysfs::device_dir(device_path).join("devfreq") }
+    }
+}
+
+impl Collector for DevfreqCollector {
+    fn name(&self) -> &str {
+        "devfreq"
+    }
+
+    fn supported(&self) -> bool {
+        self.dir.join("cur_freq").exists()
+    }
+
+    fn collect(&mut self) -> io::Result<Record> {
+        let read = |file: &str| sysfs::read_u64(self.dir.join(file)).ok();
+        Ok(Record::new(self.name())
+            .field("governor", sysfs::read_string(self.dir.join("governor")).ok())
+            .field("cur_freq_hz", sysfs::read_u64(self.dir.join("cur_freq"))?)
+            .field("target_freq_hz", read("target_freq"))
+            .field("min_freq_hz", read("min_freq"))
+            .field("max_freq_hz", read("max_freq")))
+    }
+}
+
+// ============================================================================
+// thermal
+// ============================================================================
+
+/// GPU-Temperatur aus dem KGSL-Knoten oder den Thermal-Zonen
+pub struct ThermalCollector {
+    files: Vec<PathBuf>,
+}
+
+impl ThermalCollector {
+    pub fn new(device_path: &str) -> Self {
+        ThermalCollector { files: sysfs::temperature_files(&sysfs::device_dir(device_path)) }
+    }
+}
+
+impl Collector for ThermalCollector {
+    fn name(&self) -> &str {
+        "thermal"
+    }
+
+    fn supported(&self) -> bool {
+        !self.files.is_empty()
+    }
+
+    fn collect(&mut self) -> io::Result<Record> {
+        Ok(Record::new(self.name())
+            .field("temp_c", sysfs::read_temperature(&self.files)?)
+            .field("sensors", self.files.len()))
+    }
+}
+
+// ============================================================================
+// debugfs
+// ============================================================================
+
+/// Prozesse, Contexts und Speicher aus KGSL-debugfs (Root, userdebug)
+pub struct DebugfsCollector {
+    root: Option<PathBuf>,
+    device_path: String,
+}
+
+impl DebugfsCollector {
+    pub fn new(device_path: &str) -> Self {
+        DebugfsCollector { root: debugfs::detect().ok(), device_path: device_path.to_string() }
+    }
+}
+
+impl Collector for DebugfsCollector {
+    fn name(&self) -> &str {
+        "debugfs"
+    }
+
+    fn supported(&self) -> bool {
+        self.root.is_some()
+    }
+
+    fn collect(&mut self) -> io::Result<Record> {
+        let root = self.root.as_deref().ok_or_else(|| unavailable("debugfs"))?;
+        let snapshot = debugfs::collect_from(root, &self.device_path);
+        Ok(Record::new(self.name())
+            .field("processes", snapshot.processes.len())
+            .field("process_bytes", snapshot.processes.iter().map(|p| p.total_bytes()).sum::<u64>())
+            .field("global_bytes", snapshot.globals.iter().map(|e| e.size).sum::<u64>())
+            .field("contexts", snapshot.contexts.len()))
+    }
+}
+
+// ============================================================================
+// ftrace
+// ============================================================================
+
+/// Mountpoints von tracefs, neuer zuerst
+pub const TRACEFS_DIRS: [&str; 2] = ["/sys/kernel/tracing", "/sys/kernel/debug/tracing"];
+
+/// tracefs-Verzeichnis mit `events/kgsl`, falls gemountet
+pub fn kgsl_tracefs() -> Option<PathBuf> {
+    TRACEFS_DIRS.iter().map(sysroot::resolve).find(|dir| dir.join("events/kgsl").is_dir())
+}
+
+/// Zustand der `kgsl`-Tracepoints und des Ringpuffers; liest nur, schaltet nichts
+pub struct FtraceCollector {
+    dir: Option<PathBuf>,
+}
+
+impl FtraceCollector {
+    pub fn new() -> Self {
+        FtraceCollector { dir: kgsl_tracefs() }
+    }
+}
+
+impl Default for FtraceCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Summe eines Felds aus `per_cpu/cpu*/stats` (`entries: 12`)
+fn per_cpu_stat(dir: &Path, key: &str) -> Option<u64> {
+    let cpus = fs::read_dir(dir.join("per_cpu")).ok()?;
+    Some(
+        cpus.flatten()
+            .filter_map(|cpu| fs::read_to_string(cpu.path().join("stats")).ok())
+            .filter_map(|text| {
+                text.lines().find_map(|line| line.strip_prefix(key)?.strip_prefix(':')?.trim().parse::<u64>().ok())
+            })
+            .sum(),
+    )
+}
+
+impl Collector for FtraceCollector {
+    fn name(&self) -> &str {
+        "ftrace"
+    }
+
+    fn supported(&self) -> bool {
+        self.dir.is_some()
+    }
+
+    fn collect(&mut self) -> io::Result<Record> {
+        let dir = self.dir.as_deref().ok_or_else(|| unavailable("tracefs with kgsl events"))?;
+        let events: Vec<PathBuf> =
+            fs::read_dir(dir.join("events/kgsl"))?.flatten().map(|e| e.path()).filter(|p| p.is_dir()).collect();
+        let enabled = events.iter().filter(|p| sysfs::read_string(p.join("enable")).is_ok_and(|v| v == "1")).count();
+        Ok(Record::new(self.name())
+            .field("tracing_on", sysfs::read_u64(dir.join("tracing_on")).ok().map(|v| v != 0))
+            .field("kgsl_events", events.len())
+            .field("kgsl_enabled", enabled)
+            .field("entries", per_cpu_stat(dir, "entries"))
+            .field("overrun", per_cpu_stat(dir, "overrun")))
+    }
+}
+
+// ============================================================================
+// Auswahl
+// ============================================================================
+
+/// Alle eingebauten Sammler in Standardreihenfolge
+pub fn builtin(device_path: &str) -> Vec<Box<dyn Collector>> {
+    vec![
+        Box::new(IoctlCollector::new(device_path)),
+        Box::new(SysfsCollector::new(device_path)),
+        Box::new(DevfreqCollector::new(device_path)),
+        Box::new(ThermalCollector::new(device_path)),
+        Box::new(DebugfsCollector::new(device_path)),
+        Box::new(FtraceCollector::new()),
+    ]
+}
+
+/// Filtert nach einer Auswahl wie `sysfs,thermal` (nur diese) oder
+/// `-debugfs,-ftrace` (alle außer diesen); `+name` fügt wieder hinzu.
+/// Mehrere Auswahlen wirken nacheinander.
+pub fn select(collectors: Vec<Box<dyn Collector>>, specs: &[&str]) -> Result<Vec<Box<dyn Collector>>, String> {
+    let names: Vec<String> = collectors.iter().map(|c| c.name().to_string()).collect();
+    let mut enabled = vec![true; names.len()];
+    for spec in specs {
+        let tokens: Vec<&str> = spec.split(',').map(str::trim).filter(|t| !t.is_empty()).collect();
+        if tokens.iter().any(|t| !t.starts_with(['+', '-'])) {
+            enabled.fill(false);
+        }
+        for token in tokens {
+            let (on, name) = match token.strip_prefix('-') {
+                Some(name) => (false, name),
+                None => (true, token.trim_start_matches('+')),
+            };
+            let index = names
+                .iter()
+                .position(|n| *n == name)
+                .ok_or_else(|| format!("Unknown collector '{}' (available: {})", name, names.join(", ")))?;
+            enabled[index] = on;
+        }
+    }
+    Ok(collectors.into_iter().zip(enabled).filter(|(_, on)| *on).map(|(c, _)| c).collect())
+}
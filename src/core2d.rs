@@ -0,0 +1,66 @@
+//! Adreno 2D-Kern (Z180, `/dev/kgsl-2d0`) der MSM7x30/8x60-Ära
+//!
+//! Der 2D-Kern hat keine Chip ID und beantwortet nur wenige Properties
+//! (`z180_getproperty`): DEVICE_INFO, MMU_ENABLE und INTERRUPT_WAITS. Die
+//! 3D-Dekodierung liefert hier nur Unsinn, daher ein eigener Pfad.
+
+use std::io;
+
+use crate::kgsl::{read_devinfo, read_property, DeviceKind};
+use crate::propmap::Prop;
+use crate::warnings::Warnings;
+
+pub const KGSL_PROP_MMU_ENABLE: u32 = 0x00000006;
+pub const KGSL_PROP_INTERRUPT_WAITS: u32 = 0x00000007;
+
+/// `KGSL_DEVICE_2D0`; `device_id` in DEVICE_INFO ist die Geräte-ID + 1
+const KGSL_DEVICE_2D0: u32 = 1;
+
+/// Ob ein Geräteknoten ein 2D-Kern ist (`kgsl-2d0`, `kgsl-2d1`)
+pub fn is_2d_device(path: &str) -> bool {
+    DeviceKind::from_path(path) == DeviceKind::Core2d
+}
+
+/// Was ein 2D-Kern über sich verrät
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Core2dInfo {
+    pub device_id: u32,
+    /// Index des 2D-Kerns (0 für `kgsl-2d0`)
+    pub core_index: u32,
+    /// Z180 meldet 0, ein Wert ungleich 0 deutet auf einen 3D-Knoten hin
+    pub chip_id: u32,
+    pub mmu_enabled: bool,
+    /// `None`, wenn der Kernel die Property nicht kennt
+    pub interrupt_waits: Option<bool>,
+}
+
+impl Core2dInfo {
+    pub fn name(&self) -> &'static str {
+        "Adreno 2D (Z180-class)"
+    }
+}
+
+fn read_u32_property(fd: i32, prop: Prop) -> io::Result<u32> {
+    let value: u32 = read_property(fd, prop)?;
+    Ok(value)
+}
+
+/// Liest die Properties eines 2D-Kerns, fehlende optionale Werte als Warnung
+pub fn read_2d_info(fd: i32, warnings: &mut Warnings) -> io::Result<Core2dInfo> {
+    // Auf 32-Bit-Kerneln der Ära entspricht das Layout dem heutigen kgsl_devinfo
+    let devinfo = read_devinfo(fd)?;
+    let mmu_enabled = match warnings.check("mmu-enable", read_u32_property(fd, Prop::MmuEnable)) {
+        Some(value) => value != 0,
+        None => devinfo.mmu_enabled != 0,
+    };
+    let interrupt_waits = warnings
+        .check("interrupt-waits", read_u32_property(fd, Prop::InterruptWaits))
+        .map(|v| v != 0);
+    Ok(Core2dInfo {
+        device_id: devinfo.device_id,
+        core_index: devinfo.device_id.saturating_sub(1 + KGSL_DEVICE_2D0),
+        chip_id: devinfo.chip_id,
+        mmu_enabled,
+        interrupt_waits,
+    })
+}
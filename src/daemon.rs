@@ -0,0 +1,134 @@
+//! Socket-Protokoll des Daemons
+//!
+//! Zeilenbasiert über einen Unix-Socket: eine Anfrage pro Zeile, jede Antwort
+//! ist eine Zeile kompaktes JSON, Fehler als `{"error": "..."}`.
+//!
+//! - `info` - Gerät, Intervall (Basis und aktuell), Aufbewahrungszeit, Anzahl Punkte
+//! - `latest` - jüngster Messpunkt
+//! - `history [DAUER]` - alle Punkte der letzten DAUER (ohne: alle)
+//! - `history METRIK [DAUER]` - nur `[t, wert]`-Paare, z.B. `history busy 60s`
+//!
+//! `t` ist immer das Alter in Sekunden relativ zur Anfrage (negativ).
+//!
+//! Anfragen sind höchstens [`MAX_REQUEST_BYTES`] lang, gleichzeitig werden
+//! höchstens [`MAX_CLIENTS`] Verbindungen bedient.
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::alert::Metric;
+use crate::history::History;
+use crate::instance;
+use crate::json::Json;
+
+/// Längste Anfragezeile inklusive Zeilenende
+pub const MAX_REQUEST_BYTES: u64 = 4096;
+
+/// Gleichzeitig bediente Verbindungen; weitere werden abgewiesen
+pub const MAX_CLIENTS: usize = 16;
+
+/// Standardpfad des Sockets im privaten Verzeichnis von `uid`
+///
+/// Nicht direkt in `/tmp`, wo ein anderer Benutzer den Namen zuerst belegen könnte.
+pub fn default_socket(uid: u32) -> PathBuf {
+    instance::user_dir(uid).join("adreno_ioctl.sock")
+}
+
+/// Vom Sampler-Thread gepflegter Zustand
+#[derive(Debug, Clone)]
+pub struct DaemonState {
+    pub device: String,
+    pub interval: Duration,
+    /// Aktuelles Intervall, im Leerlauf länger als `interval`
+    pub current_interval: Duration,
+    pub history: History,
+    /// Statische Gerätedaten (Chip, Modell, Frequenzen), `Null` wenn nicht lesbar
+    pub gpu: Json,
+}
+
+impl DaemonState {
+    pub fn new(device: &str, interval: Duration, retention: Duration) -> Self {
+        DaemonState {
+            device: device.to_string(),
+            interval,
+            current_interval: interval,
+            history: History::new(retention),
+            gpu: Json::Null,
+        }
+    }
+
+    pub fn info_json(&self) -> Json {
+        Json::object()
+            .field("device", self.device.as_str())
+            .field("interval_ms", self.interval.as_millis() as u64)
+            .field("current_interval_ms", self.current_interval.as_millis() as u64)
+            .field("retention_s", self.history.retention().as_secs())
+            .field("points", self.history.len())
+            .field("gpu", self.gpu.clone())
+    }
+
+    /// Jüngster Punkt plus alle Punkte der letzten `window`
+    pub fn metrics_json(&self, window: Duration, now: Instant) -> Json {
+        Json::object()
+            .field("latest", self.history.latest().map_or(Json::Null, |p| p.to_json(now)))
+            .field("history", Json::Array(self.history.range(window, now).map(|p| p.to_json(now)).collect()))
+    }
+
+    /// Beantwortet eine Zeile; `parse_duration` kommt vom Aufrufer (CLI-Einheiten)
+    pub fn handle(&self, line: &str, now: Instant, parse_duration: impl Fn(&str) -> Result<Duration, String>) -> Json {
+        self.respond(line, now, parse_duration).unwrap_or_else(|e| Json::object().field("error", e))
+    }
+
+    fn respond(
+        &self,
+        line: &str,
+        now: Instant,
+        parse_duration: impl Fn(&str) -> Result<Duration, String>,
+    ) -> Result<Json, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            ["info"] => Ok(self.info_json()),
+            ["latest"] => Ok(self.history.latest().map_or(Json::Null, |p| p.to_json(now))),
+            ["history", rest @ ..] => {
+                let (metric, window) = match rest {
+                    [] => (None, None),
+                    [one] => match find_metric(one) {
+                        Some(m) => (Some(m), None),
+                        None => (None, Some(*one)),
+                    },
+                    [metric, window] => {
+                        (Some(find_metric(metric).ok_or_else(|| format!("Unknown metric '{}'", metric))?), Some(*window))
+                    }
+                    _ => return Err("Usage: history [METRIC] [DURATION]".into()),
+                };
+                let window = window.map(parse_duration).transpose()?.unwrap_or(self.history.retention());
+                Ok(match metric {
+                    Some(metric) => {
+                        let points = self.history.series(metric, window, now);
+                        Json::object()
+                            .field("metric", metric.name())
+                            .field("points", points.into_iter().map(|(t, v)| vec![t, v]).collect::<Vec<_>>())
+                    }
+                    None => Json::Array(self.history.range(window, now).map(|p| p.to_json(now)).collect()),
+                })
+            }
+            _ => Err(format!("Unknown request '{}' (info, latest, history [METRIC] [DURATION])", line.trim())),
+        }
+    }
+}
+
+fn find_metric(name: &str) -> Option<Metric> {
+    Metric::ALL.into_iter().find(|m| m.name() == name && *m != Metric::Resets)
+}
+
+/// Schickt eine Anfrage an einen laufenden Daemon und liefert die Antwortzeile
+pub fn query(socket: &Path, request: &str) -> io::Result<String> {
+    let mut stream = UnixStream::connect(socket)?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    writeln!(stream, "{}", request.trim())?;
+    let mut reply = String::new();
+    BufReader::new(stream).read_line(&mut reply)?;
+    Ok(reply.trim_end().to_string())
+}
@@ -0,0 +1,263 @@
+//! Sammler für KGSL-debugfs (`/sys/kernel/debug/kgsl`, auf Android `/d/kgsl`)
+//!
+//! Speicherlisten je Prozess, globale Allokationen, Contexts und
+//! Dispatcher-Zustand gibt es nur hier - und debugfs nur auf
+//! userdebug/eng-Builds mit Root. Fehlt es, liefert [`collect`] einen
+//! [`Unavailable`]-Grund statt eines Fehlers, damit Aufrufer sauber darauf
+//! hinweisen können.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::json::Json;
+use crate::queue::value_after;
+use crate::sched::DEBUGFS_KGSL_DIR;
+use crate::sysroot;
+
+/// Mountpoint von debugfs
+pub const DEBUGFS_ROOT: &str = "/sys/kernel/debug";
+
+/// Android-Kurzpfad auf debugfs
+const ANDROID_KGSL_DIR: &str = "/d/kgsl";
+
+/// Warum debugfs nicht nutzbar ist
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unavailable {
+    /// debugfs nicht gemountet (user-Build)
+    NotMounted,
+    /// Gemountet, aber nur für Root lesbar
+    PermissionDenied,
+    /// Kernel ohne KGSL-debugfs
+    NoKgsl,
+}
+
+impl fmt::Display for Unavailable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Unavailable::NotMounted => "debugfs is not mounted (requires debugfs/userdebug build)",
+            Unavailable::PermissionDenied => "debugfs is not readable (requires root on a debugfs/userdebug build)",
+            Unavailable::NoKgsl => "debugfs has no kgsl directory (kernel built without KGSL debugfs)",
+        })
+    }
+}
+
+/// Findet das KGSL-Verzeichnis in debugfs
+pub fn detect() -> Result<PathBuf, Unavailable> {
+    let candidates = [sysroot::resolve(DEBUGFS_KGSL_DIR), sysroot::resolve(ANDROID_KGSL_DIR)];
+    detect_in(&[&candidates[0], &candidates[1]], &sysroot::resolve(DEBUGFS_ROOT))
+}
+
+/// Wie [`detect`], mit eigenen Kandidaten und Mountpoint
+pub fn detect_in(candidates: &[&Path], root: &Path) -> Result<PathBuf, Unavailable> {
+    let mut denied = false;
+    for dir in candidates {
+        match fs::read_dir(dir) {
+            Ok(_) => return Ok(dir.to_path_buf()),
+            Err(e) if e.kind() == io::ErrorKind::PermissionDenied => denied = true,
+            Err(_) => {}
+        }
+    }
+    if denied {
+        return Err(Unavailable::PermissionDenied);
+    }
+    match fs::read_dir(root) {
+        Err(e) if e.kind() == io::ErrorKind::PermissionDenied => Err(Unavailable::PermissionDenied),
+        // Ungemountet ist der Mountpoint ein leeres Verzeichnis
+        Ok(mut entries) => match entries.next() {
+            Some(_) => Err(Unavailable::NoKgsl),
+            None => Err(Unavailable::NotMounted),
+        },
+        Err(_) => Err(Unavailable::NotMounted),
+    }
+}
+
+/// Eine Zeile aus `proc/<pid>/mem` oder `globals`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemEntry {
+    /// Bei `%pK` ohne Root oft 0
+    pub gpuaddr: u64,
+    pub size: u64,
+    pub id: u32,
+    pub flags: String,
+    pub memtype: String,
+    pub usage: String,
+}
+
+/// Parst eine Speicherliste:
+/// `gpuaddr useraddr size id flags type usage sglen mapcount ...`
+pub fn parse_mem_list(text: &str) -> Vec<MemEntry> {
+    text.lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 7 {
+                return None;
+            }
+            Some(MemEntry {
+                gpuaddr: u64::from_str_radix(fields[0].trim_start_matches("0x"), 16).unwrap_or(0),
+                size: fields[2].parse().ok()?,
+                id: fields[3].parse().ok()?,
+                flags: fields[4].to_string(),
+                memtype: fields[5].to_string(),
+                usage: fields[6].to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Ein GPU-Context aus `<dev>/ctx/<id>`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContextInfo {
+    pub id: u32,
+    /// `gl`, `cl`, `vk`, ...
+    pub kind: Option<String>,
+    pub priority: Option<i32>,
+    pub process: Option<String>,
+    pub pid: Option<u32>,
+    pub tid: Option<u32>,
+    pub flags: Vec<String>,
+    pub queued: Option<u32>,
+    pub consumed: Option<u32>,
+    pub retired: Option<u32>,
+}
+
+impl ContextInfo {
+    /// Eingereicht, aber noch nicht retired (mit Überlauf)
+    pub fn inflight(&self) -> Option<u32> {
+        let diff = self.queued?.wrapping_sub(self.retired?) as i32;
+        Some(diff.max(0) as u32)
+    }
+
+    pub fn to_json(&self) -> Json {
+        Json::object()
+            .field("id", self.id)
+            .field("type", self.kind.clone())
+            .field("priority", self.priority)
+            .field("process", self.process.clone())
+            .field("pid", self.pid)
+            .field("tid", self.tid)
+            .field("flags", self.flags.clone())
+            .field("queued", self.queued)
+            .field("consumed", self.consumed)
+            .field("retired", self.retired)
+    }
+}
+
+/// Parst eine ctx-Datei:
+/// ```text
+/// id: 5 type: gl priority: 1 process_name: app (1234) tid: 1240
+/// flags: per_context_ts|user_generated_ts|preamble
+/// timestamps: queued: 120 consumed: 119 retired: 118 global:9876
+/// ```
+pub fn parse_context(id: u32, text: &str) -> ContextInfo {
+    let pid = text.find("process_name:").and_then(|i| {
+        let after = &text[i..];
+        let (open, close) = (after.find('(')?, after.find(')')?);
+        after.get(open + 1..close)?.trim().parse().ok()
+    });
+    let flags = text
+        .lines()
+        .find_map(|l| l.trim().strip_prefix("flags:"))
+        .map(|f| f.split(|c: char| c == '|' || c.is_whitespace()).filter(|f| !f.is_empty()).map(str::to_string).collect())
+        .unwrap_or_default();
+    ContextInfo {
+        id,
+        kind: value_after(text, "type:").map(str::to_string),
+        priority: number(text, "priority:"),
+        process: value_after(text, "process_name:").map(str::to_string),
+        pid,
+        tid: number(text, "tid:"),
+        flags,
+        queued: number(text, "queued:"),
+        consumed: number(text, "consumed:"),
+        retired: number(text, "retired:"),
+    }
+}
+
+fn number<T: FromStr>(text: &str, key: &str) -> Option<T> {
+    value_after(text, key)?.parse().ok()
+}
+
+/// Speicherliste eines Prozesses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessMemList {
+    pub pid: u32,
+    pub entries: Vec<MemEntry>,
+}
+
+impl ProcessMemList {
+    pub fn total_bytes(&self) -> u64 {
+        self.entries.iter().map(|e| e.size).sum()
+    }
+}
+
+/// Alles, was KGSL in debugfs für ein Gerät bereitstellt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugfsSnapshot {
+    pub root: PathBuf,
+    pub processes: Vec<ProcessMemList>,
+    pub globals: Vec<MemEntry>,
+    pub contexts: Vec<ContextInfo>,
+    pub dispatcher: Vec<(String, String)>,
+}
+
+/// Sammelt alle Daten; einzelne unlesbare Dateien werden übersprungen
+pub fn collect(device_path: &str) -> Result<DebugfsSnapshot, Unavailable> {
+    Ok(collect_from(&detect()?, device_path))
+}
+
+/// Wie [`collect`] unter einem bekannten KGSL-Verzeichnis
+pub fn collect_from(root: &Path, device_path: &str) -> DebugfsSnapshot {
+    let name = Path::new(device_path).file_name().and_then(|n| n.to_str()).unwrap_or("kgsl-3d0");
+    let device = root.join(name);
+
+    let mut processes: Vec<ProcessMemList> = numbered_entries(&root.join("proc"))
+        .into_iter()
+        .filter_map(|(pid, path)| {
+            let text = fs::read_to_string(path.join("mem")).ok()?;
+            Some(ProcessMemList { pid, entries: parse_mem_list(&text) })
+        })
+        .collect();
+    processes.sort_by_key(|p| p.pid);
+
+    let mut contexts: Vec<ContextInfo> = numbered_entries(&device.join("ctx"))
+        .into_iter()
+        .filter_map(|(id, path)| Some(parse_context(id, &fs::read_to_string(path).ok()?)))
+        .collect();
+    contexts.sort_by_key(|c| c.id);
+
+    DebugfsSnapshot {
+        root: root.to_path_buf(),
+        processes,
+        globals: fs::read_to_string(root.join("globals")).map(|t| parse_mem_list(&t)).unwrap_or_default(),
+        contexts,
+        dispatcher: read_key_values(&device.join("dispatcher")),
+    }
+}
+
+/// Einträge mit numerischem Namen (PIDs, Context-IDs)
+fn numbered_entries(dir: &Path) -> Vec<(u32, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries.flatten().filter_map(|e| Some((e.file_name().to_str()?.parse().ok()?, e.path()))).collect()
+}
+
+/// Erste Zeile jeder Datei eines Verzeichnisses, nach Namen sortiert
+fn read_key_values(dir: &Path) -> Vec<(String, String)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut values: Vec<(String, String)> = entries
+        .flatten()
+        .filter(|e| e.path().is_file())
+        .filter_map(|e| {
+            let text = fs::read_to_string(e.path()).ok()?;
+            Some((e.file_name().to_str()?.to_string(), text.lines().next().unwrap_or("").trim().to_string()))
+        })
+        .collect();
+    values.sort();
+    values
+}
@@ -0,0 +1,358 @@
+//! GPU-Knoten aus dem Device Tree (`/proc/device-tree`)
+//!
+//! Liefert die vom Board konfigurierten Power Levels (OPP-Tabelle) samt
+//! Spannung bzw. Spannungs-Corner, den Zap-Shader und die Speed-Bin
+//! Fuse-Zuordnung.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::sysroot;
+
+pub const DT_ROOT: &str = "/proc/device-tree";
+
+/// `compatible`-Einträge eines Adreno GPU-Knotens
+const GPU_COMPATIBLE: [&str; 3] = ["qcom,kgsl-3d0", "qcom,adreno", "qcom,kgsl-3d"];
+
+/// Maximale Suchtiefe im Baum
+const MAX_DEPTH: usize = 4;
+
+/// Spannung eines Power Levels
+///
+/// Auf RPM/RPMh-Plattformen steht statt Mikrovolt ein Corner-Level
+/// (`RPMH_REGULATOR_LEVEL_*`); die echte Spannung kennt nur der RPM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Voltage {
+    Microvolt(u32),
+    Corner(u32),
+}
+
+/// Werte darunter in `opp-microvolt` sind Corner-Level, keine Mikrovolt
+const MAX_CORNER: u32 = 1000;
+
+/// Namen der RPM/RPMh Corner-Level (dt-bindings/power/qcom-rpmpd.h)
+const CORNER_NAMES: [(u32, &str); 15] = [
+    (16, "RETENTION"),
+    (48, "MIN_SVS"),
+    (56, "LOW_SVS_D1"),
+    (64, "LOW_SVS"),
+    (80, "LOW_SVS_L1"),
+    (96, "LOW_SVS_L2"),
+    (128, "SVS"),
+    (144, "SVS_L0"),
+    (192, "SVS_L1"),
+    (224, "SVS_L2"),
+    (256, "NOM"),
+    (320, "NOM_L1"),
+    (336, "NOM_L2"),
+    (384, "TURBO"),
+    (416, "TURBO_L1"),
+];
+
+impl Voltage {
+    /// Wert aus `opp-microvolt`: kleine Zahlen sind Corner-Level
+    pub fn from_opp_microvolt(value: u32) -> Self {
+        if value < MAX_CORNER { Voltage::Corner(value) } else { Voltage::Microvolt(value) }
+    }
+
+    pub fn corner_name(level: u32) -> Option<&'static str> {
+        CORNER_NAMES.iter().find(|(l, _)| *l == level).map(|(_, name)| *name)
+    }
+}
+
+impl std::fmt::Display for Voltage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Voltage::Microvolt(uv) => write!(f, "{:.1} mV", *uv as f64 / 1000.0),
+            Voltage::Corner(level) => match Voltage::corner_name(*level) {
+                Some(name) => write!(f, "{} ({})", name, level),
+                None => write!(f, "corner {}", level),
+            },
+        }
+    }
+}
+
+/// Ein Power Level aus `qcom,gpu-pwrlevels`
+#[derive(Debug, Clone, Default)]
+pub struct DtPwrLevel {
+    pub index: u32,
+    pub freq_hz: u64,
+    pub bus_freq: Option<u32>,
+    pub bus_min: Option<u32>,
+    pub bus_max: Option<u32>,
+    /// `qcom,level`, sonst aus einer OPP-Tabelle mit gleicher Frequenz
+    pub voltage: Option<Voltage>,
+}
+
+/// Eine Tabelle von Power Levels, ggf. für eine Speed Bin
+#[derive(Debug, Clone, Default)]
+pub struct DtPwrLevelTable {
+    pub speed_bin: Option<u32>,
+    pub levels: Vec<DtPwrLevel>,
+}
+
+/// Zusammenfassung des GPU-Knotens
+#[derive(Debug, Clone, Default)]
+pub struct GpuNode {
+    pub path: PathBuf,
+    pub compatible: Vec<String>,
+    pub tables: Vec<DtPwrLevelTable>,
+    /// Firmware-Name des Zap-Shaders (z.B. "a630_zap")
+    pub zap_shader: Option<String>,
+    pub has_zap_node: bool,
+    /// `qcom,gpu-speed-bin` = <offset mask shift>
+    pub speed_bin_fuse: Option<Vec<u32>>,
+}
+
+// ============================================================================
+// Property-Helfer (Device Tree Werte sind Big Endian)
+// ============================================================================
+
+/// Zerlegt einen Property-Wert in 32-Bit Zellen; ein unvollständiger Rest fällt weg
+pub fn parse_cells(bytes: &[u8]) -> Vec<u32> {
+    bytes.chunks_exact(4).map(|c| u32::from_be_bytes([c[0], c[1], c[2], c[3]])).collect()
+}
+
+/// Zerlegt einen Property-Wert in NUL-getrennte Strings
+pub fn parse_strings(bytes: &[u8]) -> Vec<String> {
+    bytes
+        .split(|&b| b == 0)
+        .filter(|s| !s.is_empty())
+        .map(|s| String::from_utf8_lossy(s).into_owned())
+        .collect()
+}
+
+/// Liest eine Property als Liste von 32-Bit Zellen
+pub fn read_cells(path: &Path) -> io::Result<Vec<u32>> {
+    Ok(parse_cells(&fs::read(path)?))
+}
+
+/// Liest eine einzelne 32-Bit Zelle
+pub fn read_u32(path: &Path) -> Option<u32> {
+    read_cells(path).ok()?.first().copied()
+}
+
+/// Liest eine 64-Bit Zahl aus ein oder zwei Zellen
+pub fn read_u64(path: &Path) -> Option<u64> {
+    match read_cells(path).ok()?.as_slice() {
+        [v] => Some(*v as u64),
+        [hi, lo, ..] => Some(((*hi as u64) << 32) | *lo as u64),
+        _ => None,
+    }
+}
+
+/// Liest eine String-Liste (NUL-getrennt)
+pub fn read_strings(path: &Path) -> Vec<String> {
+    fs::read(path).map(|bytes| parse_strings(&bytes)).unwrap_or_default()
+}
+
+// ============================================================================
+// Suche und Parsing
+// ============================================================================
+
+/// Sucht den GPU-Knoten anhand von `compatible`
+pub fn find_gpu_node(root: &Path) -> Option<PathBuf> {
+    find_compatible(root, &GPU_COMPATIBLE)
+}
+
+/// Sucht den ersten Knoten, dessen `compatible` einen der Einträge enthält
+pub fn find_compatible(root: &Path, compatible: &[&str]) -> Option<PathBuf> {
+    fn walk(dir: &Path, compatible: &[&str], depth: usize) -> Option<PathBuf> {
+        let entries = read_strings(&dir.join("compatible"));
+        if entries.iter().any(|c| compatible.contains(&c.as_str())) {
+            return Some(dir.to_path_buf());
+        }
+        if depth >= MAX_DEPTH {
+            return None;
+        }
+        let mut children: Vec<PathBuf> = fs::read_dir(dir)
+            .ok()?
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.is_dir())
+            .collect();
+        children.sort();
+        children.iter().find_map(|c| walk(c, compatible, depth + 1))
+    }
+    walk(root, compatible, 0)
+}
+
+fn child_dirs(dir: &Path, prefix: &str) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.file_name().to_string_lossy().starts_with(prefix))
+                .map(|e| e.path())
+                .filter(|p| p.is_dir())
+                .collect()
+        })
+        .unwrap_or_default();
+    dirs.sort();
+    dirs
+}
+
+/// Parst einen `qcom,gpu-pwrlevels` Knoten
+fn parse_pwrlevels(dir: &Path) -> DtPwrLevelTable {
+    let mut levels: Vec<DtPwrLevel> = child_dirs(dir, "qcom,gpu-pwrlevel@")
+        .iter()
+        .filter_map(|level| {
+            Some(DtPwrLevel {
+                index: read_u32(&level.join("reg"))?,
+                freq_hz: read_u64(&level.join("qcom,gpu-freq"))?,
+                bus_freq: read_u32(&level.join("qcom,bus-freq")),
+                bus_min: read_u32(&level.join("qcom,bus-min")),
+                bus_max: read_u32(&level.join("qcom,bus-max")),
+                voltage: read_u32(&level.join("qcom,level")).map(Voltage::Corner),
+            })
+        })
+        .collect();
+    levels.sort_by_key(|l| l.index);
+
+    DtPwrLevelTable { speed_bin: read_u32(&dir.join("qcom,speed-bin")), levels }
+}
+
+/// Parst eine `operating-points-v2` Tabelle als Fallback
+fn parse_opp_table(dir: &Path) -> DtPwrLevelTable {
+    let mut levels: Vec<DtPwrLevel> = child_dirs(dir, "opp-")
+        .iter()
+        .filter_map(|opp| {
+            let voltage = read_u32(&opp.join("opp-level"))
+                .map(Voltage::Corner)
+                .or_else(|| read_u32(&opp.join("opp-microvolt")).map(Voltage::from_opp_microvolt));
+            Some(DtPwrLevel { freq_hz: read_u64(&opp.join("opp-hz"))?, voltage, ..Default::default() })
+        })
+        .collect();
+    // Höchste Frequenz = Level 0, wie bei KGSL
+    levels.sort_by_key(|l| std::cmp::Reverse(l.freq_hz));
+    for (i, level) in levels.iter_mut().enumerate() {
+        level.index = i as u32;
+    }
+    DtPwrLevelTable { speed_bin: None, levels }
+}
+
+/// Liest alle relevanten Informationen eines GPU-Knotens
+pub fn parse_gpu_node(node: &Path) -> GpuNode {
+    let mut gpu = GpuNode {
+        path: node.to_path_buf(),
+        compatible: read_strings(&node.join("compatible")),
+        speed_bin_fuse: read_cells(&node.join("qcom,gpu-speed-bin")).ok(),
+        ..Default::default()
+    };
+
+    // Mehrere Bins unter qcom,gpu-pwrlevel-bins, sonst eine einzelne Tabelle
+    let bins = node.join("qcom,gpu-pwrlevel-bins");
+    if bins.is_dir() {
+        gpu.tables = child_dirs(&bins, "qcom,gpu-pwrlevels").iter().map(|d| parse_pwrlevels(d)).collect();
+    } else if node.join("qcom,gpu-pwrlevels").is_dir() {
+        gpu.tables.push(parse_pwrlevels(&node.join("qcom,gpu-pwrlevels")));
+    }
+    let opp_tables: Vec<DtPwrLevelTable> = child_dirs(node, "gpu-opp-table")
+        .iter()
+        .chain(child_dirs(node, "opp-table").iter())
+        .map(|opp| parse_opp_table(opp))
+        .collect();
+    if gpu.tables.is_empty() {
+        gpu.tables = opp_tables;
+    } else {
+        // Ältere Kernel: Frequenzen in qcom,gpu-pwrlevels, Spannungen in der OPP-Tabelle
+        let opp_levels: Vec<&DtPwrLevel> = opp_tables.iter().flat_map(|t| &t.levels).collect();
+        for level in gpu.tables.iter_mut().flat_map(|t| &mut t.levels).filter(|l| l.voltage.is_none()) {
+            level.voltage = opp_levels.iter().find(|o| o.freq_hz == level.freq_hz).and_then(|o| o.voltage);
+        }
+    }
+
+    // Zap-Shader Knoten, je nach Kernel unterschiedlich benannt
+    for name in ["qcom,gpu-zap-shader", "zap-shader", "qcom,zap-shader"] {
+        let zap = node.join(name);
+        if zap.is_dir() {
+            gpu.has_zap_node = true;
+            gpu.zap_shader = read_strings(&zap.join("qcom,firmware-name")).into_iter().next()
+                .or_else(|| read_strings(&zap.join("firmware-name")).into_iter().next());
+            break;
+        }
+    }
+    if gpu.zap_shader.is_none() {
+        gpu.zap_shader = read_strings(&node.join("qcom,zap-shader")).into_iter().next();
+    }
+
+    gpu
+}
+
+/// Findet und parst den GPU-Knoten unter `/proc/device-tree`
+pub fn read_gpu_node() -> Option<GpuNode> {
+    find_gpu_node(&sysroot::resolve(DT_ROOT)).map(|node| parse_gpu_node(&node))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, property: &str, value: &[u8]) {
+        let path = root.join(property);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, value).unwrap();
+    }
+
+    fn cells(values: &[u32]) -> Vec<u8> {
+        values.iter().flat_map(|v| v.to_be_bytes()).collect()
+    }
+
+    #[test]
+    fn cells_and_strings() {
+        assert_eq!(parse_cells(&[0, 0, 1, 2, 0xff, 0xff, 0xff, 0xff, 9]), [0x0102, u32::MAX]);
+        assert_eq!(parse_strings(b"qcom,adreno-630.2\0qcom,adreno\0\0"), ["qcom,adreno-630.2", "qcom,adreno"]);
+        assert!(parse_strings(b"").is_empty());
+        assert_eq!(Voltage::from_opp_microvolt(256), Voltage::Corner(256));
+        assert_eq!(Voltage::from_opp_microvolt(800_000), Voltage::Microvolt(800_000));
+        assert_eq!(Voltage::Corner(256).to_string(), "NOM (256)");
+    }
+
+    #[test]
+    fn pwrlevels_take_voltage_from_opp_table() {
+        let node = std::env::temp_dir().join(format!("adreno_ioctl-test-dt-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&node);
+        write(&node, "compatible", b"qcom,adreno-630.2\0qcom,adreno\0");
+        write(&node, "qcom,gpu-speed-bin", &cells(&[0x13c, 0xe000, 13]));
+        for (index, hz) in [(0, 710_000_000), (1, 257_000_000)] {
+            let level = format!("qcom,gpu-pwrlevels/qcom,gpu-pwrlevel@{}", index);
+            write(&node, &format!("{}/reg", level), &cells(&[index]));
+            write(&node, &format!("{}/qcom,gpu-freq", level), &cells(&[hz]));
+        }
+        write(&node, "opp-table/opp-710000000/opp-hz", &cells(&[0, 710_000_000]));
+        write(&node, "opp-table/opp-710000000/opp-level", &cells(&[416]));
+        write(&node, "zap-shader/memory-region", b"");
+        write(&node, "zap-shader/firmware-name", b"qcom/a630_zap.mbn\0");
+
+        let gpu = parse_gpu_node(&node);
+        fs::remove_dir_all(&node).unwrap();
+        assert_eq!(gpu.compatible, ["qcom,adreno-630.2", "qcom,adreno"]);
+        assert_eq!(gpu.speed_bin_fuse, Some(vec![0x13c, 0xe000, 13]));
+        assert_eq!(gpu.tables.len(), 1);
+        let levels = &gpu.tables[0].levels;
+        assert_eq!(levels.iter().map(|l| (l.index, l.freq_hz)).collect::<Vec<_>>(), [(0, 710_000_000), (1, 257_000_000)]);
+        assert_eq!(levels[0].voltage, Some(Voltage::Corner(416)));
+        assert_eq!(levels[1].voltage, None);
+        assert!(gpu.has_zap_node);
+        assert_eq!(gpu.zap_shader.as_deref(), Some("qcom/a630_zap.mbn"));
+    }
+
+    #[test]
+    fn opp_table_alone_sorted_by_frequency() {
+        let node = std::env::temp_dir().join(format!("adreno_ioctl-test-dt-opp-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&node);
+        write(&node, "gpu-opp-table/opp-300000000/opp-hz", &cells(&[0, 300_000_000]));
+        write(&node, "gpu-opp-table/opp-300000000/opp-microvolt", &cells(&[600_000]));
+        write(&node, "gpu-opp-table/opp-900000000/opp-hz", &cells(&[0, 900_000_000]));
+        // Ohne opp-hz kein Level
+        write(&node, "gpu-opp-table/opp-broken/opp-level", &cells(&[64]));
+
+        let gpu = parse_gpu_node(&node);
+        fs::remove_dir_all(&node).unwrap();
+        let levels = &gpu.tables[0].levels;
+        assert_eq!(levels.iter().map(|l| (l.index, l.freq_hz)).collect::<Vec<_>>(), [(0, 900_000_000), (1, 300_000_000)]);
+        assert_eq!(levels[1].voltage, Some(Voltage::Microvolt(600_000)));
+        assert!(!gpu.has_zap_node);
+    }
+}
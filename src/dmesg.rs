@@ -0,0 +1,39 @@
+//! Kernel-Log Zugriff (`dmesg`)
+
+use std::io;
+
+use crate::sys;
+
+/// `SYSLOG_ACTION_READ_ALL`
+const SYSLOG_ACTION_READ_ALL: libc::c_int = 3;
+/// `SYSLOG_ACTION_SIZE_BUFFER`
+const SYSLOG_ACTION_SIZE_BUFFER: libc::c_int = 10;
+
+/// Liest den Kernel-Ringpuffer über `klogctl()`
+///
+/// Benötigt Root oder `dmesg_restrict=0`.
+pub fn read_kernel_log() -> io::Result<String> {
+    let size = sys::klogctl(SYSLOG_ACTION_SIZE_BUFFER, None)?;
+    let mut buf = vec![0u8; size.max(4096)];
+    let len = sys::klogctl(SYSLOG_ACTION_READ_ALL, Some(&mut buf))?;
+    buf.truncate(len);
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Zeilen des Kernel-Logs, die eines der Muster enthalten (ohne Groß/Klein)
+pub fn grep<'a>(log: &'a str, patterns: &[&str]) -> Vec<&'a str> {
+    log.lines()
+        .filter(|line| {
+            let lower = line.to_ascii_lowercase();
+            patterns.iter().any(|p| lower.contains(p))
+        })
+        .collect()
+}
+
+/// Kernel-Meldungen bei GPU-Fehlern und Recovery
+pub const GPU_FAULT_PATTERNS: [&str; 4] = ["gpu fault", "gpu hang", "hang detected", "gpu recovery"];
+
+/// Anzahl der GPU-Fehler im Kernel-Log (zählt nur, was noch im Ringpuffer steht)
+pub fn count_gpu_faults() -> io::Result<u64> {
+    Ok(grep(&read_kernel_log()?, &GPU_FAULT_PATTERNS).len() as u64)
+}
@@ -0,0 +1,137 @@
+//! Kernel- und KGSL-Modul Build-Informationen
+
+use std::fs;
+use std::path::Path;
+
+use crate::sys;
+use crate::sysroot;
+
+/// Kernel-Module in sysfs
+pub const MODULE_DIR: &str = "/sys/module";
+
+/// Mögliche Modulnamen des KGSL-Treibers
+const MODULE_NAMES: [&str; 2] = ["msm_kgsl", "kgsl"];
+
+/// Modulparameter, die das Verhalten merklich ändern
+pub const NOTABLE_PARAMETERS: [(&str, &str); 6] = [
+    ("nopreempt", "preemption disabled"),
+    ("swfi_latency", "software idle latency (us)"),
+    ("snapshot_dump_enabled", "snapshots on fault"),
+    ("kgsl_mmu_type", "MMU backend"),
+    ("adreno_wake_nice", "nice level of the wake thread"),
+    ("adreno_wake_timeout", "wake timeout (ms)"),
+];
+
+/// Treiber-Build Kontext für Bug Reports
+#[derive(Debug, Clone, Default)]
+pub struct DriverInfo {
+    /// `uname -r`
+    pub kernel_release: String,
+    /// `uname -v` (Build-Nummer und Datum)
+    pub kernel_version: String,
+    pub machine: String,
+    /// Name unter `/sys/module`, falls vorhanden
+    pub module_name: Option<String>,
+    pub module_version: Option<String>,
+    pub srcversion: Option<String>,
+    /// Eingebaut statt ladbar
+    pub builtin: bool,
+    pub parameters: Vec<(String, String)>,
+}
+
+impl DriverInfo {
+    /// Parameter, die in [`NOTABLE_PARAMETERS`] gelistet sind
+    pub fn notable_parameters(&self) -> Vec<(&str, &str, &'static str)> {
+        self.parameters
+            .iter()
+            .filter_map(|(name, value)| {
+                NOTABLE_PARAMETERS
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .map(|(_, desc)| (name.as_str(), value.as_str(), *desc))
+            })
+            .collect()
+    }
+}
+
+/// Kernel-Release und Version über `uname()`
+pub fn uname() -> (String, String, String) {
+    sys::uname().unwrap_or_default()
+}
+
+/// Sammelt Kernel- und Modul-Informationen
+pub fn read_driver_info() -> DriverInfo {
+    let (kernel_release, kernel_version, machine) = uname();
+    let mut info = DriverInfo { kernel_release, kernel_version, machine, ..Default::default() };
+
+    let modules = sysroot::resolve(MODULE_DIR);
+    let Some(name) = MODULE_NAMES.iter().find(|n| modules.join(n).exists()) else {
+        return info;
+    };
+    let dir = modules.join(name);
+
+    info.module_name = Some(name.to_string());
+    info.module_version = read_trimmed(&dir.join("version"));
+    info.srcversion = read_trimmed(&dir.join("srcversion"));
+    // Eingebaute Module haben keinen initstate
+    info.builtin = !dir.join("initstate").exists();
+
+    if let Ok(entries) = fs::read_dir(dir.join("parameters")) {
+        for entry in entries.flatten() {
+            let param = entry.file_name().to_string_lossy().into_owned();
+            let value = read_trimmed(&entry.path()).unwrap_or_else(|| "(unreadable)".to_string());
+            info.parameters.push((param, value));
+        }
+        info.parameters.sort();
+    }
+
+    info
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|s| s.trim().to_string())
+}
+
+// ============================================================================
+// Android
+// ============================================================================
+
+/// build.prop-Dateien, die erste mit dem gesuchten Schlüssel gewinnt
+pub const BUILD_PROPS: [&str; 4] = ["/system/build.prop", "/system/system/build.prop", "/vendor/build.prop", "/product/etc/build.prop"];
+
+/// Android-Version aus `build.prop`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AndroidVersion {
+    /// `ro.build.version.release`, z.B. "14"
+    pub release: String,
+    /// `ro.build.version.sdk` (API-Level)
+    pub sdk: Option<u32>,
+}
+
+/// Wert eines Schlüssels in einer `build.prop`
+pub fn build_prop<'a>(text: &'a str, key: &str) -> Option<&'a str> {
+    text.lines().find_map(|line| {
+        let (k, v) = line.split_once('=')?;
+        (k.trim() == key).then(|| v.trim())
+    })
+}
+
+/// `None` auf Nicht-Android-Systemen
+pub fn read_android_version() -> Option<AndroidVersion> {
+    BUILD_PROPS.iter().find_map(|path| {
+        let text = fs::read_to_string(sysroot::resolve(path)).ok()?;
+        let release = build_prop(&text, "ro.build.version.release")
+            .or_else(|| build_prop(&text, "ro.vendor.build.version.release"))?;
+        let sdk = build_prop(&text, "ro.build.version.sdk")
+            .or_else(|| build_prop(&text, "ro.vendor.build.version.sdk"))
+            .and_then(|s| s.parse().ok());
+        Some(AndroidVersion { release: release.to_string(), sdk })
+    })
+}
+
+/// Ein Schlüssel aus der ersten `build.prop`, die ihn enthält
+pub fn read_build_prop(key: &str) -> Option<String> {
+    BUILD_PROPS
+        .iter()
+        .find_map(|path| build_prop(&fs::read_to_string(sysroot::resolve(path)).ok()?, key).map(str::to_string))
+}
@@ -0,0 +1,369 @@
+//! Diskrete GPU-Ereignisse statt Samples
+//!
+//! Apps, die ihre Qualität live anpassen, wollen wissen, *dass* sich etwas
+//! geändert hat: Takt, Power Level, Drosselung, Reset. [`EventWatcher`]
+//! vergleicht dazu sysfs-Stände zwischen zwei Abfragen und zählt neue
+//! GPU-Fehler im Kernel-Log. Mit ftrace ([`EventWatcher::with_ftrace`])
+//! kommen Taktwechsel und Fehler direkt aus den `kgsl`-Tracepoints und gehen
+//! auch zwischen zwei Abfragen nicht verloren.
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read};
+use std::os::fd::AsFd;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::collector::kgsl_tracefs;
+use crate::dmesg;
+use crate::sink::Record;
+use crate::sys;
+use crate::sysfs;
+
+/// Mindestabstand zwischen zwei Durchsuchungen des Kernel-Logs
+pub const RESET_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Tracepoints, die [`EventWatcher::with_ftrace`] einschaltet
+pub const TRACEPOINTS: [&str; 2] = ["kgsl_pwrlevel", "adreno_gpu_fault"];
+
+/// Art eines Ereignisses, Namen wie in `--events`
+pub const EVENT_KINDS: [&str; 4] = ["freq", "pwrlevel", "throttle", "reset"];
+
+/// Was sich geändert hat
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    FreqChanged { from_hz: u64, to_hz: u64 },
+    /// Index in `gpu_available_frequencies`, 0 = schnellster
+    PwrlevelChanged { from: u32, to: u32 },
+    /// `thermal_pwrlevel` wurde größer 0
+    ThrottleStarted { level: u32 },
+    ThrottleStopped { level: u32 },
+    /// Neue Fehler mit Recovery seit der letzten Abfrage
+    Reset { count: u64 },
+}
+
+/// Woher ein Ereignis stammt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventSource {
+    Sysfs,
+    Ftrace,
+    KernelLog,
+}
+
+impl EventSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            EventSource::Sysfs => "sysfs",
+            EventSource::Ftrace => "ftrace",
+            EventSource::KernelLog => "kmsg",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GpuEvent {
+    pub time: Instant,
+    pub source: EventSource,
+    pub kind: EventKind,
+}
+
+impl GpuEvent {
+    fn new(source: EventSource, kind: EventKind) -> Self {
+        GpuEvent { time: Instant::now(), source, kind }
+    }
+
+    /// Eintrag aus [`EVENT_KINDS`]
+    pub fn name(&self) -> &'static str {
+        match self.kind {
+            EventKind::FreqChanged { .. } => "freq",
+            EventKind::PwrlevelChanged { .. } => "pwrlevel",
+            EventKind::ThrottleStarted { .. } | EventKind::ThrottleStopped { .. } => "throttle",
+            EventKind::Reset { .. } => "reset",
+        }
+    }
+
+    /// Datensatz für [`crate::sink`], gleiche Spalten für alle Arten;
+    /// bei `reset` ist `to` die Anzahl neuer Fehler
+    pub fn to_record(&self, device: &str) -> Record {
+        let (event, from, to) = match self.kind {
+            EventKind::FreqChanged { from_hz, to_hz } => ("freq_changed", Some(from_hz), to_hz),
+            EventKind::PwrlevelChanged { from, to } => ("pwrlevel_changed", Some(from as u64), to as u64),
+            EventKind::ThrottleStarted { level } => ("throttle_started", Some(0), level as u64),
+            EventKind::ThrottleStopped { level } => ("throttle_stopped", Some(level as u64), 0),
+            EventKind::Reset { count } => ("reset", None, count),
+        };
+        Record::new("gpu_event")
+            .field("device", device)
+            .field("event", event)
+            .field("source", self.source.label())
+            .field("from", from)
+            .field("to", to)
+    }
+}
+
+impl fmt::Display for GpuEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mhz = |hz: u64| hz / 1_000_000;
+        match self.kind {
+            EventKind::FreqChanged { from_hz, to_hz } => {
+                let arrow = if to_hz > from_hz { "⏫" } else { "⏬" };
+                write!(f, "{} clock {} -> {} MHz", arrow, mhz(from_hz), mhz(to_hz))
+            }
+            EventKind::PwrlevelChanged { from, to } => write!(f, "🎚️  power level {} -> {}", from, to),
+            EventKind::ThrottleStarted { level } => write!(f, "🔥 thermal throttling started (level {})", level),
+            EventKind::ThrottleStopped { level } => write!(f, "❄️  thermal throttling stopped (was level {})", level),
+            EventKind::Reset { count } => write!(f, "💥 GPU fault/recovery ({} new)", count),
+        }
+    }
+}
+
+/// Abonnent für [`GpuEvent`]s
+pub type Subscriber = Box<dyn FnMut(&GpuEvent) + Send>;
+
+/// Stand der zuletzt gesehenen Werte
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    freq_hz: Option<u64>,
+    thermal_level: Option<u32>,
+    faults: Option<u64>,
+}
+
+/// Leitet Ereignisse aus wiederholten Abfragen ab
+pub struct EventWatcher {
+    dir: PathBuf,
+    device_name: String,
+    frequencies: Vec<u64>,
+    state: State,
+    last_reset_check: Instant,
+    ftrace: Option<TracePipe>,
+    subscribers: Vec<Subscriber>,
+}
+
+impl EventWatcher {
+    /// Merkt sich den aktuellen Stand als Ausgangspunkt (ohne Events)
+    pub fn new(device_path: &str) -> Self {
+        let dir = sysfs::device_dir(device_path);
+        let device_name = dir.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+        let state = State {
+            freq_hz: sysfs::gpuclk(&dir).ok(),
+            thermal_level: sysfs::thermal_pwrlevel(&dir).ok(),
+            faults: dmesg::count_gpu_faults().ok(),
+        };
+        EventWatcher {
+            frequencies: sysfs::available_frequencies(&dir).unwrap_or_default(),
+            dir,
+            device_name,
+            state,
+            last_reset_check: Instant::now(),
+            ftrace: None,
+            subscribers: Vec::new(),
+        }
+    }
+
+    /// Takt und Fehler aus den [`TRACEPOINTS`] lesen (root). Schaltet sie
+    /// ein und beim Beenden wieder aus; liest `trace_pipe` und verbraucht
+    /// damit dessen Inhalt auch für andere Leser.
+    pub fn with_ftrace(mut self) -> io::Result<Self> {
+        let dir = kgsl_tracefs()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "tracefs with kgsl events not mounted"))?;
+        self.ftrace = Some(TracePipe::open(&dir)?);
+        Ok(self)
+    }
+
+    pub fn uses_ftrace(&self) -> bool {
+        self.ftrace.is_some()
+    }
+
+    pub fn subscribe(&mut self, subscriber: impl FnMut(&GpuEvent) + Send + 'static) {
+        self.subscribers.push(Box::new(subscriber));
+    }
+
+    /// Wartet höchstens `timeout` (kürzer, sobald ftrace Daten hat), dann [`Self::poll`]
+    pub fn wait(&mut self, timeout: Duration) -> io::Result<Vec<GpuEvent>> {
+        match &self.ftrace {
+            Some(pipe) => pipe.wait(timeout),
+            None => std::thread::sleep(timeout),
+        }
+        self.poll()
+    }
+
+    /// Liest den neuen Stand und meldet die Unterschiede
+    pub fn poll(&mut self) -> io::Result<Vec<GpuEvent>> {
+        let mut events = Vec::new();
+        match &mut self.ftrace {
+            Some(pipe) => {
+                for line in pipe.read_lines()? {
+                    events.extend(parse_trace_line(&line, &self.device_name));
+                }
+            }
+            None => self.poll_freq(&mut events),
+        }
+        self.poll_throttle(&mut events);
+        if self.ftrace.is_none() && self.last_reset_check.elapsed() >= RESET_CHECK_INTERVAL {
+            self.poll_faults(&mut events);
+        }
+
+        for subscriber in &mut self.subscribers {
+            for event in &events {
+                subscriber(event);
+            }
+        }
+        Ok(events)
+    }
+
+    fn level_of(&self, hz: u64) -> Option<u32> {
+        self.frequencies.iter().position(|&f| f == hz).map(|i| i as u32)
+    }
+
+    fn poll_freq(&mut self, events: &mut Vec<GpuEvent>) {
+        let Ok(now) = sysfs::gpuclk(&self.dir) else {
+            return;
+        };
+        let Some(before) = self.state.freq_hz.replace(now) else {
+            return;
+        };
+        if before == now {
+            return;
+        }
+        events.push(GpuEvent::new(EventSource::Sysfs, EventKind::FreqChanged { from_hz: before, to_hz: now }));
+        if let (Some(from), Some(to)) = (self.level_of(before), self.level_of(now)) {
+            events.push(GpuEvent::new(EventSource::Sysfs, EventKind::PwrlevelChanged { from, to }));
+        }
+    }
+
+    fn poll_throttle(&mut self, events: &mut Vec<GpuEvent>) {
+        let Ok(now) = sysfs::thermal_pwrlevel(&self.dir) else {
+            return;
+        };
+        let before = self.state.thermal_level.replace(now).unwrap_or(0);
+        let kind = match (before, now) {
+            (0, level) if level > 0 => EventKind::ThrottleStarted { level },
+            (level, 0) if level > 0 => EventKind::ThrottleStopped { level },
+            _ => return,
+        };
+        events.push(GpuEvent::new(EventSource::Sysfs, kind));
+    }
+
+    fn poll_faults(&mut self, events: &mut Vec<GpuEvent>) {
+        self.last_reset_check = Instant::now();
+        let Ok(now) = dmesg::count_gpu_faults() else {
+            return;
+        };
+        // Ein rotierter Ringpuffer kann weniger Treffer enthalten
+        if let Some(before) = self.state.faults.replace(now)
+            && now > before
+        {
+            events.push(GpuEvent::new(EventSource::KernelLog, EventKind::Reset { count: now - before }));
+        }
+    }
+}
+
+// ============================================================================
+// ftrace
+// ============================================================================
+
+/// `key=value`-Paare nach dem Tracepoint-Namen
+fn trace_field<'a>(fields: &'a str, key: &str) -> Option<&'a str> {
+    fields.split_whitespace().find_map(|pair| pair.strip_prefix(key)?.strip_prefix('='))
+}
+
+/// Eine Zeile aus `trace_pipe`, z.B.
+/// `kworker/u16:3-123 [002] d..2 1234.5: kgsl_pwrlevel: d_name=kgsl-3d0 pwrlevel=3 freq=585000000 prev_pwrlevel=2 prev_freq=680000000`
+pub fn parse_trace_line(line: &str, device_name: &str) -> Vec<GpuEvent> {
+    let event = |kind| GpuEvent::new(EventSource::Ftrace, kind);
+    if let Some((_, fields)) = line.split_once("kgsl_pwrlevel: ") {
+        if trace_field(fields, "d_name").is_some_and(|name| name != device_name) {
+            return Vec::new();
+        }
+        let number = |key| trace_field(fields, key).and_then(|v| v.parse::<u64>().ok());
+        let mut events = Vec::new();
+        if let (Some(from_hz), Some(to_hz)) = (number("prev_freq"), number("freq"))
+            && from_hz != to_hz
+        {
+            events.push(event(EventKind::FreqChanged { from_hz, to_hz }));
+        }
+        if let (Some(from), Some(to)) = (number("prev_pwrlevel"), number("pwrlevel"))
+            && from != to
+        {
+            events.push(event(EventKind::PwrlevelChanged { from: from as u32, to: to as u32 }));
+        }
+        return events;
+    }
+    if line.contains("adreno_gpu_fault: ") {
+        return vec![event(EventKind::Reset { count: 1 })];
+    }
+    Vec::new()
+}
+
+/// `trace_pipe` plus die dafür eingeschalteten Tracepoints
+struct TracePipe {
+    file: File,
+    /// `enable`-Dateien, die beim Beenden wieder auf 0 gehen
+    enabled: Vec<PathBuf>,
+    /// Angefangene Zeile vom letzten Lesen
+    partial: String,
+}
+
+impl TracePipe {
+    fn open(dir: &Path) -> io::Result<Self> {
+        let mut enabled = Vec::new();
+        for name in TRACEPOINTS {
+            let enable = dir.join("events/kgsl").join(name).join("enable");
+            if !enable.exists() {
+                continue;
+            }
+            if sysfs::read_string(&enable)? != "1" {
+                fs::write(&enable, "1")?;
+                enabled.push(enable);
+            }
+        }
+        if enabled.is_empty() && !dir.join("events/kgsl").join(TRACEPOINTS[0]).exists() {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "kernel has no kgsl_pwrlevel tracepoint"));
+        }
+        let file = OpenOptions::new().read(true).custom_flags(libc::O_NONBLOCK).open(dir.join("trace_pipe"));
+        match file {
+            Ok(file) => Ok(TracePipe { file, enabled, partial: String::new() }),
+            Err(e) => {
+                disable(&enabled);
+                Err(e)
+            }
+        }
+    }
+
+    fn wait(&self, timeout: Duration) {
+        let _ = sys::poll_readable(self.file.as_fd(), timeout);
+    }
+
+    /// Alle vollständigen Zeilen, die bisher vorliegen
+    fn read_lines(&mut self) -> io::Result<Vec<String>> {
+        let mut buf = [0u8; 16384];
+        loop {
+            match self.file.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => self.partial.push_str(&String::from_utf8_lossy(&buf[..n])),
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => break,
+                Err(e) => return Err(e),
+            }
+        }
+        let Some(end) = self.partial.rfind('\n') else {
+            return Ok(Vec::new());
+        };
+        let rest = self.partial.split_off(end + 1);
+        let lines = std::mem::replace(&mut self.partial, rest).lines().map(str::to_string).collect();
+        Ok(lines)
+    }
+}
+
+fn disable(enabled: &[PathBuf]) {
+    for enable in enabled {
+        let _ = fs::write(enable, "0");
+    }
+}
+
+impl Drop for TracePipe {
+    fn drop(&mut self) {
+        disable(&self.enabled);
+    }
+}
@@ -0,0 +1,75 @@
+//! Hardware-Features der neueren Generationen (LPAC, Concurrent Binning, ...)
+
+use crate::kgsl;
+use crate::propmap::Prop;
+
+pub const KGSL_PROP_IS_LPAC_ENABLED: u32 = 0x0000002B;
+pub const KGSL_PROP_IS_RAYTRACING_ENABLED: u32 = 0x0000002D;
+pub const KGSL_PROP_IS_FASTBLEND_ENABLED: u32 = 0x0000002E;
+pub const KGSL_PROP_IS_AQE_ENABLED: u32 = 0x00000030;
+
+/// Zustand eines Features
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureState {
+    /// Vom Treiber als aktiv gemeldet
+    Enabled,
+    /// Vom Treiber als inaktiv gemeldet
+    Disabled,
+    /// Property existiert in diesem Kernel nicht
+    NotReported,
+    /// Aus der GPU-Generation abgeleitet, nicht abgefragt
+    Inferred(bool),
+}
+
+/// Eine Zeile der Feature-Matrix
+#[derive(Debug, Clone)]
+pub struct HardwareFeature {
+    pub name: &'static str,
+    pub state: FeatureState,
+    /// Woher die Information stammt
+    pub source: &'static str,
+}
+
+/// Liest eine boolesche Property (`unsigned int` != 0)
+pub fn read_bool_property(fd: i32, prop: Prop) -> Option<bool> {
+    kgsl::read_property::<u32>(fd, prop).ok().map(|value| value != 0)
+}
+
+/// GPU-Generation aus der Chip ID (A7xx nutzt teils das neue 0x43.. Schema)
+pub fn generation(chip_id: u32) -> u8 {
+    match (chip_id >> 24) as u8 {
+        0x43 => 7,
+        major => major,
+    }
+}
+
+/// Fragt alle bekannten Feature-Properties ab
+pub fn detect_features(fd: i32, chip_id: u32) -> Vec<HardwareFeature> {
+    let chip_gen = generation(chip_id);
+    let queried = |name, prop: Prop| HardwareFeature {
+        name,
+        state: match read_bool_property(fd, prop) {
+            Some(true) => FeatureState::Enabled,
+            Some(false) => FeatureState::Disabled,
+            None => FeatureState::NotReported,
+        },
+        source: prop.name(),
+    };
+
+    vec![
+        queried("LPAC (low priority async compute)", Prop::IsLpacEnabled),
+        queried("Ray tracing", Prop::IsRaytracingEnabled),
+        queried("Fast blend", Prop::IsFastblendEnabled),
+        queried("AQE (auxiliary queue engine)", Prop::IsAqeEnabled),
+        HardwareFeature {
+            name: "Concurrent binning",
+            state: FeatureState::Inferred(chip_gen >= 7),
+            source: "A7xx generation",
+        },
+        HardwareFeature {
+            name: "Hardware fences",
+            state: FeatureState::Inferred(chip_gen >= 7),
+            source: "A7xx hardware scheduler",
+        },
+    ]
+}
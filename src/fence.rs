@@ -0,0 +1,195 @@
+//! Fences als sync_file: Erzeugen aus KGSL-Timestamps und Inspektion
+
+use std::ffi::CStr;
+use std::fs;
+use std::io;
+use std::mem::size_of;
+use std::os::fd::OwnedFd;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use zerocopy::{FromBytes, FromZeros, Immutable, IntoBytes};
+
+use crate::kgsl::{ioc, kgsl_iowr, IOC_READ, IOC_WRITE};
+use crate::sys::{self, ioctl};
+
+// ============================================================================
+// KGSL Timestamp-Fences
+// ============================================================================
+
+/// `struct kgsl_timestamp_event`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslTimestampEvent {
+    pub type_: i32,
+    pub timestamp: u32,
+    pub context_id: u32,
+    #[cfg(target_pointer_width = "64")]
+    pub _pad: u32,
+    pub priv_: usize,
+    pub len: usize,
+}
+
+/// `struct kgsl_timestamp_event_fence`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KgslTimestampEventFence {
+    pub fence_fd: i32,
+}
+
+pub const KGSL_TIMESTAMP_EVENT_FENCE: i32 = 2;
+pub const IOCTL_KGSL_TIMESTAMP_EVENT: u32 = kgsl_iowr(0x33, size_of::<KgslTimestampEvent>());
+
+/// Erzeugt einen sync_file, der signalisiert, sobald `timestamp` retired ist
+pub fn create_fence(fd: i32, context_id: u32, timestamp: u32) -> io::Result<OwnedFd> {
+    let mut fence = KgslTimestampEventFence { fence_fd: -1 };
+    let mut req = KgslTimestampEvent {
+        type_: KGSL_TIMESTAMP_EVENT_FENCE,
+        timestamp,
+        context_id,
+        priv_: &mut fence as *mut KgslTimestampEventFence as usize,
+        len: size_of::<KgslTimestampEventFence>(),
+        ..Default::default()
+    };
+    ioctl(fd, IOCTL_KGSL_TIMESTAMP_EVENT, &mut req)?;
+    if fence.fence_fd < 0 {
+        return Err(io::Error::other("kernel returned no fence fd"));
+    }
+    sys::owned_fd(fence.fence_fd)
+}
+
+// ============================================================================
+// sync_file Inspektion (`SYNC_IOC_FILE_INFO`)
+// ============================================================================
+
+/// `struct sync_fence_info`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, FromBytes, IntoBytes, Immutable)]
+pub struct SyncFenceInfoRaw {
+    pub obj_name: [u8; 32],
+    pub driver_name: [u8; 32],
+    pub status: i32,
+    pub flags: u32,
+    pub timestamp_ns: u64,
+}
+
+/// `struct sync_file_info`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, FromBytes, IntoBytes, Immutable)]
+pub struct SyncFileInfoRaw {
+    pub name: [u8; 32],
+    pub status: i32,
+    pub flags: u32,
+    pub num_fences: u32,
+    pub pad: u32,
+    pub sync_fence_info: u64,
+}
+
+const SYNC_IOC_MAGIC: u32 = b'>' as u32;
+pub const SYNC_IOC_FILE_INFO: u32 = ioc(IOC_READ | IOC_WRITE, SYNC_IOC_MAGIC, 4, size_of::<SyncFileInfoRaw>());
+
+/// Zustand einer Fence (`status` aus dem Kernel)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FenceStatus {
+    Active,
+    Signaled,
+    /// Mit Fehler signalisiert (negativer errno)
+    Error(i32),
+}
+
+impl FenceStatus {
+    pub fn from_raw(status: i32) -> Self {
+        match status {
+            0 => FenceStatus::Active,
+            1 => FenceStatus::Signaled,
+            e => FenceStatus::Error(e),
+        }
+    }
+}
+
+/// Eine einzelne Fence in einem sync_file
+#[derive(Debug, Clone)]
+pub struct FenceInfo {
+    /// Timeline-Name, bei KGSL z.B. `kgsl-3d0_5-app(1234)-app(1240)`
+    pub obj_name: String,
+    pub driver_name: String,
+    pub status: FenceStatus,
+    /// CLOCK_MONOTONIC Zeitpunkt der Signalisierung
+    pub signal_time: Option<Duration>,
+}
+
+impl FenceInfo {
+    pub fn is_kgsl(&self) -> bool {
+        self.driver_name.contains("kgsl") || self.obj_name.starts_with("kgsl")
+    }
+
+    /// Context-ID aus dem KGSL Timeline-Namen (`<device>_<ctx>-...`)
+    pub fn kgsl_context_id(&self) -> Option<u32> {
+        if !self.is_kgsl() {
+            return None;
+        }
+        let rest = self.obj_name.split_once('_')?.1;
+        rest.split(|c: char| !c.is_ascii_digit()).next()?.parse().ok()
+    }
+}
+
+/// Inhalt eines sync_file
+#[derive(Debug, Clone)]
+pub struct SyncFileInfo {
+    pub name: String,
+    pub status: FenceStatus,
+    pub fences: Vec<FenceInfo>,
+}
+
+fn c_string(raw: &[u8]) -> String {
+    CStr::from_bytes_until_nul(raw)
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| String::from_utf8_lossy(raw).into_owned())
+}
+
+/// Liest Status und enthaltene Fences eines sync_file
+pub fn sync_file_info(fd: i32) -> io::Result<SyncFileInfo> {
+    // Erst Anzahl abfragen, dann mit Puffer erneut
+    let mut info = SyncFileInfoRaw::new_zeroed();
+    ioctl(fd, SYNC_IOC_FILE_INFO, &mut info)?;
+
+    let mut fences: Vec<SyncFenceInfoRaw> = vec![SyncFenceInfoRaw::new_zeroed(); info.num_fences as usize];
+    if !fences.is_empty() {
+        info.sync_fence_info = fences.as_mut_ptr() as u64;
+        ioctl(fd, SYNC_IOC_FILE_INFO, &mut info)?;
+        fences.truncate(info.num_fences as usize);
+    }
+
+    Ok(SyncFileInfo {
+        name: c_string(&info.name),
+        status: FenceStatus::from_raw(info.status),
+        fences: fences
+            .iter()
+            .map(|f| FenceInfo {
+                obj_name: c_string(&f.obj_name),
+                driver_name: c_string(&f.driver_name),
+                status: FenceStatus::from_raw(f.status),
+                signal_time: (f.timestamp_ns != 0).then(|| Duration::from_nanos(f.timestamp_ns)),
+            })
+            .collect(),
+    })
+}
+
+/// Aktuelle CLOCK_MONOTONIC Zeit, Referenz für [`FenceInfo::signal_time`]
+pub fn monotonic_now() -> Duration {
+    sys::monotonic()
+}
+
+/// Offene sync_file Deskriptoren eines Prozesses als `/proc/<pid>/fd/<n>` Pfade
+pub fn find_sync_files(pid: u32) -> io::Result<Vec<(u32, PathBuf)>> {
+    let dir = PathBuf::from(format!("/proc/{}/fd", pid));
+    let mut found: Vec<(u32, PathBuf)> = fs::read_dir(&dir)?
+        .flatten()
+        .filter(|e| {
+            fs::read_link(e.path()).is_ok_and(|target| target.to_string_lossy() == "anon_inode:sync_file")
+        })
+        .filter_map(|e| Some((e.file_name().to_str()?.parse().ok()?, e.path())))
+        .collect();
+    found.sort();
+    Ok(found)
+}
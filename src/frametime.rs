@@ -0,0 +1,103 @@
+//! Frame-Zeiten einer App aus den Retire-Timestamps ihrer Contexts
+//!
+//! Ohne Hersteller-Tools: die debugfs-ctx-Einträge werden im
+//! Millisekundentakt gelesen. Jedes Weiterzählen von `retired` ist ein
+//! Retire-Event; Events, die dichter als `merge_gap` aufeinander folgen,
+//! gehören zum selben Frame (mehrere Submits pro Frame). Die Frame-Zeit ist
+//! der Abstand zwischen zwei Frame-Enden.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::bench::LatencyStats;
+use crate::queue::ContextQueue;
+
+/// Frame gilt als Ruckler, wenn er länger als dieses Vielfache des Medians dauert
+pub const JANK_FACTOR: f64 = 1.5;
+
+/// Ergebnis einer Messung
+#[derive(Debug, Clone, Copy)]
+pub struct FrameStats {
+    pub frames: usize,
+    pub avg: Duration,
+    pub latency: LatencyStats,
+    /// Frames länger als [`JANK_FACTOR`] × Median
+    pub janks: usize,
+}
+
+impl FrameStats {
+    pub fn fps(&self) -> f64 {
+        1.0 / self.avg.as_secs_f64().max(1e-9)
+    }
+}
+
+/// Sammelt Retire-Events und bildet daraus Frames
+#[derive(Debug, Clone)]
+pub struct FrameTimer {
+    pid: u32,
+    merge_gap: Duration,
+    retired: HashMap<u32, u32>,
+    /// Letztes Retire-Event des laufenden Frames
+    pending: Option<Instant>,
+    last_frame_end: Option<Instant>,
+    frame_times: Vec<Duration>,
+}
+
+impl FrameTimer {
+    pub fn new(pid: u32, merge_gap: Duration) -> Self {
+        FrameTimer {
+            pid,
+            merge_gap,
+            retired: HashMap::new(),
+            pending: None,
+            last_frame_end: None,
+            frame_times: Vec::new(),
+        }
+    }
+
+    /// Contexts des Zielprozesses, die bisher gesehen wurden
+    pub fn contexts(&self) -> usize {
+        self.retired.len()
+    }
+
+    /// Wertet einen Stand aller Contexts zum Zeitpunkt `now` aus
+    pub fn observe(&mut self, now: Instant, queues: &[ContextQueue]) {
+        let mut retired_any = false;
+        for q in queues.iter().filter(|q| q.pid == Some(self.pid)) {
+            match self.retired.insert(q.id, q.retired) {
+                Some(before) if before != q.retired => retired_any = true,
+                _ => {}
+            }
+        }
+
+        // Lücke seit dem letzten Event: der vorige Frame ist fertig
+        if let Some(pending) = self.pending
+            && now.duration_since(pending) > self.merge_gap
+        {
+            if let Some(end) = self.last_frame_end {
+                self.frame_times.push(pending.duration_since(end));
+            }
+            self.last_frame_end = Some(pending);
+            self.pending = None;
+        }
+        if retired_any {
+            self.pending = Some(now);
+        }
+    }
+
+    pub fn frame_times(&self) -> &[Duration] {
+        &self.frame_times
+    }
+
+    pub fn stats(&self) -> Option<FrameStats> {
+        let latency = LatencyStats::from_samples(self.frame_times.clone())?;
+        let total: Duration = self.frame_times.iter().sum();
+        let jank_limit = latency.median.mul_f64(JANK_FACTOR);
+        Some(FrameStats {
+            frames: self.frame_times.len(),
+            avg: total / self.frame_times.len() as u32,
+            latency,
+            janks: self.frame_times.iter().filter(|&&t| t > jank_limit).count(),
+        })
+    }
+}
@@ -0,0 +1,98 @@
+//! GMEM (On-Chip Tile-Speicher) Konfiguration und Bin-Größen
+
+use crate::chip::{tile_limits, ChipInfo, TileLimits};
+use crate::features::generation;
+use crate::kgsl::read_devinfo;
+
+/// Woher die GMEM-Größe stammt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GmemSource {
+    /// `gmem_sizebytes` aus KGSL_PROP_DEVICE_INFO
+    Driver,
+    /// Aus der Chip-Datenbank
+    ChipDb,
+}
+
+/// GMEM-Konfiguration eines Geräts
+#[derive(Debug, Clone)]
+pub struct GmemConfig {
+    pub size_bytes: u64,
+    pub source: GmemSource,
+    pub base_addr: Option<u64>,
+    /// Laut Chip-Datenbank
+    pub num_ccu: Option<u8>,
+    pub limits: TileLimits,
+}
+
+impl GmemConfig {
+    /// GMEM-Anteil je CCU
+    pub fn bytes_per_ccu(&self) -> Option<u64> {
+        self.num_ccu.map(|n| self.size_bytes / n.max(1) as u64)
+    }
+}
+
+/// Liest die GMEM-Konfiguration, fehlende Werte aus der Chip-Datenbank
+pub fn read_gmem(fd: i32, chip: &ChipInfo) -> Option<GmemConfig> {
+    let devinfo = read_devinfo(fd).ok();
+    let spec = chip.spec();
+    let (size_bytes, source) = match (devinfo.map(|d| d.gmem_sizebytes as u64), spec) {
+        (Some(size), _) if size > 0 => (size, GmemSource::Driver),
+        (_, Some(spec)) => (spec.gmem_bytes as u64, GmemSource::ChipDb),
+        _ => return None,
+    };
+    #[allow(clippy::unnecessary_cast)]
+    Some(GmemConfig {
+        size_bytes,
+        source,
+        base_addr: devinfo.map(|d| d.gmem_gpubaseaddr as u64),
+        num_ccu: spec.map(|s| s.num_ccu),
+        limits: tile_limits(generation(chip.raw_id)),
+    })
+}
+
+/// Aufteilung eines Render-Ziels in Bins
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinLayout {
+    pub tile_w: u32,
+    pub tile_h: u32,
+    pub bins_x: u32,
+    pub bins_y: u32,
+}
+
+impl BinLayout {
+    pub fn bin_count(&self) -> u32 {
+        self.bins_x * self.bins_y
+    }
+}
+
+fn align_up(value: u32, align: u32) -> u32 {
+    value.div_ceil(align) * align
+}
+
+/// Kleinste Bin-Anzahl, bei der ein Tile mit `bytes_per_pixel` in GMEM passt
+///
+/// Vereinfachte Version des freedreno-Algorithmus: die jeweils größere
+/// Dimension wird geteilt, bis Tile-Größe und -Grenzen passen.
+pub fn bin_layout(gmem_bytes: u64, bytes_per_pixel: u32, limits: &TileLimits, width: u32, height: u32) -> Option<BinLayout> {
+    if bytes_per_pixel == 0 || width == 0 || height == 0 {
+        return None;
+    }
+    let (mut bins_x, mut bins_y) = (1u32, 1u32);
+    loop {
+        let tile_w = align_up(width.div_ceil(bins_x), limits.align_w);
+        let tile_h = align_up(height.div_ceil(bins_y), limits.align_h);
+        let fits = tile_w as u64 * tile_h as u64 * bytes_per_pixel as u64 <= gmem_bytes;
+        if fits && tile_w <= limits.max_w && tile_h <= limits.max_h {
+            return Some(BinLayout { tile_w, tile_h, bins_x, bins_y });
+        }
+        if tile_w <= limits.align_w && tile_h <= limits.align_h {
+            // Selbst das kleinste Tile passt nicht
+            return None;
+        }
+        if tile_w >= tile_h && tile_w > limits.align_w {
+            bins_x += 1;
+        } else {
+            bins_y += 1;
+        }
+    }
+}
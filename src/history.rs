@@ -0,0 +1,114 @@
+//! Ringpuffer der letzten Samples für den Daemon
+//!
+//! Clients sollen beim Verbinden sofort Graphen zeichnen können. Pro Sample
+//! werden nur die Kennzahlen gehalten (kein IRQ-/Queue-Ballast), damit auch
+//! eine Stunde bei 1 s Takt nur wenige hundert KB belegt.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::alert::Metric;
+use crate::json::Json;
+use crate::monitor::Sample;
+
+/// Kompakter Messpunkt
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HistoryPoint {
+    pub time: Instant,
+    pub freq_hz: Option<u64>,
+    pub busy_percent: Option<f64>,
+    pub temp_c: Option<f64>,
+    pub thermal_level: Option<u32>,
+    pub gpu_mem_bytes: Option<u64>,
+}
+
+impl From<&Sample> for HistoryPoint {
+    fn from(s: &Sample) -> Self {
+        HistoryPoint {
+            time: s.time,
+            freq_hz: s.freq_hz,
+            busy_percent: s.busy_percent,
+            temp_c: s.temp_c,
+            thermal_level: s.thermal_level,
+            gpu_mem_bytes: s.gpu_mem_bytes,
+        }
+    }
+}
+
+impl HistoryPoint {
+    /// Wert einer Metrik in der Einheit der Alert-Regeln; `resets` wird nicht gespeichert
+    pub fn value(&self, metric: Metric) -> Option<f64> {
+        match metric {
+            Metric::Temp => self.temp_c,
+            Metric::Busy => self.busy_percent,
+            Metric::Freq => self.freq_hz.map(|hz| hz as f64 / 1e6),
+            Metric::Mem => self.gpu_mem_bytes.map(|b| b as f64),
+            Metric::Resets => None,
+        }
+    }
+
+    /// `t` ist das Alter relativ zu `now` in Sekunden (negativ)
+    pub fn to_json(&self, now: Instant) -> Json {
+        Json::object()
+            .field("t", -now.saturating_duration_since(self.time).as_secs_f64())
+            .field("freq_hz", self.freq_hz)
+            .field("busy_percent", self.busy_percent)
+            .field("temp_c", self.temp_c)
+            .field("thermal_level", self.thermal_level)
+            .field("gpu_mem_bytes", self.gpu_mem_bytes)
+    }
+}
+
+/// Hält alle Punkte jünger als `retention`
+#[derive(Debug, Clone)]
+pub struct History {
+    retention: Duration,
+    points: VecDeque<HistoryPoint>,
+}
+
+impl History {
+    pub fn new(retention: Duration) -> Self {
+        History { retention, points: VecDeque::new() }
+    }
+
+    pub fn retention(&self) -> Duration {
+        self.retention
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn latest(&self) -> Option<&HistoryPoint> {
+        self.points.back()
+    }
+
+    /// Hängt einen Punkt an und verwirft zu alte
+    pub fn push(&mut self, point: HistoryPoint) {
+        while let Some(front) = self.points.front() {
+            if point.time.saturating_duration_since(front.time) <= self.retention {
+                break;
+            }
+            self.points.pop_front();
+        }
+        self.points.push_back(point);
+    }
+
+    /// Punkte der letzten `window` vor `now`, älteste zuerst
+    pub fn range(&self, window: Duration, now: Instant) -> impl Iterator<Item = &HistoryPoint> {
+        // Punkte sind zeitlich sortiert: ab dem ersten passenden alles nehmen
+        let start = self.points.partition_point(|p| now.saturating_duration_since(p.time) > window);
+        self.points.range(start..)
+    }
+
+    /// `[t, wert]`-Paare einer Metrik; Punkte ohne Wert fehlen
+    pub fn series(&self, metric: Metric, window: Duration, now: Instant) -> Vec<(f64, f64)> {
+        self.range(window, now)
+            .filter_map(|p| Some((-now.saturating_duration_since(p.time).as_secs_f64(), p.value(metric)?)))
+            .collect()
+    }
+}
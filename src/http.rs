@@ -0,0 +1,186 @@
+//! HTTP-Schnittstelle des Daemons (Feature `http`)
+//!
+//! Bewusst minimal: nur `GET`, eine Anfrage pro Verbindung, kein TLS.
+//! Gedacht für `localhost` bzw. den Browser auf dem Telefon selbst: Anfragen
+//! mit einem anderen `Host` werden abgewiesen, damit eine Webseite per DNS
+//! Rebinding nicht an `/api/memory` kommt.
+//!
+//! - `GET /` - Dashboard (eine HTML-Datei mit Live-Graphen)
+//! - `GET /api/info` - wie `info` auf dem Socket
+//! - `GET /api/metrics?window=60s` - jüngster Punkt plus Verlauf
+//! - `GET /api/memory` - GPU-Speicher je Prozess
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::daemon::DaemonState;
+use crate::json::Json;
+use crate::procmem;
+use crate::schema::versioned;
+
+const DASHBOARD: &str = include_str!("dashboard.html");
+
+/// Standard-Zeitfenster für `/api/metrics`
+pub const DEFAULT_WINDOW: Duration = Duration::from_secs(60);
+
+/// Höchstens so viele Bytes für Anfragezeile und Header zusammen
+pub const MAX_HEAD_BYTES: u64 = 8192;
+
+/// Antwort vor dem Serialisieren
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, json: Json) -> Self {
+        Response { status, content_type: "application/json", body: versioned(json).to_compact() }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Response::json(status, Json::object().field("error", message))
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            403 => "Forbidden",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            431 => "Request Header Fields Too Large",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+/// Ordnet eine Anfrage einem Endpunkt zu
+pub fn route(
+    method: &str,
+    target: &str,
+    state: &Mutex<DaemonState>,
+    parse_duration: impl Fn(&str) -> Result<Duration, String>,
+) -> Response {
+    if method != "GET" {
+        return Response::error(405, "only GET is supported");
+    }
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let param = |key: &str| {
+        query.split('&').filter_map(|kv| kv.split_once('=')).find(|(k, _)| *k == key).map(|(_, v)| v)
+    };
+    match path {
+        "/" | "/index.html" => Response { status: 200, content_type: "text/html; charset=utf-8", body: DASHBOARD.into() },
+        "/api/info" => Response::json(200, state.lock().unwrap().info_json()),
+        "/api/metrics" => {
+            let window = match param("window").map(parse_duration).transpose() {
+                Ok(window) => window.unwrap_or(DEFAULT_WINDOW),
+                Err(e) => return Response::error(400, &e),
+            };
+            Response::json(200, state.lock().unwrap().metrics_json(window, Instant::now()))
+        }
+        "/api/memory" => match procmem::read_processes() {
+            Ok(processes) => Response::json(200, Json::Array(processes.iter().map(|p| p.to_json()).collect())),
+            Err(e) => Response::error(500, &format!("cannot read {}: {}", procmem::KGSL_PROC_DIR, e)),
+        },
+        _ => Response::error(404, "not found"),
+    }
+}
+
+/// Anfragezeile und `Host`-Header; `None`, wenn der Kopf zu lang ist
+pub fn read_head(reader: impl BufRead) -> io::Result<Option<(String, Option<String>)>> {
+    let mut reader = reader.take(MAX_HEAD_BYTES);
+    let mut request_line = String::new();
+    let mut host = None;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        reader.read_line(&mut line)?;
+        // Ohne Zeilenende ist das Limit erreicht oder die Verbindung geschlossen
+        if !line.ends_with('\n') {
+            return Ok((reader.limit() > 0).then_some((request_line, host)));
+        }
+        if request_line.is_empty() {
+            request_line = line.clone();
+            continue;
+        }
+        if line.trim().is_empty() {
+            return Ok(Some((request_line, host)));
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("host")
+        {
+            host = Some(value.trim().to_string());
+        }
+    }
+}
+
+/// `localhost`, `127.0.0.1` oder `[::1]`, jeweils mit optionalem Port
+pub fn is_local_host(host: &str) -> bool {
+    let name = match host.strip_prefix('[') {
+        Some(rest) => rest.split_once(']').map_or(rest, |(name, _)| name),
+        None => host.split_once(':').map_or(host, |(name, _)| name),
+    };
+    matches!(name.to_ascii_lowercase().as_str(), "localhost" | "127.0.0.1" | "::1")
+}
+
+/// Beantwortet genau eine Anfrage und schließt die Verbindung
+pub fn serve(
+    stream: TcpStream,
+    state: &Mutex<DaemonState>,
+    parse_duration: impl Fn(&str) -> Result<Duration, String>,
+) -> io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+    let response = match read_head(BufReader::new(stream.try_clone()?))? {
+        None => Response::error(431, "request head too large"),
+        Some((_, host)) if !host.as_deref().is_some_and(is_local_host) => Response::error(403, "Host must be localhost"),
+        Some((request_line, _)) => {
+            let mut parts = request_line.split_whitespace();
+            match (parts.next(), parts.next()) {
+                (Some(method), Some(target)) => route(method, target, state, parse_duration),
+                _ => Response::error(400, "malformed request line"),
+            }
+        }
+    };
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n",
+        response.status,
+        response.reason(),
+        response.content_type,
+        response.body.len()
+    )?;
+    stream.write_all(response.body.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn head_with_host() {
+        let head = read_head(&b"GET /api/info HTTP/1.1\r\nhost: localhost:8080\r\nAccept: */*\r\n\r\n"[..]).unwrap();
+        assert_eq!(head, Some(("GET /api/info HTTP/1.1\r\n".to_string(), Some("localhost:8080".to_string()))));
+    }
+
+    #[test]
+    fn head_too_large() {
+        let mut request = b"GET / HTTP/1.1\r\nX-Pad: ".to_vec();
+        request.resize(MAX_HEAD_BYTES as usize * 2, b'a');
+        assert_eq!(read_head(&request[..]).unwrap(), None);
+    }
+
+    #[test]
+    fn only_local_hosts() {
+        for host in ["localhost", "LOCALHOST:80", "127.0.0.1", "127.0.0.1:8080", "[::1]", "[::1]:8080"] {
+            assert!(is_local_host(host), "{}", host);
+        }
+        for host in ["", "evil.example", "localhost.evil.example", "127.0.0.1.nip.io", "[::2]:80", "192.168.1.2"] {
+            assert!(!is_local_host(host), "{}", host);
+        }
+    }
+}
@@ -0,0 +1,403 @@
+//! Instanz-Sperre und Zustandsdatei für schreibende Befehle
+//!
+//! Nur eine Instanz darf gleichzeitig Frequenzen pinnen oder andere
+//! Einstellungen ändern. Die Sperre ist ein `flock` auf
+//! `adreno_ioctl.lock`; der Kernel gibt sie auch bei einem Absturz frei.
+//!
+//! Jede Änderung wird *vor* dem Schreiben mit dem Originalwert in
+//! `adreno_ioctl.state` festgehalten und nach erfolgreicher Wiederherstellung
+//! wieder ausgetragen. Bleibt die Datei nach einem Absturz mit Einträgen
+//! liegen, verweigern neue Instanzen das Schreiben, bis `adreno_ioctl restore`
+//! die Originalwerte zurückgeschrieben hat. Gleiches gilt für absichtlich
+//! bestehende Änderungen (`persistent`, z.B. ein angewendetes Profil).
+//!
+//! Beide Dateien liegen in einem eigenen 0700-Verzeichnis des effektiven
+//! Benutzers, werden ohne Symlinks geöffnet und nur gelesen, wenn sie ihm
+//! gehören. `restore` schreibt ausschließlich bekannte KGSL-Einstellungen
+//! zurück ([`Setting::from_journal_path`]).
+
+use std::fs::{self, DirBuilder, File, Metadata, OpenOptions};
+use std::io::{self, Read, Write};
+use std::os::fd::AsFd;
+use std::os::unix::fs::{DirBuilderExt, MetadataExt, OpenOptionsExt};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::json::Json;
+use crate::settings::Setting;
+use crate::sys;
+
+/// Überschreibt das Verzeichnis für Sperre und Zustandsdatei
+pub const STATE_DIR_ENV: &str = "ADRENO_IOCTL_STATE_DIR";
+
+/// Verzeichnis für Sperre und Zustandsdatei, eines je effektivem Benutzer
+pub fn state_dir() -> PathBuf {
+    user_dir(sys::geteuid())
+}
+
+/// Privates Verzeichnis von `uid`; [`STATE_DIR_ENV`] gilt für alle Benutzer
+pub fn user_dir(uid: u32) -> PathBuf {
+    if let Some(dir) = std::env::var_os(STATE_DIR_ENV) {
+        return PathBuf::from(dir);
+    }
+    let base = if cfg!(target_os = "android") { PathBuf::from("/data/local/tmp") } else { std::env::temp_dir() };
+    base.join(format!("adreno_ioctl-{}", uid))
+}
+
+/// Fehler, wenn Datei oder Verzeichnis nicht `uid` gehört oder andere
+/// darin schreiben dürfen
+fn check_owner(path: &Path, meta: &Metadata, uid: u32, foreign_bits: u32) -> io::Result<()> {
+    if meta.uid() != uid || meta.mode() & foreign_bits != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("{} must belong to uid {} with mode {:o} or stricter", path.display(), uid, 0o777 & !foreign_bits),
+        ));
+    }
+    Ok(())
+}
+
+/// Legt [`user_dir`] mit 0700 an und prüft Besitzer und Rechte
+///
+/// Als root für einen anderen Benutzer angelegt, bekommt dieser das Verzeichnis.
+pub fn secure_user_dir(uid: u32, gid: u32) -> io::Result<PathBuf> {
+    let dir = user_dir(uid);
+    match DirBuilder::new().mode(0o700).create(&dir) {
+        Ok(()) if uid != sys::geteuid() => std::os::unix::fs::chown(&dir, Some(uid), Some(gid))?,
+        Err(e) if e.kind() != io::ErrorKind::AlreadyExists => return Err(e),
+        _ => {}
+    }
+    // symlink_metadata: ein untergeschobener Symlink ist kein Verzeichnis
+    let meta = fs::symlink_metadata(&dir)?;
+    if !meta.is_dir() {
+        return Err(io::Error::new(io::ErrorKind::PermissionDenied, format!("{} is not a directory", dir.display())));
+    }
+    check_owner(&dir, &meta, uid, 0o077)?;
+    Ok(dir)
+}
+
+/// Öffnet ohne Symlinks zu folgen; neue Dateien mit 0600
+fn open_nofollow(options: &mut OpenOptions, path: &Path) -> io::Result<File> {
+    options.mode(0o600).custom_flags(libc::O_NOFOLLOW | libc::O_CLOEXEC).open(path)
+}
+
+pub fn lock_path() -> PathBuf {
+    state_dir().join("adreno_ioctl.lock")
+}
+
+pub fn state_path() -> PathBuf {
+    state_dir().join("adreno_ioctl.state")
+}
+
+/// Eine noch nicht zurückgenommene Änderung
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub path: PathBuf,
+    pub original: String,
+}
+
+/// Inhalt der Zustandsdatei
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstanceState {
+    pub pid: u32,
+    pub command: String,
+    /// Startzeit in Sekunden seit 1970
+    pub started: u64,
+    /// Änderungen bleiben absichtlich bestehen (z.B. `profile apply`)
+    pub persistent: bool,
+    pub changes: Vec<Change>,
+}
+
+impl InstanceState {
+    pub fn to_json(&self) -> Json {
+        let changes: Vec<Json> = self
+            .changes
+            .iter()
+            .map(|c| Json::object().field("path", c.path.display().to_string()).field("original", c.original.as_str()))
+            .collect();
+        Json::object()
+            .field("pid", self.pid)
+            .field("command", self.command.as_str())
+            .field("started", self.started)
+            .field("persistent", self.persistent)
+            .field("changes", changes)
+    }
+
+    pub fn from_json(json: &Json) -> Option<Self> {
+        let changes = json
+            .get("changes")?
+            .as_array()?
+            .iter()
+            .map(|c| {
+                Some(Change {
+                    path: PathBuf::from(c.get("path")?.as_str()?),
+                    original: c.get("original")?.as_str()?.to_string(),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(InstanceState {
+            pid: json.get("pid")?.as_u64()? as u32,
+            command: json.get("command")?.as_str()?.to_string(),
+            started: json.get("started")?.as_u64()?,
+            persistent: json.get("persistent").and_then(Json::as_bool).unwrap_or(false),
+            changes,
+        })
+    }
+
+    /// Liest die Zustandsdatei; `Ok(None)` wenn keine existiert. Fremde oder
+    /// für andere schreibbare Dateien und Symlinks sind ein Fehler.
+    pub fn load(path: &Path) -> io::Result<Option<Self>> {
+        if let Some(dir) = path.parent() {
+            match fs::symlink_metadata(dir) {
+                Ok(meta) => check_owner(dir, &meta, sys::geteuid(), 0o022)?,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(e) => return Err(e),
+            }
+        }
+        let mut file = match open_nofollow(OpenOptions::new().read(true), path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        check_owner(path, &file.metadata()?, sys::geteuid(), 0o022)?;
+        let mut text = String::new();
+        file.read_to_string(&mut text)?;
+        let json = Json::parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        InstanceState::from_json(&json)
+            .map(Some)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "unexpected state file layout"))
+    }
+
+    /// Schreibt atomar und synchron, damit ein Absturz direkt danach nichts verliert
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let tmp = path.with_extension(format!("tmp.{}", std::process::id()));
+        // Rest eines abgestürzten Laufs mit derselben PID; entfernt auch Symlinks
+        match fs::remove_file(&tmp) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+            _ => {}
+        }
+        let mut file = open_nofollow(OpenOptions::new().write(true).create_new(true), &tmp)?;
+        file.write_all((self.to_json().to_pretty() + "\n").as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)
+    }
+}
+
+/// Von der laufenden Instanz gehaltene Sperre
+struct Held {
+    _lock: File,
+    state: InstanceState,
+}
+
+static HELD: Mutex<Option<Held>> = Mutex::new(None);
+
+/// Gibt die Sperre beim Drop frei; die Zustandsdatei bleibt nur liegen,
+/// wenn noch Änderungen offen sind
+pub struct InstanceLock(());
+
+impl InstanceLock {
+    /// Sperrt für `command`; Fehler, wenn eine andere Instanz läuft oder ein
+    /// Absturz nicht zurückgenommene Änderungen hinterlassen hat
+    pub fn acquire(command: &str) -> Result<Self, String> {
+        let lock = try_lock()?;
+        if let Some(stale) = InstanceState::load(&state_path()).map_err(|e| format!("Cannot read {}: {}", state_path().display(), e))?
+            && !stale.changes.is_empty()
+        {
+            return Err(match stale.persistent {
+                true => format!(
+                    "'{}' keeps {} setting(s) applied - run 'adreno_ioctl restore' (or 'profile reset') first",
+                    stale.command,
+                    stale.changes.len()
+                ),
+                false => format!(
+                    "A previous run (PID {}, '{}') left {} modified setting(s) - run 'adreno_ioctl restore' first",
+                    stale.pid,
+                    stale.command,
+                    stale.changes.len()
+                ),
+            });
+        }
+        let state = InstanceState {
+            pid: std::process::id(),
+            command: command.to_string(),
+            started: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+            persistent: false,
+            changes: Vec::new(),
+        };
+        state.save(&state_path()).map_err(|e| format!("Cannot write {}: {}", state_path().display(), e))?;
+        *HELD.lock().unwrap() = Some(Held { _lock: lock, state });
+        Ok(InstanceLock(()))
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        if let Some(held) = HELD.lock().unwrap().take()
+            && held.state.changes.is_empty()
+        {
+            let _ = fs::remove_file(state_path());
+        }
+    }
+}
+
+fn try_lock() -> Result<File, String> {
+    secure_user_dir(sys::geteuid(), sys::getegid()).map_err(|e| format!("Unsafe state directory: {}", e))?;
+    let path = lock_path();
+    let file = open_nofollow(OpenOptions::new().read(true).write(true).create(true).truncate(false), &path)
+        .map_err(|e| format!("Cannot open {}: {}", path.display(), e))?;
+    file.metadata()
+        .and_then(|meta| check_owner(&path, &meta, sys::geteuid(), 0o022))
+        .map_err(|e| format!("Unsafe lock file: {}", e))?;
+    if sys::flock(file.as_fd(), libc::LOCK_EX | libc::LOCK_NB).is_err() {
+        let holder = InstanceState::load(&state_path())
+            .ok()
+            .flatten()
+            .map(|s| format!(" (PID {}, '{}')", s.pid, s.command))
+            .unwrap_or_default();
+        return Err(format!("Another adreno_ioctl instance{} is changing settings", holder));
+    }
+    Ok(file)
+}
+
+/// Merkt eine Änderung vor, bevor sie geschrieben wird (ohne Sperre: nichts)
+pub fn record(path: &Path, original: &str) -> io::Result<()> {
+    let mut held = HELD.lock().unwrap();
+    let Some(held) = held.as_mut() else {
+        return Ok(());
+    };
+    // Beim mehrfachen Schreiben zählt der allererste Wert
+    if !held.state.changes.iter().any(|c| c.path == path) {
+        held.state.changes.push(Change { path: path.to_path_buf(), original: original.to_string() });
+        held.state.save(&state_path())?;
+    }
+    Ok(())
+}
+
+/// Lässt die offenen Änderungen über das Prozessende hinaus bestehen
+pub fn persist() -> io::Result<()> {
+    let mut held = HELD.lock().unwrap();
+    let Some(held) = held.as_mut() else {
+        return Ok(());
+    };
+    held.state.persistent = true;
+    held.state.save(&state_path())
+}
+
+/// Trägt eine erfolgreich zurückgenommene Änderung aus
+pub fn forget(path: &Path) {
+    let mut held = HELD.lock().unwrap();
+    if let Some(held) = held.as_mut()
+        && let Some(i) = held.state.changes.iter().position(|c| c.path == path)
+    {
+        held.state.changes.remove(i);
+        let _ = held.state.save(&state_path());
+    }
+}
+
+/// Ergebnis einer Wiederherstellung je Eintrag
+#[derive(Debug)]
+pub struct Restored {
+    pub change: Change,
+    pub result: io::Result<()>,
+}
+
+/// Schreibt die Originalwerte einer abgestürzten Instanz zurück (neueste zuerst)
+///
+/// `Ok(None)` wenn nichts offen ist. Fehlgeschlagene Einträge bleiben in der
+/// Zustandsdatei für einen weiteren Versuch.
+pub fn restore() -> Result<Option<(InstanceState, Vec<Restored>)>, String> {
+    let _lock = try_lock()?;
+    restore_from(&state_path())
+}
+
+/// [`restore`] ohne Sperre, für eine gegebene Zustandsdatei
+fn restore_from(path: &Path) -> Result<Option<(InstanceState, Vec<Restored>)>, String> {
+    let Some(mut state) = InstanceState::load(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))? else {
+        return Ok(None);
+    };
+    let mut results = Vec::new();
+    for change in state.changes.iter().rev() {
+        // Nur bekannte KGSL-Einstellungen, nie beliebige Pfade aus dem Journal
+        let result = match Setting::from_journal_path(&change.path) {
+            Some(_) => fs::write(&change.path, &change.original),
+            None => Err(io::Error::new(io::ErrorKind::PermissionDenied, "not a KGSL setting, refusing to write")),
+        };
+        results.push(Restored { change: change.clone(), result });
+    }
+    state.changes.retain(|c| results.iter().any(|r| r.change == *c && r.result.is_err()));
+    if state.changes.is_empty() {
+        let _ = fs::remove_file(path);
+    } else {
+        state.save(path).map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+    }
+    Ok(Some((state, results)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sysroot;
+
+    fn write(path: &Path, text: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, text).unwrap();
+    }
+
+    #[test]
+    fn state_round_trip() {
+        let state = InstanceState {
+            pid: 4242,
+            command: "boost --max".into(),
+            started: 1_700_000_000,
+            persistent: true,
+            changes: vec![Change { path: "/sys/class/kgsl/kgsl-3d0/idle_timer".into(), original: "80".into() }],
+        };
+        assert_eq!(InstanceState::from_json(&state.to_json()), Some(state));
+        assert_eq!(InstanceState::from_json(&Json::parse("{\"pid\": 1, \"changes\": []}").unwrap()), None);
+    }
+
+    #[test]
+    fn restore_writes_only_kgsl_settings() {
+        let root = std::env::temp_dir().join(format!("adreno_ioctl-test-restore-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let device = root.join("sys/class/kgsl/kgsl-3d0");
+        write(&device.join("idle_timer"), "10");
+        write(&root.join("etc/hosts"), "127.0.0.1 localhost");
+        // Gerätename passt, das Ziel liegt aber außerhalb von sysfs
+        fs::create_dir_all(root.join("sys/class/kgsl")).unwrap();
+        std::os::unix::fs::symlink(root.join("etc"), root.join("sys/class/kgsl/kgsl-evil")).unwrap();
+        write(&root.join("etc/idle_timer"), "10");
+
+        let path = root.join("state/adreno_ioctl.state");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let change = |path: PathBuf, original: &str| Change { path, original: original.into() };
+        let state = InstanceState {
+            pid: 1,
+            command: "boost".into(),
+            started: 0,
+            persistent: false,
+            changes: vec![
+                change(device.join("idle_timer"), "80"),
+                change(root.join("etc/hosts"), "pwned"),
+                change(root.join("sys/class/kgsl/kgsl-evil/idle_timer"), "pwned"),
+            ],
+        };
+        state.save(&path).unwrap();
+
+        sysroot::set(Some(root.clone()));
+        let restored = restore_from(&path);
+        sysroot::set(None);
+        let (_, results) = restored.unwrap().unwrap();
+
+        // Neueste Änderung zuerst
+        let ok: Vec<bool> = results.iter().map(|r| r.result.is_ok()).collect();
+        assert_eq!(ok, [false, false, true]);
+        assert_eq!(fs::read_to_string(device.join("idle_timer")).unwrap(), "80");
+        assert_eq!(fs::read_to_string(root.join("etc/hosts")).unwrap(), "127.0.0.1 localhost");
+        assert_eq!(fs::read_to_string(root.join("etc/idle_timer")).unwrap(), "10");
+        // Abgelehnte Einträge bleiben für `restore` stehen
+        let left = InstanceState::load(&path).unwrap().unwrap();
+        assert_eq!(left.changes, state.changes[1..]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,212 @@
+//! Dekodierung von IOCTL-Nummern und Tabelle der bekannten KGSL-Kommandos
+
+use crate::fence::{IOCTL_KGSL_TIMESTAMP_EVENT, SYNC_IOC_FILE_INFO};
+use crate::kgsl::{
+    IOCTL_KGSL_CMDSTREAM_READTIMESTAMP_CTXTID, IOCTL_KGSL_DEVICE_GETPROPERTY, IOCTL_KGSL_DEVICE_WAITTIMESTAMP_CTXTID,
+    IOCTL_KGSL_DRAWCTXT_CREATE, IOCTL_KGSL_DRAWCTXT_DESTROY, IOCTL_KGSL_SETPROPERTY, KGSL_IOC_TYPE,
+};
+use crate::memory::{
+    IOCTL_KGSL_GPUMEM_GET_INFO, IOCTL_KGSL_GPUOBJ_ALLOC, IOCTL_KGSL_GPUOBJ_FREE, IOCTL_KGSL_GPUOBJ_IMPORT,
+    IOCTL_KGSL_GPUOBJ_INFO, IOCTL_KGSL_GPUOBJ_SYNC, IOCTL_KGSL_MAP_USER_MEM, IOCTL_KGSL_SHAREDMEM_FREE,
+};
+use crate::sparse::{
+    IOCTL_KGSL_SPARSE_BIND, IOCTL_KGSL_SPARSE_PHYS_ALLOC, IOCTL_KGSL_SPARSE_PHYS_FREE, IOCTL_KGSL_SPARSE_VIRT_ALLOC,
+    IOCTL_KGSL_SPARSE_VIRT_FREE,
+};
+use crate::submit::IOCTL_KGSL_GPU_COMMAND;
+use crate::timeline::{
+    IOCTL_KGSL_TIMELINE_CREATE, IOCTL_KGSL_TIMELINE_DESTROY, IOCTL_KGSL_TIMELINE_FENCE_GET, IOCTL_KGSL_TIMELINE_QUERY,
+    IOCTL_KGSL_TIMELINE_SIGNAL, IOCTL_KGSL_TIMELINE_WAIT,
+};
+
+/// Zerlegte IOCTL-Nummer (`_IOC_DIR`, `_IOC_TYPE`, `_IOC_NR`, `_IOC_SIZE`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IoctlRequest {
+    pub raw: u32,
+    pub dir: u32,
+    pub type_: u8,
+    pub nr: u8,
+    pub size: u16,
+}
+
+impl IoctlRequest {
+    pub const fn decode(raw: u32) -> Self {
+        IoctlRequest {
+            raw,
+            dir: raw >> 30,
+            type_: (raw >> 8) as u8,
+            nr: raw as u8,
+            size: ((raw >> 16) & 0x3fff) as u16,
+        }
+    }
+
+    /// `_IO`, `_IOW`, `_IOR` oder `_IOWR`
+    pub fn direction_name(&self) -> &'static str {
+        match self.dir {
+            0 => "_IO (none)",
+            1 => "_IOW (write to kernel)",
+            2 => "_IOR (read from kernel)",
+            _ => "_IOWR (read/write)",
+        }
+    }
+
+    /// Bekannter Name des IOCTL-Typs
+    pub fn type_name(&self) -> Option<&'static str> {
+        match self.type_ as u32 {
+            KGSL_IOC_TYPE => Some("KGSL_IOC_TYPE"),
+            t if t == b'>' as u32 => Some("SYNC_IOC_MAGIC (sync_file)"),
+            t if t == b'd' as u32 => Some("DRM_IOCTL_BASE"),
+            t if t == b'b' as u32 => Some("DMA_BUF_BASE"),
+            _ => None,
+        }
+    }
+
+    /// KGSL-Kommando mit dieser Nummer, falls bekannt
+    pub fn kgsl_command(&self) -> Option<&'static KgslIoctl> {
+        if self.type_ as u32 != KGSL_IOC_TYPE {
+            return None;
+        }
+        KGSL_IOCTLS.iter().find(|c| c.nr == self.nr)
+    }
+}
+
+/// Ein bekanntes KGSL-Kommando
+#[derive(Debug, Clone, Copy)]
+pub struct KgslIoctl {
+    pub nr: u8,
+    pub name: &'static str,
+    pub struct_name: &'static str,
+    /// Vollständige Nummer für diese Plattform, falls in der Bibliothek definiert
+    pub request: Option<u32>,
+}
+
+const fn cmd(nr: u8, name: &'static str, struct_name: &'static str) -> KgslIoctl {
+    KgslIoctl { nr, name, struct_name, request: None }
+}
+
+const fn known(request: u32, name: &'static str, struct_name: &'static str) -> KgslIoctl {
+    KgslIoctl { nr: request as u8, name, struct_name, request: Some(request) }
+}
+
+/// KGSL-Kommandos aus `msm_kgsl.h`, nach Nummer sortiert
+pub const KGSL_IOCTLS: &[KgslIoctl] = &[
+    known(IOCTL_KGSL_DEVICE_GETPROPERTY, "IOCTL_KGSL_DEVICE_GETPROPERTY", "kgsl_device_getproperty"),
+    cmd(0x06, "IOCTL_KGSL_DEVICE_WAITTIMESTAMP", "kgsl_device_waittimestamp"),
+    known(IOCTL_KGSL_DEVICE_WAITTIMESTAMP_CTXTID, "IOCTL_KGSL_DEVICE_WAITTIMESTAMP_CTXTID", "kgsl_device_waittimestamp_ctxtid"),
+    cmd(0x0A, "IOCTL_KGSL_RINGBUFFER_ISSUEIBCMDS", "kgsl_ringbuffer_issueibcmds"),
+    cmd(0x10, "IOCTL_KGSL_CMDSTREAM_READTIMESTAMP_OLD", "kgsl_cmdstream_readtimestamp"),
+    cmd(0x11, "IOCTL_KGSL_CMDSTREAM_READTIMESTAMP", "kgsl_cmdstream_readtimestamp"),
+    cmd(0x12, "IOCTL_KGSL_CMDSTREAM_FREEMEMONTIMESTAMP", "kgsl_cmdstream_freememontimestamp"),
+    known(IOCTL_KGSL_DRAWCTXT_CREATE, "IOCTL_KGSL_DRAWCTXT_CREATE", "kgsl_drawctxt_create"),
+    known(IOCTL_KGSL_DRAWCTXT_DESTROY, "IOCTL_KGSL_DRAWCTXT_DESTROY", "kgsl_drawctxt_destroy"),
+    known(IOCTL_KGSL_MAP_USER_MEM, "IOCTL_KGSL_MAP_USER_MEM", "kgsl_map_user_mem"),
+    known(IOCTL_KGSL_CMDSTREAM_READTIMESTAMP_CTXTID, "IOCTL_KGSL_CMDSTREAM_READTIMESTAMP_CTXTID", "kgsl_cmdstream_readtimestamp_ctxtid"),
+    cmd(0x17, "IOCTL_KGSL_CMDSTREAM_FREEMEMONTIMESTAMP_CTXTID", "kgsl_cmdstream_freememontimestamp_ctxtid"),
+    cmd(0x20, "IOCTL_KGSL_SHAREDMEM_FROM_PMEM", "kgsl_sharedmem_from_pmem"),
+    known(IOCTL_KGSL_SHAREDMEM_FREE, "IOCTL_KGSL_SHAREDMEM_FREE", "kgsl_sharedmem_free"),
+    cmd(0x22, "IOCTL_KGSL_SHAREDMEM_FROM_VMALLOC", "kgsl_sharedmem_from_vmalloc"),
+    cmd(0x24, "IOCTL_KGSL_SHAREDMEM_FLUSH_CACHE", "kgsl_sharedmem_free"),
+    cmd(0x25, "IOCTL_KGSL_DRAWCTXT_SET_BIN_BASE_OFFSET", "kgsl_drawctxt_set_bin_base_offset"),
+    cmd(0x2F, "IOCTL_KGSL_GPUMEM_ALLOC", "kgsl_gpumem_alloc"),
+    cmd(0x31, "IOCTL_KGSL_TIMESTAMP_EVENT_OLD", "kgsl_timestamp_event"),
+    known(IOCTL_KGSL_SETPROPERTY, "IOCTL_KGSL_SETPROPERTY", "kgsl_device_getproperty"),
+    known(IOCTL_KGSL_TIMESTAMP_EVENT, "IOCTL_KGSL_TIMESTAMP_EVENT", "kgsl_timestamp_event"),
+    cmd(0x34, "IOCTL_KGSL_GPUMEM_ALLOC_ID", "kgsl_gpumem_alloc_id"),
+    cmd(0x35, "IOCTL_KGSL_GPUMEM_FREE_ID", "kgsl_gpumem_free_id"),
+    known(IOCTL_KGSL_GPUMEM_GET_INFO, "IOCTL_KGSL_GPUMEM_GET_INFO", "kgsl_gpumem_get_info"),
+    cmd(0x37, "IOCTL_KGSL_GPUMEM_SYNC_CACHE", "kgsl_gpumem_sync_cache"),
+    cmd(0x38, "IOCTL_KGSL_PERFCOUNTER_GET", "kgsl_perfcounter_get"),
+    cmd(0x39, "IOCTL_KGSL_PERFCOUNTER_PUT", "kgsl_perfcounter_put"),
+    cmd(0x3A, "IOCTL_KGSL_PERFCOUNTER_QUERY", "kgsl_perfcounter_query"),
+    cmd(0x3B, "IOCTL_KGSL_PERFCOUNTER_READ", "kgsl_perfcounter_read"),
+    cmd(0x3C, "IOCTL_KGSL_GPUMEM_SYNC_CACHE_BULK", "kgsl_gpumem_sync_cache_bulk"),
+    cmd(0x3D, "IOCTL_KGSL_SUBMIT_COMMANDS", "kgsl_submit_commands"),
+    cmd(0x3E, "IOCTL_KGSL_SYNCSOURCE_CREATE", "kgsl_syncsource_create"),
+    cmd(0x3F, "IOCTL_KGSL_SYNCSOURCE_DESTROY", "kgsl_syncsource_destroy"),
+    cmd(0x40, "IOCTL_KGSL_SYNCSOURCE_CREATE_FENCE", "kgsl_syncsource_create_fence"),
+    cmd(0x41, "IOCTL_KGSL_SYNCSOURCE_SIGNAL_FENCE", "kgsl_syncsource_signal_fence"),
+    known(IOCTL_KGSL_GPUOBJ_ALLOC, "IOCTL_KGSL_GPUOBJ_ALLOC", "kgsl_gpuobj_alloc"),
+    known(IOCTL_KGSL_GPUOBJ_FREE, "IOCTL_KGSL_GPUOBJ_FREE", "kgsl_gpuobj_free"),
+    known(IOCTL_KGSL_GPUOBJ_INFO, "IOCTL_KGSL_GPUOBJ_INFO", "kgsl_gpuobj_info"),
+    known(IOCTL_KGSL_GPUOBJ_IMPORT, "IOCTL_KGSL_GPUOBJ_IMPORT", "kgsl_gpuobj_import"),
+    known(IOCTL_KGSL_GPUOBJ_SYNC, "IOCTL_KGSL_GPUOBJ_SYNC", "kgsl_gpuobj_sync"),
+    known(IOCTL_KGSL_GPU_COMMAND, "IOCTL_KGSL_GPU_COMMAND", "kgsl_gpu_command"),
+    cmd(0x4B, "IOCTL_KGSL_PREEMPTIONCOUNTER_QUERY", "kgsl_preemption_counters_query"),
+    cmd(0x4C, "IOCTL_KGSL_GPUOBJ_SET_INFO", "kgsl_gpuobj_set_info"),
+    known(IOCTL_KGSL_SPARSE_PHYS_ALLOC, "IOCTL_KGSL_SPARSE_PHYS_ALLOC", "kgsl_sparse_phys_alloc"),
+    known(IOCTL_KGSL_SPARSE_PHYS_FREE, "IOCTL_KGSL_SPARSE_PHYS_FREE", "kgsl_sparse_phys_free"),
+    known(IOCTL_KGSL_SPARSE_VIRT_ALLOC, "IOCTL_KGSL_SPARSE_VIRT_ALLOC", "kgsl_sparse_virt_alloc"),
+    known(IOCTL_KGSL_SPARSE_VIRT_FREE, "IOCTL_KGSL_SPARSE_VIRT_FREE", "kgsl_sparse_virt_free"),
+    known(IOCTL_KGSL_SPARSE_BIND, "IOCTL_KGSL_SPARSE_BIND", "kgsl_sparse_bind"),
+    cmd(0x55, "IOCTL_KGSL_GPU_SPARSE_COMMAND", "kgsl_gpu_sparse_command"),
+    cmd(0x56, "IOCTL_KGSL_GPUMEM_BIND_RANGES", "kgsl_gpumem_bind_ranges"),
+    cmd(0x57, "IOCTL_KGSL_GPU_AUX_COMMAND", "kgsl_gpu_aux_command"),
+    known(IOCTL_KGSL_TIMELINE_CREATE, "IOCTL_KGSL_TIMELINE_CREATE", "kgsl_timeline_create"),
+    known(IOCTL_KGSL_TIMELINE_WAIT, "IOCTL_KGSL_TIMELINE_WAIT", "kgsl_timeline_wait"),
+    known(IOCTL_KGSL_TIMELINE_QUERY, "IOCTL_KGSL_TIMELINE_QUERY", "kgsl_timeline_val"),
+    known(IOCTL_KGSL_TIMELINE_SIGNAL, "IOCTL_KGSL_TIMELINE_SIGNAL", "kgsl_timeline_signal"),
+    known(IOCTL_KGSL_TIMELINE_FENCE_GET, "IOCTL_KGSL_TIMELINE_FENCE_GET", "kgsl_timeline_fence_get"),
+    known(IOCTL_KGSL_TIMELINE_DESTROY, "IOCTL_KGSL_TIMELINE_DESTROY", "__u32"),
+];
+
+/// Andere in der Bibliothek verwendete IOCTLs
+pub const OTHER_IOCTLS: &[(u32, &str, &str)] = &[(SYNC_IOC_FILE_INFO, "SYNC_IOC_FILE_INFO", "sync_file_info")];
+
+/// Name eines IOCTLs für Ausgaben, z.B. im Tracer
+pub fn name_of(raw: u32) -> Option<&'static str> {
+    let req = IoctlRequest::decode(raw);
+    req.kgsl_command()
+        .map(|c| c.name)
+        .or_else(|| OTHER_IOCTLS.iter().find(|(r, _, _)| *r == raw).map(|(_, n, _)| *n))
+}
+
+// ============================================================================
+// Property-IDs
+// ============================================================================
+
+/// `KGSL_PROP_*` aus `msm_kgsl.h`
+pub const KGSL_PROPERTIES: &[(u32, &str)] = &[
+    (0x01, "DEVICE_INFO"),
+    (0x02, "DEVICE_SHADOW"),
+    (0x03, "DEVICE_POWER"),
+    (0x04, "SHMEM"),
+    (0x05, "SHMEM_APERTURES"),
+    (0x06, "MMU_ENABLE"),
+    (0x07, "INTERRUPT_WAITS"),
+    (0x08, "VERSION"),
+    (0x09, "GPU_RESET_STAT"),
+    (0x0E, "PWRCTRL"),
+    (0x12, "PWR_CONSTRAINT"),
+    (0x13, "UCHE_GMEM_VADDR"),
+    (0x14, "SP_GENERIC_MEM"),
+    (0x15, "UCODE_VERSION"),
+    (0x16, "GPMU_VERSION"),
+    (0x17, "HIGHEST_BANK_BIT"),
+    (0x18, "DEVICE_BITNESS"),
+    (0x19, "DEVICE_QDSS_STM"),
+    (0x1A, "MIN_ACCESS_LENGTH"),
+    (0x1B, "UBWC_MODE"),
+    (0x20, "DEVICE_QTIMER"),
+    (0x22, "L3_PWR_CONSTRAINT"),
+    (0x23, "SECURE_BUFFER_ALIGNMENT"),
+    (0x24, "SECURE_CTXT_SUPPORT"),
+    (0x25, "SPEED_BIN"),
+    (0x26, "GAMING_BIN"),
+    (0x27, "QUERY_CAPABILITIES"),
+    (0x28, "CONTEXT_PROPERTY"),
+    (0x29, "GPU_MODEL"),
+    (0x2A, "VK_DEVICE_ID"),
+    (0x2B, "IS_LPAC_ENABLED"),
+    (0x2C, "GPU_VA64_SIZE"),
+    (0x2D, "IS_RAYTRACING_ENABLED"),
+    (0x2E, "IS_FASTBLEND_ENABLED"),
+    (0x2F, "UCHE_TRAP_BASE"),
+    (0x30, "IS_AQE_ENABLED"),
+    (0x31, "GPU_SECURE_VA_SIZE"),
+    (0x32, "GPU_SECURE_VA_INUSE"),
+];
+
+/// Name einer Property-ID ohne `KGSL_PROP_`-Präfix
+pub fn property_name(id: u32) -> Option<&'static str> {
+    KGSL_PROPERTIES.iter().find(|(i, _)| *i == id).map(|(_, n)| *n)
+}
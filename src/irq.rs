@@ -0,0 +1,126 @@
+//! GPU Interrupt-Zähler aus `/proc/interrupts`
+
+use std::fs;
+use std::io;
+
+use crate::sysroot;
+
+pub const PROC_INTERRUPTS: &str = "/proc/interrupts";
+
+/// Namensteile, an denen GPU-Interrupts erkannt werden
+const GPU_IRQ_PATTERNS: [&str; 4] = ["kgsl", "adreno", "gmu", "hfi"];
+
+/// Eine Zeile aus `/proc/interrupts`
+#[derive(Debug, Clone)]
+pub struct IrqLine {
+    /// IRQ-Nummer oder Kürzel (z.B. "300" oder "IPI0")
+    pub irq: String,
+    /// Zähler pro CPU
+    pub per_cpu: Vec<u64>,
+    /// Beschreibung (Controller, hwirq, Trigger, Name)
+    pub name: String,
+}
+
+impl IrqLine {
+    pub fn total(&self) -> u64 {
+        self.per_cpu.iter().sum()
+    }
+
+    /// Letztes Wort der Beschreibung, z.B. "kgsl-3d0"
+    pub fn short_name(&self) -> &str {
+        self.name.split_whitespace().last().unwrap_or(&self.irq)
+    }
+
+    pub fn is_gpu(&self) -> bool {
+        let name = self.name.to_ascii_lowercase();
+        GPU_IRQ_PATTERNS.iter().any(|p| name.contains(p))
+    }
+}
+
+/// Parst den Inhalt von `/proc/interrupts`
+pub fn parse_interrupts(text: &str) -> Vec<IrqLine> {
+    let mut lines = text.lines();
+    let cpus = match lines.next() {
+        Some(header) => header.split_whitespace().filter(|w| w.starts_with("CPU")).count(),
+        None => return Vec::new(),
+    };
+
+    let mut result = Vec::new();
+    for line in lines {
+        let Some((irq, rest)) = line.split_once(':') else { continue };
+        let mut fields = rest.split_whitespace().peekable();
+
+        let mut per_cpu = Vec::with_capacity(cpus);
+        while per_cpu.len() < cpus {
+            match fields.peek().and_then(|f| f.parse::<u64>().ok()) {
+                Some(v) => {
+                    per_cpu.push(v);
+                    fields.next();
+                }
+                None => break,
+            }
+        }
+
+        result.push(IrqLine {
+            irq: irq.trim().to_string(),
+            per_cpu,
+            name: fields.collect::<Vec<_>>().join(" "),
+        });
+    }
+    result
+}
+
+/// Liest alle GPU-bezogenen Interrupt-Zeilen
+pub fn read_gpu_irqs() -> io::Result<Vec<IrqLine>> {
+    let text = fs::read_to_string(sysroot::resolve(PROC_INTERRUPTS))?;
+    Ok(parse_interrupts(&text).into_iter().filter(IrqLine::is_gpu).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = "           CPU0       CPU1       CPU2       CPU3\n";
+
+    #[test]
+    fn counts_every_cpu_column() {
+        let text = format!("{} 300:       1200          5          0         17     GICv3 332 Level     kgsl-3d0\n", HEADER);
+        let lines = parse_interrupts(&text);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].irq, "300");
+        assert_eq!(lines[0].per_cpu, [1200, 5, 0, 17]);
+        assert_eq!(lines[0].total(), 1222);
+        assert_eq!(lines[0].short_name(), "kgsl-3d0");
+        assert!(lines[0].is_gpu());
+    }
+
+    #[test]
+    fn no_gpu_line_without_kgsl() {
+        let text = format!(
+            "{} IPI0:      9000       8000       7000       6000       Rescheduling interrupts\n\
+             \x20 27:         10         20         30         40     GICv3  27 Level     arch_timer\n",
+            HEADER
+        );
+        let lines = parse_interrupts(&text);
+        assert_eq!(lines.len(), 2);
+        assert!(!lines.iter().any(IrqLine::is_gpu));
+        assert_eq!(lines[0].short_name(), "interrupts");
+    }
+
+    #[test]
+    fn malformed_columns() {
+        // Zu wenige Zähler, Text statt Zahl, Zeile ohne Doppelpunkt
+        let text = format!(
+            "{} 301:         42     GICv3 333 Level     gmu\n 302:         1x          2     kgsl_hfi\nno colon here\n",
+            HEADER
+        );
+        let lines = parse_interrupts(&text);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].per_cpu, [42]);
+        assert_eq!(lines[0].name, "GICv3 333 Level gmu");
+        assert!(lines[1].per_cpu.is_empty());
+        assert_eq!(lines[1].name, "1x 2 kgsl_hfi");
+        assert!(lines.iter().all(IrqLine::is_gpu));
+        assert!(parse_interrupts("").is_empty());
+    }
+}
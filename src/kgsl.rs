@@ -0,0 +1,873 @@
+//! KGSL IOCTL-Schnittstelle
+//!
+//! Strukturen und Aufrufe für `/dev/kgsl-*`, basierend auf empirischen Tests
+//! und `msm_kgsl.h`.
+
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::mem::size_of;
+use std::os::fd::{AsFd, AsRawFd, BorrowedFd};
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use zerocopy::{FromBytes, Immutable, IntoBytes};
+
+use crate::backend::{self, getproperty_ioctl};
+use crate::memstore::{ContextTimestamps, Memstore, RINGBUFFER_SLOTS};
+use crate::messages::Msg;
+use crate::payload::Decode;
+use crate::propmap::{self, Prop, PropertyId};
+use crate::sys::{self, KgslRequest, Pod};
+
+// ============================================================================
+// IOCTL Definitionen - Basierend auf deinen Tests
+// ============================================================================
+
+/// KGSL IOCTL Typ (`KGSL_IOC_TYPE`)
+pub const KGSL_IOC_TYPE: u32 = 0x09;
+
+pub const IOC_WRITE: u32 = 1;
+pub const IOC_READ: u32 = 2;
+
+/// Baut eine IOCTL-Nummer wie `_IOC()` aus dem Kernel, für beliebige Typen
+pub const fn ioc(dir: u32, type_: u32, nr: u32, size: usize) -> u32 {
+    (dir << 30) | ((size as u32 & 0x3fff) << 16) | (type_ << 8) | nr
+}
+
+/// `_IOC()` mit dem KGSL Typ
+pub const fn kgsl_ioc(dir: u32, nr: u32, size: usize) -> u32 {
+    ioc(dir, KGSL_IOC_TYPE, nr, size)
+}
+
+/// `_IOW(KGSL_IOC_TYPE, nr, T)`
+pub const fn kgsl_iow(nr: u32, size: usize) -> u32 {
+    kgsl_ioc(IOC_WRITE, nr, size)
+}
+
+/// `_IOWR(KGSL_IOC_TYPE, nr, T)`
+pub const fn kgsl_iowr(nr: u32, size: usize) -> u32 {
+    kgsl_ioc(IOC_READ | IOC_WRITE, nr, size)
+}
+
+/// GETPROPERTY mit 20 Bytes - die funktionierende Nummer
+pub const IOCTL_KGSL_DEVICE_GETPROPERTY: u32 = 0xc0140902;
+/// SETPROPERTY, gleiche Größe wie GETPROPERTY
+pub const IOCTL_KGSL_SETPROPERTY: u32 = 0x40140932;
+pub const IOCTL_KGSL_DRAWCTXT_CREATE: u32 = kgsl_iowr(0x13, size_of::<KgslDrawctxtCreate>());
+pub const IOCTL_KGSL_DRAWCTXT_DESTROY: u32 = kgsl_iow(0x14, size_of::<KgslDrawctxtDestroy>());
+
+/// IOCTL Request Struktur
+#[repr(C)]
+#[derive(Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslDeviceGetProperty {
+    pub type_: u32,
+    #[cfg(target_pointer_width = "64")]
+    pub _pad0: u32,
+    /// Adresse des Antwortpuffers
+    pub value: usize,
+    pub sizebytes: u32,
+    pub _pad: [u32; 2],
+    #[cfg(target_pointer_width = "64")]
+    pub _pad1: u32,
+}
+
+/// GPU Info Struktur (16 Bytes)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, FromBytes, IntoBytes, Immutable)]
+pub struct KgslDeviceInfo {
+    pub device_id: u32,      // Offset 0
+    pub chip_id: u32,        // Offset 4
+    pub mmu_enabled: u32,    // Offset 8
+    pub gmem_gpubaseaddr: u32, // Offset 12
+}
+
+/// Version Info Struktur (8 Bytes)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, FromBytes, IntoBytes, Immutable)]
+pub struct KgslVersionInfo {
+    pub driver_version: u32,
+    pub device_version: u32,
+}
+
+/// Property Types (aus msm_kgsl.h)
+pub const KGSL_PROP_DEVICE_INFO: u32 = 0x00000001;
+pub const KGSL_PROP_VERSION: u32 = 0x00000008;
+pub const KGSL_PROP_PWR_CONSTRAINT: u32 = 0x00000012;
+pub const KGSL_PROP_L3_PWR_CONSTRAINT: u32 = 0x00000022;
+
+// ============================================================================
+// Generische Property-Aufrufe
+// ============================================================================
+
+/// Eintrag von `prop`; `InvalidInput`, wenn `size` nicht zur Map passt
+fn checked_property(prop: Prop, size: usize) -> io::Result<PropertyId> {
+    let entry = propmap::property(prop);
+    if entry.size != size {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} takes {} bytes, got {}", prop.name(), entry.size, size),
+        ));
+    }
+    Ok(entry)
+}
+
+/// EINVAL für eine Property, die der Baum des Geräts nicht kennt, als `Unsupported`
+///
+/// Nur im Fehlerfall wird der Baum gebraucht, der Lesepfad bleibt ohne Zusatz-I/O.
+fn not_in_tree(fd: i32, prop: Prop, entry: PropertyId, error: io::Error) -> io::Error {
+    if error.raw_os_error() != Some(libc::EINVAL) {
+        return error;
+    }
+    let tree = propmap::tree(fd);
+    match entry.expected_in(tree) {
+        true => error,
+        false => io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("{} is not in the {} property map ({})", prop.name(), tree, error),
+        ),
+    }
+}
+
+/// Liest `prop` feldweise über [`Decode`], wie [`get_property_decoded`]
+///
+/// Der Puffer geht genullt an den Kernel: Adressen im Payload sind NULL.
+pub fn read_property<T: Decode>(fd: i32, prop: Prop) -> io::Result<T> {
+    let entry = propmap::property(prop);
+    get_property_decoded(fd, entry.id).map_err(|e| not_in_tree(fd, prop, entry, e))
+}
+
+/// GETPROPERTY mit `value` als Ein- und Ausgabe
+///
+/// Nur für Payloads, die der Aufrufer füllt (Context-ID, Adressen auf
+/// lebende Puffer); reine Lesezugriffe gehen über [`read_property`]. `T`
+/// muss die Größe aus der Property-Map haben.
+pub(crate) fn query_property<T: Pod>(fd: i32, prop: Prop, value: &mut T) -> io::Result<()> {
+    let entry = checked_property(prop, size_of::<T>())?;
+    query_property_id(fd, entry.id, value).map_err(|e| not_in_tree(fd, prop, entry, e))
+}
+
+/// Wie [`query_property`], aber mit roher ID und unveränderten Fehlern
+pub(crate) fn query_property_id<T: Pod>(fd: i32, type_: u32, value: &mut T) -> io::Result<()> {
+    getproperty_ioctl(fd, IOCTL_KGSL_DEVICE_GETPROPERTY, type_, value.as_mut_bytes())
+}
+
+/// Liest `size` Rohbytes einer Property, für Properties ohne bekannte Struktur
+pub fn get_property_bytes(fd: i32, type_: u32, size: usize) -> io::Result<Vec<u8>> {
+    let mut value = vec![0u8; size];
+    getproperty_ioctl(fd, IOCTL_KGSL_DEVICE_GETPROPERTY, type_, &mut value)?;
+    Ok(value)
+}
+
+/// Zusätzliche Bytes, die [`get_property_decoded`] für längere Kernel-Strukturen probiert
+pub const PROPERTY_SLACK: usize = 64;
+
+/// Liest eine Property feldweise über [`Decode`]
+///
+/// Beginnt mit der bekannten Größe. Handler, die `sizebytes` exakt gegen
+/// eine größere Struktur prüfen, antworten mit EINVAL; dann wird in
+/// 4-Byte-Schritten bis [`PROPERTY_SLACK`] darüber probiert und der Rest ignoriert.
+pub fn get_property_decoded<T: Decode>(fd: i32, type_: u32) -> io::Result<T> {
+    let known = T::size(backend::word_size());
+    let mut size = known;
+    loop {
+        match get_property_bytes(fd, type_, size) {
+            Ok(bytes) => return T::decode_bytes(&bytes).ok_or_else(|| io::Error::other("property payload too short")),
+            Err(e) if e.raw_os_error() == Some(libc::EINVAL) && size < known + PROPERTY_SLACK => size += 4,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Schreibt eine Property (SETPROPERTY); Adressen wie bei [`query_property`]
+fn set_property<T: Pod>(fd: i32, type_: u32, value: &mut T) -> io::Result<()> {
+    sys::property_ioctl(fd, IOCTL_KGSL_SETPROPERTY, type_, value.as_mut_bytes())
+}
+
+// ============================================================================
+// Einfache, funktionierende Funktionen
+// ============================================================================
+
+/// Liest GPU Info mit der bewährten Methode
+pub fn read_gpu_info(fd: i32) -> Result<KgslDeviceInfo, String> {
+    let device_info: KgslDeviceInfo =
+        read_property(fd, Prop::DeviceInfo).map_err(|error| Msg::IoctlFailed { error: &error }.to_string())?;
+
+    // Validiere die Daten
+    if device_info.chip_id == 0 && device_info.device_id == 0 {
+        return Err(Msg::NoValidGpuData.to_string());
+    }
+
+    Ok(device_info)
+}
+
+/// Vollständige `struct kgsl_devinfo` mit nativen Typen (inkl. GMEM-Größe)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslDevinfo {
+    pub device_id: u32,
+    pub chip_id: u32,
+    pub mmu_enabled: u32,
+    #[cfg(target_pointer_width = "64")]
+    pub _pad0: u32,
+    pub gmem_gpubaseaddr: libc::c_ulong,
+    pub gpu_id: u32,
+    #[cfg(target_pointer_width = "64")]
+    pub _pad1: u32,
+    pub gmem_sizebytes: usize,
+}
+
+/// Liest die vollständige Geräteinfo - ältere Kernel kennen nur die kurze Form
+///
+/// Dekodiert mit variabler Größe, daher ohne Größenprüfung der Property-Map.
+pub fn read_devinfo(fd: i32) -> io::Result<KgslDevinfo> {
+    get_property_decoded(fd, propmap::property_id(Prop::DeviceInfo))
+}
+
+/// Modellname als String (`KGSL_PROP_GPU_MODEL`), z.B. "Adreno740v2"
+pub const KGSL_PROP_GPU_MODEL: u32 = 0x00000029;
+
+pub fn read_gpu_model(fd: i32) -> Option<String> {
+    let name: [u8; 32] = read_property(fd, Prop::GpuModel).ok()?;
+    let end = name.iter().position(|&b| b == 0).unwrap_or(name.len());
+    let model = String::from_utf8_lossy(&name[..end]).trim().to_string();
+    (!model.is_empty()).then_some(model)
+}
+
+/// Vulkan-deviceID (`KGSL_PROP_VK_DEVICE_ID`), ab msm-5.4; 0 heißt "nicht gesetzt"
+pub fn read_vk_device_id(fd: i32) -> Option<u32> {
+    let id: u32 = read_property(fd, Prop::VkDeviceId).ok()?;
+    (id != 0).then_some(id)
+}
+
+/// Ob Timestamp-Waits auf Interrupts schlafen (`KGSL_PROP_INTERRUPT_WAITS`);
+/// die 3D-Treiber der meisten Bäume kennen die Property nicht
+pub fn read_interrupt_waits(fd: i32) -> Option<bool> {
+    let value: u32 = read_property(fd, Prop::InterruptWaits).ok()?;
+    Some(value != 0)
+}
+
+/// Minimale Zugriffslänge in Bytes (`KGSL_PROP_MIN_ACCESS_LENGTH`), ab A6xx
+pub fn read_min_access_length(fd: i32) -> Option<u32> {
+    let length: u32 = read_property(fd, Prop::MinAccessLength).ok()?;
+    (length != 0).then_some(length)
+}
+
+/// Liest die Treiberversion - KORRIGIERTE VERSION
+pub fn read_gpu_version(fd: i32) -> Result<KgslVersionInfo, String> {
+    // WICHTIG: Für Version brauchen wir möglicherweise eine andere IOCTL-Nummer!
+    // Versuche verschiedene Kombinationen
+    let possible_ioctls: [u32; 3] = [
+        0xc0080902,  // 8 Bytes (wahrscheinlich richtig)
+        0xc0140902,  // 20 Bytes (wie für device info)
+        0xc00c0902,  // 12 Bytes
+    ];
+
+    let prop = propmap::property_id(Prop::Version);
+    for &ioctl_num in &possible_ioctls {
+        let mut bytes = [0u8; 8];
+        if getproperty_ioctl(fd, ioctl_num, prop, &mut bytes).is_err() {
+            continue;
+        }
+        match KgslVersionInfo::decode_bytes(&bytes) {
+            Some(version) if version.driver_version != 0 || version.device_version != 0 => return Ok(version),
+            _ => {}
+        }
+    }
+
+    Err(Msg::VersionUnavailable.to_string())
+}
+
+// ============================================================================
+// Geräte-Suche
+// ============================================================================
+
+/// Findet KGSL-Geräte
+pub fn find_kgsl_devices() -> Vec<String> {
+    let possible_paths = [
+        "/dev/kgsl-3d0",
+        "/dev/kgsl/kgsl-3d0",
+        "/dev/kgsl-3d1",
+        "/dev/kgsl-2d0",
+        "/dev/kgsl-2d1",
+        "/dev/kgsl-lpac0",
+        "/dev/kgsl/kgsl-lpac0",
+    ];
+
+    possible_paths.iter()
+        .filter(|path| crate::sysroot::resolve(path).exists())
+        .map(|&s| s.to_string())
+        .collect()
+}
+
+// ============================================================================
+// Zugriffsart
+// ============================================================================
+
+/// Öffnungsmodus des Geräts
+///
+/// Abfragen (Properties, Timestamps, Zähler) gehen mit `O_RDONLY`; manche
+/// SELinux-Domänen erlauben nur das. Lesend und schreibend öffnen nur
+/// Kommandos, die Speicher anlegen, Contexts erzeugen oder einreichen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessMode {
+    ReadOnly,
+    ReadWrite,
+}
+
+impl AccessMode {
+    /// Modus eines offenen Deskriptors (`F_GETFL`)
+    pub fn of(fd: i32) -> io::Result<AccessMode> {
+        let flags = sys::file_status_flags(fd)?;
+        Ok(if flags & libc::O_ACCMODE == libc::O_RDONLY { AccessMode::ReadOnly } else { AccessMode::ReadWrite })
+    }
+
+    pub fn allows_write(self) -> bool {
+        self == AccessMode::ReadWrite
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            AccessMode::ReadOnly => "read-only",
+            AccessMode::ReadWrite => "read-write",
+        }
+    }
+}
+
+/// Öffnet ein Gerät mit genau dieser Zugriffsart
+pub fn open_with_mode(path: &Path, mode: AccessMode) -> io::Result<File> {
+    OpenOptions::new().read(true).write(mode.allows_write()).open(path)
+}
+
+/// Stärkster Modus, den Dateirechte und SELinux für `path` zulassen
+pub fn probe_access(path: &Path) -> Option<AccessMode> {
+    [AccessMode::ReadWrite, AccessMode::ReadOnly].into_iter().find(|&mode| open_with_mode(path, mode).is_ok())
+}
+
+/// Art eines KGSL-Knotens, aus dem Namen abgeleitet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceKind {
+    /// `kgsl-3d*`: der normale Grafik- und Compute-Kern
+    Gpu3d,
+    /// `kgsl-2d*`: Z180-Kern, siehe [`crate::core2d`]
+    Core2d,
+    /// `kgsl-lpac*`: eigener Knoten für Low Priority Async Compute
+    Lpac,
+}
+
+impl DeviceKind {
+    pub fn from_path(path: &str) -> Self {
+        let name = std::path::Path::new(path).file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if name.starts_with("kgsl-2d") {
+            DeviceKind::Core2d
+        } else if name.contains("lpac") {
+            DeviceKind::Lpac
+        } else {
+            DeviceKind::Gpu3d
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DeviceKind::Gpu3d => "3D",
+            DeviceKind::Core2d => "2D",
+            DeviceKind::Lpac => "LPAC compute",
+        }
+    }
+}
+
+// ============================================================================
+// Performance/Clock Info (optional, falls verfügbar)
+// ============================================================================
+
+/// Versucht, GPU Frequenz-Informationen zu lesen
+pub fn try_read_gpu_frequency(fd: i32) -> Option<u32> {
+    let prop = propmap::property_id(Prop::PwrCtrl);
+
+    // Versuche verschiedene IOCTLs
+    let possible_ioctls: [u32; 3] = [0xc0040902, 0xc0080902, 0xc0140902];
+
+    for &ioctl_num in &possible_ioctls {
+        let mut bytes = [0u8; 4];
+        if getproperty_ioctl(fd, ioctl_num, prop, &mut bytes).is_err() {
+            continue;
+        }
+        match u32::decode_bytes(&bytes) {
+            Some(freq) if freq != 0 => return Some(freq),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+// ============================================================================
+// Draw Contexts
+// ============================================================================
+
+/// Context-Flags (aus msm_kgsl.h)
+pub const KGSL_CONTEXT_NO_GMEM_ALLOC: u32 = 0x00000002;
+pub const KGSL_CONTEXT_PREAMBLE: u32 = 0x00000010;
+pub const KGSL_CONTEXT_PER_CONTEXT_TS: u32 = 0x00000040;
+pub const KGSL_CONTEXT_PWR_CONSTRAINT: u32 = 0x00000800;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslDrawctxtCreate {
+    pub flags: u32,
+    pub drawctxt_id: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslDrawctxtDestroy {
+    pub drawctxt_id: u32,
+}
+
+/// Legt einen Draw Context an und liefert dessen ID
+pub fn create_context(fd: i32, flags: u32) -> io::Result<u32> {
+    let mut req = KgslDrawctxtCreate { flags, drawctxt_id: 0 };
+    sys::ioctl(fd, IOCTL_KGSL_DRAWCTXT_CREATE, &mut req)?;
+    Ok(req.drawctxt_id)
+}
+
+/// Zerstört einen Draw Context
+pub fn destroy_context(fd: i32, id: u32) -> io::Result<()> {
+    let mut req = KgslDrawctxtDestroy { drawctxt_id: id };
+    sys::ioctl(fd, IOCTL_KGSL_DRAWCTXT_DESTROY, &mut req)?;
+    Ok(())
+}
+
+// ============================================================================
+// Timestamps
+// ============================================================================
+
+/// Timestamp-Arten (`KGSL_TIMESTAMP_*`)
+pub const KGSL_TIMESTAMP_CONSUMED: u32 = 0x00000001;
+pub const KGSL_TIMESTAMP_RETIRED: u32 = 0x00000002;
+pub const KGSL_TIMESTAMP_QUEUED: u32 = 0x00000003;
+
+/// `struct kgsl_cmdstream_readtimestamp_ctxtid`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslCmdstreamReadtimestampCtxtid {
+    pub context_id: u32,
+    pub type_: u32,
+    pub timestamp: u32,
+}
+
+/// `struct kgsl_device_waittimestamp_ctxtid`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslDeviceWaittimestampCtxtid {
+    pub context_id: u32,
+    pub timestamp: u32,
+    /// Millisekunden
+    pub timeout: u32,
+}
+
+/// `struct kgsl_device_waittimestamp`: globaler Timestamp ohne Context
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslDeviceWaittimestamp {
+    pub timestamp: u32,
+    /// Millisekunden
+    pub timeout: u32,
+}
+
+pub const IOCTL_KGSL_DEVICE_WAITTIMESTAMP: u32 = kgsl_iow(0x06, size_of::<KgslDeviceWaittimestamp>());
+pub const IOCTL_KGSL_DEVICE_WAITTIMESTAMP_CTXTID: u32 =
+    kgsl_iow(0x07, size_of::<KgslDeviceWaittimestampCtxtid>());
+pub const IOCTL_KGSL_CMDSTREAM_READTIMESTAMP_CTXTID: u32 =
+    kgsl_iowr(0x16, size_of::<KgslCmdstreamReadtimestampCtxtid>());
+
+/// Liest einen Timestamp eines Contexts (`KGSL_TIMESTAMP_*`)
+pub fn read_timestamp(fd: i32, context_id: u32, type_: u32) -> io::Result<u32> {
+    let mut req = KgslCmdstreamReadtimestampCtxtid { context_id, type_, timestamp: 0 };
+    sys::ioctl(fd, IOCTL_KGSL_CMDSTREAM_READTIMESTAMP_CTXTID, &mut req)?;
+    Ok(req.timestamp)
+}
+
+/// Wartet bis `timestamp` retired ist, `ETIMEDOUT` nach `timeout`
+pub fn wait_timestamp(fd: i32, context_id: u32, timestamp: u32, timeout: Duration) -> io::Result<()> {
+    let mut req = KgslDeviceWaittimestampCtxtid {
+        context_id,
+        timestamp,
+        timeout: timeout.as_millis().min(u32::MAX as u128) as u32,
+    };
+    sys::ioctl(fd, IOCTL_KGSL_DEVICE_WAITTIMESTAMP_CTXTID, &mut req)?;
+    Ok(())
+}
+
+/// Wartet auf einen globalen Timestamp, `ETIMEDOUT` nach `timeout`
+///
+/// Neuere Kernel lehnen den Aufruf ohne Context mit `ENOTTY` ab.
+pub fn wait_global_timestamp(fd: i32, timestamp: u32, timeout: Duration) -> io::Result<()> {
+    let mut req = KgslDeviceWaittimestamp {
+        timestamp,
+        timeout: timeout.as_millis().min(u32::MAX as u128) as u32,
+    };
+    sys::ioctl(fd, IOCTL_KGSL_DEVICE_WAITTIMESTAMP, &mut req)?;
+    Ok(())
+}
+
+/// Context-ID 0 adressiert die globalen Timestamps (`KGSL_MEMSTORE_GLOBAL`)
+pub const KGSL_MEMSTORE_GLOBAL: u32 = 0;
+
+/// Ergebnis von [`wait_idle`]
+#[derive(Debug, Clone, Copy)]
+pub struct IdleState {
+    /// Globaler `soptimestamp`: zuletzt vom CP begonnen
+    pub consumed: u32,
+    /// Globaler `eoptimestamp`: zuletzt fertig
+    pub retired: u32,
+    pub waited: Duration,
+}
+
+/// Längster Abstand zwischen zwei Blicken in den Memstore
+const IDLE_POLL_MAX: Duration = Duration::from_millis(10);
+
+/// Ob `ts` alles Begonnene auch abgeschlossen hat (mit Überlauf)
+fn drained(ts: ContextTimestamps) -> bool {
+    (ts.retired.wrapping_sub(ts.consumed) as i32) >= 0
+}
+
+/// Wartet, bis die GPU alle Timestamps im globalen Slot und in den
+/// Ringbuffer-Slots retired hat
+///
+/// Zuerst wartet der Kernel per `IOCTL_KGSL_DEVICE_WAITTIMESTAMP` auf den
+/// aktuellen globalen Consumed-Timestamp. Lehnt er das ab (neuere Kernel:
+/// `ENOTTY`), wird der Memstore gepollt. Arbeit, die der Dispatcher noch
+/// nicht in einen Ringbuffer geschrieben hat, erscheint dort erst danach.
+/// `ETIMEDOUT`, falls die GPU nach `timeout` noch arbeitet.
+pub fn wait_idle(dev: &impl AsFd, timeout: Duration) -> io::Result<IdleState> {
+    let memstore = Memstore::map(dev)?;
+    let start = Instant::now();
+    let global = || {
+        memstore
+            .timestamps(KGSL_MEMSTORE_GLOBAL)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "memstore has no global slot"))
+    };
+
+    match wait_global_timestamp(dev.as_fd().as_raw_fd(), global()?.consumed, timeout) {
+        Err(e) if e.raw_os_error() == Some(libc::ETIMEDOUT) => return Err(e),
+        // Erfolg oder nicht unterstützt: die Ringbuffer-Slots prüft die Schleife
+        _ => {}
+    }
+
+    let mut poll = Duration::from_micros(100);
+    loop {
+        let ts = global()?;
+        let rings_drained = (0..RINGBUFFER_SLOTS).filter_map(|rb| memstore.ringbuffer_timestamps(rb)).all(drained);
+        if drained(ts) && rings_drained {
+            return Ok(IdleState { consumed: ts.consumed, retired: ts.retired, waited: start.elapsed() });
+        }
+        let Some(remaining) = timeout.checked_sub(start.elapsed()).filter(|r| !r.is_zero()) else {
+            return Err(io::Error::from_raw_os_error(libc::ETIMEDOUT));
+        };
+        std::thread::sleep(poll.min(remaining));
+        poll = (poll * 2).min(IDLE_POLL_MAX);
+    }
+}
+
+// ============================================================================
+// Power/Bus Constraints
+// ============================================================================
+
+/// Constraint-Typen (`KGSL_CONSTRAINT_*`)
+pub const KGSL_CONSTRAINT_NONE: u32 = 0;
+pub const KGSL_CONSTRAINT_PWRLEVEL: u32 = 1;
+pub const KGSL_CONSTRAINT_L3_NONE: u32 = 2;
+pub const KGSL_CONSTRAINT_L3_PWRLEVEL: u32 = 3;
+
+/// Constraint-Stufen (`KGSL_CONSTRAINT_PWR_*`)
+pub const KGSL_CONSTRAINT_PWR_MIN: u32 = 0;
+pub const KGSL_CONSTRAINT_PWR_MAX: u32 = 1;
+
+/// `struct kgsl_device_constraint`
+#[repr(C)]
+#[derive(Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslDeviceConstraint {
+    pub type_: u32,
+    pub context_id: u32,
+    /// Adresse der Nutzdaten
+    pub data: usize,
+    pub size: usize,
+}
+
+/// `struct kgsl_device_constraint_pwrlevel`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KgslDeviceConstraintPwrlevel {
+    pub level: u32,
+}
+
+/// Worauf sich ein Vote bezieht
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintTarget {
+    /// GPU Power Level
+    GpuPwrLevel,
+    /// L3 Cache Frequenz (nur auf Plattformen mit L3 Voting)
+    L3PwrLevel,
+}
+
+/// Gewünschtes Level innerhalb der Constraint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintLevel {
+    /// Mindestens das niedrigste Level halten
+    Min,
+    /// Das höchste Level anfordern
+    Max,
+}
+
+impl ConstraintTarget {
+    fn property(self) -> u32 {
+        match self {
+            ConstraintTarget::GpuPwrLevel => KGSL_PROP_PWR_CONSTRAINT,
+            ConstraintTarget::L3PwrLevel => KGSL_PROP_L3_PWR_CONSTRAINT,
+        }
+    }
+
+    fn vote_type(self) -> u32 {
+        match self {
+            ConstraintTarget::GpuPwrLevel => KGSL_CONSTRAINT_PWRLEVEL,
+            ConstraintTarget::L3PwrLevel => KGSL_CONSTRAINT_L3_PWRLEVEL,
+        }
+    }
+
+    fn release_type(self) -> u32 {
+        match self {
+            ConstraintTarget::GpuPwrLevel => KGSL_CONSTRAINT_NONE,
+            ConstraintTarget::L3PwrLevel => KGSL_CONSTRAINT_L3_NONE,
+        }
+    }
+}
+
+/// Setzt eine Constraint auf einem Context (SETPROPERTY)
+pub fn set_constraint(fd: i32, context_id: u32, target: ConstraintTarget, level: Option<ConstraintLevel>) -> io::Result<()> {
+    let mut pwrlevel = KgslDeviceConstraintPwrlevel {
+        level: match level {
+            Some(ConstraintLevel::Max) => KGSL_CONSTRAINT_PWR_MAX,
+            _ => KGSL_CONSTRAINT_PWR_MIN,
+        },
+    };
+
+    let mut constraint = KgslDeviceConstraint {
+        type_: if level.is_some() { target.vote_type() } else { target.release_type() },
+        context_id,
+        data: &mut pwrlevel as *mut KgslDeviceConstraintPwrlevel as usize,
+        size: size_of::<KgslDeviceConstraintPwrlevel>(),
+    };
+
+    set_property(fd, target.property(), &mut constraint)
+}
+
+/// Aktiver Power-Vote, wird beim Drop automatisch zurückgenommen
+///
+/// Der Treiber wendet die Constraint bei Submissions des Contexts an; ohne
+/// eigene Submissions wirkt der Vote ab der nächsten Arbeit auf diesem Context.
+pub struct PowerVote<'a> {
+    fd: BorrowedFd<'a>,
+    context_id: u32,
+    owns_context: bool,
+    target: ConstraintTarget,
+    expires: Option<Instant>,
+    timer: Option<VoteTimer>,
+    released: bool,
+}
+
+/// Nimmt den Vote nach Ablauf auf einem dup des fd zurück
+struct VoteTimer {
+    cancel: mpsc::Sender<()>,
+    thread: thread::JoinHandle<()>,
+}
+
+impl<'a> PowerVote<'a> {
+    /// Legt einen eigenen Context an und setzt den Vote darauf
+    pub fn new(dev: &'a impl AsFd, target: ConstraintTarget, level: ConstraintLevel) -> io::Result<Self> {
+        let fd = dev.as_fd();
+        let flags = KGSL_CONTEXT_NO_GMEM_ALLOC | KGSL_CONTEXT_PREAMBLE
+            | KGSL_CONTEXT_PER_CONTEXT_TS | KGSL_CONTEXT_PWR_CONSTRAINT;
+        let context_id = create_context(fd.as_raw_fd(), flags)?;
+
+        if let Err(e) = set_constraint(fd.as_raw_fd(), context_id, target, Some(level)) {
+            let _ = destroy_context(fd.as_raw_fd(), context_id);
+            return Err(e);
+        }
+
+        Ok(PowerVote { fd, context_id, owns_context: true, target, expires: None, timer: None, released: false })
+    }
+
+    /// Setzt den Vote auf einen bestehenden Context des Prozesses
+    pub fn on_context(dev: &'a impl AsFd, context_id: u32, target: ConstraintTarget, level: ConstraintLevel) -> io::Result<Self> {
+        let fd = dev.as_fd();
+        set_constraint(fd.as_raw_fd(), context_id, target, Some(level))?;
+        Ok(PowerVote { fd, context_id, owns_context: false, target, expires: None, timer: None, released: false })
+    }
+
+    /// Begrenzt den Vote auf ein Zeitfenster
+    ///
+    /// Ein Timer-Thread nimmt den Vote bei Ablauf zurück, auch wenn der
+    /// Aufrufer weder [`hold`](Self::hold) noch `release` aufruft. Der
+    /// eigene Context bleibt bis zum Drop bestehen.
+    pub fn with_timeout(mut self, duration: Duration) -> io::Result<Self> {
+        self.stop_timer();
+        let fd = self.fd.try_clone_to_owned()?;
+        let (context_id, target) = (self.context_id, self.target);
+        let (cancel, cancelled) = mpsc::channel::<()>();
+        let thread = thread::Builder::new().name("power-vote".into()).spawn(move || {
+            if let Err(mpsc::RecvTimeoutError::Timeout) = cancelled.recv_timeout(duration) {
+                let _ = set_constraint(fd.as_raw_fd(), context_id, target, None);
+            }
+        })?;
+        self.expires = Some(Instant::now() + duration);
+        self.timer = Some(VoteTimer { cancel, thread });
+        Ok(self)
+    }
+
+    /// Context, an dem der Vote hängt
+    pub fn context_id(&self) -> u32 {
+        self.context_id
+    }
+
+    /// Verbleibende Zeit im Zeitfenster (None = unbegrenzt)
+    pub fn remaining(&self) -> Option<Duration> {
+        self.expires.map(|t| t.saturating_duration_since(Instant::now()))
+    }
+
+    /// Ob das Zeitfenster abgelaufen ist
+    pub fn is_expired(&self) -> bool {
+        self.remaining() == Some(Duration::ZERO)
+    }
+
+    /// Hält den Vote bis zum Ende des Zeitfensters und gibt ihn dann frei
+    pub fn hold(self) -> io::Result<()> {
+        if let Some(rest) = self.remaining() {
+            std::thread::sleep(rest);
+        }
+        self.release()
+    }
+
+    /// Gibt den Vote explizit frei
+    pub fn release(mut self) -> io::Result<()> {
+        self.do_release()
+    }
+
+    fn stop_timer(&mut self) {
+        if let Some(timer) = self.timer.take() {
+            let _ = timer.cancel.send(());
+            let _ = timer.thread.join();
+        }
+    }
+
+    fn do_release(&mut self) -> io::Result<()> {
+        if self.released {
+            return Ok(());
+        }
+        self.released = true;
+        self.stop_timer();
+
+        let fd = self.fd.as_raw_fd();
+        let result = set_constraint(fd, self.context_id, self.target, None);
+        if self.owns_context {
+            let _ = destroy_context(fd, self.context_id);
+        }
+        result
+    }
+}
+
+impl Drop for PowerVote<'_> {
+    fn drop(&mut self) {
+        let _ = self.do_release();
+    }
+}
+
+// ============================================================================
+// GPU Reset Status (GL_EXT_robustness)
+// ============================================================================
+
+pub const KGSL_PROP_GPU_RESET_STAT: u32 = 0x00000009;
+
+/// Reset-Status Werte (`KGSL_CTX_STAT_*`)
+pub const KGSL_CTX_STAT_NO_ERROR: u32 = 0x00000000;
+pub const KGSL_CTX_STAT_GUILTY_CONTEXT_RESET_EXT: u32 = 0x00000001;
+pub const KGSL_CTX_STAT_INNOCENT_CONTEXT_RESET_EXT: u32 = 0x00000002;
+pub const KGSL_CTX_STAT_UNKNOWN_CONTEXT_RESET_EXT: u32 = 0x00000003;
+
+/// Reset-Status eines Contexts, wie `glGetGraphicsResetStatusEXT`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResetStatus {
+    NoError,
+    /// Context hat den Reset verursacht
+    Guilty,
+    /// Context war Opfer eines Resets
+    Innocent,
+    /// Reset ohne bekannte Ursache
+    Unknown,
+    Other(u32),
+}
+
+impl ResetStatus {
+    pub fn from_raw(raw: u32) -> Self {
+        match raw {
+            KGSL_CTX_STAT_NO_ERROR => ResetStatus::NoError,
+            KGSL_CTX_STAT_GUILTY_CONTEXT_RESET_EXT => ResetStatus::Guilty,
+            KGSL_CTX_STAT_INNOCENT_CONTEXT_RESET_EXT => ResetStatus::Innocent,
+            KGSL_CTX_STAT_UNKNOWN_CONTEXT_RESET_EXT => ResetStatus::Unknown,
+            other => ResetStatus::Other(other),
+        }
+    }
+
+    /// Name wie in GL_EXT_robustness
+    pub fn gl_name(self) -> &'static str {
+        match self {
+            ResetStatus::NoError => "GL_NO_ERROR",
+            ResetStatus::Guilty => "GL_GUILTY_CONTEXT_RESET_EXT",
+            ResetStatus::Innocent => "GL_INNOCENT_CONTEXT_RESET_EXT",
+            ResetStatus::Unknown => "GL_UNKNOWN_CONTEXT_RESET_EXT",
+            ResetStatus::Other(_) => "unknown",
+        }
+    }
+}
+
+/// Fragt den Reset-Status eines eigenen Contexts ab
+///
+/// Der Kernel setzt den Status nach der Abfrage auf `NoError` zurück und
+/// beantwortet nur Contexts, die diesem File Descriptor gehören.
+pub fn read_reset_status(fd: i32, context_id: u32) -> io::Result<ResetStatus> {
+    // Der Wert ist Ein- und Ausgabe: rein geht die Context-ID
+    let mut value: u32 = context_id;
+    query_property(fd, Prop::GpuResetStat, &mut value)?;
+    Ok(ResetStatus::from_raw(value))
+}
+
+// ============================================================================
+// IOCTL Probing
+// ============================================================================
+
+/// Ergebnis einer IOCTL-Probe mit genulltem Payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IoctlProbe {
+    /// Kernel kennt die IOCTL (Erfolg oder Fehler wie EINVAL/EFAULT)
+    Implemented(i32),
+    /// ENOTTY - IOCTL existiert in diesem Kernel nicht
+    NotImplemented,
+}
+
+impl IoctlProbe {
+    pub fn is_implemented(self) -> bool {
+        matches!(self, IoctlProbe::Implemented(_))
+    }
+}
+
+/// Ruft eine IOCTL mit genulltem Payload von `size` Bytes auf
+///
+/// Nur für Kommandos verwenden, die mit Nullwerten nichts verändern.
+pub fn probe_ioctl(fd: i32, request: KgslRequest) -> IoctlProbe {
+    match sys::ioctl_zeroed(fd, request) {
+        Ok(()) => IoctlProbe::Implemented(0),
+        Err(e) if e.raw_os_error() == Some(libc::ENOTTY) => IoctlProbe::NotImplemented,
+        Err(e) => IoctlProbe::Implemented(e.raw_os_error().unwrap_or(0)),
+    }
+}
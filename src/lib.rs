@@ -0,0 +1,87 @@
+//! Adreno KGSL Bibliothek - IOCTL-Zugriff auf Qualcomm Adreno GPUs
+//! Basierend auf empirischen Tests
+
+#![deny(unsafe_code)]
+
+pub mod adb;
+pub mod affinity;
+pub mod alert;
+pub mod analyze;
+pub mod appid;
+pub mod backend;
+pub mod baseline;
+pub mod battery;
+pub mod bench;
+pub mod blob;
+pub mod bugreport;
+pub mod bundle;
+pub mod bus;
+pub mod cache;
+pub mod caps;
+pub mod chip;
+pub mod collector;
+pub mod core2d;
+pub mod daemon;
+pub mod debugfs;
+pub mod devicetree;
+pub mod dmesg;
+pub mod driver;
+pub mod events;
+pub mod features;
+pub mod fence;
+pub mod frametime;
+pub mod gmem;
+pub mod history;
+#[cfg(feature = "http")]
+pub mod http;
+pub mod instance;
+pub mod ioctls;
+pub mod irq;
+pub mod json;
+pub mod kgsl;
+pub mod llc;
+pub mod lpac;
+pub mod memory;
+pub mod memstore;
+pub mod messages;
+pub mod monitor;
+pub mod opp;
+pub mod overlay;
+pub mod payload;
+pub mod perfcounter;
+pub mod platform;
+pub mod plugin;
+pub mod pm4;
+pub mod power;
+pub mod privilege;
+pub mod procmem;
+pub mod profile;
+pub mod propmap;
+pub mod queue;
+pub mod report;
+pub mod scan;
+pub mod sched;
+pub mod schema;
+pub mod settings;
+pub mod sink;
+pub mod sparse;
+#[cfg(feature = "async")]
+pub mod stream;
+pub mod submit;
+pub mod summary;
+// Einzige Stelle mit `unsafe`, siehe Moduldoku
+#[allow(unsafe_code)]
+pub mod sys;
+pub mod sysfs;
+pub mod sysroot;
+pub mod template;
+pub mod timeline;
+pub mod timesync;
+pub mod trace;
+pub mod triage;
+pub mod turnip;
+pub mod units;
+pub mod vamap;
+pub mod vkjson;
+pub mod warnings;
+pub mod zap;
@@ -0,0 +1,97 @@
+//! System-Cache (LLCC) der GPU
+//!
+//! Ab A6xx kann die GPU Slices des Last Level Cache nutzen: einen für
+//! Daten (`gpu`, LLCC-ID 12) und einen für Pagetable-Walks (`gpuhtw`, ID 11).
+//! Ob sie aktiv sind, zeigt KGSL in sysfs (`gpu_llc_slice_enable`,
+//! `gpuhtw_llc_slice_enable`); die Zuordnung steht im Device Tree
+//! (`cache-slice-names`/`cache-slices`). Trefferstatistiken gibt es nur über
+//! den herstellerspezifischen `llcc_perfmon` in debugfs.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::devicetree::{read_cells, read_strings};
+use crate::sysfs::read_u64;
+use crate::sysroot;
+
+/// debugfs-Verzeichnis des LLCC-Perfmon (Downstream-Kernel)
+pub const LLCC_PERFMON_DIR: &str = "/sys/kernel/debug/llcc_perfmon";
+
+/// Plattform-Treiber des LLCC (Name je Kernel unterschiedlich)
+const LLCC_DRIVERS: [&str; 2] = ["/sys/bus/platform/drivers/qcom-llcc", "/sys/bus/platform/drivers/qcom_llcc"];
+
+/// Bekannte LLCC-Usecase-IDs (include/linux/soc/qcom/llcc-qcom.h)
+const SLICE_IDS: [(u32, &str); 2] = [(11, "GPUHTW"), (12, "GPU")];
+
+/// Ein GPU-Slice
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LlcSlice {
+    /// `gpu` oder `gpuhtw`
+    pub name: String,
+    /// LLCC-Usecase-ID aus dem Device Tree
+    pub usecase_id: Option<u32>,
+    /// Laut KGSL-sysfs aktiviert
+    pub enabled: Option<bool>,
+}
+
+impl LlcSlice {
+    pub fn usecase_name(&self) -> Option<&'static str> {
+        let id = self.usecase_id?;
+        SLICE_IDS.iter().find(|(i, _)| *i == id).map(|(_, name)| *name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LlcInfo {
+    pub slices: Vec<LlcSlice>,
+    /// LLCC-Treiber gebunden
+    pub driver: bool,
+    /// Pfad des Perfmon, falls vorhanden
+    pub perfmon: Option<PathBuf>,
+}
+
+impl LlcInfo {
+    /// Ob die GPU den System-Cache nutzt
+    pub fn in_use(&self) -> bool {
+        self.slices.iter().any(|s| s.enabled == Some(true))
+    }
+}
+
+/// Slices aus `cache-slice-names` und `cache-slices = <&llcc ID>, ...`
+pub fn read_dt_slices(gpu_node: &Path) -> Vec<(String, Option<u32>)> {
+    let names = read_strings(&gpu_node.join("cache-slice-names"));
+    let cells = read_cells(&gpu_node.join("cache-slices")).unwrap_or_default();
+    // Paare aus Phandle und ID
+    names.into_iter().enumerate().map(|(i, name)| (name, cells.get(i * 2 + 1).copied())).collect()
+}
+
+/// Sammelt alles Verfügbare; `gpu_node` ist optional (kein Device Tree)
+pub fn read_llc_info(sysfs_dir: &Path, gpu_node: Option<&Path>) -> LlcInfo {
+    let mut slices: Vec<LlcSlice> = gpu_node
+        .map(read_dt_slices)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, usecase_id)| LlcSlice { name, usecase_id, enabled: None })
+        .collect();
+    for name in ["gpu", "gpuhtw"] {
+        let enabled = read_u64(sysfs_dir.join(format!("{}_llc_slice_enable", name))).ok().map(|v| v != 0);
+        match slices.iter_mut().find(|s| s.name == name) {
+            Some(slice) => slice.enabled = enabled,
+            None if enabled.is_some() => slices.push(LlcSlice { name: name.to_string(), usecase_id: None, enabled }),
+            None => {}
+        }
+    }
+    let perfmon = sysroot::resolve(LLCC_PERFMON_DIR);
+    LlcInfo {
+        slices,
+        driver: LLCC_DRIVERS.iter().any(|d| fs::read_dir(sysroot::resolve(d)).is_ok_and(|mut e| e.any(|e| e.is_ok_and(|e| e.path().is_symlink())))),
+        perfmon: perfmon.is_dir().then_some(perfmon),
+    }
+}
+
+/// Rohausgabe von `perfmon_counter_dump`; leer, solange nichts konfiguriert ist
+pub fn read_perfmon_dump(perfmon: &Path) -> Option<String> {
+    let text = fs::read_to_string(perfmon.join("perfmon_counter_dump")).ok()?;
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
@@ -0,0 +1,56 @@
+//! LPAC (Low Priority Async Compute) ab A7xx
+//!
+//! Upstream-KGSL hängt die LPAC-Queue an `kgsl-3d0`: Kontexte mit
+//! `KGSL_CONTEXT_LPAC` landen auf dem zweiten Ringbuffer. Einige
+//! Herstellerkernel legen zusätzlich einen eigenen Knoten `kgsl-lpac*` an.
+//! Beides wird hier erkannt.
+
+use std::io;
+
+use crate::features::read_bool_property;
+use crate::kgsl::{create_context, destroy_context, find_kgsl_devices, DeviceKind, KGSL_CONTEXT_NO_GMEM_ALLOC};
+use crate::propmap::Prop;
+
+/// Kontext auf der LPAC-Queue anlegen
+pub const KGSL_CONTEXT_LPAC: u32 = 0x20000000;
+
+/// Was über LPAC bekannt ist
+#[derive(Debug)]
+pub struct LpacInfo {
+    /// Eigene LPAC-Knoten unter /dev
+    pub nodes: Vec<String>,
+    /// `KGSL_PROP_IS_LPAC_ENABLED`, `None` wenn der Kernel die Property nicht kennt
+    pub enabled: Option<bool>,
+    /// Ergebnis eines Test-Kontexts mit `KGSL_CONTEXT_LPAC`
+    pub context: io::Result<()>,
+}
+
+impl LpacInfo {
+    /// LPAC ist nutzbar, wenn ein Kontext darauf angelegt werden konnte
+    pub fn usable(&self) -> bool {
+        self.context.is_ok()
+    }
+}
+
+/// Eigene LPAC-Knoten (bei Upstream-Kerneln leer)
+pub fn find_lpac_nodes() -> Vec<String> {
+    find_kgsl_devices()
+        .into_iter()
+        .filter(|path| DeviceKind::from_path(path) == DeviceKind::Lpac)
+        .collect()
+}
+
+/// Legt einen LPAC-Kontext an und gibt ihn sofort wieder frei
+pub fn try_lpac_context(fd: i32) -> io::Result<()> {
+    let id = create_context(fd, KGSL_CONTEXT_LPAC | KGSL_CONTEXT_NO_GMEM_ALLOC)?;
+    destroy_context(fd, id)
+}
+
+/// Fragt LPAC über `fd` ab (3D-Knoten oder eigener LPAC-Knoten)
+pub fn probe(fd: i32) -> LpacInfo {
+    LpacInfo {
+        nodes: find_lpac_nodes(),
+        enabled: read_bool_property(fd, Prop::IsLpacEnabled),
+        context: try_lpac_context(fd),
+    }
+}
@@ -1,362 +1,479 @@
 //! Adreno GPU Info - Basierend auf empirischen Tests
 //! Getestet und funktioniert auf Adreno 610
 
-use std::fs::File;
+#![deny(unsafe_code)]
+
+mod cli;
+
 use std::os::unix::io::AsRawFd;
-use std::mem::size_of;
+use std::path::PathBuf;
+
+use adreno_ioctl::affinity::Placement;
+use adreno_ioctl::backend::{self, Capture, Replay};
+use adreno_ioctl::bus::read_ddr_type;
+use adreno_ioctl::chip::decode_chip_id;
+use adreno_ioctl::core2d::{self, Core2dInfo};
+use adreno_ioctl::driver::read_driver_info;
+use adreno_ioctl::features::{detect_features, FeatureState, HardwareFeature};
+use adreno_ioctl::zap::{self, ZapStatus};
+use adreno_ioctl::json::Json;
+use adreno_ioctl::messages::{self, Lang, Msg};
+use adreno_ioctl::propmap;
+use adreno_ioctl::report::{gpu_info_json, render_gpu_info, render_warnings, InfoExtras};
+use adreno_ioctl::schema::{schema_document, versioned};
+use adreno_ioctl::sysfs;
+use adreno_ioctl::sysroot;
+use adreno_ioctl::warnings::{Warning, WarningCause, Warnings};
+use adreno_ioctl::kgsl::{
+    IOCTL_KGSL_DEVICE_GETPROPERTY, DeviceKind, KgslDeviceInfo, find_kgsl_devices, read_devinfo, read_gpu_info,
+    read_gpu_model, read_gpu_version, read_vk_device_id, try_read_gpu_frequency,
+};
+
+use cli::Args;
 
 // ============================================================================
-// IOCTL Definitionen - Basierend auf deinen Tests
+// Ausgabe-Funktionen
 // ============================================================================
 
-/// IOCTL Request Struktur
-#[repr(C)]
-struct KgslDeviceGetProperty {
-    type_: u32,
-    value: *mut std::ffi::c_void,
-    sizebytes: u32,
-    _pad: [u32; 2],
+fn print_gpu_info(info: &KgslDeviceInfo, extras: &InfoExtras) {
+    print!("{}", render_gpu_info(info, extras));
 }
 
-/// GPU Info Struktur (16 Bytes)
-#[repr(C)]
-#[derive(Debug, Clone, Copy)]
-struct KgslDeviceInfo {
-    pub device_id: u32,      // Offset 0
-    pub chip_id: u32,        // Offset 4
-    pub mmu_enabled: u32,    // Offset 8
-    pub gmem_gpubaseaddr: u32, // Offset 12
+fn print_hardware_features(features: &[HardwareFeature]) {
+    println!("🧩 {}", Msg::InfoFeatures);
+    for feature in features {
+        let state = match feature.state {
+            FeatureState::Enabled => format!("✅ {}", Msg::Enabled),
+            FeatureState::Disabled => format!("❌ {}", Msg::Disabled),
+            FeatureState::NotReported => format!("➖ {}", Msg::FeatureNotReported),
+            FeatureState::Inferred(true) => format!("🔎 {}", Msg::FeatureLikely),
+            FeatureState::Inferred(false) => format!("🔎 {}", Msg::FeatureUnlikely),
+        };
+        println!("   • {:<36} {:<16} ({})", feature.name, state, feature.source);
+    }
 }
 
-/// Version Info Struktur (8 Bytes)
-#[repr(C)]
-#[derive(Debug, Clone, Copy)]
-struct KgslVersionInfo {
-    pub driver_version: u32,
-    pub device_version: u32,
+fn print_zap_status(status: &ZapStatus) {
+    println!("🔐 {}", Msg::ZapHeader);
+    match &status.configured {
+        Some(name) => println!("   • {}", Msg::ZapConfigured { name }),
+        None if status.dt_node => println!("   • {}", Msg::ZapNodeOnly),
+        None => println!("   • {}", Msg::ZapNotConfigured),
+    }
+    if status.configured.is_some() {
+        if status.firmware_files.is_empty() {
+            println!("   • {}", Msg::ZapFirmwareMissing);
+        }
+        for file in &status.firmware_files {
+            println!("   • {}", Msg::ZapFirmware { path: &file.display() });
+        }
+    }
+    match status.secure_ctxt_support {
+        Some(true) => println!("   • {}", Msg::ZapSecureSupported),
+        Some(false) => println!("   • {}", Msg::ZapSecureUnsupported),
+        None => println!("   • {}", Msg::ZapSecureUnknown),
+    }
+    if let Some(align) = status.secure_buffer_alignment {
+        println!("   • {}", Msg::ZapAlignment { bytes: &align });
+    }
+    for line in &status.log_lines {
+        println!("   • dmesg: {}", line.trim());
+    }
+    println!("   → {}", status.diagnosis());
 }
 
-/// Property Types (aus msm_kgsl.h)
-const KGSL_PROP_DEVICE_INFO: u32 = 0x00000001;
-const KGSL_PROP_VERSION: u32 = 0x00000008;
-
 // ============================================================================
-// Chip ID Decoding
+// Hauptprogramm
 // ============================================================================
 
-#[derive(Debug, Clone)]
-struct ChipInfo {
-    pub raw_id: u32,
-    pub major: u8,
-    pub minor: u8,
-    pub patch: u8,
-    pub revision: u8,
-    pub model_name: String,
-    pub adreno_generation: String,
-    pub snapdragon_model: Option<String>,
-}
-
-fn decode_chip_id(chip_id: u32) -> ChipInfo {
-    let major = ((chip_id >> 24) & 0xFF) as u8;
-    let minor = ((chip_id >> 16) & 0xFF) as u8;
-    let patch = ((chip_id >> 8) & 0xFF) as u8;
-    let revision = (chip_id & 0xFF) as u8;
-
-    // Bestimme Adreno Generation
-    let adreno_gen = match major {
-        1 => "100",
-        2 => "200",
-        3 => "300",
-        4 => "400",
-        5 => "500",
-        6 => "600",
-        7 => "700",
-        8 => "800",
-        9 => "900",
-        _ => "Unknown",
-    };
-
-    // Spezifisches Modell
-    let model_name = match (major, minor) {
-        (6, 0) => "Adreno 600",
-        (6, 1) => "Adreno 610",
-        (6, 2) => "Adreno 620",
-        (6, 3) => "Adreno 630",
-        (6, 4) => "Adreno 640",
-        (6, 5) => "Adreno 650",
-        (6, 6) => "Adreno 660",
-        (6, 8) => "Adreno 680",
-        (6, 9) => "Adreno 690",
-        (7, 0) => "Adreno 700",
-        (7, 1) => "Adreno 710",
-        (7, 2) => "Adreno 720",
-        (7, 3) => "Adreno 730",
-        (7, 4) => "Adreno 740",
-        (7, 5) => "Adreno 750",
-        _ => "Adreno GPU",
-    };
-
-    // Typische Snapdragon Zuordnung
-    let snapdragon_model = match (major, minor) {
-        (6, 1) => Some("Snapdragon 665/680/685/690/6 Gen 1"),
-        (6, 2) => Some("Snapdragon 730/732G"),
-        (6, 3) => Some("Snapdragon 835/845"),
-        (6, 4) => Some("Snapdragon 855"),
-        (6, 5) => Some("Snapdragon 865/870"),
-        (6, 6) => Some("Snapdragon 888"),
-        (6, 8) => Some("Snapdragon 8 Gen 1"),
-        (6, 9) => Some("Snapdragon 7+ Gen 2"),
-        (7, 2) => Some("Snapdragon 7 Gen 1"),
-        (7, 3) => Some("Snapdragon 8+ Gen 1"),
-        (7, 5) => Some("Snapdragon 8 Gen 2"),
-        _ => None,
+fn main() {
+    let mut argv: Vec<String> = std::env::args().skip(1).collect();
+    if let Err(e) = select_language(&mut argv) {
+        eprintln!("❌ {}", e);
+        std::process::exit(cli::EXIT_FAILURE);
+    }
+    if let Err(e) = select_sysroot(&mut argv) {
+        eprintln!("❌ {}", e);
+        std::process::exit(cli::EXIT_FAILURE);
+    }
+    let (farm, farm_binary) = match (take_values(&mut argv, "--adb"), take_values(&mut argv, "--adb-binary")) {
+        (Ok(serials), Ok(mut binary)) => (serials, binary.pop()),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(cli::EXIT_FAILURE);
+        }
     };
-
-    ChipInfo {
-        raw_id: chip_id,
-        major,
-        minor,
-        patch,
-        revision,
-        model_name: model_name.to_string(),
-        adreno_generation: adreno_gen.to_string(),
-        snapdragon_model: snapdragon_model.map(|s| s.to_string()),
+    let quiet = take_flag(&mut argv, &["--quiet", "-q"]);
+    let plain = take_flag(&mut argv, &["--plain"]);
+    if take_flag(&mut argv, &["--read-only"]) {
+        cli::enable_read_only();
     }
-}
-
-// ============================================================================
-// Einfache, funktionierende Funktionen
-// ============================================================================
-
-/// Liest GPU Info mit der bewährten Methode
-fn read_gpu_info(fd: i32) -> Result<KgslDeviceInfo, String> {
-    let mut device_info = KgslDeviceInfo {
-        device_id: 0,
-        chip_id: 0,
-        mmu_enabled: 0,
-        gmem_gpubaseaddr: 0,
+    let (cpus, nice) = match (take_values(&mut argv, "--cpus"), take_values(&mut argv, "--nice")) {
+        (Ok(mut cpus), Ok(mut nice)) => (cpus.pop(), nice.pop()),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(cli::EXIT_FAILURE);
+        }
     };
-
-    let mut prop = KgslDeviceGetProperty {
-        type_: KGSL_PROP_DEVICE_INFO,
-        value: &mut device_info as *mut _ as *mut std::ffi::c_void,
-        sizebytes: size_of::<KgslDeviceInfo>() as u32,
-        _pad: [0; 2],
+    if quiet {
+        cli::enable_quiet();
+    } else if cli::plain::wanted(plain) {
+        cli::plain::enable();
+    }
+    let command = if argv.first().is_some_and(|a| !a.starts_with("--") || a == "--help" || a == "--man" || a == "--schema") {
+        argv.remove(0)
+    } else {
+        "info".to_string()
     };
-
-    // DIE FUNKTIONIERENDE IOCTL-NUMMER
-    let ioctl_num: u32 = 0xc0140902;
-
-    unsafe {
-        let result = libc::ioctl(fd, ioctl_num as i32, &mut prop);
-        if result < 0 {
-            return Err(format!("IOCTL failed: {}", std::io::Error::last_os_error()));
+    if !farm.is_empty() {
+        // Auf den Zielgeräten anwenden, nicht auf dem Host
+        for (option, value) in [("--cpus", cpus), ("--nice", nice)] {
+            if let Some(value) = value {
+                argv.extend([option.to_string(), value]);
+            }
         }
+        if let Err(e) = cli::farm::run(farm, farm_binary, command, argv) {
+            eprintln!("❌ {}", e);
+            cli::set_exit_code(cli::EXIT_FAILURE);
+        }
+        cli::plain::finish();
+        std::process::exit(cli::exit_code());
     }
-
-    // Validiere die Daten
-    if device_info.chip_id == 0 && device_info.device_id == 0 {
-        return Err("Keine gültigen GPU-Daten empfangen".to_string());
+    if let Err(e) = Placement::parse(cpus.as_deref(), nice.as_deref()).and_then(cli::place_process) {
+        eprintln!("❌ {}", e);
+        cli::set_exit_code(cli::EXIT_FAILURE);
+        cli::plain::finish();
+        std::process::exit(cli::exit_code());
     }
+    let args = Args::new(argv);
+
+    let result = match command.as_str() {
+        "info" => run_info(args),
+        "allocflags" => cli::allocflags::run(args),
+        "analyze" => cli::analyze::run(args),
+        "bench" => cli::bench::run(args),
+        "blob" => cli::blob::run(args),
+        "boost" => cli::boost::run(args),
+        "bugreport" => cli::bugreport::run(args),
+        "bus" => cli::bus::run(args),
+        "caps" => cli::caps::run(args),
+        "collect" => cli::collect::run(args),
+        "completions" => cli::completions::run(args),
+        "contexts" => cli::contexts::run(args),
+        "cores" => cli::cores::run(args),
+        "daemon" => cli::daemon::run(args),
+        "debugfs" => cli::debugfs::run(args),
+        "driver" => cli::driver::run(args),
+        "dt" => cli::dt::run(args),
+        "events" => cli::events::run(args),
+        "explain" => cli::explain::run(args),
+        "export" => cli::export::run(args),
+        "fence" => cli::fence::run(args),
+        "frametime" => cli::frametime::run(args),
+        "get" => cli::get::run(args),
+        "gmem" => cli::gmem::run(args),
+        "health" => cli::health::run(args),
+        "llc" => cli::llc::run(args),
+        "lpac" => cli::lpac::run(args),
+        "monitor" => cli::monitor::run(args),
+        "overlay" => cli::overlay::run(args),
+        "procmem" => cli::procmem::run(args),
+        "profile" => cli::profile::run(args),
+        "reset-stat" => cli::reset_stat::run(args),
+        "restore" => cli::restore::run(args),
+        "scan" => cli::scan::run(args),
+        "sched" => cli::sched::run(args),
+        "selftest" => cli::selftest::run(args),
+        "slumber" => cli::slumber::run(args),
+        "sparse" => cli::sparse::run(args),
+        "stress" => cli::stress::run(args),
+        "submit" => cli::submit::run(args),
+        "timesync" => cli::timesync::run(args),
+        "trace" => cli::trace::run(args),
+        "triage" => cli::triage::run(args),
+        "turnip" => cli::turnip::run(args),
+        "usermem" => cli::usermem::run(args),
+        "vamap" => cli::vamap::run(args),
+        "wait-idle" => cli::wait_idle::run(args),
+        "help" | "--help" | "-h" => {
+            cli::print_help();
+            Ok(())
+        }
+        "--man" => {
+            cli::completions::print_man();
+            Ok(())
+        }
+        "--schema" => {
+            println!("{}", schema_document().to_pretty());
+            Ok(())
+        }
+        other => Err(Msg::UnknownCommand { command: other }.to_string()),
+    };
 
-    Ok(device_info)
+    if let Err(e) = result {
+        eprintln!("❌ {}", e);
+        cli::set_exit_code(cli::EXIT_FAILURE);
+    }
+    cli::plain::finish();
+    std::process::exit(cli::exit_code());
 }
 
-/// Liest die Treiberversion - KORRIGIERTE VERSION
-fn read_gpu_version(fd: i32) -> Result<KgslVersionInfo, String> {
-    let mut version_info = KgslVersionInfo {
-        driver_version: 0,
-        device_version: 0,
+/// `--lang de|en`, sonst `ADRENO_IOCTL_LANG`; Standard ist Englisch
+fn select_language(argv: &mut Vec<String>) -> Result<(), String> {
+    let requested = match argv.iter().position(|a| a == "--lang") {
+        Some(i) if i + 1 < argv.len() => {
+            argv.remove(i);
+            Some(argv.remove(i))
+        }
+        Some(_) => return Err(Msg::RequiresValue { option: "--lang" }.to_string()),
+        None => std::env::var(messages::LANG_ENV).ok(),
     };
+    if let Some(text) = requested {
+        let lang = Lang::parse(&text).ok_or_else(|| Msg::InvalidValue { option: "--lang", value: &text }.to_string())?;
+        messages::set_language(lang);
+    }
+    Ok(())
+}
 
-    let mut prop = KgslDeviceGetProperty {
-        type_: KGSL_PROP_VERSION,
-        value: &mut version_info as *mut _ as *mut std::ffi::c_void,
-        sizebytes: size_of::<KgslVersionInfo>() as u32,
-        _pad: [0; 2],
+/// `--sysroot DIR` leitet `/dev`, `/sys`, `/proc` und die Android-Partitionen in einen gesammelten Baum um
+fn select_sysroot(argv: &mut Vec<String>) -> Result<(), String> {
+    let Some(i) = argv.iter().position(|a| a == "--sysroot") else {
+        return Ok(());
     };
+    if i + 1 >= argv.len() {
+        return Err(Msg::RequiresValue { option: "--sysroot" }.to_string());
+    }
+    argv.remove(i);
+    let root = std::path::PathBuf::from(argv.remove(i));
+    if !root.is_dir() {
+        return Err(Msg::SysrootNotDir { path: &root.display() }.to_string());
+    }
+    sysroot::set(Some(root));
+    Ok(())
+}
 
-    // WICHTIG: Für Version brauchen wir möglicherweise eine andere IOCTL-Nummer!
-    // Versuche verschiedene Kombinationen
-    let possible_ioctls: [u32; 3] = [
-        0xc0080902,  // 8 Bytes (wahrscheinlich richtig)
-        0xc0140902,  // 20 Bytes (wie für device info)
-        0xc00c0902,  // 12 Bytes
-    ];
-
-    for &ioctl_num in &possible_ioctls {
-        unsafe {
-            let result = libc::ioctl(fd, ioctl_num as i32, &mut prop);
-            if result == 0 && (version_info.driver_version != 0 || version_info.device_version != 0) {
-                return Ok(version_info);
-            }
+/// Entfernt eine globale Option mit Wert, beliebig oft
+fn take_values(argv: &mut Vec<String>, name: &'static str) -> Result<Vec<String>, String> {
+    let mut values = Vec::new();
+    while let Some(i) = argv.iter().position(|a| a == name) {
+        if i + 1 >= argv.len() {
+            return Err(Msg::RequiresValue { option: name }.to_string());
         }
+        argv.remove(i);
+        values.push(argv.remove(i));
     }
-
-    Err("Version property nicht verfügbar oder benötigt andere IOCTL".to_string())
+    Ok(values)
 }
 
-/// Findet KGSL-Geräte
-fn find_kgsl_devices() -> Vec<String> {
-    let possible_paths = [
-        "/dev/kgsl-3d0",
-        "/dev/kgsl/kgsl-3d0",
-        "/dev/kgsl-3d1",
-        "/dev/kgsl-2d0",
-        "/dev/kgsl-2d1",
-    ];
-
-    possible_paths.iter()
-        .filter(|path| std::path::Path::new(path).exists())
-        .map(|&s| s.to_string())
-        .collect()
+/// Entfernt eine globale Option an beliebiger Stelle
+fn take_flag(argv: &mut Vec<String>, names: &[&str]) -> bool {
+    match argv.iter().position(|a| names.contains(&a.as_str())) {
+        Some(i) => {
+            argv.remove(i);
+            true
+        }
+        None => false,
+    }
 }
 
-// ============================================================================
-// Performance/Clock Info (optional, falls verfügbar)
-// ============================================================================
+fn run_info(mut args: Args) -> Result<(), String> {
+    let device_arg = args.value("--device")?;
+    let record = args.value("--record")?.map(PathBuf::from);
+    let replay = args.value("--replay")?.map(PathBuf::from);
+    let json = args.flag("--json");
+    args.finish()?;
 
-/// Versucht, GPU Frequenz-Informationen zu lesen
-fn try_read_gpu_frequency(fd: i32) -> Option<u32> {
-    // Property für GPU Frequency (kann variieren)
-    const KGSL_PROP_PWRCTRL: u32 = 0x0000000E;
+    if !json {
+        println!("🔍 Adreno GPU Info Tool v1.0");
+        println!("   {}\n", Msg::InfoBanner);
+    }
 
-    let mut freq_value: u32 = 0;
+    // Bei Replay kommen alle Properties aus der Aufnahme, kein Gerät nötig
+    if let Some(path) = &replay {
+        backend::install(Box::new(Replay::new(Capture::load(path)?)));
+        if !json {
+            println!("📼 {}\n", Msg::InfoReplaying { path: &path.display() });
+        }
+        return print_info(-1, None, json);
+    }
 
-    let mut prop = KgslDeviceGetProperty {
-        type_: KGSL_PROP_PWRCTRL,
-        value: &mut freq_value as *mut _ as *mut std::ffi::c_void,
-        sizebytes: size_of::<u32>() as u32,
-        _pad: [0; 2],
+    // Gerät finden
+    let devices = match device_arg {
+        Some(path) => vec![path],
+        None => find_kgsl_devices(),
     };
+    if devices.is_empty() {
+        eprintln!("❌ {}", cli::no_devices_message());
+        cli::set_exit_code(cli::EXIT_NO_DEVICE);
+        return Ok(());
+    }
 
-    // Versuche verschiedene IOCTLs
-    let possible_ioctls: [u32; 3] = [0xc0040902, 0xc0080902, 0xc0140902];
-
-    for &ioctl_num in &possible_ioctls {
-        unsafe {
-            if libc::ioctl(fd, ioctl_num as i32, &mut prop) == 0 && freq_value != 0 {
-                return Some(freq_value);
-            }
+    if !json {
+        println!("✅ {}", Msg::InfoFound { count: &devices.len() });
+        for device in &devices {
+            println!("   • {} ({})", device, DeviceKind::from_path(device).label());
         }
+        println!();
     }
 
-    None
-}
-
-// ============================================================================
-// Ausgabe-Funktionen
-// ============================================================================
-
-fn print_gpu_info(info: &KgslDeviceInfo, version_info: Option<&KgslVersionInfo>, freq: Option<u32>) {
-    let chip_info = decode_chip_id(info.chip_id);
-
-    println!("╔══════════════════════════════════════════════════════╗");
-    println!("║                 ADRENO GPU INFORMATION               ║");
-    println!("╠══════════════════════════════════════════════════════╣");
-    println!("║  📱 Device: {}", chip_info.model_name);
+    // Erstes Gerät öffnen
+    let device_path = &devices[0];
+    let file = match std::fs::File::open(sysroot::resolve(device_path)) {
+        Ok(f) => f,
+        Err(e) => {
+            eprintln!("❌ {}", Msg::CannotOpen { path: device_path, error: &e });
+            eprintln!("   {}", Msg::TryRoot);
+            cli::set_exit_code(cli::io_exit_code(&e));
+            return Ok(());
+        }
+    };
 
-    if let Some(snapdragon) = &chip_info.snapdragon_model {
-        println!("║     Typically found in: {}", snapdragon);
+    if record.is_some() {
+        backend::start_recording(Capture::new().comment(format!("device {}", device_path)));
     }
-
-    println!("║  🏷️  Chip ID: 0x{:08x} (v{}.{}.{}.{})",
-        chip_info.raw_id,
-        chip_info.major,
-        chip_info.minor,
-        chip_info.patch,
-        chip_info.revision
-    );
-    println!("║  🔢 Device ID: 0x{:08x}", info.device_id);
-    println!("║  🛡️  MMU: {}", if info.mmu_enabled != 0 { "✅ Enabled" } else { "❌ Disabled" });
-    println!("║  💾 GMEM Base: 0x{:08x}", info.gmem_gpubaseaddr);
-    println!("║  🎯 Generation: Adreno {}", chip_info.adreno_generation);
-
-    if let Some(freq_mhz) = freq {
-        println!("║  ⚡ Frequency: {} MHz", freq_mhz / 1000000);
+    if core2d::is_2d_device(device_path) {
+        print_2d_info(file.as_raw_fd(), json);
+    } else {
+        print_info(file.as_raw_fd(), Some(device_path), json)?;
     }
-
-    if let Some(ver) = version_info {
-        println!("║  📊 Driver: 0x{:08x} | Device: 0x{:08x}",
-            ver.driver_version, ver.device_version);
+    if let (Some(path), Some(capture)) = (record, backend::stop_recording()) {
+        capture.save(&path).map_err(|e| Msg::CannotWrite { path: &path.display(), error: &e }.to_string())?;
+        if !json {
+            println!("\n💾 {}", Msg::InfoSaved { count: &capture.entries.len(), path: &path.display() });
+        }
     }
+    Ok(())
+}
 
-    println!("║  📏 Structure: {} bytes", size_of::<KgslDeviceInfo>());
+fn read_extras(fd: i32, device_path: Option<&str>) -> InfoExtras {
+    let mut warnings = Warnings::new();
 
-    // Raw bytes für Entwickler
-    println!("╠══════════════════════════════════════════════════════╣");
-    print!("║  Raw Bytes: ");
-    let bytes = unsafe {
-        std::slice::from_raw_parts(
-            info as *const _ as *const u8,
-            size_of::<KgslDeviceInfo>()
-        )
-    };
-    for (i, byte) in bytes.iter().enumerate() {
-        if i > 0 && i % 4 == 0 { print!(" "); }
-        print!("{:02x}", byte);
+    let version = read_gpu_version(fd)
+        .map_err(|e| warnings.push(Warning::new("version", WarningCause::Kernel, e)))
+        .ok();
+
+    // PWRCTRL liefert selten eine Frequenz, daher sysfs als Rückfall
+    let freq_hz = try_read_gpu_frequency(fd).or_else(|| match device_path {
+        Some(path) => warnings.check("frequency", sysfs::gpuclk(&sysfs::device_dir(path))).map(|hz| hz as u32),
+        None => {
+            warnings.push(Warning::new("frequency", WarningCause::Other, "not recorded in the capture"));
+            None
+        }
+    });
+
+    let model = read_gpu_model(fd);
+    if model.is_none() {
+        warnings.push(Warning::new("model", WarningCause::Kernel, "KGSL_PROP_GPU_MODEL not supported"));
     }
-    println!();
 
-    println!("╚══════════════════════════════════════════════════════╝");
-}
+    #[allow(clippy::unnecessary_cast)]
+    let gmem_bytes = warnings
+        .check("gmem-size", read_devinfo(fd))
+        .map(|d| d.gmem_sizebytes as u64)
+        .filter(|&size| size > 0);
 
-// ============================================================================
-// Hauptprogramm
-// ============================================================================
+    // Beides fehlt auf älteren Kerneln bzw. Boards ohne Bootloader-Eintrag, keine Warnung
+    let vk_device_id = read_vk_device_id(fd);
+    let ddr_type = if device_path.is_some() { read_ddr_type() } else { None };
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    println!("🔍 Adreno GPU Info Tool v1.0");
-    println!("   Based on empirical IOCTL testing\n");
+    InfoExtras { version, freq_hz, model, gmem_bytes, vk_device_id, ddr_type, warnings }
+}
 
-    // Gerät finden
-    let devices = find_kgsl_devices();
-    if devices.is_empty() {
-        eprintln!("❌ No KGSL devices found!");
-        return Ok(());
-    }
+fn print_warnings(warnings: &Warnings) {
+    print!("{}", render_warnings(warnings));
+}
 
-    println!("✅ Found {} device(s):", devices.len());
-    for device in &devices {
-        println!("   • {}", device);
-    }
-    println!();
+fn core2d_json(info: &Core2dInfo, warnings: &Warnings) -> Json {
+    Json::object()
+        .field("core", "2d")
+        .field("model", info.name())
+        .field("device_id", info.device_id)
+        .field("core_index", info.core_index)
+        .field("mmu_enabled", info.mmu_enabled)
+        .field("interrupt_waits", info.interrupt_waits)
+        .field("warnings", warnings.to_json())
+}
 
-    // Erstes Gerät öffnen
-    let device_path = &devices[0];
-    let file = match File::open(device_path) {
-        Ok(f) => f,
+/// 2D-Kerne kennen nur DEVICE_INFO, MMU_ENABLE und INTERRUPT_WAITS
+fn print_2d_info(fd: i32, json: bool) {
+    let mut warnings = Warnings::new();
+    let info = match core2d::read_2d_info(fd, &mut warnings) {
+        Ok(info) => info,
         Err(e) => {
-            eprintln!("❌ Cannot open {}: {}", device_path, e);
-            eprintln!("   Try with root: sudo ./adreno_ioctl");
-            return Ok(());
+            cli::set_exit_code(cli::EXIT_UNSUPPORTED);
+            eprintln!("❌ {}", Msg::IoctlFailed { error: &e });
+            return;
         }
     };
+    if info.chip_id != 0 {
+        warnings.push(Warning::new(
+            "chip-id",
+            WarningCause::Other,
+            format!("unexpected chip id 0x{:08x} on a 2D node", info.chip_id),
+        ));
+    }
+    if !warnings.is_empty() {
+        cli::set_exit_code(cli::EXIT_PARTIAL);
+    }
+    if json {
+        println!("{}", versioned(core2d_json(&info, &warnings)).to_pretty());
+        return;
+    }
+    if cli::quiet() {
+        cli::print_value(info.name());
+    }
 
-    let fd = file.as_raw_fd();
+    println!("╔══════════════════════════════════════════════════════╗");
+    println!("║{}║", Msg::Core2dTitle);
+    println!("╠══════════════════════════════════════════════════════╣");
+    println!("║  📱 {}", Msg::Core2dDevice { name: &info.name() });
+    println!("║     {}", Msg::Core2dTypical);
+    println!("║  🔢 {}", Msg::Core2dDeviceId { id: info.device_id, core: &info.core_index });
+    let mmu = if info.mmu_enabled { format!("✅ {}", Msg::Enabled) } else { format!("❌ {}", Msg::Disabled) };
+    println!("║  🛡️  {}", Msg::Core2dMmu { state: &mmu });
+    match info.interrupt_waits {
+        Some(true) => println!("║  ⏱️  {}", Msg::Core2dWaitsSupported),
+        Some(false) => println!("║  ⏱️  {}", Msg::Core2dWaitsUnsupported),
+        None => println!("║  ⏱️  {}", Msg::Core2dWaitsUnknown),
+    }
+    println!("║  ℹ️  {}", Msg::Core2dNoChipId);
+    println!("╚══════════════════════════════════════════════════════╝");
+    if !warnings.is_empty() {
+        println!();
+        print_warnings(&warnings);
+    }
+}
 
+/// Liest und druckt alle Informationen (bei Replay ist `fd` -1)
+fn print_info(fd: i32, device_path: Option<&str>, json: bool) -> Result<(), String> {
     // GPU Info lesen
     match read_gpu_info(fd) {
         Ok(info) => {
-            // Version-Info (optional)
-            let version_info = read_gpu_version(fd).ok();
-
-            // Frequency-Info (optional)
-            let freq_info = try_read_gpu_frequency(fd);
+            let extras = read_extras(fd, device_path);
+            if !extras.warnings.is_empty() {
+                cli::set_exit_code(cli::EXIT_PARTIAL);
+            }
+            if json {
+                println!("{}", versioned(gpu_info_json(&info, &extras)).to_pretty());
+                return Ok(());
+            }
 
             // Alles ausgeben
-            print_gpu_info(&info, version_info.as_ref(), freq_info);
+            print_gpu_info(&info, &extras);
+            if cli::quiet() {
+                cli::print_value(&decode_chip_id(info.chip_id).model_name);
+            }
+            if !extras.warnings.is_empty() {
+                println!();
+                print_warnings(&extras.warnings);
+            }
 
             // Zusätzliche Info
-            println!("\n💡 IOCTL Information:");
-            println!("   • Working IOCTL: 0xc0140902");
-            println!("   • Command: 0x02 (KGSL_IOC_GETPROPERTY)");
-            println!("   • Type: 0x09 (KGSL_IOC_TYPE)");
-            println!("   • Size: 20 bytes (returns 16 bytes)");
-            println!("   • Direction: IOWR (Read/Write)");
+            println!();
+            cli::explain::print_explanation(IOCTL_KGSL_DEVICE_GETPROPERTY);
 
             // Export für andere Projekte
-            println!("\n📋 For use in other projects:");
+            println!("\n📋 {}", Msg::InfoForOtherProjects);
             println!("   struct KgslDeviceInfo {{");
             println!("       device_id: u32,      // offset 0");
             println!("       chip_id: u32,        // offset 4");
@@ -364,13 +481,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("       gmem_gpubaseaddr: u32, // offset 12");
             println!("   }}");
 
+            println!();
+            cli::driver::print_driver_info(&read_driver_info(), Some(propmap::tree(fd)), false);
+
+            println!();
+            print_zap_status(&zap::detect(fd));
+
+            println!();
+            print_hardware_features(&detect_features(fd, info.chip_id));
         }
         Err(e) => {
-            eprintln!("❌ Error: {}", e);
-            eprintln!("\n🔧 Troubleshooting:");
-            eprintln!("   1. Run as root: sudo ./adreno_ioctl");
-            eprintln!("   2. Check permissions: ls -la /dev/kgsl*");
-            eprintln!("   3. Alternative IOCTLs to try:");
+            cli::set_exit_code(cli::EXIT_UNSUPPORTED);
+            eprintln!("❌ {}", Msg::InfoError { error: &e });
+            eprintln!("\n🔧 {}", Msg::Troubleshooting);
+            eprintln!("   1. {}", Msg::TryRoot);
+            eprintln!("   2. {}", Msg::CheckPermissions);
+            eprintln!("   3. {}", Msg::AlternativeIoctls);
             eprintln!("      • 0xc0100902 (16 bytes)");
             eprintln!("      • 0xc0080902 (8 bytes)");
             eprintln!("      • 0xc00c0902 (12 bytes)");
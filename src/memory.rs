@@ -0,0 +1,464 @@
+//! GPU-Speicherobjekte (GPUOBJ IOCTLs)
+
+use std::io;
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::os::fd::{AsFd, AsRawFd, BorrowedFd};
+
+use zerocopy::{FromBytes, Immutable, IntoBytes};
+
+use crate::kgsl::{kgsl_iow, kgsl_iowr};
+use crate::sys::{ioctl, Mapping, Pod};
+
+pub use crate::sys::page_size;
+
+/// `struct kgsl_gpuobj_alloc`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslGpuobjAlloc {
+    pub size: u64,
+    pub flags: u64,
+    pub va_len: u64,
+    pub mmapsize: u64,
+    pub id: u32,
+    pub metadata_len: u32,
+    pub metadata: u64,
+}
+
+/// `struct kgsl_gpumem_get_info` (Legacy-Pfad)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslGpumemGetInfo {
+    pub gpuaddr: libc::c_ulong,
+    pub id: u32,
+    pub flags: u32,
+    pub size: usize,
+    pub mmapsize: usize,
+    pub useraddr: libc::c_ulong,
+    pub _pad: [libc::c_ulong; 4],
+}
+
+/// `struct kgsl_gpuobj_sync_obj`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KgslGpuobjSyncObj {
+    pub offset: u64,
+    pub length: u64,
+    pub id: u32,
+    pub op: u32,
+}
+
+/// `struct kgsl_gpuobj_sync`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslGpuobjSync {
+    pub objs: u64,
+    pub obj_len: u32,
+    pub count: u32,
+}
+
+/// `struct kgsl_gpuobj_free`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslGpuobjFree {
+    pub flags: u64,
+    pub priv_: u64,
+    pub id: u32,
+    pub type_: u32,
+    pub len: u32,
+    pub _pad: u32,
+}
+
+/// `struct kgsl_gpuobj_info`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslGpuobjInfo {
+    pub gpuaddr: u64,
+    pub flags: u64,
+    pub size: u64,
+    pub va_len: u64,
+    pub va_addr: u64,
+    pub id: u32,
+    pub _pad: u32,
+}
+
+/// `struct kgsl_gpuobj_import`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslGpuobjImport {
+    pub priv_: u64,
+    pub priv_len: u64,
+    pub flags: u64,
+    pub type_: u32,
+    pub id: u32,
+}
+
+/// `struct kgsl_gpuobj_import_useraddr`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KgslGpuobjImportUseraddr {
+    pub virtaddr: u64,
+}
+
+/// `struct kgsl_map_user_mem` (Legacy-Pfad)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslMapUserMem {
+    pub fd: i32,
+    #[cfg(target_pointer_width = "64")]
+    pub _pad: u32,
+    pub gpuaddr: libc::c_ulong,
+    pub len: usize,
+    pub offset: usize,
+    pub hostptr: libc::c_ulong,
+    pub memtype: u32,
+    pub flags: u32,
+}
+
+/// `struct kgsl_sharedmem_free`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslSharedmemFree {
+    pub gpuaddr: libc::c_ulong,
+}
+
+pub const IOCTL_KGSL_GPUMEM_GET_INFO: u32 = kgsl_iowr(0x36, size_of::<KgslGpumemGetInfo>());
+pub const IOCTL_KGSL_GPUOBJ_ALLOC: u32 = kgsl_iowr(0x45, size_of::<KgslGpuobjAlloc>());
+pub const IOCTL_KGSL_GPUOBJ_SYNC: u32 = kgsl_iow(0x49, size_of::<KgslGpuobjSync>());
+pub const IOCTL_KGSL_MAP_USER_MEM: u32 = kgsl_iowr(0x15, size_of::<KgslMapUserMem>());
+pub const IOCTL_KGSL_SHAREDMEM_FREE: u32 = kgsl_iow(0x21, size_of::<KgslSharedmemFree>());
+pub const IOCTL_KGSL_GPUOBJ_FREE: u32 = kgsl_iow(0x46, size_of::<KgslGpuobjFree>());
+pub const IOCTL_KGSL_GPUOBJ_INFO: u32 = kgsl_iowr(0x47, size_of::<KgslGpuobjInfo>());
+pub const IOCTL_KGSL_GPUOBJ_IMPORT: u32 = kgsl_iowr(0x48, size_of::<KgslGpuobjImport>());
+
+/// Speicher-Flags (`KGSL_MEMFLAGS_*`, `KGSL_CACHEMODE_*`, `KGSL_MEMTYPE_*`)
+pub const KGSL_MEMFLAGS_SECURE: u64 = 1 << 3;
+pub const KGSL_MEMFLAGS_GPUREADONLY: u64 = 1 << 24;
+pub const KGSL_MEMFLAGS_GPUWRITEONLY: u64 = 1 << 25;
+pub const KGSL_MEMFLAGS_USE_CPU_MAP: u64 = 1 << 28;
+pub const KGSL_MEMFLAGS_SPARSE_PHYS: u64 = 1 << 29;
+pub const KGSL_MEMFLAGS_SPARSE_VIRT: u64 = 1 << 30;
+pub const KGSL_MEMFLAGS_IOCOHERENT: u64 = 1 << 31;
+pub const KGSL_MEMFLAGS_FORCE_32BIT: u64 = 1 << 32;
+pub const KGSL_MEMFLAGS_GUARD_PAGE: u64 = 1 << 33;
+pub const KGSL_MEMFLAGS_VBO: u64 = 1 << 34;
+
+pub const KGSL_CACHEMODE_MASK: u64 = 0x0C000000;
+pub const KGSL_CACHEMODE_SHIFT: u64 = 26;
+pub const KGSL_CACHEMODE_WRITECOMBINE: u64 = 0;
+pub const KGSL_CACHEMODE_UNCACHED: u64 = 1;
+pub const KGSL_CACHEMODE_WRITETHROUGH: u64 = 2;
+pub const KGSL_CACHEMODE_WRITEBACK: u64 = 3;
+
+pub const KGSL_MEMTYPE_MASK: u64 = 0x0000FF00;
+pub const KGSL_MEMTYPE_SHIFT: u64 = 8;
+pub const KGSL_MEMALIGN_MASK: u64 = 0x00FF0000;
+pub const KGSL_MEMALIGN_SHIFT: u64 = 16;
+
+/// Namen der Speichertypen (`KGSL_MEMTYPE_*`), Index = Typ
+const MEMTYPE_NAMES: [&str; 21] = [
+    "any", "framebuffer", "renderbuffer", "arraybuffer", "elementarraybuffer",
+    "vertexarraybuffer", "texture", "surface", "egl_surface", "gl", "cl",
+    "cl_buffer_map", "cl_buffer_nomap", "cl_image_map", "cl_image_nomap",
+    "cl_kernel_stack", "command", "2d", "egl_image", "egl_shadow", "multisample",
+];
+
+/// Cache-Modus Name
+pub fn cachemode_name(flags: u64) -> &'static str {
+    match (flags & KGSL_CACHEMODE_MASK) >> KGSL_CACHEMODE_SHIFT {
+        KGSL_CACHEMODE_WRITECOMBINE => "writecombine",
+        KGSL_CACHEMODE_UNCACHED => "uncached",
+        KGSL_CACHEMODE_WRITETHROUGH => "writethrough",
+        _ => "writeback",
+    }
+}
+
+/// Lesbare Beschreibung der Flags, z.B. "texture writeback gpu-ro"
+pub fn describe_flags(flags: u64) -> String {
+    let memtype = ((flags & KGSL_MEMTYPE_MASK) >> KGSL_MEMTYPE_SHIFT) as usize;
+    let mut parts = vec![
+        match memtype {
+            255 => "kernel".to_string(),
+            t => MEMTYPE_NAMES.get(t).map_or_else(|| format!("type{}", t), |n| n.to_string()),
+        },
+        cachemode_name(flags).to_string(),
+    ];
+    let bits = [
+        (KGSL_MEMFLAGS_SECURE, "secure"),
+        (KGSL_MEMFLAGS_GPUREADONLY, "gpu-ro"),
+        (KGSL_MEMFLAGS_GPUWRITEONLY, "gpu-wo"),
+        (KGSL_MEMFLAGS_USE_CPU_MAP, "cpu-map"),
+        (KGSL_MEMFLAGS_SPARSE_PHYS, "sparse-phys"),
+        (KGSL_MEMFLAGS_SPARSE_VIRT, "sparse-virt"),
+        (KGSL_MEMFLAGS_IOCOHERENT, "io-coherent"),
+        (KGSL_MEMFLAGS_FORCE_32BIT, "32bit"),
+        (KGSL_MEMFLAGS_GUARD_PAGE, "guard"),
+        (KGSL_MEMFLAGS_VBO, "vbo"),
+    ];
+    parts.extend(bits.iter().filter(|(bit, _)| flags & bit != 0).map(|(_, name)| name.to_string()));
+    parts.join(" ")
+}
+
+/// Cache-Operationen für GPUOBJ_SYNC
+pub const KGSL_GPUMEM_CACHE_CLEAN: u32 = 1;
+pub const KGSL_GPUMEM_CACHE_INV: u32 = 2;
+pub const KGSL_GPUMEM_CACHE_FLUSH: u32 = KGSL_GPUMEM_CACHE_CLEAN | KGSL_GPUMEM_CACHE_INV;
+
+/// Speichertypen für Import (`KGSL_USER_MEM_TYPE_*`)
+pub const KGSL_USER_MEM_TYPE_ADDR: u32 = 0x00000002;
+pub const KGSL_USER_MEM_TYPE_DMABUF: u32 = 0x00000003;
+
+/// Fragt Adresse, Größe und Flags eines Objekts ab
+pub fn gpuobj_info(fd: i32, id: u32) -> io::Result<KgslGpuobjInfo> {
+    let mut info = KgslGpuobjInfo { id, ..Default::default() };
+    ioctl(fd, IOCTL_KGSL_GPUOBJ_INFO, &mut info)?;
+    Ok(info)
+}
+
+/// Gibt ein Objekt per ID frei
+pub fn gpuobj_free(fd: i32, id: u32) -> io::Result<()> {
+    let mut req = KgslGpuobjFree { id, ..Default::default() };
+    ioctl(fd, IOCTL_KGSL_GPUOBJ_FREE, &mut req)
+}
+
+/// Info über GPUMEM_GET_INFO für Kernel ohne GPUOBJ_INFO
+pub fn gpumem_get_info(fd: i32, id: u32) -> io::Result<KgslGpuobjInfo> {
+    let mut req = KgslGpumemGetInfo { id, ..Default::default() };
+    ioctl(fd, IOCTL_KGSL_GPUMEM_GET_INFO, &mut req)?;
+    #[allow(clippy::unnecessary_cast)]
+    Ok(KgslGpuobjInfo {
+        gpuaddr: req.gpuaddr as u64,
+        flags: req.flags as u64,
+        size: req.size as u64,
+        va_len: req.size as u64,
+        va_addr: req.useraddr as u64,
+        id,
+        _pad: 0,
+    })
+}
+
+/// Info per GPUOBJ_INFO, bei ENOTTY über GPUMEM_GET_INFO
+pub fn object_info(fd: i32, id: u32) -> io::Result<KgslGpuobjInfo> {
+    match gpuobj_info(fd, id) {
+        Err(e) if e.raw_os_error() == Some(libc::ENOTTY) => gpumem_get_info(fd, id),
+        other => other,
+    }
+}
+
+/// Alle Objekte dieses Prozesses mit ID bis `max_id`, nach Adresse sortiert
+///
+/// IDs sind pro Prozess; fremde Allokationen sind so nicht sichtbar.
+pub fn list_objects(fd: i32, max_id: u32) -> Vec<KgslGpuobjInfo> {
+    let mut objects: Vec<KgslGpuobjInfo> = (1..=max_id).filter_map(|id| object_info(fd, id).ok()).collect();
+    objects.sort_by_key(|o| o.gpuaddr);
+    objects
+}
+
+// ============================================================================
+// Allokation
+// ============================================================================
+
+/// GPU-Speicherobjekt aus GPUOBJ_ALLOC, wird beim Drop freigegeben
+pub struct GpuBuffer<'a> {
+    fd: BorrowedFd<'a>,
+    pub id: u32,
+    pub gpuaddr: u64,
+    pub size: u64,
+    pub mmapsize: u64,
+    pub flags: u64,
+}
+
+impl<'a> GpuBuffer<'a> {
+    pub fn alloc(dev: &'a impl AsFd, size: u64, flags: u64) -> io::Result<Self> {
+        let fd = dev.as_fd();
+        let mut req = KgslGpuobjAlloc { size, flags, ..Default::default() };
+        ioctl(fd.as_raw_fd(), IOCTL_KGSL_GPUOBJ_ALLOC, &mut req)?;
+
+        let buffer = GpuBuffer { fd, id: req.id, gpuaddr: 0, size, mmapsize: req.mmapsize, flags: req.flags };
+        let info = gpuobj_info(fd.as_raw_fd(), req.id)?;
+        Ok(GpuBuffer { gpuaddr: info.gpuaddr, size: info.size, flags: info.flags, ..buffer })
+    }
+
+    pub fn fd(&self) -> i32 {
+        self.fd.as_raw_fd()
+    }
+
+    /// CPU-Cache Wartung über das ganze Objekt (`KGSL_GPUMEM_CACHE_*`)
+    pub fn sync(&self, op: u32) -> io::Result<()> {
+        let mut obj = KgslGpuobjSyncObj { offset: 0, length: self.size, id: self.id, op };
+        let mut req = KgslGpuobjSync {
+            objs: &mut obj as *mut KgslGpuobjSyncObj as u64,
+            obj_len: size_of::<KgslGpuobjSyncObj>() as u32,
+            count: 1,
+        };
+        ioctl(self.fd.as_raw_fd(), IOCTL_KGSL_GPUOBJ_SYNC, &mut req)
+    }
+
+    /// Mappt das Objekt in den CPU-Adressraum (mmap-Offset = ID in Seiten)
+    pub fn map(&self) -> io::Result<GpuMapping<'_>> {
+        let len = self.mmapsize.max(self.size) as usize;
+        let offset = self.id as u64 * page_size() as u64;
+        let map = Mapping::shared(self.fd, len, true, offset)?;
+        Ok(GpuMapping { map, _buffer: PhantomData })
+    }
+}
+
+/// CPU-Mapping eines [`GpuBuffer`], wird beim Drop aufgehoben
+///
+/// Die GPU schreibt nebenläufig mit, daher keine Slices, nur einzelne
+/// volatile Zugriffe.
+pub struct GpuMapping<'b> {
+    map: Mapping,
+    _buffer: PhantomData<&'b ()>,
+}
+
+impl GpuMapping<'_> {
+    /// Liest `T` bei `offset`, `None` außerhalb oder unausgerichtet
+    pub fn read<T: Pod>(&self, offset: usize) -> Option<T> {
+        self.map.read(offset)
+    }
+
+    /// Schreibt `T` bei `offset`; `false` außerhalb oder unausgerichtet
+    pub fn write<T: Pod>(&self, offset: usize, value: T) -> bool {
+        self.map.write(offset, value)
+    }
+
+    /// Kopiert `bytes` ab `offset`; `false` außerhalb
+    pub fn write_bytes(&self, offset: usize, bytes: &[u8]) -> bool {
+        self.map.write_bytes(offset, bytes)
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl Drop for GpuBuffer<'_> {
+    fn drop(&mut self) {
+        let _ = gpuobj_free(self.fd.as_raw_fd(), self.id);
+    }
+}
+
+// ============================================================================
+// Import von CPU-Speicher (usermem)
+// ============================================================================
+
+/// Wie der Speicher importiert wurde
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportPath {
+    /// `IOCTL_KGSL_GPUOBJ_IMPORT` mit `KGSL_USER_MEM_TYPE_ADDR`
+    GpuobjImport,
+    /// Alter `IOCTL_KGSL_MAP_USER_MEM` Pfad
+    MapUserMem,
+}
+
+/// In den GPU-Adressraum importierter CPU-Puffer
+///
+/// Leiht sich den Puffer für die Dauer des Mappings; beim Drop wird das
+/// GPU-Mapping entfernt, der CPU-Speicher bleibt unberührt.
+pub struct ImportedMemory<'a> {
+    fd: BorrowedFd<'a>,
+    pub id: Option<u32>,
+    pub gpuaddr: u64,
+    pub size: u64,
+    pub flags: u64,
+    pub path: ImportPath,
+    _buffer: PhantomData<&'a mut [u8]>,
+}
+
+/// Importiert einen seitenausgerichteten CPU-Puffer (Zero-Copy)
+///
+/// Versucht zuerst GPUOBJ_IMPORT, danach MAP_USER_MEM.
+pub fn import_user_memory<'a>(dev: &'a impl AsFd, buffer: &'a mut [u8], flags: u64) -> io::Result<ImportedMemory<'a>> {
+    let fd = dev.as_fd();
+    let raw = fd.as_raw_fd();
+    let virtaddr = buffer.as_mut_ptr() as u64;
+    let len = buffer.len() as u64;
+
+    let mut useraddr = KgslGpuobjImportUseraddr { virtaddr };
+    let mut req = KgslGpuobjImport {
+        priv_: &mut useraddr as *mut _ as u64,
+        priv_len: len,
+        flags,
+        type_: KGSL_USER_MEM_TYPE_ADDR,
+        id: 0,
+    };
+
+    match ioctl(raw, IOCTL_KGSL_GPUOBJ_IMPORT, &mut req) {
+        Ok(()) => {
+            let info = gpuobj_info(raw, req.id)?;
+            Ok(ImportedMemory {
+                fd,
+                id: Some(req.id),
+                gpuaddr: info.gpuaddr,
+                size: info.size,
+                flags: info.flags,
+                path: ImportPath::GpuobjImport,
+                _buffer: PhantomData,
+            })
+        }
+        Err(e) if e.raw_os_error() == Some(libc::ENOTTY) => {
+            let mut map = KgslMapUserMem {
+                fd: -1,
+                len: buffer.len(),
+                hostptr: virtaddr as libc::c_ulong,
+                memtype: KGSL_USER_MEM_TYPE_ADDR,
+                flags: flags as u32,
+                ..Default::default()
+            };
+            ioctl(raw, IOCTL_KGSL_MAP_USER_MEM, &mut map)?;
+            // c_ulong ist auf 32-Bit ARM nur u32
+            #[allow(clippy::unnecessary_cast)]
+            let gpuaddr = map.gpuaddr as u64;
+            Ok(ImportedMemory {
+                fd,
+                id: None,
+                gpuaddr,
+                size: len,
+                flags,
+                path: ImportPath::MapUserMem,
+                _buffer: PhantomData,
+            })
+        }
+        Err(e) => Err(e),
+    }
+}
+
+impl Drop for ImportedMemory<'_> {
+    fn drop(&mut self) {
+        let fd = self.fd.as_raw_fd();
+        match self.id {
+            Some(id) => {
+                let _ = gpuobj_free(fd, id);
+            }
+            None => {
+                let mut req = KgslSharedmemFree { gpuaddr: self.gpuaddr as libc::c_ulong };
+                let _ = ioctl(fd, IOCTL_KGSL_SHAREDMEM_FREE, &mut req);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Seitenausgerichteter CPU-Puffer
+// ============================================================================
+
+/// Anonymes mmap, seitenausgerichtet wie es usermem-Import verlangt
+pub struct PageBuffer {
+    map: Mapping,
+}
+
+impl PageBuffer {
+    pub fn new(len: usize) -> io::Result<Self> {
+        let page = page_size();
+        Ok(PageBuffer { map: Mapping::anonymous(len.div_ceil(page) * page)? })
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        self.map.as_mut_slice()
+    }
+}
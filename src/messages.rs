@@ -0,0 +1,2340 @@
+//! Meldungskatalog: Englisch als Standard, optional Deutsch
+//!
+//! Alle Meldungen der Kommandozeile stehen hier, nach Kommandos gruppiert;
+//! gemeinsame Meldungen unter "Allgemein". Die Sprache wird mit `--lang de`
+//! oder `ADRENO_IOCTL_LANG=de` gewählt.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Ausgabesprache
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    En,
+    De,
+}
+
+impl Lang {
+    /// Sprachkürzel wie "de" oder "de_DE.UTF-8"
+    pub fn parse(text: &str) -> Option<Self> {
+        match text.get(..2)?.to_ascii_lowercase().as_str() {
+            "en" => Some(Lang::En),
+            "de" => Some(Lang::De),
+            _ => None,
+        }
+    }
+}
+
+/// Umgebungsvariable für die Sprache
+pub const LANG_ENV: &str = "ADRENO_IOCTL_LANG";
+
+static LANG: AtomicU8 = AtomicU8::new(Lang::En as u8);
+
+pub fn set_language(lang: Lang) {
+    LANG.store(lang as u8, Ordering::SeqCst);
+}
+
+pub fn language() -> Lang {
+    match LANG.load(Ordering::SeqCst) {
+        1 => Lang::De,
+        _ => Lang::En,
+    }
+}
+
+/// Eine Meldung; `to_string()` liefert den Text in der aktuellen Sprache
+#[derive(Clone, Copy)]
+pub enum Msg<'a> {
+    NoDevices,
+    CannotOpen { path: &'a str, error: &'a dyn fmt::Display },
+    TryRoot,
+    IoctlFailed { error: &'a dyn fmt::Display },
+    NoValidGpuData,
+    VersionUnavailable,
+    UnknownCommand { command: &'a str },
+    UnknownArgument { arg: &'a str },
+    RequiresValue { option: &'a str },
+    InvalidValue { option: &'a str, value: &'a str },
+    InvalidDuration { text: &'a str },
+    InvalidDurationUnit { text: &'a str },
+    InvalidSize { text: &'a str },
+    InvalidSizeUnit { text: &'a str },
+    Troubleshooting,
+    CheckPermissions,
+    AlternativeIoctls,
+    // Allgemein
+    CannotRead { path: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    CannotWrite { path: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    CannotWriteRecord { error: &'a dyn fmt::Display },
+    CannotSetUpSubmission { error: &'a dyn fmt::Display },
+    DroppedRoot { user: &'a dyn fmt::Display },
+    SamplingOn { placement: &'a dyn fmt::Display },
+    Interrupted,
+    InterruptedRestoring,
+    // allocflags
+    AllocMatrix { path: &'a dyn fmt::Display, kb: &'a dyn fmt::Display },
+    AllocDriverReturned { flags: &'a dyn fmt::Display },
+    AllocReadbackMismatch,
+    AllocSummary { ok: &'a dyn fmt::Display, total: &'a dyn fmt::Display },
+    AllocUbwcNote,
+    // analyze
+    AnalyzeWindowTooShort,
+    AnalyzeNoSamples { file: &'a dyn fmt::Display },
+    AnalyzeSummary {
+        file: &'a dyn fmt::Display,
+        samples: &'a dyn fmt::Display,
+        windows: &'a dyn fmt::Display,
+        secs: &'a dyn fmt::Display,
+    },
+    // bench
+    BenchUnknown { name: &'a dyn fmt::Display },
+    BenchUsage,
+    BenchNoSysfsFiles { dir: &'a dyn fmt::Display },
+    BenchSysfsHeader { files: &'a dyn fmt::Display, scrapes: &'a dyn fmt::Display, dir: &'a dyn fmt::Display },
+    BenchSysfsSpeedup { factor: &'a dyn fmt::Display, ttl: &'a dyn fmt::Display },
+    BenchIoctlHeader { samples: &'a dyn fmt::Display, path: &'a dyn fmt::Display },
+    BenchCollectorFailed { error: &'a dyn fmt::Display },
+    BenchSetupSyscalls { count: &'a dyn fmt::Display },
+    BenchOverBudget { per_sample: &'a dyn fmt::Display, budget: &'a dyn fmt::Display },
+    BenchWithinBudget { budget: &'a dyn fmt::Display },
+    // blob
+    BlobHeader,
+    BlobNoneFound { path: &'a dyn fmt::Display },
+    BlobUpdatable { package: &'a dyn fmt::Display },
+    BlobNotInstalled,
+    BlobOptInHint,
+    BlobNoUpdatable,
+    BlobAlsoContains { version: &'a dyn fmt::Display },
+    BlobNoVersion { path: &'a dyn fmt::Display },
+    // boost
+    BoostLevelOutOfRange { level: &'a dyn fmt::Display, count: &'a dyn fmt::Display },
+    BoostTarget { level: &'a dyn fmt::Display, mhz: &'a dyn fmt::Display },
+    BoostSysfs { from: &'a dyn fmt::Display, to: &'a dyn fmt::Display, secs: &'a dyn fmt::Display },
+    CannotRestore { path: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    BoostRestored { level: &'a dyn fmt::Display },
+    BoostRestoreMismatch { now: &'a dyn fmt::Display, expected: &'a dyn fmt::Display },
+    BoostCannotVerify { error: &'a dyn fmt::Display },
+    BoostFallback { error: &'a dyn fmt::Display },
+    CannotCreateContext { error: &'a dyn fmt::Display },
+    BoostConstraintFailed { error: &'a dyn fmt::Display },
+    BoostSubmitFailed { error: &'a dyn fmt::Display },
+    BoostVote { context: &'a dyn fmt::Display, secs: &'a dyn fmt::Display, interval: &'a dyn fmt::Display },
+    BoostReleaseFailed { error: &'a dyn fmt::Display },
+    BoostReleased,
+    BoostBusyFailed { error: &'a dyn fmt::Display },
+    // bugreport
+    CannotCreate { path: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    WritingFailed { path: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    BugreportWritten { path: &'a dyn fmt::Display },
+    // bus
+    BusHeader { device: &'a dyn fmt::Display },
+    BusNoGpubw { dir: &'a dyn fmt::Display },
+    BusNoDtTable,
+    BusDtTable { mhz: &'a dyn fmt::Display },
+    BusMemory { label: &'a dyn fmt::Display },
+    BusGpuLine { freq: &'a dyn fmt::Display, level: &'a dyn fmt::Display, busy: &'a dyn fmt::Display },
+    BusLevel { level: &'a dyn fmt::Display },
+    BusLevelUnknown,
+    BusDtAllows { range: &'a dyn fmt::Display },
+    BusVoteTier {
+        vote: &'a dyn fmt::Display,
+        tier: &'a dyn fmt::Display,
+        tiers: &'a dyn fmt::Display,
+        governor: &'a dyn fmt::Display,
+    },
+    BusVote { vote: &'a dyn fmt::Display },
+    BusNotReadable,
+    BusStarved { busy: &'a dyn fmt::Display, tier: &'a dyn fmt::Display, tiers: &'a dyn fmt::Display },
+    // caps
+    CapsHeader { model: &'a dyn fmt::Display, chip_id: u32 },
+    CapsUnknownChip,
+    CapsAdvertised { count: &'a dyn fmt::Display },
+    CapsNoQuery,
+    // collect
+    CollectDuplicate { path: &'a dyn fmt::Display, name: &'a dyn fmt::Display },
+    CollectList { device: &'a dyn fmt::Display },
+    CollectSupported,
+    CollectNotAvailable,
+    CollectNoneAvailable,
+    // contexts
+    ContextsUnavailable { reason: &'a dyn fmt::Display },
+    ContextsDebugfsOnly,
+    ContextsHeader { device: &'a dyn fmt::Display, root: &'a dyn fmt::Display },
+    None,
+    ContextsSummary { contexts: &'a dyn fmt::Display, processes: &'a dyn fmt::Display },
+    ContextsExitedOwner { name: &'a dyn fmt::Display, pid: &'a dyn fmt::Display, count: &'a dyn fmt::Display },
+    ContextsLeak { name: &'a dyn fmt::Display, pid: &'a dyn fmt::Display, count: &'a dyn fmt::Display },
+    // completions
+    UnknownShell { shell: &'a dyn fmt::Display },
+    // cores
+    CoresMeasureUnsupported,
+    NotInChipDatabase { model: &'a dyn fmt::Display },
+    CoresHeader { path: &'a dyn fmt::Display, name: &'a dyn fmt::Display },
+    CoresDriverModel { model: &'a dyn fmt::Display },
+    CoresSp { count: &'a dyn fmt::Display },
+    CoresMicroTp { count: &'a dyn fmt::Display },
+    CoresAlus { count: &'a dyn fmt::Display },
+    CoresWaveSize { fibers: &'a dyn fmt::Display },
+    CoresMaxWaves { total: &'a dyn fmt::Display, per_sp: &'a dyn fmt::Display },
+    CoresFromDatabase,
+    CoresMemory,
+    CoresBusWidth { bits: &'a dyn fmt::Display },
+    CoresUche { size: &'a dyn fmt::Display },
+    CoresCacheLine { bytes: &'a dyn fmt::Display },
+    CoresMinAccessDriver { bytes: &'a dyn fmt::Display },
+    CoresMinAccessTypical { bytes: &'a dyn fmt::Display },
+    CoresCoalesce { bytes: &'a dyn fmt::Display },
+    CoresGflopsMax { gflops: &'a dyn fmt::Display, mhz: &'a dyn fmt::Display },
+    CoresGflopsCurrent { gflops: &'a dyn fmt::Display, mhz: &'a dyn fmt::Display },
+    // daemon
+    CannotQuery { path: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    CannotBind { path: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    DaemonListening {
+        device: &'a dyn fmt::Display,
+        socket: &'a dyn fmt::Display,
+        interval: &'a dyn fmt::Display,
+        max_interval: &'a dyn fmt::Display,
+        keep: &'a dyn fmt::Display,
+    },
+    AcceptFailed { error: &'a dyn fmt::Display },
+    DaemonStopped,
+    CannotListen { addr: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    DashboardOn { addr: &'a dyn fmt::Display },
+    HttpFeatureMissing,
+    // debugfs
+    DebugfsUnavailable { reason: &'a dyn fmt::Display },
+    DebugfsOnlyThere,
+    DebugfsHeader { root: &'a dyn fmt::Display },
+    DebugfsMemoryLists { count: &'a dyn fmt::Display },
+    DebugfsProcess {
+        pid: &'a dyn fmt::Display,
+        name: &'a dyn fmt::Display,
+        count: &'a dyn fmt::Display,
+        size: &'a dyn fmt::Display,
+    },
+    DebugfsGlobal { count: &'a dyn fmt::Display, size: &'a dyn fmt::Display },
+    DebugfsContexts { count: &'a dyn fmt::Display },
+    DebugfsContext {
+        id: &'a dyn fmt::Display,
+        process: &'a dyn fmt::Display,
+        queued: &'a dyn fmt::Display,
+        retired: &'a dyn fmt::Display,
+        inflight: &'a dyn fmt::Display,
+    },
+    // driver
+    DriverUserspaceBlob { version: &'a dyn fmt::Display, path: &'a dyn fmt::Display },
+    DriverHeader,
+    DriverBuiltin,
+    DriverModule,
+    DriverNoModule,
+    DriverTreeFromDriver { tree: &'a dyn fmt::Display },
+    DriverTreeFromRelease { tree: &'a dyn fmt::Display },
+    DriverParameters,
+    // dt
+    DtNoGpuNode,
+    DtHeader { path: &'a dyn fmt::Display },
+    DtZapShader { name: &'a dyn fmt::Display },
+    DtZapNoName,
+    DtZapNone,
+    DtNoTables,
+    DtSpeedBin { bin: &'a dyn fmt::Display },
+    DtDefault,
+    DtPowerLevels { bin: &'a dyn fmt::Display },
+    DtActive,
+    DtRuntimeMismatch { mhz: &'a dyn fmt::Display },
+    DtOppUnreadable { dir: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    DtRuntimeOpp { dir: &'a dyn fmt::Display, device: &'a dyn fmt::Display },
+    // events
+    EventsUnknown { event: &'a dyn fmt::Display, expected: &'a dyn fmt::Display },
+    EventsNoFtrace { error: &'a dyn fmt::Display },
+    EventsHeader { path: &'a dyn fmt::Display, kinds: &'a dyn fmt::Display, mode: &'a dyn fmt::Display },
+    EventsPolling,
+    EventsCannotRead { error: &'a dyn fmt::Display },
+    // explain
+    ExplainInvalid { arg: &'a dyn fmt::Display },
+    ExplainDirection { dir: &'a dyn fmt::Display },
+    ExplainCommand { nr: u32 },
+    ExplainSize { bytes: &'a dyn fmt::Display },
+    ExplainMatches,
+    ExplainToolUses { request: u32, dir: &'a dyn fmt::Display, bytes: &'a dyn fmt::Display },
+    ExplainUnknownKgsl,
+    // export
+    ExportNeedsFormat,
+    ExportWritten { path: &'a dyn fmt::Display },
+    // farm
+    FarmNoDevices,
+    FarmNoBinary { error: &'a dyn fmt::Display },
+    NotAFile { path: &'a dyn fmt::Display },
+    FarmRunning { command: &'a dyn fmt::Display, count: &'a dyn fmt::Display },
+    FarmSummary { ok: &'a dyn fmt::Display, failed: &'a dyn fmt::Display },
+    // fence
+    SubmitFailed { error: &'a dyn fmt::Display },
+    FenceSyncFiles { count: &'a dyn fmt::Display, pid: &'a dyn fmt::Display },
+    FenceCannotOpen { fd: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    FenceEventFailed { error: &'a dyn fmt::Display },
+    FenceCreated { context: &'a dyn fmt::Display, timestamp: &'a dyn fmt::Display, path: &'a dyn fmt::Display },
+    FenceNotSyncFile { label: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    FenceStatus { status: &'a dyn fmt::Display },
+    FenceSignaled { ms: &'a dyn fmt::Display },
+    FenceOwner { context: &'a dyn fmt::Display, timestamp: &'a dyn fmt::Display },
+    FenceOwnerKgsl { context: &'a dyn fmt::Display },
+    FenceActive,
+    FenceSignaledState,
+    FenceError { code: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    // frametime
+    FrametimeNoContexts { pid: &'a dyn fmt::Display, dir: &'a dyn fmt::Display },
+    FrametimeMeasuring {
+        pid: &'a dyn fmt::Display,
+        secs: &'a dyn fmt::Display,
+        poll: &'a dyn fmt::Display,
+        merge: &'a dyn fmt::Display,
+    },
+    FrametimeNoFrames,
+    FrametimeContexts { count: &'a dyn fmt::Display },
+    FrametimeFrames { frames: &'a dyn fmt::Display, fps: &'a dyn fmt::Display },
+    FrametimeLatency {
+        avg: &'a dyn fmt::Display,
+        median: &'a dyn fmt::Display, p99: &'a dyn fmt::Display,
+        max: &'a dyn fmt::Display,
+    },
+    FrametimeJanks { janks: &'a dyn fmt::Display, factor: &'a dyn fmt::Display },
+    FrametimeBasis,
+    // get
+    GetUnknownField { field: &'a dyn fmt::Display, names: &'a dyn fmt::Display },
+    GetFieldAndFormat,
+    GetNeedsField,
+    GetFields { names: &'a dyn fmt::Display },
+    GetPlaceholders,
+    GetUnclosed { key: &'a dyn fmt::Display },
+    GetUnknownPlaceholder { key: &'a dyn fmt::Display, names: &'a dyn fmt::Display },
+    GetUnmatchedBrace,
+    CannotReadFrom { key: &'a dyn fmt::Display, path: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    NoFrequencies,
+    GmemUnknown { model: &'a dyn fmt::Display },
+    // gmem
+    GmemNoSource { model: &'a dyn fmt::Display },
+    GmemHeader { path: &'a dyn fmt::Display, model: &'a dyn fmt::Display },
+    GmemFromDriver,
+    GmemFromDatabase,
+    GmemSize { kb: &'a dyn fmt::Display, source: &'a dyn fmt::Display },
+    GmemBase { base: u64 },
+    GmemBaseShort { base: u64 },
+    GmemSlices { ccus: &'a dyn fmt::Display, kb: &'a dyn fmt::Display },
+    GmemTileAlign {
+        w: &'a dyn fmt::Display,
+        h: &'a dyn fmt::Display,
+        max_w: &'a dyn fmt::Display,
+        max_h: &'a dyn fmt::Display,
+        pipes: &'a dyn fmt::Display,
+    },
+    GmemBins { w: &'a dyn fmt::Display, h: &'a dyn fmt::Display },
+    GmemNoFit,
+    // health
+    HealthHeader {
+        path: &'a dyn fmt::Display,
+        interval: &'a dyn fmt::Display,
+        timeout: &'a dyn fmt::Display,
+        context: &'a dyn fmt::Display,
+    },
+    HealthRetired { ts: &'a dyn fmt::Display, ms: &'a dyn fmt::Display },
+    HealthStuck { retired: &'a dyn fmt::Display, submitted: &'a dyn fmt::Display, secs: &'a dyn fmt::Display },
+    SubmissionFailed { error: &'a dyn fmt::Display },
+    HealthFailed { alarms: &'a dyn fmt::Display },
+    HealthAlarm { time: &'a dyn fmt::Display, message: &'a dyn fmt::Display },
+    // llc
+    LlcHeader { device: &'a dyn fmt::Display },
+    LlcDriver { state: &'a dyn fmt::Display },
+    LlcBound,
+    LlcNotFound,
+    LlcNoSlices,
+    LlcUsecase { id: &'a dyn fmt::Display },
+    LlcIdUnknown,
+    Enabled,
+    Disabled,
+    LlcStateUnknown,
+    LlcSlice { name: &'a dyn fmt::Display, id: &'a dyn fmt::Display, state: &'a dyn fmt::Display },
+    LlcUsed,
+    LlcUnused,
+    LlcCounters,
+    LlcNoCounters { path: &'a dyn fmt::Display },
+    LlcNoPerfmon,
+    // lpac
+    LpacHeader { path: &'a dyn fmt::Display, kind: &'a dyn fmt::Display },
+    LpacNoNode,
+    LpacNode { node: &'a dyn fmt::Display },
+    LpacEnabled,
+    LpacDisabled,
+    LpacNotReported,
+    LpacContext,
+    LpacContextFailed { error: &'a dyn fmt::Display },
+    // overlay
+    OverlayPublishing { device: &'a dyn fmt::Display, path: &'a dyn fmt::Display, ms: &'a dyn fmt::Display },
+    OverlayNoRecord,
+    OverlayRecord { path: &'a dyn fmt::Display, seq: &'a dyn fmt::Display, age: &'a dyn fmt::Display },
+    OverlayFrequency { freq: &'a dyn fmt::Display, max: &'a dyn fmt::Display },
+    OverlayBusy { busy: &'a dyn fmt::Display },
+    OverlayTemp { temp: &'a dyn fmt::Display },
+    OverlayThermal { level: &'a dyn fmt::Display },
+    Level { level: &'a dyn fmt::Display },
+    // monitor
+    MonitorAlertSingle,
+    MonitorOptionsSingle,
+    MonitorBattery { path: &'a dyn fmt::Display },
+    MonitorNoBattery,
+    MonitorNoQueues { dir: &'a dyn fmt::Display },
+    MonitorCounterBusy,
+    MonitorAlertRule { rule: &'a dyn fmt::Display },
+    MonitorStart { path: &'a dyn fmt::Display, secs: &'a dyn fmt::Display },
+    MonitorStartAll { count: &'a dyn fmt::Display, secs: &'a dyn fmt::Display },
+    MonitorNoIrqs,
+    MonitorBackoff { secs: &'a dyn fmt::Display },
+    MonitorActive { secs: &'a dyn fmt::Display },
+    MonitorEnergy {
+        joules: &'a dyn fmt::Display,
+        secs: &'a dyn fmt::Display,
+        avg: &'a dyn fmt::Display,
+        peak: &'a dyn fmt::Display,
+    },
+    MonitorAlertCleared { rule: &'a dyn fmt::Display, value: &'a dyn fmt::Display },
+    MonitorAlert { rule: &'a dyn fmt::Display, value: &'a dyn fmt::Display },
+    MonitorHookFailed { error: &'a dyn fmt::Display },
+    MonitorSuspended { secs: &'a dyn fmt::Display },
+    MonitorSummary { title: &'a dyn fmt::Display, secs: &'a dyn fmt::Display, samples: &'a dyn fmt::Display },
+    MonitorFrequency,
+    MonitorBusy,
+    MonitorTemperature,
+    MonitorThrottled { secs: &'a dyn fmt::Display, percent: &'a dyn fmt::Display },
+    MonitorIfpc { entries: &'a dyn fmt::Display, rate: &'a dyn fmt::Display },
+    MonitorIdle { secs: &'a dyn fmt::Display, percent: &'a dyn fmt::Display },
+    MonitorSuspends { secs: &'a dyn fmt::Display, count: &'a dyn fmt::Display, total: &'a dyn fmt::Display },
+    MonitorFaults { count: &'a dyn fmt::Display },
+    MonitorResidency,
+    MonitorPreemption { state: &'a dyn fmt::Display, level: &'a dyn fmt::Display },
+    Unknown,
+    MonitorNoPreemptCount,
+    MonitorWaitsIrq,
+    MonitorWaitsPolled,
+    MonitorWaitsEstimated,
+    MonitorIfpcCount { state: &'a dyn fmt::Display, count: &'a dyn fmt::Display },
+    MonitorIfpcNoCount { state: &'a dyn fmt::Display },
+    MonitorNoIfpc,
+    MonitorNoCoefficients { model: &'a dyn fmt::Display },
+    MonitorNoFrequencies,
+    MonitorPowerModel { name: &'a dyn fmt::Display, static_mw: &'a dyn fmt::Display, dynamic_mw: &'a dyn fmt::Display },
+    MonitorOverfeed { context: &'a dyn fmt::Display, owner: &'a dyn fmt::Display, count: &'a dyn fmt::Display },
+    MonitorIrqStorm { name: &'a dyn fmt::Display },
+    MonitorNoIrqsBusy { name: &'a dyn fmt::Display },
+    // procmem
+    ProcmemSinkNeedsWatch,
+    ProcmemHeader { dir: &'a dyn fmt::Display },
+    ProcmemNone,
+    ProcmemInotify,
+    ProcmemPolling,
+    ProcmemWatching { count: &'a dyn fmt::Display, mode: &'a dyn fmt::Display, min: &'a dyn fmt::Display },
+    ProcmemStarted { size: &'a dyn fmt::Display },
+    ProcmemGrew { delta: &'a dyn fmt::Display, total: &'a dyn fmt::Display },
+    ProcmemShrank { delta: &'a dyn fmt::Display, total: &'a dyn fmt::Display },
+    ProcmemExited { size: &'a dyn fmt::Display },
+    // profile
+    ProfileUnknownAction { action: &'a dyn fmt::Display },
+    ProfileUsage,
+    ProfileUnknown { name: &'a dyn fmt::Display, names: &'a dyn fmt::Display },
+    ProfileNeedsName,
+    ProfileNoSettings { dir: &'a dyn fmt::Display },
+    ProfileResettingFirst { name: &'a dyn fmt::Display },
+    ProfileApplying { name: &'a dyn fmt::Display, device: &'a dyn fmt::Display },
+    ProfileCannotSet { setting: &'a dyn fmt::Display, value: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    ProfileActive { name: &'a dyn fmt::Display },
+    UnknownGpu,
+    ProfileShowHeader { model: &'a dyn fmt::Display, device: &'a dyn fmt::Display, levels: &'a dyn fmt::Display },
+    ProfileActiveName { name: &'a dyn fmt::Display },
+    ProfileNoneActive,
+    ProfileCurrent,
+    ProfileResetting { name: &'a dyn fmt::Display },
+    ProfileNoneApplied,
+    ProfileStockRestored,
+    CouldNotRestore { path: &'a dyn fmt::Display, value: &'a dyn fmt::Display },
+    NotRestored { count: &'a dyn fmt::Display, path: &'a dyn fmt::Display },
+    // reset-stat
+    ResetStatFailed { context: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    ResetStatHeader { path: &'a dyn fmt::Display },
+    ResetStatOwnFd,
+    // restore
+    RestoreNothing { path: &'a dyn fmt::Display },
+    RestoreHeader { pid: &'a dyn fmt::Display, command: &'a dyn fmt::Display },
+    RestoreNoChanges,
+    RestoreRemaining { count: &'a dyn fmt::Display, path: &'a dyn fmt::Display },
+    // scan
+    ScanBaselineWithIoctls,
+    ScanHeader { path: &'a dyn fmt::Display, kernel: &'a dyn fmt::Display },
+    ScanAnswered { present: &'a dyn fmt::Display, total: &'a dyn fmt::Display },
+    SavedTo { path: &'a dyn fmt::Display },
+    ScanCompared { path: &'a dyn fmt::Display, kernel: &'a dyn fmt::Display },
+    ScanNoChanges,
+    ScanIoctlHeader { path: &'a dyn fmt::Display, max: u32 },
+    ScanUnknownIoctl,
+    ScanIoctlSummary { implemented: &'a dyn fmt::Display, total: &'a dyn fmt::Display, unknown: &'a dyn fmt::Display },
+    ScanAbsent { names: &'a dyn fmt::Display },
+    ScanErrnoLegend,
+    ScanAppeared { id: u32, name: &'a dyn fmt::Display, size: &'a dyn fmt::Display },
+    ScanDisappeared { id: u32, name: &'a dyn fmt::Display, size: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    ScanResized { id: u32, name: &'a dyn fmt::Display, old: &'a dyn fmt::Display, new: &'a dyn fmt::Display },
+    ScanChanged { id: u32, name: &'a dyn fmt::Display, ranges: &'a dyn fmt::Display },
+    // sched
+    SchedNoThreads,
+    SchedThreads,
+    SchedDispatcher,
+    SchedCpuBound,
+    // selftest
+    Yes,
+    No,
+    SelftestHeader { path: &'a dyn fmt::Display },
+    SelftestDeviceInfo,
+    SelftestChip { chip_id: u32 },
+    SelftestDriverVersion,
+    SelftestVersion { driver: &'a dyn fmt::Display, device: &'a dyn fmt::Display },
+    SelftestBinary,
+    SelftestContext,
+    SelftestAlloc,
+    SelftestSubmit,
+    SelftestTimestampFence,
+    SelftestHardwareFence,
+    SelftestFailed { failed: &'a dyn fmt::Display, total: &'a dyn fmt::Display },
+    SelftestPassed,
+    SelftestStatic { platform: &'a dyn fmt::Display },
+    SelftestDynamic { loader: &'a dyn fmt::Display },
+    SelftestCannotInspect { error: &'a dyn fmt::Display },
+    SelftestContextId { id: &'a dyn fmt::Display },
+    SelftestDestroyFailed { error: &'a dyn fmt::Display },
+    SelftestCreateFailed { error: &'a dyn fmt::Display },
+    GpuobjAllocFailed { error: &'a dyn fmt::Display },
+    SelftestMapped { id: &'a dyn fmt::Display, gpuaddr: u64 },
+    SelftestMmapFailed { error: &'a dyn fmt::Display },
+    SelftestRetired { ts: &'a dyn fmt::Display },
+    SelftestSignaled,
+    SelftestStatusAfterRetire { status: &'a dyn fmt::Display },
+    SelftestNoTimeline,
+    SelftestHardwareBacked,
+    SelftestSoftwareBacked,
+    SelftestTimelineOk { backing: &'a dyn fmt::Display },
+    SelftestUnexpected { before: &'a dyn fmt::Display, after: &'a dyn fmt::Display, value: &'a dyn fmt::Display },
+    SelftestHwFenceDetection,
+    SelftestA7xx { answer: &'a dyn fmt::Display },
+    SelftestTimelineIoctls { answer: &'a dyn fmt::Display },
+    SelftestKernelModule { module: &'a dyn fmt::Display },
+    SelftestComparedTo { name: &'a dyn fmt::Display },
+    // slumber
+    SlumberChanged { name: &'a dyn fmt::Display, old: &'a dyn fmt::Display, new: &'a dyn fmt::Display },
+    SlumberIdleTimer { error: &'a dyn fmt::Display },
+    SlumberHeader {
+        path: &'a dyn fmt::Display,
+        cycles: &'a dyn fmt::Display,
+        idle: &'a dyn fmt::Display,
+        settle: &'a dyn fmt::Display,
+        context: &'a dyn fmt::Display,
+    },
+    SlumberInitialFailed { error: &'a dyn fmt::Display },
+    SlumberRetiredMismatch { retired: &'a dyn fmt::Display, expected: &'a dyn fmt::Display },
+    SlumberReadRetiredFailed { error: &'a dyn fmt::Display },
+    SlumberCycle { cycle: &'a dyn fmt::Display, ts: &'a dyn fmt::Display, ms: &'a dyn fmt::Display },
+    SlumberSubmitted { ts: &'a dyn fmt::Display, previous: &'a dyn fmt::Display, retired: &'a dyn fmt::Display },
+    SlumberGlobalBackwards { old: &'a dyn fmt::Display, new: &'a dyn fmt::Display },
+    SlumberReadGlobalFailed { error: &'a dyn fmt::Display },
+    SlumberReadPropertiesFailed { error: &'a dyn fmt::Display },
+    SlumberCycleProblem { cycle: &'a dyn fmt::Display, problem: &'a dyn fmt::Display },
+    SlumberCouldNotRestore { path: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    SlumberIfpc { count: &'a dyn fmt::Display },
+    SlumberFailed { failures: &'a dyn fmt::Display, cycles: &'a dyn fmt::Display },
+    SlumberPassed { cycles: &'a dyn fmt::Display },
+    // sparse
+    SparseBadSize { size: &'a dyn fmt::Display, pagesize: &'a dyn fmt::Display },
+    SparseHeader { path: &'a dyn fmt::Display },
+    SparseUnsupported,
+    SparseSupported,
+    SparseVirtFailed { error: &'a dyn fmt::Display },
+    SparseVirt { id: &'a dyn fmt::Display, gpuaddr: u64, kb: &'a dyn fmt::Display },
+    SparsePhysFailed { error: &'a dyn fmt::Display },
+    SparsePhys { id: &'a dyn fmt::Display, kb: &'a dyn fmt::Display },
+    SparseBindFirst,
+    SparseBindRest,
+    SparseUnbind,
+    SparseFreed,
+    StepFailed { step: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    // stress
+    StressUnknownTarget { target: &'a dyn fmt::Display },
+    StressUsage,
+    StressZeroSize,
+    StressHeader { path: &'a dyn fmt::Display, mb: &'a dyn fmt::Display, iterations: &'a dyn fmt::Display },
+    StressErrors { count: &'a dyn fmt::Display },
+    StressSummary { count: &'a dyn fmt::Display, secs: &'a dyn fmt::Display },
+    StressVerified { mb: &'a dyn fmt::Display },
+    StressAllocFailures { count: &'a dyn fmt::Display },
+    StressMapFailures { count: &'a dyn fmt::Display },
+    StressSyncFailures { count: &'a dyn fmt::Display },
+    StressCorrupted { count: &'a dyn fmt::Display },
+    StressFailures { count: &'a dyn fmt::Display },
+    StressClean,
+    StressAllocFailed { kb: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    StressMmapFailed { id: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    StressSyncFailed { id: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    StressMismatch { id: &'a dyn fmt::Display, gpuaddr: u64, expected: u64, read: u64 },
+    // submit
+    SubmitMeasuring { count: &'a dyn fmt::Display },
+    SubmitUseNoBench { error: &'a dyn fmt::Display },
+    SubmitWritten { path: &'a dyn fmt::Display },
+    SubmitPrivacy,
+    // timesync
+    TimesyncHeader { path: &'a dyn fmt::Display },
+    TimesyncQtimer { gpuaddr: u64, size: &'a dyn fmt::Display },
+    TimesyncNoQtimer { error: &'a dyn fmt::Display },
+    TimesyncCpuSource,
+    TimesyncNow { ticks: &'a dyn fmt::Display, secs: &'a dyn fmt::Display },
+    TimesyncClockGettime { secs: &'a dyn fmt::Display },
+    TimesyncCpuQtimer { error: &'a dyn fmt::Display },
+    TimesyncAlwaysOnFailed { error: &'a dyn fmt::Display },
+    TimesyncGpuSource,
+    TimesyncNoAlwaysOn { error: &'a dyn fmt::Display },
+    TimesyncNoSource,
+    TimesyncNominal { mhz: &'a dyn fmt::Display },
+    TimesyncMeasured { mhz: &'a dyn fmt::Display, ppm: &'a dyn fmt::Display },
+    TimesyncUncertainty { ns: &'a dyn fmt::Display, samples: &'a dyn fmt::Display, span: &'a dyn fmt::Display },
+    // trace
+    TraceNeedsPid,
+    TraceAttachDenied { pid: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    TraceAttachFailed { pid: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    TraceHeader { pid: &'a dyn fmt::Display, threads: &'a dyn fmt::Display },
+    TraceExited { pid: &'a dyn fmt::Display },
+    TracePtraceFailed { error: &'a dyn fmt::Display },
+    TraceTotal { count: &'a dyn fmt::Display, secs: &'a dyn fmt::Display },
+    TraceSaved { count: &'a dyn fmt::Display, path: &'a dyn fmt::Display },
+    TraceWindow {
+        calls: &'a dyn fmt::Display,
+        submits: &'a dyn fmt::Display,
+        allocs: &'a dyn fmt::Display,
+        bytes: &'a dyn fmt::Display,
+        frees: &'a dyn fmt::Display,
+        errors: &'a dyn fmt::Display,
+    },
+    // triage
+    TriageHeader { device: &'a dyn fmt::Display },
+    TriageNoLog { error: &'a dyn fmt::Display },
+    TriageFaultLines { count: &'a dyn fmt::Display },
+    TriageSnapshot {
+        chip_id: u32,
+        owner: &'a dyn fmt::Display,
+        pid: &'a dyn fmt::Display,
+        context: &'a dyn fmt::Display,
+    },
+    TriageSnapshotNoDump { count: &'a dyn fmt::Display },
+    TriageNoSnapshot,
+    TriageContextList { reason: &'a dyn fmt::Display },
+    TriageNoReset,
+    TriageNoSuspect,
+    TriageSuspect { process: &'a dyn fmt::Display, pid: &'a dyn fmt::Display, context: &'a dyn fmt::Display },
+    TriageEvidence { evidence: &'a dyn fmt::Display },
+    TriageConflict { conflict: &'a dyn fmt::Display },
+    TriageSubmissions,
+    TriageContext {
+        id: &'a dyn fmt::Display,
+        kind: &'a dyn fmt::Display,
+        priority: &'a dyn fmt::Display,
+        queued: &'a dyn fmt::Display,
+        consumed: &'a dyn fmt::Display,
+        retired: &'a dyn fmt::Display,
+        inflight: &'a dyn fmt::Display,
+    },
+    TriageMemory { size: &'a dyn fmt::Display },
+    TriageExited,
+    TriagePidFaults { count: &'a dyn fmt::Display },
+    // turnip
+    TurnipNoCompatible,
+    TurnipNoDevice,
+    TurnipHeader,
+    TurnipGpu { model: &'a dyn fmt::Display, chip_id: u32 },
+    TurnipDriver { driver: &'a dyn fmt::Display },
+    TurnipAndroid { release: &'a dyn fmt::Display, sdk: &'a dyn fmt::Display },
+    TurnipNoAndroid,
+    TurnipSupported { mesa: &'a dyn fmt::Display },
+    TurnipExperimental { mesa: &'a dyn fmt::Display },
+    TurnipTooOld,
+    TurnipUnknown,
+    TurnipRequirements,
+    // usermem
+    MmapFailed { error: &'a dyn fmt::Display },
+    UsermemImporting { kb: &'a dyn fmt::Display, host: u64, path: &'a dyn fmt::Display },
+    UsermemImportFailed { error: &'a dyn fmt::Display },
+    UsermemMethod { method: &'a dyn fmt::Display },
+    UsermemSize { size: &'a dyn fmt::Display },
+    UsermemUnmapped,
+    // vamap
+    VamapHeader { path: &'a dyn fmt::Display },
+    VamapEmpty,
+    VamapGap,
+    VamapSummary { count: &'a dyn fmt::Display, used: &'a dyn fmt::Display, span: &'a dyn fmt::Display },
+    // wait-idle
+    WaitIdleDone { path: &'a dyn fmt::Display, ms: &'a dyn fmt::Display, retired: &'a dyn fmt::Display },
+    WaitIdleBusy { path: &'a dyn fmt::Display, secs: &'a dyn fmt::Display },
+    WaitIdleCannotRead { error: &'a dyn fmt::Display },
+    // Hilfe und Rechte
+    HelpUsage,
+    HelpCommands,
+    HelpGlobalOptions,
+    HelpExitCodes,
+    ReadOnly,
+    UserAndKeepRoot,
+    UserNeedsRoot,
+    CannotSwitchUser { user: &'a dyn fmt::Display, error: &'a dyn fmt::Display },
+    // info
+    InfoFeatures,
+    FeatureNotReported,
+    FeatureLikely,
+    FeatureUnlikely,
+    ZapHeader,
+    ZapConfigured { name: &'a dyn fmt::Display },
+    ZapNodeOnly,
+    ZapNotConfigured,
+    ZapFirmwareMissing,
+    ZapFirmware { path: &'a dyn fmt::Display },
+    ZapSecureSupported,
+    ZapSecureUnsupported,
+    ZapSecureUnknown,
+    ZapAlignment { bytes: &'a dyn fmt::Display },
+    SysrootNotDir { path: &'a dyn fmt::Display },
+    InfoBanner,
+    InfoReplaying { path: &'a dyn fmt::Display },
+    InfoFound { count: &'a dyn fmt::Display },
+    InfoSaved { count: &'a dyn fmt::Display, path: &'a dyn fmt::Display },
+    Core2dTitle,
+    Core2dDevice { name: &'a dyn fmt::Display },
+    Core2dTypical,
+    Core2dDeviceId { id: u32, core: &'a dyn fmt::Display },
+    Core2dMmu { state: &'a dyn fmt::Display },
+    Core2dWaitsSupported,
+    Core2dWaitsUnsupported,
+    Core2dWaitsUnknown,
+    Core2dNoChipId,
+    InfoForOtherProjects,
+    InfoError { error: &'a dyn fmt::Display },
+}
+
+impl fmt::Display for Msg<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let de = language() == Lang::De;
+        match *self {
+            Msg::NoDevices if de => write!(f, "Keine KGSL-Geräte gefunden!"),
+            Msg::NoDevices => write!(f, "No KGSL devices found!"),
+            Msg::CannotOpen { path, error } if de => write!(f, "Kann {} nicht öffnen: {}", path, error),
+            Msg::CannotOpen { path, error } => write!(f, "Cannot open {}: {}", path, error),
+            Msg::TryRoot if de => write!(f, "Als root versuchen: sudo ./adreno_ioctl"),
+            Msg::TryRoot => write!(f, "Try with root: sudo ./adreno_ioctl"),
+            Msg::IoctlFailed { error } if de => write!(f, "IOCTL fehlgeschlagen: {}", error),
+            Msg::IoctlFailed { error } => write!(f, "IOCTL failed: {}", error),
+            Msg::NoValidGpuData if de => write!(f, "Keine gültigen GPU-Daten empfangen"),
+            Msg::NoValidGpuData => write!(f, "No valid GPU data received"),
+            Msg::VersionUnavailable if de => {
+                write!(f, "Version property nicht verfügbar oder benötigt andere IOCTL")
+            }
+            Msg::VersionUnavailable => write!(f, "Version property not available or needs a different ioctl"),
+            Msg::UnknownCommand { command } if de => {
+                write!(f, "Unbekanntes Kommando: {} (siehe 'adreno_ioctl help')", command)
+            }
+            Msg::UnknownCommand { command } => write!(f, "Unknown command: {} (see 'adreno_ioctl help')", command),
+            Msg::UnknownArgument { arg } if de => write!(f, "Unbekanntes Argument: {}", arg),
+            Msg::UnknownArgument { arg } => write!(f, "Unknown argument: {}", arg),
+            Msg::RequiresValue { option } if de => write!(f, "{} braucht einen Wert", option),
+            Msg::RequiresValue { option } => write!(f, "{} requires a value", option),
+            Msg::InvalidValue { option, value } if de => write!(f, "Ungültiger Wert für {}: {}", option, value),
+            Msg::InvalidValue { option, value } => write!(f, "Invalid {}: {}", option, value),
+            Msg::InvalidDuration { text } if de => write!(f, "Ungültige Dauer: {}", text),
+            Msg::InvalidDuration { text } => write!(f, "Invalid duration: {}", text),
+            Msg::InvalidDurationUnit { text } if de => write!(f, "Ungültige Zeiteinheit: {}", text),
+            Msg::InvalidDurationUnit { text } => write!(f, "Invalid duration unit: {}", text),
+            Msg::InvalidSize { text } if de => write!(f, "Ungültige Größe: {}", text),
+            Msg::InvalidSize { text } => write!(f, "Invalid size: {}", text),
+            Msg::InvalidSizeUnit { text } if de => write!(f, "Ungültige Größeneinheit: {}", text),
+            Msg::InvalidSizeUnit { text } => write!(f, "Invalid size unit: {}", text),
+            Msg::Troubleshooting if de => write!(f, "Fehlersuche:"),
+            Msg::Troubleshooting => write!(f, "Troubleshooting:"),
+            Msg::CheckPermissions if de => write!(f, "Rechte prüfen: ls -la /dev/kgsl*"),
+            Msg::CheckPermissions => write!(f, "Check permissions: ls -la /dev/kgsl*"),
+            Msg::AlternativeIoctls if de => write!(f, "Alternative IOCTLs zum Ausprobieren:"),
+            Msg::AlternativeIoctls => write!(f, "Alternative IOCTLs to try:"),
+            // Allgemein
+            Msg::CannotRead { path, error } if de => write!(f, "{} nicht lesbar: {}", path, error),
+            Msg::CannotRead { path, error } => write!(f, "Cannot read {}: {}", path, error),
+            Msg::CannotWrite { path, error } if de => write!(f, "{} nicht schreibbar: {}", path, error),
+            Msg::CannotWrite { path, error } => write!(f, "Cannot write {}: {}", path, error),
+            Msg::CannotWriteRecord { error } if de => write!(f, "Datensatz nicht schreibbar: {}", error),
+            Msg::CannotWriteRecord { error } => write!(f, "Cannot write record: {}", error),
+            Msg::CannotSetUpSubmission { error } if de => write!(f, "Submission-Context nicht einrichtbar: {}", error),
+            Msg::CannotSetUpSubmission { error } => write!(f, "Cannot set up submission context: {}", error),
+            Msg::DroppedRoot { user } if de => write!(f, "Root-Rechte abgegeben, läuft jetzt als {}", user),
+            Msg::DroppedRoot { user } => write!(f, "Dropped root, now running as {}", user),
+            Msg::SamplingOn { placement } if de => write!(f, "Sampling auf {}", placement),
+            Msg::SamplingOn { placement } => write!(f, "Sampling on {}", placement),
+            Msg::Interrupted if de => write!(f, "Abgebrochen"),
+            Msg::Interrupted => write!(f, "Interrupted"),
+            Msg::InterruptedRestoring if de => write!(f, "Abgebrochen, stelle wieder her..."),
+            Msg::InterruptedRestoring => write!(f, "Interrupted, restoring..."),
+            // allocflags
+            Msg::AllocMatrix { path, kb } if de => write!(f, "Matrix der Allocation-Flags auf {} (je {} KB)", path, kb),
+            Msg::AllocMatrix { path, kb } => write!(f, "Allocation flag matrix on {} ({} KB each)", path, kb),
+            Msg::AllocDriverReturned { flags } if de => write!(f, "Treiber lieferte: {}", flags),
+            Msg::AllocDriverReturned { flags } => write!(f, "driver returned: {}", flags),
+            Msg::AllocReadbackMismatch if de => write!(f, "Rücklesen stimmt nicht"),
+            Msg::AllocReadbackMismatch => write!(f, "readback mismatch"),
+            Msg::AllocSummary { ok, total } if de => write!(f, "{}/{} Kombinationen alloziert", ok, total),
+            Msg::AllocSummary { ok, total } => write!(f, "{}/{} combinations allocated", ok, total),
+            Msg::AllocUbwcNote if de => {
+                write!(f, "UBWC ist kein Allocation-Flag - der UMD wählt es je Surface (siehe `caps`)")
+            }
+            Msg::AllocUbwcNote => {
+                write!(f, "UBWC is not an allocation flag - the UMD selects it per surface (see `caps`)")
+            }
+            // analyze
+            Msg::AnalyzeWindowTooShort if de => write!(f, "--window muss mindestens 1s sein"),
+            Msg::AnalyzeWindowTooShort => write!(f, "--window must be at least 1s"),
+            Msg::AnalyzeNoSamples { file } if de => {
+                write!(f, "Keine Samples in {} (aufzeichnen mit monitor --sink csv:FILE)", file)
+            }
+            Msg::AnalyzeNoSamples { file } => {
+                write!(f, "No sample records in {} (record with monitor --sink csv:FILE)", file)
+            }
+            Msg::AnalyzeSummary { file, samples, windows, secs } if de => {
+                write!(f, "{}: {} Samples, {} Fenster zu {:.0}s", file, samples, windows, secs)
+            }
+            Msg::AnalyzeSummary { file, samples, windows, secs } => {
+                write!(f, "{}: {} samples, {} window(s) of {:.0}s", file, samples, windows, secs)
+            }
+            // bench
+            Msg::BenchUnknown { name } if de => write!(f, "Unbekannter Benchmark: {}", name),
+            Msg::BenchUnknown { name } => write!(f, "Unknown benchmark: {}", name),
+            Msg::BenchUsage if de => write!(f, "Aufruf: adreno_ioctl bench ioctl|sysfs [OPTIONEN]"),
+            Msg::BenchUsage => write!(f, "Usage: adreno_ioctl bench ioctl|sysfs [OPTIONS]"),
+            Msg::BenchNoSysfsFiles { dir } if de => write!(f, "Keine lesbaren sysfs-Dateien unter {}", dir),
+            Msg::BenchNoSysfsFiles { dir } => write!(f, "No readable sysfs files under {}", dir),
+            Msg::BenchSysfsHeader { files, scrapes, dir } if de => {
+                write!(f, "sysfs-Benchmark: {} Dateien x {} Durchläufe in {}", files, scrapes, dir)
+            }
+            Msg::BenchSysfsHeader { files, scrapes, dir } => {
+                write!(f, "sysfs scrape benchmark: {} files x {} scrapes in {}", files, scrapes, dir)
+            }
+            Msg::BenchSysfsSpeedup { factor, ttl } if de => {
+                write!(f, "{:.1}x schneller als direktes Lesen (TTL {} ms, Durchläufe direkt hintereinander)", factor, ttl)
+            }
+            Msg::BenchSysfsSpeedup { factor, ttl } => {
+                write!(f, "{:.1}x faster than direct reads (TTL {} ms, back-to-back scrapes)", factor, ttl)
+            }
+            Msg::BenchIoctlHeader { samples, path } if de => {
+                write!(f, "ioctl-Sampling-Benchmark: {} Samples auf {}", samples, path)
+            }
+            Msg::BenchIoctlHeader { samples, path } => {
+                write!(f, "ioctl sampling benchmark: {} samples on {}", samples, path)
+            }
+            Msg::BenchCollectorFailed { error } if de => write!(f, "Collector fehlgeschlagen: {}", error),
+            Msg::BenchCollectorFailed { error } => write!(f, "Collector failed: {}", error),
+            Msg::BenchSetupSyscalls { count } if de => {
+                write!(f, "(+{} einmalige Syscalls für Geräteinfo, Property-Liste und Memstore)", count)
+            }
+            Msg::BenchSetupSyscalls { count } => {
+                write!(f, "(+{} one-time syscalls for device info, property list and memstore)", count)
+            }
+            Msg::BenchOverBudget { per_sample, budget } if de => {
+                write!(f, "Collector braucht {:.1} Syscalls pro Sample, Budget ist {}", per_sample, budget)
+            }
+            Msg::BenchOverBudget { per_sample, budget } => {
+                write!(f, "Collector needs {:.1} syscalls per sample, budget is {}", per_sample, budget)
+            }
+            Msg::BenchWithinBudget { budget } if de => write!(f, "Im Budget von {} Syscalls pro Sample", budget),
+            Msg::BenchWithinBudget { budget } => write!(f, "Within budget of {} syscalls per sample", budget),
+            // blob
+            Msg::BlobHeader if de => write!(f, "Adreno-Userspace-Treiber"),
+            Msg::BlobHeader => write!(f, "Adreno userspace driver"),
+            Msg::BlobNoneFound { path } if de => {
+                write!(f, "Keine Vendor-Blobs gefunden (gesucht: {} und verwandte)", path)
+            }
+            Msg::BlobNoneFound { path } => write!(f, "No vendor blobs found (looked for {} and friends)", path),
+            Msg::BlobUpdatable { package } if de => write!(f, "Aktualisierbares Treiberpaket: {}", package),
+            Msg::BlobUpdatable { package } => write!(f, "Updatable driver package: {}", package),
+            Msg::BlobNotInstalled if de => write!(f, "Nicht installiert oder /data/app nicht lesbar (root nötig)"),
+            Msg::BlobNotInstalled => write!(f, "Not installed, or /data/app not readable (root needed)"),
+            Msg::BlobOptInHint if de => {
+                write!(f, "Apps, die unter Entwickleroptionen > Grafiktreiber-Einstellungen zugeordnet sind, nutzen diesen Treiber")
+            }
+            Msg::BlobOptInHint => {
+                write!(f, "Apps opted in via Developer options > Graphics driver preferences use this driver")
+            }
+            Msg::BlobNoUpdatable if de => {
+                write!(f, "Kein aktualisierbarer Treiber konfiguriert (ro.gfx.driver.0 nicht gesetzt)")
+            }
+            Msg::BlobNoUpdatable => write!(f, "No updatable driver configured (ro.gfx.driver.0 unset)"),
+            Msg::BlobAlsoContains { version } if de => write!(f, "enthält auch V@{}", version),
+            Msg::BlobAlsoContains { version } => write!(f, "also contains V@{}", version),
+            Msg::BlobNoVersion { path } if de => write!(f, "{}: kein V@-Versionsstring", path),
+            Msg::BlobNoVersion { path } => write!(f, "{}: no V@ version string", path),
+            // boost
+            Msg::BoostLevelOutOfRange { level, count } if de => {
+                write!(f, "Level {} außerhalb des Bereichs (0..{})", level, count)
+            }
+            Msg::BoostLevelOutOfRange { level, count } => write!(f, "Level {} out of range (0..{})", level, count),
+            Msg::BoostTarget { level, mhz } if de => write!(f, "Ziel: Power Level {} ({} MHz)", level, mhz),
+            Msg::BoostTarget { level, mhz } => write!(f, "Target: power level {} ({} MHz)", level, mhz),
+            Msg::BoostSysfs { from, to, secs } if de => {
+                write!(f, "Boost: min_pwrlevel {} -> {} für {:.1}s", from, to, secs)
+            }
+            Msg::BoostSysfs { from, to, secs } => write!(f, "Boost: min_pwrlevel {} -> {} for {:.1}s", from, to, secs),
+            Msg::CannotRestore { path, error } if de => {
+                write!(f, "{} nicht wiederhergestellt: {} ('adreno_ioctl restore' ausführen)", path, error)
+            }
+            Msg::CannotRestore { path, error } => {
+                write!(f, "Could not restore {}: {} (run 'adreno_ioctl restore')", path, error)
+            }
+            Msg::BoostRestored { level } if de => write!(f, "min_pwrlevel auf {} zurückgesetzt", level),
+            Msg::BoostRestored { level } => write!(f, "Restored min_pwrlevel to {}", level),
+            Msg::BoostRestoreMismatch { now, expected } if de => {
+                write!(f, "min_pwrlevel ist nach dem Zurücksetzen {} (erwartet {})", now, expected)
+            }
+            Msg::BoostRestoreMismatch { now, expected } => {
+                write!(f, "min_pwrlevel is {} after restore (expected {})", now, expected)
+            }
+            Msg::BoostCannotVerify { error } if de => write!(f, "min_pwrlevel nicht prüfbar: {}", error),
+            Msg::BoostCannotVerify { error } => write!(f, "Could not verify min_pwrlevel: {}", error),
+            Msg::BoostFallback { error } if de => {
+                write!(f, "min_pwrlevel nicht schreibbar ({}), nutze Power-Constraint-Vote", error)
+            }
+            Msg::BoostFallback { error } => {
+                write!(f, "min_pwrlevel not writable ({}), using power constraint vote", error)
+            }
+            Msg::CannotCreateContext { error } if de => write!(f, "Context nicht anlegbar: {}", error),
+            Msg::CannotCreateContext { error } => write!(f, "Cannot create context: {}", error),
+            Msg::BoostConstraintFailed { error } if de => write!(f, "Power-Constraint fehlgeschlagen: {}", error),
+            Msg::BoostConstraintFailed { error } => write!(f, "Power constraint failed: {}", error),
+            Msg::BoostSubmitFailed { error } if de => {
+                write!(f, "Submission auf dem Vote-Context fehlgeschlagen: {}", error)
+            }
+            Msg::BoostSubmitFailed { error } => write!(f, "Submission on the vote context failed: {}", error),
+            Msg::BoostVote { context, secs, interval } if de => {
+                write!(f, "Boost: Constraint-Vote auf Context {} für {:.1}s (NOPs alle {} ms)", context, secs, interval)
+            }
+            Msg::BoostVote { context, secs, interval } => {
+                write!(f, "Boost: constraint vote on context {} for {:.1}s (NOPs every {} ms)", context, secs, interval)
+            }
+            Msg::BoostReleaseFailed { error } if de => write!(f, "Freigabe der Constraint fehlgeschlagen: {}", error),
+            Msg::BoostReleaseFailed { error } => write!(f, "Releasing constraint failed: {}", error),
+            Msg::BoostReleased if de => write!(f, "Constraint freigegeben"),
+            Msg::BoostReleased => write!(f, "Constraint released"),
+            Msg::BoostBusyFailed { error } if de => {
+                write!(f, "Beschäftigen des Boost-Contexts fehlgeschlagen: {}", error)
+            }
+            Msg::BoostBusyFailed { error } => write!(f, "Keeping the boost context busy failed: {}", error),
+            // bugreport
+            Msg::CannotCreate { path, error } if de => write!(f, "{} nicht anlegbar: {}", path, error),
+            Msg::CannotCreate { path, error } => write!(f, "Cannot create {}: {}", path, error),
+            Msg::WritingFailed { path, error } if de => write!(f, "Schreiben von {} fehlgeschlagen: {}", path, error),
+            Msg::WritingFailed { path, error } => write!(f, "Writing {} failed: {}", path, error),
+            Msg::BugreportWritten { path } if de => write!(f, "Fehlerbericht geschrieben nach {}", path),
+            Msg::BugreportWritten { path } => write!(f, "Bug report written to {}", path),
+            // bus
+            Msg::BusHeader { device } if de => write!(f, "GPU-Bus-Votes für {}", device),
+            Msg::BusHeader { device } => write!(f, "GPU bus votes for {}", device),
+            Msg::BusNoGpubw { dir } if de => write!(f, "devfreq: kein gpubw-Knoten unter {}", dir),
+            Msg::BusNoGpubw { dir } => write!(f, "devfreq: no gpubw node under {}", dir),
+            Msg::BusNoDtTable if de => write!(f, "DT: kein qcom,bus-table-ddr"),
+            Msg::BusNoDtTable => write!(f, "DT: no qcom,bus-table-ddr"),
+            Msg::BusDtTable { mhz } if de => write!(f, "DT-DDR-Tabelle: {} MHz", mhz),
+            Msg::BusDtTable { mhz } => write!(f, "DT DDR table: {} MHz", mhz),
+            Msg::BusMemory { label } if de => write!(f, "Speicher: {}", label),
+            Msg::BusMemory { label } => write!(f, "Memory: {}", label),
+            Msg::BusGpuLine { freq, level, busy } if de => write!(f, "GPU:  {} ({}), Last {}", freq, level, busy),
+            Msg::BusGpuLine { freq, level, busy } => write!(f, "GPU:  {} ({}), busy {}", freq, level, busy),
+            Msg::BusLevel { level } if de => write!(f, "Level {}", level),
+            Msg::BusLevel { level } => write!(f, "level {}", level),
+            Msg::BusLevelUnknown if de => write!(f, "Level ?"),
+            Msg::BusLevelUnknown => write!(f, "level ?"),
+            Msg::BusDtAllows { range } if de => write!(f, "DT:   dieses Level erlaubt DDR {}", range),
+            Msg::BusDtAllows { range } => write!(f, "DT:   this level allows DDR {}", range),
+            Msg::BusVoteTier { vote, tier, tiers, governor } if de => {
+                write!(f, "Bus:  gpubw-Vote {} (Stufe {}/{}, Governor {})", vote, tier, tiers, governor)
+            }
+            Msg::BusVoteTier { vote, tier, tiers, governor } => {
+                write!(f, "Bus:  gpubw vote {} (tier {}/{}, governor {})", vote, tier, tiers, governor)
+            }
+            Msg::BusVote { vote } if de => write!(f, "Bus:  gpubw-Vote {}", vote),
+            Msg::BusVote { vote } => write!(f, "Bus:  gpubw vote {}", vote),
+            Msg::BusNotReadable if de => write!(f, "Bus:  gpubw nicht lesbar"),
+            Msg::BusNotReadable => write!(f, "Bus:  gpubw not readable"),
+            Msg::BusStarved { busy, tier, tiers } if de => {
+                write!(f, "GPU auf Maximaltakt und {:.0}% ausgelastet, aber Bus-Vote nur Stufe {}/{} - womöglich fehlt Bandbreite", busy, tier, tiers)
+            }
+            Msg::BusStarved { busy, tier, tiers } => {
+                write!(f, "GPU at max clock and {:.0}% busy, but bus vote is only tier {}/{} - possibly bandwidth-starved", busy, tier, tiers)
+            }
+            // caps
+            Msg::CapsHeader { model, chip_id } if de => write!(f, "Fähigkeiten: {} (0x{:08x})", model, chip_id),
+            Msg::CapsHeader { model, chip_id } => write!(f, "Capability matrix: {} (0x{:08x})", model, chip_id),
+            Msg::CapsUnknownChip if de => write!(f, "(Chip nicht in der Datenbank - Hardware-Spalte unbekannt)"),
+            Msg::CapsUnknownChip => write!(f, "(chip not in database - hardware column unknown)"),
+            Msg::CapsAdvertised { count } if de => {
+                write!(f, "Treiber meldet {} Properties (KGSL_PROP_QUERY_CAPABILITIES):", count)
+            }
+            Msg::CapsAdvertised { count } => {
+                write!(f, "Driver advertises {} properties (KGSL_PROP_QUERY_CAPABILITIES):", count)
+            }
+            Msg::CapsNoQuery if de => {
+                write!(f, "Kein KGSL_PROP_QUERY_CAPABILITIES in diesem Kernel - Treiber-Spalte aus Proben")
+            }
+            Msg::CapsNoQuery => {
+                write!(f, "No KGSL_PROP_QUERY_CAPABILITIES in this kernel - driver column from probing")
+            }
+            // collect
+            Msg::CollectDuplicate { path, name } if de => {
+                write!(f, "{}: Collector '{}' existiert bereits, übersprungen", path, name)
+            }
+            Msg::CollectDuplicate { path, name } => {
+                write!(f, "{}: a collector named '{}' already exists, skipped", path, name)
+            }
+            Msg::CollectList { device } if de => write!(f, "Collectors für {}:", device),
+            Msg::CollectList { device } => write!(f, "Collectors for {}:", device),
+            Msg::CollectSupported if de => write!(f, "unterstützt"),
+            Msg::CollectSupported => write!(f, "supported"),
+            Msg::CollectNotAvailable if de => write!(f, "nicht verfügbar"),
+            Msg::CollectNotAvailable => write!(f, "not available"),
+            Msg::CollectNoneAvailable if de => write!(f, "Kein ausgewählter Collector ist auf diesem System verfügbar"),
+            Msg::CollectNoneAvailable => write!(f, "No selected collector is available on this system"),
+            // contexts
+            Msg::ContextsUnavailable { reason } if de => write!(f, "Context-Liste nicht verfügbar: {}", reason),
+            Msg::ContextsUnavailable { reason } => write!(f, "Context list unavailable: {}", reason),
+            Msg::ContextsDebugfsOnly if de => write!(f, "KGSL listet Contexts systemweit nur in debugfs"),
+            Msg::ContextsDebugfsOnly => write!(f, "KGSL lists contexts system-wide only in debugfs"),
+            Msg::ContextsHeader { device, root } if de => write!(f, "GPU-Contexts auf {} ({})", device, root),
+            Msg::ContextsHeader { device, root } => write!(f, "GPU contexts on {} ({})", device, root),
+            Msg::None if de => write!(f, "(keine)"),
+            Msg::None => write!(f, "(none)"),
+            Msg::ContextsSummary { contexts, processes } if de => {
+                write!(f, "{} Contexts in {} Prozessen", contexts, processes)
+            }
+            Msg::ContextsSummary { contexts, processes } => {
+                write!(f, "{} contexts in {} processes", contexts, processes)
+            }
+            Msg::ContextsExitedOwner { name, pid, count } if de => {
+                write!(f, "{} ({}) ist beendet, besitzt aber noch {} Contexts", name, pid, count)
+            }
+            Msg::ContextsExitedOwner { name, pid, count } => {
+                write!(f, "{} ({}) has exited but still owns {} contexts", name, pid, count)
+            }
+            Msg::ContextsLeak { name, pid, count } if de => {
+                write!(f, "{} ({}) besitzt {} Contexts - mögliches Context-Leck", name, pid, count)
+            }
+            Msg::ContextsLeak { name, pid, count } => {
+                write!(f, "{} ({}) owns {} contexts - possible context leak", name, pid, count)
+            }
+            // completions
+            Msg::UnknownShell { shell } if de => write!(f, "Unbekannte Shell: {} (bash, zsh oder fish)", shell),
+            Msg::UnknownShell { shell } => write!(f, "Unknown shell: {} (bash, zsh or fish)", shell),
+            // cores
+            Msg::CoresMeasureUnsupported if de => {
+                write!(f, "--measure wird nicht unterstützt: der ALU-Abgleich braucht einen Compute-Dispatch")
+            }
+            Msg::CoresMeasureUnsupported => {
+                write!(f, "--measure is not supported: the ALU cross-check needs a compute dispatch")
+            }
+            Msg::NotInChipDatabase { model } if de => write!(f, "{} ist nicht in der Chip-Datenbank", model),
+            Msg::NotInChipDatabase { model } => write!(f, "{} is not in the chip database", model),
+            Msg::CoresHeader { path, name } if de => write!(f, "Shader-Kerne auf {} ({})", path, name),
+            Msg::CoresHeader { path, name } => write!(f, "Shader cores on {} ({})", path, name),
+            Msg::CoresDriverModel { model } if de => write!(f, "Treibermodell:    {}", model),
+            Msg::CoresDriverModel { model } => write!(f, "Driver model:     {}", model),
+            Msg::CoresSp { count } if de => write!(f, "SP-Kerne:         {}", count),
+            Msg::CoresSp { count } => write!(f, "SP cores:         {}", count),
+            Msg::CoresMicroTp { count } if de => write!(f, "Micro-TPs:        {}", count),
+            Msg::CoresMicroTp { count } => write!(f, "Micro-TPs:        {}", count),
+            Msg::CoresAlus { count } if de => write!(f, "FP32-ALUs:        {}", count),
+            Msg::CoresAlus { count } => write!(f, "FP32 ALUs:        {}", count),
+            Msg::CoresWaveSize { fibers } if de => write!(f, "Wave-Größe:       {} Fibers", fibers),
+            Msg::CoresWaveSize { fibers } => write!(f, "Wave size:        {} fibers", fibers),
+            Msg::CoresMaxWaves { total, per_sp } if de => write!(f, "Max. Waves:       {} ({} pro SP)", total, per_sp),
+            Msg::CoresMaxWaves { total, per_sp } => write!(f, "Max waves:        {} ({} per SP)", total, per_sp),
+            Msg::CoresFromDatabase if de => {
+                write!(f, "Aus der Chip-Datenbank (typische Werte, nicht von der Hardware abgefragt)")
+            }
+            Msg::CoresFromDatabase => write!(f, "From the chip database (typical values, not queried from hardware)"),
+            Msg::CoresMemory if de => write!(f, "Speicher:"),
+            Msg::CoresMemory => write!(f, "Memory:"),
+            Msg::CoresBusWidth { bits } if de => write!(f, "Busbreite:        {} Bit", bits),
+            Msg::CoresBusWidth { bits } => write!(f, "Bus width:        {} bit", bits),
+            Msg::CoresUche { size } if de => write!(f, "UCHE (GPU-L2):    {} (geschätzt)", size),
+            Msg::CoresUche { size } => write!(f, "UCHE (GPU L2):    {} (estimate)", size),
+            Msg::CoresCacheLine { bytes } if de => write!(f, "Cache-Line:       {} Bytes", bytes),
+            Msg::CoresCacheLine { bytes } => write!(f, "Cache line:       {} bytes", bytes),
+            Msg::CoresMinAccessDriver { bytes } if de => {
+                write!(f, "Min. Zugriff:     {} Bytes (KGSL_PROP_MIN_ACCESS_LENGTH)", bytes)
+            }
+            Msg::CoresMinAccessDriver { bytes } => {
+                write!(f, "Min access:       {} bytes (KGSL_PROP_MIN_ACCESS_LENGTH)", bytes)
+            }
+            Msg::CoresMinAccessTypical { bytes } if de => {
+                write!(f, "Min. Zugriff:     {} Bytes (typisch, vom Treiber nicht gemeldet)", bytes)
+            }
+            Msg::CoresMinAccessTypical { bytes } => {
+                write!(f, "Min access:       {} bytes (typical, not reported by the driver)", bytes)
+            }
+            Msg::CoresCoalesce { bytes } if de => {
+                write!(f, "Zugriffe unter {} Bytes kosten trotzdem {} Bytes Bandbreite - zu vollen Lines zusammenfassen", bytes, bytes)
+            }
+            Msg::CoresCoalesce { bytes } => {
+                write!(f, "Accesses below {} bytes still cost {} bytes of bandwidth - coalesce to full lines", bytes, bytes)
+            }
+            Msg::CoresGflopsMax { gflops, mhz } if de => {
+                write!(f, "Theoretisch FP32: {:.0} GFLOPS bei {} MHz (max)", gflops, mhz)
+            }
+            Msg::CoresGflopsMax { gflops, mhz } => {
+                write!(f, "Theoretical FP32: {:.0} GFLOPS at {} MHz (max)", gflops, mhz)
+            }
+            Msg::CoresGflopsCurrent { gflops, mhz } if de => {
+                write!(f, "                  {:.0} GFLOPS bei {} MHz (aktuell)", gflops, mhz)
+            }
+            Msg::CoresGflopsCurrent { gflops, mhz } => {
+                write!(f, "                  {:.0} GFLOPS at {} MHz (current)", gflops, mhz)
+            }
+            // daemon
+            Msg::CannotQuery { path, error } if de => write!(f, "{} nicht abfragbar: {}", path, error),
+            Msg::CannotQuery { path, error } => write!(f, "Cannot query {}: {}", path, error),
+            Msg::CannotBind { path, error } if de => write!(f, "{} nicht bindbar: {}", path, error),
+            Msg::CannotBind { path, error } => write!(f, "Cannot bind {}: {}", path, error),
+            Msg::DaemonListening { device, socket, interval, max_interval, keep } if de => {
+                write!(f, "Daemon für {} wartet auf {} (Sample alle {} ms, bis {} ms im Leerlauf, {}s Verlauf)", device, socket, interval, max_interval, keep)
+            }
+            Msg::DaemonListening { device, socket, interval, max_interval, keep } => {
+                write!(f, "Daemon for {} listening on {} (sample every {} ms, up to {} ms when idle, keep {}s)", device, socket, interval, max_interval, keep)
+            }
+            Msg::AcceptFailed { error } if de => write!(f, "accept fehlgeschlagen: {}", error),
+            Msg::AcceptFailed { error } => write!(f, "accept failed: {}", error),
+            Msg::DaemonStopped if de => write!(f, "Daemon beendet"),
+            Msg::DaemonStopped => write!(f, "Daemon stopped"),
+            Msg::CannotListen { addr, error } if de => write!(f, "Kann nicht auf {} lauschen: {}", addr, error),
+            Msg::CannotListen { addr, error } => write!(f, "Cannot listen on {}: {}", addr, error),
+            Msg::DashboardOn { addr } if de => write!(f, "Dashboard auf http://{}/", addr),
+            Msg::DashboardOn { addr } => write!(f, "Dashboard on http://{}/", addr),
+            Msg::HttpFeatureMissing if de => {
+                write!(f, "--http braucht einen Build mit dem Feature 'http' (cargo build --features http)")
+            }
+            Msg::HttpFeatureMissing => {
+                write!(f, "--http needs a build with the 'http' feature (cargo build --features http)")
+            }
+            // debugfs
+            Msg::DebugfsUnavailable { reason } if de => write!(f, "KGSL-debugfs nicht verfügbar: {}", reason),
+            Msg::DebugfsUnavailable { reason } => write!(f, "KGSL debugfs unavailable: {}", reason),
+            Msg::DebugfsOnlyThere if de => {
+                write!(f, "Speicherlisten, Context-Listen und Dispatcher-Zustand gibt es nur dort")
+            }
+            Msg::DebugfsOnlyThere => {
+                write!(f, "Memory lists, context lists and dispatcher state are only exposed there")
+            }
+            Msg::DebugfsHeader { root } if de => write!(f, "KGSL-debugfs unter {}", root),
+            Msg::DebugfsHeader { root } => write!(f, "KGSL debugfs at {}", root),
+            Msg::DebugfsMemoryLists { count } if de => write!(f, "Speicherlisten: {} Prozesse", count),
+            Msg::DebugfsMemoryLists { count } => write!(f, "Memory lists: {} processes", count),
+            Msg::DebugfsProcess { pid, name, count, size } if de => {
+                write!(f, "{:>6} {:<20} {:>5} Allokationen, {:>10}", pid, name, count, size)
+            }
+            Msg::DebugfsProcess { pid, name, count, size } => {
+                write!(f, "{:>6} {:<20} {:>5} allocations, {:>10}", pid, name, count, size)
+            }
+            Msg::DebugfsGlobal { count, size } if de => write!(f, "global  {} Allokationen, {}", count, size),
+            Msg::DebugfsGlobal { count, size } => write!(f, "global  {} allocations, {}", count, size),
+            Msg::DebugfsContexts { count } if de => write!(f, "Contexts: {}", count),
+            Msg::DebugfsContexts { count } => write!(f, "Contexts: {}", count),
+            Msg::DebugfsContext { id, process, queued, retired, inflight } if de => {
+                write!(f, "ctx {:>4} {:<20} eingereiht {:>8} fertig {:>8} laufend {}", id, process, queued, retired, inflight)
+            }
+            Msg::DebugfsContext { id, process, queued, retired, inflight } => {
+                write!(f, "ctx {:>4} {:<20} queued {:>8} retired {:>8} in flight {}", id, process, queued, retired, inflight)
+            }
+            // driver
+            Msg::DriverUserspaceBlob { version, path } if de => write!(f, "Userspace-Blob: {} ({})", version, path),
+            Msg::DriverUserspaceBlob { version, path } => write!(f, "Userspace blob: {} ({})", version, path),
+            Msg::DriverHeader if de => write!(f, "Treiber-Build:"),
+            Msg::DriverHeader => write!(f, "Driver Build:"),
+            Msg::DriverBuiltin if de => write!(f, "eingebaut"),
+            Msg::DriverBuiltin => write!(f, "built-in"),
+            Msg::DriverModule if de => write!(f, "Modul"),
+            Msg::DriverModule => write!(f, "module"),
+            Msg::DriverNoModule if de => write!(f, "KGSL: kein Eintrag unter /sys/module"),
+            Msg::DriverNoModule => write!(f, "KGSL: no entry under /sys/module"),
+            Msg::DriverTreeFromDriver { tree } if de => write!(f, "Property-Map: {} (vom Treiber)", tree),
+            Msg::DriverTreeFromDriver { tree } => write!(f, "Property map: {} (from driver)", tree),
+            Msg::DriverTreeFromRelease { tree } if de => write!(f, "Property-Map: {} (aus dem Kernel-Release)", tree),
+            Msg::DriverTreeFromRelease { tree } => write!(f, "Property map: {} (from kernel release)", tree),
+            Msg::DriverParameters if de => write!(f, "Parameter:"),
+            Msg::DriverParameters => write!(f, "Parameters:"),
+            // dt
+            Msg::DtNoGpuNode if de => write!(f, "Kein Adreno-GPU-Knoten in /proc/device-tree gefunden"),
+            Msg::DtNoGpuNode => write!(f, "No Adreno GPU node found in /proc/device-tree"),
+            Msg::DtHeader { path } if de => write!(f, "GPU-Knoten im Device Tree: {}", path),
+            Msg::DtHeader { path } => write!(f, "Device Tree GPU node: {}", path),
+            Msg::DtZapShader { name } if de => write!(f, "Zap-Shader: {}", name),
+            Msg::DtZapShader { name } => write!(f, "Zap shader: {}", name),
+            Msg::DtZapNoName if de => write!(f, "Zap-Shader: Knoten vorhanden, kein Firmware-Name"),
+            Msg::DtZapNoName => write!(f, "Zap shader: node present, no firmware name"),
+            Msg::DtZapNone if de => write!(f, "Zap-Shader: nicht konfiguriert"),
+            Msg::DtZapNone => write!(f, "Zap shader: not configured"),
+            Msg::DtNoTables if de => write!(f, "Keine qcom,gpu-pwrlevels- oder OPP-Tabelle gefunden"),
+            Msg::DtNoTables => write!(f, "No qcom,gpu-pwrlevels or OPP table found"),
+            Msg::DtSpeedBin { bin } if de => write!(f, "Speed-Bin {}", bin),
+            Msg::DtSpeedBin { bin } => write!(f, "speed bin {}", bin),
+            Msg::DtDefault if de => write!(f, "Standard"),
+            Msg::DtDefault => write!(f, "default"),
+            Msg::DtPowerLevels { bin } if de => write!(f, "Power Levels ({})", bin),
+            Msg::DtPowerLevels { bin } => write!(f, "Power levels ({})", bin),
+            Msg::DtActive if de => write!(f, "aktiv"),
+            Msg::DtActive => write!(f, "active"),
+            Msg::DtRuntimeMismatch { mhz } if de => {
+                write!(f, "Laufzeit-Tabelle ({} MHz) passt zu keiner DT-Tabelle - Speed-Bin / Kernel-Grenzen prüfen", mhz)
+            }
+            Msg::DtRuntimeMismatch { mhz } => {
+                write!(f, "Runtime table ({} MHz) matches no DT table - check speed bin / kernel caps", mhz)
+            }
+            Msg::DtOppUnreadable { dir, error } if de => {
+                write!(f, "Laufzeit-OPP-Spannungen: {} nicht lesbar ({})", dir, error)
+            }
+            Msg::DtOppUnreadable { dir, error } => write!(f, "Runtime OPP voltages: {} not readable ({})", dir, error),
+            Msg::DtRuntimeOpp { dir, device } if de => write!(f, "Laufzeit-OPP-Tabelle ({}/{})", dir, device),
+            Msg::DtRuntimeOpp { dir, device } => write!(f, "Runtime OPP table ({}/{})", dir, device),
+            // events
+            Msg::EventsUnknown { event, expected } if de => {
+                write!(f, "Unbekanntes Ereignis '{}' (erwartet: {})", event, expected)
+            }
+            Msg::EventsUnknown { event, expected } => write!(f, "Unknown event '{}' (expected {})", event, expected),
+            Msg::EventsNoFtrace { error } if de => write!(f, "ftrace nicht nutzbar: {} (braucht root)", error),
+            Msg::EventsNoFtrace { error } => write!(f, "Cannot use ftrace: {} (needs root)", error),
+            Msg::EventsHeader { path, kinds, mode } if de => {
+                write!(f, "GPU-Ereignisse auf {} ({}, {})", path, kinds, mode)
+            }
+            Msg::EventsHeader { path, kinds, mode } => write!(f, "GPU events on {} ({}, {})", path, kinds, mode),
+            Msg::EventsPolling if de => write!(f, "sysfs + Polling des Kernel-Logs"),
+            Msg::EventsPolling => write!(f, "sysfs + kernel log polling"),
+            Msg::EventsCannotRead { error } if de => write!(f, "Ereignisse nicht lesbar: {}", error),
+            Msg::EventsCannotRead { error } => write!(f, "Cannot read events: {}", error),
+            // explain
+            Msg::ExplainInvalid { arg } if de => write!(f, "Ungültige IOCTL-Nummer: {}", arg),
+            Msg::ExplainInvalid { arg } => write!(f, "Invalid ioctl number: {}", arg),
+            Msg::ExplainDirection { dir } if de => write!(f, "Richtung: {}", dir),
+            Msg::ExplainDirection { dir } => write!(f, "Direction: {}", dir),
+            Msg::ExplainCommand { nr } if de => write!(f, "Kommando: 0x{:02x}", nr),
+            Msg::ExplainCommand { nr } => write!(f, "Command: 0x{:02x}", nr),
+            Msg::ExplainSize { bytes } if de => write!(f, "Größe: {} Bytes", bytes),
+            Msg::ExplainSize { bytes } => write!(f, "Size: {} bytes", bytes),
+            Msg::ExplainMatches if de => write!(f, "Entspricht der Request-Nummer dieses Tools"),
+            Msg::ExplainMatches => write!(f, "Matches the request number used by this tool"),
+            Msg::ExplainToolUses { request, dir, bytes } if de => {
+                write!(f, "Dieses Tool nutzt 0x{:08x} ({}, {} Bytes)", request, dir, bytes)
+            }
+            Msg::ExplainToolUses { request, dir, bytes } => {
+                write!(f, "This tool uses 0x{:08x} ({}, {} bytes)", request, dir, bytes)
+            }
+            Msg::ExplainUnknownKgsl if de => write!(f, "Unbekanntes KGSL-Kommando"),
+            Msg::ExplainUnknownKgsl => write!(f, "Unknown KGSL command"),
+            // export
+            Msg::ExportNeedsFormat if de => write!(f, "export braucht ein Format: --vkjson"),
+            Msg::ExportNeedsFormat => write!(f, "export needs a format: --vkjson"),
+            Msg::ExportWritten { path } if de => write!(f, "Capability-JSON im Vulkan-Stil nach {} geschrieben", path),
+            Msg::ExportWritten { path } => write!(f, "Vulkan-style capability JSON written to {}", path),
+            // farm
+            Msg::FarmNoDevices if de => write!(f, "Keine adb-Geräte im Zustand 'device' ('adb devices' prüfen)"),
+            Msg::FarmNoDevices => write!(f, "No adb devices in state 'device' (check 'adb devices')"),
+            Msg::FarmNoBinary { error } if de => write!(f, "Eigenes Binary nicht gefunden: {}", error),
+            Msg::FarmNoBinary { error } => write!(f, "Cannot locate own binary: {}", error),
+            Msg::NotAFile { path } if de => write!(f, "{}: keine Datei", path),
+            Msg::NotAFile { path } => write!(f, "{}: not a file", path),
+            Msg::FarmRunning { command, count } if de => write!(f, "Führe '{}' auf {} Geräten aus", command, count),
+            Msg::FarmRunning { command, count } => write!(f, "Running '{}' on {} devices", command, count),
+            Msg::FarmSummary { ok, failed } if de => write!(f, "{} ok, {} fehlgeschlagen", ok, failed),
+            Msg::FarmSummary { ok, failed } => write!(f, "{} ok, {} failed", ok, failed),
+            // fence
+            Msg::SubmitFailed { error } if de => write!(f, "Submit fehlgeschlagen: {}", error),
+            Msg::SubmitFailed { error } => write!(f, "Submit failed: {}", error),
+            Msg::FenceSyncFiles { count, pid } if de => write!(f, "{} sync_file(s) gehalten von PID {}", count, pid),
+            Msg::FenceSyncFiles { count, pid } => write!(f, "{} sync_file(s) held by pid {}", count, pid),
+            Msg::FenceCannotOpen { fd, error } if de => write!(f, "fd {}: nicht zu öffnen ({})", fd, error),
+            Msg::FenceCannotOpen { fd, error } => write!(f, "fd {}: cannot open ({})", fd, error),
+            Msg::FenceEventFailed { error } if de => write!(f, "TIMESTAMP_EVENT (Fence) fehlgeschlagen: {}", error),
+            Msg::FenceEventFailed { error } => write!(f, "TIMESTAMP_EVENT (fence) failed: {}", error),
+            Msg::FenceCreated { context, timestamp, path } if de => {
+                write!(f, "Fence für Kontext {} Timestamp {} auf {}", context, timestamp, path)
+            }
+            Msg::FenceCreated { context, timestamp, path } => {
+                write!(f, "Fence for context {} timestamp {} on {}", context, timestamp, path)
+            }
+            Msg::FenceNotSyncFile { label, error } if de => write!(f, "{}: kein sync_file ({})", label, error),
+            Msg::FenceNotSyncFile { label, error } => write!(f, "{}: not a sync_file ({})", label, error),
+            Msg::FenceStatus { status } if de => write!(f, "Status:      {}", status),
+            Msg::FenceStatus { status } => write!(f, "Status:      {}", status),
+            Msg::FenceSignaled { ms } if de => write!(f, "Signalisiert: vor {:.3} ms", ms),
+            Msg::FenceSignaled { ms } => write!(f, "Signaled:    {:.3} ms ago", ms),
+            Msg::FenceOwner { context, timestamp } if de => {
+                write!(f, "Besitzer:    Kontext {} Timestamp {}", context, timestamp)
+            }
+            Msg::FenceOwner { context, timestamp } => {
+                write!(f, "Owner:       context {} timestamp {}", context, timestamp)
+            }
+            Msg::FenceOwnerKgsl { context } if de => write!(f, "Besitzer:    KGSL-Kontext {}", context),
+            Msg::FenceOwnerKgsl { context } => write!(f, "Owner:       KGSL context {}", context),
+            Msg::FenceActive if de => write!(f, "aktiv"),
+            Msg::FenceActive => write!(f, "active"),
+            Msg::FenceSignaledState if de => write!(f, "signalisiert"),
+            Msg::FenceSignaledState => write!(f, "signaled"),
+            Msg::FenceError { code, error } if de => write!(f, "Fehler {} ({})", code, error),
+            Msg::FenceError { code, error } => write!(f, "error {} ({})", code, error),
+            // frametime
+            Msg::FrametimeNoContexts { pid, dir } if de => {
+                write!(f, "Keine Kontexte von PID {} unter {} (debugfs gemountet? als root?)", pid, dir)
+            }
+            Msg::FrametimeNoContexts { pid, dir } => {
+                write!(f, "No contexts of PID {} under {} (debugfs mounted? running as root?)", pid, dir)
+            }
+            Msg::FrametimeMeasuring { pid, secs, poll, merge } if de => {
+                write!(f, "Messe GPU-Frame-Zeiten von PID {} für {:.0}s (Poll {} µs, Merge-Lücke {} ms)", pid, secs, poll, merge)
+            }
+            Msg::FrametimeMeasuring { pid, secs, poll, merge } => {
+                write!(f, "Measuring GPU frame times of PID {} for {:.0}s (poll {} µs, merge gap {} ms)", pid, secs, poll, merge)
+            }
+            Msg::FrametimeNoFrames if de => write!(f, "Keine Frames beobachtet - rendert die App?"),
+            Msg::FrametimeNoFrames => write!(f, "No frames observed - is the app rendering?"),
+            Msg::FrametimeContexts { count } if de => write!(f, "Kontexte:    {}", count),
+            Msg::FrametimeContexts { count } => write!(f, "Contexts:    {}", count),
+            Msg::FrametimeFrames { frames, fps } if de => write!(f, "Frames:      {} ({:.1} fps)", frames, fps),
+            Msg::FrametimeFrames { frames, fps } => write!(f, "Frames:      {} ({:.1} fps)", frames, fps),
+            Msg::FrametimeLatency { avg, median, p99, max } if de => {
+                write!(f, "Frame-Zeit:  Mittel {:.2} ms, Median {:.2} ms, p99 {:.2} ms, max {:.2} ms", avg, median, p99, max)
+            }
+            Msg::FrametimeLatency { avg, median, p99, max } => {
+                write!(f, "Frame time:  avg {:.2} ms, median {:.2} ms, p99 {:.2} ms, max {:.2} ms", avg, median, p99, max)
+            }
+            Msg::FrametimeJanks { janks, factor } if de => {
+                write!(f, "Janks:       {} (Frames über {:.1}x dem Median)", janks, factor)
+            }
+            Msg::FrametimeJanks { janks, factor } => {
+                write!(f, "Janks:       {} (frames over {:.1}x the median)", janks, factor)
+            }
+            Msg::FrametimeBasis if de => write!(f, "Basiert auf GPU-Retire-Zeiten, nicht auf der Display-Präsentation"),
+            Msg::FrametimeBasis => write!(f, "Based on GPU retire times, not display presentation"),
+            // get
+            Msg::GetUnknownField { field, names } if de => {
+                write!(f, "Unbekanntes Feld: {} (eines von: {})", field, names)
+            }
+            Msg::GetUnknownField { field, names } => write!(f, "Unknown field: {} (one of: {})", field, names),
+            Msg::GetFieldAndFormat if de => write!(f, "Entweder ein Feld oder --format angeben, nicht beides"),
+            Msg::GetFieldAndFormat => write!(f, "Use either a field or --format, not both"),
+            Msg::GetNeedsField if de => write!(f, "get braucht ein Feld oder --format"),
+            Msg::GetNeedsField => write!(f, "get needs a field or --format"),
+            Msg::GetFields { names } if de => write!(f, "Felder: {}", names),
+            Msg::GetFields { names } => write!(f, "Fields: {}", names),
+            Msg::GetPlaceholders if de => write!(f, "Platzhalter für --format:"),
+            Msg::GetPlaceholders => write!(f, "Placeholders for --format:"),
+            Msg::GetUnclosed { key } if de => write!(f, "Nicht geschlossener Platzhalter {{{} in --format", key),
+            Msg::GetUnclosed { key } => write!(f, "Unclosed placeholder {{{} in --format", key),
+            Msg::GetUnknownPlaceholder { key, names } if de => {
+                write!(f, "Unbekannter Platzhalter {{{}}} (einer von: {})", key, names)
+            }
+            Msg::GetUnknownPlaceholder { key, names } => write!(f, "Unknown placeholder {{{}}} (one of: {})", key, names),
+            Msg::GetUnmatchedBrace if de => write!(f, "Unmatched '}}' in --format ('}}}}' für eine wörtliche Klammer)"),
+            Msg::GetUnmatchedBrace => write!(f, "Unmatched '}}' in --format (use '}}}}' for a literal brace)"),
+            Msg::CannotReadFrom { key, path, error } if de => {
+                write!(f, "Kann {} nicht aus {} lesen: {}", key, path, error)
+            }
+            Msg::CannotReadFrom { key, path, error } => write!(f, "Cannot read {} from {}: {}", key, path, error),
+            Msg::NoFrequencies if de => write!(f, "Keine verfügbaren Frequenzen gemeldet"),
+            Msg::NoFrequencies => write!(f, "No available frequencies reported"),
+            Msg::GmemUnknown { model } if de => write!(f, "GMEM-Größe für {} unbekannt", model),
+            Msg::GmemUnknown { model } => write!(f, "GMEM size unknown for {}", model),
+            // gmem
+            Msg::GmemNoSource { model } if de => {
+                write!(f, "GMEM-Größe unbekannt: der Treiber meldet sie nicht und {} ist nicht in der Chip-Datenbank", model)
+            }
+            Msg::GmemNoSource { model } => {
+                write!(f, "GMEM size unknown: driver doesn't report it and {} is not in the chip database", model)
+            }
+            Msg::GmemHeader { path, model } if de => write!(f, "GMEM auf {} ({})", path, model),
+            Msg::GmemHeader { path, model } => write!(f, "GMEM on {} ({})", path, model),
+            Msg::GmemFromDriver if de => write!(f, "Treiber"),
+            Msg::GmemFromDriver => write!(f, "driver"),
+            Msg::GmemFromDatabase if de => write!(f, "Chip-Datenbank"),
+            Msg::GmemFromDatabase => write!(f, "chip database"),
+            Msg::GmemSize { kb, source } if de => write!(f, "Größe:       {} KB (aus {})", kb, source),
+            Msg::GmemSize { kb, source } => write!(f, "Size:        {} KB (from {})", kb, source),
+            Msg::GmemBase { base } if de => write!(f, "Basis:       0x{:08x}", base),
+            Msg::GmemBase { base } => write!(f, "Base:        0x{:08x}", base),
+            Msg::GmemBaseShort { base } if de => write!(f, "Basis:       0x{:08x} (kurzes devinfo)", base),
+            Msg::GmemBaseShort { base } => write!(f, "Base:        0x{:08x} (short devinfo)", base),
+            Msg::GmemSlices { ccus, kb } if de => write!(f, "Slices:      {} CCU(s), je {} KB", ccus, kb),
+            Msg::GmemSlices { ccus, kb } => write!(f, "Slices:      {} CCU(s), {} KB each", ccus, kb),
+            Msg::GmemTileAlign { w, h, max_w, max_h, pipes } if de => {
+                write!(f, "Tile-Raster: {}x{}, max. Tile {}x{}, {} VSC-Pipes", w, h, max_w, max_h, pipes)
+            }
+            Msg::GmemTileAlign { w, h, max_w, max_h, pipes } => {
+                write!(f, "Tile align:  {}x{}, max tile {}x{}, {} VSC pipes", w, h, max_w, max_h, pipes)
+            }
+            Msg::GmemBins { w, h } if de => write!(f, "Bins für {}x{}:", w, h),
+            Msg::GmemBins { w, h } => write!(f, "Bins for {}x{}:", w, h),
+            Msg::GmemNoFit if de => write!(f, "passt nicht"),
+            Msg::GmemNoFit => write!(f, "does not fit"),
+            // health
+            Msg::HealthHeader { path, interval, timeout, context } if de => {
+                write!(f, "Health-Check auf {} alle {:.1}s, Timeout {:.1}s (Kontext {})", path, interval, timeout, context)
+            }
+            Msg::HealthHeader { path, interval, timeout, context } => {
+                write!(f, "Health check on {} every {:.1}s, timeout {:.1}s (context {})", path, interval, timeout, context)
+            }            Msg::HealthRetired { ts, ms } if de => write!(f, "ts {:>8} nach {:>7.2} ms fertig", ts, ms),
+            Msg::HealthRetired { ts, ms } => write!(f, "ts {:>8} retired in {:>7.2} ms", ts, ms),
+            Msg::HealthStuck { retired, submitted, secs } if de => {
+                write!(f, "Retire-Timestamp hängt bei {} nach Submit von {} ({:.1}s)", retired, submitted, secs)
+            }
+            Msg::HealthStuck { retired, submitted, secs } => {
+                write!(f, "retire timestamp stuck at {} after submitting {} ({:.1}s)", retired, submitted, secs)
+            }
+            Msg::SubmissionFailed { error } if de => write!(f, "Submit fehlgeschlagen: {}", error),
+            Msg::SubmissionFailed { error } => write!(f, "submission failed: {}", error),
+            Msg::HealthFailed { alarms } if de => write!(f, "GPU-Health-Check fehlgeschlagen ({} Alarme)", alarms),
+            Msg::HealthFailed { alarms } => write!(f, "GPU health check failed ({} alarms)", alarms),
+            Msg::HealthAlarm { time, message } if de => write!(f, "[{}] HEALTH-ALARM: {}", time, message),
+            Msg::HealthAlarm { time, message } => write!(f, "[{}] HEALTH ALARM: {}", time, message),
+            // llc
+            Msg::LlcHeader { device } if de => write!(f, "GPU-Systemcache (LLCC) für {}", device),
+            Msg::LlcHeader { device } => write!(f, "GPU system cache (LLCC) for {}", device),
+            Msg::LlcDriver { state } if de => write!(f, "LLCC-Treiber: {}", state),
+            Msg::LlcDriver { state } => write!(f, "LLCC driver: {}", state),
+            Msg::LlcBound if de => write!(f, "gebunden"),
+            Msg::LlcBound => write!(f, "bound"),
+            Msg::LlcNotFound if de => write!(f, "nicht gefunden"),
+            Msg::LlcNotFound => write!(f, "not found"),
+            Msg::LlcNoSlices if de => {
+                write!(f, "Keine GPU-Cache-Slices in Device Tree oder sysfs (vor A6xx oder LLCC nicht konfiguriert)")
+            }
+            Msg::LlcNoSlices => {
+                write!(f, "No GPU cache slices in device tree or sysfs (pre-A6xx or LLCC not configured)")
+            }
+            Msg::LlcUsecase { id } if de => write!(f, "Usecase {}", id),
+            Msg::LlcUsecase { id } => write!(f, "usecase {}", id),
+            Msg::LlcIdUnknown if de => write!(f, "ID unbekannt"),
+            Msg::LlcIdUnknown => write!(f, "id unknown"),
+            Msg::Enabled if de => write!(f, "aktiviert"),
+            Msg::Enabled => write!(f, "enabled"),
+            Msg::Disabled if de => write!(f, "deaktiviert"),
+            Msg::Disabled => write!(f, "disabled"),
+            Msg::LlcStateUnknown if de => write!(f, "Zustand unbekannt"),
+            Msg::LlcStateUnknown => write!(f, "state unknown"),
+            Msg::LlcSlice { name, id, state } if de => write!(f, "Slice '{}': {}, {}", name, id, state),
+            Msg::LlcSlice { name, id, state } => write!(f, "Slice '{}': {}, {}", name, id, state),
+            Msg::LlcUsed if de => write!(f, "GPU nutzt den Systemcache"),
+            Msg::LlcUsed => write!(f, "GPU uses the system cache"),
+            Msg::LlcUnused if de => write!(f, "GPU nutzt den Systemcache nicht"),
+            Msg::LlcUnused => write!(f, "GPU does not use the system cache"),
+            Msg::LlcCounters if de => write!(f, "llcc_perfmon-Zähler:"),
+            Msg::LlcCounters => write!(f, "llcc_perfmon counters:"),
+            Msg::LlcNoCounters { path } if de => {
+                write!(f, "{} existiert, hat aber keine Zähler konfiguriert (perfmon_configure, perfmon_start)", path)
+            }
+            Msg::LlcNoCounters { path } => {
+                write!(f, "{} exists but has no counters configured (perfmon_configure, perfmon_start)", path)
+            }
+            Msg::LlcNoPerfmon if de => {
+                write!(f, "Keine Trefferstatistik: der Kernel bietet kein llcc_perfmon in debugfs")
+            }
+            Msg::LlcNoPerfmon => write!(f, "No hit statistics: kernel exposes no llcc_perfmon in debugfs"),
+            // lpac
+            Msg::LpacHeader { path, kind } if de => {
+                write!(f, "LPAC (Low Priority Async Compute) über {} ({})", path, kind)
+            }
+            Msg::LpacHeader { path, kind } => write!(f, "LPAC (low priority async compute) via {} ({})", path, kind),
+            Msg::LpacNoNode if de => write!(f, "Eigener Knoten:   ➖ keiner (LPAC läuft als Queue von kgsl-3d0)"),
+            Msg::LpacNoNode => write!(f, "Dedicated node:   ➖ none (LPAC runs as a queue of kgsl-3d0)"),
+            Msg::LpacNode { node } if de => write!(f, "Eigener Knoten:   ✅ {}", node),
+            Msg::LpacNode { node } => write!(f, "Dedicated node:   ✅ {}", node),
+            Msg::LpacEnabled if de => write!(f, "Treiber-Property: ✅ aktiviert"),
+            Msg::LpacEnabled => write!(f, "Driver property:  ✅ enabled"),
+            Msg::LpacDisabled if de => write!(f, "Treiber-Property: ❌ deaktiviert"),
+            Msg::LpacDisabled => write!(f, "Driver property:  ❌ disabled"),
+            Msg::LpacNotReported if de => write!(f, "Treiber-Property: ➖ nicht gemeldet (KGSL_PROP_IS_LPAC_ENABLED)"),
+            Msg::LpacNotReported => write!(f, "Driver property:  ➖ not reported (KGSL_PROP_IS_LPAC_ENABLED)"),
+            Msg::LpacContext if de => write!(f, "LPAC-Kontext:     ✅ angelegt und zerstört"),
+            Msg::LpacContext => write!(f, "LPAC context:     ✅ created and destroyed"),
+            Msg::LpacContextFailed { error } if de => write!(f, "LPAC-Kontext:     ❌ {}", error),
+            Msg::LpacContextFailed { error } => write!(f, "LPAC context:     ❌ {}", error),
+            // overlay
+            Msg::OverlayPublishing { device, path, ms } if de => {
+                write!(f, "Veröffentliche Overlay-Feed für {} nach {} alle {} ms (Strg+C zum Beenden)", device, path, ms)
+            }
+            Msg::OverlayPublishing { device, path, ms } => {
+                write!(f, "Publishing overlay feed for {} to {} every {} ms (Ctrl+C to stop)", device, path, ms)
+            }
+            Msg::OverlayNoRecord if de => write!(f, "Kein gültiger Overlay-Eintrag (falsches Magic oder Writer hängt)"),
+            Msg::OverlayNoRecord => write!(f, "No valid overlay record (wrong magic or writer stuck)"),
+            Msg::OverlayRecord { path, seq, age } if de => {
+                write!(f, "Overlay-Eintrag {} (Seq {}, {:.0} ms alt)", path, seq, age)
+            }
+            Msg::OverlayRecord { path, seq, age } => {
+                write!(f, "Overlay record {} (seq {}, {:.0} ms old)", path, seq, age)
+            }
+            Msg::OverlayFrequency { freq, max } if de => write!(f, "Frequenz:  {} (max. {} MHz)", freq, max),
+            Msg::OverlayFrequency { freq, max } => write!(f, "Frequency: {} (max {} MHz)", freq, max),
+            Msg::OverlayBusy { busy } if de => write!(f, "Auslastung: {}", busy),
+            Msg::OverlayBusy { busy } => write!(f, "Busy:      {}", busy),
+            Msg::OverlayTemp { temp } if de => write!(f, "Temperatur: {}", temp),
+            Msg::OverlayTemp { temp } => write!(f, "Temp:      {}", temp),
+            Msg::OverlayThermal { level } if de => write!(f, "Thermik:   {}", level),
+            Msg::OverlayThermal { level } => write!(f, "Thermal:   {}", level),
+            Msg::Level { level } if de => write!(f, "Stufe {}", level),
+            Msg::Level { level } => write!(f, "level {}", level),
+            // monitor
+            Msg::MonitorAlertSingle if de => write!(f, "--alert funktioniert nur mit einem Gerät"),
+            Msg::MonitorAlertSingle => write!(f, "--alert works on a single device only"),
+            Msg::MonitorOptionsSingle if de => {
+                write!(f, "--power, --with-battery und --waits funktionieren nur mit einem Gerät")
+            }
+            Msg::MonitorOptionsSingle => write!(f, "--power, --with-battery and --waits work on a single device only"),
+            Msg::MonitorBattery { path } if de => write!(f, "Akku: {}", path),
+            Msg::MonitorBattery { path } => write!(f, "Battery: {}", path),
+            Msg::MonitorNoBattery if de => {
+                write!(f, "--with-battery: kein Akku unter /sys/class/power_supply gefunden")
+            }
+            Msg::MonitorNoBattery => write!(f, "--with-battery: no battery found under /sys/class/power_supply"),
+            Msg::MonitorNoQueues { dir } if de => write!(f, "--queue: keine Kontexte unter {} lesbar", dir),
+            Msg::MonitorNoQueues { dir } => write!(f, "--queue: no contexts readable under {}", dir),
+            Msg::MonitorCounterBusy if de => write!(f, "Auslastung aus GPU-Zählern (Always-On- + RBBM-Busy-Zyklen)"),
+            Msg::MonitorCounterBusy => write!(f, "Busy % from GPU counters (always-on + RBBM busy cycles)"),
+            Msg::MonitorAlertRule { rule } if de => write!(f, "Alarmregel: {}", rule),
+            Msg::MonitorAlertRule { rule } => write!(f, "Alert rule: {}", rule),
+            Msg::MonitorStart { path, secs } if de => {
+                write!(f, "Überwache {} alle {:.1}s (Strg+C zum Beenden)", path, secs)
+            }
+            Msg::MonitorStart { path, secs } => write!(f, "Monitoring {} every {:.1}s (Ctrl+C to stop)", path, secs),
+            Msg::MonitorStartAll { count, secs } if de => {
+                write!(f, "Überwache {} Geräte alle {:.1}s (Strg+C zum Beenden)", count, secs)
+            }
+            Msg::MonitorStartAll { count, secs } => {
+                write!(f, "Monitoring {} devices every {:.1}s (Ctrl+C to stop)", count, secs)
+            }
+            Msg::MonitorNoIrqs if de => write!(f, "(keine kgsl/adreno-Zeilen in /proc/interrupts)"),
+            Msg::MonitorNoIrqs => write!(f, "(no kgsl/adreno lines in /proc/interrupts)"),
+            Msg::MonitorBackoff { secs } if de => {
+                write!(f, "GPU im Leerlauf - höchstens {:.1}s zwischen Samples", secs)
+            }
+            Msg::MonitorBackoff { secs } => write!(f, "GPU idle - backing off to at most {:.1}s between samples", secs),
+            Msg::MonitorActive { secs } if de => write!(f, "GPU aktiv - wieder Samples alle {:.1}s", secs),
+            Msg::MonitorActive { secs } => write!(f, "GPU active - sampling every {:.1}s again", secs),
+            Msg::MonitorEnergy { joules, secs, avg, peak } if de => {
+                write!(f, "Geschätzte GPU-Energie: {:.2} J über {:.1}s (Mittel {:.0} mW, Spitze {:.0} mW)", joules, secs, avg, peak)
+            }
+            Msg::MonitorEnergy { joules, secs, avg, peak } => {
+                write!(f, "Estimated GPU energy: {:.2} J over {:.1}s (avg {:.0} mW, peak {:.0} mW)", joules, secs, avg, peak)
+            }
+            Msg::MonitorAlertCleared { rule, value } if de => write!(f, "Alarm aufgehoben: {} (jetzt {})", rule, value),
+            Msg::MonitorAlertCleared { rule, value } => write!(f, "Alert cleared: {} (now {})", rule, value),
+            Msg::MonitorAlert { rule, value } if de => write!(f, "ALARM: {} (Wert {})", rule, value),
+            Msg::MonitorAlert { rule, value } => write!(f, "ALERT: {} (value {})", rule, value),
+            Msg::MonitorHookFailed { error } if de => write!(f, "Alarm-Hook fehlgeschlagen: {}", error),
+            Msg::MonitorHookFailed { error } => write!(f, "Alert hook failed: {}", error),
+            Msg::MonitorSuspended { secs } if de => write!(f, "Gerät {:.0} s im Suspend (nicht in den Raten)", secs),
+            Msg::MonitorSuspended { secs } => write!(f, "Device suspended {:.0} s (not counted in rates)", secs),
+            Msg::MonitorSummary { title, secs, samples } if de => {
+                write!(f, "Zusammenfassung{} ({:.1}s, {} Samples):", title, secs, samples)
+            }
+            Msg::MonitorSummary { title, secs, samples } => {
+                write!(f, "Session summary{} ({:.1}s, {} samples):", title, secs, samples)
+            }
+            Msg::MonitorFrequency if de => write!(f, "Frequenz"),
+            Msg::MonitorFrequency => write!(f, "Frequency"),
+            Msg::MonitorBusy if de => write!(f, "Auslastung"),
+            Msg::MonitorBusy => write!(f, "Busy"),
+            Msg::MonitorTemperature if de => write!(f, "Temperatur"),
+            Msg::MonitorTemperature => write!(f, "Temperature"),
+            Msg::MonitorThrottled { secs, percent } if de => {
+                write!(f, "Gedrosselt   {:.1}s ({:.0}% der Sitzung)", secs, percent)
+            }
+            Msg::MonitorThrottled { secs, percent } => {
+                write!(f, "Throttled    {:.1}s ({:.0}% of the session)", secs, percent)
+            }
+            Msg::MonitorIfpc { entries, rate } if de => write!(f, "IFPC         {} Einträge ({:.1}/s)", entries, rate),
+            Msg::MonitorIfpc { entries, rate } => write!(f, "IFPC         {} entries ({:.1}/s)", entries, rate),
+            Msg::MonitorIdle { secs, percent } if de => {
+                write!(f, "GPU-Leerlauf {:.1}s ({:.0}% der Sitzung, obere Grenze für Zeit im Power Collapse)", secs, percent)
+            }
+            Msg::MonitorIdle { secs, percent } => {
+                write!(f, "GPU idle     {:.1}s ({:.0}% of the session, upper bound for time in power collapse)", secs, percent)
+            }
+            Msg::MonitorSuspends { secs, count, total } if de => {
+                write!(f, "Suspend      {:.0}s in {} Phase(n), oben nicht gezählt ({:.1}s mit Suspend)", secs, count, total)
+            }
+            Msg::MonitorSuspends { secs, count, total } => {
+                write!(f, "Suspended    {:.0}s in {} period(s), not counted above ({:.1}s including suspend)", secs, count, total)
+            }
+            Msg::MonitorFaults { count } if de => write!(f, "GPU-Faults   {} neu im Kernel-Log", count),
+            Msg::MonitorFaults { count } => write!(f, "GPU faults   {} new in the kernel log", count),
+            Msg::MonitorResidency if de => write!(f, "Busy-Zeit pro Power Level:"),
+            Msg::MonitorResidency => write!(f, "Busy time per power level:"),
+            Msg::MonitorPreemption { state, level } if de => write!(f, "Preemption {} (Stufe: {})", state, level),
+            Msg::MonitorPreemption { state, level } => write!(f, "Preemption {} (level: {})", state, level),
+            Msg::Unknown if de => write!(f, "unbekannt"),
+            Msg::Unknown => write!(f, "unknown"),
+            Msg::MonitorNoPreemptCount if de => {
+                write!(f, "--preempt: preempt_count wird von diesem Treiber nicht angeboten")
+            }
+            Msg::MonitorNoPreemptCount => write!(f, "--preempt: preempt_count not exposed by this driver"),
+            Msg::MonitorWaitsIrq if de => write!(f, "Waits: per Interrupt (KGSL_PROP_INTERRUPT_WAITS)"),
+            Msg::MonitorWaitsIrq => write!(f, "Waits: interrupt-driven (KGSL_PROP_INTERRUPT_WAITS)"),
+            Msg::MonitorWaitsPolled if de => write!(f, "Waits: gepollt (KGSL_PROP_INTERRUPT_WAITS)"),
+            Msg::MonitorWaitsPolled => write!(f, "Waits: polled (KGSL_PROP_INTERRUPT_WAITS)"),
+            Msg::MonitorWaitsEstimated if de => {
+                write!(f, "Waits: vom Treiber nicht gemeldet, geschätzt aus IRQs pro fertigem Submit")
+            }
+            Msg::MonitorWaitsEstimated => {
+                write!(f, "Waits: not reported by the driver, estimated from IRQs per retired submission")
+            }
+            Msg::MonitorIfpcCount { state, count } if de => write!(f, "IFPC {} ({} Einträge seit Boot)", state, count),
+            Msg::MonitorIfpcCount { state, count } => write!(f, "IFPC {} ({} entries since boot)", state, count),
+            Msg::MonitorIfpcNoCount { state } if de => {
+                write!(f, "IFPC {}, aber ifpc_count wird nicht angeboten", state)
+            }
+            Msg::MonitorIfpcNoCount { state } => write!(f, "IFPC {}, but ifpc_count is not exposed", state),
+            Msg::MonitorNoIfpc if de => write!(f, "--ifpc: kein ifpc-Knoten (GPU ohne GMU oder Treiber ohne IFPC)"),
+            Msg::MonitorNoIfpc => write!(f, "--ifpc: no ifpc node (GPU without GMU or driver without IFPC support)"),
+            Msg::MonitorNoCoefficients { model } if de => write!(f, "Keine Leistungskoeffizienten für {}", model),
+            Msg::MonitorNoCoefficients { model } => write!(f, "No power coefficients for {}", model),
+            Msg::MonitorNoFrequencies if de => write!(f, "GPU-Frequenztabelle nicht verfügbar"),
+            Msg::MonitorNoFrequencies => write!(f, "GPU frequency table unavailable"),
+            Msg::MonitorPowerModel { name, static_mw, dynamic_mw } if de => {
+                write!(f, "Leistungsschätzung: Modell {}, {} mW statisch + bis zu {} mW dynamisch (grob)", name, static_mw, dynamic_mw)
+            }
+            Msg::MonitorPowerModel { name, static_mw, dynamic_mw } => {
+                write!(f, "Power estimate: {} model, {} mW static + up to {} mW dynamic (rough)", name, static_mw, dynamic_mw)
+            }
+            Msg::MonitorOverfeed { context, owner, count } if de => {
+                write!(f, "Kontext {} ({}) hat {} Befehle in der Queue - App überfüttert die GPU", context, owner, count)
+            }
+            Msg::MonitorOverfeed { context, owner, count } => {
+                write!(f, "context {} ({}) has {} commands queued - app overfeeds the GPU", context, owner, count)
+            }
+            Msg::MonitorIrqStorm { name } if de => write!(f, "IRQ-Sturm auf {}", name),
+            Msg::MonitorIrqStorm { name } => write!(f, "IRQ storm on {}", name),
+            Msg::MonitorNoIrqsBusy { name } if de => write!(f, "GPU ausgelastet, aber keine Interrupts auf {}", name),
+            Msg::MonitorNoIrqsBusy { name } => write!(f, "GPU busy but no interrupts on {}", name),
+            // procmem
+            Msg::ProcmemSinkNeedsWatch if de => write!(f, "--sink braucht --watch und ersetzt --json"),
+            Msg::ProcmemSinkNeedsWatch => write!(f, "--sink needs --watch and replaces --json"),
+            Msg::ProcmemHeader { dir } if de => write!(f, "GPU-Speicher pro Prozess ({}):", dir),
+            Msg::ProcmemHeader { dir } => write!(f, "GPU memory per process ({}):", dir),
+            Msg::ProcmemNone if de => write!(f, "(kein Prozess hat die GPU offen)"),
+            Msg::ProcmemNone => write!(f, "(no process has the GPU open)"),
+            Msg::ProcmemInotify if de => write!(f, "inotify + Polling"),
+            Msg::ProcmemInotify => write!(f, "inotify + polling"),
+            Msg::ProcmemPolling if de => write!(f, "Polling"),
+            Msg::ProcmemPolling => write!(f, "polling"),
+            Msg::ProcmemWatching { count, mode, min } if de => {
+                write!(f, "Beobachte GPU-Speicher von {} Prozessen ({}, Änderungen >= {})", count, mode, min)
+            }
+            Msg::ProcmemWatching { count, mode, min } => {
+                write!(f, "Watching GPU memory of {} processes ({}, changes >= {})", count, mode, min)
+            }
+            Msg::ProcmemStarted { size } if de => write!(f, "nutzt jetzt die GPU ({})", size),
+            Msg::ProcmemStarted { size } => write!(f, "started using the GPU ({})", size),
+            Msg::ProcmemGrew { delta, total } if de => write!(f, "um {} auf {} gewachsen", delta, total),
+            Msg::ProcmemGrew { delta, total } => write!(f, "grew by {} to {}", delta, total),
+            Msg::ProcmemShrank { delta, total } if de => write!(f, "um {} auf {} geschrumpft", delta, total),
+            Msg::ProcmemShrank { delta, total } => write!(f, "shrank by {} to {}", delta, total),
+            Msg::ProcmemExited { size } if de => write!(f, "beendet (hielt {})", size),
+            Msg::ProcmemExited { size } => write!(f, "exited (held {})", size),
+            // profile
+            Msg::ProfileUnknownAction { action } if de => write!(f, "Unbekannte Profil-Aktion: {}", action),
+            Msg::ProfileUnknownAction { action } => write!(f, "Unknown profile action: {}", action),
+            Msg::ProfileUsage if de => {
+                write!(f, "Aufruf: adreno_ioctl profile apply|show|reset [NAME] [--device PATH]")
+            }
+            Msg::ProfileUsage => write!(f, "Usage: adreno_ioctl profile apply|show|reset [NAME] [--device PATH]"),
+            Msg::ProfileUnknown { name, names } if de => {
+                write!(f, "Unbekanntes Profil '{}' (eines von: {})", name, names)
+            }
+            Msg::ProfileUnknown { name, names } => write!(f, "Unknown profile '{}' (one of: {})", name, names),
+            Msg::ProfileNeedsName if de => write!(f, "profile apply braucht einen Profilnamen"),
+            Msg::ProfileNeedsName => write!(f, "profile apply needs a profile name"),
+            Msg::ProfileNoSettings { dir } if de => write!(f, "Keine schreibbaren Einstellungen unter {}", dir),
+            Msg::ProfileNoSettings { dir } => write!(f, "No writable settings under {}", dir),
+            Msg::ProfileResettingFirst { name } if de => write!(f, "Setze zuerst Profil '{}' zurück", name),
+            Msg::ProfileResettingFirst { name } => write!(f, "Resetting profile '{}' first", name),
+            Msg::ProfileApplying { name, device } if de => write!(f, "Wende Profil '{}' auf {} an:", name, device),
+            Msg::ProfileApplying { name, device } => write!(f, "Applying profile '{}' to {}:", name, device),
+            Msg::ProfileCannotSet { setting, value, error } if de => {
+                write!(f, "Kann {} nicht auf {} setzen: {} - Profil nicht angewendet", setting, value, error)
+            }
+            Msg::ProfileCannotSet { setting, value, error } => {
+                write!(f, "Cannot set {} to {}: {} - profile not applied", setting, value, error)
+            }
+            Msg::ProfileActive { name } if de => {
+                write!(f, "Profil '{}' aktiv - rückgängig mit 'adreno_ioctl profile reset'", name)
+            }
+            Msg::ProfileActive { name } => {
+                write!(f, "Profile '{}' active - undo with 'adreno_ioctl profile reset'", name)
+            }
+            Msg::UnknownGpu if de => write!(f, "unbekannte GPU"),
+            Msg::UnknownGpu => write!(f, "unknown GPU"),
+            Msg::ProfileShowHeader { model, device, levels } if de => {
+                write!(f, "{} ({}, {} Power Levels)", model, device, levels)
+            }
+            Msg::ProfileShowHeader { model, device, levels } => {
+                write!(f, "{} ({}, {} power levels)", model, device, levels)
+            }
+            Msg::ProfileActiveName { name } if de => write!(f, "Aktives Profil: {}", name),
+            Msg::ProfileActiveName { name } => write!(f, "Active profile: {}", name),
+            Msg::ProfileNoneActive if de => write!(f, "Aktives Profil: keines (Werkseinstellungen)"),
+            Msg::ProfileNoneActive => write!(f, "Active profile: none (stock settings)"),
+            Msg::ProfileCurrent if de => write!(f, "Aktuelle Einstellungen:"),
+            Msg::ProfileCurrent => write!(f, "Current settings:"),
+            Msg::ProfileResetting { name } if de => write!(f, "Setze Profil '{}' zurück", name),
+            Msg::ProfileResetting { name } => write!(f, "Resetting profile '{}'", name),
+            Msg::ProfileNoneApplied if de => write!(f, "Kein Profil angewendet"),
+            Msg::ProfileNoneApplied => write!(f, "No profile applied"),
+            Msg::ProfileStockRestored if de => write!(f, "Werkseinstellungen wiederhergestellt"),
+            Msg::ProfileStockRestored => write!(f, "Stock settings restored"),
+            Msg::CouldNotRestore { path, value } if de => write!(f, "Konnte {} nicht auf {} zurücksetzen", path, value),
+            Msg::CouldNotRestore { path, value } => write!(f, "Could not restore {} to {}", path, value),
+            Msg::NotRestored { count, path } if de => {
+                write!(f, "{} Einstellung(en) nicht wiederhergestellt, siehe {}", count, path)
+            }
+            Msg::NotRestored { count, path } => write!(f, "{} setting(s) could not be restored, see {}", count, path),
+            // reset-stat
+            Msg::ResetStatFailed { context, error } if de => {
+                write!(f, "KGSL_PROP_GPU_RESET_STAT auf Kontext {} fehlgeschlagen: {}", context, error)
+            }
+            Msg::ResetStatFailed { context, error } => {
+                write!(f, "KGSL_PROP_GPU_RESET_STAT failed on context {}: {}", context, error)
+            }
+            Msg::ResetStatHeader { path } if de => write!(f, "GPU-Reset-Status auf {}", path),
+            Msg::ResetStatHeader { path } => write!(f, "GPU reset status on {}", path),
+            Msg::ResetStatOwnFd if de => {
+                write!(f, "KGSL antwortet nur für Kontexte desselben File Descriptors; Kontexte anderer Prozesse lassen sich nicht abfragen.")
+            }
+            Msg::ResetStatOwnFd => {
+                write!(f, "KGSL only answers for contexts of the same file descriptor; other processes' contexts cannot be queried.")
+            }
+            // restore
+            Msg::RestoreNothing { path } if de => write!(f, "Nichts wiederherzustellen ({} existiert nicht)", path),
+            Msg::RestoreNothing { path } => write!(f, "Nothing to restore ({} does not exist)", path),
+            Msg::RestoreHeader { pid, command } if de => {
+                write!(f, "Stelle Einstellungen von PID {} ('{}') wieder her:", pid, command)
+            }
+            Msg::RestoreHeader { pid, command } => write!(f, "Restoring settings of PID {} ('{}'):", pid, command),
+            Msg::RestoreNoChanges if de => write!(f, "(keine offenen Änderungen)"),
+            Msg::RestoreNoChanges => write!(f, "(no open changes)"),
+            Msg::RestoreRemaining { count, path } if de => {
+                write!(f, "{} Einstellung(en) nicht wiederhergestellt, sie bleiben in {}", count, path)
+            }
+            Msg::RestoreRemaining { count, path } => {
+                write!(f, "{} setting(s) could not be restored and stay in {}", count, path)
+            }
+            // scan
+            Msg::ScanBaselineWithIoctls if de => {
+                write!(f, "--baseline und --output gelten für Property-Scans, nicht mit --ioctls")
+            }
+            Msg::ScanBaselineWithIoctls => {
+                write!(f, "--baseline and --output work on property scans, not with --ioctls")
+            }
+            Msg::ScanHeader { path, kernel } if de => write!(f, "Property-Scan auf {} (Kernel {})", path, kernel),
+            Msg::ScanHeader { path, kernel } => write!(f, "Property scan on {} (kernel {})", path, kernel),
+            Msg::ScanAnswered { present, total } if de => {
+                write!(f, "{} von {} Property-IDs beantwortet", present, total)
+            }
+            Msg::ScanAnswered { present, total } => write!(f, "{} of {} property ids answered", present, total),
+            Msg::SavedTo { path } if de => write!(f, "Gespeichert in {}", path),
+            Msg::SavedTo { path } => write!(f, "Saved to {}", path),
+            Msg::ScanCompared { path, kernel } if de => write!(f, "Verglichen mit {} (Kernel {})", path, kernel),
+            Msg::ScanCompared { path, kernel } => write!(f, "Compared with {} (kernel {})", path, kernel),
+            Msg::ScanNoChanges if de => write!(f, "Keine Änderungen am Property-ABI"),
+            Msg::ScanNoChanges => write!(f, "No property ABI changes"),
+            Msg::ScanIoctlHeader { path, max } if de => {
+                write!(f, "ioctl-Scan auf {}: Typ 0x09, Befehle 0x00-0x{:02x}, NULL-Payload", path, max)
+            }
+            Msg::ScanIoctlHeader { path, max } => {
+                write!(f, "ioctl scan on {}: type 0x09, commands 0x00-0x{:02x}, NULL payload", path, max)
+            }
+            Msg::ScanUnknownIoctl if de => write!(f, "❓ unbekannt"),
+            Msg::ScanUnknownIoctl => write!(f, "❓ unknown"),
+            Msg::ScanIoctlSummary { implemented, total, unknown } if de => {
+                write!(f, "{} von {} Befehlen implementiert, {} nicht in der bekannten Tabelle", implemented, total, unknown)
+            }
+            Msg::ScanIoctlSummary { implemented, total, unknown } => {
+                write!(f, "{} of {} commands implemented, {} not in the known table", implemented, total, unknown)
+            }
+            Msg::ScanAbsent { names } if de => write!(f, "Bekannt, aber nicht vorhanden: {}", names),
+            Msg::ScanAbsent { names } => write!(f, "Known but absent: {}", names),
+            Msg::ScanErrnoLegend if de => {
+                write!(f, "EFAULT = existiert und wollte Eingabe, EINVAL = existiert, lehnt aber die Größe ab, ENOTTY = nicht implementiert")
+            }
+            Msg::ScanErrnoLegend => {
+                write!(f, "EFAULT = exists and wanted input, EINVAL = exists but rejected the size, ENOTTY = not implemented")
+            }
+            Msg::ScanAppeared { id, name, size } if de => {
+                write!(f, "0x{:02x} {} hinzugekommen ({} Bytes)", id, name, size)
+            }
+            Msg::ScanAppeared { id, name, size } => write!(f, "0x{:02x} {} appeared ({} bytes)", id, name, size),
+            Msg::ScanDisappeared { id, name, size, error } if de => {
+                write!(f, "0x{:02x} {} verschwunden (war {} Bytes, jetzt: {})", id, name, size, error)
+            }
+            Msg::ScanDisappeared { id, name, size, error } => {
+                write!(f, "0x{:02x} {} disappeared (was {} bytes, now: {})", id, name, size, error)
+            }
+            Msg::ScanResized { id, name, old, new } if de => {
+                write!(f, "0x{:02x} {} Größe {} → {} Bytes", id, name, old, new)
+            }
+            Msg::ScanResized { id, name, old, new } => write!(f, "0x{:02x} {} size {} → {} bytes", id, name, old, new),
+            Msg::ScanChanged { id, name, ranges } if de => {
+                write!(f, "0x{:02x} {} Inhalt geändert bei Bytes {}", id, name, ranges)
+            }
+            Msg::ScanChanged { id, name, ranges } => {
+                write!(f, "0x{:02x} {} content changed at bytes {}", id, name, ranges)
+            }
+            // sched
+            Msg::SchedNoThreads if de => write!(f, "Keine kgsl/adreno-Kernel-Threads in /proc gefunden"),
+            Msg::SchedNoThreads => write!(f, "No kgsl/adreno kernel threads found in /proc"),
+            Msg::SchedThreads if de => write!(f, "KGSL-Kernel-Threads:"),
+            Msg::SchedThreads => write!(f, "KGSL kernel threads:"),
+            Msg::SchedDispatcher if de => write!(f, "Dispatcher:"),
+            Msg::SchedDispatcher => write!(f, "Dispatcher:"),
+            Msg::SchedCpuBound if de => {
+                write!(f, "KGSL-Threads warten auf eine CPU - die Submit-Latenz hängt an Dispatcher/CPU")
+            }
+            Msg::SchedCpuBound => write!(f, "KGSL threads wait for a CPU - submission latency is dispatcher/CPU bound"),
+            // selftest
+            Msg::Yes if de => write!(f, "ja"),
+            Msg::Yes => write!(f, "yes"),
+            Msg::No if de => write!(f, "nein"),
+            Msg::No => write!(f, "no"),
+            Msg::SelftestHeader { path } if de => write!(f, "Selbsttest auf {}", path),
+            Msg::SelftestHeader { path } => write!(f, "Self-test on {}", path),
+            Msg::SelftestDeviceInfo if de => write!(f, "Geräteinfo"),
+            Msg::SelftestDeviceInfo => write!(f, "Device info"),
+            Msg::SelftestChip { chip_id } if de => write!(f, "Chip 0x{:08x}", chip_id),
+            Msg::SelftestChip { chip_id } => write!(f, "chip 0x{:08x}", chip_id),
+            Msg::SelftestDriverVersion if de => write!(f, "Treiberversion"),
+            Msg::SelftestDriverVersion => write!(f, "Driver version"),
+            Msg::SelftestVersion { driver, device } if de => write!(f, "Treiber {} Gerät {}", driver, device),
+            Msg::SelftestVersion { driver, device } => write!(f, "driver {} device {}", driver, device),
+            Msg::SelftestBinary if de => write!(f, "Binary"),
+            Msg::SelftestBinary => write!(f, "Binary"),
+            Msg::SelftestContext if de => write!(f, "Kontext anlegen/zerstören"),
+            Msg::SelftestContext => write!(f, "Context create/destroy"),
+            Msg::SelftestAlloc if de => write!(f, "Alloc/Map/Free"),
+            Msg::SelftestAlloc => write!(f, "Alloc/map/free"),
+            Msg::SelftestSubmit if de => write!(f, "Submit + Retire"),
+            Msg::SelftestSubmit => write!(f, "Submit + retire"),
+            Msg::SelftestTimestampFence if de => write!(f, "Timestamp-Fence"),
+            Msg::SelftestTimestampFence => write!(f, "Timestamp fence"),
+            Msg::SelftestHardwareFence if de => write!(f, "Hardware-Fence"),
+            Msg::SelftestHardwareFence => write!(f, "Hardware fence"),
+            Msg::SelftestFailed { failed, total } if de => {
+                write!(f, "{} von {} Prüfungen fehlgeschlagen", failed, total)
+            }
+            Msg::SelftestFailed { failed, total } => write!(f, "{} of {} checks failed", failed, total),
+            Msg::SelftestPassed if de => write!(f, "Alle Prüfungen bestanden"),
+            Msg::SelftestPassed => write!(f, "All checks passed"),
+            Msg::SelftestStatic { platform } if de => write!(f, "statisch, {}", platform),
+            Msg::SelftestStatic { platform } => write!(f, "static, {}", platform),
+            Msg::SelftestDynamic { loader } if de => {
+                write!(f, "dynamisch über {} - außerhalb von vollem Android den statischen Build nutzen", loader)
+            }
+            Msg::SelftestDynamic { loader } => {
+                write!(f, "dynamic via {} - use the static build outside full Android", loader)
+            }
+            Msg::SelftestCannotInspect { error } if de => write!(f, "eigenes Binary nicht prüfbar: {}", error),
+            Msg::SelftestCannotInspect { error } => write!(f, "cannot inspect own binary: {}", error),
+            Msg::SelftestContextId { id } if de => write!(f, "Kontext {}", id),
+            Msg::SelftestContextId { id } => write!(f, "context {}", id),
+            Msg::SelftestDestroyFailed { error } if de => write!(f, "Zerstören fehlgeschlagen: {}", error),
+            Msg::SelftestDestroyFailed { error } => write!(f, "destroy failed: {}", error),
+            Msg::SelftestCreateFailed { error } if de => write!(f, "Anlegen fehlgeschlagen: {}", error),
+            Msg::SelftestCreateFailed { error } => write!(f, "create failed: {}", error),
+            Msg::GpuobjAllocFailed { error } if de => write!(f, "GPUOBJ_ALLOC fehlgeschlagen: {}", error),
+            Msg::GpuobjAllocFailed { error } => write!(f, "GPUOBJ_ALLOC failed: {}", error),
+            Msg::SelftestMapped { id, gpuaddr } if de => write!(f, "ID {} bei 0x{:x}", id, gpuaddr),
+            Msg::SelftestMapped { id, gpuaddr } => write!(f, "id {} at 0x{:x}", id, gpuaddr),
+            Msg::SelftestMmapFailed { error } if de => write!(f, "mmap fehlgeschlagen: {}", error),
+            Msg::SelftestMmapFailed { error } => write!(f, "mmap failed: {}", error),
+            Msg::SelftestRetired { ts } if de => write!(f, "Timestamp {} fertig", ts),
+            Msg::SelftestRetired { ts } => write!(f, "timestamp {} retired", ts),
+            Msg::SelftestSignaled if de => write!(f, "nach Retire signalisiert"),
+            Msg::SelftestSignaled => write!(f, "signaled after retire"),
+            Msg::SelftestStatusAfterRetire { status } if de => write!(f, "Status {} nach Retire", status),
+            Msg::SelftestStatusAfterRetire { status } => write!(f, "status {} after retire", status),
+            Msg::SelftestNoTimeline if de => write!(f, "Kernel hat keine Timeline-ioctls"),
+            Msg::SelftestNoTimeline => write!(f, "kernel has no timeline ioctls"),
+            Msg::SelftestHardwareBacked if de => write!(f, "Hardware"),
+            Msg::SelftestHardwareBacked => write!(f, "hardware"),
+            Msg::SelftestSoftwareBacked if de => write!(f, "Software"),
+            Msg::SelftestSoftwareBacked => write!(f, "software"),
+            Msg::SelftestTimelineOk { backing } if de => write!(f, "Anlegen/Signal/Warten ok ({})", backing),
+            Msg::SelftestTimelineOk { backing } => write!(f, "create/signal/wait ok ({} backed)", backing),
+            Msg::SelftestUnexpected { before, after, value } if de => {
+                write!(f, "unerwartete Zustände: {} -> {}, Timeline bei {}", before, after, value)
+            }
+            Msg::SelftestUnexpected { before, after, value } => {
+                write!(f, "unexpected states: {} -> {}, timeline at {}", before, after, value)
+            }
+            Msg::SelftestHwFenceDetection if de => write!(f, "Erkennung von Hardware-Fences:"),
+            Msg::SelftestHwFenceDetection => write!(f, "Hardware fence detection:"),
+            Msg::SelftestA7xx { answer } if de => write!(f, "A7xx oder neuer:    {}", answer),
+            Msg::SelftestA7xx { answer } => write!(f, "A7xx or newer:      {}", answer),
+            Msg::SelftestTimelineIoctls { answer } if de => write!(f, "Timeline-ioctls:    {}", answer),
+            Msg::SelftestTimelineIoctls { answer } => write!(f, "Timeline ioctls:    {}", answer),
+            Msg::SelftestKernelModule { module } if de => write!(f, "Kernel-Modul:       {}", module),
+            Msg::SelftestKernelModule { module } => write!(f, "Kernel module:      {}", module),
+            Msg::SelftestComparedTo { name } if de => write!(f, "Verglichen mit einer typischen {}:", name),
+            Msg::SelftestComparedTo { name } => write!(f, "Compared to a typical {}:", name),
+            // slumber
+            Msg::SlumberChanged { name, old, new } if de => write!(f, "{} geändert: {} -> {}", name, old, new),
+            Msg::SlumberChanged { name, old, new } => write!(f, "{} changed: {} -> {}", name, old, new),
+            Msg::SlumberIdleTimer { error } if de => {
+                write!(f, "idle_timer nicht schreibbar ({}), nutze den Timer des Kernels", error)
+            }
+            Msg::SlumberIdleTimer { error } => {
+                write!(f, "idle_timer not writable ({}), using the kernel's timer", error)
+            }
+            Msg::SlumberHeader { path, cycles, idle, settle, context } if de => {
+                write!(f, "Slumber/Resume-Test auf {}: {} Zyklen, Idle-Timer {} ms, Wartezeit {} ms (Kontext {})", path, cycles, idle, settle, context)
+            }
+            Msg::SlumberHeader { path, cycles, idle, settle, context } => {
+                write!(f, "Slumber/resume test on {}: {} cycles, idle timer {} ms, settle {} ms (context {})", path, cycles, idle, settle, context)
+            }
+            Msg::SlumberInitialFailed { error } if de => write!(f, "Erster Submit fehlgeschlagen: {}", error),
+            Msg::SlumberInitialFailed { error } => write!(f, "Initial submission failed: {}", error),
+            Msg::SlumberRetiredMismatch { retired, expected } if de => {
+                write!(f, "Retired-Timestamp {} nach Slumber, erwartet {}", retired, expected)
+            }
+            Msg::SlumberRetiredMismatch { retired, expected } => {
+                write!(f, "retired timestamp {} after slumber, expected {}", retired, expected)
+            }
+            Msg::SlumberReadRetiredFailed { error } if de => {
+                write!(f, "Lesen des Retired-Timestamps fehlgeschlagen: {}", error)
+            }
+            Msg::SlumberReadRetiredFailed { error } => write!(f, "reading retired timestamp failed: {}", error),
+            Msg::SlumberCycle { cycle, ts, ms } if de => {
+                write!(f, "Zyklus {:>4}: ts {} fertig, Resume + Submit {:.2} ms", cycle, ts, ms)
+            }
+            Msg::SlumberCycle { cycle, ts, ms } => {
+                write!(f, "cycle {:>4}: ts {} retired, resume + submit {:.2} ms", cycle, ts, ms)
+            }
+            Msg::SlumberSubmitted { ts, previous, retired } if de => {
+                write!(f, "ts {} abgeschickt (vorher {}), fertig {}", ts, previous, retired)
+            }
+            Msg::SlumberSubmitted { ts, previous, retired } => {
+                write!(f, "submitted ts {} (previous {}), retired {}", ts, previous, retired)
+            }
+            Msg::SlumberGlobalBackwards { old, new } if de => {
+                write!(f, "globaler Retired-Timestamp lief rückwärts: {} -> {}", old, new)
+            }
+            Msg::SlumberGlobalBackwards { old, new } => {
+                write!(f, "global retired timestamp went backwards: {} -> {}", old, new)
+            }
+            Msg::SlumberReadGlobalFailed { error } if de => {
+                write!(f, "Lesen des globalen Timestamps fehlgeschlagen: {}", error)
+            }
+            Msg::SlumberReadGlobalFailed { error } => write!(f, "reading global timestamp failed: {}", error),
+            Msg::SlumberReadPropertiesFailed { error } if de => {
+                write!(f, "Lesen der Properties fehlgeschlagen: {}", error)
+            }
+            Msg::SlumberReadPropertiesFailed { error } => write!(f, "reading properties failed: {}", error),
+            Msg::SlumberCycleProblem { cycle, problem } if de => write!(f, "Zyklus {:>4}: {}", cycle, problem),
+            Msg::SlumberCycleProblem { cycle, problem } => write!(f, "cycle {:>4}: {}", cycle, problem),
+            Msg::SlumberCouldNotRestore { path, error } if de => {
+                write!(f, "Konnte {} nicht zurücksetzen: {} ('adreno_ioctl restore' ausführen)", path, error)
+            }
+            Msg::SlumberCouldNotRestore { path, error } => {
+                write!(f, "Could not restore {}: {} (run 'adreno_ioctl restore')", path, error)
+            }
+            Msg::SlumberIfpc { count } if de => write!(f, "IFPC-Einträge während des Tests: {}", count),
+            Msg::SlumberIfpc { count } => write!(f, "IFPC entries during test: {}", count),
+            Msg::SlumberFailed { failures, cycles } if de => {
+                write!(f, "{} von {} Zyklen hinterließen nach Slumber einen inkonsistenten Zustand", failures, cycles)
+            }
+            Msg::SlumberFailed { failures, cycles } => {
+                write!(f, "{} of {} cycles left inconsistent state after slumber", failures, cycles)
+            }
+            Msg::SlumberPassed { cycles } if de => {
+                write!(f, "{} Zyklen: Properties und Timestamps über Slumber/Resume konsistent", cycles)
+            }
+            Msg::SlumberPassed { cycles } => {
+                write!(f, "{} cycles: properties and timestamps consistent across slumber/resume", cycles)
+            }
+            // sparse
+            Msg::SparseBadSize { size, pagesize } if de => {
+                write!(f, "Größe {} muss ein Vielfaches der Seitengröße {} und nicht null sein", size, pagesize)
+            }
+            Msg::SparseBadSize { size, pagesize } => {
+                write!(f, "Size {} must be a non-zero multiple of the page size {}", size, pagesize)
+            }
+            Msg::SparseHeader { path } if de => write!(f, "Sparse-Speicher auf {}", path),
+            Msg::SparseHeader { path } => write!(f, "Sparse memory on {}", path),
+            Msg::SparseUnsupported if de => write!(f, "Kernel implementiert die Sparse-ioctls nicht (ENOTTY)"),
+            Msg::SparseUnsupported => write!(f, "Kernel does not implement the sparse ioctls (ENOTTY)"),
+            Msg::SparseSupported if de => write!(f, "Sparse-ioctls implementiert"),
+            Msg::SparseSupported => write!(f, "Sparse ioctls implemented"),
+            Msg::SparseVirtFailed { error } if de => write!(f, "Virtuelle Allokation fehlgeschlagen: {}", error),
+            Msg::SparseVirtFailed { error } => write!(f, "Virtual alloc failed: {}", error),
+            Msg::SparseVirt { id, gpuaddr, kb } if de => {
+                write!(f, "Virtueller Bereich ID {} bei 0x{:x} ({} KB)", id, gpuaddr, kb)
+            }
+            Msg::SparseVirt { id, gpuaddr, kb } => write!(f, "Virtual range id {} at 0x{:x} ({} KB)", id, gpuaddr, kb),
+            Msg::SparsePhysFailed { error } if de => write!(f, "Physische Allokation fehlgeschlagen: {}", error),
+            Msg::SparsePhysFailed { error } => write!(f, "Physical alloc failed: {}", error),
+            Msg::SparsePhys { id, kb } if de => write!(f, "Physischer Speicher ID {} ({} KB)", id, kb),
+            Msg::SparsePhys { id, kb } => write!(f, "Physical backing id {} ({} KB)", id, kb),
+            Msg::SparseBindFirst if de => write!(f, "Erste Seite binden"),
+            Msg::SparseBindFirst => write!(f, "Bind first page"),
+            Msg::SparseBindRest if de => write!(f, "Restliche Seiten binden"),
+            Msg::SparseBindRest => write!(f, "Bind remaining pages"),
+            Msg::SparseUnbind if de => write!(f, "Alles lösen"),
+            Msg::SparseUnbind => write!(f, "Unbind all"),
+            Msg::SparseFreed if de => write!(f, "Freigegeben"),
+            Msg::SparseFreed => write!(f, "Freed"),
+            Msg::StepFailed { step, error } if de => write!(f, "{} fehlgeschlagen: {}", step, error),
+            Msg::StepFailed { step, error } => write!(f, "{} failed: {}", step, error),
+            // stress
+            Msg::StressUnknownTarget { target } if de => write!(f, "Unbekanntes Stress-Ziel: {}", target),
+            Msg::StressUnknownTarget { target } => write!(f, "Unknown stress target: {}", target),
+            Msg::StressUsage if de => write!(f, "Aufruf: adreno_ioctl stress mem [--size 1GB] [--iterations 100]"),
+            Msg::StressUsage => write!(f, "Usage: adreno_ioctl stress mem [--size 1GB] [--iterations 100]"),
+            Msg::StressZeroSize if de => write!(f, "Größe darf nicht null sein"),
+            Msg::StressZeroSize => write!(f, "Size must be non-zero"),
+            Msg::StressHeader { path, mb, iterations } if de => {
+                write!(f, "GPU-Speicher-Stresstest auf {}: {} MB x {} Durchläufe (Strg+C zum Beenden)", path, mb, iterations)
+            }
+            Msg::StressHeader { path, mb, iterations } => {
+                write!(f, "GPU memory stress on {}: {} MB x {} iterations (Ctrl+C to stop)", path, mb, iterations)
+            }
+            Msg::StressErrors { count } if de => write!(f, "{} Fehler", count),
+            Msg::StressErrors { count } => write!(f, "{} errors", count),
+            Msg::StressSummary { count, secs } if de => {
+                write!(f, "Zusammenfassung nach {} Durchläufen ({:.1}s)", count, secs)
+            }
+            Msg::StressSummary { count, secs } => write!(f, "Summary after {} iterations ({:.1}s)", count, secs),
+            Msg::StressVerified { mb } if de => write!(f, "Geprüft:           {} MB", mb),
+            Msg::StressVerified { mb } => write!(f, "Verified:          {} MB", mb),
+            Msg::StressAllocFailures { count } if de => write!(f, "Alloc-Fehler:      {}", count),
+            Msg::StressAllocFailures { count } => write!(f, "Alloc failures:    {}", count),
+            Msg::StressMapFailures { count } if de => write!(f, "mmap-Fehler:       {}", count),
+            Msg::StressMapFailures { count } => write!(f, "mmap failures:     {}", count),
+            Msg::StressSyncFailures { count } if de => write!(f, "Sync-Fehler:       {}", count),
+            Msg::StressSyncFailures { count } => write!(f, "Sync failures:     {}", count),
+            Msg::StressCorrupted { count } if de => write!(f, "Defekte Wörter:    {}", count),
+            Msg::StressCorrupted { count } => write!(f, "Corrupted words:   {}", count),
+            Msg::StressFailures { count } if de => write!(f, "{} Fehler erkannt", count),
+            Msg::StressFailures { count } => write!(f, "{} failures detected", count),
+            Msg::StressClean if de => write!(f, "Keine Datenfehler erkannt"),
+            Msg::StressClean => write!(f, "No corruption detected"),
+            Msg::StressAllocFailed { kb, error } if de => write!(f, "Alloc von {} KB fehlgeschlagen: {}", kb, error),
+            Msg::StressAllocFailed { kb, error } => write!(f, "Alloc of {} KB failed: {}", kb, error),
+            Msg::StressMmapFailed { id, error } if de => write!(f, "mmap von ID {} fehlgeschlagen: {}", id, error),
+            Msg::StressMmapFailed { id, error } => write!(f, "mmap of id {} failed: {}", id, error),
+            Msg::StressSyncFailed { id, error } if de => {
+                write!(f, "Cache-Sync von ID {} fehlgeschlagen: {}", id, error)
+            }
+            Msg::StressSyncFailed { id, error } => write!(f, "Cache sync of id {} failed: {}", id, error),
+            Msg::StressMismatch { id, gpuaddr, expected, read } if de => {
+                write!(f, "ID {} GPU-VA 0x{:x}: erwartet 0x{:016x}, gelesen 0x{:016x}", id, gpuaddr, expected, read)
+            }
+            Msg::StressMismatch { id, gpuaddr, expected, read } => {
+                write!(f, "id {} GPU VA 0x{:x}: expected 0x{:016x}, read 0x{:016x}", id, gpuaddr, expected, read)
+            }
+            // submit
+            Msg::SubmitMeasuring { count } if de => write!(f, "Messe Submit-Round-Trip ({} NOPs)...", count),
+            Msg::SubmitMeasuring { count } => write!(f, "Measuring submit round-trip ({} NOPs)...", count),
+            Msg::SubmitUseNoBench { error } if de => write!(f, "{} (--no-bench verwenden)", error),
+            Msg::SubmitUseNoBench { error } => write!(f, "{} (use --no-bench)", error),
+            Msg::SubmitWritten { path } if de => write!(f, "Ergebnispaket nach {} geschrieben", path),
+            Msg::SubmitWritten { path } => write!(f, "Result bundle written to {}", path),
+            Msg::SubmitPrivacy if de => {
+                write!(f, "   Enthält Chip, Treiber-/Kernel-Version, Fähigkeiten und Messwerte - keine Seriennummern,\n   Pfade oder Build-Namen. Nichts wurde hochgeladen: Datei prüfen und dann per Pull Request\n   zur Gerätedatenbank beitragen oder selbst hochladen.")
+            }
+            Msg::SubmitPrivacy => {
+                write!(f, "   Contains chip, driver/kernel version, capabilities and scores - no serials,\n   paths or build names. Nothing was uploaded: review the file, then contribute\n   it to the device database via pull request or upload it yourself.")
+            }
+            // timesync
+            Msg::TimesyncHeader { path } if de => write!(f, "Timestamp-Korrelation auf {}", path),
+            Msg::TimesyncHeader { path } => write!(f, "Timestamp correlation on {}", path),
+            Msg::TimesyncQtimer { gpuaddr, size } if de => {
+                write!(f, "QTimer:      GPU-Adresse 0x{:x} ({} Bytes)", gpuaddr, size)
+            }
+            Msg::TimesyncQtimer { gpuaddr, size } => {
+                write!(f, "QTimer:      GPU address 0x{:x} ({} bytes)", gpuaddr, size)
+            }
+            Msg::TimesyncNoQtimer { error } if de => write!(f, "QTimer:      nicht angeboten ({})", error),
+            Msg::TimesyncNoQtimer { error } => write!(f, "QTimer:      not exposed ({})", error),
+            Msg::TimesyncCpuSource if de => write!(f, "CPU-QTimer (CNTVCT_EL0)"),
+            Msg::TimesyncCpuSource => write!(f, "CPU QTimer (CNTVCT_EL0)"),
+            Msg::TimesyncNow { ticks, secs } if de => {
+                write!(f, "Jetzt:       {} Ticks = {:.6} s CLOCK_MONOTONIC", ticks, secs)
+            }
+            Msg::TimesyncNow { ticks, secs } => {
+                write!(f, "Now:         {} ticks = {:.6} s CLOCK_MONOTONIC", ticks, secs)
+            }
+            Msg::TimesyncClockGettime { secs } if de => write!(f, "             clock_gettime meldet {:.6} s", secs),
+            Msg::TimesyncClockGettime { secs } => write!(f, "             clock_gettime says {:.6} s", secs),
+            Msg::TimesyncCpuQtimer { error } if de => write!(f, "CPU-QTimer: {}", error),
+            Msg::TimesyncCpuQtimer { error } => write!(f, "CPU QTimer: {}", error),
+            Msg::TimesyncAlwaysOnFailed { error } if de => write!(f, "Always-On-Zähler nicht lesbar: {}", error),
+            Msg::TimesyncAlwaysOnFailed { error } => write!(f, "Cannot read the always-on counter: {}", error),
+            Msg::TimesyncGpuSource if de => write!(f, "GPU-Always-On-Zähler"),
+            Msg::TimesyncGpuSource => write!(f, "GPU always-on counter"),
+            Msg::TimesyncNoAlwaysOn { error } if de => write!(f, "GPU-Always-On-Zähler: nicht verfügbar ({})", error),
+            Msg::TimesyncNoAlwaysOn { error } => write!(f, "GPU always-on counter: not available ({})", error),
+            Msg::TimesyncNoSource if de => write!(f, "Keine Tick-Quelle zum Kalibrieren"),
+            Msg::TimesyncNoSource => write!(f, "No tick source to calibrate against"),
+            Msg::TimesyncNominal { mhz } if de => write!(f, "Nominal:     {:.3} MHz", mhz),
+            Msg::TimesyncNominal { mhz } => write!(f, "Nominal:     {:.3} MHz", mhz),
+            Msg::TimesyncMeasured { mhz, ppm } if de => write!(f, "Gemessen:    {:.6} MHz ({:+.2} ppm)", mhz, ppm),
+            Msg::TimesyncMeasured { mhz, ppm } => write!(f, "Measured:    {:.6} MHz ({:+.2} ppm)", mhz, ppm),
+            Msg::TimesyncUncertainty { ns, samples, span } if de => {
+                write!(f, "Unsicherheit: ±{} ns ({} Samples über {})", ns, samples, span)
+            }
+            Msg::TimesyncUncertainty { ns, samples, span } => {
+                write!(f, "Uncertainty: ±{} ns ({} samples over {})", ns, samples, span)
+            }
+            // trace
+            Msg::TraceNeedsPid if de => write!(f, "trace braucht --pid PID"),
+            Msg::TraceNeedsPid => write!(f, "trace needs --pid PID"),
+            Msg::TraceAttachDenied { pid, error } if de => {
+                write!(f, "Kann mich nicht an {} hängen: {} (braucht root oder CAP_SYS_PTRACE)", pid, error)
+            }
+            Msg::TraceAttachDenied { pid, error } => {
+                write!(f, "Cannot attach to {}: {} (needs root or CAP_SYS_PTRACE)", pid, error)
+            }
+            Msg::TraceAttachFailed { pid, error } if de => write!(f, "Kann mich nicht an {} hängen: {}", pid, error),
+            Msg::TraceAttachFailed { pid, error } => write!(f, "Cannot attach to {}: {}", pid, error),
+            Msg::TraceHeader { pid, threads } if de => {
+                write!(f, "Verfolge KGSL-ioctls von PID {} ({} Threads), Strg+C zum Beenden", pid, threads)
+            }
+            Msg::TraceHeader { pid, threads } => {
+                write!(f, "Tracing KGSL ioctls of PID {} ({} threads), Ctrl-C to stop", pid, threads)
+            }
+            Msg::TraceExited { pid } if de => write!(f, "Prozess {} beendet", pid),
+            Msg::TraceExited { pid } => write!(f, "Process {} exited", pid),
+            Msg::TracePtraceFailed { error } if de => write!(f, "ptrace fehlgeschlagen: {}", error),
+            Msg::TracePtraceFailed { error } => write!(f, "ptrace failed: {}", error),
+            Msg::TraceTotal { count, secs } if de => write!(f, "{} KGSL-Aufrufe in {:.1}s", count, secs),
+            Msg::TraceTotal { count, secs } => write!(f, "{} KGSL calls in {:.1}s", count, secs),
+            Msg::TraceSaved { count, path } if de => write!(f, "{} Property-Anfragen in {} gespeichert", count, path),
+            Msg::TraceSaved { count, path } => write!(f, "{} property requests saved to {}", count, path),
+            Msg::TraceWindow { calls, submits, allocs, bytes, frees, errors } if de => {
+                write!(f, "{:.0} Aufrufe/s, {:.1} Submits/s, {} Allocs ({}), {} Frees, {} Fehler", calls, submits, allocs, bytes, frees, errors)
+            }
+            Msg::TraceWindow { calls, submits, allocs, bytes, frees, errors } => {
+                write!(f, "{:.0} calls/s, {:.1} submits/s, {} allocs ({}), {} frees, {} errors", calls, submits, allocs, bytes, frees, errors)
+            }
+            // triage
+            Msg::TriageHeader { device } if de => write!(f, "Hang-Analyse für {}", device),
+            Msg::TriageHeader { device } => write!(f, "Hang triage for {}", device),
+            Msg::TriageNoLog { error } if de => {
+                write!(f, "Kernel-Log nicht lesbar ({}), root oder dmesg_restrict=0 nötig", error)
+            }
+            Msg::TriageNoLog { error } => {
+                write!(f, "Kernel log not readable ({}), root or dmesg_restrict=0 needed", error)
+            }
+            Msg::TriageFaultLines { count } if de => write!(f, "Kernel-Log: {} GPU-Fault-Zeilen", count),
+            Msg::TriageFaultLines { count } => write!(f, "Kernel log: {} GPU fault lines", count),
+            Msg::TriageSnapshot { chip_id, owner, pid, context } if de => {
+                write!(f, "Snapshot: Chip 0x{:08x}, Besitzer {} (PID {}), Kontext {}", chip_id, owner, pid, context)
+            }
+            Msg::TriageSnapshot { chip_id, owner, pid, context } => {
+                write!(f, "Snapshot: chip 0x{:08x}, owner {} (pid {}), context {}", chip_id, owner, pid, context)
+            }
+            Msg::TriageSnapshotNoDump { count } if de => {
+                write!(f, "Snapshot: {} Faults aufgezeichnet, kein Dump lesbar", count)
+            }
+            Msg::TriageSnapshotNoDump { count } => write!(f, "Snapshot: {} faults recorded, no dump readable", count),
+            Msg::TriageNoSnapshot if de => write!(f, "Snapshot: nicht verfügbar"),
+            Msg::TriageNoSnapshot => write!(f, "Snapshot: not available"),
+            Msg::TriageContextList { reason } if de => write!(f, "Kontextliste: {}", reason),
+            Msg::TriageContextList { reason } => write!(f, "Context list: {}", reason),
+            Msg::TriageNoReset if de => write!(f, "Kein GPU-Reset in Kernel-Log oder Snapshot gefunden"),
+            Msg::TriageNoReset => write!(f, "No GPU reset found in kernel log or snapshot"),
+            Msg::TriageNoSuspect if de => {
+                write!(f, "Reset erkannt, aber keine Quelle nennt einen Prozess oder Kontext")
+            }
+            Msg::TriageNoSuspect => write!(f, "Reset detected, but no source names a process or context"),
+            Msg::TriageSuspect { process, pid, context } if de => {
+                write!(f, "Wahrscheinlich schuld: {} (PID {}), Kontext {}", process, pid, context)
+            }
+            Msg::TriageSuspect { process, pid, context } => {
+                write!(f, "Likely guilty: {} (pid {}), context {}", process, pid, context)
+            }
+            Msg::TriageEvidence { evidence } if de => write!(f, "Belege: {}", evidence),
+            Msg::TriageEvidence { evidence } => write!(f, "Evidence: {}", evidence),
+            Msg::TriageConflict { conflict } if de => write!(f, "Widerspricht: {}", conflict),
+            Msg::TriageConflict { conflict } => write!(f, "Disagrees: {}", conflict),
+            Msg::TriageSubmissions if de => write!(f, "Submissions:"),
+            Msg::TriageSubmissions => write!(f, "Submissions:"),
+            Msg::TriageContext { id, kind, priority, queued, consumed, retired, inflight } if de => {
+                write!(f, "ctx {:>4} {:<4} Prio {:>2}: eingereiht {}, gelesen {}, fertig {}, unterwegs {}", id, kind, priority, queued, consumed, retired, inflight)
+            }
+            Msg::TriageContext { id, kind, priority, queued, consumed, retired, inflight } => {
+                write!(f, "ctx {:>4} {:<4} prio {:>2}: queued {}, consumed {}, retired {}, in flight {}", id, kind, priority, queued, consumed, retired, inflight)
+            }
+            Msg::TriageMemory { size } if de => write!(f, "GPU-Speicher: {}", size),
+            Msg::TriageMemory { size } => write!(f, "GPU memory: {}", size),
+            Msg::TriageExited if de => write!(f, "Prozess ist beendet"),
+            Msg::TriageExited => write!(f, "Process has exited"),
+            Msg::TriagePidFaults { count } if de => write!(f, "Fault-Zeilen mit dieser PID: {}", count),
+            Msg::TriagePidFaults { count } => write!(f, "Fault lines naming this pid: {}", count),
+            // turnip
+            Msg::TurnipNoCompatible if de => {
+                write!(f, "msm-Render-Node gefunden, aber kein adreno-compatible in seinem Device-Tree-Knoten")
+            }
+            Msg::TurnipNoCompatible => {
+                write!(f, "msm render node found, but no adreno compatible in its device tree node")
+            }
+            Msg::TurnipNoDevice if de => write!(f, "Weder KGSL-Gerät noch msm-Render-Node gefunden"),
+            Msg::TurnipNoDevice => write!(f, "No KGSL device and no msm render node found"),
+            Msg::TurnipHeader if de => write!(f, "Turnip-Kompatibilität (Mesa Vulkan)"),
+            Msg::TurnipHeader => write!(f, "Turnip (Mesa Vulkan) compatibility"),
+            Msg::TurnipGpu { model, chip_id } if de => write!(f, "GPU: {} (Chip 0x{:08x})", model, chip_id),
+            Msg::TurnipGpu { model, chip_id } => write!(f, "GPU: {} (chip 0x{:08x})", model, chip_id),
+            Msg::TurnipDriver { driver } if de => write!(f, "Kernel-Treiber: {}", driver),
+            Msg::TurnipDriver { driver } => write!(f, "Kernel driver: {}", driver),
+            Msg::TurnipAndroid { release, sdk } if de => write!(f, "Android: {} (API {})", release, sdk),
+            Msg::TurnipAndroid { release, sdk } => write!(f, "Android: {} (API {})", release, sdk),
+            Msg::TurnipNoAndroid if de => write!(f, "Android: nein (reines Linux)"),
+            Msg::TurnipNoAndroid => write!(f, "Android: no (plain Linux)"),
+            Msg::TurnipSupported { mesa } if de => write!(f, "Von Turnip unterstützt seit Mesa {}", mesa),
+            Msg::TurnipSupported { mesa } => write!(f, "Supported by Turnip since Mesa {}", mesa),
+            Msg::TurnipExperimental { mesa } if de => {
+                write!(f, "Experimentelle Turnip-Unterstützung seit Mesa {}", mesa)
+            }
+            Msg::TurnipExperimental { mesa } => write!(f, "Experimental Turnip support since Mesa {}", mesa),
+            Msg::TurnipTooOld if de => write!(f, "Nicht unterstützt: Turnip braucht Adreno 6xx oder neuer"),
+            Msg::TurnipTooOld => write!(f, "Not supported: Turnip needs Adreno 6xx or newer"),
+            Msg::TurnipUnknown if de => write!(f, "Turnip unbekannt"),
+            Msg::TurnipUnknown => write!(f, "Unknown to Turnip"),
+            Msg::TurnipRequirements if de => write!(f, "Voraussetzungen:"),
+            Msg::TurnipRequirements => write!(f, "Requirements:"),
+            // usermem
+            Msg::MmapFailed { error } if de => write!(f, "mmap fehlgeschlagen: {}", error),
+            Msg::MmapFailed { error } => write!(f, "mmap failed: {}", error),
+            Msg::UsermemImporting { kb, host, path } if de => {
+                write!(f, "Importiere {} KB CPU-Speicher bei 0x{:x} in {}", kb, host, path)
+            }
+            Msg::UsermemImporting { kb, host, path } => {
+                write!(f, "Importing {} KB of CPU memory at 0x{:x} into {}", kb, host, path)
+            }
+            Msg::UsermemImportFailed { error } if de => write!(f, "Import fehlgeschlagen: {}", error),
+            Msg::UsermemImportFailed { error } => write!(f, "Import failed: {}", error),
+            Msg::UsermemMethod { method } if de => write!(f, "Methode: {}", method),
+            Msg::UsermemMethod { method } => write!(f, "Method:  {}", method),
+            Msg::UsermemSize { size } if de => write!(f, "Größe:   {} Bytes", size),
+            Msg::UsermemSize { size } => write!(f, "Size:    {} bytes", size),
+            Msg::UsermemUnmapped if de => write!(f, "Von der GPU gelöst"),
+            Msg::UsermemUnmapped => write!(f, "Unmapped from GPU"),
+            // vamap
+            Msg::VamapHeader { path } if de => write!(f, "GPU-Adressraum dieses Prozesses auf {}", path),
+            Msg::VamapHeader { path } => write!(f, "GPU address space of this process on {}", path),
+            Msg::VamapEmpty if de => write!(f, "(keine GPU-Objekte - --demo N legt welche an)"),
+            Msg::VamapEmpty => write!(f, "(no GPU objects - use --demo N to allocate some)"),
+            Msg::VamapGap if de => write!(f, "(Lücke)"),
+            Msg::VamapGap => write!(f, "(gap)"),
+            Msg::VamapSummary { count, used, span } if de => {
+                write!(f, "{} Objekte, {} belegt in {} Spanne", count, used, span)
+            }
+            Msg::VamapSummary { count, used, span } => write!(f, "{} objects, {} used in {} span", count, used, span),
+            // wait-idle
+            Msg::WaitIdleDone { path, ms, retired } if de => {
+                write!(f, "{} nach {:.1} ms im Leerlauf (globaler Timestamp {} fertig)", path, ms, retired)
+            }
+            Msg::WaitIdleDone { path, ms, retired } => {
+                write!(f, "{} idle after {:.1} ms (global timestamp {} retired)", path, ms, retired)
+            }
+            Msg::WaitIdleBusy { path, secs } if de => write!(f, "{} nach {:.1}s noch beschäftigt", path, secs),
+            Msg::WaitIdleBusy { path, secs } => write!(f, "{} still busy after {:.1}s", path, secs),
+            Msg::WaitIdleCannotRead { error } if de => write!(f, "Timestamps nicht lesbar: {}", error),
+            Msg::WaitIdleCannotRead { error } => write!(f, "Cannot read timestamps: {}", error),
+            // Hilfe und Rechte
+            Msg::HelpUsage if de => write!(f, "Aufruf: adreno_ioctl [BEFEHL] [OPTIONEN]"),
+            Msg::HelpUsage => write!(f, "Usage: adreno_ioctl [COMMAND] [OPTIONS]"),
+            Msg::HelpCommands if de => write!(f, "Befehle:"),
+            Msg::HelpCommands => write!(f, "Commands:"),
+            Msg::HelpGlobalOptions if de => write!(f, "Globale Optionen:"),
+            Msg::HelpGlobalOptions => write!(f, "Global options:"),
+            Msg::HelpExitCodes if de => write!(f, "Exit-Codes:"),
+            Msg::HelpExitCodes => write!(f, "Exit codes:"),
+            Msg::ReadOnly if de => {
+                write!(f, "Dieser Befehl allokiert oder submittet und braucht Schreibzugriff (--read-only ist gesetzt)")
+            }
+            Msg::ReadOnly => {
+                write!(f, "This command allocates or submits and needs read-write access (--read-only is set)")
+            }
+            Msg::UserAndKeepRoot if de => write!(f, "--user und --keep-root schließen sich aus"),
+            Msg::UserAndKeepRoot => write!(f, "--user and --keep-root exclude each other"),
+            Msg::UserNeedsRoot if de => {
+                write!(f, "--user braucht root; ohne root läuft das Tool bereits unprivilegiert")
+            }
+            Msg::UserNeedsRoot => write!(f, "--user needs root; without it the tool already runs unprivileged"),
+            Msg::CannotSwitchUser { user, error } if de => write!(f, "Kann nicht zu {} wechseln: {}", user, error),
+            Msg::CannotSwitchUser { user, error } => write!(f, "Cannot switch to {}: {}", user, error),
+            // info
+            Msg::InfoFeatures if de => write!(f, "Hardware-Features:"),
+            Msg::InfoFeatures => write!(f, "Hardware features:"),
+            Msg::FeatureNotReported if de => write!(f, "nicht gemeldet"),
+            Msg::FeatureNotReported => write!(f, "not reported"),
+            Msg::FeatureLikely if de => write!(f, "wahrscheinlich"),
+            Msg::FeatureLikely => write!(f, "likely"),
+            Msg::FeatureUnlikely if de => write!(f, "unwahrscheinlich"),
+            Msg::FeatureUnlikely => write!(f, "unlikely"),
+            Msg::ZapHeader if de => write!(f, "Zap-Shader / Secure Mode:"),
+            Msg::ZapHeader => write!(f, "Zap Shader / Secure Mode:"),
+            Msg::ZapConfigured { name } if de => write!(f, "Konfiguriert: {}", name),
+            Msg::ZapConfigured { name } => write!(f, "Configured: {}", name),
+            Msg::ZapNodeOnly if de => write!(f, "Konfiguriert: Zap-Knoten ohne Firmware-Namen"),
+            Msg::ZapNodeOnly => write!(f, "Configured: zap node without firmware name"),
+            Msg::ZapNotConfigured if de => write!(f, "Konfiguriert: nein"),
+            Msg::ZapNotConfigured => write!(f, "Configured: no"),
+            Msg::ZapFirmwareMissing if de => write!(f, "Firmware: ❌ nicht gefunden"),
+            Msg::ZapFirmwareMissing => write!(f, "Firmware: ❌ not found"),
+            Msg::ZapFirmware { path } if de => write!(f, "Firmware: ✅ {}", path),
+            Msg::ZapFirmware { path } => write!(f, "Firmware: ✅ {}", path),
+            Msg::ZapSecureSupported if de => write!(f, "Secure-Kontexte: ✅ unterstützt"),
+            Msg::ZapSecureSupported => write!(f, "Secure contexts: ✅ supported"),
+            Msg::ZapSecureUnsupported if de => write!(f, "Secure-Kontexte: ❌ nicht unterstützt"),
+            Msg::ZapSecureUnsupported => write!(f, "Secure contexts: ❌ not supported"),
+            Msg::ZapSecureUnknown if de => write!(f, "Secure-Kontexte: unbekannt"),
+            Msg::ZapSecureUnknown => write!(f, "Secure contexts: unknown"),
+            Msg::ZapAlignment { bytes } if de => write!(f, "Ausrichtung sicherer Puffer: {} Bytes", bytes),
+            Msg::ZapAlignment { bytes } => write!(f, "Secure buffer alignment: {} bytes", bytes),
+            Msg::SysrootNotDir { path } if de => write!(f, "--sysroot {}: kein Verzeichnis", path),
+            Msg::SysrootNotDir { path } => write!(f, "--sysroot {}: not a directory", path),
+            Msg::InfoBanner if de => write!(f, "Basiert auf empirischen IOCTL-Tests"),
+            Msg::InfoBanner => write!(f, "Based on empirical IOCTL testing"),
+            Msg::InfoReplaying { path } if de => write!(f, "Spiele Property-Aufnahme {} ab", path),
+            Msg::InfoReplaying { path } => write!(f, "Replaying property capture {}", path),
+            Msg::InfoFound { count } if de => write!(f, "{} Gerät(e) gefunden:", count),
+            Msg::InfoFound { count } => write!(f, "Found {} device(s):", count),
+            Msg::InfoSaved { count, path } if de => write!(f, "{} Property-Anfragen in {} gespeichert", count, path),
+            Msg::InfoSaved { count, path } => write!(f, "{} property requests saved to {}", count, path),
+            Msg::Core2dTitle if de => write!(f, "             ADRENO 2D-KERN-INFORMATIONEN             "),
+            Msg::Core2dTitle => write!(f, "              ADRENO 2D CORE INFORMATION              "),
+            Msg::Core2dDevice { name } if de => write!(f, "Gerät: {}", name),
+            Msg::Core2dDevice { name } => write!(f, "Device: {}", name),
+            Msg::Core2dTypical if de => write!(f, "Typisch in: MSM7x30, MSM8x55, MSM8x60"),
+            Msg::Core2dTypical => write!(f, "Typically found in: MSM7x30, MSM8x55, MSM8x60"),
+            Msg::Core2dDeviceId { id, core } if de => write!(f, "Geräte-ID: 0x{:08x} (2D-Kern {})", id, core),
+            Msg::Core2dDeviceId { id, core } => write!(f, "Device ID: 0x{:08x} (2D core {})", id, core),
+            Msg::Core2dMmu { state } if de => write!(f, "MMU: {}", state),
+            Msg::Core2dMmu { state } => write!(f, "MMU: {}", state),
+            Msg::Core2dWaitsSupported if de => write!(f, "Interrupt-Waits: ✅ unterstützt"),
+            Msg::Core2dWaitsSupported => write!(f, "Interrupt waits: ✅ supported"),
+            Msg::Core2dWaitsUnsupported if de => write!(f, "Interrupt-Waits: ❌ nicht unterstützt"),
+            Msg::Core2dWaitsUnsupported => write!(f, "Interrupt waits: ❌ not supported"),
+            Msg::Core2dWaitsUnknown if de => write!(f, "Interrupt-Waits: unbekannt"),
+            Msg::Core2dWaitsUnknown => write!(f, "Interrupt waits: unknown"),
+            Msg::Core2dNoChipId if de => write!(f, "Keine Chip-ID, kein GMEM und keine Frequenz bei 2D-Kernen"),
+            Msg::Core2dNoChipId => write!(f, "No chip ID, GMEM or frequency on 2D cores"),
+            Msg::InfoForOtherProjects if de => write!(f, "Zur Verwendung in anderen Projekten:"),
+            Msg::InfoForOtherProjects => write!(f, "For use in other projects:"),
+            Msg::InfoError { error } if de => write!(f, "Fehler: {}", error),
+            Msg::InfoError { error } => write!(f, "Error: {}", error),
+        }
+    }
+}
@@ -0,0 +1,394 @@
+//! Periodisches Sampling von GPU-Metriken
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::battery::{self, BatterySample};
+use crate::dmesg;
+use crate::irq::{self, IrqLine};
+use crate::kgsl::KGSL_MEMSTORE_GLOBAL;
+use crate::memstore::Memstore;
+use crate::perfcounter::{BusyCounters, BusySnapshot};
+use crate::procmem;
+use crate::queue::{self, ContextQueue};
+use crate::sink::Record;
+use crate::sys;
+use crate::sysfs;
+use crate::sysroot;
+
+/// Kürzere Lücken zwischen Boot- und monotoner Zeit sind Messrauschen
+pub const SUSPEND_MIN: Duration = Duration::from_millis(500);
+
+/// Ein Messpunkt
+#[derive(Debug, Clone)]
+pub struct Sample {
+    /// Monotone Zeit, steht im Suspend; Grundlage aller Raten
+    pub time: Instant,
+    /// CLOCK_BOOTTIME, läuft im Suspend weiter
+    pub boottime: Duration,
+    /// Wanduhr zum Zuordnen, kann springen
+    pub wall: SystemTime,
+    /// Aktuelle Frequenz in Hz
+    pub freq_hz: Option<u64>,
+    /// Auslastung in Prozent
+    pub busy_percent: Option<f64>,
+    /// GPU-Temperatur in °C
+    pub temp_c: Option<f64>,
+    /// `thermal_pwrlevel`, größer 0 heißt gedrosselt
+    pub thermal_level: Option<u32>,
+    /// GPU-Interrupt-Zähler
+    pub irqs: Vec<IrqLine>,
+    /// Kumulierte Busy-Zeit in µs je Power Level
+    pub clock_stats: Vec<u64>,
+    /// Nur mit [`Monitor::with_battery`]
+    pub battery: Option<BatterySample>,
+    /// Nur mit [`Monitor::with_queues`]
+    pub queues: Option<Vec<ContextQueue>>,
+    /// Nur mit [`Monitor::with_preemption`]
+    pub preempt_count: Option<u64>,
+    /// IFPC-Eintritte, nur mit [`Monitor::with_ifpc`]
+    pub ifpc_count: Option<u64>,
+    /// GPU-Speicher aller Prozesse, nur mit [`Monitor::with_memory`]
+    pub gpu_mem_bytes: Option<u64>,
+    /// GPU-Fehler im Kernel-Log, nur mit [`Monitor::with_faults`]
+    pub gpu_faults: Option<u64>,
+    /// Globaler Retired-Timestamp, nur mit [`Monitor::with_memstore`]
+    pub retired: Option<u32>,
+    /// Always-On- und Busy-Zähler, nur mit [`Monitor::with_counters`]
+    pub counters: Option<BusySnapshot>,
+}
+
+/// Interrupt-Rate einer Leitung zwischen zwei Samples
+#[derive(Debug, Clone)]
+pub struct IrqRate {
+    pub name: String,
+    pub total: u64,
+    pub per_second: f64,
+}
+
+impl Sample {
+    /// Wache Zeit seit `prev`, ohne Suspend
+    pub fn awake_since(&self, prev: &Sample) -> Duration {
+        self.time.duration_since(prev.time)
+    }
+
+    /// Zeit im Suspend seit `prev`; unter [`SUSPEND_MIN`] null
+    pub fn suspended_since(&self, prev: &Sample) -> Duration {
+        let gap = self.boottime.saturating_sub(prev.boottime).saturating_sub(self.awake_since(prev));
+        if gap >= SUSPEND_MIN { gap } else { Duration::ZERO }
+    }
+
+    /// Preemptions pro Sekunde seit `prev`
+    pub fn preemptions_per_second(&self, prev: &Sample) -> Option<f64> {
+        let dt = self.awake_since(prev).as_secs_f64();
+        let delta = self.preempt_count?.checked_sub(prev.preempt_count?)?;
+        (dt > 0.0).then(|| delta as f64 / dt)
+    }
+
+    /// IFPC-Eintritte pro Sekunde seit `prev`
+    pub fn ifpc_per_second(&self, prev: &Sample) -> Option<f64> {
+        let dt = self.awake_since(prev).as_secs_f64();
+        let delta = self.ifpc_count?.checked_sub(prev.ifpc_count?)?;
+        (dt > 0.0).then(|| delta as f64 / dt)
+    }
+
+    /// Auslastung seit `prev` aus den Hardware-Zählern; genauer als
+    /// [`Sample::busy_percent`], das nur das letzte DCVS-Fenster abbildet
+    pub fn counter_busy_percent(&self, prev: &Sample) -> Option<f64> {
+        let freq_hz = (self.freq_hz? + prev.freq_hz?) / 2;
+        self.counters?.busy_percent(&prev.counters?, freq_hz)
+    }
+
+    /// Seit `prev` retired Submissions: Memstore, sonst Summe der Context-Queues
+    fn retired_since(&self, prev: &Sample) -> Option<u64> {
+        if let (Some(now), Some(before)) = (self.retired, prev.retired) {
+            return Some(now.wrapping_sub(before) as u64);
+        }
+        let (now, before) = (self.queues.as_ref()?, prev.queues.as_ref()?);
+        Some(
+            now.iter()
+                .filter_map(|q| {
+                    let old = before.iter().find(|p| p.id == q.id)?;
+                    Some(q.retired.wrapping_sub(old.retired) as u64)
+                })
+                .sum(),
+        )
+    }
+
+    /// Retired Submissions pro Sekunde seit `prev` (nur mit Memstore)
+    pub fn retired_per_second(&self, prev: &Sample) -> Option<f64> {
+        let dt = self.awake_since(prev).as_secs_f64();
+        let delta = self.retired?.wrapping_sub(prev.retired?);
+        (dt > 0.0).then(|| delta as f64 / dt)
+    }
+
+    /// Anteil der seit `prev` retired Submissions, die einen GPU-Interrupt
+    /// auslösten (höchstens 1.0). Nahe 1 schlafen Waits auf Interrupts,
+    /// nahe 0 pollt der Userspace die Timestamps. Braucht Memstore oder
+    /// Context-Queues.
+    pub fn interrupt_wait_ratio(&self, prev: &Sample) -> Option<f64> {
+        let retired = self.retired_since(prev)?;
+        if retired == 0 {
+            return None;
+        }
+        let irqs: u64 = self
+            .irqs
+            .iter()
+            .filter_map(|line| {
+                let old = prev.irqs.iter().find(|p| p.irq == line.irq)?;
+                Some(line.total().saturating_sub(old.total()))
+            })
+            .sum();
+        Some((irqs as f64 / retired as f64).min(1.0))
+    }
+
+    /// Datensatz für [`crate::sink`]; Raten beziehen sich auf `prev` und
+    /// zählen nur wache Zeit
+    pub fn to_record(&self, device: &str, prev: &Sample, elapsed: Duration) -> Record {
+        let inflight = self.queues.as_ref().map(|queues| queues.iter().map(|q| q.inflight()).sum::<u32>());
+        let irqs_per_second: f64 = self.irq_rates(prev).iter().map(|r| r.per_second).sum();
+        Record::new("sample")
+            .field("device", device)
+            .field("elapsed_s", elapsed.as_secs_f64())
+            .field("wall_time", self.wall.duration_since(UNIX_EPOCH).ok().map(|t| t.as_secs_f64()))
+            .field("boottime_s", self.boottime.as_secs_f64())
+            .field("suspended_s", self.suspended_since(prev).as_secs_f64())
+            .field("freq_hz", self.freq_hz)
+            .field("busy_percent", self.busy_percent)
+            .field("temp_c", self.temp_c)
+            .field("thermal_level", self.thermal_level)
+            .field("irqs_per_s", (!self.irqs.is_empty()).then_some(irqs_per_second))
+            .field("preempt_per_s", self.preemptions_per_second(prev))
+            .field("ifpc_per_s", self.ifpc_per_second(prev))
+            .field("retired_per_s", self.retired_per_second(prev))
+            .field("irq_wait_ratio", self.interrupt_wait_ratio(prev))
+            .field("inflight", inflight)
+            .field("gpu_mem_bytes", self.gpu_mem_bytes)
+            .field("gpu_faults", self.gpu_faults)
+            .field("battery_mw", self.battery.as_ref().and_then(BatterySample::power_mw))
+    }
+
+    /// Interrupts pro Sekunde seit `prev`
+    pub fn irq_rates(&self, prev: &Sample) -> Vec<IrqRate> {
+        let dt = self.awake_since(prev).as_secs_f64();
+        self.irqs
+            .iter()
+            .map(|line| {
+                let before = prev.irqs.iter().find(|p| p.irq == line.irq).map(IrqLine::total);
+                let delta = before.map(|b| line.total().saturating_sub(b)).unwrap_or(0);
+                IrqRate {
+                    name: line.short_name().to_string(),
+                    total: line.total(),
+                    per_second: if dt > 0.0 { delta as f64 / dt } else { 0.0 },
+                }
+            })
+            .collect()
+    }
+}
+
+/// Sammelt Samples für ein Gerät
+pub struct Monitor {
+    dir: PathBuf,
+    frequencies: Vec<u64>,
+    battery: Option<PathBuf>,
+    device_path: Option<String>,
+    preemption: bool,
+    ifpc: bool,
+    temp_files: Vec<PathBuf>,
+    memory: bool,
+    faults: bool,
+    memstore: Option<Memstore>,
+    counters: Option<BusyCounters>,
+}
+
+impl Monitor {
+    /// Monitor für `/dev/kgsl-3d0` o.ä.
+    pub fn new(device_path: &str) -> Self {
+        let dir = sysfs::device_dir(device_path);
+        let frequencies = sysfs::available_frequencies(&dir).unwrap_or_default();
+        let temp_files = sysfs::temperature_files(&dir);
+        Monitor {
+            dir,
+            frequencies,
+            battery: None,
+            device_path: None,
+            preemption: false,
+            ifpc: false,
+            temp_files,
+            memory: false,
+            faults: false,
+            memstore: None,
+            counters: None,
+        }
+    }
+
+    /// Batterie-Strom und -Spannung mitschreiben, falls eine Batterie gefunden wird
+    pub fn with_battery(mut self) -> Self {
+        self.battery = battery::find_battery();
+        self
+    }
+
+    /// Inflight-Kommandos je Context aus debugfs mitschreiben
+    pub fn with_queues(mut self, device_path: &str) -> Self {
+        self.device_path = Some(device_path.to_string());
+        self
+    }
+
+    /// Preemption-Zähler mitschreiben
+    pub fn with_preemption(mut self) -> Self {
+        self.preemption = true;
+        self
+    }
+
+    /// IFPC-Zähler mitschreiben
+    pub fn with_ifpc(mut self) -> Self {
+        self.ifpc = true;
+        self
+    }
+
+    /// Summe des GPU-Speichers aller Prozesse mitschreiben
+    pub fn with_memory(mut self) -> Self {
+        self.memory = true;
+        self
+    }
+
+    /// GPU-Fehler im Kernel-Log zählen (liest den ganzen Ringpuffer)
+    pub fn with_faults(mut self) -> Self {
+        self.faults = true;
+        self
+    }
+
+    /// Globalen Retired-Timestamp aus dem gemappten Memstore lesen (ohne ioctl
+    /// je Sample); bleibt aus, wenn das Gerät oder der Memstore nicht zugänglich ist
+    pub fn with_memstore(mut self, device_path: &str) -> Self {
+        self.memstore = File::open(sysroot::resolve(device_path)).ok().and_then(|f| Memstore::map(&f).ok());
+        self
+    }
+
+    /// Ob der Memstore gemappt ist
+    pub fn has_memstore(&self) -> bool {
+        self.memstore.is_some()
+    }
+
+    /// Always-On- und RBBM-Busy-Zähler reservieren; bleibt aus, wenn das
+    /// Gerät nicht zugänglich ist oder der Kernel keine Zähler hergibt
+    pub fn with_counters(mut self, device_path: &str) -> Self {
+        self.counters = File::open(sysroot::resolve(device_path)).ok().and_then(|f| BusyCounters::open(f).ok());
+        self
+    }
+
+    /// Ob die Hardware-Zähler reserviert sind
+    pub fn has_counters(&self) -> bool {
+        self.counters.is_some()
+    }
+
+    /// sysfs-Verzeichnis des Geräts
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Verwendetes power_supply Verzeichnis
+    pub fn battery_dir(&self) -> Option<&Path> {
+        self.battery.as_deref()
+    }
+
+    /// Frequenzen je Power Level, Index wie in [`Sample::clock_stats`]
+    pub fn frequencies(&self) -> &[u64] {
+        &self.frequencies
+    }
+
+    pub fn sample(&self) -> Sample {
+        Sample {
+            time: Instant::now(),
+            boottime: sys::boottime(),
+            wall: SystemTime::now(),
+            freq_hz: sysfs::gpuclk(&self.dir).ok(),
+            busy_percent: sysfs::busy_percent(&self.dir).ok(),
+            temp_c: sysfs::read_temperature(&self.temp_files).ok(),
+            thermal_level: sysfs::thermal_pwrlevel(&self.dir).ok(),
+            irqs: irq::read_gpu_irqs().unwrap_or_default(),
+            clock_stats: sysfs::clock_stats(&self.dir).unwrap_or_default(),
+            battery: self.battery.as_deref().map(battery::read_battery),
+            queues: self.device_path.as_deref().map(queue::read_context_queues),
+            preempt_count: if self.preemption { sysfs::preempt_count(&self.dir).ok() } else { None },
+            ifpc_count: if self.ifpc { sysfs::ifpc_count(&self.dir).ok() } else { None },
+            gpu_mem_bytes: if self.memory {
+                procmem::read_processes().ok().map(|p| p.iter().map(|p| p.total_bytes).sum())
+            } else {
+                None
+            },
+            gpu_faults: if self.faults { dmesg::count_gpu_faults().ok() } else { None },
+            retired: self.memstore.as_ref().and_then(|m| m.timestamps(KGSL_MEMSTORE_GLOBAL)).map(|t| t.retired),
+            counters: self.counters.as_ref().and_then(|c| c.snapshot().ok()),
+        }
+    }
+}
+
+// ============================================================================
+// Adaptives Intervall
+// ============================================================================
+
+/// Auslastung, unter der die GPU als untätig gilt
+pub const IDLE_BUSY_PERCENT: f64 = 1.0;
+
+/// So lange muss die GPU untätig sein, bevor das Intervall wächst
+pub const IDLE_GRACE: Duration = Duration::from_secs(5);
+
+/// Untätig: kaum Last und niedrigster Power Level (sofern bekannt)
+pub fn is_idle(sample: &Sample, frequencies: &[u64]) -> bool {
+    let lowest = frequencies.iter().min();
+    let at_lowest = match (sample.freq_hz, lowest) {
+        (Some(hz), Some(&min)) => hz <= min,
+        _ => true,
+    };
+    sample.busy_percent.is_some_and(|busy| busy < IDLE_BUSY_PERCENT) && at_lowest
+}
+
+/// Sampling-Intervall, das bei untätiger GPU bis `max` verdoppelt wird und
+/// bei Aktivität sofort auf `base` zurückfällt - damit der Monitor das SoC
+/// nicht selbst wach hält
+#[derive(Debug, Clone)]
+pub struct AdaptiveInterval {
+    base: Duration,
+    max: Duration,
+    current: Duration,
+    idle_since: Option<Instant>,
+}
+
+impl AdaptiveInterval {
+    pub fn new(base: Duration, max: Duration) -> Self {
+        AdaptiveInterval { base, max: max.max(base), current: base, idle_since: None }
+    }
+
+    /// Immer `base`
+    pub fn fixed(base: Duration) -> Self {
+        Self::new(base, base)
+    }
+
+    pub fn current(&self) -> Duration {
+        self.current
+    }
+
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    pub fn is_backed_off(&self) -> bool {
+        self.current > self.base
+    }
+
+    /// Nach jedem Sample; liefert die Wartezeit bis zum nächsten
+    pub fn update(&mut self, sample: &Sample, frequencies: &[u64]) -> Duration {
+        if !is_idle(sample, frequencies) {
+            self.idle_since = None;
+            self.current = self.base;
+            return self.current;
+        }
+        let since = *self.idle_since.get_or_insert(sample.time);
+        if sample.time.duration_since(since) >= IDLE_GRACE {
+            self.current = (self.current * 2).min(self.max);
+        }
+        self.current
+    }
+}
@@ -0,0 +1,72 @@
+//! Laufzeit-OPP-Tabelle aus debugfs (`/sys/kernel/debug/opp`)
+//!
+//! Zeigt, was der Kernel tatsächlich geladen hat - nützlich, um eigene
+//! Undervolt-/Overclock-Tabellen zu prüfen. Nur lesend.
+//!
+//! Layout: `opp/<gerät>/opp:<hz>/{rate_hz,u_volt_target,u_volt_min,u_volt_max,level}`,
+//! bei mehreren Reglern stehen die Spannungen unter `supply-N/`.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::sysfs::read_u64;
+
+pub const OPP_DEBUGFS_DIR: &str = "/sys/kernel/debug/opp";
+
+/// Namensteile von GPU-Geräten (`3d00000.gpu`, `5000000.qcom,kgsl-3d0`, ...)
+const GPU_DEVICE_HINTS: [&str; 3] = ["gpu", "kgsl", "adreno"];
+
+/// Ein OPP-Eintrag
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OppEntry {
+    pub rate_hz: u64,
+    pub microvolt_target: Option<u64>,
+    pub microvolt_min: Option<u64>,
+    pub microvolt_max: Option<u64>,
+    /// Performance-Level/Corner (neuere Kernel)
+    pub level: Option<u64>,
+}
+
+/// OPP-Tabelle eines Geräts, höchste Frequenz zuerst
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OppTable {
+    pub device: String,
+    pub entries: Vec<OppEntry>,
+}
+
+fn read_voltages(dir: &Path, entry: &mut OppEntry) {
+    entry.microvolt_target = read_u64(dir.join("u_volt_target")).ok();
+    entry.microvolt_min = read_u64(dir.join("u_volt_min")).ok();
+    entry.microvolt_max = read_u64(dir.join("u_volt_max")).ok();
+}
+
+fn read_entry(dir: &Path) -> Option<OppEntry> {
+    let mut entry = OppEntry { rate_hz: read_u64(dir.join("rate_hz")).ok()?, ..Default::default() };
+    entry.level = read_u64(dir.join("level")).ok().filter(|&l| l != 0 && l != u32::MAX as u64);
+    read_voltages(dir, &mut entry);
+    if entry.microvolt_target.is_none() {
+        read_voltages(&dir.join("supply-0"), &mut entry);
+    }
+    Some(entry)
+}
+
+/// Alle GPU-Tabellen unter `root` (Standard: [`OPP_DEBUGFS_DIR`])
+pub fn read_gpu_opp_tables(root: &Path) -> io::Result<Vec<OppTable>> {
+    let mut tables = Vec::new();
+    for device in fs::read_dir(root)?.flatten() {
+        let name = device.file_name().to_string_lossy().into_owned();
+        if !GPU_DEVICE_HINTS.iter().any(|hint| name.contains(hint)) {
+            continue;
+        }
+        let mut entries: Vec<OppEntry> = fs::read_dir(device.path())?
+            .flatten()
+            .filter(|e| e.file_name().to_string_lossy().starts_with("opp:"))
+            .filter_map(|e| read_entry(&e.path()))
+            .collect();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.rate_hz));
+        tables.push(OppTable { device: name, entries });
+    }
+    tables.sort_by(|a, b| a.device.cmp(&b.device));
+    Ok(tables)
+}
@@ -0,0 +1,179 @@
+//! Binärer Datenfeed für FPS-Overlays (Shared Memory, 64 Byte)
+//!
+//! Overlays lesen pro Frame; JSON wäre dafür zu teuer. Der Writer legt eine
+//! Datei an (Standard: `/dev/shm/adreno_ioctl.overlay`, auf Android
+//! `/data/local/tmp/adreno_ioctl.overlay`), mappt sie und aktualisiert einen
+//! einzigen Datensatz. Leser mappen die Datei nur lesend.
+//!
+//! Layout (little endian, `#[repr(C)]`, 64 Byte):
+//!
+//! | Offset | Typ   | Feld                                          |
+//! |--------|-------|-----------------------------------------------|
+//! | 0      | u32   | magic `ADRO` (0x4F524441)                     |
+//! | 4      | u16   | version (1)                                   |
+//! | 6      | u16   | size (64)                                     |
+//! | 8      | u32   | seq - ungerade während eines Updates          |
+//! | 12     | u32   | flags, siehe `FLAG_*`                         |
+//! | 16     | u64   | timestamp_ns (CLOCK_MONOTONIC)                |
+//! | 24     | u64   | freq_hz                                       |
+//! | 32     | u64   | max_freq_hz                                   |
+//! | 40     | u32   | busy_permille (0..1000)                       |
+//! | 44     | i32   | temp_millicelsius                             |
+//! | 48     | u32   | thermal_level (0 = ungedrosselt)              |
+//! | 52     | u32   | interval_us des Writers                       |
+//! | 56     | [u8;8]| reserviert (0)                                |
+//!
+//! Lesen nach dem Seqlock-Verfahren: `seq` lesen (ungerade: nochmal),
+//! Datensatz kopieren, `seq` erneut lesen - bei Gleichheit ist die Kopie
+//! konsistent. Ist `timestamp_ns` älter als einige Intervalle, läuft kein
+//! Writer mehr.
+
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::mem::offset_of;
+use std::os::fd::AsFd;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{fence, AtomicU32, Ordering};
+
+use zerocopy::{FromBytes, Immutable, IntoBytes};
+
+use crate::sys;
+
+pub const OVERLAY_MAGIC: u32 = 0x4F52_4441;
+pub const OVERLAY_VERSION: u16 = 1;
+pub const OVERLAY_SIZE: usize = 64;
+
+pub const FLAG_FREQ: u32 = 1 << 0;
+pub const FLAG_BUSY: u32 = 1 << 1;
+pub const FLAG_TEMP: u32 = 1 << 2;
+pub const FLAG_THERMAL: u32 = 1 << 3;
+
+/// Standardpfad des Feeds
+pub fn default_path() -> PathBuf {
+    if cfg!(target_os = "android") {
+        PathBuf::from("/data/local/tmp/adreno_ioctl.overlay")
+    } else {
+        PathBuf::from("/dev/shm/adreno_ioctl.overlay")
+    }
+}
+
+/// Ein Datensatz wie im Speicher
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, FromBytes, IntoBytes, Immutable)]
+pub struct OverlayRecord {
+    pub magic: u32,
+    pub version: u16,
+    pub size: u16,
+    pub seq: u32,
+    pub flags: u32,
+    pub timestamp_ns: u64,
+    pub freq_hz: u64,
+    pub max_freq_hz: u64,
+    pub busy_permille: u32,
+    pub temp_millicelsius: i32,
+    pub thermal_level: u32,
+    pub interval_us: u32,
+    pub reserved: [u8; 8],
+}
+
+const _: () = assert!(size_of::<OverlayRecord>() == OVERLAY_SIZE);
+
+impl OverlayRecord {
+    pub fn has(&self, flag: u32) -> bool {
+        self.flags & flag != 0
+    }
+}
+
+/// CLOCK_MONOTONIC in ns, wie im Feld `timestamp_ns`
+pub fn monotonic_ns() -> u64 {
+    sys::monotonic().as_nanos() as u64
+}
+
+/// Gemappter Datensatz
+struct Mapping {
+    map: sys::Mapping,
+}
+
+impl Mapping {
+    fn new(file: File, writable: bool) -> io::Result<Self> {
+        Ok(Mapping { map: sys::Mapping::shared(file.as_fd(), OVERLAY_SIZE, writable, 0)? })
+    }
+
+    fn seq(&self) -> &AtomicU32 {
+        self.map.atomic_u32(offset_of!(OverlayRecord, seq)).expect("seq lies inside the record")
+    }
+}
+
+/// Schreibt den Feed
+pub struct OverlayWriter {
+    map: Mapping,
+    path: PathBuf,
+}
+
+impl OverlayWriter {
+    /// Legt die Datei an (für alle lesbar) und mappt sie
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let file = OpenOptions::new().read(true).write(true).create(true).truncate(false).mode(0o644).open(path)?;
+        file.set_len(OVERLAY_SIZE as u64)?;
+        let writer = OverlayWriter { map: Mapping::new(file, true)?, path: path.to_path_buf() };
+        writer.publish(&OverlayRecord::default());
+        Ok(writer)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Veröffentlicht `record`; magic, version, size, seq und timestamp setzt der Writer
+    pub fn publish(&self, record: &OverlayRecord) {
+        let seq = self.map.seq();
+        let start = seq.load(Ordering::Relaxed) | 1;
+        seq.store(start, Ordering::Relaxed);
+        fence(Ordering::Release);
+        let mut out = *record;
+        out.magic = OVERLAY_MAGIC;
+        out.version = OVERLAY_VERSION;
+        out.size = OVERLAY_SIZE as u16;
+        out.seq = start;
+        if out.timestamp_ns == 0 {
+            out.timestamp_ns = monotonic_ns();
+        }
+        self.map.map.write(0, out);
+        fence(Ordering::Release);
+        seq.store(start.wrapping_add(1), Ordering::Release);
+    }
+}
+
+/// Liest den Feed (Referenz-Implementierung für Overlays)
+pub struct OverlayReader {
+    map: Mapping,
+}
+
+impl OverlayReader {
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        if file.metadata()?.len() < OVERLAY_SIZE as u64 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "overlay file too short"));
+        }
+        Ok(OverlayReader { map: Mapping::new(file, false)? })
+    }
+
+    /// Konsistente Kopie; `None` bei falschem magic oder dauerndem Update
+    pub fn read(&self) -> Option<OverlayRecord> {
+        let seq = self.map.seq();
+        for _ in 0..100 {
+            let before = seq.load(Ordering::Acquire);
+            if before & 1 != 0 {
+                std::hint::spin_loop();
+                continue;
+            }
+            let record: OverlayRecord = self.map.map.read(0)?;
+            fence(Ordering::Acquire);
+            if seq.load(Ordering::Relaxed) == before {
+                return (record.magic == OVERLAY_MAGIC && record.version == OVERLAY_VERSION).then_some(record);
+            }
+        }
+        None
+    }
+}
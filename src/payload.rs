@@ -0,0 +1,238 @@
+//! Property-Antworten feldweise dekodieren
+//!
+//! Statt eine ganze Struktur über den Puffer zu legen, liest [`Payload`]
+//! jedes Feld an seinem Offset, little-endian (alle Adreno-SoCs laufen LE,
+//! Captures lassen sich so auch auf anderen Hosts lesen). Bytes hinter dem
+//! letzten bekannten Feld werden ignoriert: neuere Kernel hängen Felder an,
+//! ohne die alten zu verschieben. Ein zu kurzer Puffer ergibt `None`.
+//!
+//! `long` und `size_t` sind so breit wie im Kernel des Geräts, das
+//! geantwortet hat: live wie `usize`, beim Abspielen laut Capture
+//! ([`crate::backend::word_size`]).
+
+use std::mem::size_of;
+
+use crate::backend;
+use crate::kgsl::{KgslDeviceInfo, KgslDevinfo, KgslVersionInfo};
+use crate::memstore::KgslShadowprop;
+use crate::timesync::KgslQtimerProp;
+
+/// Breite von `long`/`size_t` auf diesem Host
+pub const NATIVE_WORD: usize = size_of::<usize>();
+
+/// Lesesicht auf eine Property-Antwort
+#[derive(Debug, Clone, Copy)]
+pub struct Payload<'a> {
+    bytes: &'a [u8],
+    /// Breite von `long`/`size_t`, 4 oder 8
+    word: usize,
+}
+
+impl<'a> Payload<'a> {
+    /// Antwort der aktuellen Quelle (Gerät oder Capture)
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self::with_word(bytes, backend::word_size())
+    }
+
+    pub fn with_word(bytes: &'a [u8], word: usize) -> Self {
+        Payload { bytes, word }
+    }
+
+    pub fn word(&self) -> usize {
+        self.word
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn bytes_at(&self, offset: usize, len: usize) -> Option<&'a [u8]> {
+        self.bytes.get(offset..offset.checked_add(len)?)
+    }
+
+    pub fn u32_at(&self, offset: usize) -> Option<u32> {
+        Some(u32::from_le_bytes(self.bytes_at(offset, 4)?.try_into().ok()?))
+    }
+
+    pub fn u64_at(&self, offset: usize) -> Option<u64> {
+        Some(u64::from_le_bytes(self.bytes_at(offset, 8)?.try_into().ok()?))
+    }
+
+    /// `long`/`size_t` in der Breite des Geräts
+    pub fn word_at(&self, offset: usize) -> Option<u64> {
+        match self.word {
+            8 => self.u64_at(offset),
+            _ => self.u32_at(offset).map(u64::from),
+        }
+    }
+}
+
+/// Typ, der sich aus einer (auch längeren) Property-Antwort lesen lässt
+pub trait Decode: Sized {
+    /// Bytes bis zum Ende des letzten bekannten Felds bei `word` breitem `long`
+    fn size(word: usize) -> usize;
+
+    fn decode(payload: &Payload<'_>) -> Option<Self>;
+
+    /// Bequemlichkeit für Rohbytes der aktuellen Quelle
+    fn decode_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::decode(&Payload::new(bytes))
+    }
+}
+
+/// `unsigned int`-Properties (Flags, Modi, Größen)
+impl Decode for u32 {
+    fn size(_word: usize) -> usize {
+        4
+    }
+
+    fn decode(p: &Payload<'_>) -> Option<Self> {
+        p.u32_at(0)
+    }
+}
+
+/// Zeichenketten fester Länge, z.B. `KGSL_PROP_GPU_MODEL`
+impl<const N: usize> Decode for [u8; N] {
+    fn size(_word: usize) -> usize {
+        N
+    }
+
+    fn decode(p: &Payload<'_>) -> Option<Self> {
+        p.bytes_at(0, N)?.try_into().ok()
+    }
+}
+
+// ============================================================================
+// Bekannte Properties
+// ============================================================================
+
+/// `KGSL_PROP_DEVICE_INFO`, alte Form
+///
+/// | Offset | Feld               |
+/// |--------|--------------------|
+/// | 0      | `device_id`        |
+/// | 4      | `chip_id`          |
+/// | 8      | `mmu_enabled`      |
+/// | 12     | `gmem_gpubaseaddr` |
+impl Decode for KgslDeviceInfo {
+    fn size(_word: usize) -> usize {
+        16
+    }
+
+    fn decode(p: &Payload<'_>) -> Option<Self> {
+        Some(KgslDeviceInfo {
+            device_id: p.u32_at(0)?,
+            chip_id: p.u32_at(4)?,
+            mmu_enabled: p.u32_at(8)?,
+            gmem_gpubaseaddr: p.u32_at(12)?,
+        })
+    }
+}
+
+/// `KGSL_PROP_DEVICE_INFO`, `struct kgsl_devinfo`
+///
+/// | Offset (arm64) | Feld                       |
+/// |----------------|----------------------------|
+/// | 0              | `device_id`                |
+/// | 4              | `chip_id`                  |
+/// | 8              | `mmu_enabled`              |
+/// | 16             | `gmem_gpubaseaddr` (long)  |
+/// | 24             | `gpu_id`                   |
+/// | 32             | `gmem_sizebytes` (size_t)  |
+impl Decode for KgslDevinfo {
+    fn size(word: usize) -> usize {
+        if word == 8 { 40 } else { 24 }
+    }
+
+    fn decode(p: &Payload<'_>) -> Option<Self> {
+        // Auf 32 Bit liegen die langen Felder ohne Lücke bei 12, 16 und 20
+        let (base, gpu_id, gmem) = if p.word() == 8 { (16, 24, 32) } else { (12, 16, 20) };
+        Some(KgslDevinfo {
+            device_id: p.u32_at(0)?,
+            chip_id: p.u32_at(4)?,
+            mmu_enabled: p.u32_at(8)?,
+            gmem_gpubaseaddr: p.word_at(base)? as libc::c_ulong,
+            gpu_id: p.u32_at(gpu_id)?,
+            gmem_sizebytes: p.word_at(gmem)? as usize,
+            ..Default::default()
+        })
+    }
+}
+
+/// `KGSL_PROP_VERSION`: `driver_version` bei 0, `device_version` bei 4
+impl Decode for KgslVersionInfo {
+    fn size(_word: usize) -> usize {
+        8
+    }
+
+    fn decode(p: &Payload<'_>) -> Option<Self> {
+        Some(KgslVersionInfo { driver_version: p.u32_at(0)?, device_version: p.u32_at(4)? })
+    }
+}
+
+/// `KGSL_PROP_DEVICE_SHADOW`, `struct kgsl_shadowprop`
+///
+/// | Offset (arm64) | Feld               |
+/// |----------------|--------------------|
+/// | 0              | `gpuaddr` (long)   |
+/// | 8              | `size` (size_t)    |
+/// | 16             | `flags`            |
+impl Decode for KgslShadowprop {
+    fn size(word: usize) -> usize {
+        2 * word + 4
+    }
+
+    fn decode(p: &Payload<'_>) -> Option<Self> {
+        let word = p.word();
+        Some(KgslShadowprop {
+            gpuaddr: p.word_at(0)? as libc::c_ulong,
+            size: p.word_at(word)? as libc::size_t,
+            flags: p.u32_at(2 * word)?,
+            ..Default::default()
+        })
+    }
+}
+
+/// `KGSL_PROP_DEVICE_QTIMER`: `gpuaddr` (u64) bei 0, `size` bei 8
+impl Decode for KgslQtimerProp {
+    fn size(_word: usize) -> usize {
+        12
+    }
+
+    fn decode(p: &Payload<'_>) -> Option<Self> {
+        Some(KgslQtimerProp { gpuaddr: p.u64_at(0)?, size: p.u32_at(8)?, ..Default::default() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shadowprop_from_32bit_capture() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&0xfc00_0000u32.to_le_bytes());
+        bytes.extend_from_slice(&0x1000u32.to_le_bytes());
+        bytes.extend_from_slice(&3u32.to_le_bytes());
+        let shadow = KgslShadowprop::decode(&Payload::with_word(&bytes, 4)).unwrap();
+        assert_eq!(KgslShadowprop::size(4), 12);
+        assert_eq!(shadow.gpuaddr, 0xfc00_0000);
+        assert_eq!(shadow.size, 0x1000);
+        assert_eq!(shadow.flags, 3);
+    }
+
+    #[test]
+    fn devinfo_offsets_follow_word() {
+        let mut bytes = vec![0u8; 24];
+        bytes[16..20].copy_from_slice(&0x0703_0001u32.to_le_bytes());
+        bytes[20..24].copy_from_slice(&0x10_0000u32.to_le_bytes());
+        let info = KgslDevinfo::decode(&Payload::with_word(&bytes, 4)).unwrap();
+        assert_eq!(info.gpu_id, 0x0703_0001);
+        assert_eq!(info.gmem_sizebytes, 0x10_0000);
+        assert!(KgslDevinfo::decode(&Payload::with_word(&bytes, 8)).is_none());
+    }
+}
@@ -0,0 +1,202 @@
+//! Hardware-Zähler über `IOCTL_KGSL_PERFCOUNTER_*`
+//!
+//! Der Always-On-Zähler läuft im QTimer-Takt (19.2 MHz), unabhängig von
+//! GPU-Frequenz und Clock-Gating - damit misst er die verstrichene GPU-Zeit
+//! auch über DCVS-Wechsel hinweg. Zusammen mit den Busy-Zyklen des RBBM
+//! ergibt sich eine Auslastung über genau das eigene Messintervall, statt
+//! über das DCVS-Fenster, das `gpubusy` in sysfs zufällig gerade abdeckt.
+//!
+//! Reservierte Zähler gibt der Kernel beim Schließen des fds frei; [`PerfCounters`]
+//! gibt sie beim Drop schon vorher zurück.
+
+use std::fs::File;
+use std::io;
+use std::mem::size_of;
+use std::os::fd::AsRawFd;
+use std::time::Duration;
+
+use zerocopy::{FromBytes, Immutable, IntoBytes};
+
+use crate::kgsl::{kgsl_iow, kgsl_iowr};
+use crate::sys::ioctl;
+use crate::timesync::QTIMER_HZ;
+
+/// `KGSL_PERFCOUNTER_GROUP_*`
+pub const KGSL_PERFCOUNTER_GROUP_RBBM: u32 = 0x1;
+pub const KGSL_PERFCOUNTER_GROUP_ALWAYSON: u32 = 0x1B;
+
+/// Einziger Zähler der Always-On-Gruppe
+pub const ALWAYSON_COUNTABLE: u32 = 0;
+/// `PERF_RBBM_STATUS_MASKED`: GPU-Takte mit gesetztem Busy-Bit (A5xx bis A7xx)
+pub const RBBM_BUSY_COUNTABLE: u32 = 6;
+
+/// `struct kgsl_perfcounter_get`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub(crate) struct KgslPerfcounterGet {
+    groupid: u32,
+    countable: u32,
+    offset: u32,
+    offset_hi: u32,
+    _pad: u32,
+}
+
+/// `struct kgsl_perfcounter_put`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub(crate) struct KgslPerfcounterPut {
+    groupid: u32,
+    countable: u32,
+    _pad: [u32; 2],
+}
+
+/// `struct kgsl_perfcounter_read_group`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub(crate) struct KgslPerfcounterReadGroup {
+    groupid: u32,
+    countable: u32,
+    value: u64,
+}
+
+/// `struct kgsl_perfcounter_read`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub(crate) struct KgslPerfcounterRead {
+    /// Adresse des `KgslPerfcounterReadGroup`-Arrays
+    reads: usize,
+    count: u32,
+    _pad: [u32; 2],
+    #[cfg(target_pointer_width = "64")]
+    _pad1: u32,
+}
+
+pub const IOCTL_KGSL_PERFCOUNTER_GET: u32 = kgsl_iowr(0x38, size_of::<KgslPerfcounterGet>());
+pub const IOCTL_KGSL_PERFCOUNTER_PUT: u32 = kgsl_iow(0x39, size_of::<KgslPerfcounterPut>());
+pub const IOCTL_KGSL_PERFCOUNTER_READ: u32 = kgsl_iowr(0x3B, size_of::<KgslPerfcounterRead>());
+
+/// Reserviert `countable` in `group`
+fn perfcounter_get(fd: i32, groupid: u32, countable: u32) -> io::Result<()> {
+    let mut req = KgslPerfcounterGet { groupid, countable, ..Default::default() };
+    ioctl(fd, IOCTL_KGSL_PERFCOUNTER_GET, &mut req)
+}
+
+fn perfcounter_put(fd: i32, groupid: u32, countable: u32) -> io::Result<()> {
+    let mut req = KgslPerfcounterPut { groupid, countable, _pad: [0; 2] };
+    ioctl(fd, IOCTL_KGSL_PERFCOUNTER_PUT, &mut req)
+}
+
+/// Reservierte Zähler (Gruppe, Countable), gemeinsam in einem ioctl gelesen
+pub struct PerfCounters {
+    file: File,
+    counters: Vec<(u32, u32)>,
+}
+
+impl PerfCounters {
+    /// Reserviert alle `counters`; schlägt einer fehl, werden die übrigen freigegeben
+    pub fn reserve(file: File, counters: &[(u32, u32)]) -> io::Result<Self> {
+        let mut set = PerfCounters { file, counters: Vec::with_capacity(counters.len()) };
+        for &(group, countable) in counters {
+            perfcounter_get(set.file.as_raw_fd(), group, countable)?;
+            set.counters.push((group, countable));
+        }
+        Ok(set)
+    }
+
+    /// Aktuelle Werte, Reihenfolge wie bei [`PerfCounters::reserve`]
+    pub fn read(&self) -> io::Result<Vec<u64>> {
+        let mut reads: Vec<KgslPerfcounterReadGroup> = self
+            .counters
+            .iter()
+            .map(|&(groupid, countable)| KgslPerfcounterReadGroup { groupid, countable, value: 0 })
+            .collect();
+        let mut req = KgslPerfcounterRead {
+            reads: reads.as_mut_ptr() as usize,
+            count: reads.len() as u32,
+            ..Default::default()
+        };
+        ioctl(self.file.as_raw_fd(), IOCTL_KGSL_PERFCOUNTER_READ, &mut req)?;
+        Ok(reads.iter().map(|r| r.value).collect())
+    }
+}
+
+impl Drop for PerfCounters {
+    fn drop(&mut self) {
+        for &(group, countable) in &self.counters {
+            let _ = perfcounter_put(self.file.as_raw_fd(), group, countable);
+        }
+    }
+}
+
+/// Ticks des Always-On-Zählers als Dauer
+pub fn always_on_duration(ticks: u64) -> Duration {
+    Duration::from_nanos((ticks as u128 * 1_000_000_000 / QTIMER_HZ as u128) as u64)
+}
+
+/// Der Always-On-Zähler allein
+pub struct AlwaysOnCounter {
+    counters: PerfCounters,
+}
+
+impl AlwaysOnCounter {
+    pub fn open(file: File) -> io::Result<Self> {
+        let counters = PerfCounters::reserve(file, &[(KGSL_PERFCOUNTER_GROUP_ALWAYSON, ALWAYSON_COUNTABLE)])?;
+        Ok(AlwaysOnCounter { counters })
+    }
+
+    /// Aktueller Stand in QTimer-Ticks
+    pub fn read(&self) -> io::Result<u64> {
+        Ok(self.counters.read()?[0])
+    }
+
+    /// Verstrichene GPU-Zeit seit einem früheren [`AlwaysOnCounter::read`]
+    pub fn elapsed_since(&self, start: u64) -> io::Result<Duration> {
+        Ok(always_on_duration(self.read()?.wrapping_sub(start)))
+    }
+}
+
+/// Stand von Always-On- und Busy-Zähler
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusySnapshot {
+    pub always_on: u64,
+    pub busy_cycles: u64,
+}
+
+impl BusySnapshot {
+    /// Auslastung seit `prev`. Busy-Takte werden mit der mittleren Frequenz
+    /// beider Samples in Zeit umgerechnet - das einzige Stück, das noch von
+    /// sysfs abhängt.
+    pub fn busy_percent(&self, prev: &BusySnapshot, freq_hz: u64) -> Option<f64> {
+        let elapsed = self.always_on.checked_sub(prev.always_on)?;
+        let busy = self.busy_cycles.checked_sub(prev.busy_cycles)?;
+        if elapsed == 0 || freq_hz == 0 {
+            return None;
+        }
+        let busy_s = busy as f64 / freq_hz as f64;
+        let elapsed_s = elapsed as f64 / QTIMER_HZ as f64;
+        Some((busy_s / elapsed_s * 100.0).min(100.0))
+    }
+}
+
+/// Always-On- und RBBM-Busy-Zähler für die Auslastung
+pub struct BusyCounters {
+    counters: PerfCounters,
+}
+
+impl BusyCounters {
+    pub fn open(file: File) -> io::Result<Self> {
+        let counters = PerfCounters::reserve(
+            file,
+            &[
+                (KGSL_PERFCOUNTER_GROUP_ALWAYSON, ALWAYSON_COUNTABLE),
+                (KGSL_PERFCOUNTER_GROUP_RBBM, RBBM_BUSY_COUNTABLE),
+            ],
+        )?;
+        Ok(BusyCounters { counters })
+    }
+
+    pub fn snapshot(&self) -> io::Result<BusySnapshot> {
+        let values = self.counters.read()?;
+        Ok(BusySnapshot { always_on: values[0], busy_cycles: values[1] })
+    }
+}
@@ -0,0 +1,94 @@
+//! Laufzeit-Selbstprüfung: passt die Umgebung zu diesem Binary?
+//!
+//! Das offizielle Release-Binary ist statisch gelinkt (Profil `static`,
+//! siehe `.cargo/config.toml`) und läuft damit auch in Recovery und
+//! Minimal-Shells ohne passenden Loader. Findet sich kein KGSL-Gerät,
+//! erklärt [`no_device_hint`], woran es auf diesem System liegt.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use crate::driver::BUILD_PROPS;
+use crate::turnip::find_msm_render_node;
+
+/// Wo das Programm läuft
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Platform {
+    Android,
+    /// TWRP/AOSP-Recovery: Android-Kernel, aber kein `/system`
+    Recovery,
+    Linux,
+}
+
+impl Platform {
+    pub fn label(self) -> &'static str {
+        match self {
+            Platform::Android => "Android",
+            Platform::Recovery => "Android recovery",
+            Platform::Linux => "Linux (not Android)",
+        }
+    }
+}
+
+pub fn detect() -> Platform {
+    if BUILD_PROPS.iter().any(|p| Path::new(p).exists()) || std::env::var_os("ANDROID_ROOT").is_some() {
+        Platform::Android
+    } else if Path::new("/sbin/recovery").exists() || Path::new("/system/bin/recovery").exists() {
+        Platform::Recovery
+    } else {
+        Platform::Linux
+    }
+}
+
+/// Hinweis, warum hier kein KGSL-Gerät existiert
+pub fn no_device_hint() -> String {
+    match (detect(), find_msm_render_node()) {
+        (Platform::Linux, Some(node)) => format!(
+            "This is mainline Linux: the Adreno GPU is driven by msm DRM ({}), not KGSL. \
+             KGSL ioctls do not exist here - use Mesa tools, or 'adreno_ioctl turnip'",
+            node.display()
+        ),
+        (Platform::Linux, None) => "This is not Android and no Adreno GPU driver is loaded. \
+             The tool needs a Qualcomm Android kernel - run it on the phone, e.g. with --adb SERIAL"
+            .to_string(),
+        (Platform::Recovery, _) => "Recovery usually boots without the GPU driver. \
+             Reboot to Android, or check 'ls /dev/kgsl*' after loading msm_kgsl"
+            .to_string(),
+        (Platform::Android, _) => "Android without /dev/kgsl-3d0: SELinux may hide it from this shell, \
+             try 'adb root' or 'su'"
+            .to_string(),
+    }
+}
+
+/// ELF-Programmheader-Typ des Loaders
+const PT_INTERP: u32 = 3;
+
+/// Loader aus dem ELF-Header (`None` = statisch gelinkt)
+pub fn elf_interpreter(elf: &[u8]) -> Option<String> {
+    // Nur 64-Bit Little Endian - alle unterstützten Targets
+    if elf.get(..5)? != b"\x7fELF\x02" || elf.get(5) != Some(&1) {
+        return None;
+    }
+    let u16_at = |o: usize| elf.get(o..o + 2).map(|b| u16::from_le_bytes([b[0], b[1]]) as usize);
+    let u32_at = |o: usize| elf.get(o..o + 4).map(|b| u32::from_le_bytes(b.try_into().unwrap()));
+    let u64_at = |o: usize| elf.get(o..o + 8).map(|b| u64::from_le_bytes(b.try_into().unwrap()) as usize);
+    let (phoff, phentsize, phnum) = (u64_at(0x20)?, u16_at(0x36)?, u16_at(0x38)?);
+    (0..phnum).find_map(|i| {
+        let ph = phoff + i * phentsize;
+        if u32_at(ph)? != PT_INTERP {
+            return None;
+        }
+        let (offset, size) = (u64_at(ph + 8)?, u64_at(ph + 32)?);
+        let name = elf.get(offset..offset + size)?;
+        Some(String::from_utf8_lossy(name).trim_end_matches('\0').to_string())
+    })
+}
+
+/// Loader dieses Programms; Fehler, wenn das eigene Binary nicht lesbar ist
+pub fn own_interpreter() -> std::io::Result<Option<String>> {
+    // Header und Programmheader liegen am Dateianfang
+    let mut head = Vec::with_capacity(4096);
+    File::open("/proc/self/exe")?.take(4096).read_to_end(&mut head)?;
+    Ok(elf_interpreter(&head))
+}
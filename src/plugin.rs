@@ -0,0 +1,145 @@
+//! Sammler aus externen Bibliotheken (`dlopen`, stabile C-ABI)
+//!
+//! Ein Plugin ist eine Shared Library mit dem Symbol
+//! `adreno_plugin_init`, das einen [`PluginDescriptor`] zurückgibt. Das
+//! Layout ist in `include/adreno_plugin.h` festgeschrieben und wird nur
+//! über [`PLUGIN_ABI_VERSION`] geändert. Geladene Plugins sind normale
+//! [`Collector`]s und lassen sich wie die eingebauten auswählen.
+//!
+//! Statische Builds (`cargo android`, `cargo static`) können keine
+//! Bibliotheken nachladen; [`load`] meldet dann den Fehler von `dlopen`.
+
+use std::ffi::{CStr, c_char, c_int};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::collector::Collector;
+use crate::sink::Record;
+use crate::sys::{metric_name, plugin_collect, plugin_name, plugin_supported, PluginLibrary};
+
+/// Version des Plugin-Layouts; erhöht bei jeder inkompatiblen Änderung
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// Name der Einsprungfunktion
+pub const PLUGIN_INIT_SYMBOL: &CStr = c"adreno_plugin_init";
+
+/// Verzeichnis mit `*.so`-Plugins, zusätzlich zu `--plugin`
+pub const PLUGIN_DIR_ENV: &str = "ADRENO_IOCTL_PLUGIN_DIR";
+
+/// Höchstzahl Messwerte pro `collect`-Aufruf
+pub const MAX_PLUGIN_METRICS: usize = 64;
+
+/// Ein Messwert (`struct adreno_metric`)
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct PluginMetric {
+    /// Nullterminiert, gültig bis zum nächsten `collect`
+    pub(crate) name: *const c_char,
+    pub(crate) value: f64,
+}
+
+impl PluginMetric {
+    /// Der Name muss bis zum nächsten `collect` leben, siehe Plugin-Header
+    pub fn new(name: &CStr, value: f64) -> Self {
+        PluginMetric { name: name.as_ptr(), value }
+    }
+}
+
+impl Default for PluginMetric {
+    fn default() -> Self {
+        PluginMetric { name: std::ptr::null(), value: 0.0 }
+    }
+}
+
+/// `collect` eines Plugins: schreibt bis zu `max` Werte nach `out`;
+/// Anzahl oder negativer errno
+pub type CollectFn = unsafe extern "C" fn(out: *mut PluginMetric, max: usize) -> c_int;
+
+/// Beschreibung eines Plugins (`struct adreno_plugin`)
+///
+/// Die Felder liest nur der Host; Plugins bauen den Deskriptor über
+/// [`PluginDescriptor::new`].
+#[repr(C)]
+pub struct PluginDescriptor {
+    /// Muss [`PLUGIN_ABI_VERSION`] sein
+    pub(crate) abi_version: u32,
+    /// Kurzname, nullterminiert, statisch
+    pub(crate) name: *const c_char,
+    /// 1 wenn die Quelle auf diesem System lesbar ist
+    pub(crate) supported: Option<unsafe extern "C" fn() -> c_int>,
+    pub(crate) collect: Option<CollectFn>,
+}
+
+/// Ein geladenes Plugin
+pub struct Plugin {
+    library: PluginLibrary,
+    name: String,
+    path: PathBuf,
+}
+
+impl Plugin {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Lädt ein Plugin und prüft die ABI-Version
+///
+/// # Safety
+///
+/// Wie [`PluginLibrary::open`]: die Bibliothek läuft ungeprüft im Prozess
+/// und muss `include/adreno_plugin.h` einhalten.
+#[allow(unsafe_code)]
+pub unsafe fn load(path: &Path) -> Result<Plugin, String> {
+    let fail = |e: String| format!("Cannot load plugin {}: {}", path.display(), e);
+    // SAFETY: Vertrag an den Aufrufer weitergereicht
+    let library = unsafe { PluginLibrary::open(path, PLUGIN_INIT_SYMBOL) }.map_err(fail)?;
+    let descriptor = library.descriptor();
+    if descriptor.abi_version != PLUGIN_ABI_VERSION {
+        return Err(fail(format!("ABI version {}, expected {}", descriptor.abi_version, PLUGIN_ABI_VERSION)));
+    }
+    let name = plugin_name(descriptor).filter(|_| descriptor.collect.is_some());
+    let name = name.ok_or_else(|| fail("descriptor without name or collect".to_string()))?;
+    Ok(Plugin { library, name, path: path.to_path_buf() })
+}
+
+/// Alle `*.so` eines Verzeichnisses, nach Namen sortiert; Fehler je Datei
+///
+/// # Safety
+///
+/// Wie [`load`], für jede Bibliothek im Verzeichnis.
+#[allow(unsafe_code)]
+pub unsafe fn load_dir(dir: &Path) -> io::Result<Vec<Result<Plugin, String>>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "so"))
+        .collect();
+    paths.sort();
+    // SAFETY: Vertrag an den Aufrufer weitergereicht
+    Ok(paths.iter().map(|path| unsafe { load(path) }).collect())
+}
+
+impl Collector for Plugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn supported(&self) -> bool {
+        plugin_supported(self.library.descriptor())
+    }
+
+    fn collect(&mut self) -> io::Result<Record> {
+        let mut metrics = [PluginMetric::default(); MAX_PLUGIN_METRICS];
+        let count = plugin_collect(self.library.descriptor(), &mut metrics).expect("checked in load");
+        if count < 0 {
+            return Err(io::Error::from_raw_os_error(-count));
+        }
+        let record = metrics[..(count as usize).min(MAX_PLUGIN_METRICS)]
+            .iter()
+            .filter_map(|m| Some((metric_name(m)?, m.value)))
+            .fold(Record::new(&self.name), |record, (name, value)| record.field(&name, value));
+        Ok(record)
+    }
+}
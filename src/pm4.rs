@@ -0,0 +1,179 @@
+//! Minimale PM4 Paket-Erzeugung für den Command Processor (CP)
+//!
+//! A5xx und neuer verwenden Type-7 Pakete, ältere Generationen Type-3.
+
+/// CP Opcodes
+pub const CP_NOP: u8 = 0x10;
+pub const CP_WAIT_FOR_IDLE: u8 = 0x26;
+pub const CP_MEM_WRITE: u8 = 0x3d;
+
+/// Ungerade Parität über die Bits von `val` (wie `pm4_odd_parity_bit` in freedreno)
+pub const fn odd_parity(val: u32) -> u32 {
+    let mut v = val;
+    v ^= v >> 16;
+    v ^= v >> 8;
+    v ^= v >> 4;
+    v &= 0xf;
+    (!0x6996u32 >> v) & 1
+}
+
+/// Type-7 Header (A5xx+)
+pub const fn pkt7(opcode: u8, count: u16) -> u32 {
+    let cnt = count as u32 & 0x3fff;
+    let op = opcode as u32 & 0x7f;
+    (7 << 28) | cnt | (odd_parity(cnt) << 15) | (op << 16) | (odd_parity(op) << 23)
+}
+
+/// Type-3 Header (A3xx/A4xx)
+pub const fn pkt3(opcode: u8, count: u16) -> u32 {
+    (3 << 30) | (((count as u32).wrapping_sub(1) & 0x3fff) << 16) | ((opcode as u32) << 8)
+}
+
+/// Schreibt Pakete für eine bestimmte GPU-Generation
+#[derive(Debug, Clone, Default)]
+pub struct CommandStream {
+    type7: bool,
+    pub dwords: Vec<u32>,
+}
+
+impl CommandStream {
+    /// `generation` wie in [`crate::features::generation`]
+    pub fn new(generation: u8) -> Self {
+        CommandStream { type7: generation >= 5, dwords: Vec::new() }
+    }
+
+    /// Paket mit Nutzdaten anhängen
+    pub fn packet(&mut self, opcode: u8, payload: &[u32]) -> &mut Self {
+        let count = payload.len() as u16;
+        self.dwords.push(if self.type7 { pkt7(opcode, count) } else { pkt3(opcode, count.max(1)) });
+        // Type-3 Pakete haben immer mindestens ein Nutzwort
+        if !self.type7 && payload.is_empty() {
+            self.dwords.push(0);
+        }
+        self.dwords.extend_from_slice(payload);
+        self
+    }
+
+    /// `count` NOP-Nutzwörter (Inhalt wird vom CP ignoriert)
+    pub fn nop(&mut self, count: usize) -> &mut Self {
+        self.packet(CP_NOP, &vec![0; count])
+    }
+
+    pub fn wait_for_idle(&mut self) -> &mut Self {
+        self.packet(CP_WAIT_FOR_IDLE, &[])
+    }
+
+    /// Schreibt `values` an die GPU-Adresse `gpuaddr`
+    pub fn mem_write(&mut self, gpuaddr: u64, values: &[u32]) -> &mut Self {
+        let mut payload = vec![gpuaddr as u32];
+        if self.type7 {
+            payload.push((gpuaddr >> 32) as u32);
+        }
+        payload.extend_from_slice(values);
+        self.packet(CP_MEM_WRITE, &payload)
+    }
+
+    pub fn len_bytes(&self) -> usize {
+        self.dwords.len() * 4
+    }
+
+    pub fn as_bytes(&self) -> Vec<u8> {
+        self.dwords.iter().flat_map(|d| d.to_le_bytes()).collect()
+    }
+}
+
+// ============================================================================
+// Dekodieren
+// ============================================================================
+
+/// Ein dekodiertes Paket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub opcode: u8,
+    pub payload: Vec<u32>,
+}
+
+/// Name bekannter Opcodes
+pub fn opcode_name(opcode: u8) -> Option<&'static str> {
+    match opcode {
+        CP_MEM_WRITE => Some("CP_MEM_WRITE"),
+        CP_NOP => Some("CP_NOP"),
+        CP_WAIT_FOR_IDLE => Some("CP_WAIT_FOR_IDLE"),
+        _ => None,
+    }
+}
+
+/// Zerlegt Dwords in Pakete; Gegenstück zu [`CommandStream`]
+///
+/// Type-4/Type-0 Registerschreiber erzeugt [`CommandStream`] nicht, sie gelten als Fehler.
+pub fn decode(dwords: &[u32], generation: u8) -> Result<Vec<Packet>, String> {
+    let type7 = generation >= 5;
+    let mut packets = Vec::new();
+    let mut offset = 0;
+    while let Some(&header) = dwords.get(offset) {
+        let (opcode, count) = if type7 {
+            if header >> 28 != 7 {
+                return Err(format!("dword {}: no type-7 header (0x{:08x})", offset, header));
+            }
+            let count = header & 0x3fff;
+            let opcode = (header >> 16) & 0x7f;
+            if (header >> 15) & 1 != odd_parity(count) || (header >> 23) & 1 != odd_parity(opcode) {
+                return Err(format!("dword {}: parity mismatch (0x{:08x})", offset, header));
+            }
+            (opcode as u8, count as usize)
+        } else {
+            if header >> 30 != 3 {
+                return Err(format!("dword {}: no type-3 header (0x{:08x})", offset, header));
+            }
+            ((header >> 8) as u8, ((header >> 16) & 0x3fff) as usize + 1)
+        };
+        let payload = dwords
+            .get(offset + 1..offset + 1 + count)
+            .ok_or_else(|| format!("dword {}: {} payload dwords, {} left", offset, count, dwords.len() - offset - 1))?;
+        packets.push(Packet { opcode, payload: payload.to_vec() });
+        offset += 1 + count;
+    }
+    Ok(packets)
+}
+
+/// Wie [`decode`], aber aus Little-Endian Bytes (z.B. eine IB aus einem Dump)
+pub fn decode_bytes(bytes: &[u8], generation: u8) -> Result<Vec<Packet>, String> {
+    if !bytes.len().is_multiple_of(4) {
+        return Err(format!("{} bytes are not a multiple of 4", bytes.len()));
+    }
+    let dwords: Vec<u32> = bytes.chunks_exact(4).map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]])).collect();
+    decode(&dwords, generation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_round_trip() {
+        for generation in [4, 6] {
+            let mut stream = CommandStream::new(generation);
+            stream.nop(2).mem_write(0x1_0000_1000, &[7, 8]);
+            let packets = decode_bytes(&stream.as_bytes(), generation).unwrap();
+            assert_eq!(packets.len(), 2);
+            assert_eq!((packets[0].opcode, packets[0].payload.len()), (CP_NOP, 2));
+            assert_eq!(packets[1].opcode, CP_MEM_WRITE);
+            assert_eq!(packets[1].payload.last(), Some(&8));
+        }
+        // Type-3 ohne Nutzdaten bekommt ein Füllwort
+        let mut stream = CommandStream::new(4);
+        stream.wait_for_idle();
+        assert_eq!(decode(&stream.dwords, 4).unwrap(), [Packet { opcode: CP_WAIT_FOR_IDLE, payload: vec![0] }]);
+    }
+
+    #[test]
+    fn decode_rejects_broken_streams() {
+        let header = pkt7(CP_NOP, 3);
+        assert!(decode(&[header, 0], 6).is_err());
+        assert!(decode(&[header ^ (1 << 15), 0, 0, 0], 6).is_err());
+        assert!(decode(&[0x4000_0000], 6).is_err());
+        assert!(decode(&[0], 4).is_err());
+        assert!(decode_bytes(&[0; 3], 6).is_err());
+        assert_eq!(decode(&[], 6), Ok(Vec::new()));
+    }
+}
@@ -0,0 +1,107 @@
+//! Leistungsschätzung aus Frequenz-Residenz und Auslastung
+//!
+//! Telefone haben keine GPU-Stromschiene für Userspace. Das Modell ist
+//! `P = P_static + P_dyn(f) * busy` mit `P_dyn(f) = P_dyn_max * (f / f_max)^2.5`:
+//! die Spannung steigt mit der Frequenz (V²·f), flacht unten aber ab.
+//! Die Koeffizienten stammen aus der Chip-Datenbank und sind grobe Schätzungen.
+
+use std::time::Duration;
+
+use crate::chip::ChipSpec;
+use crate::monitor::Sample;
+
+/// Exponent der Frequenzabhängigkeit der dynamischen Leistung
+const DYNAMIC_EXPONENT: f64 = 2.5;
+
+/// Woraus eine Schätzung berechnet wurde
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EstimateSource {
+    /// Busy-Zeit je Power Level aus `gpu_clock_stats`
+    Residency,
+    /// Aktuelle Frequenz und Busy-Prozent
+    BusyPercent,
+}
+
+/// Leistung über ein Sample-Intervall
+#[derive(Debug, Clone, Copy)]
+pub struct PowerEstimate {
+    pub milliwatts: f64,
+    pub joules: f64,
+    pub source: EstimateSource,
+}
+
+/// Leistungsmodell eines Chips
+#[derive(Debug, Clone, Copy)]
+pub struct PowerModel {
+    pub static_mw: f64,
+    pub dynamic_mw: f64,
+    pub max_freq_hz: u64,
+}
+
+impl PowerModel {
+    pub fn new(spec: &ChipSpec, max_freq_hz: u64) -> Self {
+        PowerModel { static_mw: spec.static_mw as f64, dynamic_mw: spec.dynamic_mw as f64, max_freq_hz }
+    }
+
+    /// Dynamische Leistung bei 100% Last und `freq_hz`
+    pub fn dynamic_mw_at(&self, freq_hz: u64) -> f64 {
+        if self.max_freq_hz == 0 {
+            return 0.0;
+        }
+        let ratio = (freq_hz as f64 / self.max_freq_hz as f64).min(1.0);
+        self.dynamic_mw * ratio.powf(DYNAMIC_EXPONENT)
+    }
+
+    /// Schätzung für das Intervall `prev` → `sample`
+    ///
+    /// `frequencies` ist die Frequenz je Power Level (Index wie `clock_stats`).
+    pub fn estimate(&self, prev: &Sample, sample: &Sample, frequencies: &[u64]) -> Option<PowerEstimate> {
+        let dt = sample.time.duration_since(prev.time).as_secs_f64();
+        if dt <= 0.0 {
+            return None;
+        }
+
+        let residency = sample.clock_stats.len() == prev.clock_stats.len()
+            && sample.clock_stats.len() == frequencies.len()
+            && !frequencies.is_empty();
+        if residency {
+            let dynamic_j: f64 = sample
+                .clock_stats
+                .iter()
+                .zip(&prev.clock_stats)
+                .zip(frequencies)
+                .map(|((now, before), &freq)| now.saturating_sub(*before) as f64 / 1e6 * self.dynamic_mw_at(freq) / 1000.0)
+                .sum();
+            let joules = dynamic_j + self.static_mw / 1000.0 * dt;
+            return Some(PowerEstimate { milliwatts: joules / dt * 1000.0, joules, source: EstimateSource::Residency });
+        }
+
+        let freq = sample.freq_hz?;
+        let busy = sample.busy_percent? / 100.0;
+        let milliwatts = self.static_mw + self.dynamic_mw_at(freq) * busy;
+        Some(PowerEstimate { milliwatts, joules: milliwatts / 1000.0 * dt, source: EstimateSource::BusyPercent })
+    }
+}
+
+/// Integriert Energie über eine Monitoring-Session
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnergyMeter {
+    pub joules: f64,
+    pub elapsed: Duration,
+    pub peak_mw: f64,
+}
+
+impl EnergyMeter {
+    pub fn add(&mut self, estimate: &PowerEstimate, interval: Duration) {
+        self.joules += estimate.joules;
+        self.elapsed += interval;
+        self.peak_mw = self.peak_mw.max(estimate.milliwatts);
+    }
+
+    pub fn average_mw(&self) -> f64 {
+        match self.elapsed.as_secs_f64() {
+            0.0 => 0.0,
+            secs => self.joules / secs * 1000.0,
+        }
+    }
+}
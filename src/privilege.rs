@@ -0,0 +1,69 @@
+//! Rechteabbau nach dem Öffnen von Gerät und sysfs-Knoten
+//!
+//! Lang laufende Modi (`monitor`, `daemon`) brauchen root nur zum Öffnen.
+//! Danach wechselt der Prozess auf einen unprivilegierten Benutzer
+//! (Standard: `nobody`), bevor er Netzwerkeingaben verarbeitet. Bereits
+//! offene Dateien bleiben lesbar; sysfs-Knoten, die später neu geöffnet
+//! würden, hält [`crate::sysfs::hold`] vorher offen.
+//!
+//! Auf Linux gilt `setresuid` für alle Threads nur dank libc-Emulation;
+//! deshalb vor dem Start weiterer Threads aufrufen.
+
+use std::ffi::CString;
+use std::fmt;
+use std::io;
+
+use crate::sys;
+
+/// Ziel ohne `--user`
+pub const DEFAULT_USER: &str = "nobody";
+
+/// Benutzer und primäre Gruppe nach dem Wechsel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Identity {
+    pub name: String,
+    pub uid: u32,
+    pub gid: u32,
+}
+
+impl fmt::Display for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (uid {}, gid {})", self.name, self.uid, self.gid)
+    }
+}
+
+pub fn is_root() -> bool {
+    sys::geteuid() == 0
+}
+
+/// Benutzername oder `UID[:GID]` (ohne GID gilt die UID auch als GID)
+pub fn lookup(user: &str) -> Result<Identity, String> {
+    if let Some((uid, gid)) = parse_ids(user) {
+        return Ok(Identity { name: user.to_string(), uid, gid });
+    }
+    let name = CString::new(user).map_err(|_| format!("Invalid user name {:?}", user))?;
+    let (name, uid, gid) = sys::getpwnam(&name)
+        .ok_or_else(|| format!("Unknown user '{}' (use a name from /etc/passwd or UID[:GID])", user))?;
+    Ok(Identity { name, uid, gid })
+}
+
+fn parse_ids(text: &str) -> Option<(u32, u32)> {
+    match text.split_once(':') {
+        Some((uid, gid)) => Some((uid.parse().ok()?, gid.parse().ok()?)),
+        None => text.parse().ok().map(|uid| (uid, uid)),
+    }
+}
+
+/// Wechselt endgültig auf `identity`: Zusatzgruppen weg, GID, dann UID,
+/// `no_new_privs` gegen setuid-Binaries in Alert-Hooks
+pub fn drop_to(identity: &Identity) -> io::Result<()> {
+    if identity.uid == 0 {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "target user is root"));
+    }
+    sys::set_identity(identity.uid, identity.gid)?;
+    // Der Rückweg muss jetzt scheitern
+    if sys::try_setuid(0) {
+        return Err(io::Error::new(io::ErrorKind::PermissionDenied, "could regain root after dropping privileges"));
+    }
+    Ok(())
+}
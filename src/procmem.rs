@@ -0,0 +1,265 @@
+//! GPU-Speicher je Prozess (`/sys/class/kgsl/kgsl/proc/<pid>`) und Änderungs-Events
+//!
+//! KGSL legt pro Prozess mit offenem Gerät ein Verzeichnis an. Der
+//! [`MemoryWatcher`] vergleicht aufeinanderfolgende Stände und meldet Start,
+//! Wachstum, Schrumpfen und Ende an seine Abonnenten. inotify auf dem
+//! proc-Verzeichnis weckt ihn bei neuen oder verschwundenen Prozessen sofort,
+//! Größenänderungen werden im Intervall abgefragt (sysfs meldet sie nicht).
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::os::fd::AsFd;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::appid::{self, AndroidUid, PackageDb, ProcessIdentity};
+use crate::json::Json;
+use crate::sink::Record;
+use crate::sys;
+use crate::sysroot;
+
+/// Verzeichnis der Prozess-Einträge
+pub const KGSL_PROC_DIR: &str = "/sys/class/kgsl/kgsl/proc";
+
+/// Dateien, die keine eigene Speicherart sind
+const NON_MEMTYPE_FILES: [&str; 4] = ["gpumem_mapped", "gpumem_unmapped", "imported_mem", "total_gpumem"];
+
+/// GPU-Speicher eines Prozesses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessMemory {
+    pub pid: u32,
+    /// Anzeigename mit Profil, siehe [`ProcessIdentity`]
+    pub name: String,
+    /// Reale UID aus `/proc/<pid>/status`
+    pub uid: Option<u32>,
+    pub package: Option<String>,
+    /// `total_gpumem`, sonst Summe aus `kernel` und `user`
+    pub total_bytes: u64,
+    /// Alle numerischen Einträge (kernel, user, ion, egl_image, ...)
+    pub entries: Vec<(String, u64)>,
+}
+
+impl ProcessMemory {
+    /// Einträge ohne die Summen- und Mapping-Zähler
+    pub fn memtypes(&self) -> impl Iterator<Item = &(String, u64)> {
+        self.entries.iter().filter(|(name, _)| !NON_MEMTYPE_FILES.contains(&name.as_str()))
+    }
+
+    pub fn to_json(&self) -> Json {
+        let memtypes = self.memtypes().fold(Json::object(), |json, (name, bytes)| json.field(name, *bytes));
+        Json::object()
+            .field("pid", self.pid)
+            .field("name", self.name.as_str())
+            .field("uid", self.uid)
+            .field("user_id", self.uid.map(|uid| AndroidUid::new(uid).user_id))
+            .field("package", self.package.as_deref())
+            .field("total_bytes", self.total_bytes)
+            .field("memtypes", memtypes)
+    }
+}
+
+fn read_process(dir: &Path, pid: u32, packages: &PackageDb) -> Option<ProcessMemory> {
+    let mut entries: Vec<(String, u64)> = fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_str()?.to_string();
+            let value = fs::read_to_string(entry.path()).ok()?.split_whitespace().next()?.parse().ok()?;
+            Some((name, value))
+        })
+        .collect();
+    entries.sort();
+    let value = |key: &str| entries.iter().find(|(name, _)| name == key).map(|(_, v)| *v);
+    let total_bytes = value("total_gpumem").unwrap_or_else(|| value("kernel").unwrap_or(0) + value("user").unwrap_or(0));
+    let identity = ProcessIdentity::read(pid, packages);
+    Some(ProcessMemory {
+        pid,
+        name: identity.as_ref().map_or_else(|| "?".to_string(), ProcessIdentity::to_string),
+        uid: identity.as_ref().and_then(|i| i.uid).map(|u| u.uid),
+        package: identity.and_then(|i| i.package),
+        total_bytes,
+        entries,
+    })
+}
+
+/// Alle Prozesse mit GPU-Speicher, nach PID sortiert
+pub fn read_processes() -> io::Result<Vec<ProcessMemory>> {
+    let packages = appid::packages();
+    let mut processes: Vec<ProcessMemory> = fs::read_dir(sysroot::resolve(KGSL_PROC_DIR))?
+        .flatten()
+        .filter_map(|entry| {
+            let pid = entry.file_name().to_str()?.parse().ok()?;
+            read_process(&entry.path(), pid, &packages)
+        })
+        .collect();
+    processes.sort_by_key(|p| p.pid);
+    Ok(processes)
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// Änderung im GPU-Speicher eines Prozesses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemoryEvent {
+    Started { pid: u32, name: String, bytes: u64 },
+    Grew { pid: u32, name: String, from: u64, to: u64 },
+    Shrank { pid: u32, name: String, from: u64, to: u64 },
+    Exited { pid: u32, name: String, last_bytes: u64 },
+}
+
+impl MemoryEvent {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            MemoryEvent::Started { .. } => "started",
+            MemoryEvent::Grew { .. } => "grew",
+            MemoryEvent::Shrank { .. } => "shrank",
+            MemoryEvent::Exited { .. } => "exited",
+        }
+    }
+
+    pub fn pid(&self) -> u32 {
+        match *self {
+            MemoryEvent::Started { pid, .. }
+            | MemoryEvent::Grew { pid, .. }
+            | MemoryEvent::Shrank { pid, .. }
+            | MemoryEvent::Exited { pid, .. } => pid,
+        }
+    }
+
+    /// Datensatz für [`crate::sink`], gleiche Spalten für alle Arten
+    pub fn to_record(&self) -> Record {
+        let (name, bytes, delta) = match self {
+            MemoryEvent::Started { name, bytes, .. } => (name, *bytes, *bytes as i64),
+            MemoryEvent::Grew { name, from, to, .. } | MemoryEvent::Shrank { name, from, to, .. } => {
+                (name, *to, *to as i64 - *from as i64)
+            }
+            MemoryEvent::Exited { name, last_bytes, .. } => (name, 0, -(*last_bytes as i64)),
+        };
+        Record::new("memory_event")
+            .field("event", self.kind())
+            .field("pid", self.pid())
+            .field("name", name.as_str())
+            .field("bytes", bytes)
+            .field("delta", delta)
+    }
+
+    pub fn to_json(&self) -> Json {
+        let json = Json::object().field("event", self.kind()).field("pid", self.pid());
+        match self {
+            MemoryEvent::Started { name, bytes, .. } => json.field("name", name.as_str()).field("bytes", *bytes),
+            MemoryEvent::Grew { name, from, to, .. } | MemoryEvent::Shrank { name, from, to, .. } => json
+                .field("name", name.as_str())
+                .field("from", *from)
+                .field("to", *to)
+                .field("delta", *to as i64 - *from as i64),
+            MemoryEvent::Exited { name, last_bytes, .. } => {
+                json.field("name", name.as_str()).field("last_bytes", *last_bytes)
+            }
+        }
+    }
+}
+
+/// Abonnent für [`MemoryEvent`]s
+pub type Subscriber = Box<dyn FnMut(&MemoryEvent) + Send>;
+
+/// Vergleicht Stände und verteilt Events
+pub struct MemoryWatcher {
+    known: BTreeMap<u32, ProcessMemory>,
+    /// Kleinere Änderungen werden nicht gemeldet
+    min_change: u64,
+    subscribers: Vec<Subscriber>,
+    notify: Option<Inotify>,
+}
+
+impl MemoryWatcher {
+    /// Merkt sich den aktuellen Stand als Ausgangspunkt (ohne Events)
+    pub fn new(min_change: u64) -> io::Result<Self> {
+        let known = read_processes()?.into_iter().map(|p| (p.pid, p)).collect();
+        Ok(MemoryWatcher { known, min_change, subscribers: Vec::new(), notify: Inotify::watch(&sysroot::resolve(KGSL_PROC_DIR)).ok() })
+    }
+
+    /// Aktuell bekannte Prozesse
+    pub fn processes(&self) -> impl Iterator<Item = &ProcessMemory> {
+        self.known.values()
+    }
+
+    pub fn subscribe(&mut self, subscriber: impl FnMut(&MemoryEvent) + Send + 'static) {
+        self.subscribers.push(Box::new(subscriber));
+    }
+
+    /// Ob inotify aktiv ist (sonst nur Intervall-Abfrage)
+    pub fn uses_inotify(&self) -> bool {
+        self.notify.is_some()
+    }
+
+    /// Wartet höchstens `timeout` (kürzer bei inotify-Events), dann [`Self::poll`]
+    pub fn wait(&mut self, timeout: Duration) -> io::Result<Vec<MemoryEvent>> {
+        match &self.notify {
+            Some(notify) => notify.wait(timeout),
+            None => std::thread::sleep(timeout),
+        }
+        self.poll()
+    }
+
+    /// Liest den neuen Stand und meldet die Unterschiede
+    pub fn poll(&mut self) -> io::Result<Vec<MemoryEvent>> {
+        let current: BTreeMap<u32, ProcessMemory> = read_processes()?.into_iter().map(|p| (p.pid, p)).collect();
+        let mut events = Vec::new();
+        for (pid, now) in &current {
+            match self.known.get(pid) {
+                None => events.push(MemoryEvent::Started { pid: *pid, name: now.name.clone(), bytes: now.total_bytes }),
+                Some(before) if now.total_bytes.abs_diff(before.total_bytes) < self.min_change.max(1) => {}
+                Some(before) => {
+                    let (from, to, name) = (before.total_bytes, now.total_bytes, now.name.clone());
+                    events.push(match to > from {
+                        true => MemoryEvent::Grew { pid: *pid, name, from, to },
+                        false => MemoryEvent::Shrank { pid: *pid, name, from, to },
+                    });
+                }
+            }
+        }
+        for (pid, before) in &self.known {
+            if !current.contains_key(pid) {
+                events.push(MemoryEvent::Exited { pid: *pid, name: before.name.clone(), last_bytes: before.total_bytes });
+            }
+        }
+
+        // Unter der Schwelle bleibt der alte Bezugswert, damit langsames Wachstum auffällt
+        for event in &events {
+            let pid = event.pid();
+            match current.get(&pid) {
+                Some(now) => self.known.insert(pid, now.clone()),
+                None => self.known.remove(&pid),
+            };
+        }
+        for subscriber in &mut self.subscribers {
+            for event in &events {
+                subscriber(event);
+            }
+        }
+        Ok(events)
+    }
+}
+
+/// inotify auf ein Verzeichnis (IN_CREATE/IN_DELETE)
+struct Inotify {
+    file: File,
+}
+
+impl Inotify {
+    fn watch(dir: &Path) -> io::Result<Self> {
+        let fd = sys::inotify(dir, libc::IN_CREATE | libc::IN_DELETE)?;
+        Ok(Inotify { file: File::from(fd) })
+    }
+
+    /// Blockiert bis zu einem Event oder `timeout`, liest die Events weg
+    fn wait(&self, timeout: Duration) {
+        if sys::poll_readable(self.file.as_fd(), timeout).unwrap_or(false) {
+            let mut buf = [0u8; 4096];
+            while (&self.file).read(&mut buf).is_ok_and(|n| n > 0) {}
+        }
+    }
+}
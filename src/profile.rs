@@ -0,0 +1,80 @@
+//! Profile: geprüfte Bündel von Einstellungen
+//!
+//! Ein Profil wird pro Gerät geplant ([`Profile::plan`]): Power Levels
+//! richten sich nach `num_pwrlevels`, der Governor wird nur gesetzt, wenn
+//! der Kernel ihn anbietet, und Dateien, die es nicht gibt, fallen weg.
+//! Angewendet wird über den [`SettingsGuard`](crate::settings::SettingsGuard),
+//! zurückgesetzt über das Journal.
+
+use std::path::Path;
+
+use crate::settings::Setting;
+use crate::sysfs;
+
+/// Standard-Governor von KGSL
+pub const DEFAULT_GOVERNOR: &str = "msm-adreno-tz";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    Powersave,
+    Balanced,
+    Performance,
+    Benchmark,
+}
+
+impl Profile {
+    pub const ALL: [Profile; 4] = [Profile::Powersave, Profile::Balanced, Profile::Performance, Profile::Benchmark];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Profile::Powersave => "powersave",
+            Profile::Balanced => "balanced",
+            Profile::Performance => "performance",
+            Profile::Benchmark => "benchmark",
+        }
+    }
+
+    pub fn about(self) -> &'static str {
+        match self {
+            Profile::Powersave => "Cap at the middle power level, short idle timer",
+            Profile::Balanced => "Full frequency range with the stock governor",
+            Profile::Performance => "Floor at the middle power level, long idle timer",
+            Profile::Benchmark => "Pin the highest level, clocks always on (reproducible runs)",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Profile> {
+        Profile::ALL.into_iter().find(|p| p.name() == name)
+    }
+
+    /// Zu schreibende Werte für das Gerät in `dir`, in Schreibreihenfolge
+    ///
+    /// `max_pwrlevel` kommt vor `min_pwrlevel`, weil KGSL das Minimum auf das
+    /// Maximum klemmt.
+    pub fn plan(self, dir: &Path) -> Result<Vec<(Setting, String)>, String> {
+        let levels = sysfs::num_pwrlevels(dir).map_err(|e| format!("Cannot read num_pwrlevels: {}", e))?;
+        let lowest = levels.saturating_sub(1);
+        let middle = levels / 2;
+        let governors = sysfs::read_string(dir.join("devfreq/available_governors")).unwrap_or_default();
+        let governor = |name: &str| governors.split_whitespace().any(|g| g == name).then(|| name.to_string());
+
+        let (max, min, idle_ms, governor, forced) = match self {
+            Profile::Powersave => (middle, lowest, 50, governor(DEFAULT_GOVERNOR), false),
+            Profile::Balanced => (0, lowest, 80, governor(DEFAULT_GOVERNOR), false),
+            Profile::Performance => (0, middle, 500, governor(DEFAULT_GOVERNOR), false),
+            Profile::Benchmark => (0, 0, 10_000, governor("performance"), true),
+        };
+        let forced = forced as u32;
+        let mut plan = Vec::new();
+        if let Some(governor) = governor {
+            plan.push((Setting::Governor, governor));
+        }
+        plan.push((Setting::MaxPwrlevel, max.to_string()));
+        plan.push((Setting::MinPwrlevel, min.to_string()));
+        plan.push((Setting::IdleTimer, idle_ms.to_string()));
+        plan.push((Setting::ForceClkOn, forced.to_string()));
+        plan.push((Setting::ForceNoNap, forced.to_string()));
+        plan.retain(|(setting, _)| setting.path(dir).exists());
+        Ok(plan)
+    }
+}
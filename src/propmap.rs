@@ -0,0 +1,267 @@
+//! Property-Nummern und KGSL-Quellbäume
+//!
+//! Die Property-IDs sind in `msm_kgsl.h` festgelegt und in allen Bäumen
+//! gleich, aber nicht jeder Baum kennt jede Property: `msm-4.14` endet bei
+//! `SECURE_CTXT_SUPPORT`, die `gen7`-Bäume (A7xx, Kernel 5.10 bis 6.1)
+//! bringen LPAC und Ray Tracing, `gen8` (Kernel 6.6+) AQE. [`PROPERTY_MAP`]
+//! hält je Property den ältesten Baum; den Baum des Geräts ermittelt
+//! [`tree`] einmal aus den Antworten des Treibers. Höhere Funktionen fragen
+//! hier nach der ID statt eine Nummer fest zu verdrahten.
+
+use std::fmt;
+use std::fs;
+use std::mem::size_of;
+use std::sync::OnceLock;
+
+use crate::backend::getproperty_ioctl;
+use crate::caps::{self, KgslCapabilities};
+use crate::kgsl::{KgslDeviceInfo, KgslVersionInfo, IOCTL_KGSL_DEVICE_GETPROPERTY};
+use crate::memstore::KgslShadowprop;
+use crate::sysroot;
+use crate::timesync::KgslQtimerProp;
+
+/// Kernel-Release des laufenden Systems
+const OSRELEASE: &str = "/proc/sys/kernel/osrelease";
+
+/// KGSL-Quellbaum, geordnet nach Alter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum KgslTree {
+    /// Kernel 4.14 und älter (A5xx/A6xx)
+    Msm414,
+    Msm419,
+    Msm54,
+    /// Kernel 5.10 bis 6.1, `adreno_gen7`
+    Gen7,
+    /// Kernel 6.6+, `adreno_gen8`
+    Gen8,
+}
+
+impl KgslTree {
+    /// Baum zu `uname -r`, z.B. "5.15.123-android14-11-g..."
+    pub fn from_kernel_release(release: &str) -> Option<Self> {
+        let mut parts = release.split(['.', '-']);
+        let major: u32 = parts.next()?.parse().ok()?;
+        let minor: u32 = parts.next()?.parse().ok()?;
+        Some(match (major, minor) {
+            (0..=3, _) | (4, 0..=14) => KgslTree::Msm414,
+            (4, _) => KgslTree::Msm419,
+            (5, 0..=9) => KgslTree::Msm54,
+            (5, _) | (6, 0..=5) => KgslTree::Gen7,
+            _ => KgslTree::Gen8,
+        })
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            KgslTree::Msm414 => "msm-4.14",
+            KgslTree::Msm419 => "msm-4.19",
+            KgslTree::Msm54 => "msm-5.4",
+            KgslTree::Gen7 => "gen7",
+            KgslTree::Gen8 => "gen8",
+        }
+    }
+}
+
+impl fmt::Display for KgslTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Logische Property, unabhängig von der Nummer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Prop {
+    DeviceInfo,
+    DeviceShadow,
+    MmuEnable,
+    InterruptWaits,
+    Version,
+    GpuResetStat,
+    PwrCtrl,
+    MinAccessLength,
+    UbwcMode,
+    DeviceQtimer,
+    SecureBufferAlignment,
+    SecureCtxtSupport,
+    QueryCapabilities,
+    GpuModel,
+    VkDeviceId,
+    IsLpacEnabled,
+    IsRaytracingEnabled,
+    IsFastblendEnabled,
+    IsAqeEnabled,
+}
+
+impl Prop {
+    /// Name wie in `msm_kgsl.h`
+    pub fn name(self) -> &'static str {
+        match self {
+            Prop::DeviceInfo => "KGSL_PROP_DEVICE_INFO",
+            Prop::DeviceShadow => "KGSL_PROP_DEVICE_SHADOW",
+            Prop::MmuEnable => "KGSL_PROP_MMU_ENABLE",
+            Prop::InterruptWaits => "KGSL_PROP_INTERRUPT_WAITS",
+            Prop::Version => "KGSL_PROP_VERSION",
+            Prop::GpuResetStat => "KGSL_PROP_GPU_RESET_STAT",
+            Prop::PwrCtrl => "KGSL_PROP_PWRCTRL",
+            Prop::MinAccessLength => "KGSL_PROP_MIN_ACCESS_LENGTH",
+            Prop::UbwcMode => "KGSL_PROP_UBWC_MODE",
+            Prop::DeviceQtimer => "KGSL_PROP_DEVICE_QTIMER",
+            Prop::SecureBufferAlignment => "KGSL_PROP_SECURE_BUFFER_ALIGNMENT",
+            Prop::SecureCtxtSupport => "KGSL_PROP_SECURE_CTXT_SUPPORT",
+            Prop::QueryCapabilities => "KGSL_PROP_QUERY_CAPABILITIES",
+            Prop::GpuModel => "KGSL_PROP_GPU_MODEL",
+            Prop::VkDeviceId => "KGSL_PROP_VK_DEVICE_ID",
+            Prop::IsLpacEnabled => "KGSL_PROP_IS_LPAC_ENABLED",
+            Prop::IsRaytracingEnabled => "KGSL_PROP_IS_RAYTRACING_ENABLED",
+            Prop::IsFastblendEnabled => "KGSL_PROP_IS_FASTBLEND_ENABLED",
+            Prop::IsAqeEnabled => "KGSL_PROP_IS_AQE_ENABLED",
+        }
+    }
+}
+
+/// (Property, ID, Payload in Bytes, ältester Baum mit der Property)
+pub type Entry = (Prop, u32, usize, KgslTree);
+
+/// Alle Properties, nach ID sortiert
+pub const PROPERTY_MAP: &[Entry] = &[
+    (Prop::DeviceInfo, 0x01, size_of::<KgslDeviceInfo>(), KgslTree::Msm414),
+    (Prop::DeviceShadow, 0x02, size_of::<KgslShadowprop>(), KgslTree::Msm414),
+    (Prop::MmuEnable, 0x06, 4, KgslTree::Msm414),
+    (Prop::InterruptWaits, 0x07, 4, KgslTree::Msm414),
+    (Prop::Version, 0x08, size_of::<KgslVersionInfo>(), KgslTree::Msm414),
+    (Prop::GpuResetStat, 0x09, 4, KgslTree::Msm414),
+    (Prop::PwrCtrl, 0x0E, 4, KgslTree::Msm414),
+    (Prop::MinAccessLength, 0x1A, 4, KgslTree::Msm414),
+    (Prop::UbwcMode, 0x1B, 4, KgslTree::Msm414),
+    (Prop::DeviceQtimer, 0x20, size_of::<KgslQtimerProp>(), KgslTree::Msm414),
+    (Prop::SecureBufferAlignment, 0x23, 4, KgslTree::Msm414),
+    (Prop::SecureCtxtSupport, 0x24, 4, KgslTree::Msm414),
+    (Prop::QueryCapabilities, 0x27, size_of::<KgslCapabilities>(), KgslTree::Msm419),
+    (Prop::GpuModel, 0x29, 32, KgslTree::Msm54),
+    (Prop::VkDeviceId, 0x2A, 4, KgslTree::Msm54),
+    (Prop::IsLpacEnabled, 0x2B, 4, KgslTree::Gen7),
+    (Prop::IsRaytracingEnabled, 0x2D, 4, KgslTree::Gen7),
+    (Prop::IsFastblendEnabled, 0x2E, 4, KgslTree::Gen7),
+    (Prop::IsAqeEnabled, 0x30, 4, KgslTree::Gen8),
+];
+
+/// Eintrag einer Property
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PropertyId {
+    pub id: u32,
+    /// Payload-Größe laut Map
+    pub size: usize,
+    /// Ältester Baum, der die Property kennt
+    pub since: KgslTree,
+}
+
+impl PropertyId {
+    /// `false`: der Baum kennt die Property laut Map nicht; gefragt wird
+    /// trotzdem (Backports sind häufig), Fehler heißen dann "nicht vorhanden"
+    pub fn expected_in(self, tree: KgslTree) -> bool {
+        tree >= self.since
+    }
+}
+
+/// Eintrag von `prop`, ohne I/O
+pub fn property(prop: Prop) -> PropertyId {
+    let &(_, id, size, since) =
+        PROPERTY_MAP.iter().find(|(p, ..)| *p == prop).expect("every Prop has a PROPERTY_MAP entry");
+    PropertyId { id, size, since }
+}
+
+/// ID von `prop`
+pub fn property_id(prop: Prop) -> u32 {
+    property(prop).id
+}
+
+// ============================================================================
+// Baum-Erkennung
+// ============================================================================
+
+/// Vom Treiber ermittelter Baum; ein Kernel hat genau einen KGSL-Treiber
+static TREE: OnceLock<KgslTree> = OnceLock::new();
+
+/// Baum des Treibers hinter `fd`, einmal pro Prozess ermittelt
+///
+/// Fragt die jüngsten Properties ab, neueste zuerst. Antwortet der
+/// Treiber gar nicht als KGSL (kein EINVAL), gilt ungecacht der Baum zu
+/// `uname -r`; unbekannt zählt als neuester.
+pub fn tree(fd: i32) -> KgslTree {
+    if let Some(&tree) = TREE.get() {
+        return tree;
+    }
+    match probe_tree(fd) {
+        Some(tree) => *TREE.get_or_init(|| tree),
+        None => fs::read_to_string(sysroot::resolve(OSRELEASE))
+            .ok()
+            .and_then(|release| KgslTree::from_kernel_release(release.trim()))
+            .unwrap_or(KgslTree::Gen8),
+    }
+}
+
+/// `Some(true)`: vorhanden, `Some(false)`: EINVAL, `None`: andere Fehler
+fn answers(fd: i32, prop: Prop) -> Option<bool> {
+    let mut value = [0u8; 4];
+    match getproperty_ioctl(fd, IOCTL_KGSL_DEVICE_GETPROPERTY, property_id(prop), &mut value) {
+        Ok(()) => Some(true),
+        Err(e) if e.raw_os_error() == Some(libc::EINVAL) => Some(false),
+        Err(_) => None,
+    }
+}
+
+fn probe_tree(fd: i32) -> Option<KgslTree> {
+    const PROBES: [(Prop, KgslTree); 3] = [
+        (Prop::IsAqeEnabled, KgslTree::Gen8),
+        (Prop::IsLpacEnabled, KgslTree::Gen7),
+        (Prop::VkDeviceId, KgslTree::Msm54),
+    ];
+    for (prop, tree) in PROBES {
+        if answers(fd, prop)? {
+            return Some(tree);
+        }
+    }
+    match caps::probe_query_capabilities(fd) {
+        Ok(()) => Some(KgslTree::Msm419),
+        Err(e) if e.raw_os_error() == Some(libc::EINVAL) => Some(KgslTree::Msm414),
+        Err(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tree_from_kernel_release() {
+        let tree = |release| KgslTree::from_kernel_release(release);
+        assert_eq!(tree("3.18.140-perf"), Some(KgslTree::Msm414));
+        assert_eq!(tree("4.14.190-perf+"), Some(KgslTree::Msm414));
+        assert_eq!(tree("4.19.157-perf-g1234"), Some(KgslTree::Msm419));
+        assert_eq!(tree("5.4.210-qgki-g5678"), Some(KgslTree::Msm54));
+        assert_eq!(tree("5.10.168-android12-9-00001"), Some(KgslTree::Gen7));
+        assert_eq!(tree("5.15.123-android14-11-gabcdef"), Some(KgslTree::Gen7));
+        assert_eq!(tree("6.1.57-android14-11"), Some(KgslTree::Gen7));
+        assert_eq!(tree("6.6.30-android15-8"), Some(KgslTree::Gen8));
+        assert_eq!(tree("linux"), None);
+        assert_eq!(tree("5"), None);
+    }
+
+    #[test]
+    fn property_marks_newer_trees() {
+        let model = property(Prop::GpuModel);
+        assert_eq!(model, PropertyId { id: 0x29, size: 32, since: KgslTree::Msm54 });
+        assert!(model.expected_in(KgslTree::Gen7));
+        assert!(!model.expected_in(KgslTree::Msm414));
+        assert!(property(Prop::IsAqeEnabled).expected_in(KgslTree::Gen8));
+        assert!(!property(Prop::IsAqeEnabled).expected_in(KgslTree::Gen7));
+        assert_eq!(property(Prop::DeviceInfo).size, size_of::<KgslDeviceInfo>());
+    }
+
+    #[test]
+    fn property_map_is_sorted_and_unique() {
+        for pair in PROPERTY_MAP.windows(2) {
+            assert!(pair[0].1 < pair[1].1, "{} before {}", pair[0].0.name(), pair[1].0.name());
+        }
+    }
+}
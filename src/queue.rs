@@ -0,0 +1,70 @@
+//! Eingereichte, noch nicht abgeschlossene Kommandos je Context (debugfs)
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::sched::DEBUGFS_KGSL_DIR;
+use crate::sysroot;
+
+/// Queue-Stand eines Contexts aus `debugfs/kgsl/<dev>/ctx/<id>`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContextQueue {
+    pub id: u32,
+    pub process: Option<String>,
+    pub pid: Option<u32>,
+    pub queued: u32,
+    pub retired: u32,
+}
+
+impl ContextQueue {
+    /// Eingereicht, aber noch nicht retired (mit Überlauf)
+    pub fn inflight(&self) -> u32 {
+        let diff = self.queued.wrapping_sub(self.retired) as i32;
+        diff.max(0) as u32
+    }
+}
+
+pub(crate) fn value_after<'a>(text: &'a str, key: &str) -> Option<&'a str> {
+    let start = text.find(key)? + key.len();
+    text[start..].split_whitespace().next()
+}
+
+/// Parst eine ctx-Datei, z.B.
+/// `id: 5 type: gl priority: 1 process_name: app (1234) tid: 1240`
+/// `timestamps: queued: 120 consumed: 119 retired: 118 global:9876`
+pub fn parse_ctx_debugfs(id: u32, text: &str) -> Option<ContextQueue> {
+    let pid = text.find("process_name:").and_then(|i| {
+        let after = &text[i..];
+        let (open, close) = (after.find('(')?, after.find(')')?);
+        after.get(open + 1..close)?.trim().parse().ok()
+    });
+    Some(ContextQueue {
+        id,
+        process: value_after(text, "process_name:").map(str::to_string),
+        pid,
+        queued: value_after(text, "queued:")?.parse().ok()?,
+        retired: value_after(text, "retired:")?.parse().ok()?,
+    })
+}
+
+/// ctx-Verzeichnis eines Geräts in debugfs
+pub fn ctx_dir(device_path: &str) -> PathBuf {
+    let name = Path::new(device_path).file_name().and_then(|n| n.to_str()).unwrap_or("kgsl-3d0");
+    sysroot::resolve(DEBUGFS_KGSL_DIR).join(name).join("ctx")
+}
+
+/// Liest alle Contexts; leer, wenn debugfs nicht gemountet oder nicht lesbar ist
+pub fn read_context_queues(device_path: &str) -> Vec<ContextQueue> {
+    let Ok(entries) = fs::read_dir(ctx_dir(device_path)) else {
+        return Vec::new();
+    };
+    let mut queues: Vec<ContextQueue> = entries
+        .flatten()
+        .filter_map(|e| {
+            let id = e.file_name().to_str()?.parse().ok()?;
+            parse_ctx_debugfs(id, &fs::read_to_string(e.path()).ok()?)
+        })
+        .collect();
+    queues.sort_by_key(|q| q.id);
+    queues
+}
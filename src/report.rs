@@ -0,0 +1,154 @@
+//! Text- und JSON-Darstellung der Geräteinfo
+//!
+//! Die Berichte werden als `String` gebaut statt direkt gedruckt, damit die
+//! Golden-Tests in `tests/golden.rs` sie mit eingecheckten Ausgaben
+//! vergleichen können. [`to_ascii`] ist dieselbe Übersetzung, die `--plain`
+//! auf stdout anwendet.
+
+use std::fmt::Write as _;
+use std::mem::size_of;
+
+use crate::bus::DdrType;
+use crate::chip::decode_chip_id;
+use crate::json::Json;
+use crate::kgsl::{KgslDeviceInfo, KgslVersionInfo};
+use crate::sys::IntoBytes;
+use crate::vkjson::pci_style_id;
+use crate::warnings::Warnings;
+
+/// Optionale Werte neben der Geräteinfo, fehlende als Warnung vermerkt
+#[derive(Debug, Clone, Default)]
+pub struct InfoExtras {
+    pub version: Option<KgslVersionInfo>,
+    pub freq_hz: Option<u32>,
+    pub model: Option<String>,
+    pub gmem_bytes: Option<u64>,
+    pub vk_device_id: Option<u32>,
+    pub ddr_type: Option<DdrType>,
+    pub warnings: Warnings,
+}
+
+/// Der Info-Kasten von `info`
+pub fn render_gpu_info(info: &KgslDeviceInfo, extras: &InfoExtras) -> String {
+    let chip_info = decode_chip_id(info.chip_id);
+    let mut out = String::new();
+
+    let _ = writeln!(out, "╔══════════════════════════════════════════════════════╗");
+    let _ = writeln!(out, "║                 ADRENO GPU INFORMATION               ║");
+    let _ = writeln!(out, "╠══════════════════════════════════════════════════════╣");
+    let _ = writeln!(out, "║  📱 Device: {}", chip_info.model_name);
+
+    if let Some(snapdragon) = &chip_info.snapdragon_model {
+        let _ = writeln!(out, "║     Typically found in: {}", snapdragon);
+    }
+
+    let _ = writeln!(out, "║  🏷️  Chip ID: 0x{:08x} (v{}.{}.{}.{})",
+        chip_info.raw_id,
+        chip_info.major,
+        chip_info.minor,
+        chip_info.patch,
+        chip_info.revision
+    );
+    let _ = writeln!(out, "║  🔢 Device ID: 0x{:08x}", info.device_id);
+    let _ = writeln!(out, "║  🛡️  MMU: {}", if info.mmu_enabled != 0 { "✅ Enabled" } else { "❌ Disabled" });
+    let _ = match chip_info.spec() {
+        Some(spec) => writeln!(out, "║  💾 GMEM Base: 0x{:08x} (size {} KB, see `gmem`)", info.gmem_gpubaseaddr, spec.gmem_bytes / 1024),
+        None => writeln!(out, "║  💾 GMEM Base: 0x{:08x}", info.gmem_gpubaseaddr),
+    };
+    let _ = writeln!(out, "║  🎯 Generation: Adreno {}", chip_info.adreno_generation);
+
+    if let Some(freq_mhz) = extras.freq_hz {
+        let _ = writeln!(out, "║  ⚡ Frequency: {} MHz", freq_mhz / 1000000);
+    }
+
+    if let Some(ver) = &extras.version {
+        let _ = writeln!(out, "║  📊 Driver: 0x{:08x} | Device: 0x{:08x}",
+            ver.driver_version, ver.device_version);
+    }
+    if let Some(id) = extras.vk_device_id {
+        let _ = writeln!(out, "║  🌋 Vulkan ID: {}", pci_style_id(id));
+    }
+    if let Some(ddr) = extras.ddr_type {
+        let _ = writeln!(out, "║  🧮 Memory: {}", ddr.label());
+    }
+
+    let _ = writeln!(out, "║  📏 Structure: {} bytes", size_of::<KgslDeviceInfo>());
+
+    // Raw bytes für Entwickler
+    let _ = writeln!(out, "╠══════════════════════════════════════════════════════╣");
+    out.push_str("║  Raw Bytes: ");
+    for (i, byte) in info.as_bytes().iter().enumerate() {
+        if i > 0 && i % 4 == 0 { out.push(' '); }
+        let _ = write!(out, "{:02x}", byte);
+    }
+    out.push('\n');
+
+    let _ = writeln!(out, "╚══════════════════════════════════════════════════════╝");
+    out
+}
+
+/// Liste fehlgeschlagener Abfragen
+pub fn render_warnings(warnings: &Warnings) -> String {
+    let mut out = format!("⚠️  Warnings ({} probes failed):\n", warnings.len());
+    for warning in warnings.iter() {
+        let _ = writeln!(out, "   • {}", warning);
+    }
+    out
+}
+
+/// `info --json`
+pub fn gpu_info_json(info: &KgslDeviceInfo, extras: &InfoExtras) -> Json {
+    let chip = decode_chip_id(info.chip_id);
+    Json::object()
+        .field("device_id", info.device_id)
+        .field("chip_id", format!("0x{:08x}", info.chip_id))
+        .field("model", extras.model.clone().unwrap_or(chip.model_name))
+        .field("generation", chip.adreno_generation)
+        .field("mmu_enabled", info.mmu_enabled != 0)
+        .field("gmem_base", info.gmem_gpubaseaddr)
+        .field("gmem_bytes", extras.gmem_bytes)
+        .field("freq_hz", extras.freq_hz)
+        .field("driver_version", extras.version.map(|v| v.driver_version))
+        .field("device_version", extras.version.map(|v| v.device_version))
+        .field("vk_device_id", extras.vk_device_id.map(pci_style_id))
+        .field("ddr_type", extras.ddr_type.map(DdrType::label))
+        .field("warnings", extras.warnings.to_json())
+}
+
+/// Übersetzt Text in ASCII mit gleicher Anzeigebreite
+pub fn to_ascii(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            c if c.is_ascii() => out.push(c),
+            // Variation Selector / Zero Width Joiner haben keine Breite
+            '\u{fe0f}' | '\u{200d}' => {}
+            '✅' => out.push_str("+ "),
+            '❌' => out.push_str("x "),
+            '⚠' => out.push_str("! "),
+            '➖' => out.push_str("- "),
+            '❓' | '❔' | '🔎' => out.push_str("? "),
+            '⚪' => out.push_str("o "),
+            '═' => out.push('='),
+            '─' => out.push('-'),
+            '║' | '│' => out.push('|'),
+            '╔' | '╗' | '╚' | '╝' | '╠' | '╣' | '┌' | '┐' | '└' | '┘' => out.push('+'),
+            '•' => out.push('-'),
+            '→' | '↳' => out.push('>'),
+            '←' => out.push('<'),
+            '█' => out.push('#'),
+            '░' | '·' => out.push('.'),
+            'ä' => out.push('a'),
+            'ö' => out.push('o'),
+            'ü' => out.push('u'),
+            'ß' => out.push('s'),
+            c if is_emoji(c) => out.push_str("* "),
+            _ => out.push('?'),
+        }
+    }
+    out
+}
+
+fn is_emoji(c: char) -> bool {
+    matches!(c as u32, 0x2139 | 0x2300..=0x27BF | 0x2B00..=0x2BFF | 0x1F000..=0x1FAFF)
+}
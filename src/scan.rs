@@ -0,0 +1,262 @@
+//! Property-Scan: alle IDs mit ihrer Größe und ihren Rohbytes
+//!
+//! Die Größe einer Property verrät der Kernel nicht; viele Handler prüfen
+//! `sizebytes` exakt, andere nur als Mindestgröße. Der Scan probiert
+//! aufsteigende Größen, die erste akzeptierte ist die Strukturgröße. Ein
+//! gespeicherter Scan dient als Baseline: [`diff`] zeigt nach einem
+//! Kernel-Update, welche Properties neu sind, fehlen oder sich geändert haben.
+//!
+//! Dazu kommt die Suche über den ganzen KGSL-Kommandoraum (Typ 0x09,
+//! Nummern 0x00 bis 0x60): welche ioctls kennt dieser Kernel überhaupt?
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::backend::{from_hex, to_hex};
+use crate::ioctls::{property_name, KGSL_IOCTLS};
+use crate::json::Json;
+use crate::schema::versioned;
+use crate::payload::Decode;
+use crate::sys::{self, KgslRequest};
+use crate::kgsl::{get_property_bytes, kgsl_iow};
+
+/// Höchste abgefragte Property-ID (etwas Luft über den bekannten)
+pub const MAX_PROPERTY_ID: u32 = 0x40;
+
+/// Größte probierte Strukturgröße
+pub const MAX_PROBE_SIZE: usize = 256;
+
+/// Ergebnis für eine Property-ID
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScannedProperty {
+    pub id: u32,
+    /// Akzeptierte Größe und Antwort, sonst errno
+    pub result: Result<Vec<u8>, i32>,
+}
+
+impl ScannedProperty {
+    pub fn name(&self) -> String {
+        property_name(self.id).map_or_else(|| format!("0x{:02x}", self.id), str::to_string)
+    }
+
+    pub fn is_present(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Antwort feldweise als Struktur, längere Antworten erlaubt
+    pub fn decode<T: Decode>(&self) -> Option<T> {
+        T::decode_bytes(self.result.as_deref().ok()?)
+    }
+}
+
+/// Eine Property mit allen Größen in 4-Byte-Schritten abfragen
+pub fn scan_property(fd: i32, id: u32) -> ScannedProperty {
+    // EINVAL heißt meist "falsche Größe"; jeder andere Fehler ist aussagekräftiger
+    let mut errno = libc::EINVAL;
+    for size in (4..=MAX_PROBE_SIZE).step_by(4) {
+        match get_property_bytes(fd, id, size) {
+            Ok(bytes) => return ScannedProperty { id, result: Ok(bytes) },
+            Err(e) => match e.raw_os_error() {
+                Some(libc::EINVAL) | None => {}
+                Some(other) => errno = other,
+            },
+        }
+    }
+    ScannedProperty { id, result: Err(errno) }
+}
+
+/// Alle IDs bis [`MAX_PROPERTY_ID`]
+pub fn scan_properties(fd: i32) -> Vec<ScannedProperty> {
+    (1..=MAX_PROPERTY_ID).map(|id| scan_property(fd, id)).collect()
+}
+
+/// Ein Scan mit Kernel-Version, als JSON speicherbar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scan {
+    pub kernel_release: String,
+    pub properties: Vec<ScannedProperty>,
+}
+
+impl Scan {
+    pub fn property(&self, id: u32) -> Option<&ScannedProperty> {
+        self.properties.iter().find(|p| p.id == id)
+    }
+
+    pub fn to_json(&self) -> Json {
+        let properties: Vec<Json> = self
+            .properties
+            .iter()
+            .map(|p| {
+                let entry = Json::object().field("id", p.id).field("name", p.name());
+                match &p.result {
+                    Ok(bytes) => entry.field("size", bytes.len()).field("hex", to_hex(bytes)),
+                    Err(errno) => entry.field("errno", *errno),
+                }
+            })
+            .collect();
+        Json::object()
+            .field("kernel_release", self.kernel_release.as_str())
+            .field("properties", properties)
+    }
+
+    pub fn from_json(json: &Json) -> Result<Self, String> {
+        let properties = json
+            .get("properties")
+            .and_then(Json::as_array)
+            .ok_or("Not a property scan (missing 'properties')")?
+            .iter()
+            .map(|p| {
+                let id = p.get("id").and_then(Json::as_u64).ok_or("property without 'id'")? as u32;
+                let result = match (p.get("hex").and_then(Json::as_str), p.get("errno").and_then(Json::as_u64)) {
+                    (Some(hex), _) => Ok(from_hex(hex).ok_or_else(|| format!("property 0x{:02x}: invalid hex", id))?),
+                    (None, Some(errno)) => Err(errno as i32),
+                    (None, None) => return Err(format!("property 0x{:02x}: neither 'hex' nor 'errno'", id)),
+                };
+                Ok(ScannedProperty { id, result })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let kernel_release = json.get("kernel_release").and_then(Json::as_str).unwrap_or_default().to_string();
+        Ok(Scan { kernel_release, properties })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+        let json = Json::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Self::from_json(&json).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, versioned(self.to_json()).to_pretty() + "\n")
+    }
+}
+
+/// Unterschied einer Property gegenüber der Baseline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PropertyChange {
+    Appeared { size: usize },
+    Disappeared { size: usize, errno: i32 },
+    Resized { old: usize, new: usize },
+    /// Gleiche Größe, abweichende Bytes an diesen Offsets
+    Changed { offsets: Vec<usize> },
+}
+
+/// Änderungen von `baseline` nach `current`, nach ID sortiert
+pub fn diff(baseline: &Scan, current: &Scan) -> Vec<(u32, PropertyChange)> {
+    let mut ids: Vec<u32> = baseline.properties.iter().chain(&current.properties).map(|p| p.id).collect();
+    ids.sort_unstable();
+    ids.dedup();
+
+    ids.into_iter()
+        .filter_map(|id| {
+            let old = baseline.property(id).and_then(|p| p.result.as_ref().ok());
+            let now = current.property(id).map(|p| &p.result);
+            let change = match (old, now.and_then(|r| r.as_ref().ok())) {
+                (None, Some(new)) => PropertyChange::Appeared { size: new.len() },
+                (Some(old), None) => PropertyChange::Disappeared {
+                    size: old.len(),
+                    errno: now.and_then(|r| r.as_ref().err().copied()).unwrap_or(libc::EINVAL),
+                },
+                (Some(old), Some(new)) if old.len() != new.len() => {
+                    PropertyChange::Resized { old: old.len(), new: new.len() }
+                }
+                (Some(old), Some(new)) if old != new => PropertyChange::Changed {
+                    offsets: old.iter().zip(new).enumerate().filter(|(_, (a, b))| a != b).map(|(i, _)| i).collect(),
+                },
+                _ => return None,
+            };
+            Some((id, change))
+        })
+        .collect()
+}
+
+// ============================================================================
+// IOCTL-Nummern
+// ============================================================================
+
+/// Höchste probierte Kommandonummer
+pub const MAX_IOCTL_NR: u8 = 0x60;
+
+/// Größe im Probe-Request; nur ungleich 0 muss sie sein
+const PROBE_SIZE: usize = 8;
+
+/// Antwort des Kernels auf eine Probe mit NULL-Payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IoctlResponse {
+    /// ENOTTY: keine Tabellenzeile für diese Nummer
+    NotImplemented,
+    /// EFAULT: Kommando existiert, das Kopieren der Argumente scheiterte
+    Fault,
+    /// EINVAL: Kommando existiert, Größe oder Argumente abgelehnt
+    Invalid,
+    /// Der Handler lief ohne Eingabedaten durch
+    Accepted,
+    Errno(i32),
+}
+
+impl IoctlResponse {
+    pub fn from_errno(errno: i32) -> Self {
+        match errno {
+            0 => IoctlResponse::Accepted,
+            libc::ENOTTY => IoctlResponse::NotImplemented,
+            libc::EFAULT => IoctlResponse::Fault,
+            libc::EINVAL => IoctlResponse::Invalid,
+            other => IoctlResponse::Errno(other),
+        }
+    }
+
+    pub fn is_implemented(self) -> bool {
+        self != IoctlResponse::NotImplemented
+    }
+
+    pub fn label(self) -> String {
+        match self {
+            IoctlResponse::NotImplemented => "ENOTTY".to_string(),
+            IoctlResponse::Fault => "EFAULT".to_string(),
+            IoctlResponse::Invalid => "EINVAL".to_string(),
+            IoctlResponse::Accepted => "ok".to_string(),
+            IoctlResponse::Errno(errno) => format!("errno {}", errno),
+        }
+    }
+}
+
+/// Probe-Request für `nr`
+///
+/// Die Richtungsbits des Aufrufers entscheiden mit, ob KGSL Argumente
+/// kopiert: mit `_IOR` überspringt der Kernel `copy_from_user` und ruft den
+/// Handler mit einer genullten Struktur auf - auch für CREATE und ALLOC.
+/// Deshalb `_IOW` mit NULL-Zeiger: jedes Kommando mit Eingabe endet mit
+/// EFAULT beim Kopieren, bevor der Handler läuft. Der Kernel liest dabei
+/// nur, geschrieben wird nichts.
+pub const fn probe_request(nr: u8) -> u32 {
+    kgsl_iow(nr as u32, PROBE_SIZE)
+}
+
+/// Eine Kommandonummer mit NULL-Payload aufrufen
+pub fn probe_ioctl_nr(fd: i32, nr: u8) -> IoctlResponse {
+    match sys::ioctl_null(fd, KgslRequest::new(probe_request(nr))) {
+        Ok(()) => IoctlResponse::Accepted,
+        Err(e) => IoctlResponse::from_errno(e.raw_os_error().unwrap_or(0)),
+    }
+}
+
+/// Alle Kommandonummern von 0 bis [`MAX_IOCTL_NR`]
+pub fn scan_ioctls(fd: i32) -> Vec<(u8, IoctlResponse)> {
+    (0..=MAX_IOCTL_NR).map(|nr| (nr, probe_ioctl_nr(fd, nr))).collect()
+}
+
+/// Ioctl-Scan als JSON, mit bekanntem Namen je Nummer
+pub fn ioctls_to_json(results: &[(u8, IoctlResponse)]) -> Json {
+    let entries: Vec<Json> = results
+        .iter()
+        .map(|&(nr, response)| {
+            let name = KGSL_IOCTLS.iter().find(|c| c.nr == nr).map(|c| c.name);
+            Json::object()
+                .field("nr", nr as u32)
+                .field("name", name)
+                .field("implemented", response.is_implemented())
+                .field("response", response.label())
+        })
+        .collect();
+    Json::object().field("ioctls", entries)
+}
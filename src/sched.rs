@@ -0,0 +1,138 @@
+//! CPU-Scheduling der KGSL Kernel-Threads (`/proc/<pid>/schedstat`)
+//!
+//! Zeigt, ob Submission-Latenz vom CPU-Scheduler (Worker wartet auf eine CPU)
+//! oder von der GPU selbst kommt.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::sysfs;
+use crate::sysroot;
+
+/// Namensanfänge der KGSL/Adreno Kernel-Threads
+const THREAD_PREFIXES: [&str; 3] = ["kgsl", "adreno", "gmu"];
+
+/// Debugfs-Verzeichnis des Dispatchers
+pub const DEBUGFS_KGSL_DIR: &str = "/sys/kernel/debug/kgsl";
+
+/// Scheduler-Statistik eines Threads
+#[derive(Debug, Clone, Default)]
+pub struct ThreadStat {
+    pub pid: u32,
+    pub comm: String,
+    /// Zeit auf der CPU
+    pub run_ns: u64,
+    /// Zeit lauffähig in der Runqueue
+    pub wait_ns: u64,
+    pub timeslices: u64,
+    pub voluntary_switches: u64,
+    pub nonvoluntary_switches: u64,
+}
+
+/// Differenz zweier [`ThreadStat`] über ein Intervall
+#[derive(Debug, Clone)]
+pub struct ThreadDelta {
+    pub pid: u32,
+    pub comm: String,
+    pub run: Duration,
+    pub wait: Duration,
+    pub timeslices: u64,
+    pub nonvoluntary_switches: u64,
+}
+
+impl ThreadDelta {
+    /// Mittlere Wartezeit auf eine CPU pro Zeitscheibe
+    pub fn wait_per_slice(&self) -> Duration {
+        match self.timeslices {
+            0 => Duration::ZERO,
+            n => self.wait / n as u32,
+        }
+    }
+}
+
+impl ThreadStat {
+    pub fn delta(&self, prev: &ThreadStat) -> ThreadDelta {
+        ThreadDelta {
+            pid: self.pid,
+            comm: self.comm.clone(),
+            run: Duration::from_nanos(self.run_ns.saturating_sub(prev.run_ns)),
+            wait: Duration::from_nanos(self.wait_ns.saturating_sub(prev.wait_ns)),
+            timeslices: self.timeslices.saturating_sub(prev.timeslices),
+            nonvoluntary_switches: self.nonvoluntary_switches.saturating_sub(prev.nonvoluntary_switches),
+        }
+    }
+}
+
+/// Parst `/proc/<pid>/schedstat`: "<run_ns> <wait_ns> <timeslices>"
+pub fn parse_schedstat(text: &str) -> Option<(u64, u64, u64)> {
+    let mut fields = text.split_whitespace().map(|f| f.parse::<u64>().ok());
+    Some((fields.next()??, fields.next()??, fields.next()??))
+}
+
+fn status_field(status: &str, name: &str) -> u64 {
+    status
+        .lines()
+        .find_map(|l| l.strip_prefix(name)?.strip_prefix(':'))
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+/// Liest die Statistik eines Threads
+pub fn read_thread(pid: u32) -> Option<ThreadStat> {
+    let dir = sysroot::resolve(format!("/proc/{}", pid));
+    let comm = fs::read_to_string(dir.join("comm")).ok()?.trim().to_string();
+    let (run_ns, wait_ns, timeslices) = parse_schedstat(&fs::read_to_string(dir.join("schedstat")).ok()?)?;
+    let status = fs::read_to_string(dir.join("status")).unwrap_or_default();
+    Some(ThreadStat {
+        pid,
+        comm,
+        run_ns,
+        wait_ns,
+        timeslices,
+        voluntary_switches: status_field(&status, "voluntary_ctxt_switches"),
+        nonvoluntary_switches: status_field(&status, "nonvoluntary_ctxt_switches"),
+    })
+}
+
+/// Alle KGSL/Adreno Kernel-Threads (Kernel-Threads erscheinen als Prozesse)
+pub fn find_kgsl_threads() -> Vec<ThreadStat> {
+    let Ok(entries) = fs::read_dir(sysroot::resolve("/proc")) else {
+        return Vec::new();
+    };
+    let mut threads: Vec<ThreadStat> = entries
+        .flatten()
+        .filter_map(|e| e.file_name().to_str()?.parse::<u32>().ok())
+        .filter(|pid| {
+            fs::read_to_string(sysroot::resolve(format!("/proc/{}/comm", pid)))
+                .is_ok_and(|c| THREAD_PREFIXES.iter().any(|p| c.trim().starts_with(p)))
+        })
+        // Kernel-Threads haben keine Kommandozeile (schließt z.B. dieses Tool aus)
+        .filter(|pid| fs::read(sysroot::resolve(format!("/proc/{}/cmdline", pid))).is_ok_and(|c| c.is_empty()))
+        .filter_map(read_thread)
+        .collect();
+    threads.sort_by_key(|t| t.pid);
+    threads
+}
+
+/// Dispatcher-Werte aus debugfs und sysfs (`dispatch/`), soweit lesbar
+pub fn read_dispatcher_counters(device_path: &str) -> Vec<(String, String)> {
+    let name = Path::new(device_path).file_name().and_then(|n| n.to_str()).unwrap_or("kgsl-3d0");
+    let dirs = [
+        sysroot::resolve(DEBUGFS_KGSL_DIR).join(name).join("dispatcher"),
+        sysfs::device_dir(device_path).join("dispatch"),
+    ];
+    let mut counters = Vec::new();
+    for dir in dirs {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+        let mut files: Vec<PathBuf> = entries.flatten().map(|e| e.path()).filter(|p| p.is_file()).collect();
+        files.sort();
+        for file in files {
+            if let Ok(value) = sysfs::read_string(&file) {
+                let key = file.file_name().unwrap_or_default().to_string_lossy().into_owned();
+                counters.push((key, value.lines().next().unwrap_or("").to_string()));
+            }
+        }
+    }
+    counters
+}
@@ -0,0 +1,161 @@
+//! Version der JSON-Ausgaben
+//!
+//! Jedes JSON-Dokument (`--json`, Daemon-Antworten, HTTP, gespeicherte
+//! Scans) beginnt mit `schema_version` und `tool_version`. Regeln:
+//!
+//! - Neue Felder und neue Dokumentarten ändern die Version nicht;
+//!   Verbraucher müssen unbekannte Felder ignorieren.
+//! - Entfernen, Umbenennen oder ein anderer Typ eines Feldes erhöht
+//!   [`SCHEMA_VERSION`].
+//! - `null` heißt "nicht verfügbar", nie "0" oder "falsch".
+//!
+//! `adreno_ioctl --schema` druckt das JSON Schema aus [`DOCUMENTS`].
+
+use crate::json::Json;
+
+/// Erhöhen nur bei inkompatiblen Änderungen
+pub const SCHEMA_VERSION: u32 = 1;
+
+pub const TOOL_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Stellt `schema_version` und `tool_version` an den Anfang eines Objekts
+pub fn versioned(json: Json) -> Json {
+    match json {
+        Json::Object(fields) => {
+            let mut out = vec![
+                ("schema_version".to_string(), Json::from(SCHEMA_VERSION)),
+                ("tool_version".to_string(), Json::from(TOOL_VERSION)),
+            ];
+            out.extend(fields.into_iter().filter(|(key, _)| key != "schema_version" && key != "tool_version"));
+            Json::Object(out)
+        }
+        other => other,
+    }
+}
+
+/// Eine Dokumentart mit ihren Pflichtfeldern
+#[derive(Debug, Clone, Copy)]
+pub struct DocumentSchema {
+    pub name: &'static str,
+    /// Wo das Dokument erscheint
+    pub about: &'static str,
+    /// (Feld, JSON-Typ), Alternativen mit `|`
+    pub fields: &'static [(&'static str, &'static str)],
+}
+
+const fn doc(name: &'static str, about: &'static str, fields: &'static [(&'static str, &'static str)]) -> DocumentSchema {
+    DocumentSchema { name, about, fields }
+}
+
+pub const DOCUMENTS: &[DocumentSchema] = &[
+    doc(
+        "info",
+        "info --json",
+        &[
+            ("device_id", "integer"),
+            ("chip_id", "string"),
+            ("model", "string"),
+            ("generation", "string"),
+            ("mmu_enabled", "boolean"),
+            ("gmem_base", "integer"),
+            ("gmem_bytes", "integer|null"),
+            ("freq_hz", "integer|null"),
+            ("driver_version", "integer|null"),
+            ("device_version", "integer|null"),
+            ("vk_device_id", "string|null"),
+            ("ddr_type", "string|null"),
+            ("warnings", "array"),
+        ],
+    ),
+    doc(
+        "core2d",
+        "info --json on a 2D core",
+        &[
+            ("core", "string"),
+            ("model", "string"),
+            ("device_id", "integer"),
+            ("core_index", "integer"),
+            ("mmu_enabled", "boolean"),
+            ("interrupt_waits", "boolean|null"),
+            ("warnings", "array"),
+        ],
+    ),
+    doc(
+        "analyze",
+        "analyze --json; windows per device with avg/min/max, throttled_s and residency",
+        &[("file", "string"), ("window_s", "number"), ("windows", "array")],
+    ),
+    doc("contexts", "contexts --json", &[("device", "string"), ("contexts", "array")]),
+    doc(
+        "lpac",
+        "lpac --json",
+        &[
+            ("device", "string"),
+            ("kind", "string"),
+            ("nodes", "array"),
+            ("enabled", "boolean|null"),
+            ("context", "boolean"),
+            ("context_error", "string|null"),
+            ("usable", "boolean"),
+        ],
+    ),
+    doc("vamap", "vamap --json", &[("device", "string"), ("used_bytes", "integer"), ("regions", "array")]),
+    doc("scan", "scan --json and scan --output", &[("kernel_release", "string"), ("properties", "array")]),
+    doc("ioctls", "scan --ioctls --json", &[("ioctls", "array")]),
+    doc("procmem_event", "procmem --watch --json, one per line", &[("event", "string"), ("pid", "integer")]),
+    doc("record", "--sink json and --sink socket, one per line", &[("record", "string")]),
+    doc(
+        "gpu_event",
+        "events --sink json, record gpu_event; to is the number of new faults for reset",
+        &[("record", "string"), ("event", "string"), ("source", "string"), ("from", "integer|null"), ("to", "integer")],
+    ),
+    doc(
+        "submission",
+        "submit --out",
+        &[("chip_id", "string|null"), ("kernel", "string"), ("capabilities", "object"), ("scores", "object")],
+    ),
+    doc("farm", "--adb SERIAL", &[("command", "string"), ("devices", "array")]),
+    doc("daemon_reply", "daemon socket and HTTP API, one per request", &[]),
+];
+
+fn type_schema(types: &str) -> Json {
+    let types: Vec<Json> = types.split('|').map(Json::from).collect();
+    match types.len() {
+        1 => Json::object().field("type", types.into_iter().next().unwrap()),
+        _ => Json::object().field("type", types),
+    }
+}
+
+/// JSON Schema (Draft 2020-12) aller Dokumente
+pub fn schema_document() -> Json {
+    let envelope = Json::object()
+        .field("schema_version", Json::object().field("const", SCHEMA_VERSION))
+        .field("tool_version", Json::object().field("type", "string"));
+    let defs = DOCUMENTS.iter().fold(Json::object(), |defs, doc| {
+        let properties =
+            doc.fields.iter().fold(Json::object(), |props, (field, types)| props.field(field, type_schema(types)));
+        defs.field(
+            doc.name,
+            Json::object()
+                .field("description", doc.about)
+                .field("type", "object")
+                .field("properties", properties)
+                .field("required", doc.fields.iter().map(|(field, _)| *field).collect::<Vec<_>>()),
+        )
+    });
+    let any_of: Vec<Json> =
+        DOCUMENTS.iter().map(|doc| Json::object().field("$ref", format!("#/$defs/{}", doc.name))).collect();
+    Json::object()
+        .field("$schema", "https://json-schema.org/draft/2020-12/schema")
+        .field("title", "adreno_ioctl JSON output")
+        .field(
+            "description",
+            "Every document starts with schema_version and tool_version. New fields keep the version; \
+             removing, renaming or retyping a field bumps schema_version. Ignore unknown fields; null means unavailable.",
+        )
+        .field("type", "object")
+        .field("properties", envelope)
+        .field("required", vec!["schema_version", "tool_version"])
+        .field("anyOf", any_of)
+        .field("$defs", defs)
+}
@@ -0,0 +1,176 @@
+//! Transaktionaler Guard für alle schreibenden Zugriffe auf Einstellungen
+//!
+//! Jeder Schreibzugriff (Governor, Frequenzgrenzen, Idle-Timer, FT-Policy,
+//! ...) läuft über [`SettingsGuard::set`]. Der Guard hält die
+//! [`InstanceLock`], trägt den Originalwert vor dem Schreiben ins Journal
+//! (`adreno_ioctl.state`) ein und schreibt beim Drop alle Originalwerte in
+//! umgekehrter Reihenfolge zurück. Stirbt der Prozess vorher, bleibt das
+//! Journal liegen und `adreno_ioctl restore` übernimmt.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use crate::instance::{self, Change, InstanceLock};
+use crate::sysfs::{read_string, write_value, KGSL_CLASS_DIR};
+use crate::sysroot;
+
+/// Bekannte schreibbare KGSL-Einstellungen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Setting {
+    Governor,
+    /// devfreq-Untergrenze in Hz
+    MinFreq,
+    /// devfreq-Obergrenze in Hz
+    MaxFreq,
+    MinPwrlevel,
+    MaxPwrlevel,
+    /// Millisekunden bis zum Abschalten der Clocks
+    IdleTimer,
+    FtPolicy,
+    FtPagefaultPolicy,
+    ForceClkOn,
+    ForceBusOn,
+    ForceRailOn,
+    ForceNoNap,
+}
+
+impl Setting {
+    pub const ALL: [Setting; 12] = [
+        Setting::Governor,
+        Setting::MinFreq,
+        Setting::MaxFreq,
+        Setting::MinPwrlevel,
+        Setting::MaxPwrlevel,
+        Setting::IdleTimer,
+        Setting::FtPolicy,
+        Setting::FtPagefaultPolicy,
+        Setting::ForceClkOn,
+        Setting::ForceBusOn,
+        Setting::ForceRailOn,
+        Setting::ForceNoNap,
+    ];
+
+    /// Pfad relativ zum sysfs-Verzeichnis des Geräts
+    pub fn file(self) -> &'static str {
+        match self {
+            Setting::Governor => "devfreq/governor",
+            Setting::MinFreq => "devfreq/min_freq",
+            Setting::MaxFreq => "devfreq/max_freq",
+            Setting::MinPwrlevel => "min_pwrlevel",
+            Setting::MaxPwrlevel => "max_pwrlevel",
+            Setting::IdleTimer => "idle_timer",
+            Setting::FtPolicy => "ft_policy",
+            Setting::FtPagefaultPolicy => "ft_pagefault_policy",
+            Setting::ForceClkOn => "force_clk_on",
+            Setting::ForceBusOn => "force_bus_on",
+            Setting::ForceRailOn => "force_rail_on",
+            Setting::ForceNoNap => "force_no_nap",
+        }
+    }
+
+    pub fn path(self, dir: &Path) -> PathBuf {
+        dir.join(self.file())
+    }
+
+    /// Einstellung zu einem Pfad aus dem Journal: nur
+    /// `<KGSL_CLASS_DIR>/kgsl-*/<file>`, und das Ziel der Symlinks (devfreq
+    /// zeigt ins Plattformgerät) muss in sysfs liegen
+    pub fn from_journal_path(path: &Path) -> Option<Setting> {
+        let class = sysroot::resolve(KGSL_CLASS_DIR);
+        let Some(Component::Normal(device)) = path.strip_prefix(&class).ok()?.components().next() else {
+            return None;
+        };
+        if !device.to_str()?.starts_with("kgsl-") {
+            return None;
+        }
+        let dir = class.join(device);
+        let setting = Setting::ALL.into_iter().find(|s| s.path(&dir) == path)?;
+        let sys = fs::canonicalize(sysroot::resolve("/sys")).ok()?;
+        fs::canonicalize(path).ok()?.starts_with(sys).then_some(setting)
+    }
+}
+
+impl fmt::Display for Setting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.file())
+    }
+}
+
+/// Sammelt Änderungen und nimmt sie beim Drop zurück
+pub struct SettingsGuard {
+    changes: Vec<Change>,
+    _lock: InstanceLock,
+}
+
+impl SettingsGuard {
+    /// Holt die Instanz-Sperre für `command`
+    pub fn new(command: &str) -> Result<Self, String> {
+        Ok(SettingsGuard { changes: Vec::new(), _lock: InstanceLock::acquire(command)? })
+    }
+
+    /// Schreibt `value`; der Originalwert wird nur beim ersten Schreiben gemerkt
+    pub fn set(&mut self, path: impl Into<PathBuf>, value: impl fmt::Display) -> io::Result<()> {
+        let path = path.into();
+        if self.changes.iter().any(|c| c.path == path) {
+            return write_value(&path, value);
+        }
+        let original = read_string(&path)?;
+        instance::record(&path, &original)?;
+        match write_value(&path, value) {
+            Ok(()) => {
+                self.changes.push(Change { path, original });
+                Ok(())
+            }
+            Err(e) => {
+                // Nichts geändert - Journal-Eintrag wieder entfernen
+                instance::forget(&path);
+                Err(e)
+            }
+        }
+    }
+
+    /// [`set`](Self::set) für eine bekannte Einstellung des Geräts in `dir`
+    pub fn set_setting(&mut self, dir: &Path, setting: Setting, value: impl fmt::Display) -> io::Result<()> {
+        self.set(setting.path(dir), value)
+    }
+
+    /// Offene Änderungen in Schreibreihenfolge
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    pub fn original(&self, path: &Path) -> Option<&str> {
+        self.changes.iter().find(|c| c.path == path).map(|c| c.original.as_str())
+    }
+
+    /// Behält alle Änderungen nach Prozessende bei; das Journal bleibt mit
+    /// `persistent` liegen und `adreno_ioctl restore` nimmt sie zurück
+    pub fn persist(mut self) -> io::Result<()> {
+        instance::persist()?;
+        self.changes.clear();
+        Ok(())
+    }
+
+    /// Schreibt alle Originalwerte zurück (neueste zuerst) und liefert die
+    /// Fehlschläge; diese bleiben im Journal für `adreno_ioctl restore`
+    pub fn restore(&mut self) -> Vec<(Change, io::Error)> {
+        let mut failed = Vec::new();
+        while let Some(change) = self.changes.pop() {
+            match write_value(&change.path, &change.original) {
+                Ok(()) => instance::forget(&change.path),
+                Err(e) => failed.push((change, e)),
+            }
+        }
+        failed
+    }
+}
+
+impl Drop for SettingsGuard {
+    fn drop(&mut self) {
+        for (change, e) in self.restore() {
+            eprintln!("⚠️  Could not restore {} to {}: {}", change.path.display(), change.original, e);
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! Ausgabeziele für strukturierte Datensätze
+//!
+//! Kommandos erzeugen [`Record`]s und kennen nur den [`Sink`]-Trait; wie ein
+//! Datensatz auf dem Terminal, als JSON-Zeile, CSV, Prometheus-Text oder über
+//! einen Socket landet, entscheidet allein die Implementierung. Ein neues
+//! Format ist damit eine neue `Sink`-Implementierung plus ein Eintrag in
+//! [`open`].
+
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::net::TcpStream;
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+
+use crate::json::Json;
+use crate::schema::versioned;
+
+/// Ein Datensatz: Art plus Felder in fester Reihenfolge
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    /// z.B. "sample" oder "memory_event"
+    pub kind: String,
+    pub fields: Vec<(String, Json)>,
+}
+
+impl Record {
+    pub fn new(kind: &str) -> Self {
+        Record { kind: kind.to_string(), fields: Vec::new() }
+    }
+
+    /// Fügt ein Feld hinzu; `None` wird zu `null`
+    pub fn field(mut self, key: &str, value: impl Into<Json>) -> Self {
+        self.fields.push((key.to_string(), value.into()));
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        self.fields.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// JSON-Objekt mit `record` als erstem Feld
+    pub fn to_json(&self) -> Json {
+        let mut fields = vec![("record".to_string(), Json::from(self.kind.as_str()))];
+        fields.extend(self.fields.iter().cloned());
+        Json::Object(fields)
+    }
+}
+
+/// Ziel für [`Record`]s
+pub trait Sink: Send {
+    fn write(&mut self, record: &Record) -> io::Result<()>;
+
+    /// Puffer leeren, Abschluss schreiben
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Verteilt jeden Datensatz an alle Ziele
+impl Sink for Vec<Box<dyn Sink>> {
+    fn write(&mut self, record: &Record) -> io::Result<()> {
+        self.iter_mut().try_for_each(|sink| sink.write(record))
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.iter_mut().try_for_each(|sink| sink.finish())
+    }
+}
+
+pub type Output = Box<dyn Write + Send>;
+
+/// Skalar als Text; `None` für `null` und zusammengesetzte Werte
+fn scalar(value: &Json) -> Option<String> {
+    match value {
+        Json::Null | Json::Array(_) | Json::Object(_) => None,
+        Json::String(s) => Some(s.clone()),
+        Json::Float(f) => Some(format!("{:.3}", f).trim_end_matches('0').trim_end_matches('.').to_string()),
+        other => Some(other.to_compact()),
+    }
+}
+
+// ============================================================================
+// Terminal
+// ============================================================================
+
+/// `kind  key=value key=value`, fehlende Werte werden ausgelassen
+pub struct TerminalSink {
+    out: Output,
+}
+
+impl TerminalSink {
+    pub fn new(out: Output) -> Self {
+        TerminalSink { out }
+    }
+}
+
+impl Sink for TerminalSink {
+    fn write(&mut self, record: &Record) -> io::Result<()> {
+        let pairs: Vec<String> = record
+            .fields
+            .iter()
+            .filter_map(|(key, value)| scalar(value).map(|v| format!("{}={}", key, v)))
+            .collect();
+        writeln!(self.out, "{:<14} {}", record.kind, pairs.join(" "))?;
+        self.out.flush()
+    }
+}
+
+// ============================================================================
+// JSON Lines
+// ============================================================================
+
+/// Ein versioniertes JSON-Objekt pro Zeile
+pub struct JsonLinesSink {
+    out: Output,
+}
+
+impl JsonLinesSink {
+    pub fn new(out: Output) -> Self {
+        JsonLinesSink { out }
+    }
+}
+
+impl Sink for JsonLinesSink {
+    fn write(&mut self, record: &Record) -> io::Result<()> {
+        writeln!(self.out, "{}", versioned(record.to_json()).to_compact())?;
+        self.out.flush()
+    }
+}
+
+// ============================================================================
+// CSV
+// ============================================================================
+
+/// CSV nach RFC 4180; die Kopfzeile kommt aus dem ersten Datensatz und wird
+/// wiederholt, sobald sich Art oder Spalten ändern
+pub struct CsvSink {
+    out: Output,
+    columns: Option<(String, Vec<String>)>,
+}
+
+impl CsvSink {
+    pub fn new(out: Output) -> Self {
+        CsvSink { out, columns: None }
+    }
+}
+
+fn csv_cell(value: &Json) -> String {
+    let text = match value {
+        Json::Array(_) | Json::Object(_) => value.to_compact(),
+        other => scalar(other).unwrap_or_default(),
+    };
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+impl Sink for CsvSink {
+    fn write(&mut self, record: &Record) -> io::Result<()> {
+        let columns: Vec<String> = record.fields.iter().map(|(key, _)| key.clone()).collect();
+        let same = self.columns.as_ref().is_some_and(|(kind, cols)| *kind == record.kind && *cols == columns);
+        if !same {
+            writeln!(self.out, "record,{}", columns.join(","))?;
+            self.columns = Some((record.kind.clone(), columns));
+        }
+        let cells: Vec<String> = record.fields.iter().map(|(_, value)| csv_cell(value)).collect();
+        writeln!(self.out, "{},{}", record.kind, cells.join(","))?;
+        self.out.flush()
+    }
+}
+
+// ============================================================================
+// Prometheus
+// ============================================================================
+
+/// Prometheus-Textformat: Zahlen und Booleans werden zu Gauges
+/// `adreno_<kind>_<feld>`, Strings zu Labels
+///
+/// Mit einer Datei als Ziel wird sie bei jedem Datensatz atomar ersetzt
+/// (node_exporter textfile collector), sonst folgt Block auf Block.
+pub struct PrometheusSink {
+    out: Option<Output>,
+    textfile: Option<PathBuf>,
+    /// Bereits mit `# TYPE` angekündigte Metriken
+    announced: BTreeSet<String>,
+}
+
+impl PrometheusSink {
+    pub fn new(out: Output) -> Self {
+        PrometheusSink { out: Some(out), textfile: None, announced: BTreeSet::new() }
+    }
+
+    pub fn textfile(path: PathBuf) -> Self {
+        PrometheusSink { out: None, textfile: Some(path), announced: BTreeSet::new() }
+    }
+}
+
+/// Nur `[a-zA-Z0-9_]` ist in Metrik- und Labelnamen erlaubt
+fn metric_name(name: &str) -> String {
+    name.chars().map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' }).collect()
+}
+
+fn label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Exposition eines Datensatzes; `announced` unterdrückt doppelte `# TYPE`
+pub fn prometheus_text(record: &Record, announced: &mut BTreeSet<String>) -> String {
+    let labels: Vec<String> = record
+        .fields
+        .iter()
+        .filter_map(|(key, value)| match value {
+            Json::String(s) => Some(format!("{}=\"{}\"", metric_name(key), label_value(s))),
+            _ => None,
+        })
+        .collect();
+    let labels = if labels.is_empty() { String::new() } else { format!("{{{}}}", labels.join(",")) };
+    let mut text = String::new();
+    for (key, value) in &record.fields {
+        let number = match value {
+            Json::Bool(b) => u8::from(*b).to_string(),
+            Json::UInt(_) | Json::Int(_) | Json::Float(_) => match scalar(value) {
+                Some(n) => n,
+                None => continue,
+            },
+            _ => continue,
+        };
+        let name = format!("adreno_{}_{}", metric_name(&record.kind), metric_name(key));
+        if announced.insert(name.clone()) {
+            text.push_str(&format!("# TYPE {} gauge\n", name));
+        }
+        text.push_str(&format!("{}{} {}\n", name, labels, number));
+    }
+    text
+}
+
+impl Sink for PrometheusSink {
+    fn write(&mut self, record: &Record) -> io::Result<()> {
+        match (&mut self.out, &self.textfile) {
+            (Some(out), _) => {
+                out.write_all(prometheus_text(record, &mut self.announced).as_bytes())?;
+                writeln!(out)?;
+                out.flush()
+            }
+            (None, Some(path)) => {
+                // Jede Datei ist eine vollständige Exposition
+                let text = prometheus_text(record, &mut BTreeSet::new());
+                let tmp = path.with_extension("prom.tmp");
+                fs::write(&tmp, text)?;
+                fs::rename(&tmp, path)
+            }
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+// ============================================================================
+// Socket
+// ============================================================================
+
+/// JSON Lines über einen Unix-Socket (Pfad) oder TCP (`host:port`)
+pub struct SocketSink {
+    inner: JsonLinesSink,
+}
+
+impl SocketSink {
+    pub fn connect(address: &str) -> io::Result<Self> {
+        let out: Output = if address.starts_with('/') || address.starts_with('.') {
+            Box::new(UnixStream::connect(address)?)
+        } else {
+            Box::new(TcpStream::connect(address)?)
+        };
+        Ok(SocketSink { inner: JsonLinesSink::new(out) })
+    }
+}
+
+impl Sink for SocketSink {
+    fn write(&mut self, record: &Record) -> io::Result<()> {
+        self.inner.write(record)
+    }
+}
+
+// ============================================================================
+// Auswahl
+// ============================================================================
+
+pub const FORMATS: &[&str] = &["text", "json", "csv", "prometheus", "socket"];
+
+/// `FORMAT[:ZIEL]`, Ziel ist eine Datei (Standard: stdout) bzw. bei `socket`
+/// die Adresse
+pub fn open(spec: &str) -> Result<Box<dyn Sink>, String> {
+    let (format, target) = match spec.split_once(':') {
+        Some((format, target)) => (format, Some(target)),
+        None => (spec, None),
+    };
+    let output = || -> Result<Output, String> {
+        match target {
+            None | Some("-") => Ok(Box::new(io::stdout())),
+            Some(path) => File::create(path)
+                .map(|f| Box::new(BufWriter::new(f)) as Output)
+                .map_err(|e| format!("Cannot create {}: {}", path, e)),
+        }
+    };
+    Ok(match format {
+        "text" => Box::new(TerminalSink::new(output()?)),
+        "json" => Box::new(JsonLinesSink::new(output()?)),
+        "csv" => Box::new(CsvSink::new(output()?)),
+        "prometheus" => match target {
+            None | Some("-") => Box::new(PrometheusSink::new(output()?)),
+            Some(path) => Box::new(PrometheusSink::textfile(PathBuf::from(path))),
+        },
+        "socket" => {
+            let address = target.ok_or("socket needs an address: socket:/path/to.sock or socket:HOST:PORT")?;
+            Box::new(SocketSink::connect(address).map_err(|e| format!("Cannot connect to {}: {}", address, e))?)
+        }
+        other => return Err(format!("Unknown sink '{}' (expected {})", other, FORMATS.join(", "))),
+    })
+}
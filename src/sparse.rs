@@ -0,0 +1,169 @@
+//! Sparse (virtuelle/physische) Speicher-IOCTLs
+//!
+//! Virtuelle Bereiche und physische Seiten werden getrennt angelegt und per
+//! Bind/Unbind verknüpft - die Grundlage für Sparse-Texturen in Vulkan.
+
+use std::io;
+use std::mem::size_of;
+use std::os::fd::{AsFd, AsRawFd, BorrowedFd};
+
+use zerocopy::{FromBytes, Immutable, IntoBytes};
+
+use crate::kgsl::{kgsl_iow, kgsl_iowr, probe_ioctl};
+use crate::sys::{ioctl, KgslRequest};
+
+/// `struct kgsl_sparse_phys_alloc`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslSparsePhysAlloc {
+    pub size: u64,
+    pub pagesize: u64,
+    pub flags: u64,
+    pub id: u32,
+    pub _pad: u32,
+}
+
+/// `struct kgsl_sparse_phys_free`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslSparsePhysFree {
+    pub id: u32,
+}
+
+/// `struct kgsl_sparse_virt_alloc`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslSparseVirtAlloc {
+    pub size: u64,
+    pub pagesize: u64,
+    pub flags: u64,
+    pub gpuaddr: u64,
+    pub id: u32,
+    pub _pad: u32,
+}
+
+/// `struct kgsl_sparse_virt_free`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslSparseVirtFree {
+    pub id: u32,
+}
+
+/// `struct kgsl_sparse_binding_object`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KgslSparseBindingObject {
+    pub virtoffset: u64,
+    pub physoffset: u64,
+    pub size: u64,
+    pub flags: u64,
+    pub id: u32,
+}
+
+/// `struct kgsl_sparse_bind`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslSparseBind {
+    pub list: u64,
+    pub id: u32,
+    pub size: u32,
+    pub count: u32,
+    pub _pad: u32,
+}
+
+pub const KGSL_SPARSE_BIND: u64 = 0x1;
+pub const KGSL_SPARSE_UNBIND: u64 = 0x2;
+
+pub const IOCTL_KGSL_SPARSE_PHYS_ALLOC: u32 = kgsl_iowr(0x50, size_of::<KgslSparsePhysAlloc>());
+pub const IOCTL_KGSL_SPARSE_PHYS_FREE: u32 = kgsl_iow(0x51, size_of::<KgslSparsePhysFree>());
+pub const IOCTL_KGSL_SPARSE_VIRT_ALLOC: u32 = kgsl_iowr(0x52, size_of::<KgslSparseVirtAlloc>());
+pub const IOCTL_KGSL_SPARSE_VIRT_FREE: u32 = kgsl_iow(0x53, size_of::<KgslSparseVirtFree>());
+pub const IOCTL_KGSL_SPARSE_BIND: u32 = kgsl_iow(0x54, size_of::<KgslSparseBind>());
+
+/// Übliche Sparse-Seitengröße
+pub const SPARSE_PAGE_SIZE: u64 = 64 * 1024;
+
+/// Ob der Kernel die Sparse-IOCTLs kennt (ENOTTY = nein)
+pub fn sparse_supported(fd: i32) -> bool {
+    probe_ioctl(fd, const { KgslRequest::new(IOCTL_KGSL_SPARSE_VIRT_ALLOC) }).is_implemented()
+}
+
+/// Physischer Sparse-Speicher, wird beim Drop freigegeben
+pub struct SparsePhys<'a> {
+    fd: BorrowedFd<'a>,
+    pub id: u32,
+    pub size: u64,
+}
+
+impl<'a> SparsePhys<'a> {
+    pub fn alloc(dev: &'a impl AsFd, size: u64, pagesize: u64) -> io::Result<Self> {
+        let fd = dev.as_fd();
+        let mut req = KgslSparsePhysAlloc { size, pagesize, ..Default::default() };
+        ioctl(fd.as_raw_fd(), IOCTL_KGSL_SPARSE_PHYS_ALLOC, &mut req)?;
+        Ok(SparsePhys { fd, id: req.id, size })
+    }
+}
+
+impl Drop for SparsePhys<'_> {
+    fn drop(&mut self) {
+        let mut req = KgslSparsePhysFree { id: self.id };
+        let _ = ioctl(self.fd.as_raw_fd(), IOCTL_KGSL_SPARSE_PHYS_FREE, &mut req);
+    }
+}
+
+/// Virtueller Sparse-Bereich, wird beim Drop freigegeben
+pub struct SparseVirt<'a> {
+    fd: BorrowedFd<'a>,
+    pub id: u32,
+    pub gpuaddr: u64,
+    pub size: u64,
+}
+
+impl<'a> SparseVirt<'a> {
+    pub fn alloc(dev: &'a impl AsFd, size: u64, pagesize: u64) -> io::Result<Self> {
+        let fd = dev.as_fd();
+        let mut req = KgslSparseVirtAlloc { size, pagesize, ..Default::default() };
+        ioctl(fd.as_raw_fd(), IOCTL_KGSL_SPARSE_VIRT_ALLOC, &mut req)?;
+        Ok(SparseVirt { fd, id: req.id, gpuaddr: req.gpuaddr, size })
+    }
+
+    /// Bindet `size` Bytes physischen Speichers an `virt_offset`
+    pub fn bind(&self, virt_offset: u64, phys: &SparsePhys, phys_offset: u64, size: u64) -> io::Result<()> {
+        self.submit(KgslSparseBindingObject {
+            virtoffset: virt_offset,
+            physoffset: phys_offset,
+            size,
+            flags: KGSL_SPARSE_BIND,
+            id: phys.id,
+        })
+    }
+
+    /// Löst die Bindung ab `virt_offset`
+    pub fn unbind(&self, virt_offset: u64, size: u64) -> io::Result<()> {
+        self.submit(KgslSparseBindingObject {
+            virtoffset: virt_offset,
+            size,
+            flags: KGSL_SPARSE_UNBIND,
+            ..Default::default()
+        })
+    }
+
+    fn submit(&self, object: KgslSparseBindingObject) -> io::Result<()> {
+        let mut list = [object];
+        let mut req = KgslSparseBind {
+            list: list.as_mut_ptr() as u64,
+            id: self.id,
+            size: size_of::<KgslSparseBindingObject>() as u32,
+            count: 1,
+            _pad: 0,
+        };
+        ioctl(self.fd.as_raw_fd(), IOCTL_KGSL_SPARSE_BIND, &mut req)
+    }
+}
+
+impl Drop for SparseVirt<'_> {
+    fn drop(&mut self) {
+        let mut req = KgslSparseVirtFree { id: self.id };
+        let _ = ioctl(self.fd.as_raw_fd(), IOCTL_KGSL_SPARSE_VIRT_FREE, &mut req);
+    }
+}
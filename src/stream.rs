@@ -0,0 +1,206 @@
+//! Asynchrone Sample-Quelle für Dienste (Feature `async`)
+//!
+//! Unabhängig von der Runtime: eine Sampling-Schleife liest sysfs im Takt
+//! und weckt den wartenden Task über dessen `Waker`. Der Task selbst
+//! blockiert nie. Die Schleife läuft auf einem eigenen Thread
+//! ([`DeviceMonitor::stream`]) oder auf dem Blocking-Pool der Runtime
+//! ([`DeviceMonitor::stream_with`], z.B. tokios `spawn_blocking`).
+
+use std::collections::VecDeque;
+use std::future::poll_fn;
+use std::io;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::monitor::{Monitor, Sample};
+
+/// Ungelesene Samples, ältere werden verworfen
+const MAX_PENDING: usize = 16;
+
+/// Sampling-Schleife, wie sie [`DeviceMonitor::stream_with`] übergibt
+pub type SamplerJob = Box<dyn FnOnce() + Send + 'static>;
+
+/// Monitor für ein Gerät, als Stream nutzbar
+pub struct DeviceMonitor {
+    monitor: Monitor,
+}
+
+impl DeviceMonitor {
+    pub fn new(device_path: &str) -> Self {
+        DeviceMonitor { monitor: Monitor::new(device_path) }
+    }
+
+    /// Für zusätzliche Quellen (Batterie, Queues, ...)
+    pub fn from_monitor(monitor: Monitor) -> Self {
+        DeviceMonitor { monitor }
+    }
+
+    /// Liefert alle `interval` ein Sample, beginnend sofort
+    ///
+    /// Die Schleife läuft auf einem eigenen Thread; scheitert dessen Start,
+    /// kommt der Fehler zurück.
+    pub fn stream(self, interval: Duration) -> io::Result<SampleStream> {
+        let (shared, job) = self.job(interval);
+        let worker = thread::Builder::new().name("adreno-sampler".to_string()).spawn(job)?;
+        Ok(SampleStream { shared, worker: Some(worker) })
+    }
+
+    /// Wie [`stream`](Self::stream), startet die Schleife aber über `spawn`
+    ///
+    /// Unter tokio:
+    ///
+    /// ```ignore
+    /// let stream = monitor.stream_with(interval, |job| {
+    ///     tokio::task::spawn_blocking(job);
+    ///     Ok(())
+    /// })?;
+    /// ```
+    ///
+    /// Die Schleife belegt den Blocking-Thread, bis der Stream gedroppt wird.
+    pub fn stream_with(
+        self,
+        interval: Duration,
+        spawn: impl FnOnce(SamplerJob) -> io::Result<()>,
+    ) -> io::Result<SampleStream> {
+        let (shared, job) = self.job(interval);
+        spawn(Box::new(job))?;
+        Ok(SampleStream { shared, worker: None })
+    }
+
+    fn job(self, interval: Duration) -> (Arc<Shared>, impl FnOnce() + Send + 'static) {
+        let shared = Arc::new(Shared::default());
+        let worker_shared = Arc::clone(&shared);
+        let monitor = self.monitor;
+        (shared, move || {
+            let _finish = Finish(&worker_shared);
+            sample_loop(monitor, interval, &worker_shared);
+        })
+    }
+}
+
+#[derive(Default)]
+struct Shared {
+    state: Mutex<State>,
+    /// Weckt die Schleife beim Drop des Streams
+    wake: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    pending: VecDeque<Sample>,
+    waker: Option<Waker>,
+    stop: bool,
+    /// Schleife beendet (auch per Panic)
+    done: bool,
+}
+
+/// Markiert die Schleife beim Verlassen als beendet und weckt den Task
+struct Finish<'a>(&'a Shared);
+
+impl Drop for Finish<'_> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut state = self.0.state.lock().unwrap_or_else(|e| e.into_inner());
+            state.done = true;
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+fn sample_loop(monitor: Monitor, interval: Duration, shared: &Shared) {
+    let mut next = Instant::now();
+    loop {
+        {
+            let mut state = shared.state.lock().unwrap();
+            // Drop weckt über die Condvar, damit das Ende nicht ein Intervall dauert
+            while !state.stop && Instant::now() < next {
+                let wait = next.saturating_duration_since(Instant::now());
+                state = shared.wake.wait_timeout(state, wait).unwrap().0;
+            }
+            if state.stop {
+                return;
+            }
+        }
+        let sample = monitor.sample();
+        let waker = {
+            let mut state = shared.state.lock().unwrap();
+            if state.pending.len() == MAX_PENDING {
+                state.pending.pop_front();
+            }
+            state.pending.push_back(sample);
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        next += interval;
+    }
+}
+
+/// Strom von Samples; beim Drop endet der Sampling-Thread
+pub struct SampleStream {
+    shared: Arc<Shared>,
+    /// Nur bei [`DeviceMonitor::stream`]; fremde Executor joinen selbst
+    worker: Option<JoinHandle<()>>,
+}
+
+impl SampleStream {
+    /// Nächstes Sample (wie `Stream::poll_next`)
+    pub fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Sample>> {
+        let mut state = self.shared.state.lock().unwrap();
+        if let Some(sample) = state.pending.pop_front() {
+            return Poll::Ready(Some(sample));
+        }
+        if state.done {
+            return Poll::Ready(None);
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    /// Wartet auf das nächste Sample
+    pub async fn next(&mut self) -> Option<Sample> {
+        poll_fn(|cx| self.poll_next(cx)).await
+    }
+}
+
+impl Drop for SampleStream {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap_or_else(|e| e.into_inner()).stop = true;
+        self.shared.wake.notify_all();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stream_with_runs_on_foreign_spawner() {
+        let monitor = DeviceMonitor::new("/nonexistent/kgsl-3d0");
+        let mut stream = monitor
+            .stream_with(Duration::from_millis(1), |job| thread::Builder::new().spawn(job).map(drop))
+            .unwrap();
+        let mut cx = Context::from_waker(Waker::noop());
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while stream.poll_next(&mut cx).is_pending() {
+            assert!(Instant::now() < deadline, "no sample from the spawned loop");
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn spawn_error_is_returned() {
+        let monitor = DeviceMonitor::new("/nonexistent/kgsl-3d0");
+        let result = monitor.stream_with(Duration::from_secs(1), |_| Err(io::Error::other("no threads")));
+        assert!(result.is_err());
+    }
+}
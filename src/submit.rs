@@ -0,0 +1,149 @@
+//! Command-Submission über `IOCTL_KGSL_GPU_COMMAND`
+
+use std::io;
+use std::mem::size_of;
+use std::os::fd::{AsFd, AsRawFd, BorrowedFd};
+use std::time::Duration;
+
+use zerocopy::{FromBytes, Immutable, IntoBytes};
+
+use crate::kgsl::{self, kgsl_iowr, KGSL_CONTEXT_NO_GMEM_ALLOC, KGSL_CONTEXT_PER_CONTEXT_TS, KGSL_CONTEXT_PREAMBLE};
+use crate::memory::{GpuBuffer, KGSL_CACHEMODE_SHIFT, KGSL_CACHEMODE_UNCACHED, KGSL_MEMFLAGS_GPUREADONLY};
+use crate::pm4::CommandStream;
+use crate::sys::ioctl;
+
+/// `struct kgsl_command_object`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KgslCommandObject {
+    pub offset: u64,
+    pub gpuaddr: u64,
+    pub size: u64,
+    pub flags: u32,
+    pub id: u32,
+}
+
+/// `struct kgsl_gpu_command`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslGpuCommand {
+    pub flags: u64,
+    pub cmdlist: u64,
+    pub cmdsize: u32,
+    pub numcmds: u32,
+    pub objlist: u64,
+    pub objsize: u32,
+    pub numobjs: u32,
+    pub synclist: u64,
+    pub syncsize: u32,
+    pub numsyncs: u32,
+    pub context_id: u32,
+    pub timestamp: u32,
+}
+
+pub const IOCTL_KGSL_GPU_COMMAND: u32 = kgsl_iowr(0x4A, size_of::<KgslGpuCommand>());
+
+/// Objekt-Typ in der Command-Liste
+pub const KGSL_CMDLIST_IB: u32 = 0x00000001;
+
+/// Größe des Command-Puffers
+const CMDBUF_SIZE: u64 = 4096;
+
+/// Eigener Context mit Command-Puffer für einfache Submissions
+///
+/// Context und Puffer werden beim Drop freigegeben.
+pub struct Submitter<'a> {
+    fd: BorrowedFd<'a>,
+    context_id: u32,
+    cmdbuf: GpuBuffer<'a>,
+    generation: u8,
+}
+
+impl<'a> Submitter<'a> {
+    /// `generation` bestimmt das PM4 Paketformat
+    pub fn new(dev: &'a impl AsFd, generation: u8) -> io::Result<Self> {
+        Self::with_context_flags(dev, generation, 0)
+    }
+
+    /// Wie [`Submitter::new`], mit zusätzlichen Context-Flags
+    /// (z.B. `KGSL_CONTEXT_PWR_CONSTRAINT`)
+    pub fn with_context_flags(dev: &'a impl AsFd, generation: u8, extra: u32) -> io::Result<Self> {
+        let fd = dev.as_fd();
+        let flags = KGSL_CONTEXT_PREAMBLE | KGSL_CONTEXT_NO_GMEM_ALLOC | KGSL_CONTEXT_PER_CONTEXT_TS | extra;
+        let context_id = kgsl::create_context(fd.as_raw_fd(), flags)?;
+        let cmdbuf = GpuBuffer::alloc(
+            dev,
+            CMDBUF_SIZE,
+            KGSL_MEMFLAGS_GPUREADONLY | (KGSL_CACHEMODE_UNCACHED << KGSL_CACHEMODE_SHIFT),
+        )
+        .inspect_err(|_| {
+            let _ = kgsl::destroy_context(fd.as_raw_fd(), context_id);
+        })?;
+        Ok(Submitter { fd, context_id, cmdbuf, generation })
+    }
+
+    pub fn context_id(&self) -> u32 {
+        self.context_id
+    }
+
+    /// Neuer leerer Command Stream im passenden Format
+    pub fn stream(&self) -> CommandStream {
+        CommandStream::new(self.generation)
+    }
+
+    /// Kopiert `stream` in den Puffer und reicht ihn ein, liefert den Timestamp
+    pub fn submit(&self, stream: &CommandStream) -> io::Result<u32> {
+        let bytes = stream.as_bytes();
+        if bytes.len() as u64 > CMDBUF_SIZE {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "command stream too large"));
+        }
+        if !self.cmdbuf.map()?.write_bytes(0, &bytes) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "command stream exceeds the mapping"));
+        }
+
+        let mut cmd = KgslCommandObject {
+            gpuaddr: self.cmdbuf.gpuaddr,
+            size: bytes.len() as u64,
+            flags: KGSL_CMDLIST_IB,
+            id: self.cmdbuf.id,
+            ..Default::default()
+        };
+        let mut req = KgslGpuCommand {
+            cmdlist: &mut cmd as *mut KgslCommandObject as u64,
+            cmdsize: size_of::<KgslCommandObject>() as u32,
+            numcmds: 1,
+            context_id: self.context_id,
+            ..Default::default()
+        };
+        ioctl(self.fd.as_raw_fd(), IOCTL_KGSL_GPU_COMMAND, &mut req)?;
+        Ok(req.timestamp)
+    }
+
+    /// Reicht ein paar NOPs ein - der einfachste mögliche GPU-Auftrag
+    pub fn submit_nop(&self) -> io::Result<u32> {
+        let mut stream = self.stream();
+        stream.nop(4);
+        self.submit(&stream)
+    }
+
+    /// Zuletzt retirter Timestamp dieses Contexts
+    pub fn retired(&self) -> io::Result<u32> {
+        kgsl::read_timestamp(self.fd.as_raw_fd(), self.context_id, kgsl::KGSL_TIMESTAMP_RETIRED)
+    }
+
+    /// Wartet auf `timestamp`
+    pub fn wait(&self, timestamp: u32, timeout: Duration) -> io::Result<()> {
+        kgsl::wait_timestamp(self.fd.as_raw_fd(), self.context_id, timestamp, timeout)
+    }
+}
+
+/// Ob `retired` mindestens `submitted` erreicht hat (mit Überlauf)
+pub fn retired_since(retired: u32, submitted: u32) -> bool {
+    (retired.wrapping_sub(submitted) as i32) >= 0
+}
+
+impl Drop for Submitter<'_> {
+    fn drop(&mut self) {
+        let _ = kgsl::destroy_context(self.fd.as_raw_fd(), self.context_id);
+    }
+}
@@ -0,0 +1,165 @@
+//! Zusammenfassung einer Monitor-Sitzung
+//!
+//! Wird online aus den Samples berechnet, damit niemand dafür CSVs
+//! nachbearbeiten muss. Dauern und Raten zählen nur wache Zeit; Suspend-
+//! Phasen stehen getrennt in [`SessionSummary::suspended`].
+
+use std::time::Duration;
+
+use crate::monitor::Sample;
+
+/// Minimum, Mittelwert und Maximum einer Größe
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Stat {
+    pub min: f64,
+    pub max: f64,
+    sum: f64,
+    pub count: u64,
+}
+
+impl Stat {
+    pub fn add(&mut self, value: f64) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.sum += value;
+        self.count += 1;
+    }
+
+    pub fn avg(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum / self.count as f64)
+    }
+}
+
+/// Zeitanteil eines Power Levels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Residency {
+    pub level: usize,
+    pub freq_hz: Option<u64>,
+    pub busy: Duration,
+    pub percent: f64,
+}
+
+/// Laufende Statistik über alle Samples
+#[derive(Debug, Clone, Default)]
+pub struct SessionSummary {
+    first: Option<Sample>,
+    last: Option<Sample>,
+    pub freq_mhz: Stat,
+    pub busy_percent: Stat,
+    pub temp_c: Stat,
+    /// Zeit mit `thermal_pwrlevel` > 0, `None` ohne den Knoten
+    pub throttled: Option<Duration>,
+    pub samples: u64,
+    /// GPU-Fehler im Kernel-Log zu Beginn und am Ende
+    pub faults_start: Option<u64>,
+    pub faults_end: Option<u64>,
+    /// Summe der Suspend-Phasen zwischen zwei Samples
+    pub suspended: Duration,
+    pub suspends: u32,
+}
+
+impl SessionSummary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, sample: &Sample) {
+        if let Some(hz) = sample.freq_hz {
+            self.freq_mhz.add(hz as f64 / 1e6);
+        }
+        if let Some(busy) = sample.busy_percent {
+            self.busy_percent.add(busy);
+        }
+        if let Some(temp) = sample.temp_c {
+            self.temp_c.add(temp);
+        }
+        // Intervall bis zu diesem Sample zählt, wenn es gedrosselt endet
+        if let Some(level) = sample.thermal_level {
+            let throttled = self.throttled.get_or_insert(Duration::ZERO);
+            if let Some(last) = &self.last
+                && level > 0
+            {
+                *throttled += sample.awake_since(last);
+            }
+        }
+        if let Some(last) = &self.last {
+            let gap = sample.suspended_since(last);
+            if !gap.is_zero() {
+                self.suspended += gap;
+                self.suspends += 1;
+            }
+        }
+        if self.first.is_none() {
+            self.first = Some(sample.clone());
+        }
+        self.last = Some(sample.clone());
+        self.samples += 1;
+    }
+
+    /// Wache Zeit zwischen erstem und letztem Sample
+    pub fn elapsed(&self) -> Duration {
+        match (&self.first, &self.last) {
+            (Some(first), Some(last)) => last.awake_since(first),
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Zeit zwischen erstem und letztem Sample einschließlich Suspend
+    pub fn boot_elapsed(&self) -> Duration {
+        match (&self.first, &self.last) {
+            (Some(first), Some(last)) => last.boottime.saturating_sub(first.boottime),
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Busy-Zeit je Power Level aus der Differenz von `gpu_clock_stats`
+    pub fn residency(&self, frequencies: &[u64]) -> Vec<Residency> {
+        let (Some(first), Some(last)) = (&self.first, &self.last) else {
+            return Vec::new();
+        };
+        if first.clock_stats.len() != last.clock_stats.len() {
+            return Vec::new();
+        }
+        let deltas: Vec<u64> =
+            last.clock_stats.iter().zip(&first.clock_stats).map(|(now, before)| now.saturating_sub(*before)).collect();
+        let total: u64 = deltas.iter().sum();
+        if total == 0 {
+            return Vec::new();
+        }
+        deltas
+            .iter()
+            .enumerate()
+            .map(|(level, &us)| Residency {
+                level,
+                freq_hz: frequencies.get(level).copied(),
+                busy: Duration::from_micros(us),
+                percent: us as f64 * 100.0 / total as f64,
+            })
+            .collect()
+    }
+
+    /// IFPC-Eintritte während der Sitzung
+    pub fn ifpc_entries(&self) -> Option<u64> {
+        Some(self.last.as_ref()?.ifpc_count?.saturating_sub(self.first.as_ref()?.ifpc_count?))
+    }
+
+    /// Nicht-Busy-Zeit aus `gpu_clock_stats` - obere Schranke für die Zeit im
+    /// Power Collapse, den KGSL selbst nicht misst
+    pub fn idle_time(&self) -> Option<Duration> {
+        let (first, last) = (self.first.as_ref()?, self.last.as_ref()?);
+        if first.clock_stats.is_empty() || first.clock_stats.len() != last.clock_stats.len() {
+            return None;
+        }
+        let busy: u64 = last.clock_stats.iter().zip(&first.clock_stats).map(|(now, before)| now.saturating_sub(*before)).sum();
+        Some(self.elapsed().saturating_sub(Duration::from_micros(busy)))
+    }
+
+    /// Neue GPU-Fehler während der Sitzung
+    pub fn fault_delta(&self) -> Option<u64> {
+        Some(self.faults_end?.saturating_sub(self.faults_start?))
+    }
+}
@@ -0,0 +1,740 @@
+//! Die einzige Stelle mit `unsafe`
+//!
+//! Alle FFI-Aufrufe (ioctl, mmap, ptrace, dlopen, ...) und
+//! Zeiger-Umwandlungen der Crate laufen über die Wrapper hier; `lib.rs` und
+//! `main.rs` verbieten `unsafe` überall sonst. Jeder Block nennt seine
+//! Annahme in einem `SAFETY`-Kommentar. Der übrige Code ist reines Rust und
+//! läuft unter Miri (siehe README).
+//!
+//! Was kein Typ prüfen kann: ioctl-Argumente enthalten teils Adressen
+//! (`u64` oder `usize`), denen der Kernel folgt. Die Puffer dahinter müssen
+//! während des Aufrufs leben und so groß sein, wie die Zählfelder im
+//! Argument angeben. Deshalb sind [`ioctl`], [`property_ioctl`] und
+//! [`ptrace`] nur in der Crate sichtbar; nach außen gibt es nur die
+//! typisierten Aufrufe in `kgsl`, `memory`, `fence` usw., die ihre Adressen
+//! selbst aus geliehenen Puffern setzen. Von außen erreichbar sind sonst nur
+//! KGSL-Requests ([`KgslRequest`]) mit genulltem oder NULL-Argument. Eine
+//! genullte Adresse heißt für viele Handler "keine", nicht EFAULT; was der
+//! Handler mit Nullwerten tut, hängt am Kommando.
+//!
+//! `unsafe fn` sind nur [`PluginLibrary::open`] und [`set_signal_handler`]:
+//! fremder Code läuft dort ohne Prüfung.
+
+use std::cell::Cell;
+use std::ffi::{CStr, CString, c_char, c_int, c_void};
+use std::io;
+use std::mem::size_of;
+use std::os::fd::{AsRawFd, BorrowedFd, FromRawFd, OwnedFd};
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU32, Ordering};
+use std::time::Duration;
+
+use crate::kgsl::KgslDeviceGetProperty;
+use crate::plugin::{CollectFn, PluginDescriptor, PluginMetric, PLUGIN_ABI_VERSION};
+
+fn check(result: c_int) -> io::Result<c_int> {
+    if result < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(result)
+}
+
+// ============================================================================
+// Plain Old Data
+// ============================================================================
+
+// ioctl-Strukturen leiten `FromBytes` (Kernel darf jedes Muster schreiben),
+// `IntoBytes` (lückenlos, jedes Byte lesbar) und `Immutable` von zerocopy
+// ab; Padding steht als explizites `_pad`-Feld im Struct.
+pub use zerocopy::{FromBytes, FromZeros, Immutable, IntoBytes};
+
+/// Argument, das der Kernel liest und beschreibt
+pub trait Pod: FromBytes + IntoBytes + Immutable {}
+
+impl<T: FromBytes + IntoBytes + Immutable> Pod for T {}
+
+/// Sicht als 64-Bit-Wörter; Vor- und Nachspann mit falscher Ausrichtung fallen weg
+pub fn as_words_mut(bytes: &mut [u8]) -> &mut [u64] {
+    // SAFETY: jedes Bitmuster ist ein gültiges u64, align_to_mut hält die Ausrichtung ein
+    let (_, words, _) = unsafe { bytes.align_to_mut::<u64>() };
+    words
+}
+
+// ============================================================================
+// ioctl
+// ============================================================================
+
+thread_local! {
+    /// Geräte-Syscalls (ioctl, mmap) dieses Threads, inklusive abgespielter Antworten
+    static DEVICE_CALLS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Bisherige Geräte-Syscalls dieses Threads; Differenzen zählen einen Abschnitt
+pub fn device_calls() -> u64 {
+    DEVICE_CALLS.with(Cell::get)
+}
+
+/// Zählt einen Geräte-Syscall, auch einen, den ein Backend statt des Kernels beantwortet
+pub(crate) fn count_device_call() {
+    DEVICE_CALLS.with(|calls| calls.set(calls.get() + 1));
+}
+
+/// Argumentgröße aus einer `_IOC`-Nummer
+pub const fn ioc_size(request: u32) -> usize {
+    ((request >> 16) & 0x3fff) as usize
+}
+
+fn check_size(request: u32, len: usize) -> io::Result<()> {
+    if ioc_size(request) > len {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("ioctl 0x{:08x} copies {} bytes, argument has {}", request, ioc_size(request), len),
+        ));
+    }
+    Ok(())
+}
+
+/// ioctl mit einem Argument fester Größe
+///
+/// Der Kernel kopiert höchstens so viele Bytes wie `request` angibt; ist
+/// `T` kleiner, wird gar nicht erst aufgerufen. Adressen in `arg` muss der
+/// Aufrufer aus lebenden Puffern passender Größe setzen, daher nur crate-intern.
+pub(crate) fn ioctl<T: Pod>(fd: i32, request: u32, arg: &mut T) -> io::Result<()> {
+    check_size(request, size_of::<T>())?;
+    count_device_call();
+    // SAFETY: arg ist gültig und mindestens ioc_size(request) groß
+    check(unsafe { libc::ioctl(fd, request as _, arg.as_mut_bytes().as_mut_ptr()) }).map(|_| ())
+}
+
+/// KGSL-Request (`KGSL_IOC_TYPE`) mit kodierter Argumentgröße
+///
+/// Ältere Treiber anderer Typen ignorieren die Größe in der Nummer (z.B.
+/// TCGETS mit Größe 0) und schreiben ihre ganze Struktur; KGSL kopiert genau
+/// `_IOC_SIZE`. Deshalb nehmen die öffentlichen Probes nur diesen Typ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KgslRequest(u32);
+
+impl KgslRequest {
+    /// Bricht bei fremdem Typ oder Größe 0 ab, in `const` schon beim Kompilieren
+    pub const fn new(request: u32) -> Self {
+        assert!((request >> 8) & 0xff == crate::kgsl::KGSL_IOC_TYPE, "not a KGSL ioctl");
+        assert!(ioc_size(request) > 0, "KGSL ioctl without argument size");
+        KgslRequest(request)
+    }
+
+    pub const fn raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// ioctl mit genulltem Argument der kodierten Größe (Probes unbekannter Strukturen)
+///
+/// Nur für Kommandos, die mit Nullwerten nichts verändern.
+pub fn ioctl_zeroed(fd: i32, request: KgslRequest) -> io::Result<()> {
+    let mut buf = vec![0u64; ioc_size(request.0).div_ceil(8)];
+    count_device_call();
+    // SAFETY: KGSL kopiert höchstens ioc_size(request) Bytes, der Puffer deckt
+    // sie ab; er enthält keine gültigen Adressen
+    check(unsafe { libc::ioctl(fd, request.0 as _, buf.as_mut_ptr()) }).map(|_| ())
+}
+
+/// ioctl mit NULL als Argument; KGSL kopiert das Argument und endet dabei mit EFAULT
+pub fn ioctl_null(fd: i32, request: KgslRequest) -> io::Result<()> {
+    count_device_call();
+    // SAFETY: der Kernel prüft Zeiger aus dem Userspace, NULL wird nie beschrieben
+    check(unsafe { libc::ioctl(fd, request.0 as _, std::ptr::null_mut::<c_void>()) }).map(|_| ())
+}
+
+/// GETPROPERTY bzw. SETPROPERTY mit `value` als Payload
+///
+/// `sizebytes` ist immer die Länge von `value`, der Kernel schreibt nicht
+/// darüber hinaus. Manche Properties folgen Adressen im Payload, daher
+/// crate-intern wie [`ioctl`].
+pub(crate) fn property_ioctl(fd: i32, request: u32, type_: u32, value: &mut [u8]) -> io::Result<()> {
+    let mut prop = KgslDeviceGetProperty {
+        type_,
+        value: value.as_mut_ptr() as usize,
+        sizebytes: value.len() as u32,
+        ..Default::default()
+    };
+    ioctl(fd, request, &mut prop)
+}
+
+// ============================================================================
+// Speicher-Mappings
+// ============================================================================
+
+/// mmap-Bereich, wird beim Drop aufgehoben
+pub struct Mapping {
+    ptr: *mut u8,
+    len: usize,
+    writable: bool,
+    /// Kernel, GPU oder andere Prozesse schreiben mit
+    shared: bool,
+}
+
+// SAFETY: das Mapping gehört exklusiv diesem Wert, Zugriffe gehen über &self/&mut self
+unsafe impl Send for Mapping {}
+
+impl Mapping {
+    fn new(len: usize, writable: bool, flags: c_int, fd: c_int, offset: u64) -> io::Result<Self> {
+        let prot = if writable { libc::PROT_READ | libc::PROT_WRITE } else { libc::PROT_READ };
+        if fd >= 0 {
+            count_device_call();
+        }
+        // SAFETY: neues Mapping an einer vom Kernel gewählten Adresse, überschreibt nichts
+        let ptr = unsafe { libc::mmap(std::ptr::null_mut(), len, prot, flags, fd, offset as libc::off_t) };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        let shared = flags & libc::MAP_SHARED != 0;
+        Ok(Mapping { ptr: ptr.cast(), len, writable, shared })
+    }
+
+    /// `MAP_SHARED` von `fd` ab `offset`
+    pub fn shared(fd: BorrowedFd<'_>, len: usize, writable: bool, offset: u64) -> io::Result<Self> {
+        Self::new(len, writable, libc::MAP_SHARED, fd.as_raw_fd(), offset)
+    }
+
+    /// Anonymer, genullter und seitenausgerichteter Speicher
+    pub fn anonymous(len: usize) -> io::Result<Self> {
+        Self::new(len, true, libc::MAP_PRIVATE | libc::MAP_ANONYMOUS, -1, 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Nur für private Mappings; geteilte gehen über [`Mapping::read`] und
+    /// [`Mapping::write`], weil dort nebenläufig geschrieben wird
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        assert!(self.writable && !self.shared, "shared or read-only mapping");
+        // SAFETY: ptr..ptr+len ist gemappt und lebt so lange wie self
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    /// Zeiger auf `T` bei `offset`, falls innerhalb und ausgerichtet
+    fn at<T>(&self, offset: usize) -> Option<*mut T> {
+        let end = offset.checked_add(size_of::<T>())?;
+        let ptr = self.ptr.wrapping_add(offset);
+        (end <= self.len && ptr.align_offset(align_of::<T>()) == 0).then_some(ptr.cast())
+    }
+
+    /// Liest `T` bei `offset`; der Kernel oder ein anderer Prozess schreibt nebenläufig
+    pub fn read<T: Pod>(&self, offset: usize) -> Option<T> {
+        let ptr = self.at::<T>(offset)?;
+        // SAFETY: innerhalb des Mappings und ausgerichtet, jedes Muster ist gültig
+        Some(unsafe { std::ptr::read_volatile(ptr) })
+    }
+
+    /// Schreibt `T` bei `offset`; `false` außerhalb oder bei read-only
+    pub fn write<T: Pod>(&self, offset: usize, value: T) -> bool {
+        match self.at::<T>(offset) {
+            Some(ptr) if self.writable => {
+                // SAFETY: innerhalb eines schreibbaren Mappings und ausgerichtet
+                unsafe { std::ptr::write_volatile(ptr, value) };
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Kopiert `bytes` ab `offset`; `false` außerhalb oder bei read-only
+    pub fn write_bytes(&self, offset: usize, bytes: &[u8]) -> bool {
+        if offset.checked_add(bytes.len()).is_none_or(|end| end > self.len) || !self.writable {
+            return false;
+        }
+        for (i, &byte) in bytes.iter().enumerate() {
+            // SAFETY: innerhalb eines schreibbaren Mappings, siehe oben
+            unsafe { std::ptr::write_volatile(self.ptr.add(offset + i), byte) };
+        }
+        true
+    }
+
+    /// Atomarer Zähler bei `offset` (Seqlock zwischen Prozessen)
+    pub fn atomic_u32(&self, offset: usize) -> Option<&AtomicU32> {
+        let ptr = self.at::<AtomicU32>(offset)?;
+        // SAFETY: ausgerichtet, lebt so lange wie self; AtomicU32 hat das Layout von u32
+        Some(unsafe { &*ptr })
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        // SAFETY: genau der Bereich aus mmap, danach nicht mehr benutzt
+        unsafe { libc::munmap(self.ptr.cast(), self.len) };
+    }
+}
+
+/// Seitengröße des Systems
+pub fn page_size() -> usize {
+    // SAFETY: reine Abfrage
+    unsafe { libc::sysconf(libc::_SC_PAGESIZE) as usize }
+}
+
+// ============================================================================
+// Datei-Deskriptoren
+// ============================================================================
+
+/// Übernimmt einen fd, den der Kernel gerade zurückgegeben hat
+///
+/// Nur für frische fds aus ioctl-Antworten: sie gehören sonst niemandem.
+pub(crate) fn owned_fd(raw: i32) -> io::Result<OwnedFd> {
+    if raw < 0 {
+        return Err(io::Error::other("kernel returned no file descriptor"));
+    }
+    // SAFETY: siehe oben, der fd ist offen und ohne anderen Besitzer
+    Ok(unsafe { OwnedFd::from_raw_fd(raw) })
+}
+
+/// Kopie eines (auch fremd verwalteten) fds wie stdout
+pub fn dup(fd: i32) -> io::Result<OwnedFd> {
+    // SAFETY: dup liefert einen neuen fd, der nur uns gehört
+    check(unsafe { libc::dup(fd) }).map(|raw| unsafe { OwnedFd::from_raw_fd(raw) })
+}
+
+/// Lese- und Schreibende einer Pipe
+pub fn pipe() -> io::Result<(OwnedFd, OwnedFd)> {
+    let mut fds = [0; 2];
+    // SAFETY: pipe schreibt genau zwei fds in das Array
+    check(unsafe { libc::pipe(fds.as_mut_ptr()) })?;
+    // SAFETY: beide fds sind neu und gehören nur uns
+    Ok(unsafe { (OwnedFd::from_raw_fd(fds[0]), OwnedFd::from_raw_fd(fds[1])) })
+}
+
+/// Nicht blockierende Pipe mit `O_CLOEXEC`, z.B. zum Wecken aus Signal-Handlern
+pub fn pipe_nonblocking() -> io::Result<(OwnedFd, OwnedFd)> {
+    let mut fds = [0; 2];
+    // SAFETY: pipe2 schreibt genau zwei fds in das Array
+    check(unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_NONBLOCK | libc::O_CLOEXEC) })?;
+    // SAFETY: beide fds sind neu und gehören nur uns
+    Ok(unsafe { (OwnedFd::from_raw_fd(fds[0]), OwnedFd::from_raw_fd(fds[1])) })
+}
+
+/// Ersetzt `target` (z.B. stdout) durch eine Kopie von `fd`
+pub fn dup2(fd: BorrowedFd<'_>, target: i32) -> io::Result<()> {
+    // SAFETY: berührt nur die fd-Tabelle
+    check(unsafe { libc::dup2(fd.as_raw_fd(), target) }).map(|_| ())
+}
+
+/// Schreibt alle Teile mit einem Syscall, ohne zu allozieren
+pub fn writev(fd: i32, parts: &[&[u8]; 2]) -> io::Result<usize> {
+    let iov = parts.map(|p| libc::iovec { iov_base: p.as_ptr() as *mut c_void, iov_len: p.len() });
+    // SAFETY: der Kernel liest nur aus den Slices
+    let n = unsafe { libc::writev(fd, iov.as_ptr(), iov.len() as c_int) };
+    if n < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(n as usize)
+}
+
+/// Statusflags eines fds (`F_GETFL`)
+pub fn file_status_flags(fd: i32) -> io::Result<c_int> {
+    // SAFETY: reine Abfrage
+    check(unsafe { libc::fcntl(fd, libc::F_GETFL) })
+}
+
+pub fn isatty(fd: i32) -> bool {
+    // SAFETY: reine Abfrage
+    unsafe { libc::isatty(fd) != 0 }
+}
+
+/// `flock`, z.B. `LOCK_EX | LOCK_NB`
+pub fn flock(fd: BorrowedFd<'_>, operation: c_int) -> io::Result<()> {
+    // SAFETY: reine Abfrage
+    check(unsafe { libc::flock(fd.as_raw_fd(), operation) }).map(|_| ())
+}
+
+/// Wartet bis `fd` lesbar ist; `false` nach `timeout`
+pub fn poll_readable(fd: BorrowedFd<'_>, timeout: Duration) -> io::Result<bool> {
+    let mut pfd = libc::pollfd { fd: fd.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+    let ms = timeout.as_millis().min(i32::MAX as u128) as i32;
+    // SAFETY: ein gültiger pollfd
+    check(unsafe { libc::poll(&mut pfd, 1, ms) }).map(|n| n > 0)
+}
+
+/// Index des ersten lesbaren fds; `None` nach `timeout`
+pub fn poll_readable_any(fds: &[BorrowedFd<'_>], timeout: Duration) -> io::Result<Option<usize>> {
+    let mut pfds: Vec<libc::pollfd> =
+        fds.iter().map(|fd| libc::pollfd { fd: fd.as_raw_fd(), events: libc::POLLIN, revents: 0 }).collect();
+    let ms = timeout.as_millis().min(i32::MAX as u128) as i32;
+    // SAFETY: `pfds.len()` gültige pollfds
+    check(unsafe { libc::poll(pfds.as_mut_ptr(), pfds.len() as libc::nfds_t, ms) })?;
+    Ok(pfds.iter().position(|p| p.revents != 0))
+}
+
+/// Nicht blockierender inotify-fd mit Watch auf `dir`
+pub fn inotify(dir: &Path, mask: u32) -> io::Result<OwnedFd> {
+    let path = CString::new(dir.as_os_str().as_bytes()).map_err(io::Error::other)?;
+    // SAFETY: neuer fd, der nur uns gehört
+    let fd = unsafe { OwnedFd::from_raw_fd(check(libc::inotify_init1(libc::IN_NONBLOCK | libc::IN_CLOEXEC))?) };
+    // SAFETY: nullterminierter Pfad
+    check(unsafe { libc::inotify_add_watch(fd.as_raw_fd(), path.as_ptr(), mask) })?;
+    Ok(fd)
+}
+
+// ============================================================================
+// Prozess und Rechte
+// ============================================================================
+
+/// Release, Version und Maschine aus `uname()`
+pub fn uname() -> Option<(String, String, String)> {
+    // SAFETY: utsname besteht nur aus char-Arrays
+    let mut uts: libc::utsname = unsafe { std::mem::zeroed() };
+    // SAFETY: uts ist groß genug, der Kernel schreibt nullterminierte Felder
+    if unsafe { libc::uname(&mut uts) } != 0 {
+        return None;
+    }
+    let field = |raw: &[c_char]| {
+        let bytes: Vec<u8> = raw.iter().take_while(|&&c| c != 0).map(|&c| c as u8).collect();
+        String::from_utf8_lossy(&bytes).into_owned()
+    };
+    Some((field(&uts.release), field(&uts.version), field(&uts.machine)))
+}
+
+pub fn geteuid() -> u32 {
+    // SAFETY: reine Abfrage
+    unsafe { libc::geteuid() }
+}
+
+pub fn getegid() -> u32 {
+    // SAFETY: reine Abfrage
+    unsafe { libc::getegid() }
+}
+
+/// Name, UID und GID aus der Passwort-Datenbank
+pub fn getpwnam(user: &CStr) -> Option<(String, u32, u32)> {
+    // SAFETY: passwd besteht aus Zahlen und Zeigern, null ist gültig
+    let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut buf = vec![0 as c_char; 4096];
+    let mut result: *mut libc::passwd = std::ptr::null_mut();
+    // SAFETY: alle Puffer gültig, die Strings in pwd zeigen in buf
+    let rc = unsafe { libc::getpwnam_r(user.as_ptr(), &mut pwd, buf.as_mut_ptr(), buf.len(), &mut result) };
+    if rc != 0 || result.is_null() {
+        return None;
+    }
+    // SAFETY: bei Erfolg ist pw_name nullterminiert und lebt in buf
+    let name = unsafe { CStr::from_ptr(pwd.pw_name) }.to_string_lossy().into_owned();
+    Some((name, pwd.pw_uid, pwd.pw_gid))
+}
+
+/// Zusatzgruppen weg, dann GID und UID endgültig setzen, `no_new_privs`
+pub fn set_identity(uid: u32, gid: u32) -> io::Result<()> {
+    // SAFETY: nur Prozessattribute, keine Zeiger außer NULL bei setgroups(0)
+    unsafe {
+        check(libc::setgroups(0, std::ptr::null()))?;
+        check(libc::setresgid(gid, gid, gid))?;
+        check(libc::setresuid(uid, uid, uid))?;
+        check(libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0))?;
+    }
+    Ok(())
+}
+
+/// Ob `setuid(uid)` gelingt
+pub fn try_setuid(uid: u32) -> bool {
+    // SAFETY: nur Prozessattribute
+    unsafe { libc::setuid(uid) == 0 }
+}
+
+/// Pinnt den aufrufenden Thread und alle späteren
+pub fn set_affinity(cpus: &[usize]) -> io::Result<()> {
+    // SAFETY: cpu_set_t ist ein Bitfeld, genullt heißt leer
+    let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    for &cpu in cpus {
+        if cpu >= libc::CPU_SETSIZE as usize {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("CPU {} out of range", cpu)));
+        }
+        // SAFETY: cpu ist innerhalb von cpu_set_t
+        unsafe { libc::CPU_SET(cpu, &mut set) };
+    }
+    // SAFETY: set ist vollständig initialisiert
+    check(unsafe { libc::sched_setaffinity(0, size_of::<libc::cpu_set_t>(), &set) }).map(|_| ())
+}
+
+/// Nice-Level des aufrufenden Threads
+pub fn set_priority(nice: i32) -> io::Result<()> {
+    // `which` ist je nach libc signed oder unsigned
+    // SAFETY: nur Prozessattribute
+    check(unsafe { libc::setpriority(libc::PRIO_PROCESS as _, 0, nice) }).map(|_| ())
+}
+
+fn clock(id: libc::clockid_t) -> Duration {
+    let mut ts = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+    // SAFETY: ts ist gültig
+    unsafe { libc::clock_gettime(id, &mut ts) };
+    Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32)
+}
+
+/// CLOCK_MONOTONIC, gleiche Zeitbasis wie Fences und der Kernel
+pub fn monotonic() -> Duration {
+    clock(libc::CLOCK_MONOTONIC)
+}
+
+/// CLOCK_BOOTTIME: wie CLOCK_MONOTONIC, zählt aber auch im Suspend weiter
+pub fn boottime() -> Duration {
+    clock(libc::CLOCK_BOOTTIME)
+}
+
+/// `klogctl()` mit Puffer; `None` fragt nur die Größe ab
+pub fn klogctl(action: c_int, buf: Option<&mut [u8]>) -> io::Result<usize> {
+    let (ptr, len) = match buf {
+        Some(buf) => (buf.as_mut_ptr().cast::<c_char>(), buf.len().min(c_int::MAX as usize) as c_int),
+        None => (std::ptr::null_mut(), 0),
+    };
+    // SAFETY: der Kernel schreibt höchstens len Bytes
+    check(unsafe { libc::klogctl(action, ptr, len) }).map(|n| n as usize)
+}
+
+// ============================================================================
+// Signale
+// ============================================================================
+
+static SIGNALLED: AtomicBool = AtomicBool::new(false);
+static WAKE_FD: AtomicI32 = AtomicI32::new(-1);
+
+extern "C" fn on_signal(_sig: c_int) {
+    SIGNALLED.store(true, Ordering::SeqCst);
+    let fd = WAKE_FD.load(Ordering::SeqCst);
+    if fd >= 0 {
+        // SAFETY: write ist async-signal-sicher und liest genau ein Byte;
+        // ein geschlossener fd liefert nur EBADF
+        unsafe { libc::write(fd, [1u8].as_ptr().cast(), 1) };
+    }
+}
+
+/// Setzt einen beliebigen Signal-Handler
+///
+/// # Safety
+///
+/// `handler` läuft mitten in beliebigem Code dieses Prozesses und darf nur
+/// async-signal-sichere Funktionen aufrufen (kein `malloc`, keine Locks,
+/// kein `println!`), siehe signal-safety(7).
+pub unsafe fn set_signal_handler(sig: c_int, handler: extern "C" fn(c_int)) {
+    // SAFETY: Signatur passt, der Rest ist Vertrag des Aufrufers
+    unsafe { libc::signal(sig, handler as libc::sighandler_t) };
+}
+
+/// Fängt `signals` ab: [`signal_caught`] wird `true`, und nach `wake`
+/// (Schreibende einer [`pipe_nonblocking`]) geht ein Byte, das schlafende
+/// Threads weckt
+pub fn catch_signals(signals: &[c_int], wake: Option<BorrowedFd<'static>>) {
+    if let Some(fd) = wake {
+        WAKE_FD.store(fd.as_raw_fd(), Ordering::SeqCst);
+    }
+    for &sig in signals {
+        // SAFETY: `on_signal` nutzt nur Atomics und write
+        unsafe { set_signal_handler(sig, on_signal) };
+    }
+}
+
+/// Ob eines der Signale aus [`catch_signals`] kam
+pub fn signal_caught() -> bool {
+    SIGNALLED.load(Ordering::SeqCst)
+}
+
+/// Blockierende Syscalls kehren bei `sig` mit EINTR zurück
+pub fn interrupt_syscalls_on(sig: c_int) {
+    // SAFETY: Nullmuster ist ein gültiger (leerer) sigaction; sigaction liest
+    // und schreibt nur die übergebene Struktur
+    unsafe {
+        let mut action: libc::sigaction = std::mem::zeroed();
+        if libc::sigaction(sig, std::ptr::null(), &mut action) == 0 {
+            action.sa_flags &= !libc::SA_RESTART;
+            libc::sigaction(sig, &action, std::ptr::null_mut());
+        }
+    }
+}
+
+// ============================================================================
+// ptrace
+// ============================================================================
+
+/// ptrace-Aufruf, dessen `data` kein Zeiger in unseren Speicher ist
+///
+/// Nur für SEIZE, INTERRUPT, LISTEN, SYSCALL und DETACH; für Requests, die
+/// in den Aufrufer schreiben, gibt es eigene Wrapper. Daher crate-intern.
+pub(crate) fn ptrace(request: libc::c_uint, tid: i32, addr: usize, data: usize) -> io::Result<libc::c_long> {
+    const REQUESTS: [libc::c_uint; 5] =
+        [libc::PTRACE_SEIZE, libc::PTRACE_INTERRUPT, libc::PTRACE_LISTEN, libc::PTRACE_SYSCALL, libc::PTRACE_DETACH];
+    if !REQUESTS.contains(&request) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("ptrace request {} not allowed", request)));
+    }
+    // SAFETY: siehe oben, der Kernel schreibt nur in den Zielprozess
+    let ret = unsafe { libc::ptrace(request as _, tid, addr as *mut c_void, data as *mut c_void) };
+    if ret < 0 { Err(io::Error::last_os_error()) } else { Ok(ret) }
+}
+
+/// `PTRACE_GETREGSET` in `regs`
+pub fn ptrace_getregset<T: Pod>(tid: i32, note: c_int, regs: &mut T) -> io::Result<()> {
+    let mut iov = libc::iovec { iov_base: regs.as_mut_bytes().as_mut_ptr().cast(), iov_len: size_of::<T>() };
+    // SAFETY: der Kernel schreibt höchstens iov_len Bytes nach regs
+    let ret = unsafe { libc::ptrace(libc::PTRACE_GETREGSET, tid, note as usize as *mut c_void, &mut iov) };
+    if ret < 0 { Err(io::Error::last_os_error()) } else { Ok(()) }
+}
+
+/// `waitpid`, liefert TID und Status
+pub fn waitpid(pid: i32, options: c_int) -> io::Result<(i32, c_int)> {
+    let mut status = 0;
+    // SAFETY: status ist gültig
+    let tid = check(unsafe { libc::waitpid(pid, &mut status, options) })?;
+    Ok((tid, status))
+}
+
+/// Liest `buf.len()` Bytes ab `addr` aus einem anderen Prozess
+pub fn process_vm_read(tid: i32, addr: usize, buf: &mut [u8]) -> bool {
+    let local = libc::iovec { iov_base: buf.as_mut_ptr().cast(), iov_len: buf.len() };
+    let remote = libc::iovec { iov_base: addr as *mut c_void, iov_len: buf.len() };
+    // SAFETY: geschrieben wird nur in buf; die fremde Adresse prüft der Kernel
+    let n = unsafe { libc::process_vm_readv(tid, &local, 1, &remote, 1, 0) };
+    n == buf.len() as isize
+}
+
+// ============================================================================
+// Zähler-Register
+// ============================================================================
+
+/// `CNTVCT_EL0`
+#[cfg(target_arch = "aarch64")]
+pub fn cntvct() -> u64 {
+    let ticks: u64;
+    // isb: der Zähler darf nicht vor vorherigen Befehlen gelesen werden
+    // SAFETY: im Userspace lesbares Systemregister, kein Speicherzugriff
+    unsafe { std::arch::asm!("isb", "mrs {}, cntvct_el0", out(reg) ticks, options(nomem, nostack)) };
+    ticks
+}
+
+/// `CNTFRQ_EL0`
+#[cfg(target_arch = "aarch64")]
+pub fn cntfrq() -> u64 {
+    let hz: u64;
+    // SAFETY: wie `cntvct`
+    unsafe { std::arch::asm!("mrs {}, cntfrq_el0", out(reg) hz, options(nomem, nostack)) };
+    hz
+}
+
+// ============================================================================
+// Plugins
+// ============================================================================
+
+/// Mit `dlopen` geladenes Plugin, `dlclose` beim Drop
+///
+/// Vertrauensgrenze: das Plugin muss `include/adreno_plugin.h` einhalten.
+pub struct PluginLibrary {
+    handle: *mut c_void,
+    descriptor: *const PluginDescriptor,
+}
+
+// SAFETY: Handle und Deskriptor gehören exklusiv diesem Wert; Plugins müssen
+// `collect` aus wechselnden Threads vertragen (nicht gleichzeitig)
+unsafe impl Send for PluginLibrary {}
+
+// SAFETY: der Deskriptor ist unveränderlich und lebt so lange wie die Bibliothek
+unsafe impl Sync for PluginDescriptor {}
+
+impl PluginDescriptor {
+    /// Deskriptor der aktuellen ABI-Version, für Plugins in Rust
+    ///
+    /// # Safety
+    ///
+    /// Der Host ruft die Funktionen ohne weitere Prüfung: `collect` schreibt
+    /// höchstens `max` Einträge nach `out`, ihre Namen leben bis zum nächsten
+    /// Aufruf, und beide Funktionen vertragen Aufrufe aus wechselnden Threads
+    /// (nie gleichzeitig). Siehe `include/adreno_plugin.h`.
+    pub const unsafe fn new(
+        name: &'static CStr,
+        supported: Option<unsafe extern "C" fn() -> c_int>,
+        collect: CollectFn,
+    ) -> Self {
+        PluginDescriptor { abi_version: PLUGIN_ABI_VERSION, name: name.as_ptr(), supported, collect: Some(collect) }
+    }
+}
+
+/// Letzter `dlerror`-Text
+fn dl_error() -> String {
+    // SAFETY: dlerror liefert NULL oder einen nullterminierten String
+    let text = unsafe { libc::dlerror() };
+    if text.is_null() {
+        return "unknown dlopen error".to_string();
+    }
+    // SAFETY: siehe oben
+    unsafe { CStr::from_ptr(text) }.to_string_lossy().into_owned()
+}
+
+impl PluginLibrary {
+    /// Lädt `path` und ruft `init_symbol` als `const adreno_plugin *(*)(void)` auf
+    ///
+    /// # Safety
+    ///
+    /// `dlopen` führt die Konstruktoren der Bibliothek aus, und der
+    /// Deskriptor wird danach ungeprüft benutzt. Die Bibliothek muss
+    /// vertrauenswürdig sein und `include/adreno_plugin.h` einhalten: `init`
+    /// liefert NULL oder einen statischen Deskriptor, dessen Funktionen die
+    /// Regeln von [`PluginDescriptor::new`] erfüllen.
+    pub unsafe fn open(path: &Path, init_symbol: &CStr) -> Result<Self, String> {
+        let c_path = CString::new(path.as_os_str().as_bytes()).map_err(|e| e.to_string())?;
+        // SAFETY: nullterminierter Pfad; Konstruktoren der Bibliothek laufen hier
+        let handle = unsafe { libc::dlopen(c_path.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL) };
+        if handle.is_null() {
+            return Err(dl_error());
+        }
+        // Ab hier schließt der Drop das Handle auch im Fehlerfall
+        let mut library = PluginLibrary { handle, descriptor: std::ptr::null() };
+        // SAFETY: gültiges Handle, nullterminierter Name
+        let symbol = unsafe { libc::dlsym(handle, init_symbol.as_ptr()) };
+        if symbol.is_null() {
+            return Err(format!("no {} symbol", init_symbol.to_string_lossy()));
+        }
+        // SAFETY: Signatur laut Plugin-Header
+        let init = unsafe { std::mem::transmute::<*mut c_void, extern "C" fn() -> *const PluginDescriptor>(symbol) };
+        library.descriptor = init();
+        if library.descriptor.is_null() {
+            return Err("init returned NULL".to_string());
+        }
+        Ok(library)
+    }
+
+    pub fn descriptor(&self) -> &PluginDescriptor {
+        // SAFETY: in `open` geprüft, statisch und unveränderlich, solange die Bibliothek geladen ist
+        unsafe { &*self.descriptor }
+    }
+}
+
+impl Drop for PluginLibrary {
+    fn drop(&mut self) {
+        // SAFETY: Handle aus dlopen, danach nicht mehr benutzt
+        unsafe { libc::dlclose(self.handle) };
+    }
+}
+
+/// Kurzname aus dem Deskriptor
+pub(crate) fn plugin_name(descriptor: &PluginDescriptor) -> Option<String> {
+    // SAFETY: laut Header NULL oder nullterminiert und statisch
+    (!descriptor.name.is_null()).then(|| unsafe { CStr::from_ptr(descriptor.name) }.to_string_lossy().into_owned())
+}
+
+/// `supported()` des Plugins, ohne Funktion gilt es als unterstützt
+pub(crate) fn plugin_supported(descriptor: &PluginDescriptor) -> bool {
+    match descriptor.supported {
+        // SAFETY: Vertrauensgrenze wie bei `plugin_descriptor`
+        Some(supported) => unsafe { supported() != 0 },
+        None => true,
+    }
+}
+
+/// `collect()` des Plugins in `out`; Anzahl oder negativer errno
+pub(crate) fn plugin_collect(descriptor: &PluginDescriptor, out: &mut [PluginMetric]) -> Option<c_int> {
+    let collect = descriptor.collect?;
+    // SAFETY: das Plugin schreibt höchstens out.len() Einträge
+    Some(unsafe { collect(out.as_mut_ptr(), out.len()) })
+}
+
+/// Name eines Messwerts, gültig bis zum nächsten `collect`
+pub(crate) fn metric_name(metric: &PluginMetric) -> Option<String> {
+    // SAFETY: laut Header NULL oder nullterminiert
+    (!metric.name.is_null()).then(|| unsafe { CStr::from_ptr(metric.name) }.to_string_lossy().into_owned())
+}
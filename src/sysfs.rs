@@ -0,0 +1,347 @@
+//! KGSL sysfs Zugriff (`/sys/class/kgsl/kgsl-3d0/...`)
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io;
+use std::os::unix::fs::FileExt;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::sysroot;
+
+/// Basisverzeichnis der KGSL Klassen-Einträge
+pub const KGSL_CLASS_DIR: &str = "/sys/class/kgsl";
+
+/// sysfs-Verzeichnis eines Geräts, z.B. `kgsl-3d0` aus `/dev/kgsl-3d0`
+pub fn device_dir(device_path: &str) -> PathBuf {
+    let name = Path::new(device_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("kgsl-3d0");
+    sysroot::resolve(KGSL_CLASS_DIR).join(name)
+}
+
+/// Liest eine sysfs-Datei als getrimmten String; mit [`hold`] offen
+/// gehaltene Dateien über ihr Handle
+pub fn read_string(path: impl AsRef<Path>) -> io::Result<String> {
+    if let Some(held) = read_held(path.as_ref()) {
+        return Ok(held?.trim().to_string());
+    }
+    Ok(fs::read_to_string(path)?.trim().to_string())
+}
+
+/// Liest eine sysfs-Datei als Zahl
+pub fn read_u64(path: impl AsRef<Path>) -> io::Result<u64> {
+    let text = read_string(path)?;
+    parse_u64(&text).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("not a number: {:?}", text)))
+}
+
+/// Schreibt einen Wert in eine sysfs-Datei (von außen nur über
+/// [`SettingsGuard`](crate::settings::SettingsGuard))
+pub(crate) fn write_value(path: impl AsRef<Path>, value: impl std::fmt::Display) -> io::Result<()> {
+    fs::write(path, value.to_string())
+}
+
+/// Aktuelle GPU Frequenz in Hz (`gpuclk`)
+pub fn gpuclk(dir: &Path) -> io::Result<u64> {
+    read_u64(dir.join("gpuclk"))
+}
+
+/// GPU-Auslastung in Prozent (`gpu_busy_percentage`, sonst `gpubusy`)
+pub fn busy_percent(dir: &Path) -> io::Result<f64> {
+    if let Ok(percent) = read_u64(dir.join("gpu_busy_percentage")) {
+        return Ok(percent as f64);
+    }
+
+    let text = read_string(dir.join("gpubusy"))?;
+    parse_gpubusy(&text).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("unexpected gpubusy: {:?}", text)))
+}
+
+/// Index des niedrigsten erlaubten Power Levels (höchster Index = niedrigste Frequenz)
+pub fn min_pwrlevel(dir: &Path) -> io::Result<u32> {
+    read_u64(dir.join("min_pwrlevel")).map(|v| v as u32)
+}
+
+/// Index des höchsten erlaubten Power Levels (0 = höchste Frequenz)
+pub fn max_pwrlevel(dir: &Path) -> io::Result<u32> {
+    read_u64(dir.join("max_pwrlevel")).map(|v| v as u32)
+}
+
+/// Vom Thermal-Management erzwungenes höchstes Power Level (0 = ungedrosselt)
+pub fn thermal_pwrlevel(dir: &Path) -> io::Result<u32> {
+    read_u64(dir.join("thermal_pwrlevel")).map(|v| v as u32)
+}
+
+/// Anzahl der Power Levels
+pub fn num_pwrlevels(dir: &Path) -> io::Result<u32> {
+    read_u64(dir.join("num_pwrlevels")).map(|v| v as u32)
+}
+
+/// Verfügbare Frequenzen in Hz, Index entspricht dem Power Level
+pub fn available_frequencies(dir: &Path) -> io::Result<Vec<u64>> {
+    Ok(parse_u64_list(&read_string(dir.join("gpu_available_frequencies"))?))
+}
+
+/// Kumulierte Busy-Zeit in µs je Power Level (`gpu_clock_stats`)
+pub fn clock_stats(dir: &Path) -> io::Result<Vec<u64>> {
+    Ok(parse_u64_list(&read_string(dir.join("gpu_clock_stats"))?))
+}
+
+/// Anzahl bisheriger Preemptions (`preempt_count`)
+pub fn preempt_count(dir: &Path) -> io::Result<u64> {
+    read_u64(dir.join("preempt_count"))
+}
+
+/// Preemption aktiviert (`preemption`)
+pub fn preemption_enabled(dir: &Path) -> io::Result<bool> {
+    read_u64(dir.join("preemption")).map(|v| v != 0)
+}
+
+/// Preemption-Level (`preempt_level`): 0 = Ringbuffer, 1 = Chunk, 2 = Draw
+pub fn preempt_level(dir: &Path) -> io::Result<u64> {
+    read_u64(dir.join("preempt_level"))
+}
+
+/// Inter-Frame Power Collapse aktiviert (`ifpc`, nur mit GMU)
+pub fn ifpc_enabled(dir: &Path) -> io::Result<bool> {
+    read_u64(dir.join("ifpc")).map(|v| v != 0)
+}
+
+/// Anzahl bisheriger IFPC-Eintritte (`ifpc_count`)
+pub fn ifpc_count(dir: &Path) -> io::Result<u64> {
+    read_u64(dir.join("ifpc_count"))
+}
+
+/// Thermal-Zonen des Kernels
+pub const THERMAL_DIR: &str = "/sys/class/thermal";
+
+/// Temperatur-Dateien der GPU: `temp` des KGSL-Geräts, sonst Thermal-Zonen mit "gpu" im Typ
+pub fn temperature_files(dir: &Path) -> Vec<PathBuf> {
+    let own = dir.join("temp");
+    if own.exists() {
+        return vec![own];
+    }
+    let Ok(zones) = fs::read_dir(sysroot::resolve(THERMAL_DIR)) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = zones
+        .flatten()
+        .map(|zone| zone.path())
+        .filter(|zone| read_string(zone.join("type")).is_ok_and(|t| t.to_ascii_lowercase().contains("gpu")))
+        .map(|zone| zone.join("temp"))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Höchste Temperatur in °C aus [`temperature_files`] (Werte in m°C)
+pub fn read_temperature(files: &[PathBuf]) -> io::Result<f64> {
+    files
+        .iter()
+        .filter_map(|file| read_u64(file).ok())
+        .max()
+        .map(|milli| milli as f64 / 1000.0)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no GPU temperature sensor"))
+}
+
+// ============================================================================
+// Parser für den Dateiinhalt
+// ============================================================================
+
+/// Erste Zahl, z.B. aus `"585000000"` oder `"3 (max)"`
+pub fn parse_u64(text: &str) -> Option<u64> {
+    text.split_whitespace().next()?.parse().ok()
+}
+
+/// Alle Zahlen einer Liste; Unlesbares fällt weg
+pub fn parse_u64_list(text: &str) -> Vec<u64> {
+    text.split_whitespace().filter_map(|s| s.parse().ok()).collect()
+}
+
+/// `gpubusy`: "<busy> <total>" seit dem letzten Lesen, in Prozent
+pub fn parse_gpubusy(text: &str) -> Option<f64> {
+    match parse_u64_list(text).as_slice() {
+        [_, 0] => Some(0.0),
+        [busy, total] => Some(*busy as f64 * 100.0 / *total as f64),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Offen gehaltene Dateien
+// ============================================================================
+
+/// Vor dem Rechteabbau geöffnete Dateien
+static HELD: Mutex<Option<HashMap<PathBuf, File>>> = Mutex::new(None);
+
+/// Öffnet `path` jetzt; spätere [`read_string`]-Aufrufe lesen über dieses
+/// Handle, auch wenn ein neues `open()` ohne root scheitern würde
+pub fn hold(path: impl AsRef<Path>) -> io::Result<()> {
+    let file = File::open(path.as_ref())?;
+    HELD.lock().unwrap().get_or_insert_with(HashMap::new).insert(path.as_ref().to_path_buf(), file);
+    Ok(())
+}
+
+/// Alle lesbaren Dateien direkt in `dir` (keine Unterverzeichnisse); Anzahl
+pub fn hold_dir(dir: &Path) -> usize {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_file()))
+        .filter(|entry| hold(entry.path()).is_ok())
+        .count()
+}
+
+fn read_held(path: &Path) -> Option<io::Result<String>> {
+    let held = HELD.lock().unwrap();
+    Some(pread_all(held.as_ref()?.get(path)?))
+}
+
+// ============================================================================
+// Cache für häufige Abfragen
+// ============================================================================
+
+/// Dateien, die sich zur Laufzeit nicht ändern
+pub const STATIC_FILES: [&str; 5] =
+    ["gpu_available_frequencies", "num_pwrlevels", "gpu_model", "max_gpuclk", "thermal_pwrlevel"];
+
+/// Gültigkeit für [`STATIC_FILES`]
+pub const STATIC_TTL: Duration = Duration::from_secs(60);
+
+/// Zähler zum Nachweis der Einsparung
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Aus dem Cache beantwortet
+    pub hits: u64,
+    /// Tatsächliche Lesezugriffe (pread)
+    pub reads: u64,
+    /// open()-Aufrufe
+    pub opens: u64,
+}
+
+struct CacheEntry {
+    file: Option<File>,
+    value: Result<String, Option<i32>>,
+    read_at: Instant,
+}
+
+/// sysfs-Leser mit TTL je Datei und offen gehaltenen Dateien
+///
+/// Ein Exporter-Scrape liest sonst jede Datei mit open/read/close. Hier
+/// bleibt die Datei offen und wird mit `pread` ab Offset 0 neu gelesen (sysfs
+/// erzeugt den Inhalt dabei neu); innerhalb der TTL kommt der Wert ganz ohne
+/// Syscall aus dem Cache. `bench sysfs` misst den Unterschied auf dem Gerät.
+///
+/// Messwerte und Methode stehen im README unter "sysfs cache".
+pub struct CachedSysfs {
+    default_ttl: Duration,
+    ttls: Vec<(String, Duration)>,
+    entries: HashMap<PathBuf, CacheEntry>,
+    stats: CacheStats,
+}
+
+impl CachedSysfs {
+    /// `default_ttl` gilt für alle Dateien ohne eigene TTL
+    pub fn new(default_ttl: Duration) -> Self {
+        let ttls = STATIC_FILES.iter().map(|name| (name.to_string(), STATIC_TTL)).collect();
+        CachedSysfs { default_ttl, ttls, entries: HashMap::new(), stats: CacheStats::default() }
+    }
+
+    /// Eigene TTL für einen Dateinamen (z.B. "gpuclk")
+    pub fn with_ttl(mut self, file_name: &str, ttl: Duration) -> Self {
+        self.ttls.retain(|(name, _)| name != file_name);
+        self.ttls.push((file_name.to_string(), ttl));
+        self
+    }
+
+    fn ttl(&self, path: &Path) -> Duration {
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        self.ttls.iter().find(|(n, _)| n == name).map_or(self.default_ttl, |(_, ttl)| *ttl)
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Wie [`read_string`], aber gecacht
+    pub fn read_string(&mut self, path: impl AsRef<Path>) -> io::Result<String> {
+        self.refresh(&[path.as_ref()], Instant::now());
+        self.cached(path.as_ref())
+    }
+
+    /// Wie [`read_u64`], aber gecacht
+    pub fn read_u64(&mut self, path: impl AsRef<Path>) -> io::Result<u64> {
+        let text = self.read_string(path)?;
+        let first = text.split_whitespace().next().unwrap_or("");
+        first.parse().map_err(|_| io::Error::new(io::ErrorKind::InvalidData, format!("not a number: {:?}", text)))
+    }
+
+    /// Liest alle abgelaufenen Dateien in einem Durchgang mit gemeinsamem Zeitstempel
+    pub fn read_many(&mut self, paths: &[&Path]) -> Vec<io::Result<String>> {
+        self.refresh(paths, Instant::now());
+        paths.iter().map(|path| self.cached(path)).collect()
+    }
+
+    fn cached(&self, path: &Path) -> io::Result<String> {
+        match &self.entries[path].value {
+            Ok(text) => Ok(text.clone()),
+            Err(Some(errno)) => Err(io::Error::from_raw_os_error(*errno)),
+            Err(None) => Err(io::Error::other("read failed")),
+        }
+    }
+
+    fn refresh(&mut self, paths: &[&Path], now: Instant) {
+        for &path in paths {
+            let ttl = self.ttl(path);
+            let fresh = self.entries.get(path).is_some_and(|e| now.duration_since(e.read_at) < ttl);
+            if fresh {
+                self.stats.hits += 1;
+                continue;
+            }
+            let entry = self.entries.entry(path.to_path_buf()).or_insert(CacheEntry {
+                file: None,
+                value: Err(None),
+                read_at: now,
+            });
+            if entry.file.is_none() {
+                self.stats.opens += 1;
+                match File::open(path) {
+                    Ok(file) => entry.file = Some(file),
+                    Err(e) => {
+                        entry.value = Err(e.raw_os_error());
+                        entry.read_at = now;
+                        continue;
+                    }
+                }
+            }
+            self.stats.reads += 1;
+            let file = entry.file.as_ref().expect("opened above");
+            entry.value = pread_all(file).map(|text| text.trim().to_string()).map_err(|e| e.raw_os_error());
+            entry.read_at = now;
+            if entry.value.is_err() {
+                // Beim nächsten Mal neu öffnen (Knoten kann verschwunden sein)
+                entry.file = None;
+            }
+        }
+    }
+}
+
+/// Liest eine sysfs-Datei ab Offset 0 vollständig
+fn pread_all(file: &File) -> io::Result<String> {
+    let mut buf = vec![0u8; 4096];
+    let mut len = 0;
+    loop {
+        if len == buf.len() {
+            buf.resize(buf.len() * 2, 0);
+        }
+        match file.read_at(&mut buf[len..], len as u64)? {
+            0 => break,
+            n => len += n,
+        }
+    }
+    buf.truncate(len);
+    String::from_utf8(buf).map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "not UTF-8"))
+}
@@ -0,0 +1,51 @@
+//! Umgeleitetes Wurzelverzeichnis für `/dev`, `/sys`, `/proc` und die
+//! Android-Partitionen
+//!
+//! Mit `--sysroot DIR` oder `ADRENO_IOCTL_SYSROOT=DIR` liest das Tool einen
+//! gesammelten Verzeichnisbaum eines anderen Geräts statt des eigenen
+//! Systems - zum Nachstellen von Parser-Problemen aus Nutzer-Dumps. ioctls
+//! auf `dev/kgsl-*` im Baum schlagen fehl; alles aus sysfs, procfs, debugfs
+//! und Device Tree funktioniert, ebenso Firmware, Blobs, `build.prop` und
+//! `packages.list` unter `vendor/`, `system/` und `data/`. Eigene Dateien
+//! des Tools (Cache, Socket, Overlay) bleiben auf dem echten System. Live-Prozesse (`trace`, `fence`) bleiben
+//! auf dem echten `/proc`.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Umgebungsvariable als Alternative zu `--sysroot`
+pub const SYSROOT_ENV: &str = "ADRENO_IOCTL_SYSROOT";
+
+/// Umgeleitete Wurzeln (`/d` ist Androids debugfs-Link)
+const REDIRECTED: [&str; 10] =
+    ["/d", "/data", "/dev", "/lib/firmware", "/odm", "/proc", "/product", "/sys", "/system", "/vendor"];
+
+/// Explizit gesetzte Wurzel, Vorrang vor der Umgebung
+static SYSROOT: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Setzt die Wurzel (`None` schaltet auf die Umgebungsvariable zurück)
+pub fn set(root: Option<PathBuf>) {
+    *SYSROOT.lock().unwrap_or_else(|e| e.into_inner()) = root;
+}
+
+/// Aktive Wurzel, falls umgeleitet wird
+pub fn get() -> Option<PathBuf> {
+    let explicit = SYSROOT.lock().unwrap_or_else(|e| e.into_inner()).clone();
+    explicit.or_else(|| std::env::var_os(SYSROOT_ENV).filter(|v| !v.is_empty()).map(PathBuf::from))
+}
+
+/// Ob gerade ein fremder Baum gelesen wird
+pub fn active() -> bool {
+    get().is_some()
+}
+
+/// Setzt die Wurzel vor absolute Pfade unter [`REDIRECTED`]
+pub fn resolve(path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    match get() {
+        Some(root) if REDIRECTED.iter().any(|r| path.starts_with(r)) => {
+            root.join(path.strip_prefix("/").unwrap_or(path))
+        }
+        _ => path.to_path_buf(),
+    }
+}
@@ -0,0 +1,59 @@
+//! Ausgabevorlagen wie `--format '{model} ({chip_id})'`
+
+use crate::messages::Msg;
+
+/// Zerlegt eine Vorlage in Text und Platzhalter (`{{`/`}}` für Klammern)
+///
+/// Liefert `(true, name)` für Platzhalter und `(false, text)` für Text dazwischen;
+/// Platzhalter außerhalb von `names` sind ein Fehler.
+pub fn parse(template: &str, names: &[&str]) -> Result<Vec<(bool, String)>, String> {
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                text.push('}');
+            }
+            '{' => {
+                let mut key = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => key.push(c),
+                        None => return Err(Msg::GetUnclosed { key: &key }.to_string()),
+                    }
+                }
+                if !names.contains(&key.as_str()) {
+                    return Err(Msg::GetUnknownPlaceholder { key: &key, names: &names.join(", ") }.to_string());
+                }
+                parts.push((false, std::mem::take(&mut text)));
+                parts.push((true, key));
+            }
+            '}' => return Err(Msg::GetUnmatchedBrace.to_string()),
+            c => text.push(c),
+        }
+    }
+    parts.push((false, text));
+    Ok(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parts_and_escapes() {
+        let parts = parse("{a}{{b}}{a}", &["a"]).unwrap();
+        let expected = [(false, ""), (true, "a"), (false, "{b}"), (true, "a"), (false, "")];
+        assert_eq!(parts, expected.map(|(key, text)| (key, text.to_string())));
+        assert!(parse("{b}", &["a"]).is_err());
+        assert!(parse("{a", &["a"]).is_err());
+        assert!(parse("}", &[]).is_err());
+    }
+}
@@ -0,0 +1,186 @@
+//! KGSL Timelines und Hardware-Fences (A7xx)
+//!
+//! Timelines sind die Userspace-Sicht auf Fences. Auf A7xx-Kernels mit
+//! Hardware-Fence Unterstützung werden sie vom GMU/CP statt der CPU signalisiert.
+
+use std::io;
+use std::mem::size_of;
+use std::os::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd};
+use std::time::Duration;
+
+use zerocopy::{FromBytes, Immutable, IntoBytes};
+
+use crate::dmesg;
+use crate::driver::MODULE_DIR;
+use crate::kgsl::{kgsl_iow, kgsl_iowr, probe_ioctl};
+use crate::sys::{self, ioctl, KgslRequest};
+use crate::sysroot;
+
+/// `struct kgsl_timeline_create`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslTimelineCreate {
+    pub seqno: u64,
+    pub id: u32,
+    pub padding: u32,
+}
+
+/// `struct kgsl_timeline_val`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslTimelineVal {
+    pub seqno: u64,
+    pub timeline: u32,
+    pub padding: u32,
+}
+
+/// `struct kgsl_timeline_wait`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslTimelineWait {
+    pub tv_sec: i64,
+    pub tv_nsec: i64,
+    pub timelines: u64,
+    pub count: u32,
+    pub timelines_size: u32,
+    pub flags: u32,
+    pub padding: u32,
+}
+
+/// `struct kgsl_timeline_signal`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslTimelineSignal {
+    pub timelines: u64,
+    pub count: u32,
+    pub timelines_size: u32,
+}
+
+/// `struct kgsl_timeline_fence_get`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub struct KgslTimelineFenceGet {
+    pub seqno: u64,
+    pub timeline: u32,
+    pub handle: i32,
+}
+
+pub const IOCTL_KGSL_TIMELINE_CREATE: u32 = kgsl_iowr(0x58, size_of::<KgslTimelineCreate>());
+pub const IOCTL_KGSL_TIMELINE_WAIT: u32 = kgsl_iow(0x59, size_of::<KgslTimelineWait>());
+pub const IOCTL_KGSL_TIMELINE_QUERY: u32 = kgsl_iowr(0x5A, size_of::<KgslTimelineVal>());
+pub const IOCTL_KGSL_TIMELINE_SIGNAL: u32 = kgsl_iow(0x5B, size_of::<KgslTimelineSignal>());
+pub const IOCTL_KGSL_TIMELINE_FENCE_GET: u32 = kgsl_iowr(0x5C, size_of::<KgslTimelineFenceGet>());
+pub const IOCTL_KGSL_TIMELINE_DESTROY: u32 = kgsl_iow(0x5D, size_of::<u32>());
+
+/// Warten auf alle (statt eine) Timelines
+pub const KGSL_TIMELINE_WAIT_ALL: u32 = 1;
+
+/// Eine KGSL Timeline, wird beim Drop zerstört
+pub struct Timeline<'a> {
+    fd: BorrowedFd<'a>,
+    pub id: u32,
+}
+
+impl<'a> Timeline<'a> {
+    pub fn create(dev: &'a impl AsFd, initial: u64) -> io::Result<Self> {
+        let fd = dev.as_fd();
+        let mut req = KgslTimelineCreate { seqno: initial, ..Default::default() };
+        ioctl(fd.as_raw_fd(), IOCTL_KGSL_TIMELINE_CREATE, &mut req)?;
+        Ok(Timeline { fd, id: req.id })
+    }
+
+    /// Aktueller Wert
+    pub fn query(&self) -> io::Result<u64> {
+        let mut req = KgslTimelineVal { timeline: self.id, ..Default::default() };
+        ioctl(self.fd.as_raw_fd(), IOCTL_KGSL_TIMELINE_QUERY, &mut req)?;
+        Ok(req.seqno)
+    }
+
+    /// Setzt die Timeline von der CPU aus auf `seqno`
+    pub fn signal(&self, seqno: u64) -> io::Result<()> {
+        let mut val = KgslTimelineVal { seqno, timeline: self.id, padding: 0 };
+        let mut req = KgslTimelineSignal {
+            timelines: &mut val as *mut KgslTimelineVal as u64,
+            count: 1,
+            timelines_size: size_of::<KgslTimelineVal>() as u32,
+        };
+        ioctl(self.fd.as_raw_fd(), IOCTL_KGSL_TIMELINE_SIGNAL, &mut req)
+    }
+
+    /// Wartet bis mindestens `seqno` erreicht ist, `ETIMEDOUT` nach `timeout`
+    pub fn wait(&self, seqno: u64, timeout: Duration) -> io::Result<()> {
+        let mut val = KgslTimelineVal { seqno, timeline: self.id, padding: 0 };
+        let mut req = KgslTimelineWait {
+            tv_sec: timeout.as_secs() as i64,
+            tv_nsec: timeout.subsec_nanos() as i64,
+            timelines: &mut val as *mut KgslTimelineVal as u64,
+            count: 1,
+            timelines_size: size_of::<KgslTimelineVal>() as u32,
+            flags: KGSL_TIMELINE_WAIT_ALL,
+            padding: 0,
+        };
+        ioctl(self.fd.as_raw_fd(), IOCTL_KGSL_TIMELINE_WAIT, &mut req)
+    }
+
+    /// sync_file, der bei `seqno` signalisiert
+    pub fn fence(&self, seqno: u64) -> io::Result<OwnedFd> {
+        let mut req = KgslTimelineFenceGet { seqno, timeline: self.id, handle: -1 };
+        ioctl(self.fd.as_raw_fd(), IOCTL_KGSL_TIMELINE_FENCE_GET, &mut req)?;
+        sys::owned_fd(req.handle)
+    }
+}
+
+impl Drop for Timeline<'_> {
+    fn drop(&mut self) {
+        let mut id = self.id;
+        let _ = ioctl(self.fd.as_raw_fd(), IOCTL_KGSL_TIMELINE_DESTROY, &mut id);
+    }
+}
+
+// ============================================================================
+// Hardware-Fence Erkennung
+// ============================================================================
+
+/// Kernel-Module, die Hardware-Fences bereitstellen
+const HW_FENCE_MODULES: [&str; 2] = ["msm_hw_fence", "synx_driver"];
+
+/// Log-Muster des KGSL Hardware-Fence Pfads
+const HW_FENCE_LOG_PATTERNS: [&str; 3] = ["hw fence", "hw_fence", "hwfence"];
+
+/// Ergebnis der Hardware-Fence Erkennung
+#[derive(Debug, Clone, Default)]
+pub struct HwFenceSupport {
+    /// A7xx oder neuer
+    pub hardware: bool,
+    /// Timeline-IOCTLs vorhanden
+    pub timeline_ioctls: bool,
+    /// Geladenes Hardware-Fence Modul
+    pub kernel_module: Option<String>,
+    /// Passende dmesg-Zeilen
+    pub log_lines: Vec<String>,
+}
+
+impl HwFenceSupport {
+    /// Hardware-Fences sehr wahrscheinlich aktiv
+    pub fn likely_enabled(&self) -> bool {
+        self.hardware && self.timeline_ioctls && (self.kernel_module.is_some() || !self.log_lines.is_empty())
+    }
+}
+
+/// Erkennt Hardware-Fence Unterstützung für `generation`
+pub fn detect_hw_fences(fd: i32, generation: u8) -> HwFenceSupport {
+    let log = dmesg::read_kernel_log().unwrap_or_default();
+    HwFenceSupport {
+        hardware: generation >= 7,
+        timeline_ioctls: probe_ioctl(fd, const { KgslRequest::new(IOCTL_KGSL_TIMELINE_QUERY) }).is_implemented(),
+        kernel_module: HW_FENCE_MODULES
+            .iter()
+            .find(|m| sysroot::resolve(MODULE_DIR).join(m).exists())
+            .map(|m| m.to_string()),
+        log_lines: dmesg::grep(&log, &HW_FENCE_LOG_PATTERNS)
+            .into_iter()
+            .filter(|l| l.to_ascii_lowercase().contains("kgsl") || l.to_ascii_lowercase().contains("adreno"))
+            .map(str::to_string)
+            .collect(),
+    }
+}
@@ -0,0 +1,210 @@
+//! GPU-Ticks ↔ CLOCK_MONOTONIC
+//!
+//! Ab A6xx zählen die GPU-Zeitstempel (Always-On-Zähler, CP-Events) im
+//! Takt des SoC-QTimers, nominal 19.2 MHz. `KGSL_PROP_DEVICE_QTIMER`
+//! meldet, dass der Treiber den QTimer in den GPU-Adressraum gemappt hat;
+//! die CPU liest denselben Zähler über `CNTVCT_EL0`. [`TimestampConverter`]
+//! kalibriert aus vielen Messpaaren (CLOCK_MONOTONIC, Ticks): Referenzpunkt
+//! ist das Paar mit dem engsten Messfenster, die Rate kommt aus einer
+//! Regression über die bessere Hälfte der Paare.
+
+use std::io;
+use std::thread;
+use std::time::Duration;
+
+use zerocopy::{FromBytes, Immutable, IntoBytes};
+
+use crate::fence::monotonic_now;
+use crate::kgsl;
+use crate::propmap::Prop;
+
+/// Nominale QTimer-Frequenz aller Snapdragon-SoCs
+pub const QTIMER_HZ: u64 = 19_200_000;
+
+/// `struct kgsl_qtimer_prop`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub(crate) struct KgslQtimerProp {
+    pub(crate) gpuaddr: u64,
+    pub(crate) size: u32,
+    pub(crate) _pad: u32,
+}
+
+/// QTimer-Register im GPU-Adressraum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QtimerInfo {
+    pub gpuaddr: u64,
+    pub size: u32,
+}
+
+/// `KGSL_PROP_DEVICE_QTIMER`; fehlt vor A6xx
+pub fn read_qtimer(fd: i32) -> io::Result<QtimerInfo> {
+    let prop: KgslQtimerProp = kgsl::read_property(fd, Prop::DeviceQtimer)?;
+    if prop.size == 0 {
+        return Err(io::Error::new(io::ErrorKind::Unsupported, "driver maps no QTimer for the GPU"));
+    }
+    Ok(QtimerInfo { gpuaddr: prop.gpuaddr, size: prop.size })
+}
+
+/// QTimer aus Sicht der CPU (`CNTVCT_EL0`), nur auf aarch64
+#[cfg(target_arch = "aarch64")]
+pub fn read_cpu_qtimer() -> Option<u64> {
+    Some(crate::sys::cntvct())
+}
+
+#[cfg(not(target_arch = "aarch64"))]
+pub fn read_cpu_qtimer() -> Option<u64> {
+    None
+}
+
+/// Frequenz laut `CNTFRQ_EL0`, sonst [`QTIMER_HZ`]
+#[cfg(target_arch = "aarch64")]
+pub fn qtimer_hz() -> u64 {
+    let hz = crate::sys::cntfrq();
+    if hz == 0 { QTIMER_HZ } else { hz }
+}
+
+#[cfg(not(target_arch = "aarch64"))]
+pub fn qtimer_hz() -> u64 {
+    QTIMER_HZ
+}
+
+fn monotonic_ns() -> u64 {
+    monotonic_now().as_nanos() as u64
+}
+
+/// Ein Messpaar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncSample {
+    /// Mitte des Messfensters
+    pub monotonic_ns: u64,
+    pub ticks: u64,
+    /// Abstand der beiden Uhrzeit-Lesungen um den Zählerzugriff
+    pub window_ns: u64,
+}
+
+/// Liest die Uhr vor und nach `read_ticks`
+pub fn take_sample(read_ticks: &mut impl FnMut() -> io::Result<u64>) -> io::Result<SyncSample> {
+    let before = monotonic_ns();
+    let ticks = read_ticks()?;
+    let after = monotonic_ns();
+    let window_ns = after.saturating_sub(before);
+    Ok(SyncSample { monotonic_ns: before + window_ns / 2, ticks, window_ns })
+}
+
+/// Lineare Abbildung zwischen GPU-Ticks und CLOCK_MONOTONIC
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimestampConverter {
+    ref_ticks: u64,
+    ref_ns: u64,
+    ns_per_tick: f64,
+    nominal_hz: u64,
+    uncertainty_ns: u64,
+}
+
+impl TimestampConverter {
+    /// Feste Abbildung über einen Referenzpunkt mit nominaler Frequenz
+    pub fn new(ref_ticks: u64, ref_ns: u64, nominal_hz: u64) -> Self {
+        TimestampConverter {
+            ref_ticks,
+            ref_ns,
+            ns_per_tick: 1e9 / nominal_hz as f64,
+            nominal_hz,
+            uncertainty_ns: 0,
+        }
+    }
+
+    /// Kalibrierung aus Messpaaren; `None` ohne brauchbares Paar.
+    /// Liegen die Paare zu dicht für eine Regression, gilt `nominal_hz`.
+    pub fn from_samples(samples: &[SyncSample], nominal_hz: u64) -> Option<Self> {
+        let mut best: Vec<SyncSample> = samples.to_vec();
+        best.sort_by_key(|s| s.window_ns);
+        best.truncate(best.len().div_ceil(2).max(2.min(best.len())));
+        let reference = *best.first()?;
+
+        let mut converter = TimestampConverter::new(reference.ticks, reference.monotonic_ns, nominal_hz);
+        converter.uncertainty_ns = reference.window_ns.div_ceil(2);
+
+        // Regression relativ zum Referenzpunkt, damit f64 genau bleibt
+        let points: Vec<(f64, f64)> = best
+            .iter()
+            .map(|s| {
+                (
+                    s.ticks.wrapping_sub(reference.ticks) as i64 as f64,
+                    s.monotonic_ns as f64 - reference.monotonic_ns as f64,
+                )
+            })
+            .collect();
+        let n = points.len() as f64;
+        let (mean_x, mean_y) = points.iter().fold((0.0, 0.0), |(x, y), p| (x + p.0 / n, y + p.1 / n));
+        let sxx: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+        let sxy: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+        // Unter einer Millisekunde Spannweite schwankt die Steigung stärker als jede echte Drift
+        let span_ticks = nominal_hz as f64 / 1000.0;
+        if sxx > 0.0 && points.iter().any(|p| p.0.abs() >= span_ticks) {
+            let slope = sxy / sxx;
+            if slope.is_finite() && slope > 0.0 {
+                converter.ns_per_tick = slope;
+            }
+        }
+        Some(converter)
+    }
+
+    /// `count` Messpaare im Abstand `spacing` über `read_ticks`
+    pub fn calibrate(
+        mut read_ticks: impl FnMut() -> io::Result<u64>,
+        nominal_hz: u64,
+        count: usize,
+        spacing: Duration,
+    ) -> io::Result<Self> {
+        let mut samples = Vec::with_capacity(count);
+        for i in 0..count {
+            if i > 0 {
+                thread::sleep(spacing);
+            }
+            samples.push(take_sample(&mut read_ticks)?);
+        }
+        Self::from_samples(&samples, nominal_hz)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no calibration samples"))
+    }
+
+    /// Kalibrierung gegen den QTimer der CPU
+    pub fn from_qtimer(count: usize, spacing: Duration) -> io::Result<Self> {
+        if read_cpu_qtimer().is_none() {
+            return Err(io::Error::new(io::ErrorKind::Unsupported, "the QTimer is only readable on aarch64"));
+        }
+        let read = || read_cpu_qtimer().ok_or_else(|| io::Error::from(io::ErrorKind::Unsupported));
+        Self::calibrate(read, qtimer_hz(), count, spacing)
+    }
+
+    /// GPU-Ticks als CLOCK_MONOTONIC in ns
+    pub fn to_monotonic_ns(&self, ticks: u64) -> u64 {
+        let delta = ticks.wrapping_sub(self.ref_ticks) as i64 as f64 * self.ns_per_tick;
+        (self.ref_ns as f64 + delta).max(0.0).round() as u64
+    }
+
+    /// CLOCK_MONOTONIC in ns als GPU-Ticks
+    pub fn to_ticks(&self, monotonic_ns: u64) -> u64 {
+        let delta = (monotonic_ns as f64 - self.ref_ns as f64) / self.ns_per_tick;
+        self.ref_ticks.wrapping_add(delta.round() as i64 as u64)
+    }
+
+    /// Gemessene Frequenz in Hz
+    pub fn frequency_hz(&self) -> f64 {
+        1e9 / self.ns_per_tick
+    }
+
+    pub fn nominal_hz(&self) -> u64 {
+        self.nominal_hz
+    }
+
+    /// Abweichung der gemessenen von der nominalen Frequenz
+    pub fn drift_ppm(&self) -> f64 {
+        (self.frequency_hz() / self.nominal_hz as f64 - 1.0) * 1e6
+    }
+
+    /// Unsicherheit des Referenzpunkts (halbes Messfenster)
+    pub fn uncertainty_ns(&self) -> u64 {
+        self.uncertainty_ns
+    }
+}
@@ -0,0 +1,364 @@
+//! KGSL-Aufrufe fremder Prozesse mitlesen (ptrace, benötigt root)
+//!
+//! Alle Threads des Zielprozesses werden mit `PTRACE_SEIZE` angehängt, neue
+//! Threads über `PTRACE_O_TRACECLONE` automatisch mitverfolgt. Bei jedem
+//! `ioctl` auf ein `/dev/kgsl*` fd wird am Syscall-Ende die Argument-Struktur
+//! aus dem Zielprozess gelesen und dekodiert.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::time::Instant;
+
+use zerocopy::{FromBytes, FromZeros, Immutable, IntoBytes};
+
+use crate::ioctls::IoctlRequest;
+use crate::kgsl::{
+    IOCTL_KGSL_DEVICE_GETPROPERTY, IOCTL_KGSL_DRAWCTXT_CREATE, IOCTL_KGSL_DRAWCTXT_DESTROY, IOCTL_KGSL_SETPROPERTY,
+    KGSL_IOC_TYPE, KgslDrawctxtCreate, KgslDrawctxtDestroy,
+};
+use crate::memory::{IOCTL_KGSL_GPUOBJ_ALLOC, IOCTL_KGSL_GPUOBJ_FREE, KgslGpuobjAlloc, KgslGpuobjFree};
+use crate::submit::{IOCTL_KGSL_GPU_COMMAND, KgslGpuCommand};
+use crate::sys::{self, ptrace, Pod};
+
+/// `PTRACE_GETREGSET` Typ für die allgemeinen Register
+const NT_PRSTATUS: libc::c_int = 1;
+
+#[cfg(target_arch = "aarch64")]
+const SYS_IOCTL: u64 = 29;
+#[cfg(target_arch = "x86_64")]
+const SYS_IOCTL: u64 = 16;
+
+/// Register in der Reihenfolge von `struct user_pt_regs`
+#[cfg(target_arch = "aarch64")]
+#[repr(C)]
+#[derive(Clone, Copy, FromBytes, IntoBytes, Immutable)]
+pub(crate) struct Regs {
+    regs: [u64; 31],
+    sp: u64,
+    pc: u64,
+    pstate: u64,
+}
+
+#[cfg(target_arch = "aarch64")]
+impl Regs {
+    fn syscall(&self) -> u64 {
+        self.regs[8]
+    }
+    fn args(&self) -> [u64; 3] {
+        [self.regs[0], self.regs[1], self.regs[2]]
+    }
+    fn result(&self) -> i64 {
+        self.regs[0] as i64
+    }
+}
+
+/// `struct user_regs_struct`: r15 bis gs, 27 Register
+#[cfg(target_arch = "x86_64")]
+#[repr(C)]
+#[derive(Clone, Copy, FromBytes, IntoBytes, Immutable)]
+pub(crate) struct Regs {
+    regs: [u64; 27],
+}
+
+#[cfg(target_arch = "x86_64")]
+impl Regs {
+    /// `orig_rax`
+    fn syscall(&self) -> u64 {
+        self.regs[15]
+    }
+    /// `rdi`, `rsi`, `rdx`
+    fn args(&self) -> [u64; 3] {
+        [self.regs[14], self.regs[13], self.regs[12]]
+    }
+    /// `rax`
+    fn result(&self) -> i64 {
+        self.regs[10] as i64
+    }
+}
+
+/// Dekodierter KGSL-Aufruf
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KgslCall {
+    /// `value` enthält die Antwort des Kernels (leer bei Fehler)
+    GetProperty { prop: u32, size: u32, value: Vec<u8> },
+    SetProperty { prop: u32, size: u32 },
+    GpuobjAlloc { size: u64, flags: u64, id: u32, mmapsize: u64 },
+    GpuobjFree { id: u32 },
+    GpuCommand { context_id: u32, numcmds: u32, numobjs: u32, timestamp: u32 },
+    DrawctxtCreate { flags: u32, context_id: u32 },
+    DrawctxtDestroy { context_id: u32 },
+    /// Bekannter Typ 0x09, aber (noch) nicht dekodiert
+    Other,
+}
+
+/// Ein abgeschlossener KGSL-ioctl im Zielprozess
+#[derive(Debug, Clone)]
+pub struct TraceEvent {
+    pub time: Instant,
+    pub tid: i32,
+    pub fd: i32,
+    pub request: u32,
+    /// Rückgabewert des Syscalls (negativ = `-errno`)
+    pub result: i64,
+    pub call: KgslCall,
+}
+
+impl TraceEvent {
+    pub fn succeeded(&self) -> bool {
+        self.result >= 0
+    }
+}
+
+/// Zustand eines verfolgten Threads
+#[derive(Default)]
+struct ThreadState {
+    /// Zwischen Syscall-Eintritt und -Austritt: die gesicherten Argumente
+    in_syscall: Option<(u64, [u64; 3])>,
+}
+
+/// Angehängter Tracer, löst sich beim Drop vom Zielprozess
+pub struct Tracer {
+    pid: i32,
+    threads: HashMap<i32, ThreadState>,
+    /// fd → ist KGSL (Cache, wird bei close/dup nicht invalidiert)
+    kgsl_fds: HashMap<i32, bool>,
+}
+
+impl Tracer {
+    /// Hängt sich an alle Threads von `pid` an
+    pub fn attach(pid: i32) -> io::Result<Self> {
+        let options = libc::PTRACE_O_TRACESYSGOOD | libc::PTRACE_O_TRACECLONE;
+        let mut tracer = Tracer { pid, threads: HashMap::new(), kgsl_fds: HashMap::new() };
+
+        let mut tids: Vec<i32> = fs::read_dir(format!("/proc/{}/task", pid))?
+            .flatten()
+            .filter_map(|e| e.file_name().to_str()?.parse().ok())
+            .collect();
+        tids.sort();
+        for tid in tids {
+            match ptrace(libc::PTRACE_SEIZE, tid, 0, options as usize) {
+                Ok(_) => {}
+                // Thread ist inzwischen beendet
+                Err(e) if e.raw_os_error() == Some(libc::ESRCH) => continue,
+                Err(e) => return Err(e),
+            }
+            tracer.threads.insert(tid, ThreadState::default());
+            ptrace(libc::PTRACE_INTERRUPT, tid, 0, 0)?;
+        }
+        if tracer.threads.is_empty() {
+            return Err(io::Error::from_raw_os_error(libc::ESRCH));
+        }
+        Ok(tracer)
+    }
+
+    pub fn pid(&self) -> i32 {
+        self.pid
+    }
+
+    /// Anzahl der verfolgten Threads
+    pub fn thread_count(&self) -> usize {
+        self.threads.len()
+    }
+
+    /// Wartet auf den nächsten KGSL-Aufruf
+    ///
+    /// `Ok(None)`, wenn der Prozess beendet ist oder das Warten durch ein
+    /// Signal unterbrochen wurde (siehe [`Tracer::is_alive`]).
+    pub fn next_event(&mut self) -> io::Result<Option<TraceEvent>> {
+        loop {
+            if self.threads.is_empty() {
+                return Ok(None);
+            }
+            let (tid, status) = match sys::waitpid(-1, libc::__WALL) {
+                Ok(result) => result,
+                Err(err) => {
+                    return match err.raw_os_error() {
+                        Some(libc::EINTR) => Ok(None),
+                        Some(libc::ECHILD) => {
+                            self.threads.clear();
+                            Ok(None)
+                        }
+                        _ => Err(err),
+                    };
+                }
+            };
+
+            if libc::WIFEXITED(status) || libc::WIFSIGNALED(status) {
+                self.threads.remove(&tid);
+                continue;
+            }
+            if !libc::WIFSTOPPED(status) {
+                continue;
+            }
+            // Neue Threads können ihren ersten Stop vor dem Clone-Event melden
+            self.threads.entry(tid).or_default();
+
+            let sig = libc::WSTOPSIG(status);
+            let event = status >> 16;
+            if sig == libc::SIGTRAP | 0x80 {
+                let found = self.on_syscall_stop(tid);
+                self.resume(tid, 0);
+                match found {
+                    Some(ev) => return Ok(Some(ev)),
+                    None => continue,
+                }
+            }
+            if event == libc::PTRACE_EVENT_STOP {
+                if matches!(sig, libc::SIGSTOP | libc::SIGTSTP | libc::SIGTTIN | libc::SIGTTOU) {
+                    // Group-Stop: angehalten lassen, bis SIGCONT kommt
+                    let _ = ptrace(libc::PTRACE_LISTEN, tid, 0, 0);
+                } else {
+                    self.resume(tid, 0);
+                }
+                continue;
+            }
+            if event != 0 {
+                self.resume(tid, 0);
+                continue;
+            }
+            // Normales Signal an den Zielprozess weiterreichen
+            self.resume(tid, sig);
+        }
+    }
+
+    /// Ob noch mindestens ein Thread verfolgt wird
+    pub fn is_alive(&self) -> bool {
+        !self.threads.is_empty()
+    }
+
+    fn resume(&mut self, tid: i32, sig: i32) {
+        if ptrace(libc::PTRACE_SYSCALL, tid, 0, sig as usize).is_err() {
+            self.threads.remove(&tid);
+        }
+    }
+
+    fn on_syscall_stop(&mut self, tid: i32) -> Option<TraceEvent> {
+        let regs = read_regs(tid).ok()?;
+        let state = self.threads.get_mut(&tid)?;
+        match state.in_syscall.take() {
+            None => {
+                state.in_syscall = Some((regs.syscall(), regs.args()));
+                None
+            }
+            Some((nr, [fd, request, arg])) => {
+                if nr != SYS_IOCTL {
+                    return None;
+                }
+                let (fd, request) = (fd as i32, request as u32);
+                if IoctlRequest::decode(request).type_ as u32 != KGSL_IOC_TYPE || !self.is_kgsl_fd(fd) {
+                    return None;
+                }
+                Some(TraceEvent {
+                    time: Instant::now(),
+                    tid,
+                    fd,
+                    request,
+                    result: regs.result(),
+                    call: decode_call(tid, request, arg as usize, regs.result() >= 0),
+                })
+            }
+        }
+    }
+
+    fn is_kgsl_fd(&mut self, fd: i32) -> bool {
+        let pid = self.pid;
+        *self.kgsl_fds.entry(fd).or_insert_with(|| {
+            fs::read_link(format!("/proc/{}/fd/{}", pid, fd))
+                .map(|target| target.to_string_lossy().starts_with("/dev/kgsl"))
+                .unwrap_or(false)
+        })
+    }
+}
+
+impl Drop for Tracer {
+    fn drop(&mut self) {
+        // Detach geht nur im Stop-Zustand
+        for &tid in self.threads.keys() {
+            if ptrace(libc::PTRACE_INTERRUPT, tid, 0, 0).is_err() {
+                continue;
+            }
+            let mut sig = 0;
+            if let Ok((stopped, status)) = sys::waitpid(tid, libc::__WALL)
+                && stopped == tid
+                && libc::WIFSTOPPED(status)
+                && status >> 16 == 0
+                && libc::WSTOPSIG(status) != libc::SIGTRAP | 0x80
+            {
+                sig = libc::WSTOPSIG(status);
+            }
+            let _ = ptrace(libc::PTRACE_DETACH, tid, 0, sig as usize);
+        }
+    }
+}
+
+// ============================================================================
+// Lesen aus dem Zielprozess
+// ============================================================================
+
+fn read_regs(tid: i32) -> io::Result<Regs> {
+    let mut regs = Regs::new_zeroed();
+    sys::ptrace_getregset(tid, NT_PRSTATUS, &mut regs)?;
+    Ok(regs)
+}
+
+/// Liest eine Struktur aus dem Adressraum eines Threads
+fn read_remote<T: Pod>(tid: i32, addr: usize) -> Option<T> {
+    let mut value = T::new_zeroed();
+    sys::process_vm_read(tid, addr, value.as_mut_bytes()).then_some(value)
+}
+
+/// Obergrenze für mitgelesene Property-Antworten
+const MAX_PROPERTY_BYTES: usize = 4096;
+
+/// `struct kgsl_device_getproperty` mit Zeiger als Zahl
+#[repr(C)]
+#[derive(Clone, Copy, Default, FromBytes, IntoBytes, Immutable)]
+pub(crate) struct RemoteGetProperty {
+    type_: u32,
+    #[cfg(target_pointer_width = "64")]
+    _pad0: u32,
+    value: usize,
+    sizebytes: u32,
+    #[cfg(target_pointer_width = "64")]
+    _pad1: u32,
+}
+
+/// Liest bis zu `len` Bytes aus dem Zielprozess
+fn read_remote_bytes(tid: i32, addr: usize, len: usize) -> Option<Vec<u8>> {
+    let mut buf = vec![0u8; len];
+    sys::process_vm_read(tid, addr, &mut buf).then_some(buf)
+}
+
+fn decode_call(tid: i32, request: u32, arg: usize, succeeded: bool) -> KgslCall {
+    let decoded = match request {
+        IOCTL_KGSL_DEVICE_GETPROPERTY => read_remote::<RemoteGetProperty>(tid, arg).map(|p| {
+            let value = match succeeded && p.sizebytes as usize <= MAX_PROPERTY_BYTES {
+                true => read_remote_bytes(tid, p.value, p.sizebytes as usize).unwrap_or_default(),
+                false => Vec::new(),
+            };
+            KgslCall::GetProperty { prop: p.type_, size: p.sizebytes, value }
+        }),
+        IOCTL_KGSL_SETPROPERTY => read_remote::<RemoteGetProperty>(tid, arg)
+            .map(|p| KgslCall::SetProperty { prop: p.type_, size: p.sizebytes }),
+        IOCTL_KGSL_GPUOBJ_ALLOC => read_remote::<KgslGpuobjAlloc>(tid, arg).map(|a| KgslCall::GpuobjAlloc {
+            size: a.size,
+            flags: a.flags,
+            id: a.id,
+            mmapsize: a.mmapsize,
+        }),
+        IOCTL_KGSL_GPUOBJ_FREE => read_remote::<KgslGpuobjFree>(tid, arg).map(|f| KgslCall::GpuobjFree { id: f.id }),
+        IOCTL_KGSL_GPU_COMMAND => read_remote::<KgslGpuCommand>(tid, arg).map(|c| KgslCall::GpuCommand {
+            context_id: c.context_id,
+            numcmds: c.numcmds,
+            numobjs: c.numobjs,
+            timestamp: c.timestamp,
+        }),
+        IOCTL_KGSL_DRAWCTXT_CREATE => read_remote::<KgslDrawctxtCreate>(tid, arg)
+            .map(|c| KgslCall::DrawctxtCreate { flags: c.flags, context_id: c.drawctxt_id }),
+        IOCTL_KGSL_DRAWCTXT_DESTROY => read_remote::<KgslDrawctxtDestroy>(tid, arg)
+            .map(|c| KgslCall::DrawctxtDestroy { context_id: c.drawctxt_id }),
+        _ => None,
+    };
+    decoded.unwrap_or(KgslCall::Other)
+}
@@ -0,0 +1,342 @@
+//! Hang-Triage: welcher Prozess hat die GPU zum Absturz gebracht?
+//!
+//! Drei Quellen werden zusammengeführt:
+//! - Kernel-Log: KGSL nennt bei Faults Prozess, PID, Context und Timestamp
+//! - GPU-Snapshot (`<sysfs>/snapshot/dump`): die OS-Section enthält PID,
+//!   Prozessname und aktuellen Context zum Zeitpunkt des Fehlers
+//! - Context-Liste aus debugfs: Besitzer und Submission-Stand je Context
+//!
+//! Stimmen mehrere Quellen überein, ist der Verdächtige ziemlich sicher.
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+use crate::debugfs::ContextInfo;
+use crate::sysfs::read_u64;
+
+/// Muster für Fault-Zeilen (ohne Groß/Klein)
+pub const FAULT_LINE_PATTERNS: [&str; 5] = ["gpu fault", "gpu hang", "hang detected", "gpu page fault", "gpu recovery"];
+
+/// Art einer Fault-Meldung
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaultKind {
+    Hang,
+    PageFault,
+    Fault,
+    Recovery,
+}
+
+impl FaultKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            FaultKind::Hang => "hang",
+            FaultKind::PageFault => "page fault",
+            FaultKind::Fault => "fault",
+            FaultKind::Recovery => "recovery",
+        }
+    }
+}
+
+/// Eine Fault-Zeile aus dem Kernel-Log
+#[derive(Debug, Clone, PartialEq)]
+pub struct FaultRecord {
+    pub kind: FaultKind,
+    /// Kernel-Zeit in Sekunden (`[  123.456789]`)
+    pub time: Option<f64>,
+    pub process: Option<String>,
+    pub pid: Option<u32>,
+    pub context: Option<u32>,
+    pub timestamp: Option<u32>,
+    pub line: String,
+}
+
+/// Zahl nach `key`, Leerzeichen und `=` werden übersprungen, `0x` ist Hex
+fn number_after(text: &str, key: &str) -> Option<u32> {
+    let start = text.find(key)? + key.len();
+    let rest = text[start..].trim_start_matches([' ', '=', ':']);
+    if let Some(hex) = rest.strip_prefix("0x") {
+        let end = hex.find(|c: char| !c.is_ascii_hexdigit()).unwrap_or(hex.len());
+        return u32::from_str_radix(&hex[..end], 16).ok();
+    }
+    let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    rest[..end].parse().ok()
+}
+
+/// Wort nach `key`, ohne Anführungszeichen
+fn word_after(text: &str, key: &str) -> Option<String> {
+    let start = text.find(key)? + key.len();
+    let word = text[start..].trim_start_matches([' ', '=', ':']).split_whitespace().next()?;
+    let word = word.trim_matches(|c| c == '\'' || c == '"' || c == ',');
+    (!word.is_empty()).then(|| word.to_string())
+}
+
+/// `name[1234]` oder `'name'[1234]:` wie in KGSL-Fault-Meldungen
+fn process_with_pid(line: &str) -> Option<(String, u32)> {
+    line.split_whitespace().find_map(|token| {
+        let token = token.trim_end_matches(':');
+        let (name, pid) = token.strip_suffix(']')?.rsplit_once('[')?;
+        let name = name.trim_matches(|c| c == '\'' || c == '"');
+        if name.is_empty() || name.starts_with('<') {
+            return None;
+        }
+        Some((name.to_string(), pid.parse().ok()?))
+    })
+}
+
+/// Parst eine Zeile; `None`, wenn sie kein GPU-Fault ist
+pub fn parse_fault_line(line: &str) -> Option<FaultRecord> {
+    let lower = line.to_ascii_lowercase();
+    if !FAULT_LINE_PATTERNS.iter().any(|p| lower.contains(p)) {
+        return None;
+    }
+    let kind = if lower.contains("page fault") {
+        FaultKind::PageFault
+    } else if lower.contains("hang") {
+        FaultKind::Hang
+    } else if lower.contains("recovery") {
+        FaultKind::Recovery
+    } else {
+        FaultKind::Fault
+    };
+
+    // klogctl liefert "<3>[  123.456] ...", dmesg "[  123.456] ..."
+    let time = line
+        .split_once('[')
+        .and_then(|(prefix, rest)| prefix.trim_start_matches(|c: char| c == '<' || c == '>' || c.is_ascii_digit()).is_empty().then_some(rest))
+        .and_then(|rest| rest.split_once(']'))
+        .and_then(|(t, _)| t.trim().parse().ok());
+
+    let named = process_with_pid(line);
+    let pid = named.as_ref().map(|(_, pid)| *pid).or_else(|| number_after(&lower, "pid"));
+    let process = named
+        .map(|(name, _)| name)
+        .or_else(|| ["name=", "name:", "comm=", "comm:"].iter().find_map(|key| word_after(line, key)));
+    let context = ["drawctxt", "ctx_id", "ctx "].iter().find_map(|key| number_after(&lower, key));
+
+    Some(FaultRecord {
+        kind,
+        time,
+        process,
+        pid,
+        context,
+        timestamp: number_after(&lower, " ts "),
+        line: line.trim().to_string(),
+    })
+}
+
+/// Alle Fault-Zeilen eines Logs in Reihenfolge
+pub fn parse_faults(log: &str) -> Vec<FaultRecord> {
+    log.lines().filter_map(parse_fault_line).collect()
+}
+
+// ============================================================================
+// Snapshot
+// ============================================================================
+
+/// `KGSL_SNAPSHOT_MAGIC`
+const SNAPSHOT_MAGIC: u32 = 0x504D0002;
+/// `SNAPSHOT_SECTION_MAGIC`
+const SECTION_MAGIC: u16 = 0xABCD;
+/// `KGSL_SNAPSHOT_SECTION_OS`
+const SECTION_OS: u16 = 0x0101;
+/// `KGSL_SNAPSHOT_SECTION_END`
+const SECTION_END: u16 = 0xFFFF;
+/// OS-IDs der `kgsl_snapshot_linux*`-Layouts
+const OS_LINUX: u32 = 0x0001;
+const OS_LINUX_V3: u32 = 0x0202;
+const OS_LINUX_V4: u32 = 0x0203;
+
+/// So viel vom Dump wird gelesen - der Kernel gibt den Snapshot erst frei,
+/// wenn bis zum Ende gelesen wurde; die OS-Section steht ganz vorne
+const SNAPSHOT_PREFIX: usize = 64 * 1024;
+
+/// Kopf des GPU-Snapshots
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotInfo {
+    pub gpu_id: u32,
+    pub chip_id: u32,
+    /// Besitzer der aktiven Pagetable
+    pub pid: Option<u32>,
+    pub process: Option<String>,
+    pub context: Option<u32>,
+}
+
+fn u16_at(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(data.get(offset..offset.checked_add(2)?)?.try_into().ok()?))
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(offset..offset.checked_add(4)?)?.try_into().ok()?))
+}
+
+fn c_string(data: &[u8]) -> Option<String> {
+    let end = data.iter().position(|&b| b == 0).unwrap_or(data.len());
+    let text = String::from_utf8_lossy(&data[..end]).trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+/// Parst Header und OS-Section eines Snapshot-Dumps (auch nur den Anfang)
+pub fn parse_snapshot(data: &[u8]) -> Option<SnapshotInfo> {
+    if u32_at(data, 0)? != SNAPSHOT_MAGIC {
+        return None;
+    }
+    let mut info = SnapshotInfo { gpu_id: u32_at(data, 4)?, chip_id: u32_at(data, 8)?, pid: None, process: None, context: None };
+    let mut offset = 12;
+    while let (Some(magic), Some(id), Some(size)) = (u16_at(data, offset), u16_at(data, offset.saturating_add(2)), u32_at(data, offset.saturating_add(4))) {
+        if magic != SECTION_MAGIC || id == SECTION_END || size < 8 {
+            break;
+        }
+        if id == SECTION_OS {
+            // Kaputte Größen dürfen auch auf 32 Bit nicht überlaufen
+            let os = &data[offset + 8..data.len().min(offset.saturating_add(size as usize))];
+            // Offsets von pid, current_context und comm je Layout
+            let layout = match u32_at(os, 0) {
+                Some(OS_LINUX | OS_LINUX_V3) => Some((36, 40, 112)),
+                Some(OS_LINUX_V4) => Some((44, 52, 128)),
+                _ => None,
+            };
+            if let Some((pid, context, comm)) = layout {
+                info.pid = u32_at(os, pid).filter(|&p| p != 0);
+                info.context = u32_at(os, context);
+                info.process = os.get(comm..comm + 16).and_then(c_string);
+            }
+            break;
+        }
+        offset = offset.saturating_add(size as usize);
+    }
+    Some(info)
+}
+
+/// Anzahl der bisher erfassten Faults (`snapshot/faultcount`)
+pub fn snapshot_fault_count(sysfs_dir: &Path) -> io::Result<u64> {
+    read_u64(sysfs_dir.join("snapshot/faultcount"))
+}
+
+/// Liest den Anfang des Dumps, ohne den Snapshot freizugeben
+pub fn read_snapshot(path: &Path) -> io::Result<Option<SnapshotInfo>> {
+    let mut data = Vec::with_capacity(SNAPSHOT_PREFIX);
+    File::open(path)?.take(SNAPSHOT_PREFIX as u64).read_to_end(&mut data)?;
+    Ok(parse_snapshot(&data))
+}
+
+// ============================================================================
+// Korrelation
+// ============================================================================
+
+/// Wahrscheinlicher Verursacher mit Begründung
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Suspect {
+    pub pid: Option<u32>,
+    pub process: Option<String>,
+    pub context: Option<u32>,
+    /// Quellen, die den Verdächtigen stützen
+    pub evidence: Vec<String>,
+    /// Quellen, die etwas anderes sagen
+    pub conflicts: Vec<String>,
+}
+
+impl Suspect {
+    fn merge(&mut self, source: &str, pid: Option<u32>, process: Option<&str>, context: Option<u32>) {
+        let agrees = |mine: Option<u32>, theirs: Option<u32>| mine.is_none() || theirs.is_none() || mine == theirs;
+        if !agrees(self.pid, pid) || !agrees(self.context, context) {
+            self.conflicts.push(format!("{}: pid {}, context {}", source, show(pid), show(context)));
+            return;
+        }
+        self.pid = self.pid.or(pid);
+        self.context = self.context.or(context);
+        if self.process.is_none() {
+            self.process = process.map(str::to_string);
+        }
+        self.evidence.push(source.to_string());
+    }
+}
+
+fn show(value: Option<u32>) -> String {
+    value.map_or("?".to_string(), |v| v.to_string())
+}
+
+/// Führt die Quellen zusammen: letzter Fault im Log, dann Snapshot, dann Context-Liste
+pub fn identify(faults: &[FaultRecord], snapshot: Option<&SnapshotInfo>, contexts: &[ContextInfo]) -> Option<Suspect> {
+    let mut suspect = Suspect::default();
+    if let Some(fault) = faults.iter().rev().find(|f| f.pid.is_some() || f.context.is_some()) {
+        suspect.merge(&format!("kernel log ({})", fault.kind.label()), fault.pid, fault.process.as_deref(), fault.context);
+    }
+    if let Some(s) = snapshot.filter(|s| s.pid.is_some()) {
+        suspect.merge("GPU snapshot", s.pid, s.process.as_deref(), s.context);
+    }
+    if let Some(ctx) = suspect.context.and_then(|id| contexts.iter().find(|c| c.id == id)) {
+        suspect.merge("context list", ctx.pid, ctx.process.as_deref(), Some(ctx.id));
+    }
+    (suspect.pid.is_some() || suspect.context.is_some()).then_some(suspect)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Header plus Sections `(id, Nutzdaten)`
+    fn snapshot(sections: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for word in [SNAPSHOT_MAGIC, 0x0003_0000, 0x0603_0001] {
+            data.extend_from_slice(&word.to_le_bytes());
+        }
+        for (id, payload) in sections {
+            data.extend_from_slice(&SECTION_MAGIC.to_le_bytes());
+            data.extend_from_slice(&id.to_le_bytes());
+            data.extend_from_slice(&(payload.len() as u32 + 8).to_le_bytes());
+            data.extend_from_slice(payload);
+        }
+        data
+    }
+
+    /// OS-Section im v4-Layout
+    fn os_v4(pid: u32, context: u32, comm: &str) -> Vec<u8> {
+        let mut os = vec![0; 144];
+        os[..4].copy_from_slice(&OS_LINUX_V4.to_le_bytes());
+        os[44..48].copy_from_slice(&pid.to_le_bytes());
+        os[52..56].copy_from_slice(&context.to_le_bytes());
+        os[128..128 + comm.len()].copy_from_slice(comm.as_bytes());
+        os
+    }
+
+    #[test]
+    fn snapshot_os_section_after_other_sections() {
+        let data = snapshot(&[(0x0b01, vec![0xaa; 20]), (SECTION_OS, os_v4(4242, 7, "com.example.gam"))]);
+        let info = parse_snapshot(&data).unwrap();
+        assert_eq!((info.gpu_id, info.chip_id), (0x0003_0000, 0x0603_0001));
+        assert_eq!((info.pid, info.context), (Some(4242), Some(7)));
+        assert_eq!(info.process.as_deref(), Some("com.example.gam"));
+    }
+
+    #[test]
+    fn snapshot_truncated_or_foreign() {
+        let data = snapshot(&[(SECTION_OS, os_v4(0, 3, ""))]);
+        // PID 0 und leerer Name heißen "unbekannt"
+        let info = parse_snapshot(&data).unwrap();
+        assert_eq!((info.pid, info.process, info.context), (None, None, Some(3)));
+        // Nur der Header: Kopf ohne OS-Daten
+        assert_eq!(parse_snapshot(&data[..12]).unwrap().pid, None);
+        assert_eq!(parse_snapshot(&data[..40]).unwrap().context, None);
+        assert!(parse_snapshot(&data[..8]).is_none());
+        assert!(parse_snapshot(b"ELF\x7f and more bytes").is_none());
+        // Section-Größe unter 8 beendet die Suche
+        let mut broken = snapshot(&[(SECTION_OS, os_v4(1, 1, "x"))]);
+        broken[16..20].copy_from_slice(&4u32.to_le_bytes());
+        assert_eq!(parse_snapshot(&broken).unwrap().pid, None);
+    }
+
+    #[test]
+    fn fault_lines() {
+        let line = "<3>[  812.345678] kgsl kgsl-3d0: 'RenderThread'[4242]: gpu fault ctx 7 ctx_type GL ts 1234 status 0x1";
+        let fault = parse_fault_line(line).unwrap();
+        assert_eq!((fault.kind, fault.time), (FaultKind::Fault, Some(812.345678)));
+        assert_eq!((fault.process.as_deref(), fault.pid), (Some("RenderThread"), Some(4242)));
+        assert_eq!((fault.context, fault.timestamp), (Some(7), Some(1234)));
+
+        let fault = parse_fault_line("kgsl: GPU PAGE FAULT: pid = 0x1f name=game drawctxt=12").unwrap();
+        assert_eq!((fault.kind, fault.time, fault.pid), (FaultKind::PageFault, None, Some(0x1f)));
+        assert_eq!((fault.process.as_deref(), fault.context), (Some("game"), Some(12)));
+        assert!(parse_fault_line("[  1.0] usb 1-1: new device").is_none());
+    }
+}
@@ -0,0 +1,179 @@
+//! Turnip-Berater: unterstützt der Open-Source-Vulkan-Treiber diese GPU?
+//!
+//! Turnip (Mesa) läuft ab A6xx, entweder auf dem Upstream-DRM-Treiber
+//! (`msm`) oder - wie auf praktisch allen Android-Geräten - direkt auf KGSL,
+//! wofür Mesa mit dem KGSL-Backend gebaut sein muss. Die Mesa-Versionen
+//! geben die erste Release mit brauchbarer Unterstützung an (gerundet).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::chip::ChipInfo;
+use crate::driver::AndroidVersion;
+use crate::sysroot;
+
+/// DRM-Geräteklasse in sysfs
+const DRM_CLASS_DIR: &str = "/sys/class/drm";
+
+/// Namen des Upstream-Treibers der Adreno-GPU
+const MSM_DRIVERS: [&str; 2] = ["msm", "adreno"];
+
+/// Ab diesem API-Level laden Emulatoren eigene Treiber (adrenotools)
+const CUSTOM_DRIVER_MIN_SDK: u32 = 28;
+
+/// Kernel-Treiber der GPU
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KernelDriver {
+    Kgsl,
+    /// Upstream-DRM mit Render-Node
+    Msm(PathBuf),
+    /// Kein GPU-Treiber gefunden
+    Unknown,
+}
+
+impl KernelDriver {
+    pub fn label(&self) -> String {
+        match self {
+            KernelDriver::Kgsl => "KGSL (downstream Qualcomm)".to_string(),
+            KernelDriver::Msm(node) => format!("msm DRM ({})", node.display()),
+            KernelDriver::Unknown => "unknown".to_string(),
+        }
+    }
+}
+
+/// Render-Node des `msm`-Treibers, falls vorhanden
+pub fn find_msm_render_node() -> Option<PathBuf> {
+    let mut nodes: Vec<PathBuf> = fs::read_dir(sysroot::resolve(DRM_CLASS_DIR))
+        .ok()?
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().starts_with("renderD"))
+        .filter(|e| {
+            fs::read_link(e.path().join("device/driver"))
+                .ok()
+                .and_then(|link| link.file_name().map(|n| n.to_string_lossy().into_owned()))
+                .is_some_and(|name| MSM_DRIVERS.iter().any(|d| name.starts_with(d)))
+        })
+        .map(|e| Path::new("/dev/dri").join(e.file_name()))
+        .collect();
+    nodes.sort();
+    nodes.into_iter().next()
+}
+
+/// KGSL hat Vorrang - mit KGSL ist kein `msm` geladen
+pub fn detect_kernel_driver(kgsl_present: bool) -> KernelDriver {
+    match (kgsl_present, find_msm_render_node()) {
+        (true, _) => KernelDriver::Kgsl,
+        (false, Some(node)) => KernelDriver::Msm(node),
+        (false, None) => KernelDriver::Unknown,
+    }
+}
+
+/// Chip aus `compatible = "qcom,adreno-630.2"` eines DRM-Geräts
+pub fn chip_from_compatible(compatible: &str) -> Option<(u8, u8, u8)> {
+    let digits = compatible.split(',').nth(1)?.strip_prefix("adreno-")?;
+    let model = digits.split('.').next()?.as_bytes();
+    if model.len() != 3 || !model.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    Some((model[0] - b'0', model[1] - b'0', model[2] - b'0'))
+}
+
+/// Unterstützungsstand eines Chips
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Support {
+    /// Seit dieser Mesa-Version
+    Supported(&'static str),
+    /// In Mesa, aber unvollständig oder nur auf einzelnen Geräten getestet
+    Experimental(&'static str),
+    /// Turnip braucht A6xx oder neuer
+    TooOld,
+    /// Chip in Mesa nicht bekannt
+    Unknown,
+}
+
+/// (major, minor, patch oder None für alle) -> Unterstützung
+const TURNIP_TABLE: &[(u8, u8, Option<u8>, Support)] = &[
+    (6, 1, Some(0), Support::Supported("23.2")),
+    (6, 1, Some(2), Support::Experimental("24.1")),
+    (6, 1, Some(5), Support::Supported("22.2")),
+    (6, 1, Some(6), Support::Supported("22.2")),
+    (6, 1, Some(8), Support::Supported("20.3")),
+    (6, 1, Some(9), Support::Supported("22.2")),
+    (6, 2, None, Support::Supported("21.2")),
+    (6, 3, None, Support::Supported("20.0")),
+    (6, 4, None, Support::Supported("20.3")),
+    (6, 5, None, Support::Supported("21.0")),
+    (6, 6, None, Support::Supported("21.3")),
+    (6, 8, None, Support::Supported("22.0")),
+    (6, 9, None, Support::Supported("23.0")),
+    (7, 0, None, Support::Experimental("24.1")),
+    (7, 1, None, Support::Experimental("24.1")),
+    (7, 2, None, Support::Experimental("24.1")),
+    (7, 3, None, Support::Supported("24.0")),
+    (7, 4, None, Support::Supported("24.0")),
+    (7, 5, None, Support::Supported("24.2")),
+];
+
+pub fn lookup(major: u8, minor: u8, patch: u8) -> Support {
+    if major < 6 {
+        return Support::TooOld;
+    }
+    TURNIP_TABLE
+        .iter()
+        .find(|(ma, mi, pa, _)| *ma == major && *mi == minor && pa.is_none_or(|p| p == patch))
+        .map_or(Support::Unknown, |(_, _, _, support)| *support)
+}
+
+/// Ergebnis des Beraters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Advice {
+    pub support: Support,
+    /// Was gebraucht wird, in Reihenfolge
+    pub requirements: Vec<String>,
+    pub notes: Vec<String>,
+}
+
+pub fn advise(chip: &ChipInfo, driver: &KernelDriver, android: Option<&AndroidVersion>) -> Advice {
+    let support = lookup(chip.major, chip.minor, chip.patch);
+    let mut requirements = Vec::new();
+    let mut notes = Vec::new();
+
+    let mesa = match support {
+        Support::Supported(v) | Support::Experimental(v) => v,
+        Support::TooOld => {
+            notes.push("Turnip supports A6xx and newer only; for A5xx and older there is freedreno OpenGL ES on msm DRM kernels".to_string());
+            return Advice { support, requirements, notes };
+        }
+        Support::Unknown => {
+            notes.push("This chip is not in Mesa's device table yet - check freedreno_devices.py in a current Mesa".to_string());
+            return Advice { support, requirements, notes };
+        }
+    };
+
+    match driver {
+        KernelDriver::Kgsl => {
+            requirements.push(format!("Mesa >= {} built with the KGSL backend (-Dfreedreno-kmds=kgsl)", mesa));
+            notes.push("Stock Android kernels only have KGSL; regular Linux Mesa packages target msm and will not find the GPU".to_string());
+        }
+        KernelDriver::Msm(_) => requirements.push(format!("Mesa >= {} (default msm backend)", mesa)),
+        KernelDriver::Unknown => {
+            requirements.push(format!("Mesa >= {} with the backend matching the kernel: kgsl (Android) or msm (mainline)", mesa));
+        }
+    }
+    if let Some(android) = android {
+        match android.sdk {
+            Some(sdk) if sdk < CUSTOM_DRIVER_MIN_SDK => notes.push(format!(
+                "Android {} (API {}) is too old for custom driver loading in emulators (needs API {}+)",
+                android.release, sdk, CUSTOM_DRIVER_MIN_SDK
+            )),
+            _ => requirements.push("On Android: load Turnip through an app that supports custom drivers (adrenotools), system Vulkan stays the vendor blob".to_string()),
+        }
+    }
+    if matches!(support, Support::Experimental(_)) {
+        notes.push("Support is marked experimental: expect missing features or rendering issues".to_string());
+    }
+    if chip.major >= 7 && matches!(driver, KernelDriver::Kgsl) {
+        notes.push("A7xx on KGSL needs a recent Mesa; older builds fail at device creation".to_string());
+    }
+    Advice { support, requirements, notes }
+}
@@ -0,0 +1,81 @@
+//! Einheiten auf der Kommandozeile: Dauern und Größen
+//!
+//! Liegt in der Bibliothek, damit auch die Fuzz-Targets die Parser erreichen.
+
+use std::time::Duration;
+
+use crate::messages::Msg;
+
+/// Parst Dauer wie `5s`, `500ms`, `2m` oder `10` (Sekunden)
+pub fn parse_duration(text: &str) -> Result<Duration, String> {
+    let text = text.trim();
+    let (number, unit) = match text.find(|c: char| !c.is_ascii_digit() && c != '.') {
+        Some(i) => (&text[..i], &text[i..]),
+        None => (text, "s"),
+    };
+    let value: f64 = number.parse().map_err(|_| Msg::InvalidDuration { text }.to_string())?;
+    let secs = match unit {
+        "ms" => value / 1000.0,
+        "s" => value,
+        "m" | "min" => value * 60.0,
+        "h" => value * 3600.0,
+        _ => return Err(Msg::InvalidDurationUnit { text }.to_string()),
+    };
+    // Zu große Werte sind ein Eingabefehler, keine Panik
+    Duration::try_from_secs_f64(secs).map_err(|_| Msg::InvalidDuration { text }.to_string())
+}
+
+/// Parst Größen wie `64K`, `1M`, `1GB`, `512MiB` oder `4096` (Bytes)
+pub fn parse_size(text: &str) -> Result<u64, String> {
+    let text = text.trim();
+    let (number, unit) = match text.find(|c: char| !c.is_ascii_digit() && c != '.') {
+        Some(i) => (&text[..i], &text[i..]),
+        None => (text, ""),
+    };
+    let value: f64 = number.parse().map_err(|_| Msg::InvalidSize { text }.to_string())?;
+    let factor: u64 = match unit.to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "K" | "KB" | "KIB" => 1 << 10,
+        "M" | "MB" | "MIB" => 1 << 20,
+        "G" | "GB" | "GIB" => 1 << 30,
+        _ => return Err(Msg::InvalidSizeUnit { text }.to_string()),
+    };
+    let bytes = value * factor as f64;
+    // `as u64` würde negative Werte und NaN stillschweigend auf 0 setzen
+    if !bytes.is_finite() || bytes < 0.0 || bytes >= u64::MAX as f64 {
+        return Err(Msg::InvalidSize { text }.to_string());
+    }
+    Ok(bytes as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("10"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_duration(" 500ms "), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("2min"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1h"), Ok(Duration::from_secs(3600)));
+        for bad in ["", "s", "1.2.3s", "5 s", "5d", "-1s", "1e400", "99999999999999999999999h"] {
+            assert!(parse_duration(bad).is_err(), "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn sizes() {
+        assert_eq!(parse_size("4096"), Ok(4096));
+        assert_eq!(parse_size("4096B"), Ok(4096));
+        assert_eq!(parse_size("64K"), Ok(64 << 10));
+        assert_eq!(parse_size("64kb"), Ok(64 << 10));
+        assert_eq!(parse_size("1.5M"), Ok(3 << 19));
+        assert_eq!(parse_size("512MiB"), Ok(512 << 20));
+        assert_eq!(parse_size("1GB"), Ok(1 << 30));
+        for bad in ["", "K", "10BBB", "10KBB", "10KIBIB", "10T", "1.2.3K", "99999999999G", "-5K", "nan", "inf"] {
+            assert!(parse_size(bad).is_err(), "{:?}", bad);
+        }
+    }
+}
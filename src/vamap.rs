@@ -0,0 +1,84 @@
+//! Karte des GPU-Adressraums eines Prozesses (Objekte und Lücken)
+
+use crate::memory::{self, KgslGpuobjInfo};
+
+/// Ein Abschnitt des GPU-Adressraums
+#[derive(Debug, Clone)]
+pub enum VaRegion {
+    Object(KgslGpuobjInfo),
+    /// Unbelegt zwischen zwei Objekten
+    Gap { start: u64, len: u64 },
+}
+
+impl VaRegion {
+    pub fn start(&self) -> u64 {
+        match self {
+            VaRegion::Object(o) => o.gpuaddr,
+            VaRegion::Gap { start, .. } => *start,
+        }
+    }
+
+    /// Länge inklusive reserviertem VA-Bereich
+    pub fn len(&self) -> u64 {
+        match self {
+            VaRegion::Object(o) => o.va_len.max(o.size),
+            VaRegion::Gap { len, .. } => *len,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn end(&self) -> u64 {
+        self.start() + self.len()
+    }
+}
+
+/// Sortierte Folge von Objekten und Lücken
+#[derive(Debug, Clone, Default)]
+pub struct VaMap {
+    pub regions: Vec<VaRegion>,
+}
+
+impl VaMap {
+    /// Baut die Karte aus beliebig sortierten Objekten
+    pub fn from_objects(mut objects: Vec<KgslGpuobjInfo>) -> Self {
+        objects.sort_by_key(|o| o.gpuaddr);
+        let mut regions = Vec::with_capacity(objects.len() * 2);
+        let mut cursor: Option<u64> = None;
+        for obj in objects {
+            let region = VaRegion::Object(obj);
+            if let Some(end) = cursor
+                && region.start() > end
+            {
+                regions.push(VaRegion::Gap { start: end, len: region.start() - end });
+            }
+            cursor = Some(cursor.map_or(region.end(), |c| c.max(region.end())));
+            regions.push(region);
+        }
+        VaMap { regions }
+    }
+
+    /// Liest alle Objekte dieses Prozesses mit ID bis `max_id`
+    pub fn read(fd: i32, max_id: u32) -> Self {
+        Self::from_objects(memory::list_objects(fd, max_id))
+    }
+
+    pub fn objects(&self) -> impl Iterator<Item = &KgslGpuobjInfo> {
+        self.regions.iter().filter_map(|r| match r {
+            VaRegion::Object(o) => Some(o),
+            VaRegion::Gap { .. } => None,
+        })
+    }
+
+    /// Adressbereich von erstem bis letztem Objekt
+    pub fn span(&self) -> Option<(u64, u64)> {
+        Some((self.regions.first()?.start(), self.regions.iter().map(VaRegion::end).max()?))
+    }
+
+    /// Summe der belegten Bytes
+    pub fn used_bytes(&self) -> u64 {
+        self.objects().map(|o| o.size).sum()
+    }
+}
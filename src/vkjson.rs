@@ -0,0 +1,77 @@
+//! Export im Stil von vulkaninfo/devsim-JSON
+//!
+//! Nur was der Kernel verrät: Vendor/Device ID, Name und Gerätetyp gehen in
+//! die Standard-Struktur `VkPhysicalDeviceProperties`. API- und
+//! Treiberversion kennt nur der Userspace-Treiber, sie fehlen daher. GMEM,
+//! UBWC und Takte stehen in einem eigenen Abschnitt `adreno`.
+
+use crate::chip::ChipInfo;
+use crate::gmem::GmemConfig;
+use crate::json::Json;
+
+/// Schema der Vulkan Device Simulation Layer Dateien
+pub const DEVSIM_SCHEMA: &str = "https://schema.khronos.org/vulkan/devsim_1_0_0.json#";
+
+/// PCI Vendor ID von Qualcomm, wie sie der Vulkan-Treiber meldet
+pub const QUALCOMM_VENDOR_ID: u32 = 0x5143;
+
+/// `VK_PHYSICAL_DEVICE_TYPE_INTEGRATED_GPU`
+const INTEGRATED_GPU: u32 = 1;
+
+/// PCI-artige Schreibweise `vendor:device`, z.B. "5143:43050a01"
+pub fn pci_style_id(device_id: u32) -> String {
+    format!("{:04x}:{:08x}", QUALCOMM_VENDOR_ID, device_id)
+}
+
+/// Hardware-Fakten für den Export
+#[derive(Debug, Clone)]
+pub struct DeviceFacts {
+    pub chip: ChipInfo,
+    /// `KGSL_PROP_GPU_MODEL`, z.B. "Adreno740v2"
+    pub model: Option<String>,
+    /// `KGSL_PROP_VK_DEVICE_ID`, sonst gilt die Chip ID
+    pub vk_device_id: Option<u32>,
+    pub gmem: Option<GmemConfig>,
+    /// Verfügbare Frequenzen aus sysfs
+    pub frequencies_hz: Vec<u64>,
+    pub kernel_release: String,
+}
+
+/// Name wie im Vulkan-Treiber, z.B. "Adreno (TM) 740"
+pub fn vulkan_device_name(chip: &ChipInfo) -> String {
+    let number = chip.model_name.trim_start_matches("Adreno").trim();
+    format!("Adreno (TM) {}", number)
+}
+
+pub fn to_vkjson(facts: &DeviceFacts) -> Json {
+    let chip = &facts.chip;
+    // Der Qualcomm-Treiber meldet die Chip ID als deviceID, neuere Kernel verraten sie direkt
+    let properties = Json::object()
+        .field("vendorID", QUALCOMM_VENDOR_ID)
+        .field("deviceID", facts.vk_device_id.unwrap_or(chip.raw_id))
+        .field("deviceType", INTEGRATED_GPU)
+        .field("deviceName", vulkan_device_name(chip));
+
+    let spec = chip.spec();
+    let adreno = Json::object()
+        .field("chipId", format!("0x{:08x}", chip.raw_id))
+        .field("model", facts.model.clone())
+        .field("generation", chip.adreno_generation.clone())
+        .field("gmemBytes", facts.gmem.as_ref().map(|g| g.size_bytes))
+        .field("ubwcVersion", spec.map(|s| s.ubwc_version as u32))
+        .field("shaderProcessors", spec.map(|s| s.num_sp as u32))
+        .field("frequenciesHz", facts.frequencies_hz.clone())
+        .field("maxFrequencyHz", facts.frequencies_hz.iter().max().copied());
+
+    Json::object()
+        .field("$schema", DEVSIM_SCHEMA)
+        .field(
+            "comments",
+            Json::object()
+                .field("desc", format!("{} - hardware facts from KGSL", vulkan_device_name(chip)))
+                .field("kernel", facts.kernel_release.as_str())
+                .field("tool", concat!("adreno_ioctl ", env!("CARGO_PKG_VERSION"))),
+        )
+        .field("VkPhysicalDeviceProperties", properties)
+        .field("adreno", adreno)
+}
@@ -0,0 +1,114 @@
+//! Warnungen für unvollständige Ergebnisse
+//!
+//! Schlägt ein einzelner Probe fehl, wird der Wert nicht stillschweigend
+//! weggelassen, sondern mit Ursache im Bericht vermerkt.
+
+use std::fmt;
+use std::io;
+
+use crate::json::Json;
+
+/// Warum ein Wert fehlt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningCause {
+    /// Keine Rechte (EACCES/EPERM)
+    Permission,
+    /// Kernel kennt die Abfrage nicht (ENOTTY/EINVAL/EOPNOTSUPP)
+    Kernel,
+    /// Hardware oder Knoten nicht vorhanden (ENOENT/ENODEV)
+    Hardware,
+    Other,
+}
+
+impl WarningCause {
+    pub fn from_io(e: &io::Error) -> Self {
+        match e.raw_os_error() {
+            Some(libc::EACCES | libc::EPERM) => WarningCause::Permission,
+            Some(libc::ENOTTY | libc::EINVAL | libc::EOPNOTSUPP) => WarningCause::Kernel,
+            Some(libc::ENOENT | libc::ENODEV | libc::ENXIO) => WarningCause::Hardware,
+            _ => WarningCause::Other,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            WarningCause::Permission => "permission",
+            WarningCause::Kernel => "kernel",
+            WarningCause::Hardware => "hardware",
+            WarningCause::Other => "other",
+        }
+    }
+}
+
+/// Ein fehlgeschlagener Probe
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    /// Name des Probes, z.B. "version" oder "frequency"
+    pub probe: &'static str,
+    pub cause: WarningCause,
+    pub detail: String,
+}
+
+impl Warning {
+    pub fn new(probe: &'static str, cause: WarningCause, detail: impl Into<String>) -> Self {
+        Warning { probe, cause, detail: detail.into() }
+    }
+
+    pub fn from_io(probe: &'static str, e: &io::Error) -> Self {
+        Warning::new(probe, WarningCause::from_io(e), e.to_string())
+    }
+
+    pub fn to_json(&self) -> Json {
+        Json::object()
+            .field("probe", self.probe)
+            .field("cause", self.cause.name())
+            .field("detail", self.detail.as_str())
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} ({})", self.probe, self.detail, self.cause.name())
+    }
+}
+
+/// Sammlung der Warnungen eines Berichts
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Warnings(Vec<Warning>);
+
+impl Warnings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, warning: Warning) {
+        self.0.push(warning);
+    }
+
+    /// Übernimmt den Wert oder vermerkt den Fehler
+    pub fn check<T>(&mut self, probe: &'static str, result: io::Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.push(Warning::from_io(probe, &e));
+                None
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Warning> {
+        self.0.iter()
+    }
+
+    pub fn to_json(&self) -> Json {
+        Json::Array(self.0.iter().map(Warning::to_json).collect())
+    }
+}
@@ -0,0 +1,107 @@
+//! Zap-Shader und Secure-Mode Erkennung
+//!
+//! Ohne passende Zap-Firmware kann die GPU auf A5xx/A6xx nicht aus dem
+//! Secure Mode wechseln - typisches Symptom auf Custom ROMs: schwarzer Bildschirm.
+
+use std::path::PathBuf;
+
+use crate::devicetree;
+use crate::dmesg;
+use crate::kgsl;
+use crate::propmap::Prop;
+use crate::sysroot;
+
+/// Übliche Firmware-Verzeichnisse auf Android und Linux
+pub const FIRMWARE_DIRS: [&str; 7] = [
+    "/vendor/firmware",
+    "/vendor/firmware_mnt/image",
+    "/vendor/lib/firmware",
+    "/odm/firmware",
+    "/firmware/image",
+    "/lib/firmware",
+    "/lib/firmware/qcom",
+];
+
+/// Dateiendungen einer aufgeteilten (mdt) oder ganzen (mbn) Firmware
+const FIRMWARE_EXTENSIONS: [&str; 3] = ["mdt", "mbn", "b00"];
+
+pub const KGSL_PROP_SECURE_BUFFER_ALIGNMENT: u32 = 0x00000023;
+pub const KGSL_PROP_SECURE_CTXT_SUPPORT: u32 = 0x00000024;
+
+/// Ergebnis der Zap/Secure-Diagnose
+#[derive(Debug, Clone, Default)]
+pub struct ZapStatus {
+    /// Firmware-Name laut Device Tree
+    pub configured: Option<String>,
+    /// Zap-Knoten im Device Tree vorhanden
+    pub dt_node: bool,
+    /// Gefundene Firmware-Dateien
+    pub firmware_files: Vec<PathBuf>,
+    /// `KGSL_PROP_SECURE_CTXT_SUPPORT`, None wenn nicht abfragbar
+    pub secure_ctxt_support: Option<bool>,
+    pub secure_buffer_alignment: Option<u32>,
+    /// Zap-bezogene Kernel-Log Zeilen (nur mit Root)
+    pub log_lines: Vec<String>,
+}
+
+impl ZapStatus {
+    /// Zap konfiguriert, aber keine Firmware gefunden
+    pub fn firmware_missing(&self) -> bool {
+        self.configured.is_some() && self.firmware_files.is_empty()
+    }
+
+    /// Kurze Diagnose für die Ausgabe
+    pub fn diagnosis(&self) -> &'static str {
+        if self.firmware_missing() {
+            "zap firmware missing - GPU cannot leave secure mode (black screen)"
+        } else if self.log_lines.iter().any(|l| l.to_ascii_lowercase().contains("fail")) {
+            "zap shader load failed - see kernel log"
+        } else if self.secure_ctxt_support == Some(true) {
+            "GPU came up with secure mode support"
+        } else if self.configured.is_none() && !self.dt_node {
+            "no zap shader configured - GPU runs without secure mode"
+        } else {
+            "secure mode not reported by the driver"
+        }
+    }
+}
+
+/// Sucht `<name>.mdt/.mbn/.b00` in den Firmware-Verzeichnissen
+pub fn find_firmware(name: &str) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    for dir in FIRMWARE_DIRS {
+        for ext in FIRMWARE_EXTENSIONS {
+            let path = sysroot::resolve(dir).join(format!("{}.{}", name, ext));
+            if path.exists() {
+                found.push(path);
+            }
+        }
+    }
+    found
+}
+
+/// Sammelt alle Hinweise auf Zap-Shader und Secure Mode
+pub fn detect(fd: i32) -> ZapStatus {
+    let mut status = ZapStatus::default();
+
+    if let Some(node) = devicetree::read_gpu_node() {
+        status.configured = node.zap_shader;
+        status.dt_node = node.has_zap_node;
+    }
+    if let Some(name) = &status.configured {
+        status.firmware_files = find_firmware(name);
+    }
+
+    if let Ok(value) = kgsl::read_property::<u32>(fd, Prop::SecureCtxtSupport) {
+        status.secure_ctxt_support = Some(value != 0);
+    }
+    if let Ok(align) = kgsl::read_property::<u32>(fd, Prop::SecureBufferAlignment) {
+        status.secure_buffer_alignment = (align != 0).then_some(align);
+    }
+
+    if let Ok(log) = dmesg::read_kernel_log() {
+        status.log_lines = dmesg::grep(&log, &["zap"]).into_iter().map(str::to_string).collect();
+    }
+
+    status
+}
@@ -0,0 +1,83 @@
+//! Golden-Tests der Berichtsausgabe
+//!
+//! Jeder Fall rendert Fixture-Daten in drei Modi (Rahmen mit Emoji,
+//! `--plain`, `--json`) und vergleicht mit `tests/golden/<fall>.<modus>`.
+//! Nach einer gewollten Formatänderung neu schreiben mit
+//! `UPDATE_GOLDEN=1 cargo test --test golden` und den Diff prüfen.
+
+use std::fs;
+use std::path::PathBuf;
+
+use adreno_ioctl::bus::DdrType;
+use adreno_ioctl::kgsl::{KgslDeviceInfo, KgslVersionInfo};
+use adreno_ioctl::report::{gpu_info_json, render_gpu_info, render_warnings, to_ascii, InfoExtras};
+use adreno_ioctl::warnings::{Warning, WarningCause, Warnings};
+
+fn golden_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(name)
+}
+
+fn check(name: &str, actual: &str) {
+    let path = golden_path(name);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("{}: {} (run with UPDATE_GOLDEN=1 to create it)", path.display(), e));
+    assert!(
+        expected == actual,
+        "{} differs from the rendered output\n--- expected\n{}\n--- actual\n{}",
+        path.display(),
+        expected,
+        actual
+    );
+}
+
+/// Bericht wie `info`: Kasten, bei Warnungen mit Liste darunter
+fn render(info: &KgslDeviceInfo, extras: &InfoExtras) -> String {
+    let mut out = render_gpu_info(info, extras);
+    if !extras.warnings.is_empty() {
+        out.push('\n');
+        out.push_str(&render_warnings(&extras.warnings));
+    }
+    out
+}
+
+fn check_all_modes(case: &str, info: &KgslDeviceInfo, extras: &InfoExtras) {
+    let fancy = render(info, extras);
+    check(&format!("{}.txt", case), &fancy);
+    check(&format!("{}.plain.txt", case), &to_ascii(&fancy));
+    check(&format!("{}.json", case), &(gpu_info_json(info, extras).to_pretty() + "\n"));
+}
+
+#[test]
+fn a610_all_probes() {
+    let info = KgslDeviceInfo { device_id: 1, chip_id: 0x0601_0000, mmu_enabled: 1, gmem_gpubaseaddr: 0x0010_0000 };
+    let extras = InfoExtras {
+        version: Some(KgslVersionInfo { driver_version: 0x0003_000f, device_version: 0x0003_0001 }),
+        freq_hz: Some(845_000_000),
+        model: Some("Adreno610v1".to_string()),
+        gmem_bytes: Some(132 * 1024),
+        vk_device_id: Some(0x0601_0000),
+        ddr_type: Some(DdrType::Lpddr4x),
+        warnings: Warnings::new(),
+    };
+    check_all_modes("a610_all_probes", &info, &extras);
+}
+
+#[test]
+fn a750_with_warnings() {
+    let info = KgslDeviceInfo { device_id: 1, chip_id: 0x0705_0001, mmu_enabled: 1, gmem_gpubaseaddr: 0 };
+    let mut warnings = Warnings::new();
+    warnings.push(Warning::new("version", WarningCause::Permission, "Permission denied (os error 13)"));
+    warnings.push(Warning::new("model", WarningCause::Kernel, "KGSL_PROP_GPU_MODEL not supported"));
+    let extras = InfoExtras { freq_hz: Some(903_000_000), warnings, ..Default::default() };
+    check_all_modes("a750_with_warnings", &info, &extras);
+}
+
+#[test]
+fn unknown_chip_minimal() {
+    let info = KgslDeviceInfo { device_id: 7, chip_id: 0x0909_0909, mmu_enabled: 0, gmem_gpubaseaddr: 0xdead_beef };
+    check_all_modes("unknown_chip_minimal", &info, &InfoExtras::default());
+}